@@ -0,0 +1,118 @@
+// benches/heavy_nodes.rs - 무거운 노드 성능 회귀 측정
+// 실행: cargo bench --bench heavy_nodes
+use automation_gui_lib::bench_api::{diagnostics, qr_code_node, text_merger_node, worker_pool};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use std::path::PathBuf;
+
+fn bench_qr_generation(c: &mut Criterion) {
+    let runtime = tokio::runtime::Runtime::new().expect("failed to build tokio runtime");
+    let mut group = c.benchmark_group("qr_code_node");
+
+    // 짧은 URL부터 QR 최대 용량에 가까운 입력까지
+    for size in [32usize, 256, 1024] {
+        let text = format!("https://example.com/{}", "a".repeat(size));
+        group.throughput(Throughput::Bytes(text.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &text, |b, text| {
+            b.to_async(&runtime)
+                .iter(|| async { qr_code_node(black_box(text.clone())).await.unwrap() });
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_text_merging(c: &mut Criterion) {
+    let mut group = c.benchmark_group("text_merger_node");
+    group.sample_size(20);
+
+    for megabytes in [1usize, 16] {
+        let line = "2024-01-01 12:00:00 INFO request handled in 12ms\n";
+        let text = line.repeat(megabytes * 1024 * 1024 / line.len());
+        group.throughput(Throughput::Bytes((text.len() * 2) as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!("{}MB", megabytes)),
+            &text,
+            |b, text| {
+                b.iter(|| {
+                    text_merger_node(
+                        black_box(text.clone()),
+                        black_box(text.clone()),
+                        "\n".to_string(),
+                    )
+                    .unwrap()
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
+// 로그 형태의 압축이 잘 되는 내용 (실제 동기화 / 진단 번들 입력과 비슷하게)
+fn sample_files(count: usize, bytes: usize) -> Vec<(String, Vec<u8>)> {
+    let line = "2024-01-01 12:00:00 INFO request handled in 12ms\n";
+    (0..count)
+        .map(|i| {
+            let data = line.repeat(bytes / line.len()).into_bytes();
+            (format!("logs/file_{}.log", i), data)
+        })
+        .collect()
+}
+
+fn bench_file_hashing(c: &mut Criterion) {
+    let mut group = c.benchmark_group("worker_pool::hash_files");
+    group.sample_size(20);
+    let dir = tempfile::tempdir().expect("failed to create temp dir");
+
+    for (count, kilobytes) in [(64usize, 64usize), (8, 4096)] {
+        let paths: Vec<PathBuf> = sample_files(count, kilobytes * 1024)
+            .into_iter()
+            .enumerate()
+            .map(|(i, (_, data))| {
+                let path = dir.path().join(format!("{}_{}_{}.log", count, kilobytes, i));
+                std::fs::write(&path, data).expect("failed to write sample file");
+                path
+            })
+            .collect();
+        group.throughput(Throughput::Bytes((count * kilobytes * 1024) as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!("{}x{}KB", count, kilobytes)),
+            &paths,
+            |b, paths| {
+                b.iter(|| worker_pool::hash_files(black_box(paths.clone())).unwrap());
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn bench_archive_creation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("diagnostics::write_zip");
+    group.sample_size(20);
+    let dir = tempfile::tempdir().expect("failed to create temp dir");
+    let target = dir.path().join("bundle.zip");
+
+    for (count, kilobytes) in [(20usize, 256usize), (4, 4096)] {
+        let entries = sample_files(count, kilobytes * 1024);
+        group.throughput(Throughput::Bytes((count * kilobytes * 1024) as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!("{}x{}KB", count, kilobytes)),
+            &entries,
+            |b, entries| {
+                b.iter(|| diagnostics::write_zip(&target, black_box(entries)).unwrap());
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_qr_generation,
+    bench_text_merging,
+    bench_file_hashing,
+    bench_archive_creation
+);
+criterion_main!(benches);
@@ -1,16 +1,61 @@
 // lib.rs - Tauri 앱 설정 및 노드 자동 등록
 mod nodes;
 
+// 벤치마크(benches/heavy_nodes.rs)에서 쓰는 노드만 공개
+#[doc(hidden)]
+pub mod bench_api {
+    pub use crate::nodes::{diagnostics, qr_code_node, text_merger_node, worker_pool};
+}
+
 use nodes::*;
 
+// MCP 클라이언트가 `--mcp-stdio` 로 실행한 경우 GUI 없이 실행 중인 앱으로 중계 → 종료 코드
+pub fn run_mcp_stdio_bridge() -> Option<i32> {
+    nodes::mcp_server::stdio_bridge_port().map(nodes::mcp_server::run_stdio_bridge)
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    tauri::Builder::default()
+    // 크래시 리포트 (store/logs/crash_*.txt)
+    nodes::diagnostics::install_panic_hook();
+
+    let builder = tauri::Builder::default();
+
+    // 이미 실행 중이면 인자/링크를 기존 인스턴스로 넘기고 종료 (가장 먼저 등록해야 함)
+    #[cfg(desktop)]
+    let builder = builder.plugin(tauri_plugin_single_instance::init(|app, argv, cwd| {
+        nodes::instance::handle_second_instance(app, argv, cwd);
+    }));
+
+    builder
+        .plugin(tauri_plugin_deep_link::init())
         .plugin(tauri_plugin_clipboard_manager::init())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_store::Builder::default().build())
+        .setup(|app| {
+            // 백그라운드 정리 작업들
+            nodes::cli_ai_node::start_history_pruning_job();
+            nodes::git_sync::pull_on_launch(app.handle());
+            nodes::doctor::check_on_launch(app.handle());
+            nodes::http_client::warm_on_launch();
+
+            // trigger:// 링크 (Windows/Linux 는 설치 시 등록되지만 개발 빌드는 직접 등록)
+            {
+                use tauri_plugin_deep_link::DeepLinkExt;
+                #[cfg(any(windows, target_os = "linux"))]
+                if let Err(e) = app.deep_link().register_all() {
+                    println!("⚠️ trigger:// 링크 등록 실패: {}", e);
+                }
+                let handle = app.handle().clone();
+                app.deep_link().on_open_url(move |event| {
+                    let urls: Vec<String> = event.urls().iter().map(|u| u.to_string()).collect();
+                    nodes::instance::handle_open_urls(&handle, &urls);
+                });
+            }
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             file_creator_node,
             text_file_editor_node,
@@ -26,6 +71,8 @@ pub fn run() {
             save_workflow_to_desktop,
             load_workflow_from_desktop,
             load_specific_workflow,
+            get_workflow_parameters,
+            prepare_workflow_run,
             qr_code_node,
             video_download_node,
             file_path_node,
@@ -35,6 +82,239 @@ pub fn run() {
             cli_node,
             update_cli_result,
             clear_conversation_history,
+            get_cached_node_output,
+            set_cached_node_output,
+            clear_node_cache,
+            set_mock_mode,
+            get_mock_mode,
+            clear_mock_recordings,
+            qr_code_batch_node,
+            begin_file_write,
+            append_chunk,
+            finish_file_write,
+            abort_file_write,
+            list_ai_sessions,
+            get_ai_history_limits,
+            set_ai_history_limits,
+            prune_ai_sessions,
+            summarize_node,
+            ai_extract_node,
+            start_voice_trigger,
+            stop_voice_trigger,
+            get_voice_trigger_status,
+            notification_node,
+            presence_node,
+            media_control_node,
+            wifi_node,
+            start_usb_watcher,
+            stop_usb_watcher,
+            list_usb_devices,
+            get_usb_watcher_status,
+            display_node,
+            dns_node,
+            port_check_node,
+            ticket_node,
+            mcp_node,
+            start_mcp_server,
+            stop_mcp_server,
+            get_mcp_server_status,
+            publish_workflow_tool,
+            unpublish_workflow_tool,
+            list_published_tools,
+            complete_mcp_tool_call,
+            encoding_node,
+            normalize_text_node,
+            list_ops_node,
+            table_node,
+            pdf_builder_node,
+            photo_sort_node,
+            ffprobe_node,
+            watermark_node,
+            clipboard_image_node,
+            color_node,
+            set_secret,
+            delete_secret,
+            list_secrets,
+            password_node,
+            totp_node,
+            service_node,
+            export_os_schedule,
+            remove_os_schedule,
+            list_os_schedules,
+            take_launch_workflow,
+            generate_diagnostics_bundle,
+            set_crash_reporting,
+            set_telemetry_enabled,
+            record_node_usage,
+            record_workflow_usage,
+            get_usage_stats,
+            reset_usage_stats,
+            get_resource_limits,
+            set_resource_limits,
+            get_resource_usage,
+            check_disk_space,
+            get_server_access_log,
+            clear_server_access_log,
+            acquire_workflow_lock,
+            release_workflow_lock,
+            get_workflow_lock,
+            take_launch_request,
+            shell_session_node,
+            reset_shell_session,
+            list_shell_sessions,
+            normalize_node_output,
+            coerce_node_output,
+            store_large_value,
+            get_value_preview,
+            read_value_chunk,
+            release_value,
+            clear_value_store,
+            start_run,
+            record_node_run,
+            finish_run,
+            get_run,
+            export_run_report,
+            publish_run_event,
+            list_runs,
+            push_node,
+            barcode_node,
+            check_schedule_conditions,
+            list_holidays,
+            get_sun_times,
+            start_geofence_trigger,
+            stop_geofence_trigger,
+            get_geofence_status,
+            market_data_node,
+            now_playing_node,
+            list_control_surfaces,
+            start_midi_trigger,
+            stop_midi_trigger,
+            get_midi_trigger_status,
+            mock_server_node,
+            update_mock_routes,
+            get_mock_server_log,
+            stop_mock_server,
+            get_mock_server_info,
+            soap_node,
+            excel_read_node,
+            doc_text_node,
+            vcard_node,
+            email_parse_node,
+            torrent_node,
+            cancel_torrent,
+            list_torrents,
+            podcast_node,
+            check_download_history,
+            get_download_history,
+            clear_download_history,
+            gallery_node,
+            get_file_search_settings,
+            set_file_search_settings,
+            resolve_workspace,
+            expand_node_params,
+            set_variable,
+            delete_variable,
+            list_variables,
+            approval_node,
+            respond_approval,
+            cancel_approval,
+            list_pending_approvals,
+            prompt_node,
+            timer_node,
+            cancel_timer,
+            get_timer_status,
+            list_timers,
+            acquire_rate_token,
+            get_rate_buckets,
+            reset_rate_bucket,
+            acquire_lock,
+            release_lock,
+            list_locks,
+            cache_node,
+            aggregate_node,
+            log_node,
+            assert_node,
+            snapshot_node,
+            redact_node,
+            scan_workflow_secrets,
+            move_secrets_to_manager,
+            sign_workflow,
+            verify_workflow,
+            get_signing_public_key,
+            trust_signer,
+            untrust_signer,
+            list_trusted_signers,
+            get_marketplace_settings,
+            set_marketplace_settings,
+            marketplace_search,
+            marketplace_fetch,
+            marketplace_install,
+            marketplace_list_installed,
+            list_profiles,
+            create_profile,
+            switch_profile,
+            delete_profile,
+            get_git_sync_settings,
+            set_git_sync_settings,
+            git_sync_now,
+            git_sync_status,
+            get_cloud_sync_settings,
+            set_cloud_sync_settings,
+            sync_now,
+            share_run,
+            revoke_run_share,
+            list_run_shares,
+            get_node_docs,
+            list_node_docs,
+            list_workflow_versions,
+            get_workflow_version,
+            annotate_workflow_version,
+            set_run_tracing,
+            get_run_tracing,
+            list_node_traces,
+            get_node_trace,
+            replay_node,
+            complete_node_replay,
+            set_breakpoints,
+            get_breakpoints,
+            debug_before_node,
+            step_run,
+            continue_run,
+            abort_run,
+            pause_run,
+            list_paused_runs,
+            add_watch,
+            remove_watch,
+            list_watches,
+            register_cleanup,
+            unregister_cleanup,
+            list_cleanups,
+            run_cleanup_now,
+            doctor,
+            list_tools,
+            set_tool_pin,
+            install_tool,
+            remove_tool,
+            get_worker_pool,
+            set_worker_pool,
+            acquire_run_slot,
+            release_run_slot,
+            yield_run_slot,
+            list_run_queue,
+            set_workflow_priority,
+            set_max_concurrent_runs,
+            get_http_client_settings,
+            set_http_client_settings,
+            warm_http_connections,
+            get_ws_limits,
+            set_ws_limits,
+            list_message_routes,
+            save_message_route,
+            delete_message_route,
+            reorder_message_routes,
+            test_message_route,
+            route_inbound_message,
+            build_workflow_from_prompt,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
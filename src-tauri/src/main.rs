@@ -4,5 +4,10 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 fn main() {
+    // MCP 클라이언트가 stdio 로 실행한 경우 GUI 없이 실행 중인 앱으로 중계만 함
+    if let Some(code) = automation_gui_lib::run_mcp_stdio_bridge() {
+        std::process::exit(code);
+    }
+
     automation_gui_lib::run()
 }
@@ -0,0 +1,251 @@
+// src-tauri/src/nodes/access_log.rs
+use super::atomic_store;
+use rusqlite::{params, Connection};
+use serde::Serialize;
+use std::sync::Mutex;
+
+// 📜 외부 공개 서버 접근 기록
+// 채팅/정적 파일/MCP 서버가 처리한 모든 HTTP·WebSocket 요청(IP, 경로, User-Agent, 허용 여부)을
+// store/logs.db 에 기록해 터널로 공개한 주소에 누가 접근했는지 확인할 수 있게 함
+
+const RETENTION_DAYS: i64 = 30;
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct AccessLogEntry {
+    pub node_id: String,
+    pub timestamp: i64,
+    pub ip: String,
+    pub method: String,
+    pub path: String,
+    pub status: u16,
+    pub user_agent: Option<String>,
+    pub auth_result: String,
+    pub elapsed_ms: u64,
+}
+
+static LOG_DB: std::sync::OnceLock<Option<Mutex<Connection>>> = std::sync::OnceLock::new();
+
+pub fn init_schema(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS server_access_log (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            node_id TEXT NOT NULL,
+            timestamp INTEGER NOT NULL,
+            ip TEXT NOT NULL,
+            method TEXT NOT NULL,
+            path TEXT NOT NULL,
+            status INTEGER NOT NULL,
+            user_agent TEXT,
+            auth_result TEXT NOT NULL,
+            elapsed_ms INTEGER NOT NULL
+        );
+        CREATE INDEX IF NOT EXISTS idx_access_node_time ON server_access_log(node_id, timestamp);",
+    )
+}
+
+fn get_log_db() -> Option<&'static Mutex<Connection>> {
+    LOG_DB
+        .get_or_init(|| {
+            let dir = atomic_store::store_dir();
+            std::fs::create_dir_all(&dir).ok()?;
+            let conn = Connection::open(dir.join("logs.db"))
+                .map_err(|e| println!("⚠️ 로그 DB 열기 실패: {}", e))
+                .ok()?;
+            let _ = conn.pragma_update(None, "journal_mode", "WAL");
+            init_schema(&conn).ok()?;
+
+            // 오래된 기록 정리
+            let cutoff = chrono::Utc::now().timestamp() - RETENTION_DAYS * 24 * 60 * 60;
+            let _ = conn.execute(
+                "DELETE FROM server_access_log WHERE timestamp < ?1",
+                params![cutoff],
+            );
+            Some(Mutex::new(conn))
+        })
+        .as_ref()
+}
+
+// 401/403 은 거부, 나머지는 허용으로 기록
+pub fn auth_result(status: u16) -> &'static str {
+    match status {
+        401 | 403 => "denied",
+        _ => "allowed",
+    }
+}
+
+// 터널(cloudflared)을 거친 요청은 실제 IP 가 헤더에 있음
+// 터널은 로컬에서 접속하므로 상대가 loopback 일 때만 헤더를 믿음 (LAN 에서 직접 온 요청은 헤더 위조 가능)
+pub fn client_ip(headers: &warp::http::HeaderMap, remote: Option<std::net::SocketAddr>) -> String {
+    let from_tunnel = remote.is_some_and(|addr| addr.ip().is_loopback());
+    if !from_tunnel {
+        return remote
+            .map(|addr| addr.ip().to_string())
+            .unwrap_or_else(|| "unknown".to_string());
+    }
+    let header = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.split(',').next().unwrap_or("").trim().to_string())
+            .filter(|v| !v.is_empty())
+    };
+    header("cf-connecting-ip")
+        .or_else(|| header("x-forwarded-for"))
+        .or_else(|| remote.map(|addr| addr.ip().to_string()))
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+pub fn insert_entry(conn: &Connection, entry: &AccessLogEntry) -> rusqlite::Result<()> {
+    conn.execute(
+        "INSERT INTO server_access_log
+            (node_id, timestamp, ip, method, path, status, user_agent, auth_result, elapsed_ms)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+        params![
+            entry.node_id,
+            entry.timestamp,
+            entry.ip,
+            entry.method,
+            entry.path,
+            entry.status,
+            entry.user_agent,
+            entry.auth_result,
+            entry.elapsed_ms as i64
+        ],
+    )?;
+    Ok(())
+}
+
+pub fn query_entries(
+    conn: &Connection,
+    node_id: &str,
+    limit: usize,
+) -> rusqlite::Result<Vec<AccessLogEntry>> {
+    let mut stmt = conn.prepare(
+        "SELECT node_id, timestamp, ip, method, path, status, user_agent, auth_result, elapsed_ms
+         FROM server_access_log WHERE node_id = ?1 ORDER BY id DESC LIMIT ?2",
+    )?;
+    let rows = stmt.query_map(params![node_id, limit as i64], |row| {
+        Ok(AccessLogEntry {
+            node_id: row.get(0)?,
+            timestamp: row.get(1)?,
+            ip: row.get(2)?,
+            method: row.get(3)?,
+            path: row.get(4)?,
+            status: row.get(5)?,
+            user_agent: row.get(6)?,
+            auth_result: row.get(7)?,
+            elapsed_ms: row.get::<_, i64>(8)? as u64,
+        })
+    })?;
+    rows.collect()
+}
+
+pub fn record(entry: AccessLogEntry) {
+    if let Some(db) = get_log_db() {
+        if let Ok(conn) = db.lock() {
+            if let Err(e) = insert_entry(&conn, &entry) {
+                println!("⚠️ 접근 기록 저장 실패: {}", e);
+            }
+        }
+    }
+}
+
+// warp 서버에 붙이는 요청 로거 (WebSocket 은 업그레이드 요청이 101 로 기록됨)
+pub fn warp_logger(node_id: String) -> warp::log::Log<impl Fn(warp::log::Info) + Clone> {
+    warp::log::custom(move |info: warp::log::Info| {
+        let status = info.status().as_u16();
+        record(AccessLogEntry {
+            node_id: node_id.clone(),
+            timestamp: chrono::Utc::now().timestamp(),
+            ip: client_ip(info.request_headers(), info.remote_addr()),
+            method: info.method().to_string(),
+            path: info.path().to_string(),
+            status,
+            user_agent: info.user_agent().map(str::to_string),
+            auth_result: auth_result(status).to_string(),
+            elapsed_ms: info.elapsed().as_millis() as u64,
+        });
+    })
+}
+
+#[tauri::command]
+pub fn get_server_access_log(
+    node_id: String,
+    limit: Option<usize>,
+) -> Result<Vec<AccessLogEntry>, String> {
+    let db = get_log_db().ok_or("로그 DB 를 열 수 없습니다")?;
+    let conn = db.lock().map_err(|e| e.to_string())?;
+    query_entries(&conn, &node_id, limit.unwrap_or(200).min(5000))
+        .map_err(|e| format!("접근 기록 조회 실패: {}", e))
+}
+
+#[tauri::command]
+pub fn clear_server_access_log(node_id: String) -> Result<String, String> {
+    let db = get_log_db().ok_or("로그 DB 를 열 수 없습니다")?;
+    let conn = db.lock().map_err(|e| e.to_string())?;
+    conn.execute(
+        "DELETE FROM server_access_log WHERE node_id = ?1",
+        params![node_id],
+    )
+    .map_err(|e| format!("접근 기록 삭제 실패: {}", e))?;
+    println!("🗑️ 접근 기록 삭제: {}", node_id);
+    Ok("SUCCESS".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn access_log_stores_and_queries_entries_per_node() {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        init_schema(&conn).unwrap();
+
+        for (node, path, status) in [
+            ("chat_1", "/", 200),
+            ("chat_1", "/ws", 403),
+            ("chat_2", "/files/a", 404),
+        ] {
+            insert_entry(
+                &conn,
+                &AccessLogEntry {
+                    node_id: node.to_string(),
+                    timestamp: 1_700_000_000,
+                    ip: "203.0.113.9".to_string(),
+                    method: "GET".to_string(),
+                    path: path.to_string(),
+                    status,
+                    user_agent: Some("curl/8".to_string()),
+                    auth_result: auth_result(status).to_string(),
+                    elapsed_ms: 3,
+                },
+            )
+            .unwrap();
+        }
+
+        let entries = query_entries(&conn, "chat_1", 10).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].path, "/ws");
+        assert_eq!(entries[0].auth_result, "denied");
+        assert_eq!(entries[1].auth_result, "allowed");
+        assert_eq!(
+            query_entries(&conn, "chat_1", 1).unwrap().len(),
+            1
+        );
+    }
+
+    #[test]
+    fn access_log_prefers_tunnel_client_ip() {
+        let mut headers = warp::http::HeaderMap::new();
+        let remote = Some("127.0.0.1:5000".parse().unwrap());
+        assert_eq!(client_ip(&headers, remote), "127.0.0.1");
+        headers.insert("x-forwarded-for", "198.51.100.7, 10.0.0.1".parse().unwrap());
+        assert_eq!(client_ip(&headers, remote), "198.51.100.7");
+        headers.insert("cf-connecting-ip", "203.0.113.9".parse().unwrap());
+        assert_eq!(client_ip(&headers, remote), "203.0.113.9");
+
+        // LAN 에서 직접 온 요청의 헤더는 무시
+        let lan = Some("192.168.0.20:5000".parse().unwrap());
+        assert_eq!(client_ip(&headers, lan), "192.168.0.20");
+    }
+}
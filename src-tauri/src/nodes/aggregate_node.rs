@@ -0,0 +1,219 @@
+// src-tauri/src/nodes/aggregate_node.rs
+use super::atomic_store;
+use chrono::{DateTime, Datelike, Local};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+// 📊 카운터 / 집계 노드
+// 실행 사이에 이름 있는 카운터(개수, 합계, 최소, 최대)를 유지 (store/aggregates.json)
+// "오늘 처리한 파일 수" 같은 요약을 DB 없이 보고할 수 있도록
+// period: total(기본) | day | week | month → 기간이 바뀌면 자동으로 0 부터 다시
+// action: increment | reset | read | list
+
+static AGGREGATE_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Aggregate {
+    pub count: u64,
+    pub sum: f64,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    #[serde(default)]
+    pub period: String,
+    pub period_key: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+impl Aggregate {
+    pub fn record(&mut self, value: f64) {
+        self.count += 1;
+        self.sum += value;
+        self.min = Some(self.min.map_or(value, |m| m.min(value)));
+        self.max = Some(self.max.map_or(value, |m| m.max(value)));
+    }
+
+    // 기간이 지났으면 값을 비움
+    pub fn roll(&mut self, period: &str, now: DateTime<Local>) {
+        let key = period_key(period, now);
+        if self.period != period || self.period_key != key {
+            *self = Aggregate {
+                period: period.to_string(),
+                period_key: key,
+                ..Default::default()
+            };
+        }
+    }
+
+    pub fn to_json(&self, name: &str) -> Value {
+        json!({
+            "name": name,
+            "count": self.count,
+            "sum": self.sum,
+            "min": self.min,
+            "max": self.max,
+            "avg": if self.count > 0 { Some(self.sum / self.count as f64) } else { None },
+            "period": self.period,
+            "period_key": self.period_key,
+            "updated_at": self.updated_at
+        })
+    }
+}
+
+// day → "2026-10-16", week → "2026-W42", month → "2026-10", total → 없음
+pub fn period_key(period: &str, now: DateTime<Local>) -> Option<String> {
+    match period {
+        "day" => Some(now.format("%Y-%m-%d").to_string()),
+        "week" => {
+            let week = now.iso_week();
+            Some(format!("{}-W{:02}", week.year(), week.week()))
+        }
+        "month" => Some(now.format("%Y-%m").to_string()),
+        _ => None,
+    }
+}
+
+fn normalize_period(period: Option<&str>) -> Result<String, String> {
+    match period.map(|p| p.trim().to_lowercase()).as_deref() {
+        None | Some("") | Some("total") => Ok("total".to_string()),
+        Some(p @ ("day" | "week" | "month")) => Ok(p.to_string()),
+        Some(other) => Err(format!(
+            "INVALID_PERIOD: {} (total, day, week, month)",
+            other
+        )),
+    }
+}
+
+fn aggregates_path() -> PathBuf {
+    atomic_store::store_dir().join("aggregates.json")
+}
+
+fn load_aggregates() -> BTreeMap<String, Aggregate> {
+    atomic_store::read_json_verified(&aggregates_path()).unwrap_or_default()
+}
+
+#[tauri::command]
+pub fn aggregate_node(
+    action: String,
+    name: Option<String>,
+    value: Option<f64>,
+    period: Option<String>,
+) -> Result<String, String> {
+    let _guard = AGGREGATE_LOCK.lock().map_err(|e| e.to_string())?;
+    let mut aggregates = load_aggregates();
+    let now = Local::now();
+    let action = action.trim().to_lowercase();
+
+    if action == "list" {
+        let list: Vec<Value> = aggregates
+            .iter_mut()
+            .map(|(name, aggregate)| {
+                let period = aggregate.period.clone();
+                aggregate.roll(&period, now);
+                aggregate.to_json(name)
+            })
+            .collect();
+        return Ok(json!({ "aggregates": list }).to_string());
+    }
+
+    let name = name
+        .map(|n| n.trim().to_string())
+        .filter(|n| !n.is_empty())
+        .ok_or("AGGREGATE_NAME_REQUIRED")?;
+    // period 를 생략하면 기존 카운터의 기간을 그대로 사용
+    let period = match (&period, aggregates.get(&name)) {
+        (None, Some(existing)) if !existing.period.is_empty() => existing.period.clone(),
+        _ => normalize_period(period.as_deref())?,
+    };
+    let aggregate = aggregates.entry(name.clone()).or_default();
+    aggregate.roll(&period, now);
+
+    match action.as_str() {
+        "increment" | "add" => {
+            let value = value.unwrap_or(1.0);
+            if !value.is_finite() {
+                return Err("INVALID_VALUE".to_string());
+            }
+            aggregate.record(value);
+            aggregate.updated_at = Some(now.to_rfc3339());
+            println!("📊 집계 {}: +{} (개수 {})", name, value, aggregate.count);
+        }
+        "reset" => {
+            *aggregate = Aggregate {
+                period: period.clone(),
+                period_key: period_key(&period, now),
+                updated_at: Some(now.to_rfc3339()),
+                ..Default::default()
+            };
+            println!("🔄 집계 초기화: {}", name);
+        }
+        "read" => {}
+        other => return Err(format!("UNKNOWN_AGGREGATE_ACTION: {}", other)),
+    }
+
+    let result = aggregate.to_json(&name);
+    if action != "read" {
+        atomic_store::write_json_atomic(&aggregates_path(), &aggregates)?;
+    }
+    Ok(result.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn aggregate_node_tracks_count_sum_min_max() {
+        let mut aggregate = Aggregate::default();
+        for value in [3.0, -1.0, 10.0] {
+            aggregate.record(value);
+        }
+        assert_eq!(aggregate.count, 3);
+        assert_eq!(aggregate.sum, 12.0);
+        assert_eq!(aggregate.min, Some(-1.0));
+        assert_eq!(aggregate.max, Some(10.0));
+        assert_eq!(aggregate.to_json("files")["avg"], json!(4.0));
+    }
+
+    #[test]
+    fn aggregate_node_rolls_over_when_period_changes() {
+        use chrono::TimeZone;
+        let monday = chrono::Local
+            .with_ymd_and_hms(2026, 10, 12, 9, 0, 0)
+            .unwrap();
+        let tuesday = chrono::Local
+            .with_ymd_and_hms(2026, 10, 13, 9, 0, 0)
+            .unwrap();
+
+        assert_eq!(
+            period_key("day", monday).as_deref(),
+            Some("2026-10-12")
+        );
+        assert_eq!(
+            period_key("week", monday).as_deref(),
+            Some("2026-W42")
+        );
+        assert_eq!(
+            period_key("month", monday).as_deref(),
+            Some("2026-10")
+        );
+        assert_eq!(period_key("total", monday), None);
+
+        let mut daily = Aggregate::default();
+        daily.roll("day", monday);
+        daily.record(5.0);
+        daily.roll("day", monday);
+        assert_eq!(daily.count, 1);
+        daily.roll("day", tuesday);
+        assert_eq!(daily.count, 0);
+
+        let mut weekly = Aggregate::default();
+        weekly.roll("week", monday);
+        weekly.record(1.0);
+        weekly.roll("week", tuesday);
+        assert_eq!(weekly.count, 1);
+    }
+}
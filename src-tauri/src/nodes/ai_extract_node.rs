@@ -0,0 +1,121 @@
+// src-tauri/src/nodes/ai_extract_node.rs
+use serde_json::{json, Value};
+
+use super::cli_ai_node::request_claude_completion;
+use super::http_client;
+use super::json_schema;
+use super::node_cache::make_cache_key;
+
+// 모델 응답에서 JSON 부분만 추출 (```json 코드블록, 앞뒤 설명문 제거)
+pub(crate) fn extract_json_text(response: &str) -> &str {
+    let trimmed = response.trim();
+
+    if let Some(start) = trimmed.find("```") {
+        let after_fence = &trimmed[start + 3..];
+        let body_start = after_fence.find('\n').map(|i| i + 1).unwrap_or(0);
+        let body = &after_fence[body_start..];
+        if let Some(end) = body.find("```") {
+            return body[..end].trim();
+        }
+    }
+
+    let start = trimmed.find(['{', '[']);
+    let end = trimmed.rfind(['}', ']']);
+    match (start, end) {
+        (Some(start), Some(end)) if end > start => &trimmed[start..=end],
+        _ => trimmed,
+    }
+}
+
+#[tauri::command]
+pub async fn ai_extract_node(
+    text: String,
+    json_schema: String,
+    api_key: String,
+    model: String,
+    max_retries: Option<u32>,
+) -> Result<String, String> {
+    println!("🧾 AI Extract Node 실행 시작");
+
+    if text.trim().is_empty() {
+        return Err("NO_INPUT_TEXT".to_string());
+    }
+    if api_key.trim().is_empty() {
+        return Err("NO_API_KEY".to_string());
+    }
+
+    let schema: Value =
+        serde_json::from_str(&json_schema).map_err(|e| format!("INVALID_JSON_SCHEMA: {}", e))?;
+    let schema_text = serde_json::to_string_pretty(&schema).unwrap_or_else(|_| json_schema.clone());
+    let max_retries = max_retries.unwrap_or(2);
+
+    let system_prompt = format!(
+        "You extract structured data from text. Respond with ONLY a single JSON value that \
+         conforms to this JSON Schema. No markdown, no explanations.\n\nJSON Schema:\n{}",
+        schema_text
+    );
+
+    let client = http_client::client();
+    let mut prompt = text.clone();
+    let mut attempts = Vec::new();
+
+    for attempt in 0..=max_retries {
+        let mock_request = json!({
+            "node": "ai_extract_node",
+            "attempt": attempt,
+            "schema": make_cache_key("schema", &schema),
+            "prompt": make_cache_key("prompt", &json!(prompt))
+        });
+
+        let response_json = request_claude_completion(
+            &client,
+            &api_key,
+            &model,
+            &system_prompt,
+            &json!(prompt),
+            &mock_request,
+        )
+        .await?;
+
+        let raw_output = response_json["content"][0]["text"]
+            .as_str()
+            .ok_or("No content in API response")?
+            .to_string();
+
+        // 파싱 + 스키마 검증
+        let errors = match serde_json::from_str::<Value>(extract_json_text(&raw_output)) {
+            Ok(data) => {
+                let errors = json_schema::validate(&data, &schema);
+                if errors.is_empty() {
+                    println!("✅ 스키마 검증 통과 (시도 {}회)", attempt + 1);
+                    let result = json!({
+                        "data": data,
+                        "attempts": attempt + 1,
+                        "repair_history": attempts,
+                        "model_used": model
+                    });
+                    return Ok(result.to_string());
+                }
+                errors
+            }
+            Err(e) => vec![format!("$: output is not valid JSON ({})", e)],
+        };
+
+        println!("⚠️ 스키마 검증 실패 (시도 {}): {:?}", attempt + 1, errors);
+        attempts.push(json!({ "attempt": attempt + 1, "errors": errors }));
+
+        // 수리(repair) 프롬프트: 원문 + 직전 출력 + 오류 목록
+        prompt = format!(
+            "Source text:\n{}\n\nYour previous output:\n{}\n\nIt failed validation with these errors:\n- {}\n\n\
+             Return corrected JSON that satisfies the schema.",
+            text,
+            raw_output,
+            errors.join("\n- ")
+        );
+    }
+
+    Err(format!(
+        "SCHEMA_VALIDATION_FAILED: {}",
+        Value::Array(attempts)
+    ))
+}
@@ -0,0 +1,359 @@
+// src-tauri/src/nodes/approval_node.rs
+use serde::Serialize;
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tauri::{AppHandle, Emitter, Listener};
+use tauri_plugin_dialog::{DialogExt, MessageDialogButtons};
+use tokio::sync::{oneshot, Mutex};
+
+// ✋ 승인(사람 확인) 노드
+// 실행을 멈추고 사용자에게 선택지를 보여준 뒤 고른 값으로 이어서 실행
+// channel:
+// - "dialog"       네이티브 메시지 창 (선택지 2개까지)
+// - "chat"         실행 중인 채팅 서버(chat_node_id)로 질문 전송, 선택지 이름이나 번호로 답장
+// - "notification" Windows 토스트 버튼 (다른 OS 는 기본 알림 + 앱/채팅 응답)
+// - "app"          "approval-requested" 이벤트만 (프론트엔드 / 컨트롤 페이지에서 respond_approval)
+// 어느 채널이든 respond_approval 로 응답 가능, 제한 시간이 지나면 default_option 또는 APPROVAL_TIMEOUT
+
+const DEFAULT_TIMEOUT_SECS: u64 = 600;
+const MAX_TIMEOUT_SECS: u64 = 24 * 60 * 60;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PendingApproval {
+    pub approval_id: String,
+    pub node_id: String,
+    pub message: String,
+    pub options: Vec<String>,
+    pub channel: String,
+    pub chat_node_id: Option<String>,
+    pub requested_at: String,
+    pub expires_at: String,
+}
+
+struct PendingEntry {
+    info: PendingApproval,
+    sender: oneshot::Sender<String>,
+}
+
+static PENDING: std::sync::OnceLock<Mutex<HashMap<String, PendingEntry>>> =
+    std::sync::OnceLock::new();
+static APPROVAL_COUNTER: AtomicU64 = AtomicU64::new(1);
+
+fn get_pending() -> &'static Mutex<HashMap<String, PendingEntry>> {
+    PENDING.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+// 답장 텍스트 → 선택지 (이름 대소문자 무시, 또는 1부터 시작하는 번호)
+pub fn match_option(options: &[String], answer: &str) -> Option<String> {
+    let answer = answer.trim();
+    if let Ok(index) = answer.parse::<usize>() {
+        if (1..=options.len()).contains(&index) {
+            return Some(options[index - 1].clone());
+        }
+    }
+    options
+        .iter()
+        .find(|option| option.to_lowercase() == answer.to_lowercase())
+        .cloned()
+}
+
+pub fn chat_prompt(message: &str, options: &[String]) -> String {
+    let choices: Vec<String> = options
+        .iter()
+        .enumerate()
+        .map(|(i, option)| format!("{}. {}", i + 1, option))
+        .collect();
+    format!(
+        "✋ {}\n{}\n(번호나 선택지 이름으로 답장해 주세요)",
+        message,
+        choices.join("\n")
+    )
+}
+
+async fn resolve(approval_id: &str, option: String) -> Result<(), String> {
+    let entry = get_pending()
+        .lock()
+        .await
+        .remove(approval_id)
+        .ok_or_else(|| format!("APPROVAL_NOT_FOUND: {}", approval_id))?;
+    if !entry.info.options.contains(&option) {
+        let id = entry.info.approval_id.clone();
+        get_pending().lock().await.insert(id, entry);
+        return Err(format!("INVALID_APPROVAL_OPTION: {}", option));
+    }
+    let _ = entry.sender.send(option);
+    Ok(())
+}
+
+// 채팅 서버로 들어온 메시지가 대기 중인 승인에 대한 답이면 처리 (처리했으면 true)
+pub async fn try_answer_from_chat(chat_node_id: &str, message: &str) -> bool {
+    let matched = {
+        let pending = get_pending().lock().await;
+        pending
+            .values()
+            .filter(|entry| entry.info.chat_node_id.as_deref() == Some(chat_node_id))
+            .min_by(|a, b| a.info.requested_at.cmp(&b.info.requested_at))
+            .and_then(|entry| {
+                match_option(&entry.info.options, message)
+                    .map(|option| (entry.info.approval_id.clone(), option))
+            })
+    };
+    match matched {
+        Some((approval_id, option)) => {
+            println!("✋ 채팅 답장으로 승인 처리: {} → {}", approval_id, option);
+            resolve(&approval_id, option).await.is_ok()
+        }
+        None => false,
+    }
+}
+
+fn show_dialog(app_handle: &AppHandle, approval_id: String, message: String, options: Vec<String>) {
+    let app = app_handle.clone();
+    tokio::task::spawn_blocking(move || {
+        let buttons = match options.as_slice() {
+            [only] => MessageDialogButtons::OkCustom(only.clone()),
+            [first, second] => MessageDialogButtons::OkCancelCustom(first.clone(), second.clone()),
+            _ => return,
+        };
+        let confirmed = app
+            .dialog()
+            .message(message)
+            .title("승인 요청")
+            .buttons(buttons)
+            .blocking_show();
+        let choice = if confirmed || options.len() == 1 {
+            options[0].clone()
+        } else {
+            options[1].clone()
+        };
+        tauri::async_runtime::block_on(async {
+            // 다른 채널에서 먼저 응답했으면 무시
+            let _ = resolve(&approval_id, choice).await;
+        });
+    });
+}
+
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn approval_node(
+    app_handle: AppHandle,
+    node_id: Option<String>,
+    message: String,
+    options: Option<Vec<String>>,
+    timeout_secs: Option<u64>,
+    channel: Option<String>,
+    default_option: Option<String>,
+    chat_node_id: Option<String>,
+) -> Result<String, String> {
+    let node_id = node_id.unwrap_or_else(|| "unknown".to_string());
+    if message.trim().is_empty() {
+        return Err("EMPTY_APPROVAL_MESSAGE".to_string());
+    }
+    let mut options: Vec<String> = options
+        .unwrap_or_default()
+        .into_iter()
+        .map(|o| o.trim().to_string())
+        .filter(|o| !o.is_empty())
+        .collect();
+    options.dedup();
+    if options.is_empty() {
+        options = vec!["승인".to_string(), "거절".to_string()];
+    }
+    if let Some(default) = &default_option {
+        if !options.contains(default) {
+            return Err(format!("INVALID_DEFAULT_OPTION: {}", default));
+        }
+    }
+    let channel = channel
+        .map(|c| c.trim().to_lowercase())
+        .filter(|c| !c.is_empty())
+        .unwrap_or_else(|| "dialog".to_string());
+    match channel.as_str() {
+        "dialog" if options.len() > 2 => {
+            return Err("DIALOG_SUPPORTS_TWO_OPTIONS: 선택지가 3개 이상이면 chat / notification / app 채널을 사용하세요".to_string())
+        }
+        "chat" if chat_node_id.as_deref().is_none_or(|id| id.trim().is_empty()) => {
+            return Err("CHAT_NODE_ID_REQUIRED".to_string())
+        }
+        "dialog" | "chat" | "notification" | "app" => {}
+        other => return Err(format!("UNKNOWN_APPROVAL_CHANNEL: {}", other)),
+    }
+    let timeout_secs = timeout_secs
+        .unwrap_or(DEFAULT_TIMEOUT_SECS)
+        .clamp(1, MAX_TIMEOUT_SECS);
+
+    let approval_id = format!(
+        "approval_{}_{}",
+        chrono::Local::now().timestamp_millis(),
+        APPROVAL_COUNTER.fetch_add(1, Ordering::SeqCst)
+    );
+    let now = chrono::Local::now();
+    let info = PendingApproval {
+        approval_id: approval_id.clone(),
+        node_id: node_id.clone(),
+        message: message.clone(),
+        options: options.clone(),
+        channel: channel.clone(),
+        chat_node_id: chat_node_id.clone().filter(|_| channel == "chat"),
+        requested_at: now.to_rfc3339(),
+        expires_at: (now + chrono::Duration::seconds(timeout_secs as i64)).to_rfc3339(),
+    };
+    let (sender, receiver) = oneshot::channel();
+    get_pending().lock().await.insert(
+        approval_id.clone(),
+        PendingEntry {
+            info: info.clone(),
+            sender,
+        },
+    );
+    println!("✋ 승인 대기 ({}): {} {:?}", channel, message, options);
+    if let Err(e) = app_handle.emit("approval-requested", &info) {
+        eprintln!("❌ Failed to emit approval request: {}", e);
+    }
+
+    let mut listener = None;
+    match channel.as_str() {
+        "dialog" => show_dialog(
+            &app_handle,
+            approval_id.clone(),
+            message.clone(),
+            options.clone(),
+        ),
+        "chat" => {
+            let chat_node_id = chat_node_id.clone().unwrap_or_default();
+            if let Err(e) = super::chat_web_server_node::send_to_mobile_with_type(
+                chat_node_id.trim().to_string(),
+                chat_prompt(&message, &options),
+                "approval".to_string(),
+            )
+            .await
+            {
+                get_pending().lock().await.remove(&approval_id);
+                return Err(e);
+            }
+        }
+        "notification" => {
+            // 토스트 결과는 notification_node 가 "notification-action" 이벤트로 알려줌
+            let toast_id = approval_id.clone();
+            listener = Some(app_handle.listen_any("notification-action", move |event| {
+                let Ok(payload) = serde_json::from_str::<serde_json::Value>(event.payload()) else {
+                    return;
+                };
+                if payload["node_id"] != toast_id.as_str() || payload["result"] != "activated" {
+                    return;
+                }
+                if let Some(action) = payload["action"].as_str() {
+                    let (id, action) = (toast_id.clone(), action.to_string());
+                    tauri::async_runtime::spawn(async move {
+                        let _ = resolve(&id, action).await;
+                    });
+                }
+            }));
+            super::notification_node::notification_node(
+                app_handle.clone(),
+                Some(approval_id.clone()),
+                "승인 요청".to_string(),
+                message.clone(),
+                Some(options.clone()),
+                None,
+                Some(timeout_secs),
+            )
+            .await?;
+        }
+        _ => {}
+    }
+
+    let outcome =
+        tokio::time::timeout(std::time::Duration::from_secs(timeout_secs), receiver).await;
+    if let Some(id) = listener {
+        app_handle.unlisten(id);
+    }
+    get_pending().lock().await.remove(&approval_id);
+    let _ = app_handle.emit("approval-finished", json!({ "approval_id": approval_id }));
+
+    let (selected, timed_out) = match outcome {
+        Ok(Ok(selected)) => (selected, false),
+        Ok(Err(_)) => return Err("APPROVAL_CANCELLED".to_string()),
+        Err(_) => match default_option {
+            Some(default) => {
+                println!("⏰ 승인 시간 초과 - 기본값 사용: {}", default);
+                (default, true)
+            }
+            None => return Err(format!("APPROVAL_TIMEOUT: {}초", timeout_secs)),
+        },
+    };
+    println!("✅ 승인 결과: {}", selected);
+    Ok(json!({
+        "approval_id": approval_id,
+        "node_id": node_id,
+        "selected": selected,
+        "index": options.iter().position(|o| *o == selected),
+        "channel": channel,
+        "timed_out": timed_out,
+        "responded_at": chrono::Local::now().to_rfc3339()
+    })
+    .to_string())
+}
+
+#[tauri::command]
+pub async fn respond_approval(approval_id: String, option: String) -> Result<String, String> {
+    resolve(approval_id.trim(), option.trim().to_string()).await?;
+    Ok("SUCCESS".to_string())
+}
+
+// 대기 중인 승인을 취소 (노드는 APPROVAL_CANCELLED 로 끝남)
+#[tauri::command]
+pub async fn cancel_approval(approval_id: String) -> Result<String, String> {
+    get_pending()
+        .lock()
+        .await
+        .remove(approval_id.trim())
+        .ok_or_else(|| format!("APPROVAL_NOT_FOUND: {}", approval_id))?;
+    Ok("SUCCESS".to_string())
+}
+
+#[tauri::command]
+pub async fn list_pending_approvals() -> Result<Vec<PendingApproval>, String> {
+    let pending = get_pending().lock().await;
+    let mut approvals: Vec<PendingApproval> = pending.values().map(|e| e.info.clone()).collect();
+    approvals.sort_by(|a, b| a.requested_at.cmp(&b.requested_at));
+    Ok(approvals)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn approval_matches_chat_answers_by_name_or_number() {
+        let options = vec!["승인".to_string(), "거절".to_string(), "Later".to_string()];
+        assert_eq!(
+            match_option(&options, " 2 "),
+            Some("거절".to_string())
+        );
+        assert_eq!(
+            match_option(&options, "later"),
+            Some("Later".to_string())
+        );
+        assert_eq!(
+            match_option(&options, "승인"),
+            Some("승인".to_string())
+        );
+        assert_eq!(match_option(&options, "4"), None);
+        assert_eq!(match_option(&options, "좋아요"), None);
+
+        let prompt = chat_prompt("배포할까요?", &options);
+        assert!(prompt.contains("배포할까요?"));
+        assert!(prompt.contains("1. 승인\n2. 거절\n3. Later"));
+    }
+
+    #[tokio::test]
+    async fn approval_rejects_unknown_ids() {
+        let err = respond_approval("approval_missing".to_string(), "승인".to_string())
+            .await
+            .unwrap_err();
+        assert!(err.starts_with("APPROVAL_NOT_FOUND"));
+        assert!(!try_answer_from_chat("no-such-chat", "1").await);
+    }
+}
@@ -0,0 +1,361 @@
+// src-tauri/src/nodes/assert_node.rs
+use super::json_schema;
+use super::template::{lookup, render, value_to_text};
+use regex::Regex;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+// ✅ 검증(assert) 노드
+// 앞 노드의 데이터가 기대와 다르면 실행을 실패(error) 또는 경고(warn) 처리
+// AI 가 만들거나 고친 워크플로우도 스스로 검사할 수 있도록
+// condition: true/false (앞 노드에서 계산한 값) 또는 검사 객체 / 검사 배열(모두 통과해야 함)
+//   { "op": "gte", "path": "rows", "value": 1 } 처럼 path 는 점(.)으로 구분, 생략하면 값 전체
+// 검사: equals | not_equals | gt | gte | lt | lte | exists | not_empty | contains | matches | length | schema
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum Check {
+    Equals {
+        #[serde(default)]
+        path: String,
+        value: Value,
+    },
+    NotEquals {
+        #[serde(default)]
+        path: String,
+        value: Value,
+    },
+    Gt {
+        #[serde(default)]
+        path: String,
+        value: f64,
+    },
+    Gte {
+        #[serde(default)]
+        path: String,
+        value: f64,
+    },
+    Lt {
+        #[serde(default)]
+        path: String,
+        value: f64,
+    },
+    Lte {
+        #[serde(default)]
+        path: String,
+        value: f64,
+    },
+    Exists {
+        #[serde(default)]
+        path: String,
+    },
+    NotEmpty {
+        #[serde(default)]
+        path: String,
+    },
+    Contains {
+        #[serde(default)]
+        path: String,
+        value: Value,
+    },
+    Matches {
+        #[serde(default)]
+        path: String,
+        pattern: String,
+    },
+    Length {
+        #[serde(default)]
+        path: String,
+        #[serde(default)]
+        min: Option<usize>,
+        #[serde(default)]
+        max: Option<usize>,
+    },
+    Schema {
+        #[serde(default)]
+        path: String,
+        schema: Value,
+    },
+}
+
+fn display_path(path: &str) -> &str {
+    if path.is_empty() {
+        "$"
+    } else {
+        path
+    }
+}
+
+// 숫자 또는 숫자 문자열 ("12.5")
+fn as_number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+// 배열 개수, 객체 키 개수, 문자열 글자 수
+fn length_of(value: &Value) -> Option<usize> {
+    match value {
+        Value::Array(items) => Some(items.len()),
+        Value::Object(map) => Some(map.len()),
+        Value::String(s) => Some(s.chars().count()),
+        _ => None,
+    }
+}
+
+fn is_empty(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::String(s) => s.trim().is_empty(),
+        other => length_of(other) == Some(0),
+    }
+}
+
+fn compare(path: &str, actual: Option<&Value>, expected: f64, op: &str) -> Option<String> {
+    let Some(number) = actual.and_then(as_number) else {
+        return Some(format!("{}: 숫자가 아님", display_path(path)));
+    };
+    let ok = match op {
+        ">" => number > expected,
+        ">=" => number >= expected,
+        "<" => number < expected,
+        _ => number <= expected,
+    };
+    (!ok).then(|| {
+        format!(
+            "{}: {} {} {} 아님",
+            display_path(path),
+            number,
+            op,
+            expected
+        )
+    })
+}
+
+// 실패한 이유 (통과하면 None)
+pub fn evaluate(check: &Check, data: &Value) -> Result<Option<String>, String> {
+    Ok(match check {
+        Check::Equals { path, value } => {
+            let actual = lookup(data, path);
+            (actual != Some(value)).then(|| {
+                format!(
+                    "{}: {} ≠ {}",
+                    display_path(path),
+                    actual
+                        .map(|v| v.to_string())
+                        .unwrap_or_else(|| "없음".into()),
+                    value
+                )
+            })
+        }
+        Check::NotEquals { path, value } => (lookup(data, path) == Some(value))
+            .then(|| format!("{}: {} 이면 안 됨", display_path(path), value)),
+        Check::Gt { path, value } => compare(path, lookup(data, path), *value, ">"),
+        Check::Gte { path, value } => compare(path, lookup(data, path), *value, ">="),
+        Check::Lt { path, value } => compare(path, lookup(data, path), *value, "<"),
+        Check::Lte { path, value } => compare(path, lookup(data, path), *value, "<="),
+        Check::Exists { path } => lookup(data, path)
+            .is_none()
+            .then(|| format!("{}: 없음", display_path(path))),
+        Check::NotEmpty { path } => match lookup(data, path) {
+            Some(value) if !is_empty(value) => None,
+            _ => Some(format!("{}: 비어 있음", display_path(path))),
+        },
+        Check::Contains { path, value } => {
+            let found = match lookup(data, path) {
+                Some(Value::Array(items)) => items.contains(value),
+                Some(Value::String(s)) => s.contains(&value_to_text(value)),
+                Some(Value::Object(map)) => map.contains_key(&value_to_text(value)),
+                _ => false,
+            };
+            (!found).then(|| format!("{}: {} 을(를) 포함하지 않음", display_path(path), value))
+        }
+        Check::Matches { path, pattern } => {
+            let regex = Regex::new(pattern).map_err(|e| format!("INVALID_REGEX: {}", e))?;
+            let text = lookup(data, path).map(value_to_text).unwrap_or_default();
+            (!regex.is_match(&text))
+                .then(|| format!("{}: /{}/ 와 맞지 않음", display_path(path), pattern))
+        }
+        Check::Length { path, min, max } => match lookup(data, path).and_then(length_of) {
+            None => Some(format!("{}: 길이를 잴 수 없음", display_path(path))),
+            Some(len) if min.is_some_and(|m| len < m) || max.is_some_and(|m| len > m) => {
+                Some(format!(
+                    "{}: 길이 {} (허용 {}~{})",
+                    display_path(path),
+                    len,
+                    min.map(|m| m.to_string()).unwrap_or_default(),
+                    max.map(|m| m.to_string()).unwrap_or_default()
+                ))
+            }
+            Some(_) => None,
+        },
+        Check::Schema { path, schema } => {
+            let errors = match lookup(data, path) {
+                Some(value) => json_schema::validate(value, schema),
+                None => vec![format!("{}: 없음", display_path(path))],
+            };
+            (!errors.is_empty()).then(|| errors.join(", "))
+        }
+    })
+}
+
+// condition 을 검사해 실패 이유 목록 반환 (비어 있으면 통과)
+pub fn check_condition(condition: &Value, data: &Value) -> Result<Vec<String>, String> {
+    let checks: Vec<Value> = match condition {
+        Value::Bool(true) => return Ok(Vec::new()),
+        Value::Bool(false) => return Ok(vec!["조건이 false".to_string()]),
+        Value::String(s) => match s.trim().to_lowercase().as_str() {
+            "true" | "1" | "yes" => return Ok(Vec::new()),
+            "false" | "0" | "no" | "" => return Ok(vec![format!("조건이 {:?}", s)]),
+            _ => return Err(format!("INVALID_CONDITION: {}", s)),
+        },
+        Value::Array(items) => items.clone(),
+        Value::Object(_) => vec![condition.clone()],
+        other => return Err(format!("INVALID_CONDITION: {}", other)),
+    };
+
+    let mut failures = Vec::new();
+    for check in checks {
+        let parsed: Check = serde_json::from_value(check.clone())
+            .map_err(|e| format!("INVALID_CHECK: {} ({})", check, e))?;
+        failures.extend(evaluate(&parsed, data)?);
+    }
+    Ok(failures)
+}
+
+#[tauri::command]
+pub fn assert_node(
+    condition: Value,
+    value: Option<Value>,
+    message: Option<String>,
+    severity: Option<String>,
+) -> Result<String, String> {
+    // 문자열로 들어온 JSON 은 풀어서 검사
+    let data = match value.unwrap_or(Value::Null) {
+        Value::String(text) => serde_json::from_str(text.trim()).unwrap_or(Value::String(text)),
+        other => other,
+    };
+    let severity = match severity.as_deref().map(str::trim) {
+        None | Some("") | Some("error") => "error",
+        Some("warn") | Some("warning") => "warn",
+        Some(other) => return Err(format!("INVALID_SEVERITY: {}", other)),
+    };
+
+    let failures = check_condition(&condition, &data)?;
+    if failures.is_empty() {
+        println!("✅ 검증 통과");
+        return Ok(json!({ "passed": true, "severity": severity, "failures": [] }).to_string());
+    }
+
+    let message = render(
+        message.as_deref().unwrap_or("검증 실패"),
+        &json!({ "value": data }),
+    );
+    let detail = failures.join("; ");
+    if severity == "error" {
+        println!("❌ 검증 실패: {} ({})", message, detail);
+        return Err(format!("ASSERTION_FAILED: {} ({})", message, detail));
+    }
+    println!("⚠️ 검증 경고: {} ({})", message, detail);
+    Ok(json!({
+        "passed": false,
+        "severity": severity,
+        "message": message,
+        "failures": failures
+    })
+    .to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::parse_output;
+    use serde_json::json;
+
+    #[test]
+    fn assert_node_checks_paths_against_data() {
+        let data = json!({
+            "status": "ok",
+            "rows": [{ "name": "a" }, { "name": "b" }],
+            "total": "12.5",
+            "title": ""
+        });
+        let conditions = json!([
+            { "op": "equals", "path": "status", "value": "ok" },
+            { "op": "gte", "path": "total", "value": 10 },
+            { "op": "length", "path": "rows", "min": 1, "max": 5 },
+            { "op": "exists", "path": "rows.1.name" },
+            { "op": "matches", "path": "rows.0.name", "pattern": "^[a-z]$" },
+            { "op": "contains", "path": "status", "value": "o" }
+        ]);
+        assert!(check_condition(&conditions, &data)
+            .unwrap()
+            .is_empty());
+
+        let failures = check_condition(
+            &json!([
+                { "op": "not_empty", "path": "title" },
+                { "op": "lt", "path": "total", "value": 5 },
+                { "op": "exists", "path": "missing" }
+            ]),
+            &data,
+        )
+        .unwrap();
+        assert_eq!(failures.len(), 3);
+        assert!(failures[0].starts_with("title"));
+    }
+
+    #[test]
+    fn assert_node_accepts_boolean_and_schema_conditions() {
+        assert!(check_condition(&json!(true), &json!(null))
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            check_condition(&json!("false"), &json!(null))
+                .unwrap()
+                .len(),
+            1
+        );
+        assert!(check_condition(&json!("maybe"), &json!(null)).is_err());
+        assert!(check_condition(&json!({ "op": "nope" }), &json!(null)).is_err());
+
+        let schema = json!({ "op": "schema", "schema": { "type": "object", "required": ["id"] } });
+        assert!(check_condition(&schema, &json!({ "id": 1 }))
+            .unwrap()
+            .is_empty());
+        assert!(!check_condition(&schema, &json!({}))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn assert_node_fails_or_warns_by_severity() {
+        let condition = json!({ "op": "gt", "path": "count", "value": 0 });
+        let err = assert_node(
+            condition.clone(),
+            Some(json!("{\"count\": 0}")),
+            Some("결과 {{value.count}}건".into()),
+            None,
+        )
+        .unwrap_err();
+        assert!(err.starts_with("ASSERTION_FAILED: 결과 0건"));
+
+        let warned = parse_output(
+            &assert_node(
+                condition.clone(),
+                Some(json!({ "count": 0 })),
+                None,
+                Some("warn".into()),
+            )
+            .unwrap(),
+        );
+        assert_eq!(warned["passed"], false);
+        assert_eq!(warned["failures"].as_array().unwrap().len(), 1);
+
+        let passed =
+            parse_output(&assert_node(condition, Some(json!({ "count": 3 })), None, None).unwrap());
+        assert_eq!(passed["passed"], true);
+    }
+}
@@ -0,0 +1,154 @@
+// src-tauri/src/nodes/atomic_store.rs
+use super::profiles;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+// 백엔드 상태 파일 공용 저장 헬퍼
+// - 쓰기: <파일>.tmp 에 기록 + fsync → 기존 파일을 <파일>.bak 으로 보관 → rename 으로 교체
+// - 체크섬: <파일>.sha256 에 SHA-256 기록, 읽을 때 불일치하면 손상으로 판단
+// - 손상/파싱 실패 시 .bak 으로 자동 복구
+
+// 프로젝트 루트의 store 폴더 (src-tauri 에서 실행되면 상위로 이동)
+pub fn base_store_dir() -> PathBuf {
+    let mut path = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+    if path.file_name() == Some(std::ffi::OsStr::new("src-tauri")) {
+        path.pop();
+    }
+    path.push("store");
+    path
+}
+
+// 현재 프로필의 데이터 폴더 (default 프로필은 store 그대로)
+pub fn store_dir() -> PathBuf {
+    profiles::profile_dir(&base_store_dir(), profiles::active_profile())
+}
+
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path
+        .file_name()
+        .map(|n| n.to_os_string())
+        .unwrap_or_default();
+    name.push(suffix);
+    path.with_file_name(name)
+}
+
+fn checksum_hex(data: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(data))
+}
+
+fn write_synced(path: &Path, data: &[u8]) -> std::io::Result<()> {
+    let mut file = File::create(path)?;
+    file.write_all(data)?;
+    file.sync_all()
+}
+
+pub fn write_atomic(path: &Path, data: &[u8]) -> Result<(), String> {
+    if let Some(parent_dir) = path.parent() {
+        fs::create_dir_all(parent_dir).map_err(|e| format!("폴더 생성 실패: {}", e))?;
+    }
+
+    let tmp_path = with_suffix(path, ".tmp");
+    let bak_path = with_suffix(path, ".bak");
+    let sum_path = with_suffix(path, ".sha256");
+
+    write_synced(&tmp_path, data).map_err(|e| format!("Failed to write temp file: {}", e))?;
+
+    // 현재 파일이 정상일 때만 백업으로 보관 (손상된 파일로 백업을 덮어쓰지 않음)
+    if path.exists() && read_checked(path).is_some() {
+        if let Err(e) = fs::copy(path, &bak_path) {
+            println!("⚠️ 백업 생성 실패: {}", e);
+        } else if let Ok(sum) = fs::read(&sum_path) {
+            let _ = fs::write(with_suffix(&bak_path, ".sha256"), sum);
+        }
+    }
+
+    fs::rename(&tmp_path, path).map_err(|e| {
+        let _ = fs::remove_file(&tmp_path);
+        format!("Failed to replace file: {}", e)
+    })?;
+
+    write_synced(&sum_path, checksum_hex(data).as_bytes())
+        .map_err(|e| format!("Failed to write checksum: {}", e))
+}
+
+// 체크섬이 맞으면 내용 반환 (체크섬 파일이 없는 예전 파일은 그대로 신뢰)
+fn read_checked(path: &Path) -> Option<Vec<u8>> {
+    let data = fs::read(path).ok()?;
+
+    match fs::read_to_string(with_suffix(path, ".sha256")) {
+        Ok(expected) if expected.trim() != checksum_hex(&data) => {
+            println!("⚠️ 체크섬 불일치 (손상된 파일): {}", path.display());
+            None
+        }
+        _ => Some(data),
+    }
+}
+
+// 원본이 손상되었으면 .bak 으로 대체
+pub fn read_verified(path: &Path) -> Option<Vec<u8>> {
+    if let Some(data) = read_checked(path) {
+        return Some(data);
+    }
+
+    let bak_path = with_suffix(path, ".bak");
+    let data = read_checked(&bak_path)?;
+    println!("🩹 백업에서 복구: {}", bak_path.display());
+    Some(data)
+}
+
+pub fn write_json_atomic<T: Serialize>(path: &Path, value: &T) -> Result<(), String> {
+    let content =
+        serde_json::to_string_pretty(value).map_err(|e| format!("Failed to serialize: {}", e))?;
+    write_atomic(path, content.as_bytes())
+}
+
+// JSON 파싱까지 실패하면 백업으로 재시도
+pub fn read_json_verified<T: DeserializeOwned>(path: &Path) -> Option<T> {
+    if let Some(value) = read_checked(path).and_then(|data| serde_json::from_slice(&data).ok()) {
+        return Some(value);
+    }
+
+    let bak_path = with_suffix(path, ".bak");
+    let value = read_checked(&bak_path).and_then(|data| serde_json::from_slice(&data).ok())?;
+    println!("🩹 백업에서 복구: {}", bak_path.display());
+    Some(value)
+}
+
+// 본 파일과 체크섬/백업 파일 함께 삭제
+pub fn remove_with_sidecars(path: &Path) -> Result<(), String> {
+    for sidecar in [".sha256", ".bak", ".bak.sha256", ".tmp"] {
+        let _ = fs::remove_file(with_suffix(path, sidecar));
+    }
+    if path.exists() {
+        fs::remove_file(path).map_err(|e| format!("Failed to remove file: {}", e))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::temp_dir;
+    use serde_json::json;
+    use std::fs;
+
+    #[test]
+    fn atomic_store_recovers_from_corrupted_file() {
+        let dir = temp_dir();
+        let path = dir.path().join("state.json");
+
+        write_json_atomic(&path, &json!({ "version": 1 })).unwrap();
+        write_json_atomic(&path, &json!({ "version": 2 })).unwrap();
+
+        // 쓰기 도중 크래시로 내용이 잘린 상황 재현
+        fs::write(&path, "{\"vers").unwrap();
+
+        let recovered: serde_json::Value = read_json_verified(&path).unwrap();
+        assert_eq!(recovered["version"], 1);
+        assert!(!dir.path().join("state.json.tmp").exists());
+    }
+}
@@ -0,0 +1,366 @@
+// src-tauri/src/nodes/barcode_node.rs
+use super::safe_path;
+use base64::{engine::general_purpose, Engine as _};
+use image::{ImageBuffer, Rgb, RgbImage};
+use serde_json::json;
+
+// 📊 바코드 생성 노드 (Code128 / EAN-13 → PNG 또는 SVG)
+// 재고 관리, 라벨 인쇄 워크플로우용 (QR 은 qr_code_node)
+// output_path 가 있으면 파일로 저장, 없으면 PNG 는 base64 / SVG 는 문자열로 반환
+
+// Code128 심볼 0~106 의 막대/공백 폭 (막대부터 시작, 106 은 정지 패턴)
+const CODE128_PATTERNS: [&str; 107] = [
+    "212222", "222122", "222221", "121223", "121322", "131222", "122213", "122312", "132212",
+    "221213", "221312", "231212", "112232", "122132", "122231", "113222", "123122", "123221",
+    "223211", "221132", "221231", "213212", "223112", "312131", "311222", "321122", "321221",
+    "312212", "322112", "322211", "212123", "212321", "232121", "111323", "131123", "131321",
+    "112313", "132113", "132311", "211313", "231113", "231311", "112133", "112331", "132131",
+    "113123", "113321", "133121", "313121", "211331", "231131", "213113", "213311", "213131",
+    "311123", "311321", "331121", "312113", "312311", "332111", "314111", "221411", "431111",
+    "111224", "111422", "121124", "121421", "141122", "141221", "112214", "112412", "122114",
+    "122411", "142112", "142211", "241211", "221114", "413111", "241112", "134111", "111242",
+    "121142", "121241", "114212", "124112", "124211", "411212", "421112", "421211", "212141",
+    "214121", "412121", "111143", "111341", "131141", "114113", "114311", "411113", "411311",
+    "113141", "114131", "311141", "411131", "211412", "211214", "211232", "2331112",
+];
+const CODE128_CODE_B: usize = 100;
+const CODE128_START_B: usize = 104;
+const CODE128_START_C: usize = 105;
+const CODE128_STOP: usize = 106;
+
+// EAN-13 왼쪽 절반 L 코드 (R 은 L 의 반전, G 는 R 을 뒤집은 것)
+const EAN_L_CODES: [&str; 10] = [
+    "0001101", "0011001", "0010011", "0111101", "0100011", "0110001", "0101111", "0111011",
+    "0110111", "0001011",
+];
+// 첫 자리에 따른 왼쪽 6자리의 L/G 배치
+const EAN_PARITY: [&str; 10] = [
+    "LLLLLL", "LLGLGG", "LLGGLG", "LLGGGL", "LGLLGG", "LGGLLG", "LGGGLL", "LGLGLG", "LGLGGL",
+    "LGGLGL",
+];
+
+#[derive(Debug)]
+pub struct Barcode {
+    pub modules: Vec<bool>, // true = 막대
+    pub text: String,       // 사람이 읽는 글자 (EAN-13 은 검증 숫자 포함)
+    pub quiet_left: usize,
+    pub quiet_right: usize,
+}
+
+fn push_widths(modules: &mut Vec<bool>, widths: &str) {
+    for (i, width) in widths.bytes().enumerate() {
+        let bar = i % 2 == 0;
+        modules.extend(std::iter::repeat_n(bar, (width - b'0') as usize));
+    }
+}
+
+// 숫자만 있으면 코드셋 C(두 자리씩), 아니면 코드셋 B(ASCII 32~126)
+pub fn code128_values(data: &str) -> Result<Vec<usize>, String> {
+    if data.is_empty() {
+        return Err("EMPTY_DATA".to_string());
+    }
+    if let Some(c) = data.chars().find(|c| !(' '..='~').contains(c)) {
+        return Err(format!("UNSUPPORTED_CHARACTER: {:?}", c));
+    }
+
+    let digits = data.bytes().all(|b| b.is_ascii_digit());
+    let mut values = Vec::new();
+    if digits && data.len() >= 2 {
+        values.push(CODE128_START_C);
+        let bytes = data.as_bytes();
+        let pairs = bytes.len() / 2;
+        for pair in bytes[..pairs * 2].chunks(2) {
+            values.push(((pair[0] - b'0') * 10 + (pair[1] - b'0')) as usize);
+        }
+        // 홀수 자리면 마지막 한 자리는 코드셋 B 로
+        if bytes.len() % 2 == 1 {
+            values.push(CODE128_CODE_B);
+            values.push((bytes[bytes.len() - 1] - b' ') as usize);
+        }
+    } else {
+        values.push(CODE128_START_B);
+        values.extend(data.bytes().map(|b| (b - b' ') as usize));
+    }
+
+    // 검사 심볼: 시작 값 + Σ(위치 × 값) mod 103
+    let checksum = values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| i.max(1) * value)
+        .sum::<usize>()
+        % 103;
+    values.push(checksum);
+    values.push(CODE128_STOP);
+    Ok(values)
+}
+
+pub fn encode_code128(data: &str) -> Result<Barcode, String> {
+    let mut modules = Vec::new();
+    for value in code128_values(data)? {
+        push_widths(&mut modules, CODE128_PATTERNS[value]);
+    }
+    Ok(Barcode {
+        modules,
+        text: data.to_string(),
+        quiet_left: 10,
+        quiet_right: 10,
+    })
+}
+
+// 12자리 → 검증 숫자 (왼쪽부터 가중치 1, 3 반복)
+pub fn ean13_check_digit(digits: &str) -> Result<u8, String> {
+    if digits.len() != 12 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err("INVALID_EAN13: 숫자 12자리 또는 13자리가 필요합니다".to_string());
+    }
+    let sum: u32 = digits
+        .bytes()
+        .enumerate()
+        .map(|(i, b)| (b - b'0') as u32 * if i % 2 == 0 { 1 } else { 3 })
+        .sum();
+    Ok(((10 - sum % 10) % 10) as u8)
+}
+
+pub fn encode_ean13(data: &str) -> Result<Barcode, String> {
+    let data: String = data
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .collect();
+    let check = ean13_check_digit(&data[..data.len().min(12)])?;
+    let full = match data.len() {
+        12 => format!("{}{}", data, check),
+        13 if data.ends_with(char::from(b'0' + check)) => data,
+        13 => return Err(format!("INVALID_CHECK_DIGIT: 올바른 검증 숫자는 {}", check)),
+        _ => return Err("INVALID_EAN13: 숫자 12자리 또는 13자리가 필요합니다".to_string()),
+    };
+
+    let digits: Vec<usize> = full.bytes().map(|b| (b - b'0') as usize).collect();
+    let l_code = |d: usize| EAN_L_CODES[d].to_string();
+    let r_code = |d: usize| {
+        EAN_L_CODES[d]
+            .chars()
+            .map(|c| if c == '0' { '1' } else { '0' })
+            .collect::<String>()
+    };
+    let g_code = |d: usize| r_code(d).chars().rev().collect::<String>();
+
+    let mut bits = String::from("101");
+    for (i, parity) in EAN_PARITY[digits[0]].chars().enumerate() {
+        let digit = digits[i + 1];
+        bits.push_str(&if parity == 'L' {
+            l_code(digit)
+        } else {
+            g_code(digit)
+        });
+    }
+    bits.push_str("01010");
+    for &digit in &digits[7..] {
+        bits.push_str(&r_code(digit));
+    }
+    bits.push_str("101");
+
+    Ok(Barcode {
+        modules: bits.chars().map(|c| c == '1').collect(),
+        text: full,
+        quiet_left: 11,
+        quiet_right: 7,
+    })
+}
+
+pub fn render_svg(barcode: &Barcode, module_width: u32, height: u32, show_text: bool) -> String {
+    let total = (barcode.quiet_left + barcode.modules.len() + barcode.quiet_right) as u32;
+    let width = total * module_width;
+    let text_height = if show_text { 20 } else { 0 };
+
+    let mut rects = String::new();
+    let mut x = barcode.quiet_left;
+    // 연속된 막대는 하나의 사각형으로
+    for run in barcode.modules.chunk_by(|a, b| a == b) {
+        if run[0] {
+            rects.push_str(&format!(
+                "<rect x=\"{}\" y=\"0\" width=\"{}\" height=\"{}\"/>",
+                x as u32 * module_width,
+                run.len() as u32 * module_width,
+                height
+            ));
+        }
+        x += run.len();
+    }
+
+    let text = if show_text {
+        let escaped = barcode
+            .text
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;");
+        format!(
+            "<text x=\"{}\" y=\"{}\" font-family=\"monospace\" font-size=\"16\" text-anchor=\"middle\">{}</text>",
+            width / 2,
+            height + 16,
+            escaped
+        )
+    } else {
+        String::new()
+    };
+
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\">\
+<rect width=\"100%\" height=\"100%\" fill=\"#fff\"/><g fill=\"#000\">{rects}</g>{text}</svg>",
+        w = width,
+        h = height + text_height,
+        rects = rects,
+        text = text
+    )
+}
+
+fn render_png(barcode: &Barcode, module_width: u32, height: u32) -> Result<Vec<u8>, String> {
+    let total = (barcode.quiet_left + barcode.modules.len() + barcode.quiet_right) as u32;
+    let width = total * module_width;
+
+    let img: RgbImage = ImageBuffer::from_fn(width, height, |x, _| {
+        let module = (x / module_width) as usize;
+        let bar = module >= barcode.quiet_left
+            && barcode
+                .modules
+                .get(module - barcode.quiet_left)
+                .copied()
+                .unwrap_or(false);
+        if bar {
+            Rgb([0, 0, 0])
+        } else {
+            Rgb([255, 255, 255])
+        }
+    });
+
+    let mut png_data = Vec::new();
+    {
+        use image::ImageEncoder;
+        let encoder = image::codecs::png::PngEncoder::new(&mut png_data);
+        encoder
+            .write_image(&img, width, height, image::ColorType::Rgb8)
+            .map_err(|e| format!("PNG encoding failed: {}", e))?;
+    }
+    Ok(png_data)
+}
+
+#[tauri::command]
+pub async fn barcode_node(
+    data: String,
+    symbology: Option<String>,
+    format: Option<String>,
+    module_width: Option<u32>,
+    height: Option<u32>,
+    show_text: Option<bool>,
+    output_path: Option<String>,
+) -> Result<String, String> {
+    let symbology = symbology
+        .as_deref()
+        .map(|s| s.trim().to_lowercase().replace(['-', '_'], ""))
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| "code128".to_string());
+    let format = format
+        .as_deref()
+        .map(|f| f.trim().to_lowercase())
+        .filter(|f| !f.is_empty())
+        .unwrap_or_else(|| "png".to_string());
+    let module_width = module_width.unwrap_or(2).clamp(1, 20);
+    let height = height.unwrap_or(80).clamp(10, 2000);
+
+    println!("📊 Barcode Node: {} ({}, {})", data, symbology, format);
+
+    let barcode = match symbology.as_str() {
+        "code128" => encode_code128(&data)?,
+        "ean13" => encode_ean13(&data)?,
+        other => return Err(format!("UNSUPPORTED_SYMBOLOGY: {}", other)),
+    };
+
+    let bytes = match format.as_str() {
+        "svg" => render_svg(&barcode, module_width, height, show_text.unwrap_or(true)).into_bytes(),
+        "png" => render_png(&barcode, module_width, height)?,
+        other => return Err(format!("UNSUPPORTED_FORMAT: {}", other)),
+    };
+
+    let mut result = json!({
+        "symbology": symbology,
+        "format": format,
+        "text": barcode.text,
+    });
+
+    match output_path
+        .as_deref()
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+    {
+        Some(path) => {
+            let path = safe_path::prepare_new(path)?;
+            if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                std::fs::create_dir_all(parent)
+                    .map_err(|_| "DIRECTORY_CREATE_ERROR".to_string())?;
+            }
+            std::fs::write(&path, &bytes).map_err(|e| format!("바코드 저장 실패: {}", e))?;
+            result["file_path"] = json!(path.to_string_lossy());
+        }
+        None if format == "svg" => {
+            result["svg"] = json!(String::from_utf8_lossy(&bytes));
+        }
+        None => {
+            result["image_base64"] = json!(general_purpose::STANDARD.encode(&bytes));
+        }
+    }
+
+    println!("✅ 바코드 생성 완료: {}", barcode.text);
+    Ok(result.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn barcode_node_encodes_code128() {
+        assert_eq!(
+            code128_values("Wikipedia").unwrap(),
+            vec![104, 55, 73, 75, 73, 80, 69, 68, 73, 65, 88, 106]
+        );
+        // 숫자만 있으면 코드셋 C, 홀수 자리의 마지막은 코드셋 B
+        assert_eq!(
+            code128_values("12345").unwrap(),
+            vec![105, 12, 34, 100, 21, 54, 106]
+        );
+        assert!(code128_values("한글")
+            .unwrap_err()
+            .starts_with("UNSUPPORTED_CHARACTER"));
+
+        // 11모듈 × (시작 + 9글자 + 검사) + 정지 13모듈
+        assert_eq!(
+            encode_code128("Wikipedia")
+                .unwrap()
+                .modules
+                .len(),
+            134
+        );
+    }
+
+    #[test]
+    fn barcode_node_encodes_ean13() {
+        assert_eq!(ean13_check_digit("400638133393").unwrap(), 1);
+
+        let barcode = encode_ean13("590123412345").unwrap();
+        assert_eq!(barcode.text, "5901234123457");
+        let bits: String = barcode
+            .modules
+            .iter()
+            .map(|&m| if m { '1' } else { '0' })
+            .collect();
+        assert_eq!(
+            bits,
+            "10100010110100111011001100100110111101001110101010110011011011001000010101110010011101000100101"
+        );
+        assert!(encode_ean13("5901234123450")
+            .unwrap_err()
+            .starts_with("INVALID_CHECK_DIGIT"));
+
+        let svg = render_svg(&barcode, 2, 60, true);
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains(">5901234123457</text>"));
+    }
+}
@@ -0,0 +1,225 @@
+// src-tauri/src/nodes/cache_node.rs
+use super::atomic_store;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde_json::{json, Value};
+use std::sync::Mutex;
+
+// 🗄️ TTL 이 있는 키-값 캐시 노드
+// API 응답, 스크래핑 결과 등을 실행 사이에 재사용하기 위한 캐시 (store/cache.db)
+// 변수 저장소(variables.json)와 달리 만료 시간이 있고 네임스페이스별로 비울 수 있음
+// action: get | set | delete | clear
+
+pub const DEFAULT_NAMESPACE: &str = "default";
+
+static CACHE_DB: std::sync::OnceLock<Option<Mutex<Connection>>> = std::sync::OnceLock::new();
+
+pub fn init_schema(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS cache_entries (
+            namespace TEXT NOT NULL,
+            key TEXT NOT NULL,
+            value TEXT NOT NULL,
+            expires_at INTEGER,
+            updated_at INTEGER NOT NULL,
+            PRIMARY KEY (namespace, key)
+        );
+        CREATE INDEX IF NOT EXISTS idx_cache_expires ON cache_entries(expires_at);",
+    )
+}
+
+fn get_cache_db() -> Option<&'static Mutex<Connection>> {
+    CACHE_DB
+        .get_or_init(|| {
+            let dir = atomic_store::store_dir();
+            std::fs::create_dir_all(&dir).ok()?;
+            let conn = Connection::open(dir.join("cache.db"))
+                .map_err(|e| println!("⚠️ 캐시 DB 열기 실패: {}", e))
+                .ok()?;
+            let _ = conn.pragma_update(None, "journal_mode", "WAL");
+            init_schema(&conn).ok()?;
+            let _ = purge_expired(&conn, chrono::Utc::now().timestamp());
+            Some(Mutex::new(conn))
+        })
+        .as_ref()
+}
+
+// 만료된 항목은 없는 것으로 취급 (지연 삭제)
+pub fn get_entry(
+    conn: &Connection,
+    namespace: &str,
+    key: &str,
+    now: i64,
+) -> rusqlite::Result<Option<(Value, Option<i64>)>> {
+    let row: Option<(String, Option<i64>)> = conn
+        .query_row(
+            "SELECT value, expires_at FROM cache_entries
+             WHERE namespace = ?1 AND key = ?2 AND (expires_at IS NULL OR expires_at > ?3)",
+            params![namespace, key, now],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .optional()?;
+    Ok(row.map(|(text, expires_at)| {
+        (
+            serde_json::from_str(&text).unwrap_or(Value::String(text)),
+            expires_at,
+        )
+    }))
+}
+
+pub fn set_entry(
+    conn: &Connection,
+    namespace: &str,
+    key: &str,
+    value: &Value,
+    ttl_secs: Option<u64>,
+    now: i64,
+) -> rusqlite::Result<Option<i64>> {
+    let expires_at = ttl_secs.map(|ttl| now + ttl as i64);
+    conn.execute(
+        "INSERT INTO cache_entries (namespace, key, value, expires_at, updated_at)
+         VALUES (?1, ?2, ?3, ?4, ?5)
+         ON CONFLICT(namespace, key) DO UPDATE SET
+            value = excluded.value, expires_at = excluded.expires_at, updated_at = excluded.updated_at",
+        params![namespace, key, value.to_string(), expires_at, now],
+    )?;
+    Ok(expires_at)
+}
+
+pub fn delete_entry(conn: &Connection, namespace: &str, key: &str) -> rusqlite::Result<bool> {
+    Ok(conn.execute(
+        "DELETE FROM cache_entries WHERE namespace = ?1 AND key = ?2",
+        params![namespace, key],
+    )? > 0)
+}
+
+pub fn clear_namespace(conn: &Connection, namespace: &str) -> rusqlite::Result<usize> {
+    conn.execute(
+        "DELETE FROM cache_entries WHERE namespace = ?1",
+        params![namespace],
+    )
+}
+
+pub fn purge_expired(conn: &Connection, now: i64) -> rusqlite::Result<usize> {
+    conn.execute(
+        "DELETE FROM cache_entries WHERE expires_at IS NOT NULL AND expires_at <= ?1",
+        params![now],
+    )
+}
+
+#[tauri::command]
+pub fn cache_node(
+    action: String,
+    key: Option<String>,
+    value: Option<Value>,
+    ttl_secs: Option<u64>,
+    namespace: Option<String>,
+) -> Result<String, String> {
+    let db = get_cache_db().ok_or("캐시 DB 를 열 수 없습니다")?;
+    let conn = db.lock().map_err(|e| e.to_string())?;
+    let namespace = namespace
+        .map(|ns| ns.trim().to_string())
+        .filter(|ns| !ns.is_empty())
+        .unwrap_or_else(|| DEFAULT_NAMESPACE.to_string());
+    let now = chrono::Utc::now().timestamp();
+    let db_err = |e: rusqlite::Error| format!("캐시 처리 실패: {}", e);
+    let require_key = || {
+        key.as_deref()
+            .map(str::trim)
+            .filter(|k| !k.is_empty())
+            .ok_or_else(|| "CACHE_KEY_REQUIRED".to_string())
+    };
+
+    match action.trim().to_lowercase().as_str() {
+        "get" => {
+            let key = require_key()?;
+            let entry = get_entry(&conn, &namespace, key, now).map_err(db_err)?;
+            println!(
+                "🗄️ 캐시 {}: {}/{}",
+                if entry.is_some() { "적중" } else { "없음" },
+                namespace,
+                key
+            );
+            Ok(match entry {
+                Some((value, expires_at)) => json!({
+                    "hit": true, "key": key, "value": value, "expires_at": expires_at
+                }),
+                None => json!({ "hit": false, "key": key, "value": null }),
+            }
+            .to_string())
+        }
+        "set" => {
+            let key = require_key()?;
+            let value = value.ok_or("CACHE_VALUE_REQUIRED")?;
+            let expires_at =
+                set_entry(&conn, &namespace, key, &value, ttl_secs, now).map_err(db_err)?;
+            println!("🗄️ 캐시 저장: {}/{} (TTL {:?}초)", namespace, key, ttl_secs);
+            Ok(json!({ "key": key, "stored": true, "expires_at": expires_at }).to_string())
+        }
+        "delete" => {
+            let key = require_key()?;
+            let deleted = delete_entry(&conn, &namespace, key).map_err(db_err)?;
+            Ok(json!({ "key": key, "deleted": deleted }).to_string())
+        }
+        "clear" => {
+            let cleared = clear_namespace(&conn, &namespace).map_err(db_err)?;
+            println!("🗑️ 캐시 비움: {} ({}개)", namespace, cleared);
+            Ok(json!({ "namespace": namespace, "cleared": cleared }).to_string())
+        }
+        other => Err(format!("UNKNOWN_CACHE_ACTION: {}", other)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn cache_node_expires_entries_by_ttl() {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        init_schema(&conn).unwrap();
+
+        let value = json!({ "items": [1, 2, 3] });
+        set_entry(&conn, "api", "list", &value, Some(60), 1_000).unwrap();
+        set_entry(&conn, "api", "forever", &json!("x"), None, 1_000).unwrap();
+
+        let (cached, expires_at) = get_entry(&conn, "api", "list", 1_030)
+            .unwrap()
+            .unwrap();
+        assert_eq!(cached, value);
+        assert_eq!(expires_at, Some(1_060));
+        assert!(get_entry(&conn, "api", "list", 1_060)
+            .unwrap()
+            .is_none());
+        assert!(get_entry(&conn, "other", "forever", 1_000)
+            .unwrap()
+            .is_none());
+
+        assert_eq!(purge_expired(&conn, 2_000).unwrap(), 1);
+        assert!(get_entry(&conn, "api", "forever", 9_999)
+            .unwrap()
+            .is_some());
+    }
+
+    #[test]
+    fn cache_node_overwrites_and_deletes() {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        init_schema(&conn).unwrap();
+
+        set_entry(&conn, "ns", "k", &json!(1), None, 0).unwrap();
+        set_entry(&conn, "ns", "k", &json!(2), None, 0).unwrap();
+        assert_eq!(
+            get_entry(&conn, "ns", "k", 0)
+                .unwrap()
+                .unwrap()
+                .0,
+            json!(2)
+        );
+        assert!(delete_entry(&conn, "ns", "k").unwrap());
+        assert!(!delete_entry(&conn, "ns", "k").unwrap());
+
+        set_entry(&conn, "ns", "a", &json!(1), None, 0).unwrap();
+        set_entry(&conn, "ns", "b", &json!(1), None, 0).unwrap();
+        assert_eq!(clear_namespace(&conn, "ns").unwrap(), 2);
+    }
+}
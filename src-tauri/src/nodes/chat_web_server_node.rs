@@ -1,4 +1,3 @@
-use futures_util::{sink::SinkExt, stream::StreamExt};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -8,6 +7,7 @@ use tauri::{AppHandle, Emitter};
 use tauri_plugin_shell::{process::CommandEvent, ShellExt};
 use tokio::sync::{broadcast, RwLock};
 use warp::Filter;
+use super::{access_log, control_page, event_chunks, geofence_trigger, origin_policy, message_router, path_guard, run_cleanup, run_history, run_share, static_cache, ws_limits};
 
 // 💬 채팅 웹서버 노드 구조체들
 
@@ -26,7 +26,6 @@ pub struct ChatWebServerResult {
 #[derive(Debug, Deserialize)]
 struct ChatMessage {
     message: String,
-    #[allow(dead_code)]
     sender: Option<String>,
 }
 
@@ -56,6 +55,12 @@ struct ChatServerHandle {
     // 🆕 터널 관련 정보
     has_tunnel: bool,
     tunnel_url: Option<String>,
+    // 👀 관전 모드: 실행 상태만 보여주고 입력은 받지 않음
+    spectator: bool,
+    // 📶 송신 메시지 최대 크기 (ws_limits)
+    max_message_bytes: usize,
+    // 🌐 같은 출처로 믿을 터널 호스트를 갱신하기 위해 보관
+    origin_policy: Arc<origin_policy::OriginPolicy>,
 }
 
 // 전역 레지스트리들
@@ -133,10 +138,14 @@ async fn start_cloudflare_tunnel(
     );
 
     // 🔧 Tauri v2: cloudflared 실행
-    let sidecar_command = app
-        .shell()
-        .sidecar("cloudflared")
-        .map_err(|e| format!("Failed to create cloudflared command: {}", e))?;
+    // 🧰 도구 관리자로 설치한 cloudflared 가 있으면 우선 사용
+    let sidecar_command = match super::tools::managed_path("cloudflared") {
+        Some(path) => app.shell().command(path),
+        None => app
+            .shell()
+            .sidecar("cloudflared")
+            .map_err(|e| format!("Failed to create cloudflared command: {}", e))?,
+    };
 
     let (mut rx, child) = sidecar_command
         .args(["tunnel", "--url", &format!("http://localhost:{}", port)])
@@ -237,21 +246,20 @@ async fn stop_cloudflare_tunnel(node_id: String) -> Result<(), String> {
 
 // 📱 모던한 채팅 HTML 생성 함수 (example.rs 스타일 적용)
 fn create_mobile_chat_html() -> String {
-    format!(
-        r#"<!DOCTYPE html>
+    r#"<!DOCTYPE html>
 <html lang="ko">
 <head>
     <meta charset="UTF-8">
     <meta name="viewport" content="width=device-width, initial-scale=1.0, user-scalable=no">
     <title>Chat Server</title>
     <style>
-        * {{
+        * {
             margin: 0;
             padding: 0;
             box-sizing: border-box;
-        }}
+        }
         
-        body {{
+        body {
             font-family: "Inter", -apple-system, BlinkMacSystemFont, sans-serif;
             background: #0f0f0f;
             height: 100vh;
@@ -259,9 +267,9 @@ fn create_mobile_chat_html() -> String {
             flex-direction: column;
             overflow: hidden;
             color: #ffffff;
-        }}
+        }
         
-        .header {{
+        .header {
             background: #1a1a1a;
             border-bottom: 1px solid #2a2a2a;
             padding: 16px 20px;
@@ -269,15 +277,15 @@ fn create_mobile_chat_html() -> String {
             align-items: center;
             justify-content: space-between;
             backdrop-filter: blur(10px);
-        }}
+        }
         
-        .header h1 {{
+        .header h1 {
             color: #ffffff;
             font-size: 18px;
             font-weight: 600;
-        }}
+        }
         
-        .status {{
+        .status {
             color: #10b981;
             font-size: 13px;
             display: flex;
@@ -286,15 +294,15 @@ fn create_mobile_chat_html() -> String {
             background: rgba(16, 185, 129, 0.1);
             padding: 4px 8px;
             border-radius: 12px;
-        }}
+        }
         
-        .status::before {{
+        .status::before {
             content: '●';
             color: #10b981;
             font-size: 8px;
-        }}
+        }
         
-        .chat-container {{
+        .chat-container {
             flex: 1;
             overflow-y: auto;
             padding: 20px;
@@ -304,9 +312,9 @@ fn create_mobile_chat_html() -> String {
             gap: 12px;
             transition: padding-bottom 0.3s ease;
             scroll-behavior: smooth;
-        }}
+        }
         
-        .message {{
+        .message {
             max-width: 80%;
             padding: 12px 16px;
             border-radius: 16px;
@@ -314,37 +322,37 @@ fn create_mobile_chat_html() -> String {
             line-height: 1.4;
             word-wrap: break-word;
             animation: messageSlide 0.2s ease-out;
-        }}
+        }
         
-        @keyframes messageSlide {{
-            from {{
+        @keyframes messageSlide {
+            from {
                 opacity: 0;
                 transform: translateY(10px);
-            }}
-            to {{
+            }
+            to {
                 opacity: 1;
                 transform: translateY(0);
-            }}
-        }}
+            }
+        }
         
-        .message.user {{
+        .message.user {
             background: #2563eb;
             color: white;
             align-self: flex-end;
             border-bottom-right-radius: 6px;
             box-shadow: 0 2px 6px rgba(37, 99, 235, 0.25);
-        }}
+        }
         
-        .message.computer {{
+        .message.computer {
             background: #1a1a1a;
             color: #e8e8e8;
             align-self: flex-start;
             border-bottom-left-radius: 6px;
             border: 1px solid #333;
             box-shadow: 0 1px 3px rgba(0,0,0,0.2);
-        }}
+        }
         
-        .message.assistant {{
+        .message.assistant {
             background: #f8f9fa;
             color: #1a1a1a;
             align-self: flex-start;
@@ -357,18 +365,18 @@ fn create_mobile_chat_html() -> String {
             font-size: 14px;
             line-height: 1.4;
             word-wrap: break-word;
-        }}
+        }
         
-        .message.system {{
+        .message.system {
             background: rgba(99, 102, 241, 0.1);
             color: #6366f1;
             align-self: center;
             font-size: 13px;
             border: 1px solid rgba(99, 102, 241, 0.2);
             border-radius: 12px;
-        }}
+        }
         
-        .bottom-container {{
+        .bottom-container {
             position: fixed;
             bottom: 0;
             left: 0;
@@ -378,21 +386,21 @@ fn create_mobile_chat_html() -> String {
             backdrop-filter: blur(10px);
             box-shadow: 0 -4px 20px rgba(0, 0, 0, 0.3);
             z-index: 1000;
-        }}
+        }
         
-        .input-container {{
+        .input-container {
             padding: 12px 16px;
             display: flex;
             align-items: flex-end;
             gap: 12px;
-        }}
+        }
         
-        .input-wrapper {{
+        .input-wrapper {
             flex: 1;
             position: relative;
-        }}
+        }
         
-        .message-input {{
+        .message-input {
             width: 100%;
             padding: 14px 20px;
             border: 1px solid #404040;
@@ -405,19 +413,19 @@ fn create_mobile_chat_html() -> String {
             font-family: inherit;
             resize: none;
             min-height: 48px;
-        }}
+        }
         
-        .message-input:focus {{
+        .message-input:focus {
             border-color: #6366f1;
             background: #2a2a2a;
             box-shadow: 0 0 0 3px rgba(99, 102, 241, 0.1);
-        }}
+        }
         
-        .message-input::placeholder {{
+        .message-input::placeholder {
             color: #7a7a7a;
-        }}
+        }
         
-        .send-button {{
+        .send-button {
             background: linear-gradient(135deg, #6366f1 0%, #8b5cf6 100%);
             color: white;
             border: none;
@@ -431,25 +439,25 @@ fn create_mobile_chat_html() -> String {
             transition: all 0.2s ease;
             font-size: 18px;
             box-shadow: 0 2px 8px rgba(99, 102, 241, 0.3);
-        }}
+        }
         
-        .send-button:hover {{
+        .send-button:hover {
             transform: scale(1.05);
             box-shadow: 0 4px 12px rgba(99, 102, 241, 0.4);
-        }}
+        }
         
-        .send-button:disabled {{
+        .send-button:disabled {
             background: #404040;
             cursor: not-allowed;
             transform: none;
             box-shadow: none;
-        }}
+        }
         
-        .send-button:active {{
+        .send-button:active {
             transform: scale(0.98);
-        }}
+        }
         
-        .websocket-status {{
+        .websocket-status {
             position: fixed;
             top: 20px;
             right: 20px;
@@ -458,37 +466,37 @@ fn create_mobile_chat_html() -> String {
             font-size: 12px;
             font-weight: 500;
             z-index: 1001;
-        }}
+        }
         
-        .websocket-connected {{
+        .websocket-connected {
             background: rgba(16, 185, 129, 0.15);
             color: #10b981;
             border: 1px solid rgba(16, 185, 129, 0.3);
-        }}
+        }
         
-        .websocket-disconnected {{
+        .websocket-disconnected {
             background: rgba(239, 68, 68, 0.15);
             color: #ef4444;
             border: 1px solid rgba(239, 68, 68, 0.3);
-        }}
+        }
         
-        @media (max-width: 480px) {{
-            .header {{
+        @media (max-width: 480px) {
+            .header {
                 padding: 12px 16px;
-            }}
+            }
             
-            .chat-container {{
+            .chat-container {
                 padding: 16px;
-            }}
+            }
             
-            .input-container {{
+            .input-container {
                 padding: 8px 12px;
-            }}
+            }
             
-            .message-input {{
+            .message-input {
                 font-size: 16px;
-            }}
-        }}
+            }
+        }
     </style>
 </head>
 <body>
@@ -531,79 +539,79 @@ fn create_mobile_chat_html() -> String {
         let reconnectAttempts = 0;
         const maxReconnectAttempts = 5;
         
-        function addMessage(content, type = 'user') {{
+        function addMessage(content, type = 'user') {
             const messageDiv = document.createElement('div');
-            messageDiv.className = `message ${{type}}`;
+            messageDiv.className = `message ${type}`;
             messageDiv.textContent = content;
             chatContainer.appendChild(messageDiv);
             chatContainer.scrollTop = chatContainer.scrollHeight;
-        }}
+        }
         
-        function connectWebSocket() {{
+        function connectWebSocket() {
             const wsProtocol = window.location.protocol === 'https:' ? 'wss:' : 'ws:';
-            const wsUrl = `${{wsProtocol}}//${{window.location.host}}/ws`;
+            const wsUrl = `${wsProtocol}//${window.location.host}/ws`;
             
             console.log('🔗 WebSocket 연결 시도:', wsUrl);
             
-            try {{
+            try {
                 websocket = new WebSocket(wsUrl);
                 
-                websocket.onopen = function(event) {{
+                websocket.onopen = function(event) {
                     console.log('✅ WebSocket 연결됨');
                     wsStatus.textContent = '연결됨';
                     wsStatus.className = 'websocket-status websocket-connected';
                     reconnectAttempts = 0;
-                }};
+                };
                 
-                websocket.onmessage = function(event) {{
+                websocket.onmessage = function(event) {
                     console.log('💻 컴퓨터에서 메시지 받음:', event.data);
                     
-                    try {{
+                    try {
                         // JSON 파싱 시도
                         const messageData = JSON.parse(event.data);
-                        if (messageData.message && messageData.type) {{
+                        if (messageData.message && messageData.type) {
                             addMessage(messageData.message, messageData.type);
-                        }} else {{
+                        } else {
                             // JSON이지만 올바른 형태가 아닌 경우 기본값으로 처리
                             addMessage(event.data, 'user');
-                        }}
-                    }} catch (e) {{
+                        }
+                    } catch (e) {
                         // JSON이 아닌 일반 텍스트인 경우 기본값으로 처리
                         addMessage(event.data, 'user');
-                    }}
-                }};
+                    }
+                };
                 
-                websocket.onclose = function(event) {{
+                websocket.onclose = function(event) {
                     console.log('❌ WebSocket 연결 해제됨 (코드:', event.code, ')');
                     wsStatus.textContent = '연결 해제됨';
                     wsStatus.className = 'websocket-status websocket-disconnected';
                     
-                    if (reconnectAttempts < maxReconnectAttempts) {{
+                    if (reconnectAttempts < maxReconnectAttempts) {
                         reconnectAttempts++;
                         const delay = Math.min(1000 * Math.pow(2, reconnectAttempts - 1), 30000);
-                        console.log(`🔄 ${{delay/1000}}초 후 재연결 시도 (${{reconnectAttempts}}/${{maxReconnectAttempts}})`);
-                        wsStatus.textContent = `재연결 중... (${{reconnectAttempts}}/${{maxReconnectAttempts}})`;
+                        console.log(`🔄 ${delay/1000}초 후 재연결 시도 (${reconnectAttempts}/${maxReconnectAttempts})`);
+                        wsStatus.textContent = `재연결 중... (${reconnectAttempts}/${maxReconnectAttempts})`;
                         setTimeout(connectWebSocket, delay);
-                    }} else {{
+                    } else {
                         console.log('❌ 최대 재연결 시도 횟수 초과');
                         wsStatus.textContent = '연결 실패 (새로고침 필요)';
-                    }}
-                }};
+                    }
+                };
                 
-                websocket.onerror = function(error) {{
+                websocket.onerror = function(error) {
                     console.error('❌ WebSocket 에러:', error);
                     wsStatus.textContent = '연결 오류';
                     wsStatus.className = 'websocket-status websocket-disconnected';
-                }};
+                };
                 
-            }} catch (error) {{
+            } catch (error) {
                 console.error('❌ WebSocket 생성 실패:', error);
                 wsStatus.textContent = '연결 실패';
                 wsStatus.className = 'websocket-status websocket-disconnected';
-            }}
-        }}
+            }
+        }
         
-        async function sendMessage() {{
+        async function sendMessage() {
             const message = messageInput.value.trim();
             if (!message) return;
             
@@ -615,60 +623,60 @@ fn create_mobile_chat_html() -> String {
             addMessage(message, 'user');
             messageInput.value = '';
             
-            try {{
-                const response = await fetch('/send-message', {{
+            try {
+                const response = await fetch('/send-message', {
                     method: 'POST',
-                    headers: {{
+                    headers: {
                         'Content-Type': 'application/json',
-                    }},
-                    body: JSON.stringify({{
+                    },
+                    body: JSON.stringify({
                         message: message,
                         sender: 'user'
-                    }})
-                }});
+                    })
+                });
                 
-                if (!response.ok) {{
+                if (!response.ok) {
                     throw new Error('서버 응답 오류: ' + response.status);
-                }}
+                }
                 
                 console.log('✅ 메시지 전송 성공:', message);
-            }} catch (error) {{
+            } catch (error) {
                 console.error('❌ 메시지 전송 실패:', error);
                 addMessage('메시지 전송에 실패했습니다: ' + error.message, 'system');
-            }}
+            }
             
             sendButton.disabled = false;
             sendButton.innerHTML = originalText;
-        }}
+        }
         
         sendButton.addEventListener('click', sendMessage);
         
-        messageInput.addEventListener('keypress', function(e) {{
-            if (e.key === 'Enter' && !e.shiftKey) {{
+        messageInput.addEventListener('keypress', function(e) {
+            if (e.key === 'Enter' && !e.shiftKey) {
                 e.preventDefault();
                 sendMessage();
-            }}
-        }});
+            }
+        });
         
         // 모바일 키보드 대응
         let initialViewportHeight = window.visualViewport ? window.visualViewport.height : window.innerHeight;
         
-        function handleViewportChange() {{
-            if (window.visualViewport) {{
+        function handleViewportChange() {
+            if (window.visualViewport) {
                 const currentHeight = window.visualViewport.height;
                 const heightDifference = initialViewportHeight - currentHeight;
                 
-                if (heightDifference > 150) {{
+                if (heightDifference > 150) {
                     chatContainer.classList.add('keyboard-active');
-                }} else {{
+                } else {
                     chatContainer.classList.remove('keyboard-active');
-                }}
-            }}
-        }}
+                }
+            }
+        }
         
-        if (window.visualViewport) {{
+        if (window.visualViewport) {
             window.visualViewport.addEventListener('resize', handleViewportChange);
-        }}
+        }
         
         connectWebSocket();
         messageInput.focus();
@@ -677,16 +685,180 @@ fn create_mobile_chat_html() -> String {
     </script>
 </body>
 </html>"#
-    )
+        .to_string()
 }
 
-// 💬 채팅 서버 시작 함수 (🔧 터널 기능 통합)
-async fn start_chat_server(
+// 👀 관전 모드 대시보드 HTML - 입력 요소 없이 실행 상태만 표시 (벽걸이 태블릿용)
+fn create_spectator_html() -> String {
+    r#"<!DOCTYPE html>
+<html lang="ko">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+    <title>Automation Status</title>
+    <style>
+        * { margin: 0; padding: 0; box-sizing: border-box; }
+        body {
+            font-family: "Inter", -apple-system, BlinkMacSystemFont, sans-serif;
+            background: #0f0f0f;
+            color: #e5e5e5;
+            padding: 24px;
+        }
+        header { display: flex; justify-content: space-between; align-items: center; margin-bottom: 20px; }
+        h1 { font-size: 22px; font-weight: 600; }
+        #connection { font-size: 13px; color: #888; }
+        #connection.online { color: #4ade80; }
+        .run {
+            background: #1a1a1a;
+            border-left: 4px solid #555;
+            border-radius: 8px;
+            padding: 14px 16px;
+            margin-bottom: 10px;
+        }
+        .run.running { border-color: #60a5fa; }
+        .run.success { border-color: #4ade80; }
+        .run.error, .run.cancelled { border-color: #f87171; }
+        .run .title { display: flex; justify-content: space-between; font-size: 17px; }
+        .run .meta { font-size: 13px; color: #888; margin-top: 4px; }
+        .run .node { font-size: 13px; color: #bbb; margin-top: 6px; }
+        .empty { color: #666; text-align: center; margin-top: 40px; }
+    </style>
+</head>
+<body>
+    <header>
+        <h1>🤖 자동화 상태</h1>
+        <span id="connection">연결 중...</span>
+    </header>
+    <div id="runs"><div class="empty">실행 기록이 없습니다</div></div>
+
+    <script>
+        const runs = new Map();
+        const icons = { running: '⏳', success: '✅', error: '❌', cancelled: '⏹️' };
+
+        function text(value) {
+            const span = document.createElement('span');
+            span.textContent = value == null ? '' : String(value);
+            return span.innerHTML;
+        }
+
+        function render() {
+            const container = document.getElementById('runs');
+            const list = [...runs.values()]
+                .sort((a, b) => String(b.started_at || '').localeCompare(String(a.started_at || '')))
+                .slice(0, 20);
+            if (list.length === 0) {
+                container.innerHTML = '<div class="empty">실행 기록이 없습니다</div>';
+                return;
+            }
+            container.innerHTML = list.map(run => `
+                <div class="run ${text(run.status)}">
+                    <div class="title">
+                        <span>${icons[run.status] || '•'} ${text(run.workflow)}</span>
+                        <span>${text(run.status)}</span>
+                    </div>
+                    <div class="meta">${text(new Date(run.started_at).toLocaleString())} · 노드 ${text(run.nodes || 0)}개${run.failed_nodes ? ` · 실패 ${text(run.failed_nodes)}개` : ''}</div>
+                    ${run.last_node ? `<div class="node">마지막 노드: ${text(run.last_node)}</div>` : ''}
+                </div>`).join('');
+        }
+
+        function applyEvent(event) {
+            const run = runs.get(event.run_id) || { run_id: event.run_id, nodes: 0, failed_nodes: 0 };
+            if (event.event === 'run_started') {
+                Object.assign(run, { workflow: event.workflow, started_at: event.started_at, status: 'running' });
+            } else if (event.event === 'node_finished') {
+                run.nodes = (run.nodes || 0) + 1;
+                if (event.status === 'error') run.failed_nodes = (run.failed_nodes || 0) + 1;
+                run.last_node = `${event.node_id} (${event.status})`;
+            } else if (event.event === 'node_started') {
+                run.last_node = `${event.node_id} (실행 중)`;
+            } else if (event.event === 'run_finished') {
+                run.status = event.status;
+            } else {
+                return;
+            }
+            runs.set(event.run_id, run);
+            render();
+        }
+
+        // 열람 토큰은 페이지 주소의 ?token= 을 그대로 전달
+        const viewToken = new URLSearchParams(window.location.search).get('token');
+        const tokenQuery = viewToken ? `?token=${encodeURIComponent(viewToken)}` : '';
+
+        async function loadStatus() {
+            try {
+                const response = await fetch(`/status${tokenQuery}`);
+                const data = await response.json();
+                runs.clear();
+                (data.runs || []).forEach(run => runs.set(run.run_id, run));
+                render();
+            } catch (e) {
+                console.log('❌ 상태 불러오기 실패:', e);
+            }
+        }
+
+        function connect() {
+            const wsProtocol = window.location.protocol === 'https:' ? 'wss:' : 'ws:';
+            const websocket = new WebSocket(`${wsProtocol}//${window.location.host}/ws${tokenQuery}`);
+            const connection = document.getElementById('connection');
+
+            websocket.onopen = () => {
+                connection.textContent = '● 실시간';
+                connection.className = 'online';
+                loadStatus();
+            };
+            websocket.onmessage = (message) => {
+                try {
+                    const event = JSON.parse(message.data);
+                    if (event && event.run_id) applyEvent(event);
+                } catch (e) {
+                    // 실행 이벤트가 아닌 메시지는 무시
+                }
+            };
+            websocket.onclose = () => {
+                connection.textContent = '연결 끊김 - 재연결 중...';
+                connection.className = '';
+                setTimeout(connect, 3000);
+            };
+        }
+
+        loadStatus();
+        connect();
+    </script>
+</body>
+</html>"#
+        .to_string()
+}
+
+// 💬 채팅 서버 시작 설정 (chat_web_server_node 에서 검증 후 전달)
+struct ChatServerConfig {
     port: u16,
     node_id: String,
-    app_handle: AppHandle,
     enable_global: bool, // 🆕 글로벌 터널 옵션
+    static_root: Option<std::path::PathBuf>,
+    allowed_origins: Option<Vec<String>>,
+    spectator: bool,
+    view_token: Option<String>, // 👀 관전 모드 /status, /ws 열람 토큰
+    webhook_token: Option<String>, // 🪝 /hook 토큰 (원격 제어 토큰과 별개)
+    control: control_page::ControlConfig,
+}
+
+// 💬 채팅 서버 시작 함수 (🔧 터널 기능 통합)
+async fn start_chat_server(
+    app_handle: AppHandle,
+    config: ChatServerConfig,
 ) -> Result<ChatWebServerResult, String> {
+    let ChatServerConfig {
+        port,
+        node_id,
+        enable_global,
+        static_root,
+        allowed_origins,
+        spectator,
+        view_token,
+        webhook_token,
+        control,
+    } = config;
+    let view_token = view_token.map(Arc::new);
     let actual_port = find_available_port(port)?;
     let local_ips = get_local_ip_addresses();
 
@@ -696,16 +868,33 @@ async fn start_chat_server(
         format!("http://127.0.0.1:{}", actual_port)
     };
 
-    // 🎯 WebSocket 브로드캐스트 채널 생성
-    let (websocket_tx, _) = broadcast::channel::<String>(1000);
+    // 🎯 WebSocket 브로드캐스트 채널 생성 (크기 / 클라이언트별 대기열은 ws_limits 설정)
+    let limits = ws_limits::load_limits();
+    let (websocket_tx, _) = broadcast::channel::<String>(limits.broadcast_capacity);
     let websocket_tx_clone = websocket_tx.clone();
 
-    // 채팅 HTML 생성
-    let chat_html = create_mobile_chat_html();
+    // 채팅 HTML 생성 (관전 모드면 실행 상태 대시보드)
+    let chat_html = if spectator {
+        create_spectator_html()
+    } else {
+        create_mobile_chat_html()
+    };
 
-    // 메인 페이지 라우트
-    let chat_html_clone = chat_html.clone();
-    let main_route = warp::path::end().map(move || warp::reply::html(chat_html_clone.clone()));
+    // 메인 페이지 라우트 (압축본 미리 준비 + ETag 로 재방문 시 304)
+    let chat_page = Arc::new(static_cache::CachedAsset::new(
+        "text/html; charset=utf-8",
+        static_cache::PAGE_CACHE_CONTROL,
+        chat_html.into_bytes(),
+    ));
+    let main_route = warp::path::end()
+        .and(static_cache::conditional_headers())
+        .map(move |accept_encoding: Option<String>, if_none_match: Option<String>| {
+            static_cache::respond(
+                &chat_page,
+                accept_encoding.as_deref(),
+                if_none_match.as_deref(),
+            )
+        });
 
     // 메시지 전송 라우트
     let node_id_clone = node_id.clone();
@@ -713,13 +902,32 @@ async fn start_chat_server(
 
     let message_route = warp::path("send-message")
         .and(warp::post())
+        .and(interactive_only(spectator))
         .and(warp::body::json())
         .map(move |chat_msg: ChatMessage| {
             let node_id = node_id_clone.clone();
             let app_handle = app_handle_clone.clone();
             let message = chat_msg.message.clone();
+            let sender = chat_msg.sender.clone();
 
             tokio::spawn(async move {
+                // 승인 노드가 기다리는 답장이면 일반 채팅 메시지로 넘기지 않음
+                if super::approval_node::try_answer_from_chat(&node_id, &message).await {
+                    return;
+                }
+
+                // 🔀 라우팅 규칙에 맞으면 규칙의 대상으로만 전달
+                let inbound = message_router::InboundMessage {
+                    source: "chat".to_string(),
+                    sender,
+                    subject: None,
+                    text: message.clone(),
+                    node_id: Some(node_id.clone()),
+                };
+                if !message_router::route_message(&app_handle, &inbound).is_empty() {
+                    return;
+                }
+
                 let chat_event = ChatEvent {
                     node_id: node_id.clone(),
                     message: message.clone(),
@@ -729,7 +937,7 @@ async fn start_chat_server(
                         .as_millis() as u64,
                 };
 
-                if let Err(e) = app_handle.emit("chat-message-received", &chat_event) {
+                if let Err(e) = event_chunks::emit_chunked(&app_handle, "chat-message-received", &chat_event) {
                     eprintln!("❌ Failed to emit chat event: {}", e);
                 } else {
                     println!("📨 Chat message sent to frontend: {}", message);
@@ -745,39 +953,100 @@ async fn start_chat_server(
 
     // WebSocket 라우트
     let websocket_tx_for_route = websocket_tx_clone.clone();
+    let route_limits = limits.clone();
     let websocket_route = warp::path("ws")
+        .and(view_authorized(view_token.clone()))
         .and(warp::ws())
         .map(move |ws: warp::ws::Ws| {
             let tx = websocket_tx_for_route.clone();
-            ws.on_upgrade(move |websocket| {
+            let limits = route_limits.clone();
+            ws_limits::configure(ws, &limits).on_upgrade(move |websocket| {
                 println!("📱 WebSocket 클라이언트 연결됨");
-
-                let (mut ws_sender, _ws_receiver) = websocket.split();
-                let mut rx = tx.subscribe();
+                let rx = tx.subscribe();
 
                 async move {
-                    while let Ok(message) = rx.recv().await {
-                        println!("📱 WebSocket으로 메시지 전송: {}", message);
-
-                        if let Err(e) = ws_sender.send(warp::ws::Message::text(message)).await {
-                            println!("❌ WebSocket 클라이언트 연결 해제됨: {}", e);
-                            break;
-                        } else {
-                            println!("✅ WebSocket 메시지 전송 성공");
-                        }
-                    }
+                    ws_limits::pump(websocket, rx, limits).await;
                     println!("📱 WebSocket 연결 종료됨");
                 }
             })
-        });
+        })
+        .map(|reply| Box::new(reply) as Box<dyn warp::Reply>)
+        .recover(control_page::handle_rejection)
+        .unify();
+
+    // 📁 정적 파일 라우트 (/files/경로, static_root 가 있을 때만)
+    let files_route = warp::path("files")
+        .and(warp::get())
+        .and(warp::path::tail())
+        .and(static_cache::conditional_headers())
+        .and_then(
+            move |tail: warp::path::Tail,
+                  accept_encoding: Option<String>,
+                  if_none_match: Option<String>| {
+                let root = static_root.clone();
+                async move {
+                    let asset = load_static_file(root, tail.as_str()).await?;
+                    Ok::<_, warp::Rejection>(static_cache::respond(
+                        &asset,
+                        accept_encoding.as_deref(),
+                        if_none_match.as_deref(),
+                    ))
+                }
+            },
+        );
+
+    // 👀 최근 실행 요약 (관전 모드 전용)
+    let status_route = warp::path("status")
+        .and(warp::get())
+        .and(spectator_only(spectator))
+        .and(view_authorized(view_token))
+        .map(|| {
+            Box::new(warp::reply::json(&serde_json::json!({
+                "runs": run_history::recent_runs(20)
+            }))) as Box<dyn warp::Reply>
+        })
+        .recover(control_page::handle_rejection)
+        .unify();
+
+    // 📍 폰 위치 보고 (/location, 원격 제어와 같은 토큰)
+    let location_route = geofence_trigger::location_route(control.token.clone());
+
+    // 🪝 웹훅 수신 (/hook, 웹훅 전용 토큰) → 라우팅 규칙으로 전달
+    // 외부 서비스에 넘기는 토큰이므로 /control 실행 권한과 나눔
+    let hook_route = message_router::webhook_route(app_handle.clone(), webhook_token);
+
+    // 📱 원격 제어 페이지 (/control, 토큰이 있을 때만)
+    let control_route = control_page::routes(app_handle.clone(), control);
+
+    // 🔗 실행 공유 페이지 (/share/<run_id>, share_run 으로 만든 토큰 필요)
+    let share_route = run_share::routes();
+
+    // 🌐 Origin 제한 (터널 사용 시 기본은 같은 출처만 허용)
+    let policy = Arc::new(origin_policy::OriginPolicy::new(allowed_origins, enable_global));
+    let origin_check = origin_policy::origin_filter(policy.clone());
+    let preflight_route = warp::options()
+        .and(origin_check.clone())
+        .map(origin_policy::preflight);
 
     // 라우트 결합
-    let routes = main_route.or(message_route).or(websocket_route).with(
-        warp::cors()
-            .allow_any_origin()
-            .allow_headers(vec!["content-type"])
-            .allow_methods(vec!["GET", "POST"]),
-    );
+    let routes = preflight_route
+        .or(origin_check
+            .and(
+                main_route
+                    .or(message_route)
+                    .or(websocket_route)
+                    .or(files_route)
+                    .or(status_route)
+                    .or(control_route)
+                    .or(location_route)
+                    .or(hook_route)
+                    .or(share_route),
+            )
+            .map(|origin, reply| origin_policy::with_cors_headers(origin, reply)))
+        .recover(origin_policy::handle_rejection)
+        .with(access_log::warp_logger(node_id.clone()))
+        // Box<dyn Reply> 라우트가 섞인 필터 체인은 그대로 spawn 하면 수명 추론이 실패하므로 boxed
+        .boxed();
 
     let addr: SocketAddr = format!("0.0.0.0:{}", actual_port)
         .parse()
@@ -786,12 +1055,34 @@ async fn start_chat_server(
     // 🚀 서버 시작
     let server_key = format!("chat_server_{}", actual_port);
 
+    // 👀 관전 모드: 실행 이벤트를 WebSocket 클라이언트로 중계 (서버 중지 시 함께 종료)
+    let run_event_relay = spectator.then(|| {
+        let mut run_events = run_history::subscribe_events();
+        let tx = websocket_tx.clone();
+        async move {
+            loop {
+                match run_events.recv().await {
+                    Ok(event) => {
+                        let _ = tx.send(event);
+                    }
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        }
+    });
+
     let server_task = tokio::spawn(async move {
         println!(
-            "💬 WebSocket 채팅 서버 시작: {} (모든 네트워크에서 접근 가능)",
-            addr
+            "💬 WebSocket 채팅 서버 시작: {} (모든 네트워크에서 접근 가능, 관전 모드: {})",
+            addr, spectator
         );
-        warp::serve(routes).run(addr).await;
+        match run_event_relay {
+            Some(relay) => {
+                tokio::join!(warp::serve(routes).run(addr), relay);
+            }
+            None => warp::serve(routes).run(addr).await,
+        }
         println!("🛑 채팅 서버 중지됨: {}", addr);
     });
 
@@ -812,6 +1103,7 @@ async fn start_chat_server(
             Ok(global_url) => {
                 println!("✅ Global tunnel ready: {}", global_url);
                 final_server_url = global_url.clone();
+                policy.set_tunnel_url(Some(&global_url));
                 tunnel_url = Some(global_url);
                 tunnel_status = Some("active".to_string());
             }
@@ -840,6 +1132,9 @@ async fn start_chat_server(
         websocket_sender: websocket_tx,
         has_tunnel: enable_global && tunnel_url.is_some(),
         tunnel_url: tunnel_url.clone(),
+        spectator,
+        max_message_bytes: limits.max_message_bytes,
+        origin_policy: policy,
     };
 
     {
@@ -885,6 +1180,87 @@ async fn start_chat_server(
     })
 }
 
+// 🔒 관전 모드에서는 입력 라우트를 아예 노출하지 않음 (404)
+fn interactive_only(spectator: bool) -> impl Filter<Extract = (), Error = warp::Rejection> + Clone {
+    warp::any()
+        .and_then(move || async move {
+            if spectator {
+                Err(warp::reject::not_found())
+            } else {
+                Ok(())
+            }
+        })
+        .untuple_one()
+}
+
+fn spectator_only(spectator: bool) -> impl Filter<Extract = (), Error = warp::Rejection> + Clone {
+    interactive_only(!spectator)
+}
+
+// 👀 열람 토큰이 있으면 /status, /ws 도 토큰 확인 (없으면 로컬 네트워크 전용이라 통과)
+fn view_authorized(view_token: Option<Arc<String>>) -> warp::filters::BoxedFilter<()> {
+    match view_token {
+        Some(token) => control_page::authorized(Some(token)).boxed(),
+        None => warp::any().boxed(),
+    }
+}
+
+// 📁 정적 파일 제공 - 모든 경로는 path_guard 로 루트 안인지 확인 (압축 / ETag 는 static_cache)
+async fn load_static_file(
+    root: Option<std::path::PathBuf>,
+    requested: &str,
+) -> Result<Arc<static_cache::CachedAsset>, warp::Rejection> {
+    let root = root.ok_or_else(warp::reject::not_found)?;
+    let path = match path_guard::resolve_within(&root, requested) {
+        Ok(path) if path.is_file() => path,
+        Ok(_) => return Err(warp::reject::not_found()),
+        Err(e) => {
+            println!("🚫 정적 파일 요청 거부: {}", e);
+            return Err(warp::reject::not_found());
+        }
+    };
+    static_cache::load_file(&path, content_type_for(&path))
+        .await
+        .map_err(|_| warp::reject::not_found())
+}
+
+pub fn content_type_for(path: &std::path::Path) -> &'static str {
+    match path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase())
+        .as_deref()
+    {
+        Some("html") | Some("htm") => "text/html; charset=utf-8",
+        Some("txt") | Some("md") | Some("log") => "text/plain; charset=utf-8",
+        Some("json") => "application/json",
+        Some("css") => "text/css",
+        Some("js") => "text/javascript",
+        Some("png") => "image/png",
+        Some("jpg") | Some("jpeg") => "image/jpeg",
+        Some("gif") => "image/gif",
+        Some("webp") => "image/webp",
+        Some("svg") => "image/svg+xml",
+        Some("pdf") => "application/pdf",
+        Some("mp4") => "video/mp4",
+        Some("mp3") => "audio/mpeg",
+        _ => "application/octet-stream",
+    }
+}
+
+// 🎛️ 채팅 서버 선택 옵션 (프론트엔드에서 { staticRoot, allowedOrigins, ... } 로 전달)
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatServerOptions {
+    pub static_root: Option<String>,          // 📁 /files/ 로 제공할 폴더
+    pub allowed_origins: Option<Vec<String>>, // 🌐 허용할 다른 출처 ("*" = 모두)
+    pub mode: Option<String>,                 // 👀 "chat"(기본) | "spectator"(읽기 전용 실행 상태)
+    pub control_token: Option<String>,        // 📱 /control, /location 접근 토큰 (없으면 비활성)
+    pub workflows_dir: Option<String>,        // 📱 /control 에 보여줄 워크플로우 폴더
+    pub view_token: Option<String>,           // 👀 관전 모드 열람 토큰 (터널 사용 시 필수)
+    pub webhook_token: Option<String>,        // 🪝 /hook 접근 토큰 (없으면 비활성)
+}
+
 // 🎯 Tauri 명령 함수 (🔧 글로벌 옵션 추가)
 #[tauri::command]
 pub async fn chat_web_server_node(
@@ -892,21 +1268,90 @@ pub async fn chat_web_server_node(
     port: u16,
     node_id: Option<String>,
     enable_global: Option<bool>, // 🆕 글로벌 터널 옵션
+    options: Option<ChatServerOptions>,
+    run_id: Option<String>, // 🧹 실행이 끝나면 서버와 터널을 함께 정리
 ) -> Result<ChatWebServerResult, String> {
     let node_id = node_id.unwrap_or_else(|| "unknown".to_string());
     let enable_global = enable_global.unwrap_or(false);
+    let ChatServerOptions {
+        static_root,
+        allowed_origins,
+        mode,
+        control_token,
+        workflows_dir,
+        view_token,
+        webhook_token,
+    } = options.unwrap_or_default();
+    let spectator = match mode.as_deref().map(str::trim).unwrap_or("chat") {
+        "chat" | "" => false,
+        "spectator" => true,
+        other => return Err(format!("UNSUPPORTED_MODE: {}", other)),
+    };
+    let control_token = control_token.filter(|token| !token.trim().is_empty());
+    if spectator && control_token.is_some() {
+        // 관전 모드는 입력을 받지 않으므로 원격 실행도 허용하지 않음
+        return Err("CONTROL_NOT_ALLOWED_IN_SPECTATOR_MODE".to_string());
+    }
+    let webhook_token = webhook_token.filter(|token| !token.trim().is_empty());
+    if spectator && webhook_token.is_some() {
+        return Err("WEBHOOK_NOT_ALLOWED_IN_SPECTATOR_MODE".to_string());
+    }
+    let view_token = view_token.filter(|token| !token.trim().is_empty());
+    if !spectator && view_token.is_some() {
+        return Err("VIEW_TOKEN_ONLY_IN_SPECTATOR_MODE".to_string());
+    }
+    if spectator && enable_global && view_token.is_none() {
+        // 터널 주소는 누구나 열 수 있으므로 실행 상태를 토큰 없이 공개하지 않음
+        return Err("VIEW_TOKEN_REQUIRED_FOR_TUNNEL".to_string());
+    }
+    let workflows_dir = workflows_dir
+        .filter(|dir| !dir.trim().is_empty())
+        .map(|dir| std::path::PathBuf::from(dir.trim()))
+        .unwrap_or_else(control_page::default_workflows_dir);
+    if control_token.is_some() && !workflows_dir.is_dir() {
+        return Err(format!("WORKFLOWS_DIR_NOT_FOUND: {}", workflows_dir.display()));
+    }
+    let static_root = static_root
+        .filter(|root| !root.trim().is_empty())
+        .map(|root| std::path::PathBuf::from(root.trim()));
+    if let Some(root) = &static_root {
+        if !root.is_dir() {
+            return Err(format!("STATIC_ROOT_NOT_FOUND: {}", root.display()));
+        }
+    }
 
     println!(
         "💬 ChatWebServerNode: 포트 {}에서 채팅 서버 시작 중 (글로벌: {})",
         port, enable_global
     );
 
-    match start_chat_server(port, node_id, app_handle, enable_global).await {
+    let config = ChatServerConfig {
+        port,
+        node_id: node_id.clone(),
+        enable_global,
+        static_root,
+        allowed_origins,
+        spectator,
+        view_token,
+        webhook_token,
+        control: control_page::ControlConfig {
+            token: control_token,
+            workflows_dir,
+        },
+    };
+    match start_chat_server(app_handle, config).await {
         Ok(result) => {
             println!(
                 "✅ ChatWebServerNode: 채팅 서버 시작 완료 - {}",
                 result.server_url
             );
+            if let Err(e) = run_cleanup::register(
+                run_id.as_deref(),
+                Some(&node_id),
+                run_cleanup::CleanupAction::StopChatServer { node_id: node_id.clone() },
+            ) {
+                println!("⚠️ 실행 정리 등록 실패: {}", e);
+            }
             Ok(result)
         }
         Err(error) => {
@@ -938,7 +1383,11 @@ pub async fn send_web_response(node_id: String, response_message: String) -> Res
                 .as_millis()
         });
         
-        if let Err(e) = handle.websocket_sender.send(response_json.to_string()) {
+        let response_json = response_json.to_string();
+        if response_json.len() > handle.max_message_bytes {
+            return Err(format!("WS_MESSAGE_TOO_LARGE: {} bytes", response_json.len()));
+        }
+        if let Err(e) = handle.websocket_sender.send(response_json) {
             println!("❌ Failed to send web response: {}", e);
             return Err(format!("Failed to send web response: {}", e));
         }
@@ -973,6 +1422,9 @@ pub async fn send_to_mobile_with_type(node_id: String, message: String, message_
             "message": message,
             "type": message_type
         }).to_string();
+        if message_json.len() > handle.max_message_bytes {
+            return Err(format!("WS_MESSAGE_TOO_LARGE: {} bytes", message_json.len()));
+        }
         
         match handle.websocket_sender.send(message_json) {
             Ok(receiver_count) => {
@@ -1002,6 +1454,9 @@ pub async fn send_to_mobile_with_type(node_id: String, message: String, message_
 #[tauri::command]
 pub async fn stop_chat_server_node(node_id: String) -> Result<String, String> {
     println!("🛑 StopChatServerNode: 노드 {} 서버 중지 중", node_id);
+    run_cleanup::unregister_where(|entry| {
+        entry.action == run_cleanup::CleanupAction::StopChatServer { node_id: node_id.clone() }
+    });
 
     let registry = get_chat_server_registry();
     let mut servers = registry.write().await;
@@ -1074,6 +1529,7 @@ pub async fn stop_chat_tunnel(node_id: String) -> Result<String, String> {
                 if handle.node_id == node_id {
                     handle.has_tunnel = false;
                     handle.tunnel_url = None;
+                    handle.origin_policy.set_tunnel_url(None);
                     handle.server_url = handle
                         .local_url
                         .clone()
@@ -1115,7 +1571,8 @@ pub async fn get_chat_server_info(node_id: String) -> Result<serde_json::Value,
             "local_url": handle.local_url,
             "has_tunnel": handle.has_tunnel,
             "tunnel_url": handle.tunnel_url,
-            "status": handle.status
+            "status": handle.status,
+            "mode": if handle.spectator { "spectator" } else { "chat" }
         }))
     } else {
         Ok(serde_json::json!({
@@ -1124,6 +1581,25 @@ pub async fn get_chat_server_info(node_id: String) -> Result<serde_json::Value,
     }
 }
 
+// 🔗 공유 링크에 붙일 주소 (터널이 있으면 터널 주소 우선)
+pub async fn public_base_urls() -> Vec<String> {
+    let registry = get_chat_server_registry();
+    let servers = registry.read().await;
+    let mut urls: Vec<String> = servers
+        .values()
+        .filter(|handle| handle.status == "running")
+        .map(|handle| {
+            handle
+                .tunnel_url
+                .clone()
+                .unwrap_or_else(|| handle.server_url.clone())
+        })
+        .collect();
+    urls.sort();
+    urls.dedup();
+    urls
+}
+
 // 🧹 정리 함수들
 #[allow(dead_code)]
 pub async fn list_running_chat_servers() -> Vec<String> {
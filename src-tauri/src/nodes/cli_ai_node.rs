@@ -1,9 +1,11 @@
 // src-tauri/src/nodes/cli_ai_node.rs
 
+use base64::{engine::general_purpose, Engine as _};
 use serde_json::json;
 use std::process::Command;
 use std::path::{Path, PathBuf};
-use std::fs;
+
+use super::{atomic_store, http_client, mock_replay, node_cache};
 
 // 언어 감지를 위한 enum (현재 미사용, 향후 사용 예정)
 #[allow(dead_code)]
@@ -22,18 +24,138 @@ struct ConversationEntry {
     ai_response: String,
     cli_command: Option<String>,
     cli_result: Option<String>,
+    // 기록 시각 (unix 초) - 예전 파일에는 없을 수 있음
+    #[serde(default)]
+    timestamp: Option<i64>,
+}
+
+// 대화 기록 보관 한도 (store/ai_history_limits.json)
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct HistoryLimits {
+    pub max_entries: usize,
+    pub max_bytes: u64,
+    pub max_age_days: u64,
+}
+
+impl Default for HistoryLimits {
+    fn default() -> Self {
+        HistoryLimits {
+            max_entries: 100,
+            max_bytes: 512 * 1024,
+            max_age_days: 30,
+        }
+    }
+}
+
+fn get_history_limits_path() -> PathBuf {
+    atomic_store::store_dir().join("ai_history_limits.json")
+}
+
+fn load_history_limits() -> HistoryLimits {
+    atomic_store::read_json_verified(&get_history_limits_path()).unwrap_or_default()
+}
+
+// 기록 파일의 마지막 수정 시각 (unix 초)
+fn file_modified_secs(path: &Path) -> Option<i64> {
+    let modified = std::fs::metadata(path).and_then(|m| m.modified()).ok()?;
+    let secs = modified.duration_since(std::time::UNIX_EPOCH).ok()?.as_secs();
+    i64::try_from(secs).ok()
+}
+
+// 한도를 넘는 오래된 항목부터 제거, 제거된 개수 반환
+// 시각이 없는 예전 항목은 fallback(파일 수정 시각)으로 기록해 두고 그 기준으로 나이 계산
+fn prune_history(
+    history: &mut Vec<ConversationEntry>,
+    limits: &HistoryLimits,
+    fallback_timestamp: Option<i64>,
+) -> usize {
+    let before = history.len();
+
+    if let Some(fallback) = fallback_timestamp {
+        for entry in history.iter_mut() {
+            entry.timestamp.get_or_insert(fallback);
+        }
+    }
+
+    if limits.max_age_days > 0 {
+        let cutoff = chrono::Utc::now().timestamp() - (limits.max_age_days * 24 * 60 * 60) as i64;
+        history.retain(|entry| !matches!(entry.timestamp, Some(ts) if ts < cutoff));
+    }
+
+    if limits.max_entries > 0 && history.len() > limits.max_entries {
+        let excess = history.len() - limits.max_entries;
+        history.drain(..excess);
+    }
+
+    if limits.max_bytes > 0 {
+        while !history.is_empty() {
+            let size = serde_json::to_vec(&*history).map(|v| v.len() as u64).unwrap_or(0);
+            if size <= limits.max_bytes {
+                break;
+            }
+            history.remove(0);
+        }
+    }
+
+    before - history.len()
+}
+
+// store 폴더의 모든 cliainode_*.json 파일 목록 (node_id, 경로)
+fn list_conversation_files() -> Vec<(String, PathBuf)> {
+    let mut sessions = Vec::new();
+
+    if let Ok(entries) = std::fs::read_dir(atomic_store::store_dir()) {
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if let Some(node_id) = name
+                .strip_prefix("cliainode_")
+                .and_then(|rest| rest.strip_suffix(".json"))
+            {
+                sessions.push((node_id.to_string(), entry.path()));
+            }
+        }
+    }
+
+    sessions.sort();
+    sessions
+}
+
+fn prune_all_sessions(limits: &HistoryLimits) -> usize {
+    let mut total_removed = 0;
+
+    for (node_id, path) in list_conversation_files() {
+        let mut history = get_conversation_history(&node_id);
+        let removed = prune_history(&mut history, limits, file_modified_secs(&path));
+        if removed == 0 {
+            continue;
+        }
+
+        match atomic_store::write_json_atomic(&path, &history) {
+            Ok(_) => total_removed += removed,
+            Err(e) => println!("⚠️ 대화 기록 정리 실패 ({}): {}", node_id, e),
+        }
+    }
+
+    total_removed
+}
+
+// 앱 시작 시 한 번, 이후 1시간마다 자동 정리
+pub fn start_history_pruning_job() {
+    tauri::async_runtime::spawn(async {
+        let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(60 * 60));
+        loop {
+            interval.tick().await;
+            let removed = prune_all_sessions(&load_history_limits());
+            if removed > 0 {
+                println!("🧹 AI 대화 기록 자동 정리: {}개 항목 삭제", removed);
+            }
+        }
+    });
 }
 
 // JSON 파일 기반 세션 관리 함수들
 fn get_conversation_file_path(node_id: &str) -> PathBuf {
-    let mut path = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
-    // src-tauri 폴더에서 상위 디렉토리로 이동 (프로젝트 루트)
-    if path.file_name() == Some(std::ffi::OsStr::new("src-tauri")) {
-        path.pop();
-    }
-    path.push("store");
-    path.push(format!("cliainode_{}.json", node_id));
-    path
+    atomic_store::store_dir().join(format!("cliainode_{}.json", node_id))
 }
 
 
@@ -44,15 +166,8 @@ fn get_conversation_history(node_id: &str) -> Vec<ConversationEntry> {
         return Vec::new();
     }
     
-    match fs::read_to_string(&file_path) {
-        Ok(content) => {
-            match serde_json::from_str::<Vec<ConversationEntry>>(&content) {
-                Ok(history) => history,
-                Err(_) => Vec::new()
-            }
-        },
-        Err(_) => Vec::new()
-    }
+    // 체크섬 검증 + 손상 시 백업 복구
+    atomic_store::read_json_verified::<Vec<ConversationEntry>>(&file_path).unwrap_or_default()
 }
 
 
@@ -78,26 +193,24 @@ fn get_comprehensive_directory_info() -> String {
     let mut folders = Vec::new();
     
     if let Ok(entries) = std::fs::read_dir(&current_dir) {
-        for entry in entries {
-            if let Ok(entry) = entry {
-                let name = entry.file_name().to_string_lossy().to_string();
-                let path = entry.path();
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let path = entry.path();
+            
+            if path.is_dir() {
+                folders.push(name);
+            } else {
+                // 파일 크기와 수정 시간 추가
+                let size = std::fs::metadata(&path)
+                    .map(|m| m.len())
+                    .unwrap_or(0);
                 
-                if path.is_dir() {
-                    folders.push(name);
-                } else {
-                    // 파일 크기와 수정 시간 추가
-                    let size = std::fs::metadata(&path)
-                        .map(|m| m.len())
-                        .unwrap_or(0);
-                    
-                    let modified = std::fs::metadata(&path)
-                        .and_then(|m| m.modified())
-                        .map(|t| format!("{:?}", t))
-                        .unwrap_or_else(|_| "Unknown".to_string());
-                    
-                    files.push(format!("{} ({}bytes, modified: {})", name, size, modified));
-                }
+                let modified = std::fs::metadata(&path)
+                    .and_then(|m| m.modified())
+                    .map(|t| format!("{:?}", t))
+                    .unwrap_or_else(|_| "Unknown".to_string());
+                
+                files.push(format!("{} ({}bytes, modified: {})", name, size, modified));
             }
         }
     }
@@ -134,16 +247,14 @@ fn get_recent_changes(current_dir: &Path, info: &mut String) {
     if let Ok(entries) = std::fs::read_dir(current_dir) {
         let mut recent_files = Vec::new();
         
-        for entry in entries {
-            if let Ok(entry) = entry {
-                let path = entry.path();
-                if path.is_file() {
-                    if let Ok(metadata) = std::fs::metadata(&path) {
-                        if let Ok(modified) = metadata.modified() {
-                            if modified > five_minutes_ago {
-                                let name = entry.file_name().to_string_lossy().to_string();
-                                recent_files.push(name);
-                            }
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_file() {
+                if let Ok(metadata) = std::fs::metadata(&path) {
+                    if let Ok(modified) = metadata.modified() {
+                        if modified > five_minutes_ago {
+                            let name = entry.file_name().to_string_lossy().to_string();
+                            recent_files.push(name);
                         }
                     }
                 }
@@ -169,26 +280,24 @@ fn intelligent_file_search(pattern: &str) -> String {
     // 1. 현재 디렉토리에서 직접 검색
     let mut found_files = Vec::new();
     if let Ok(entries) = std::fs::read_dir(&current_dir) {
-        for entry in entries {
-            if let Ok(entry) = entry {
-                let name = entry.file_name().to_string_lossy().to_string();
-                let name_lower = name.to_lowercase();
-                let pattern_lower = pattern.to_lowercase();
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let name_lower = name.to_lowercase();
+            let pattern_lower = pattern.to_lowercase();
+            
+            // 퍼지 매칭: 부분 문자열 포함 검색
+            if name_lower.contains(&pattern_lower) {
+                let path = entry.path();
+                let is_dir = path.is_dir();
+                let size = if is_dir { 0 } else {
+                    std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0)
+                };
                 
-                // 퍼지 매칭: 부분 문자열 포함 검색
-                if name_lower.contains(&pattern_lower) {
-                    let path = entry.path();
-                    let is_dir = path.is_dir();
-                    let size = if is_dir { 0 } else {
-                        std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0)
-                    };
-                    
-                    found_files.push(format!("{} {} ({}bytes)", 
-                        if is_dir { "📁" } else { "📄" },
-                        name,
-                        size
-                    ));
-                }
+                found_files.push(format!("{} {} ({}bytes)", 
+                    if is_dir { "📁" } else { "📄" },
+                    name,
+                    size
+                ));
             }
         }
     }
@@ -271,8 +380,136 @@ fn extract_intelligent_keywords(user_input: &str) -> Vec<String> {
     keywords
 }
 
+// Claude 비전 입력용 이미지 (긴 변 1568px 이하로 축소 후 JPEG 인코딩)
+const VISION_MAX_DIMENSION: u32 = 1568;
+
+struct VisionImage {
+    media_type: &'static str,
+    data: String,
+    digest: String,
+}
+
+fn prepare_vision_image(input: &str) -> Result<VisionImage, String> {
+    let input = input.trim();
+
+    // 1. 존재하는 파일 경로면 파일에서 읽기, 아니면 base64(data URL 포함)로 해석
+    let raw_bytes = if Path::new(input).is_file() {
+        std::fs::read(input).map_err(|e| format!("IMAGE_READ_ERROR: {}", e))?
+    } else {
+        let payload = match input.find(";base64,") {
+            Some(index) if input.starts_with("data:") => &input[index + 8..],
+            _ => input,
+        };
+        let cleaned: String = payload.chars().filter(|c| !c.is_whitespace()).collect();
+        general_purpose::STANDARD
+            .decode(cleaned)
+            .map_err(|_| "INVALID_IMAGE_INPUT: 파일 경로나 base64 이미지가 아닙니다".to_string())?
+    };
+
+    // 2. 디코딩 + 축소
+    let decoded = image::load_from_memory(&raw_bytes)
+        .map_err(|e| format!("IMAGE_DECODE_ERROR: {}", e))?;
+    let resized = if decoded.width() > VISION_MAX_DIMENSION || decoded.height() > VISION_MAX_DIMENSION {
+        decoded.resize(VISION_MAX_DIMENSION, VISION_MAX_DIMENSION, image::imageops::FilterType::Triangle)
+    } else {
+        decoded
+    };
+
+    // 3. JPEG 인코딩 (알파 채널 제거)
+    let mut jpeg_data = Vec::new();
+    image::codecs::jpeg::JpegEncoder::new_with_quality(&mut jpeg_data, 85)
+        .encode_image(&resized.to_rgb8())
+        .map_err(|e| format!("IMAGE_ENCODE_ERROR: {}", e))?;
+
+    let data = general_purpose::STANDARD.encode(&jpeg_data);
+    Ok(VisionImage {
+        media_type: "image/jpeg",
+        digest: node_cache::make_cache_key("image", &json!(data)),
+        data,
+    })
+}
+
+// Claude Messages API 단일 호출 (목 모드 재생/녹화 포함)
+pub(crate) async fn request_claude_completion(
+    client: &reqwest::Client,
+    api_key: &str,
+    model: &str,
+    system_prompt: &str,
+    user_content: &serde_json::Value,
+    mock_request: &serde_json::Value,
+) -> Result<serde_json::Value, String> {
+    // 목(mock) 모드: 녹화된 응답 재생 또는 실제 응답 녹화 (모델별로 따로 녹화)
+    let mut mock_request = mock_request.clone();
+    mock_request["model"] = json!(model);
+
+    if let Some(recorded) = mock_replay::replay("claude", &mock_request)? {
+        return Ok(recorded);
+    }
+
+    let request_body = json!({
+        "model": model,
+        "max_tokens": 1000,
+        "system": system_prompt,
+        "messages": [
+            {
+                "role": "user",
+                "content": user_content
+            }
+        ]
+    });
+
+    let response = client
+        .post("https://api.anthropic.com/v1/messages")
+        .header("x-api-key", api_key)
+        .header("Content-Type", "application/json")
+        .header("anthropic-version", "2023-06-01")
+        .json(&request_body)
+        .send()
+        .await
+        .map_err(|e| format!("API request failed: {}", e))?;
+
+    if !response.status().is_success() {
+        let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+        return Err(format!("Claude API error: {}", error_text));
+    }
+
+    let response_json: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse API response: {}", e))?;
+
+    mock_replay::record("claude", &mock_request, &response_json);
+    Ok(response_json)
+}
+
+// 거절 응답이나 빈 응답이면 사유 반환 (다음 모델로 넘길지 판단)
+fn refusal_reason(response_json: &serde_json::Value) -> Option<String> {
+    if response_json["stop_reason"].as_str() == Some("refusal") {
+        return Some("refusal".to_string());
+    }
+
+    match response_json["content"][0]["text"].as_str() {
+        Some(text) if !text.trim().is_empty() => None,
+        _ => Some("empty_response".to_string()),
+    }
+}
+
+// 선택 옵션 (캐시, 대체 모델, 이미지 입력) - 프론트엔드에서 객체 하나로 전달
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CliAiOptions {
+    pub cache_ttl_secs: Option<u64>,
+    pub fallback_models: Option<Vec<String>>,
+    pub images: Option<Vec<String>>,
+}
+
 #[tauri::command]
-pub async fn cli_ai_node(user_input: String, api_key: String, model: String, cli_result: Option<String>, node_id: Option<String>) -> Result<String, String> {
+pub async fn cli_ai_node(user_input: String, api_key: String, model: String, cli_result: Option<String>, node_id: Option<String>, options: Option<CliAiOptions>) -> Result<String, String> {
+    let CliAiOptions {
+        cache_ttl_secs,
+        fallback_models,
+        images,
+    } = options.unwrap_or_default();
     let node_id = node_id.unwrap_or_else(|| "default".to_string());
     println!("🧠 AI Node processing with Claude API: {} (node: {})", user_input, node_id);
 
@@ -285,6 +522,30 @@ pub async fn cli_ai_node(user_input: String, api_key: String, model: String, cli
         return Err("NO_API_KEY".to_string());
     }
 
+    // 옵트인 캐시: 같은 입력이면 API 호출 없이 이전 결과 반환
+    let cache_ttl_secs = cache_ttl_secs.unwrap_or(0);
+    let cache_inputs = json!({
+        "user_input": user_input,
+        "model": model,
+        "fallback_models": fallback_models,
+        "cli_result": cli_result,
+        "images": images
+    });
+    if cache_ttl_secs > 0 {
+        if let Some(cached) = node_cache::lookup("cli_ai_node", &cache_inputs) {
+            println!("♻️ AI Node cache hit (node: {})", node_id);
+            return Ok(cached);
+        }
+    }
+
+    // 이미지 입력 (스크린샷 분석 등) - 파일 경로 또는 base64, 서버에서 축소
+    let vision_images = images
+        .unwrap_or_default()
+        .iter()
+        .filter(|image| !image.trim().is_empty())
+        .map(|image| prepare_vision_image(image))
+        .collect::<Result<Vec<_>, String>>()?;
+
     // 강화된 파일 시스템 정보 수집
     let _current_dir_info = get_comprehensive_directory_info();
     let file_keywords = extract_intelligent_keywords(&user_input);
@@ -305,8 +566,8 @@ pub async fn cli_ai_node(user_input: String, api_key: String, model: String, cli
         file_search_info.push_str("No specific file patterns detected in user input.\n");
     }
 
-    // Claude API 호출
-    let client = reqwest::Client::new();
+    // Claude API 호출 (공용 클라이언트 - 이전 호출의 연결 재사용)
+    let client = http_client::client();
     
     let cli_result_context = cli_result.as_ref()
         .map(|result| format!("Previous CLI Execution Result:\n{}\n\n", result))
@@ -373,37 +634,80 @@ file_search_info);
 
     let enhanced_user_input = format!("{}\n\nProvide the CLI command in the specified format.", user_input);
 
-    let request_body = json!({
-        "model": model,
-        "max_tokens": 1000,
-        "system": system_prompt,
-        "messages": [
-            {
-                "role": "user",
-                "content": enhanced_user_input
-            }
-        ]
+    // 이미지가 있으면 image 블록 + text 블록, 없으면 기존처럼 문자열
+    let user_content = if vision_images.is_empty() {
+        json!(enhanced_user_input)
+    } else {
+        let mut blocks: Vec<serde_json::Value> = vision_images
+            .iter()
+            .map(|image| json!({
+                "type": "image",
+                "source": {
+                    "type": "base64",
+                    "media_type": image.media_type,
+                    "data": image.data
+                }
+            }))
+            .collect();
+        blocks.push(json!({ "type": "text", "text": enhanced_user_input }));
+        json!(blocks)
+    };
+
+    let mock_request = json!({
+        "user_input": user_input,
+        "cli_result": cli_result,
+        "images": vision_images.iter().map(|image| image.digest.clone()).collect::<Vec<_>>()
     });
 
-    let response = client
-        .post("https://api.anthropic.com/v1/messages")
-        .header("x-api-key", api_key)
-        .header("Content-Type", "application/json")
-        .header("anthropic-version", "2023-06-01")
-        .json(&request_body)
-        .send()
-        .await
-        .map_err(|e| format!("API request failed: {}", e))?;
+    // 모델 라우팅 체인: 기본 모델 → fallback_models 순서로 시도 (에러/거절 시 다음 모델)
+    let mut model_chain = vec![model.clone()];
+    for fallback in fallback_models.unwrap_or_default() {
+        let fallback = fallback.trim().to_string();
+        if !fallback.is_empty() && !model_chain.contains(&fallback) {
+            model_chain.push(fallback);
+        }
+    }
 
-    if !response.status().is_success() {
-        let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
-        return Err(format!("Claude API error: {}", error_text));
+    let mut routing = Vec::new();
+    let mut answered: Option<(String, serde_json::Value)> = None;
+    let mut last_error = String::new();
+
+    for candidate in &model_chain {
+        let attempt = request_claude_completion(
+            &client,
+            &api_key,
+            candidate,
+            &system_prompt,
+            &user_content,
+            &mock_request,
+        )
+        .await;
+
+        match attempt {
+            Ok(response_json) => {
+                if let Some(reason) = refusal_reason(&response_json) {
+                    println!("↪️ {} 응답 거절/비어있음 ({}), 다음 모델로 전환", candidate, reason);
+                    routing.push(json!({ "model": candidate, "status": "refused", "reason": reason }));
+                    last_error = format!("Model {} refused: {}", candidate, reason);
+                    continue;
+                }
+                routing.push(json!({ "model": candidate, "status": "ok" }));
+                answered = Some((candidate.clone(), response_json));
+                break;
+            }
+            Err(e) => {
+                println!("↪️ {} 호출 실패, 다음 모델로 전환: {}", candidate, e);
+                routing.push(json!({ "model": candidate, "status": "error", "error": e }));
+                last_error = e;
+            }
+        }
     }
 
-    let response_json: serde_json::Value = response
-        .json()
-        .await
-        .map_err(|e| format!("Failed to parse API response: {}", e))?;
+    let (model_used, response_json) = match answered {
+        Some(answer) => answer,
+        None if model_chain.len() == 1 => return Err(last_error),
+        None => return Err(format!("ALL_MODELS_FAILED: {}", serde_json::Value::Array(routing))),
+    };
 
     let full_response = response_json["content"][0]["text"]
         .as_str()
@@ -458,10 +762,14 @@ file_search_info);
         "explanation": if explanation.is_empty() { full_response } else { &explanation },
         "full_response": full_response,
         "user_input": user_input,
-        "model_used": model
+        "model_used": model_used,
+        "routing": routing
     });
-    
-    Ok(result.to_string())
+
+    let result = result.to_string();
+    node_cache::store("cli_ai_node", &cache_inputs, &result, cache_ttl_secs);
+
+    Ok(result)
 }
 
 #[tauri::command]
@@ -469,7 +777,7 @@ pub async fn clear_conversation_history(node_id: String) -> Result<String, Strin
     let file_path = get_conversation_file_path(&node_id);
     
     if file_path.exists() {
-        match fs::remove_file(&file_path) {
+        match atomic_store::remove_with_sidecars(&file_path) {
             Ok(_) => {
                 println!("🧹 Conversation history cleared for node {}", node_id);
                 Ok("Conversation history cleared".to_string())
@@ -502,22 +810,61 @@ pub async fn update_cli_result(node_id: String, cli_result: String) -> Result<St
     // 가장 최근 대화에 CLI 결과 추가
     if let Some(last_entry) = history.last_mut() {
         last_entry.cli_result = Some(cli_result.clone());
+        prune_history(
+            &mut history,
+            &load_history_limits(),
+            file_modified_secs(&file_path),
+        );
         
-        // 파일에 다시 저장
-        match serde_json::to_string_pretty(&history) {
-            Ok(json_content) => {
-                if let Err(e) = fs::write(&file_path, json_content) {
-                    return Err(format!("Failed to update conversation: {}", e));
-                } else {
-                    println!("🔄 Updated CLI result for node {}: {}", node_id, cli_result);
-                    return Ok("CLI result updated successfully".to_string());
-                }
+        // 파일에 다시 저장 (원자적 쓰기)
+        match atomic_store::write_json_atomic(&file_path, &history) {
+            Ok(_) => {
+                println!("🔄 Updated CLI result for node {}: {}", node_id, cli_result);
+                return Ok("CLI result updated successfully".to_string());
             },
             Err(e) => {
-                return Err(format!("Failed to serialize conversation: {}", e));
+                return Err(format!("Failed to update conversation: {}", e));
             }
         }
     }
     
     Err("Failed to update CLI result".to_string())
-}
\ No newline at end of file
+}
+
+#[tauri::command]
+pub async fn list_ai_sessions() -> Result<serde_json::Value, String> {
+    let sessions: Vec<serde_json::Value> = list_conversation_files()
+        .into_iter()
+        .map(|(node_id, path)| {
+            let history = get_conversation_history(&node_id);
+            let size = std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+            json!({
+                "node_id": node_id,
+                "entries": history.len(),
+                "bytes": size,
+                "last_timestamp": history.iter().filter_map(|e| e.timestamp).max()
+            })
+        })
+        .collect();
+
+    Ok(json!(sessions))
+}
+
+#[tauri::command]
+pub async fn get_ai_history_limits() -> Result<HistoryLimits, String> {
+    Ok(load_history_limits())
+}
+
+#[tauri::command]
+pub async fn set_ai_history_limits(limits: HistoryLimits) -> Result<String, String> {
+    atomic_store::write_json_atomic(&get_history_limits_path(), &limits)?;
+    let removed = prune_all_sessions(&limits);
+    Ok(format!("History limits saved ({} entries pruned)", removed))
+}
+
+#[tauri::command]
+pub async fn prune_ai_sessions() -> Result<String, String> {
+    let removed = prune_all_sessions(&load_history_limits());
+    println!("🧹 AI 대화 기록 정리: {}개 항목 삭제", removed);
+    Ok(format!("{} entries pruned", removed))
+}
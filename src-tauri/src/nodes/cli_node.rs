@@ -1,11 +1,12 @@
-use std::process::Command;
+use std::process::{Command, Stdio};
 use serde_json::json;
+use super::{os_command, resource_guard, value_store};
 
 #[cfg(target_os = "windows")]
 use std::os::windows::process::CommandExt;
 
 #[tauri::command]
-pub fn cli_node(command: String) -> Result<String, String> {
+pub fn cli_node(command: String, elevated: Option<bool>, priority: Option<String>) -> Result<String, String> {
     println!("🖥️ CLI Node executing command: '{}'", command);
 
     // 입력값 검증
@@ -27,14 +28,68 @@ pub fn cli_node(command: String) -> Result<String, String> {
         }
     }
 
-    // Windows와 Unix 계열 운영체제에 따라 다른 명령어 실행
-    let output = if cfg!(target_os = "windows") {
+    // 관리자 권한 실행: UAC / sudo 암호 창으로 사용자에게 명시적으로 확인받음
+    let (stdout, stderr, exit_code) = if elevated.unwrap_or(false) {
+        println!("🛡️ 관리자 권한으로 실행 요청: {}", command);
+        os_command::run_elevated_shell(&command, None)
+            .map(|out| (out.stdout, out.stderr, out.status))?
+    } else {
+        run_shell(&command, os_command::ProcessPriority::parse(priority.as_deref())?)?
+    };
+    
+    // 디버깅 정보 출력
+    println!("📋 Command executed: {}", command);
+    println!("📤 Exit code: {}", exit_code);
+    println!("📜 Stdout length: {} chars", stdout.len());
+    println!("⚠️ Stderr length: {} chars", stderr.len());
+    
+    // 결과 결정
+    let final_output = if !stderr.is_empty() && exit_code != 0 {
+        // 실제 에러인 경우
+        return Err(format!("COMMAND_FAILED: {}", stderr.trim()));
+    } else if !stderr.is_empty() && !stdout.is_empty() {
+        // 경고가 있지만 성공한 경우
+        format!("{}\n[Warning: {}]", stdout.trim(), stderr.trim())
+    } else if stdout.is_empty() && stderr.is_empty() {
+        // 출력이 없는 성공적인 명령어
+        "Command executed successfully (no output)".to_string()
+    } else {
+        // 정상적인 출력
+        stdout.trim().to_string()
+    };
+
+    println!("✅ Command completed successfully");
+
+    // JSON 형태로 결과 반환 (FileCreator 패턴과 동일)
+    let mut result = json!({
+        "output": final_output,
+        "command": command,
+        "exitCode": exit_code,
+        "hasStderr": !stderr.is_empty(),
+        "outputLength": final_output.len()
+    });
+
+    // 너무 큰 출력은 보관소에 두고 미리보기 + 핸들만 전달
+    if let Some(stored) = value_store::offload(&final_output) {
+        result["output"] = json!(stored.preview);
+        result["outputHandle"] = json!(stored.handle);
+        result["truncated"] = json!(true);
+    }
+
+    Ok(result.to_string())
+}
+
+// Windows와 Unix 계열 운영체제에 따라 다른 명령어 실행
+fn run_shell(command: &str, priority: os_command::ProcessPriority) -> Result<(String, String, i32), String> {
+    let child = if cfg!(target_os = "windows") {
         #[cfg(target_os = "windows")]
         {
             Command::new("cmd")
                 .raw_arg("/C")
-                .raw_arg(&command)
-                .output()
+                .raw_arg(command)
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped())
+                .spawn()
         }
         #[cfg(not(target_os = "windows"))]
         {
@@ -42,53 +97,57 @@ pub fn cli_node(command: String) -> Result<String, String> {
         }
     } else {
         Command::new("sh")
-            .args(["-c", &command])
-            .output()
+            .args(["-c", command])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
     };
 
-    match output {
-        Ok(output) => {
-            let stdout = String::from_utf8_lossy(&output.stdout);
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            let exit_code = output.status.code().unwrap_or(-1);
-            
-            // 디버깅 정보 출력
-            println!("📋 Command executed: {}", command);
-            println!("📤 Exit code: {}", exit_code);
-            println!("📜 Stdout length: {} chars", stdout.len());
-            println!("⚠️ Stderr length: {} chars", stderr.len());
-            
-            // 결과 결정
-            let final_output = if !stderr.is_empty() && exit_code != 0 {
-                // 실제 에러인 경우
-                return Err(format!("COMMAND_FAILED: {}", stderr.trim()));
-            } else if !stderr.is_empty() && !stdout.is_empty() {
-                // 경고가 있지만 성공한 경우
-                format!("{}\n[Warning: {}]", stdout.trim(), stderr.trim())
-            } else if stdout.is_empty() && stderr.is_empty() {
-                // 출력이 없는 성공적인 명령어
-                "Command executed successfully (no output)".to_string()
-            } else {
-                // 정상적인 출력
-                stdout.trim().to_string()
-            };
-
-            println!("✅ Command completed successfully");
-
-            // JSON 형태로 결과 반환 (FileCreator 패턴과 동일)
-            let result = json!({
-                "output": final_output,
-                "command": command,
-                "exitCode": exit_code,
-                "hasStderr": !stderr.is_empty(),
-                "outputLength": final_output.len()
-            });
-
-            Ok(result.to_string())
+    let child = match child {
+        Ok(child) => child,
+        Err(e) => {
+            println!("❌ CLI command execution failed: {}", e);
+            return Err(format!("EXECUTION_ERROR: {}", e));
         }
+    };
+
+    // 실행 중 리소스 한도 감시
+    priority.apply(Some(child.id()));
+    let guard = resource_guard::watch(Some(child.id()), "cli_node");
+    let output = child.wait_with_output();
+    guard.check()?;
+
+    match output {
+        Ok(output) => Ok((
+            String::from_utf8_lossy(&output.stdout).to_string(),
+            String::from_utf8_lossy(&output.stderr).to_string(),
+            output.status.code().unwrap_or(-1),
+        )),
         Err(e) => {
             println!("❌ CLI command execution failed: {}", e);
             Err(format!("EXECUTION_ERROR: {}", e))
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::parse_output;
+
+    #[test]
+    fn cli_node_returns_structured_output() {
+        let output = cli_node("echo hello".to_string(), None, None).unwrap();
+        let value = parse_output(&output);
+
+        assert_eq!(value["output"], "hello");
+        assert_eq!(value["exitCode"], 0);
+    }
+
+    #[test]
+    fn cli_node_blocks_dangerous_commands() {
+        let result = cli_node("rm -rf /tmp/nothing".to_string(), None, None);
+
+        assert!(result.unwrap_err().starts_with("DANGEROUS_COMMAND_BLOCKED"));
+    }
+}
@@ -0,0 +1,90 @@
+// src-tauri/src/nodes/clipboard_image_node.rs
+use super::safe_path;
+use base64::{engine::general_purpose, Engine as _};
+use image::{DynamicImage, ImageOutputFormat, RgbaImage};
+use serde_json::json;
+use std::io::Cursor;
+
+// 📋 클립보드 이미지 저장 노드
+// 클립보드의 이미지(스크린샷 등)를 PNG/JPEG 파일로 저장하거나 base64 로 반환
+// "스크린샷 → OCR → AI" 같은 흐름에서 수동 저장 단계를 없애기 위함
+
+fn read_clipboard_image() -> Result<DynamicImage, String> {
+    let mut clipboard =
+        arboard::Clipboard::new().map_err(|e| format!("클립보드 접근 실패: {}", e))?;
+    let data = clipboard.get_image().map_err(|e| match e {
+        arboard::Error::ContentNotAvailable => "NO_IMAGE_IN_CLIPBOARD".to_string(),
+        other => format!("클립보드 이미지 읽기 실패: {}", other),
+    })?;
+
+    RgbaImage::from_raw(
+        data.width as u32,
+        data.height as u32,
+        data.bytes.into_owned(),
+    )
+    .map(DynamicImage::ImageRgba8)
+    .ok_or_else(|| "INVALID_CLIPBOARD_IMAGE".to_string())
+}
+
+fn encode_image(image: &DynamicImage, format: &str) -> Result<Vec<u8>, String> {
+    let mut bytes = Vec::new();
+    let result = match format {
+        "png" => image.write_to(&mut Cursor::new(&mut bytes), ImageOutputFormat::Png),
+        // JPEG 은 알파 채널이 없으므로 RGB 로 변환
+        "jpeg" => DynamicImage::ImageRgb8(image.to_rgb8())
+            .write_to(&mut Cursor::new(&mut bytes), ImageOutputFormat::Jpeg(90)),
+        other => return Err(format!("UNSUPPORTED_FORMAT: {}", other)),
+    };
+    result.map_err(|e| format!("이미지 인코딩 실패: {}", e))?;
+    Ok(bytes)
+}
+
+#[tauri::command]
+pub async fn clipboard_image_node(
+    output_path: Option<String>,
+    format: Option<String>,
+) -> Result<String, String> {
+    let output_path = output_path
+        .map(|p| p.trim().to_string())
+        .filter(|p| !p.is_empty());
+
+    // 형식: 지정값 → 출력 파일 확장자 → png
+    let format = format
+        .map(|f| f.trim().to_lowercase())
+        .filter(|f| !f.is_empty())
+        .or_else(|| {
+            output_path.as_ref().and_then(|p| {
+                std::path::Path::new(p)
+                    .extension()
+                    .map(|e| e.to_string_lossy().to_lowercase())
+            })
+        })
+        .map(|f| if f == "jpg" { "jpeg".to_string() } else { f })
+        .unwrap_or_else(|| "png".to_string());
+
+    println!("📋 Clipboard Image Node: {:?} ({})", output_path, format);
+
+    let (bytes, width, height) = tokio::task::spawn_blocking(move || {
+        let image = read_clipboard_image()?;
+        let bytes = encode_image(&image, &format)?;
+        Ok::<_, String>((bytes, image.width(), image.height()))
+    })
+    .await
+    .map_err(|e| format!("클립보드 이미지 처리 실패: {}", e))??;
+
+    match output_path {
+        Some(path) => {
+            let path = safe_path::prepare_new(&path)?.to_string_lossy().to_string();
+            super::file_creator_node::ensure_parent_dir(&path)?;
+            std::fs::write(&path, &bytes).map_err(|_| "FILE_CREATE_ERROR".to_string())?;
+            println!("✅ 클립보드 이미지 저장: {} ({}x{})", path, width, height);
+            Ok(json!({ "file_path": path, "width": width, "height": height }).to_string())
+        }
+        None => Ok(json!({
+            "image_base64": general_purpose::STANDARD.encode(&bytes),
+            "width": width,
+            "height": height
+        })
+        .to_string()),
+    }
+}
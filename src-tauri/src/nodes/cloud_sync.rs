@@ -0,0 +1,815 @@
+// src-tauri/src/nodes/cloud_sync.rs
+use super::{atomic_store, control_page, disk_preflight, secrets, worker_pool};
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Component, Path, PathBuf};
+use tauri::{AppHandle, Emitter};
+
+// ☁️ 클라우드 동기화 (종단간 암호화, 선택 기능)
+// 사용자가 지정한 WebDAV 폴더 또는 S3 호환 버킷에 워크플로우 / 변수 / 설정을 올려 여러 PC 에서 공유
+// - 모든 파일은 동기화 암호(키체인 보관)로 PBKDF2 → ChaCha20-Poly1305 암호화해서 올림 (서버는 내용/파일명을 모름)
+// - 원격에는 manifest.enc(경로 → 해시/블롭 이름) + blob-<임의값>.enc 만 저장
+// - 마지막 동기화 시점 해시(store/cloud_sync_state.json)와 비교해 양쪽이 모두 바뀐 파일은 충돌로 남기고
+//   "cloud-sync-conflict" 이벤트로 알림 → sync_now 의 resolve 로 "local" / "remote" 선택
+// - 동기화 도중 다른 PC 가 manifest 를 바꾸면 덮어쓰지 않고 CLOUD_SYNC_REMOTE_CHANGED (다시 시도하면 병합됨)
+// 설정은 store/cloud_sync_settings.json, 암호와 WebDAV 비밀번호 / S3 secret key 는 비밀값 관리에 저장
+
+const PASSPHRASE_SECRET: &str = "cloud_sync.passphrase";
+const CREDENTIAL_SECRET: &str = "cloud_sync.credential";
+const MANIFEST_NAME: &str = "manifest.enc";
+const VARIABLES_KEY: &str = "variables.json";
+const MAGIC: &[u8] = b"TVCS1";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+const PBKDF2_ROUNDS: u32 = 100_000;
+const MAX_OBJECT_BYTES: usize = 50 * 1024 * 1024;
+// PC 마다 다른 값(경로, 동기화 설정 자체)은 올리지 않음
+const EXCLUDED_SETTINGS: &[&str] = &["cloud_sync_settings.json", "git_sync_settings.json"];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CloudSyncSettings {
+    pub enabled: bool,
+    // "webdav" | "s3"
+    pub backend: String,
+    // WebDAV 폴더 URL 또는 S3 엔드포인트 (https://s3.<region>.amazonaws.com 등)
+    pub endpoint: String,
+    // WebDAV 사용자 이름 / S3 access key
+    pub username: Option<String>,
+    pub bucket: Option<String>,
+    pub region: String,
+    pub prefix: String,
+    pub sync_workflows: bool,
+    pub sync_variables: bool,
+    pub sync_settings: bool,
+}
+
+impl Default for CloudSyncSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            backend: "webdav".to_string(),
+            endpoint: String::new(),
+            username: None,
+            bucket: None,
+            region: "us-east-1".to_string(),
+            prefix: "automation-gui".to_string(),
+            sync_workflows: true,
+            sync_variables: true,
+            sync_settings: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteEntry {
+    pub hash: String,
+    pub blob: String,
+    pub device: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RemoteManifest {
+    pub revision: u64,
+    #[serde(default)]
+    pub files: BTreeMap<String, RemoteEntry>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SyncAction {
+    Unchanged,
+    Upload,
+    Download,
+    DeleteRemote,
+    DeleteLocal,
+    Conflict,
+}
+
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct CloudSyncReport {
+    pub uploaded: Vec<String>,
+    pub downloaded: Vec<String>,
+    pub deleted_remote: Vec<String>,
+    pub deleted_local: Vec<String>,
+    pub conflicts: Vec<String>,
+    pub revision: u64,
+}
+
+fn settings_path() -> PathBuf {
+    atomic_store::store_dir().join("cloud_sync_settings.json")
+}
+
+fn state_path() -> PathBuf {
+    atomic_store::store_dir().join("cloud_sync_state.json")
+}
+
+pub fn load_settings() -> CloudSyncSettings {
+    atomic_store::read_json_verified(&settings_path()).unwrap_or_default()
+}
+
+fn device_name() -> String {
+    sysinfo::System::host_name().unwrap_or_else(|| "unknown".to_string())
+}
+
+fn sha256_hex(data: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(data))
+}
+
+// ===== 암호화 =====
+
+pub fn derive_key(passphrase: &str, salt: &[u8]) -> [u8; 32] {
+    let mut key = [0u8; 32];
+    pbkdf2::pbkdf2_hmac::<Sha256>(passphrase.as_bytes(), salt, PBKDF2_ROUNDS, &mut key);
+    key
+}
+
+// 형식: MAGIC | salt(16) | nonce(12) | 암호문+태그
+// 솔트별로 키를 한 번만 유도해 재사용 (PBKDF2 가 느리기 때문)
+pub struct SyncCipher {
+    passphrase: String,
+    salt: [u8; SALT_LEN],
+    keys: HashMap<[u8; SALT_LEN], [u8; 32]>,
+}
+
+impl SyncCipher {
+    pub fn new(passphrase: &str) -> Self {
+        let mut salt = [0u8; SALT_LEN];
+        rand::thread_rng().fill_bytes(&mut salt);
+        let mut keys = HashMap::new();
+        keys.insert(salt, derive_key(passphrase, &salt));
+        Self {
+            passphrase: passphrase.to_string(),
+            salt,
+            keys,
+        }
+    }
+
+    pub fn encrypt(&self, plain: &[u8]) -> Result<Vec<u8>, String> {
+        let mut nonce = [0u8; NONCE_LEN];
+        rand::thread_rng().fill_bytes(&mut nonce);
+        let cipher = ChaCha20Poly1305::new(Key::from_slice(&self.keys[&self.salt]));
+        let sealed = cipher
+            .encrypt(Nonce::from_slice(&nonce), plain)
+            .map_err(|_| "CLOUD_SYNC_ENCRYPT_FAILED".to_string())?;
+        let mut out = Vec::with_capacity(MAGIC.len() + SALT_LEN + NONCE_LEN + sealed.len());
+        out.extend_from_slice(MAGIC);
+        out.extend_from_slice(&self.salt);
+        out.extend_from_slice(&nonce);
+        out.extend_from_slice(&sealed);
+        Ok(out)
+    }
+
+    // 암호가 틀렸거나 내용이 변조되면 CLOUD_SYNC_DECRYPT_FAILED
+    pub fn decrypt(&mut self, data: &[u8]) -> Result<Vec<u8>, String> {
+        let body = data
+            .strip_prefix(MAGIC)
+            .filter(|body| body.len() > SALT_LEN + NONCE_LEN)
+            .ok_or("CLOUD_SYNC_INVALID_OBJECT")?;
+        let (salt, rest) = body.split_at(SALT_LEN);
+        let (nonce, sealed) = rest.split_at(NONCE_LEN);
+        let salt: [u8; SALT_LEN] = salt.try_into().map_err(|_| "CLOUD_SYNC_INVALID_OBJECT")?;
+        let passphrase = &self.passphrase;
+        let key = self
+            .keys
+            .entry(salt)
+            .or_insert_with(|| derive_key(passphrase, &salt));
+        ChaCha20Poly1305::new(Key::from_slice(key))
+            .decrypt(Nonce::from_slice(nonce), sealed)
+            .map_err(|_| "CLOUD_SYNC_DECRYPT_FAILED".to_string())
+    }
+}
+
+// ===== 동기화 계획 =====
+
+// local / remote / base: 현재 로컬, 원격, 마지막 동기화 시점의 해시
+pub fn plan_action(local: Option<&str>, remote: Option<&str>, base: Option<&str>) -> SyncAction {
+    if local == remote {
+        return SyncAction::Unchanged;
+    }
+    match (local != base, remote != base) {
+        (true, false) if local.is_none() => SyncAction::DeleteRemote,
+        (true, false) => SyncAction::Upload,
+        (false, true) if remote.is_none() => SyncAction::DeleteLocal,
+        (false, true) => SyncAction::Download,
+        _ => SyncAction::Conflict,
+    }
+}
+
+fn resolve_conflict(choice: Option<&str>, local: Option<&str>, remote: Option<&str>) -> SyncAction {
+    match choice {
+        Some("local") if local.is_some() => SyncAction::Upload,
+        Some("local") => SyncAction::DeleteRemote,
+        Some("remote") if remote.is_some() => SyncAction::Download,
+        Some("remote") => SyncAction::DeleteLocal,
+        _ => SyncAction::Conflict,
+    }
+}
+
+// 동기화 키 → 로컬 경로 (원격에서 온 키가 store 밖을 가리키지 못하도록 검사)
+pub fn local_path(store: &Path, workflows: &Path, key: &str) -> Option<PathBuf> {
+    let (root, relative) = if key == VARIABLES_KEY {
+        (store, key)
+    } else if let Some(relative) = key.strip_prefix("workflows/") {
+        (workflows, relative)
+    } else if let Some(name) = key.strip_prefix("settings/") {
+        if name.contains('/') || !name.ends_with("_settings.json") || EXCLUDED_SETTINGS.contains(&name)
+        {
+            return None;
+        }
+        (store, name)
+    } else {
+        return None;
+    };
+    let relative = Path::new(relative);
+    let is_safe = relative
+        .components()
+        .all(|c| matches!(c, Component::Normal(_)));
+    let is_json = relative
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+    (is_safe && is_json).then(|| root.join(relative))
+}
+
+// 동기화 대상 로컬 파일 (키 → 경로)
+pub fn local_files(
+    settings: &CloudSyncSettings,
+    store: &Path,
+    workflows: &Path,
+) -> BTreeMap<String, PathBuf> {
+    let mut files = BTreeMap::new();
+    if settings.sync_workflows {
+        for entry in walkdir::WalkDir::new(workflows)
+            .max_depth(3)
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file())
+        {
+            let Ok(relative) = entry.path().strip_prefix(workflows) else {
+                continue;
+            };
+            let key = format!("workflows/{}", relative.to_string_lossy().replace('\\', "/"));
+            if local_path(store, workflows, &key).is_some() {
+                files.insert(key, entry.path().to_path_buf());
+            }
+        }
+    }
+    if settings.sync_variables && store.join(VARIABLES_KEY).is_file() {
+        files.insert(VARIABLES_KEY.to_string(), store.join(VARIABLES_KEY));
+    }
+    if settings.sync_settings {
+        if let Ok(entries) = std::fs::read_dir(store) {
+            for entry in entries.filter_map(|e| e.ok()) {
+                let key = format!("settings/{}", entry.file_name().to_string_lossy());
+                if entry.path().is_file() && local_path(store, workflows, &key).is_some() {
+                    files.insert(key, entry.path());
+                }
+            }
+        }
+    }
+    files
+}
+
+fn is_enabled_key(settings: &CloudSyncSettings, key: &str) -> bool {
+    if key.starts_with("workflows/") {
+        settings.sync_workflows
+    } else if key.starts_with("settings/") {
+        settings.sync_settings
+    } else {
+        settings.sync_variables
+    }
+}
+
+// store 파일은 체크섬과 함께 저장 (read_json_verified 로 읽히도록)
+fn write_local(key: &str, path: &Path, data: &[u8]) -> Result<(), String> {
+    if key.starts_with("workflows/") {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|_| "DIRECTORY_CREATE_ERROR".to_string())?;
+        }
+        std::fs::write(path, data).map_err(|e| format!("Failed to write file: {}", e))
+    } else {
+        atomic_store::write_atomic(path, data)
+    }
+}
+
+fn remove_local(key: &str, path: &Path) -> Result<(), String> {
+    if key.starts_with("workflows/") {
+        match std::fs::remove_file(path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                Err(format!("Failed to remove file: {}", e))
+            }
+            _ => Ok(()),
+        }
+    } else {
+        atomic_store::remove_with_sidecars(path)
+    }
+}
+
+// ===== 원격 저장소 (WebDAV / S3) =====
+
+type HmacSha256 = Hmac<Sha256>;
+
+fn hmac_sha256(key: &[u8], data: &str) -> Vec<u8> {
+    let mut mac = <HmacSha256 as Mac>::new_from_slice(key).expect("HMAC accepts any key length");
+    mac.update(data.as_bytes());
+    mac.finalize().into_bytes().to_vec()
+}
+
+// AWS Signature V4 (host, x-amz-content-sha256, x-amz-date 헤더만 서명)
+#[allow(clippy::too_many_arguments)]
+pub fn sigv4_authorization(
+    method: &str,
+    host: &str,
+    path: &str,
+    amz_date: &str,
+    region: &str,
+    access_key: &str,
+    secret_key: &str,
+    payload_hash: &str,
+) -> String {
+    let date = &amz_date[..8];
+    let signed_headers = "host;x-amz-content-sha256;x-amz-date";
+    let canonical_request = format!(
+        "{}\n{}\n\nhost:{}\nx-amz-content-sha256:{}\nx-amz-date:{}\n\n{}\n{}",
+        method, path, host, payload_hash, amz_date, signed_headers, payload_hash
+    );
+    let scope = format!("{}/{}/s3/aws4_request", date, region);
+    let string_to_sign = format!(
+        "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+        amz_date,
+        scope,
+        sha256_hex(canonical_request.as_bytes())
+    );
+    let mut key = hmac_sha256(format!("AWS4{}", secret_key).as_bytes(), date);
+    for part in [region, "s3", "aws4_request"] {
+        key = hmac_sha256(&key, part);
+    }
+    let signature: String = hmac_sha256(&key, &string_to_sign)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    format!(
+        "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+        access_key, scope, signed_headers, signature
+    )
+}
+
+struct RemoteStore {
+    client: reqwest::Client,
+    settings: CloudSyncSettings,
+    credential: Option<String>,
+}
+
+impl RemoteStore {
+    fn new(settings: &CloudSyncSettings, credential: Option<String>) -> Result<Self, String> {
+        if settings.endpoint.trim().is_empty() {
+            return Err("CLOUD_SYNC_ENDPOINT_NOT_CONFIGURED".to_string());
+        }
+        if settings.backend == "s3" && (settings.username.is_none() || credential.is_none()) {
+            return Err("CLOUD_SYNC_CREDENTIALS_REQUIRED".to_string());
+        }
+        let client = reqwest::Client::builder()
+            .user_agent("automation-gui-sync/1.0")
+            .timeout(std::time::Duration::from_secs(120))
+            .build()
+            .map_err(|e| e.to_string())?;
+        Ok(Self {
+            client,
+            settings: settings.clone(),
+            credential,
+        })
+    }
+
+    fn prefix_segments(&self) -> Vec<&str> {
+        self.settings
+            .prefix
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .collect()
+    }
+
+    fn url(&self, name: &str) -> Result<url::Url, String> {
+        let mut segments: Vec<&str> = Vec::new();
+        if self.settings.backend == "s3" {
+            segments.push(
+                self.settings
+                    .bucket
+                    .as_deref()
+                    .filter(|b| !b.trim().is_empty())
+                    .ok_or("CLOUD_SYNC_BUCKET_REQUIRED")?,
+            );
+        }
+        segments.extend(self.prefix_segments());
+        if !name.is_empty() {
+            segments.push(name);
+        }
+        let mut url = url::Url::parse(self.settings.endpoint.trim())
+            .map_err(|e| format!("INVALID_CLOUD_SYNC_ENDPOINT: {}", e))?;
+        url.path_segments_mut()
+            .map_err(|_| "INVALID_CLOUD_SYNC_ENDPOINT".to_string())?
+            .pop_if_empty()
+            .extend(segments);
+        Ok(url)
+    }
+
+    fn request(
+        &self,
+        method: reqwest::Method,
+        url: url::Url,
+        body: Option<Vec<u8>>,
+    ) -> reqwest::RequestBuilder {
+        let mut builder = self.client.request(method.clone(), url.clone());
+        if self.settings.backend == "s3" {
+            let payload_hash = sha256_hex(body.as_deref().unwrap_or_default());
+            let amz_date = chrono::Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+            let host = match url.port() {
+                Some(port) => format!("{}:{}", url.host_str().unwrap_or_default(), port),
+                None => url.host_str().unwrap_or_default().to_string(),
+            };
+            let authorization = sigv4_authorization(
+                method.as_str(),
+                &host,
+                url.path(),
+                &amz_date,
+                &self.settings.region,
+                self.settings.username.as_deref().unwrap_or_default(),
+                self.credential.as_deref().unwrap_or_default(),
+                &payload_hash,
+            );
+            builder = builder
+                .header("x-amz-content-sha256", payload_hash)
+                .header("x-amz-date", amz_date)
+                .header("Authorization", authorization);
+        } else if let Some(user) = &self.settings.username {
+            builder = builder.basic_auth(user, self.credential.as_deref());
+        }
+        match body {
+            Some(body) => builder.body(body),
+            None => builder,
+        }
+    }
+
+    // WebDAV 는 prefix 폴더를 미리 만들어야 PUT 가능 (이미 있으면 405)
+    async fn prepare(&self) -> Result<(), String> {
+        if self.settings.backend == "s3" {
+            return Ok(());
+        }
+        let mkcol = reqwest::Method::from_bytes(b"MKCOL").map_err(|e| e.to_string())?;
+        let mut url = url::Url::parse(self.settings.endpoint.trim())
+            .map_err(|e| format!("INVALID_CLOUD_SYNC_ENDPOINT: {}", e))?;
+        for segment in self.prefix_segments() {
+            url.path_segments_mut()
+                .map_err(|_| "INVALID_CLOUD_SYNC_ENDPOINT".to_string())?
+                .pop_if_empty()
+                .push(segment);
+            let response = self
+                .request(mkcol.clone(), url.clone(), None)
+                .send()
+                .await
+                .map_err(|e| format!("클라우드 연결 실패: {}", e))?;
+            let status = response.status();
+            if !status.is_success() && status.as_u16() != 405 {
+                return Err(format!("CLOUD_SYNC_HTTP_ERROR: MKCOL {}", status));
+            }
+        }
+        Ok(())
+    }
+
+    async fn get(&self, name: &str) -> Result<Option<Vec<u8>>, String> {
+        let response = self
+            .request(reqwest::Method::GET, self.url(name)?, None)
+            .send()
+            .await
+            .map_err(|e| format!("클라우드 연결 실패: {}", e))?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !response.status().is_success() {
+            return Err(format!("CLOUD_SYNC_HTTP_ERROR: GET {}", response.status()));
+        }
+        let bytes = response
+            .bytes()
+            .await
+            .map_err(|e| format!("클라우드 읽기 실패: {}", e))?;
+        if bytes.len() > MAX_OBJECT_BYTES {
+            return Err("CLOUD_SYNC_OBJECT_TOO_LARGE".to_string());
+        }
+        Ok(Some(bytes.to_vec()))
+    }
+
+    async fn put(&self, name: &str, data: Vec<u8>) -> Result<(), String> {
+        let response = self
+            .request(reqwest::Method::PUT, self.url(name)?, Some(data))
+            .send()
+            .await
+            .map_err(|e| format!("클라우드 연결 실패: {}", e))?;
+        if !response.status().is_success() {
+            return Err(format!("CLOUD_SYNC_HTTP_ERROR: PUT {}", response.status()));
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, name: &str) -> Result<(), String> {
+        let response = self
+            .request(reqwest::Method::DELETE, self.url(name)?, None)
+            .send()
+            .await
+            .map_err(|e| format!("클라우드 연결 실패: {}", e))?;
+        let status = response.status();
+        if !status.is_success() && status != reqwest::StatusCode::NOT_FOUND {
+            return Err(format!("CLOUD_SYNC_HTTP_ERROR: DELETE {}", status));
+        }
+        Ok(())
+    }
+
+    async fn manifest(&self, cipher: &mut SyncCipher) -> Result<RemoteManifest, String> {
+        match self.get(MANIFEST_NAME).await? {
+            Some(data) => serde_json::from_slice(&cipher.decrypt(&data)?)
+                .map_err(|e| format!("CLOUD_SYNC_INVALID_MANIFEST: {}", e)),
+            None => Ok(RemoteManifest::default()),
+        }
+    }
+}
+
+fn new_blob_name() -> String {
+    let mut id = [0u8; 16];
+    rand::thread_rng().fill_bytes(&mut id);
+    let id: String = id.iter().map(|b| format!("{:02x}", b)).collect();
+    format!("blob-{}.enc", id)
+}
+
+// ===== 동기화 =====
+
+pub async fn sync(
+    settings: &CloudSyncSettings,
+    passphrase: &str,
+    credential: Option<String>,
+    resolve: &BTreeMap<String, String>,
+) -> Result<CloudSyncReport, String> {
+    let remote = RemoteStore::new(settings, credential)?;
+    remote.prepare().await?;
+
+    let store = atomic_store::store_dir();
+    let workflows = control_page::default_workflows_dir();
+    let mut cipher = SyncCipher::new(passphrase);
+    let mut manifest = remote.manifest(&mut cipher).await?;
+    let start_revision = manifest.revision;
+    let base: BTreeMap<String, String> =
+        atomic_store::read_json_verified(&state_path()).unwrap_or_default();
+
+    // ⚙️ 로컬 파일 해시는 공용 워커 풀에서 병렬 계산
+    let (keys, paths): (Vec<String>, Vec<PathBuf>) =
+        local_files(settings, &store, &workflows).into_iter().unzip();
+    let hashes = worker_pool::run_blocking(move || worker_pool::hash_files(paths)).await??;
+    let mut local = BTreeMap::new();
+    for (key, hashed) in keys.into_iter().zip(hashes) {
+        let (hash, _) = hashed.map_err(|e| format!("Failed to read file: {}", e))?;
+        local.insert(key, hash);
+    }
+
+    let keys: BTreeSet<String> = local
+        .keys()
+        .chain(manifest.files.keys())
+        .chain(base.keys())
+        .filter(|key| is_enabled_key(settings, key))
+        .cloned()
+        .collect();
+
+    let device = device_name();
+    let mut report = CloudSyncReport::default();
+    let mut new_base = BTreeMap::new();
+    let mut stale_blobs = Vec::new();
+
+    for key in keys {
+        let Some(path) = local_path(&store, &workflows, &key) else {
+            continue;
+        };
+        let local_hash = local.get(&key).map(String::as_str);
+        let remote_hash = manifest.files.get(&key).map(|e| e.hash.clone());
+        let base_hash = base.get(&key).map(String::as_str);
+        let mut action = plan_action(local_hash, remote_hash.as_deref(), base_hash);
+        if action == SyncAction::Conflict {
+            action = resolve_conflict(
+                resolve.get(&key).map(String::as_str),
+                local_hash,
+                remote_hash.as_deref(),
+            );
+        }
+
+        match action {
+            SyncAction::Unchanged => {
+                if let Some(hash) = local_hash {
+                    new_base.insert(key, hash.to_string());
+                }
+            }
+            SyncAction::Upload => {
+                let data =
+                    std::fs::read(&path).map_err(|e| format!("Failed to read file: {}", e))?;
+                let hash = sha256_hex(&data);
+                let blob = new_blob_name();
+                remote.put(&blob, cipher.encrypt(&data)?).await?;
+                let entry = RemoteEntry {
+                    hash: hash.clone(),
+                    blob,
+                    device: device.clone(),
+                    updated_at: chrono::Local::now().to_rfc3339(),
+                };
+                if let Some(old) = manifest.files.insert(key.clone(), entry) {
+                    stale_blobs.push(old.blob);
+                }
+                new_base.insert(key.clone(), hash);
+                report.uploaded.push(key);
+            }
+            SyncAction::Download => {
+                let entry = manifest.files[&key].clone();
+                let data = remote
+                    .get(&entry.blob)
+                    .await?
+                    .ok_or_else(|| format!("CLOUD_SYNC_BLOB_MISSING: {}", key))?;
+                let plain = cipher.decrypt(&data)?;
+                if sha256_hex(&plain) != entry.hash {
+                    return Err(format!("CLOUD_SYNC_HASH_MISMATCH: {}", key));
+                }
+                disk_preflight::ensure_free_space(&path, plain.len() as u64)?;
+                write_local(&key, &path, &plain)?;
+                new_base.insert(key.clone(), entry.hash);
+                report.downloaded.push(key);
+            }
+            SyncAction::DeleteRemote => {
+                if let Some(old) = manifest.files.remove(&key) {
+                    stale_blobs.push(old.blob);
+                }
+                report.deleted_remote.push(key);
+            }
+            SyncAction::DeleteLocal => {
+                remove_local(&key, &path)?;
+                report.deleted_local.push(key);
+            }
+            SyncAction::Conflict => {
+                // 기준 해시를 그대로 둬서 다음 동기화에서도 충돌로 남음
+                if let Some(hash) = base_hash {
+                    new_base.insert(key.clone(), hash.to_string());
+                }
+                report.conflicts.push(key);
+            }
+        }
+    }
+
+    if !report.uploaded.is_empty() || !report.deleted_remote.is_empty() {
+        // 그 사이 다른 PC 가 동기화했으면 덮어쓰지 않음
+        if remote.manifest(&mut cipher).await?.revision != start_revision {
+            return Err("CLOUD_SYNC_REMOTE_CHANGED".to_string());
+        }
+        manifest.revision += 1;
+        let data = serde_json::to_vec(&manifest).map_err(|e| e.to_string())?;
+        remote.put(MANIFEST_NAME, cipher.encrypt(&data)?).await?;
+        for blob in stale_blobs {
+            let _ = remote.delete(&blob).await;
+        }
+    }
+    atomic_store::write_json_atomic(&state_path(), &new_base)?;
+    report.revision = manifest.revision;
+    Ok(report)
+}
+
+#[tauri::command]
+pub fn get_cloud_sync_settings() -> Result<String, String> {
+    let settings = load_settings();
+    let stored = secrets::list_secrets()?;
+    let configured = |name: &str| stored.iter().any(|s| s.name == name);
+    Ok(serde_json::json!({
+        "settings": settings,
+        "passphrase_set": configured(PASSPHRASE_SECRET),
+        "credential_set": configured(CREDENTIAL_SECRET)
+    })
+    .to_string())
+}
+
+// 암호 / 비밀번호는 주어진 경우에만 바꿈 (빈 문자열이면 삭제)
+#[tauri::command]
+pub fn set_cloud_sync_settings(
+    settings: CloudSyncSettings,
+    passphrase: Option<String>,
+    credential: Option<String>,
+) -> Result<String, String> {
+    if !matches!(settings.backend.as_str(), "webdav" | "s3") {
+        return Err(format!("UNSUPPORTED_CLOUD_SYNC_BACKEND: {}", settings.backend));
+    }
+    if !settings.endpoint.trim().is_empty() {
+        url::Url::parse(settings.endpoint.trim())
+            .map_err(|e| format!("INVALID_CLOUD_SYNC_ENDPOINT: {}", e))?;
+    }
+    for (name, value) in [(PASSPHRASE_SECRET, passphrase), (CREDENTIAL_SECRET, credential)] {
+        match value {
+            Some(value) if value.is_empty() => secrets::delete(name)?,
+            Some(value) => secrets::set(name, &value)?,
+            None => {}
+        }
+    }
+    atomic_store::write_json_atomic(&settings_path(), &settings)?;
+    Ok("SUCCESS".to_string())
+}
+
+// resolve: 충돌 파일별 선택 { "workflows/a.json": "local" | "remote" }
+#[tauri::command]
+pub async fn sync_now(
+    app_handle: AppHandle,
+    resolve: Option<BTreeMap<String, String>>,
+) -> Result<CloudSyncReport, String> {
+    let settings = load_settings();
+    if !settings.enabled {
+        return Err("CLOUD_SYNC_DISABLED".to_string());
+    }
+    let passphrase = secrets::get(PASSPHRASE_SECRET)
+        .map_err(|_| "CLOUD_SYNC_PASSPHRASE_REQUIRED".to_string())?;
+    let credential = secrets::get(CREDENTIAL_SECRET).ok();
+    let result = sync(&settings, &passphrase, credential, &resolve.unwrap_or_default()).await;
+    match &result {
+        Ok(report) if !report.conflicts.is_empty() => {
+            println!("⚠️ 클라우드 동기화 충돌: {:?}", report.conflicts);
+            let _ = app_handle.emit("cloud-sync-conflict", report);
+        }
+        Ok(report) => println!(
+            "☁️ 클라우드 동기화: 올림 {}, 받음 {}, 원격 삭제 {}, 로컬 삭제 {} (rev {})",
+            report.uploaded.len(),
+            report.downloaded.len(),
+            report.deleted_remote.len(),
+            report.deleted_local.len(),
+            report.revision
+        ),
+        Err(e) => {
+            println!("⚠️ 클라우드 동기화 실패: {}", e);
+            let _ = app_handle.emit("cloud-sync-error", e);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cloud_sync_encrypts_and_rejects_wrong_passphrase() {
+        let cipher = SyncCipher::new("correct horse");
+        let sealed = cipher.encrypt(b"{\"v\":1}").unwrap();
+        assert!(!sealed.windows(7).any(|w| w == b"{\"v\":1}"));
+
+        let mut other_device = SyncCipher::new("correct horse");
+        assert_eq!(other_device.decrypt(&sealed).unwrap(), b"{\"v\":1}");
+
+        let mut wrong = SyncCipher::new("battery staple");
+        assert_eq!(
+            wrong.decrypt(&sealed),
+            Err("CLOUD_SYNC_DECRYPT_FAILED".to_string())
+        );
+        let mut tampered = sealed.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(other_device.decrypt(&tampered).is_err());
+    }
+
+    #[test]
+    fn cloud_sync_plans_actions_against_last_sync() {
+        use {plan_action, SyncAction::*};
+        let (a, b, c) = (Some("a"), Some("b"), Some("c"));
+        assert_eq!(plan_action(a, a, None), Unchanged);
+        assert_eq!(plan_action(b, a, a), Upload);
+        assert_eq!(plan_action(a, b, a), Download);
+        assert_eq!(plan_action(None, a, a), DeleteRemote);
+        assert_eq!(plan_action(a, None, a), DeleteLocal);
+        assert_eq!(plan_action(b, c, a), Conflict);
+        assert_eq!(plan_action(a, b, None), Conflict);
+    }
+
+    #[test]
+    fn cloud_sync_rejects_keys_outside_store() {
+        let store = std::path::Path::new("/data/store");
+        let workflows = store.join("workflows");
+        assert_eq!(
+            local_path(store, &workflows, "workflows/sub/a.flow.json"),
+            Some(workflows.join("sub").join("a.flow.json"))
+        );
+        assert_eq!(
+            local_path(store, &workflows, "settings/update_settings.json"),
+            Some(store.join("update_settings.json"))
+        );
+        assert_eq!(
+            local_path(store, &workflows, "variables.json"),
+            Some(store.join("variables.json"))
+        );
+        for key in [
+            "workflows/../secrets_index.json",
+            "workflows/a.txt",
+            "settings/cloud_sync_settings.json",
+            "settings/../x_settings.json",
+            "run_history.db",
+        ] {
+            assert_eq!(local_path(store, &workflows, key), None, "{}", key);
+        }
+    }
+}
@@ -0,0 +1,235 @@
+// src-tauri/src/nodes/color_node.rs
+use super::os_command;
+use image::GenericImageView;
+use serde_json::json;
+
+// 🎨 색상 노드
+// action: pixel(x, y) → 화면 좌표의 색상 / palette(image_path, count) → 이미지의 대표 색상
+
+fn to_hex(rgb: [u8; 3]) -> String {
+    format!("#{:02X}{:02X}{:02X}", rgb[0], rgb[1], rgb[2])
+}
+
+fn color_json(rgb: [u8; 3]) -> serde_json::Value {
+    json!({ "hex": to_hex(rgb), "r": rgb[0], "g": rgb[1], "b": rgb[2] })
+}
+
+// 화면 1x1 영역 캡처 후 색상 읽기
+fn sample_screen_pixel(x: i32, y: i32) -> Result<[u8; 3], String> {
+    if cfg!(target_os = "windows") {
+        let script = format!(
+            "Add-Type -AssemblyName System.Drawing\n\
+             $bmp = New-Object System.Drawing.Bitmap 1, 1\n\
+             $g = [System.Drawing.Graphics]::FromImage($bmp)\n\
+             $g.CopyFromScreen({}, {}, 0, 0, $bmp.Size)\n\
+             $c = $bmp.GetPixel(0, 0)\n\
+             \"$($c.R) $($c.G) $($c.B)\"",
+            x, y
+        );
+        let output = os_command::powershell(&script)?;
+        let values: Vec<u8> = output
+            .split_whitespace()
+            .filter_map(|v| v.parse().ok())
+            .collect();
+        return match values.as_slice() {
+            [r, g, b] => Ok([*r, *g, *b]),
+            _ => Err(format!("색상 읽기 실패: {}", output)),
+        };
+    }
+
+    let capture = std::env::temp_dir().join(format!("color_pick_{}.png", std::process::id()));
+    let capture_str = capture.to_string_lossy().to_string();
+    if cfg!(target_os = "macos") {
+        os_command::run(
+            "screencapture",
+            &["-x", "-R", &format!("{},{},1,1", x, y), &capture_str],
+        )?;
+    } else {
+        os_command::run(
+            "import",
+            &[
+                "-window",
+                "root",
+                "-crop",
+                &format!("1x1+{}+{}", x, y),
+                &capture_str,
+            ],
+        )?;
+    }
+
+    let image = image::open(&capture).map_err(|e| format!("캡처 이미지 읽기 실패: {}", e))?;
+    let _ = std::fs::remove_file(&capture);
+    let pixel = image.get_pixel(0, 0);
+    Ok([pixel[0], pixel[1], pixel[2]])
+}
+
+fn distance(a: [f32; 3], b: [f32; 3]) -> f32 {
+    (0..3).map(|i| (a[i] - b[i]).powi(2)).sum()
+}
+
+// 축소한 이미지에 k-means 를 돌려 비중이 큰 순서로 (색상, 비율) 반환
+pub fn dominant_colors(image: &image::DynamicImage, count: usize) -> Vec<([u8; 3], f32)> {
+    // 큰 이미지만 축소 (작은 이미지를 늘리면 경계에 섞인 색이 생김)
+    let small = if image.width() > 64 || image.height() > 64 {
+        image.thumbnail(64, 64).to_rgba8()
+    } else {
+        image.to_rgba8()
+    };
+    let pixels: Vec<[f32; 3]> = small
+        .pixels()
+        .filter(|p| p[3] >= 128) // 투명한 부분 제외
+        .map(|p| [p[0] as f32, p[1] as f32, p[2] as f32])
+        .collect();
+    if pixels.is_empty() || count == 0 {
+        return Vec::new();
+    }
+
+    // 초기 중심: 서로 멀리 떨어진 픽셀을 차례로 선택 (결과가 항상 같도록)
+    let mut centers = vec![pixels[0]];
+    while centers.len() < count.min(pixels.len()) {
+        let farthest = pixels
+            .iter()
+            .max_by(|a, b| {
+                let da = centers
+                    .iter()
+                    .map(|c| distance(**a, *c))
+                    .fold(f32::MAX, f32::min);
+                let db = centers
+                    .iter()
+                    .map(|c| distance(**b, *c))
+                    .fold(f32::MAX, f32::min);
+                da.total_cmp(&db)
+            })
+            .copied()
+            .unwrap_or(pixels[0]);
+        if centers.contains(&farthest) {
+            break;
+        }
+        centers.push(farthest);
+    }
+
+    let mut assignments = vec![0usize; pixels.len()];
+    for _ in 0..10 {
+        for (i, pixel) in pixels.iter().enumerate() {
+            assignments[i] = (0..centers.len())
+                .min_by(|a, b| {
+                    distance(*pixel, centers[*a]).total_cmp(&distance(*pixel, centers[*b]))
+                })
+                .unwrap_or(0);
+        }
+        for (c, center) in centers.iter_mut().enumerate() {
+            let members: Vec<&[f32; 3]> = pixels
+                .iter()
+                .zip(&assignments)
+                .filter(|(_, a)| **a == c)
+                .map(|(p, _)| p)
+                .collect();
+            if !members.is_empty() {
+                for channel in 0..3 {
+                    center[channel] =
+                        members.iter().map(|p| p[channel]).sum::<f32>() / members.len() as f32;
+                }
+            }
+        }
+    }
+
+    let mut result: Vec<([u8; 3], f32)> = centers
+        .iter()
+        .enumerate()
+        .map(|(c, center)| {
+            let share =
+                assignments.iter().filter(|a| **a == c).count() as f32 / pixels.len() as f32;
+            (
+                [
+                    center[0].round() as u8,
+                    center[1].round() as u8,
+                    center[2].round() as u8,
+                ],
+                share,
+            )
+        })
+        .filter(|(_, share)| *share > 0.0)
+        .collect();
+    result.sort_by(|a, b| b.1.total_cmp(&a.1));
+    result
+}
+
+#[tauri::command]
+pub async fn color_node(
+    action: String,
+    x: Option<i32>,
+    y: Option<i32>,
+    image_path: Option<String>,
+    count: Option<usize>,
+) -> Result<String, String> {
+    let action = action.trim().to_lowercase();
+    println!("🎨 Color Node: {}", action);
+
+    match action.as_str() {
+        "pixel" => {
+            let (x, y) = x.zip(y).ok_or("COORDINATES_REQUIRED")?;
+            let rgb = tokio::task::spawn_blocking(move || sample_screen_pixel(x, y))
+                .await
+                .map_err(|e| format!("색상 읽기 실패: {}", e))??;
+
+            let mut result = color_json(rgb);
+            result["x"] = json!(x);
+            result["y"] = json!(y);
+            Ok(result.to_string())
+        }
+        "palette" => {
+            let image_path = image_path
+                .map(|p| p.trim().to_string())
+                .filter(|p| !p.is_empty())
+                .ok_or("EMPTY_IMAGE_PATH")?;
+            let count = count.unwrap_or(5).clamp(1, 16);
+
+            let colors = tokio::task::spawn_blocking(move || {
+                let image =
+                    image::open(&image_path).map_err(|e| format!("IMAGE_LOAD_ERROR: {}", e))?;
+                Ok::<_, String>(dominant_colors(&image, count))
+            })
+            .await
+            .map_err(|e| format!("색상 추출 실패: {}", e))??;
+
+            let palette: Vec<_> = colors
+                .iter()
+                .map(|(rgb, share)| {
+                    let mut color = color_json(*rgb);
+                    color["share"] = json!((share * 1000.0).round() / 1000.0);
+                    color
+                })
+                .collect();
+            Ok(json!({
+                "dominant": palette.first().map(|c| c["hex"].clone()),
+                "palette": palette
+            })
+            .to_string())
+        }
+        other => Err(format!("UNSUPPORTED_ACTION: {}", other)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn color_palette_orders_colors_by_share() {
+        // 3/4 빨강, 1/4 파랑
+        let image = image::RgbImage::from_fn(40, 40, |x, _| {
+            if x < 30 {
+                image::Rgb([255, 0, 0])
+            } else {
+                image::Rgb([0, 0, 255])
+            }
+        });
+
+        let colors = dominant_colors(&image::DynamicImage::ImageRgb8(image), 3);
+
+        assert_eq!(colors.len(), 2);
+        assert_eq!(colors[0].0, [255, 0, 0]);
+        assert!((colors[0].1 - 0.75).abs() < 0.01);
+        assert_eq!(colors[1].0, [0, 0, 255]);
+    }
+}
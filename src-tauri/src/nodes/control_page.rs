@@ -0,0 +1,404 @@
+// src-tauri/src/nodes/control_page.rs
+use super::{atomic_store, instance, path_guard, run_history, ws_limits};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tauri::AppHandle;
+use warp::http::StatusCode;
+use warp::{Filter, Rejection, Reply};
+
+// 📱 모바일 원격 제어 페이지 (/control)
+// 워크플로우 폴더의 워크플로우 목록 + 실행 버튼 + 실시간 실행 상태
+// 토큰 인증 필수 (Authorization: Bearer <토큰> 또는 ?token=<토큰>) - 토큰을 설정하지 않으면 /control 은 404
+// 실행은 "instance-request" 이벤트로 앱의 실행 엔진에 전달 (trigger://run 과 같은 경로, 확인 창 없음)
+// 앱이 시작하지 못한 요청(다른 실행 중 / 파일 오류)은 실패한 실행으로 기록되어 실시간 상태에 표시
+
+pub struct ControlConfig {
+    pub token: Option<String>,
+    pub workflows_dir: PathBuf,
+}
+
+#[derive(Debug)]
+struct ControlUnauthorized;
+
+impl warp::reject::Reject for ControlUnauthorized {}
+
+#[derive(Debug, Deserialize)]
+struct RunRequest {
+    workflow: String,
+}
+
+pub fn default_workflows_dir() -> PathBuf {
+    atomic_store::store_dir().join("workflows")
+}
+
+pub(crate) fn is_workflow_file(path: &Path) -> bool {
+    path.is_file()
+        && path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_lowercase())
+            .is_some_and(|n| n.ends_with(".json"))
+}
+
+pub fn workflow_name(file_name: &str) -> &str {
+    file_name
+        .strip_suffix(".flow.json")
+        .or_else(|| file_name.strip_suffix(".json"))
+        .unwrap_or(file_name)
+}
+
+// 워크플로우 폴더(하위 폴더 포함) 목록 + 최근 실행 상태
+pub fn list_workflows(dir: &Path) -> Vec<Value> {
+    let recent = run_history::recent_runs(100);
+    let mut workflows: Vec<Value> = walkdir::WalkDir::new(dir)
+        .max_depth(3)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| is_workflow_file(e.path()))
+        .filter_map(|e| {
+            let relative = e.path().strip_prefix(dir).ok()?;
+            let relative = relative.to_string_lossy().replace('\\', "/");
+            let name = workflow_name(&e.file_name().to_string_lossy()).to_string();
+            let last_run = recent.iter().find(|run| run["workflow"] == name.as_str());
+            Some(json!({
+                "name": name,
+                "path": relative,
+                "last_status": last_run.map(|run| run["status"].clone()),
+                "last_run_at": last_run.map(|run| run["started_at"].clone()),
+            }))
+        })
+        .collect();
+    workflows.sort_by(|a, b| a["path"].as_str().cmp(&b["path"].as_str()));
+    workflows
+}
+
+// 길이가 같으면 끝까지 비교 (타이밍으로 토큰을 추측하지 못하게)
+pub fn token_matches(expected: &str, provided: &str) -> bool {
+    let (expected, provided) = (expected.as_bytes(), provided.as_bytes());
+    expected.len() == provided.len()
+        && expected
+            .iter()
+            .zip(provided)
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+pub fn provided_token(
+    authorization: Option<&str>,
+    query: &HashMap<String, String>,
+) -> Option<String> {
+    authorization
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(|token| token.trim().to_string())
+        .or_else(|| query.get("token").cloned())
+}
+
+pub fn authorized(
+    token: Option<Arc<String>>,
+) -> impl Filter<Extract = (), Error = Rejection> + Clone {
+    warp::header::optional::<String>("authorization")
+        .and(warp::query::<HashMap<String, String>>())
+        .and_then(
+            move |authorization: Option<String>, query: HashMap<String, String>| {
+                let token = token.clone();
+                async move {
+                    let Some(token) = token else {
+                        return Err(warp::reject::not_found());
+                    };
+                    match provided_token(authorization.as_deref(), &query) {
+                        Some(provided) if token_matches(&token, &provided) => Ok(()),
+                        _ => Err(warp::reject::custom(ControlUnauthorized)),
+                    }
+                }
+            },
+        )
+        .untuple_one()
+}
+
+fn run_workflow(app: &AppHandle, dir: &Path, request: RunRequest) -> Result<String, String> {
+    let path = path_guard::resolve_within(dir, &request.workflow)?;
+    if !is_workflow_file(&path) {
+        return Err("WORKFLOW_NOT_FOUND".to_string());
+    }
+    instance::request_workflow_run(app, &path)?;
+    println!("📱 원격 실행 요청: {}", path.display());
+    Ok(path.to_string_lossy().to_string())
+}
+
+// 실행 이벤트를 그대로 WebSocket 으로 중계 (입력은 무시)
+pub fn routes(
+    app_handle: AppHandle,
+    config: ControlConfig,
+) -> impl Filter<Extract = (Box<dyn Reply>,), Error = Rejection> + Clone {
+    let auth = authorized(
+        config
+            .token
+            .filter(|token| !token.trim().is_empty())
+            .map(Arc::new),
+    );
+    let dir = Arc::new(config.workflows_dir);
+
+    let page = warp::path::end()
+        .and(warp::get())
+        .map(|| Box::new(warp::reply::html(create_control_html())) as Box<dyn Reply>);
+
+    let list_dir = dir.clone();
+    let list = warp::path("workflows")
+        .and(warp::path::end())
+        .and(warp::get())
+        .map(move || {
+            Box::new(warp::reply::json(&json!({
+                "workflows": list_workflows(&list_dir),
+                "runs": run_history::recent_runs(10)
+            }))) as Box<dyn Reply>
+        });
+
+    let run = warp::path("run")
+        .and(warp::path::end())
+        .and(warp::post())
+        .and(warp::body::json())
+        .map(move |request: RunRequest| {
+            let reply = match run_workflow(&app_handle, &dir, request) {
+                Ok(path) => warp::reply::with_status(
+                    warp::reply::json(&json!({ "status": "queued", "workflow": path })),
+                    StatusCode::ACCEPTED,
+                ),
+                Err(e) => warp::reply::with_status(
+                    warp::reply::json(&json!({ "status": "error", "error": e })),
+                    StatusCode::BAD_REQUEST,
+                ),
+            };
+            Box::new(reply) as Box<dyn Reply>
+        });
+
+    // 실행 상태 스트림도 채팅 WebSocket 과 같은 크기 제한 / heartbeat / 유휴 정리 적용
+    let events = warp::path("ws")
+        .and(warp::ws())
+        .map(|ws: warp::ws::Ws| {
+            let limits = ws_limits::load_limits();
+            Box::new(ws_limits::configure(ws, &limits).on_upgrade(move |websocket| {
+                ws_limits::pump(websocket, run_history::subscribe_events(), limits)
+            })) as Box<dyn Reply>
+        });
+
+    warp::path("control")
+        .and(auth)
+        .and(page.or(list).unify().or(run).unify().or(events).unify())
+        .recover(handle_rejection)
+        .unify()
+}
+
+// 토큰 불일치는 401, 나머지는 다음 라우트로
+pub async fn handle_rejection(rejection: Rejection) -> Result<Box<dyn Reply>, Rejection> {
+    if rejection.find::<ControlUnauthorized>().is_some() {
+        return Ok(Box::new(warp::reply::with_status(
+            "Unauthorized",
+            StatusCode::UNAUTHORIZED,
+        )));
+    }
+    Err(rejection)
+}
+
+fn create_control_html() -> String {
+    r#"<!DOCTYPE html>
+<html lang="ko">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0, user-scalable=no">
+    <title>Workflow Control</title>
+    <style>
+        * { margin: 0; padding: 0; box-sizing: border-box; }
+        body {
+            font-family: "Inter", -apple-system, BlinkMacSystemFont, sans-serif;
+            background: #0f0f0f;
+            color: #e5e5e5;
+            padding: 16px;
+        }
+        header { display: flex; justify-content: space-between; align-items: center; margin-bottom: 16px; }
+        h1 { font-size: 20px; font-weight: 600; }
+        #connection { font-size: 12px; color: #888; }
+        #connection.online { color: #4ade80; }
+        .workflow {
+            display: flex;
+            align-items: center;
+            justify-content: space-between;
+            background: #1a1a1a;
+            border-radius: 10px;
+            padding: 14px;
+            margin-bottom: 10px;
+        }
+        .workflow .name { font-size: 16px; }
+        .workflow .status { font-size: 12px; color: #888; margin-top: 4px; }
+        .workflow button {
+            background: #2563eb;
+            color: white;
+            border: none;
+            border-radius: 8px;
+            padding: 10px 18px;
+            font-size: 15px;
+        }
+        .workflow button:disabled { background: #444; }
+        .empty { color: #666; text-align: center; margin-top: 40px; }
+        #toast { position: fixed; bottom: 16px; left: 16px; right: 16px; text-align: center; color: #fca5a5; }
+    </style>
+</head>
+<body>
+    <header>
+        <h1>📱 워크플로우 제어</h1>
+        <span id="connection">연결 중...</span>
+    </header>
+    <div id="workflows"><div class="empty">불러오는 중...</div></div>
+    <div id="toast"></div>
+
+    <script>
+        const token = new URLSearchParams(window.location.search).get('token') || '';
+        const headers = { 'Authorization': `Bearer ${token}`, 'Content-Type': 'application/json' };
+        const icons = { running: '⏳', success: '✅', error: '❌', cancelled: '⏹️' };
+        const statusByName = new Map();
+        let workflows = [];
+
+        function text(value) {
+            const span = document.createElement('span');
+            span.textContent = value == null ? '' : String(value);
+            return span.innerHTML;
+        }
+
+        function showToast(message) {
+            const toast = document.getElementById('toast');
+            toast.textContent = message;
+            setTimeout(() => { toast.textContent = ''; }, 4000);
+        }
+
+        function render() {
+            const container = document.getElementById('workflows');
+            if (workflows.length === 0) {
+                container.innerHTML = '<div class="empty">실행할 수 있는 워크플로우가 없습니다</div>';
+                return;
+            }
+            container.innerHTML = workflows.map((workflow, index) => {
+                const status = statusByName.get(workflow.name) || workflow.last_status;
+                const label = status ? `${icons[status] || '•'} ${text(status)}` : '실행 기록 없음';
+                return `
+                <div class="workflow">
+                    <div>
+                        <div class="name">${text(workflow.name)}</div>
+                        <div class="status">${label}</div>
+                    </div>
+                    <button data-index="${index}" ${status === 'running' ? 'disabled' : ''}>실행</button>
+                </div>`;
+            }).join('');
+            container.querySelectorAll('button').forEach(button => {
+                button.onclick = () => runWorkflow(workflows[button.dataset.index], button);
+            });
+        }
+
+        async function loadWorkflows() {
+            const response = await fetch('/control/workflows', { headers });
+            if (!response.ok) {
+                showToast('인증 실패 - 토큰을 확인하세요');
+                return;
+            }
+            const data = await response.json();
+            workflows = data.workflows || [];
+            render();
+        }
+
+        async function runWorkflow(workflow, button) {
+            button.disabled = true;
+            const response = await fetch('/control/run', {
+                method: 'POST',
+                headers,
+                body: JSON.stringify({ workflow: workflow.path })
+            });
+            if (!response.ok) {
+                const data = await response.json().catch(() => ({}));
+                showToast(`실행 실패: ${data.error || response.status}`);
+                button.disabled = false;
+                return;
+            }
+            statusByName.set(workflow.name, 'running');
+            render();
+        }
+
+        function connect() {
+            const wsProtocol = window.location.protocol === 'https:' ? 'wss:' : 'ws:';
+            const websocket = new WebSocket(`${wsProtocol}//${window.location.host}/control/ws?token=${encodeURIComponent(token)}`);
+            const connection = document.getElementById('connection');
+
+            websocket.onopen = () => {
+                connection.textContent = '● 실시간';
+                connection.className = 'online';
+            };
+            websocket.onmessage = (message) => {
+                try {
+                    const event = JSON.parse(message.data);
+                    if (event.event === 'run_started') statusByName.set(event.workflow, 'running');
+                    if (event.event === 'run_finished') statusByName.set(event.workflow, event.status);
+                    render();
+                } catch (e) {
+                    // 실행 이벤트가 아닌 메시지는 무시
+                }
+            };
+            websocket.onclose = () => {
+                connection.textContent = '연결 끊김 - 재연결 중...';
+                connection.className = '';
+                setTimeout(connect, 3000);
+            };
+        }
+
+        loadWorkflows();
+        connect();
+    </script>
+</body>
+</html>"#
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::temp_dir;
+    use std::fs;
+
+    #[test]
+    fn control_page_checks_tokens() {
+        assert!(token_matches("s3cret", "s3cret"));
+        assert!(!token_matches("s3cret", "s3cre"));
+        assert!(!token_matches("s3cret", "s3creT"));
+
+        let mut query = std::collections::HashMap::new();
+        assert_eq!(
+            provided_token(Some("Bearer abc"), &query),
+            Some("abc".to_string())
+        );
+        assert_eq!(
+            provided_token(Some("Basic abc"), &query),
+            None
+        );
+        query.insert("token".to_string(), "xyz".to_string());
+        assert_eq!(
+            provided_token(None, &query),
+            Some("xyz".to_string())
+        );
+    }
+
+    #[test]
+    fn control_page_lists_workflow_files() {
+        let dir = temp_dir();
+        fs::write(dir.path().join("backup.flow.json"), "{}").unwrap();
+        fs::create_dir_all(dir.path().join("daily")).unwrap();
+        fs::write(dir.path().join("daily/report.json"), "{}").unwrap();
+        fs::write(dir.path().join("notes.txt"), "x").unwrap();
+
+        let workflows = list_workflows(dir.path());
+        let paths: Vec<&str> = workflows
+            .iter()
+            .map(|w| w["path"].as_str().unwrap())
+            .collect();
+        assert_eq!(paths, vec!["backup.flow.json", "daily/report.json"]);
+        assert_eq!(workflows[0]["name"], "backup");
+        assert_eq!(workflows[1]["name"], "report");
+    }
+}
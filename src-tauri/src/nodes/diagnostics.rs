@@ -0,0 +1,298 @@
+// src-tauri/src/nodes/diagnostics.rs
+use super::{atomic_store, disk_preflight, os_command, worker_pool};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+// 🩺 진단 번들 / 크래시 리포트
+// - generate_diagnostics_bundle: 최근 로그, 설정(비밀값 가림), 플러그인 목록, 환경 정보를 zip 으로 묶음
+// - 패닉 훅: 크래시 리포트를 store/logs/crash_*.txt 로 기록 (설정에서 끌 수 있음)
+
+const REDACTED: &str = "[REDACTED]";
+const SENSITIVE_KEYS: [&str; 9] = [
+    "token",
+    "secret",
+    "password",
+    "passwd",
+    "api_key",
+    "apikey",
+    "authorization",
+    "credential",
+    "cookie",
+];
+const MAX_LOG_BYTES: u64 = 1024 * 1024;
+const MAX_LOG_FILES: usize = 20;
+const LOG_MAX_AGE_DAYS: u64 = 7;
+const TAURI_PLUGINS: [&str; 6] = [
+    "tauri-plugin-single-instance",
+    "tauri-plugin-deep-link",
+    "tauri-plugin-clipboard-manager",
+    "tauri-plugin-dialog",
+    "tauri-plugin-shell",
+    "tauri-plugin-store",
+];
+pub const BUNDLED_TOOLS: [&str; 3] = ["yt-dlp", "ffmpeg", "cloudflared"];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DiagnosticsSettings {
+    crash_reports: bool,
+}
+
+impl Default for DiagnosticsSettings {
+    fn default() -> Self {
+        Self {
+            crash_reports: true,
+        }
+    }
+}
+
+// 로그 파일 공용 폴더 (store/logs)
+pub fn logs_dir() -> PathBuf {
+    atomic_store::store_dir().join("logs")
+}
+
+fn settings_path() -> PathBuf {
+    atomic_store::store_dir().join("diagnostics_settings.json")
+}
+
+fn load_settings() -> DiagnosticsSettings {
+    atomic_store::read_json_verified(&settings_path()).unwrap_or_default()
+}
+
+pub fn is_sensitive_key(key: &str) -> bool {
+    let key = key.to_lowercase();
+    SENSITIVE_KEYS.iter().any(|s| key.contains(s)) || key == "key" || key.ends_with("_key")
+}
+
+// 민감한 키의 값을 재귀적으로 가림
+pub fn redact_json(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, item) in map.iter_mut() {
+                if is_sensitive_key(key) && !item.is_null() {
+                    *item = Value::String(REDACTED.to_string());
+                } else {
+                    redact_json(item);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(redact_json),
+        _ => {}
+    }
+}
+
+fn environment_info() -> Value {
+    let tools: Value = BUNDLED_TOOLS
+        .iter()
+        .map(|tool| {
+            let path = os_command::bundled_binary(tool);
+            let bundled = Path::new(&path).is_absolute();
+            (
+                tool.to_string(),
+                json!({ "bundled": bundled, "path": path }),
+            )
+        })
+        .collect::<serde_json::Map<_, _>>()
+        .into();
+
+    json!({
+        "app_version": env!("CARGO_PKG_VERSION"),
+        "os": std::env::consts::OS,
+        "arch": std::env::consts::ARCH,
+        "family": std::env::consts::FAMILY,
+        "os_version": os_version(),
+        "generated_at": chrono::Local::now().to_rfc3339(),
+        "current_dir": std::env::current_dir().ok().map(|p| p.to_string_lossy().to_string()),
+        "cpu_count": std::thread::available_parallelism().map(|n| n.get()).ok(),
+        "plugins": TAURI_PLUGINS,
+        "tools": tools
+    })
+}
+
+fn os_version() -> Option<String> {
+    if cfg!(target_os = "windows") {
+        os_command::run_optional("cmd", &["/C", "ver"])
+    } else if cfg!(target_os = "macos") {
+        os_command::run_optional("sw_vers", &["-productVersion"])
+    } else {
+        os_command::run_optional("uname", &["-sr"])
+    }
+}
+
+// 최근 로그 파일 (최근 7일, 최대 20개, 각 파일은 마지막 1MB 만)
+fn recent_logs() -> Vec<(String, Vec<u8>)> {
+    let cutoff = std::time::SystemTime::now()
+        - std::time::Duration::from_secs(LOG_MAX_AGE_DAYS * 24 * 60 * 60);
+    let mut files: Vec<(std::time::SystemTime, PathBuf)> = std::fs::read_dir(logs_dir())
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| {
+            let modified = entry.metadata().ok()?.modified().ok()?;
+            (entry.path().is_file() && modified >= cutoff).then(|| (modified, entry.path()))
+        })
+        .collect();
+    files.sort_by_key(|(modified, _)| std::cmp::Reverse(*modified));
+
+    files
+        .into_iter()
+        .take(MAX_LOG_FILES)
+        .filter_map(|(_, path)| {
+            let data = std::fs::read(&path).ok()?;
+            let start = data.len().saturating_sub(MAX_LOG_BYTES as usize);
+            let name = path.file_name()?.to_string_lossy().to_string();
+            Some((name, data[start..].to_vec()))
+        })
+        .collect()
+}
+
+// store 폴더의 설정 JSON (체크섬/백업 파일 제외, 비밀값 가림)
+fn redacted_settings() -> Vec<(String, Vec<u8>)> {
+    std::fs::read_dir(atomic_store::store_dir())
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("json")
+                || entry.metadata().ok()?.len() > MAX_LOG_BYTES
+            {
+                return None;
+            }
+            let mut value: Value = serde_json::from_slice(&std::fs::read(&path).ok()?).ok()?;
+            redact_json(&mut value);
+            let name = path.file_name()?.to_string_lossy().to_string();
+            Some((name, serde_json::to_vec_pretty(&value).ok()?))
+        })
+        .collect()
+}
+
+// (이름, 내용) 목록을 deflate zip 으로 저장
+pub fn write_zip(path: &Path, entries: &[(String, Vec<u8>)]) -> Result<(), String> {
+    let file = std::fs::File::create(path).map_err(|e| format!("번들 파일 생성 실패: {}", e))?;
+    let mut zip = zip::ZipWriter::new(file);
+    let options =
+        zip::write::FileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+
+    for (name, data) in entries {
+        zip.start_file(name.as_str(), options)
+            .map_err(|e| format!("zip 쓰기 실패: {}", e))?;
+        zip.write_all(data)
+            .map_err(|e| format!("zip 쓰기 실패: {}", e))?;
+    }
+    zip.finish().map_err(|e| format!("zip 완료 실패: {}", e))?;
+    Ok(())
+}
+
+fn write_bundle(path: &Path) -> Result<Vec<String>, String> {
+    let mut entries: Vec<(String, Vec<u8>)> = vec![(
+        "environment.json".to_string(),
+        serde_json::to_vec_pretty(&environment_info()).unwrap_or_default(),
+    )];
+    entries.extend(
+        redacted_settings()
+            .into_iter()
+            .map(|(name, data)| (format!("settings/{}", name), data)),
+    );
+    entries.extend(
+        recent_logs()
+            .into_iter()
+            .map(|(name, data)| (format!("logs/{}", name), data)),
+    );
+
+    // 압축 전 크기 기준으로 여유 공간 확인 (zip 은 이보다 작음)
+    let total: u64 = entries.iter().map(|(_, data)| data.len() as u64).sum();
+    disk_preflight::ensure_free_space(path, total)?;
+    write_zip(path, &entries)?;
+    Ok(entries.into_iter().map(|(name, _)| name).collect())
+}
+
+// 패닉 발생 시 크래시 리포트 기록 (앱 시작 시 한 번 설치)
+pub fn install_panic_hook() {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        if load_settings().crash_reports {
+            let report = format!(
+                "automation-gui {} 크래시 리포트\n시각: {}\nOS: {} {}\n스레드: {}\n\n{}\n\n{}\n",
+                env!("CARGO_PKG_VERSION"),
+                chrono::Local::now().to_rfc3339(),
+                std::env::consts::OS,
+                std::env::consts::ARCH,
+                std::thread::current().name().unwrap_or("unnamed"),
+                info,
+                std::backtrace::Backtrace::force_capture()
+            );
+            let dir = logs_dir();
+            let path = dir.join(format!(
+                "crash_{}.txt",
+                chrono::Local::now().format("%Y%m%d_%H%M%S_%3f")
+            ));
+            if std::fs::create_dir_all(&dir).is_ok() && std::fs::write(&path, report).is_ok() {
+                eprintln!("💥 크래시 리포트 저장: {}", path.display());
+            }
+        }
+        previous(info);
+    }));
+}
+
+#[tauri::command]
+pub fn set_crash_reporting(enabled: bool) -> Result<String, String> {
+    atomic_store::write_json_atomic(
+        &settings_path(),
+        &DiagnosticsSettings {
+            crash_reports: enabled,
+        },
+    )?;
+    println!(
+        "🩺 크래시 리포트: {}",
+        if enabled { "켜짐" } else { "꺼짐" }
+    );
+    Ok("SUCCESS".to_string())
+}
+
+#[tauri::command]
+pub async fn generate_diagnostics_bundle(output_path: Option<String>) -> Result<String, String> {
+    let path = match output_path.filter(|p| !p.trim().is_empty()) {
+        Some(path) => PathBuf::from(path.trim()),
+        None => dirs::desktop_dir()
+            .or_else(dirs::home_dir)
+            .ok_or("저장 위치를 찾을 수 없습니다")?
+            .join(format!(
+                "automation-gui-diagnostics-{}.zip",
+                chrono::Local::now().format("%Y%m%d-%H%M%S")
+            )),
+    };
+    println!("🩺 진단 번들 생성: {}", path.display());
+
+    let bundle_path = path.clone();
+    // zip 압축은 공용 워커 풀 허가를 받아 실행
+    let files = worker_pool::run_blocking(move || write_bundle(&bundle_path))
+        .await
+        .map_err(|e| format!("진단 번들 생성 실패: {}", e))??;
+
+    println!("✅ 진단 번들 완료 ({}개 파일)", files.len());
+    Ok(json!({ "path": path.to_string_lossy(), "files": files }).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diagnostics_redacts_sensitive_settings() {
+        let mut value = serde_json::json!({
+            "channel": "beta",
+            "api_token": "abc",
+            "nested": [{ "password": "pw", "name": "n", "openai_key": "sk" }],
+            "tokens_used": null
+        });
+        redact_json(&mut value);
+        assert_eq!(value["channel"], "beta");
+        assert_eq!(value["api_token"], "[REDACTED]");
+        assert_eq!(value["nested"][0]["password"], "[REDACTED]");
+        assert_eq!(value["nested"][0]["openai_key"], "[REDACTED]");
+        assert_eq!(value["nested"][0]["name"], "n");
+        assert!(value["tokens_used"].is_null());
+    }
+}
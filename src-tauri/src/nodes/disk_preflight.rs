@@ -0,0 +1,148 @@
+// src-tauri/src/nodes/disk_preflight.rs
+use serde_json::json;
+use std::path::{Path, PathBuf};
+use sysinfo::Disks;
+
+// 💾 디스크 여유 공간 사전 확인
+// 큰 파일을 만드는 노드(동영상 다운로드, 사진 복사 등)가 시작 전에 호출해서
+// 중간에 디스크가 가득 차 실패하는 대신 DISK_FULL 에러로 바로 실패하게 함
+// 에러 형식: DISK_FULL: {"path": ..., "required_bytes": ..., "available_bytes": ...}
+
+// 작업 후에도 남겨둘 최소 여유 공간
+pub const RESERVE_BYTES: u64 = 200 * 1024 * 1024;
+
+// 아직 없는 경로면 존재하는 가장 가까운 상위 폴더 기준
+fn existing_ancestor(path: &Path) -> PathBuf {
+    let absolute = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        std::env::current_dir()
+            .unwrap_or_else(|_| PathBuf::from("."))
+            .join(path)
+    };
+    let mut current = absolute.as_path();
+    loop {
+        if current.exists() {
+            return current
+                .canonicalize()
+                .unwrap_or_else(|_| current.to_path_buf());
+        }
+        match current.parent() {
+            Some(parent) => current = parent,
+            None => return absolute,
+        }
+    }
+}
+
+// 경로가 속한 마운트 지점 (가장 긴 접두사)
+pub fn best_mount(path: &Path, mounts: &[PathBuf]) -> Option<usize> {
+    mounts
+        .iter()
+        .enumerate()
+        .filter(|(_, mount)| path.starts_with(mount))
+        .max_by_key(|(_, mount)| mount.as_os_str().len())
+        .map(|(index, _)| index)
+}
+
+pub fn available_space(path: &Path) -> Result<u64, String> {
+    let target = existing_ancestor(path);
+    let disks = Disks::new_with_refreshed_list();
+    let mounts: Vec<PathBuf> = disks
+        .list()
+        .iter()
+        .map(|disk| {
+            let mount = disk.mount_point();
+            mount.canonicalize().unwrap_or_else(|_| mount.to_path_buf())
+        })
+        .collect();
+
+    best_mount(&target, &mounts)
+        .map(|index| disks.list()[index].available_space())
+        .ok_or_else(|| format!("디스크를 찾을 수 없습니다: {}", target.display()))
+}
+
+pub fn disk_full_error(path: &Path, required: u64, available: u64) -> String {
+    format!(
+        "DISK_FULL: {}",
+        json!({
+            "path": path.to_string_lossy(),
+            "required_bytes": required,
+            "available_bytes": available
+        })
+    )
+}
+
+// required_bytes + 예비 공간만큼 남아있는지 확인
+// 디스크 정보를 못 읽으면 작업을 막지 않음
+pub fn ensure_free_space(path: &Path, required_bytes: u64) -> Result<(), String> {
+    let available = match available_space(path) {
+        Ok(available) => available,
+        Err(e) => {
+            println!("⚠️ 디스크 공간 확인 생략: {}", e);
+            return Ok(());
+        }
+    };
+    let required = required_bytes.saturating_add(RESERVE_BYTES);
+    if available < required {
+        println!(
+            "💾 디스크 공간 부족: {} (필요 {}MB, 남음 {}MB)",
+            path.display(),
+            required / (1024 * 1024),
+            available / (1024 * 1024)
+        );
+        return Err(disk_full_error(path, required, available));
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn check_disk_space(path: String, required_mb: Option<u64>) -> Result<String, String> {
+    let path = PathBuf::from(path.trim());
+    let available = available_space(&path)?;
+    let required = required_mb.unwrap_or(0) * 1024 * 1024;
+    Ok(json!({
+        "path": path.to_string_lossy(),
+        "available_bytes": available,
+        "available_mb": available / (1024 * 1024),
+        "enough": available >= required.saturating_add(RESERVE_BYTES)
+    })
+    .to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disk_preflight_picks_longest_mount_and_formats_error() {
+        use std::path::{Path, PathBuf};
+        let mounts = vec![
+            PathBuf::from("/"),
+            PathBuf::from("/home"),
+            PathBuf::from("/home/user/data"),
+        ];
+        assert_eq!(
+            best_mount(Path::new("/home/user/data/videos"), &mounts),
+            Some(2)
+        );
+        assert_eq!(
+            best_mount(Path::new("/home/other"), &mounts),
+            Some(1)
+        );
+        assert_eq!(
+            best_mount(Path::new("/var/tmp"), &mounts),
+            Some(0)
+        );
+        // 문자열 접두사가 아닌 경로 구성요소 기준
+        assert_eq!(
+            best_mount(Path::new("/homework"), &mounts),
+            Some(0)
+        );
+
+        let error = disk_full_error(Path::new("/data"), 100, 10);
+        let detail: serde_json::Value =
+            serde_json::from_str(error.strip_prefix("DISK_FULL: ").unwrap()).unwrap();
+        assert_eq!(detail["required_bytes"], 100);
+        assert_eq!(detail["available_bytes"], 10);
+    }
+}
@@ -0,0 +1,344 @@
+// src-tauri/src/nodes/display_node.rs
+use super::os_command;
+use serde::Serialize;
+use serde_json::json;
+
+// 🖥️ 모니터 / 디스플레이 노드 (도킹 스테이션 연결 시 화면 설정 자동화)
+// action: list | set_resolution(monitor, width, height) | set_primary(monitor) | set_wallpaper(monitor?, image_path)
+// monitor 는 list 결과의 index (또는 장치 이름)
+
+#[derive(Debug, Serialize, Clone)]
+struct MonitorInfo {
+    index: usize,
+    name: String,
+    width: u32,
+    height: u32,
+    x: i32,
+    y: i32,
+    primary: bool,
+}
+
+#[cfg(target_os = "windows")]
+const DISPLAY_HELPER: &str = r#"
+Add-Type @'
+using System;
+using System.Runtime.InteropServices;
+[StructLayout(LayoutKind.Sequential, CharSet = CharSet.Unicode)]
+public struct DEVMODE {
+    [MarshalAs(UnmanagedType.ByValTStr, SizeConst = 32)] public string dmDeviceName;
+    public short dmSpecVersion, dmDriverVersion, dmSize, dmDriverExtra;
+    public int dmFields, dmPositionX, dmPositionY, dmDisplayOrientation, dmDisplayFixedOutput;
+    public short dmColor, dmDuplex, dmYResolution, dmTTOption, dmCollate;
+    [MarshalAs(UnmanagedType.ByValTStr, SizeConst = 32)] public string dmFormName;
+    public short dmLogPixels;
+    public int dmBitsPerPel, dmPelsWidth, dmPelsHeight, dmDisplayFlags, dmDisplayFrequency;
+    public int dmICMMethod, dmICMIntent, dmMediaType, dmDitherType, dmReserved1, dmReserved2, dmPanningWidth, dmPanningHeight;
+}
+[ComImport, Guid("B92B56A9-8B55-4E14-9A89-0199BBB6F93B"), InterfaceType(ComInterfaceType.InterfaceIsIUnknown)]
+public interface IDesktopWallpaper {
+    void SetWallpaper([MarshalAs(UnmanagedType.LPWStr)] string monitorId, [MarshalAs(UnmanagedType.LPWStr)] string wallpaper);
+    [return: MarshalAs(UnmanagedType.LPWStr)] string GetWallpaper([MarshalAs(UnmanagedType.LPWStr)] string monitorId);
+    [return: MarshalAs(UnmanagedType.LPWStr)] string GetMonitorDevicePathAt(uint monitorIndex);
+    uint GetMonitorDevicePathCount();
+}
+[ComImport, Guid("C2CF3110-460E-4fc1-B9D0-8A1C0C9CC4BD")] public class DesktopWallpaperClass { }
+public static class Display {
+    [DllImport("user32.dll", CharSet = CharSet.Unicode)] static extern bool EnumDisplaySettings(string name, int mode, ref DEVMODE dm);
+    [DllImport("user32.dll", CharSet = CharSet.Unicode)] static extern int ChangeDisplaySettingsEx(string name, ref DEVMODE dm, IntPtr hwnd, uint flags, IntPtr param);
+    [DllImport("user32.dll", CharSet = CharSet.Unicode)] static extern int ChangeDisplaySettingsEx(string name, IntPtr dm, IntPtr hwnd, uint flags, IntPtr param);
+    const int ENUM_CURRENT_SETTINGS = -1;
+    const int DM_POSITION = 0x20, DM_PELSWIDTH = 0x80000, DM_PELSHEIGHT = 0x100000;
+    const uint CDS_UPDATEREGISTRY = 0x1, CDS_SET_PRIMARY = 0x10, CDS_NORESET = 0x10000000;
+    static DEVMODE Current(string name) {
+        var dm = new DEVMODE();
+        dm.dmSize = (short)Marshal.SizeOf(typeof(DEVMODE));
+        if (!EnumDisplaySettings(name, ENUM_CURRENT_SETTINGS, ref dm)) throw new Exception("DISPLAY_NOT_FOUND: " + name);
+        return dm;
+    }
+    public static int SetResolution(string name, int width, int height) {
+        var dm = Current(name);
+        dm.dmPelsWidth = width; dm.dmPelsHeight = height;
+        dm.dmFields = DM_PELSWIDTH | DM_PELSHEIGHT;
+        return ChangeDisplaySettingsEx(name, ref dm, IntPtr.Zero, CDS_UPDATEREGISTRY, IntPtr.Zero);
+    }
+    // 새 주 모니터가 (0,0)에 오도록 모든 모니터 위치를 옮긴 뒤 한 번에 적용
+    public static int SetPrimary(string[] names, string primary) {
+        var target = Current(primary);
+        int dx = target.dmPositionX, dy = target.dmPositionY;
+        foreach (var name in names) {
+            var dm = Current(name);
+            dm.dmPositionX -= dx; dm.dmPositionY -= dy;
+            dm.dmFields = DM_POSITION;
+            uint flags = CDS_UPDATEREGISTRY | CDS_NORESET | (name == primary ? CDS_SET_PRIMARY : 0);
+            int result = ChangeDisplaySettingsEx(name, ref dm, IntPtr.Zero, flags, IntPtr.Zero);
+            if (result != 0) return result;
+        }
+        return ChangeDisplaySettingsEx(null, IntPtr.Zero, IntPtr.Zero, 0, IntPtr.Zero);
+    }
+    public static void SetWallpaper(int index, string path) {
+        var wallpaper = (IDesktopWallpaper)new DesktopWallpaperClass();
+        wallpaper.SetWallpaper(index < 0 ? null : wallpaper.GetMonitorDevicePathAt((uint)index), path);
+    }
+}
+'@
+Add-Type -AssemblyName System.Windows.Forms
+$screens = [System.Windows.Forms.Screen]::AllScreens
+"#;
+
+#[cfg(target_os = "windows")]
+fn run_display_script(command: &str) -> Result<String, String> {
+    os_command::powershell(&format!("{}\n{}", DISPLAY_HELPER, command))
+}
+
+#[cfg(target_os = "windows")]
+fn list_monitors() -> Result<Vec<MonitorInfo>, String> {
+    let output = run_display_script(
+        r#"$screens | ForEach-Object { "$($_.DeviceName)`t$($_.Bounds.Width)`t$($_.Bounds.Height)`t$($_.Bounds.X)`t$($_.Bounds.Y)`t$($_.Primary)" }"#,
+    )?;
+
+    Ok(output
+        .lines()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            let parts: Vec<&str> = line.trim_end_matches('\r').split('\t').collect();
+            if parts.len() != 6 {
+                return None;
+            }
+            Some(MonitorInfo {
+                index,
+                name: parts[0].to_string(),
+                width: parts[1].parse().ok()?,
+                height: parts[2].parse().ok()?,
+                x: parts[3].parse().ok()?,
+                y: parts[4].parse().ok()?,
+                primary: parts[5].eq_ignore_ascii_case("true"),
+            })
+        })
+        .collect())
+}
+
+#[cfg(target_os = "windows")]
+fn check_change_result(output: String) -> Result<(), String> {
+    // ChangeDisplaySettingsEx: 0 = DISP_CHANGE_SUCCESSFUL, 1 = 재시작 필요
+    match output.trim() {
+        "0" => Ok(()),
+        "1" => Err("DISPLAY_CHANGE_NEEDS_RESTART".to_string()),
+        code => Err(format!("DISPLAY_CHANGE_FAILED: {}", code)),
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn set_resolution(monitor: &MonitorInfo, width: u32, height: u32) -> Result<(), String> {
+    run_display_script(&format!(
+        "[Display]::SetResolution({}, {}, {})",
+        os_command::ps_quote(&monitor.name),
+        width,
+        height
+    ))
+    .and_then(check_change_result)
+}
+
+#[cfg(target_os = "windows")]
+fn set_primary(monitor: &MonitorInfo) -> Result<(), String> {
+    run_display_script(&format!(
+        "[Display]::SetPrimary(@($screens | ForEach-Object {{ $_.DeviceName }}), {})",
+        os_command::ps_quote(&monitor.name)
+    ))
+    .and_then(check_change_result)
+}
+
+// 모니터 순서는 IDesktopWallpaper 의 장치 순서를 따름 (None 이면 모든 모니터)
+#[cfg(target_os = "windows")]
+fn set_wallpaper(monitor: Option<&MonitorInfo>, image_path: &str) -> Result<(), String> {
+    run_display_script(&format!(
+        "[Display]::SetWallpaper({}, {})",
+        monitor.map(|m| m.index as i64).unwrap_or(-1),
+        os_command::ps_quote(image_path)
+    ))
+    .map(|_| ())
+}
+
+// macOS: 해상도 목록은 system_profiler, 배경화면은 System Events 의 desktop 별 설정
+#[cfg(target_os = "macos")]
+fn list_monitors() -> Result<Vec<MonitorInfo>, String> {
+    let output = os_command::run("system_profiler", &["SPDisplaysDataType", "-json"])?;
+    let value: serde_json::Value =
+        serde_json::from_str(&output).map_err(|e| format!("디스플레이 정보 파싱 실패: {}", e))?;
+
+    let mut monitors = Vec::new();
+    for gpu in value["SPDisplaysDataType"].as_array().into_iter().flatten() {
+        for display in gpu["spdisplays_ndrvs"].as_array().into_iter().flatten() {
+            // "_spdisplays_resolution": "2560 x 1440 @ 60.00Hz"
+            let resolution = display["_spdisplays_resolution"]
+                .as_str()
+                .or(display["_spdisplays_pixels"].as_str())
+                .unwrap_or("");
+            let mut numbers = resolution
+                .split(|c: char| !c.is_ascii_digit())
+                .filter(|s| !s.is_empty());
+            monitors.push(MonitorInfo {
+                index: monitors.len(),
+                name: display["_name"].as_str().unwrap_or("Display").to_string(),
+                width: numbers.next().and_then(|n| n.parse().ok()).unwrap_or(0),
+                height: numbers.next().and_then(|n| n.parse().ok()).unwrap_or(0),
+                x: 0,
+                y: 0,
+                primary: display["spdisplays_main"].as_str() == Some("spdisplays_yes"),
+            });
+        }
+    }
+    Ok(monitors)
+}
+
+#[cfg(target_os = "macos")]
+fn set_resolution(_monitor: &MonitorInfo, _width: u32, _height: u32) -> Result<(), String> {
+    Err("NOT_SUPPORTED_ON_MACOS: 해상도 변경은 시스템 설정에서 해주세요".to_string())
+}
+
+#[cfg(target_os = "macos")]
+fn set_primary(_monitor: &MonitorInfo) -> Result<(), String> {
+    Err("NOT_SUPPORTED_ON_MACOS: 주 모니터 변경은 시스템 설정에서 해주세요".to_string())
+}
+
+#[cfg(target_os = "macos")]
+fn set_wallpaper(monitor: Option<&MonitorInfo>, image_path: &str) -> Result<(), String> {
+    let target = match monitor {
+        Some(m) => format!("desktop {}", m.index + 1),
+        None => "every desktop".to_string(),
+    };
+    let script = format!(
+        "tell application \"System Events\" to tell {} to set picture to POSIX file \"{}\"",
+        target,
+        image_path.replace('"', "\\\"")
+    );
+    os_command::run("osascript", &["-e", &script]).map(|_| ())
+}
+
+// Linux(X11): xrandr
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn list_monitors() -> Result<Vec<MonitorInfo>, String> {
+    let output = os_command::run("xrandr", &["--query"])?;
+    let mut monitors = Vec::new();
+
+    // "HDMI-1 connected primary 1920x1080+0+0 (normal ...) 527mm x 296mm"
+    for line in output.lines().filter(|l| l.contains(" connected")) {
+        let mut words = line.split_whitespace();
+        let name = words.next().unwrap_or_default().to_string();
+        let primary = line.contains(" primary ");
+        let geometry = line
+            .split_whitespace()
+            .find(|w| w.contains('x') && w.contains('+'))
+            .unwrap_or("0x0+0+0");
+        let numbers: Vec<i64> = geometry
+            .split(['x', '+'])
+            .filter_map(|n| n.parse().ok())
+            .collect();
+
+        monitors.push(MonitorInfo {
+            index: monitors.len(),
+            name,
+            width: numbers.first().copied().unwrap_or(0) as u32,
+            height: numbers.get(1).copied().unwrap_or(0) as u32,
+            x: numbers.get(2).copied().unwrap_or(0) as i32,
+            y: numbers.get(3).copied().unwrap_or(0) as i32,
+            primary,
+        });
+    }
+    Ok(monitors)
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn set_resolution(monitor: &MonitorInfo, width: u32, height: u32) -> Result<(), String> {
+    let mode = format!("{}x{}", width, height);
+    os_command::run("xrandr", &["--output", &monitor.name, "--mode", &mode]).map(|_| ())
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn set_primary(monitor: &MonitorInfo) -> Result<(), String> {
+    os_command::run("xrandr", &["--output", &monitor.name, "--primary"]).map(|_| ())
+}
+
+// GNOME 은 모니터별 배경화면을 지원하지 않으므로 전체 배경화면으로 설정
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn set_wallpaper(monitor: Option<&MonitorInfo>, image_path: &str) -> Result<(), String> {
+    if monitor.is_some() {
+        println!("⚠️ 모니터별 배경화면은 지원되지 않아 전체 배경화면으로 설정합니다");
+    }
+    let uri = format!("file://{}", image_path);
+    os_command::run(
+        "gsettings",
+        &["set", "org.gnome.desktop.background", "picture-uri", &uri],
+    )?;
+    let _ = os_command::run(
+        "gsettings",
+        &[
+            "set",
+            "org.gnome.desktop.background",
+            "picture-uri-dark",
+            &uri,
+        ],
+    );
+    Ok(())
+}
+
+fn find_monitor<'a>(monitors: &'a [MonitorInfo], monitor: &str) -> Result<&'a MonitorInfo, String> {
+    let monitor = monitor.trim();
+    monitors
+        .iter()
+        .find(|m| m.index.to_string() == monitor || m.name.eq_ignore_ascii_case(monitor))
+        .ok_or_else(|| format!("MONITOR_NOT_FOUND: {}", monitor))
+}
+
+#[tauri::command]
+pub async fn display_node(
+    action: Option<String>,
+    monitor: Option<String>,
+    width: Option<u32>,
+    height: Option<u32>,
+    image_path: Option<String>,
+) -> Result<String, String> {
+    let action = action
+        .map(|a| a.trim().to_lowercase())
+        .filter(|a| !a.is_empty())
+        .unwrap_or_else(|| "list".to_string());
+    println!("🖥️ Display Node: {} {:?}", action, monitor);
+
+    tokio::task::spawn_blocking(move || {
+        let monitors = list_monitors()?;
+        let monitor = monitor.filter(|m| !m.trim().is_empty());
+
+        match action.as_str() {
+            "list" => return Ok(json!({ "monitors": monitors }).to_string()),
+            "set_resolution" => {
+                let target =
+                    find_monitor(&monitors, monitor.as_deref().ok_or("MONITOR_REQUIRED")?)?;
+                let (width, height) = match (width, height) {
+                    (Some(w), Some(h)) if w > 0 && h > 0 => (w, h),
+                    _ => return Err("RESOLUTION_REQUIRED".to_string()),
+                };
+                set_resolution(target, width, height)?;
+            }
+            "set_primary" => {
+                let target =
+                    find_monitor(&monitors, monitor.as_deref().ok_or("MONITOR_REQUIRED")?)?;
+                set_primary(target)?;
+            }
+            "set_wallpaper" => {
+                let image_path = image_path
+                    .filter(|p| std::path::Path::new(p.trim()).is_file())
+                    .ok_or("IMAGE_NOT_FOUND")?;
+                let target = match monitor.as_deref() {
+                    Some(m) => Some(find_monitor(&monitors, m)?),
+                    None => None,
+                };
+                set_wallpaper(target, image_path.trim())?;
+            }
+            other => return Err(format!("UNSUPPORTED_ACTION: {}", other)),
+        }
+
+        println!("✅ 디스플레이 설정 변경: {}", action);
+        Ok(json!({ "action": action, "success": true, "monitors": list_monitors()? }).to_string())
+    })
+    .await
+    .map_err(|e| format!("디스플레이 작업 실패: {}", e))?
+}
@@ -0,0 +1,198 @@
+// src-tauri/src/nodes/dns_node.rs
+use super::os_command;
+use serde_json::json;
+use std::path::PathBuf;
+
+// 🌐 DNS / hosts 파일 노드 (개발 환경 전환용)
+// action: resolve(hostname) | flush | hosts_list | hosts_add(hostname, ip) | hosts_remove(hostname)
+// hosts 파일 수정은 관리자 권한이 필요하며, 수정 전 항상 백업을 남김
+
+fn hosts_path() -> PathBuf {
+    if cfg!(target_os = "windows") {
+        let root = std::env::var("SystemRoot").unwrap_or_else(|_| "C:\\Windows".to_string());
+        PathBuf::from(root).join("System32\\drivers\\etc\\hosts")
+    } else {
+        PathBuf::from("/etc/hosts")
+    }
+}
+
+// hosts 한 줄 파싱: "127.0.0.1  app.local api.local  # comment" → (ip, [hosts])
+fn parse_hosts_line(line: &str) -> Option<(&str, Vec<&str>)> {
+    let content = line.split('#').next()?.trim();
+    let mut parts = content.split_whitespace();
+    let ip = parts.next()?;
+    let hosts: Vec<&str> = parts.collect();
+    (!hosts.is_empty()).then_some((ip, hosts))
+}
+
+pub fn list_hosts_entries(content: &str) -> Vec<(String, String)> {
+    content
+        .lines()
+        .filter_map(parse_hosts_line)
+        .flat_map(|(ip, hosts)| {
+            hosts
+                .into_iter()
+                .map(move |host| (host.to_string(), ip.to_string()))
+        })
+        .collect()
+}
+
+// 해당 호스트 이름을 모든 줄에서 제거 (다른 호스트가 남은 줄은 유지)
+pub fn remove_hosts_entry(content: &str, hostname: &str) -> String {
+    let mut lines: Vec<String> = Vec::new();
+
+    for line in content.lines() {
+        match parse_hosts_line(line) {
+            Some((ip, hosts)) if hosts.iter().any(|h| h.eq_ignore_ascii_case(hostname)) => {
+                let remaining: Vec<&str> = hosts
+                    .into_iter()
+                    .filter(|h| !h.eq_ignore_ascii_case(hostname))
+                    .collect();
+                if !remaining.is_empty() {
+                    lines.push(format!("{}\t{}", ip, remaining.join(" ")));
+                }
+            }
+            _ => lines.push(line.to_string()),
+        }
+    }
+
+    let mut result = lines.join("\n");
+    if content.ends_with('\n') || result.is_empty() {
+        result.push('\n');
+    }
+    result
+}
+
+// 기존 항목을 지우고 파일 끝에 "ip<TAB>hostname" 추가
+pub fn upsert_hosts_entry(content: &str, hostname: &str, ip: &str) -> String {
+    let mut result = remove_hosts_entry(content, hostname);
+    if !result.ends_with('\n') {
+        result.push('\n');
+    }
+    result.push_str(&format!("{}\t{}\n", ip, hostname));
+    result
+}
+
+fn write_hosts(content: &str) -> Result<String, String> {
+    let path = hosts_path();
+    let backup = path.with_file_name(format!(
+        "hosts.backup-{}",
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    ));
+
+    let map_err = |e: std::io::Error| {
+        if e.kind() == std::io::ErrorKind::PermissionDenied {
+            "ELEVATION_REQUIRED: hosts 파일 수정에는 관리자 권한이 필요합니다".to_string()
+        } else {
+            format!("HOSTS_WRITE_ERROR: {}", e)
+        }
+    };
+
+    std::fs::copy(&path, &backup).map_err(map_err)?;
+    std::fs::write(&path, content).map_err(map_err)?;
+    println!("📝 hosts 파일 수정 (백업: {})", backup.display());
+    Ok(backup.to_string_lossy().to_string())
+}
+
+fn flush_dns_cache() -> Result<(), String> {
+    if cfg!(target_os = "windows") {
+        os_command::run("ipconfig", &["/flushdns"])?;
+    } else if cfg!(target_os = "macos") {
+        os_command::run("dscacheutil", &["-flushcache"])?;
+        let _ = os_command::run("killall", &["-HUP", "mDNSResponder"]);
+    } else {
+        os_command::run("resolvectl", &["flush-caches"])?;
+    }
+    Ok(())
+}
+
+fn required(value: Option<String>, error: &str) -> Result<String, String> {
+    value
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+        .ok_or_else(|| error.to_string())
+}
+
+#[tauri::command]
+pub async fn dns_node(
+    action: String,
+    hostname: Option<String>,
+    ip: Option<String>,
+) -> Result<String, String> {
+    let action = action.trim().to_lowercase();
+    println!("🌐 DNS Node: {} {:?} {:?}", action, hostname, ip);
+
+    match action.as_str() {
+        "resolve" => {
+            let hostname = required(hostname, "EMPTY_HOSTNAME")?;
+            let addresses: Vec<String> = tokio::net::lookup_host((hostname.as_str(), 0))
+                .await
+                .map_err(|e| format!("RESOLVE_FAILED: {}", e))?
+                .map(|addr| addr.ip().to_string())
+                .collect();
+
+            Ok(json!({ "hostname": hostname, "addresses": addresses }).to_string())
+        }
+        "flush" => {
+            tokio::task::spawn_blocking(flush_dns_cache)
+                .await
+                .map_err(|e| format!("DNS 캐시 초기화 실패: {}", e))??;
+            Ok(json!({ "flushed": true }).to_string())
+        }
+        "hosts_list" => {
+            let content = std::fs::read_to_string(hosts_path())
+                .map_err(|e| format!("HOSTS_READ_ERROR: {}", e))?;
+            let entries: Vec<_> = list_hosts_entries(&content)
+                .into_iter()
+                .map(|(hostname, ip)| json!({ "hostname": hostname, "ip": ip }))
+                .collect();
+            Ok(json!({ "entries": entries }).to_string())
+        }
+        "hosts_add" | "hosts_remove" => {
+            let hostname = required(hostname, "EMPTY_HOSTNAME")?;
+            let content = std::fs::read_to_string(hosts_path())
+                .map_err(|e| format!("HOSTS_READ_ERROR: {}", e))?;
+
+            let updated = if action == "hosts_add" {
+                let ip = required(ip, "EMPTY_IP")?;
+                ip.parse::<std::net::IpAddr>()
+                    .map_err(|_| format!("INVALID_IP: {}", ip))?;
+                upsert_hosts_entry(&content, &hostname, &ip)
+            } else {
+                remove_hosts_entry(&content, &hostname)
+            };
+
+            if updated == content {
+                return Ok(json!({ "changed": false, "hostname": hostname }).to_string());
+            }
+
+            let backup = write_hosts(&updated)?;
+            // 변경 사항이 바로 반영되도록 캐시 초기화 (실패해도 무시)
+            let _ = tokio::task::spawn_blocking(flush_dns_cache).await;
+
+            Ok(json!({ "changed": true, "hostname": hostname, "backup": backup }).to_string())
+        }
+        other => Err(format!("UNSUPPORTED_ACTION: {}", other)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hosts_upsert_replaces_existing_entry_and_keeps_others() {
+        let content = "# local\n127.0.0.1\tlocalhost\n10.0.0.5\tapp.local api.local\n";
+
+        let updated = upsert_hosts_entry(content, "app.local", "127.0.0.1");
+
+        assert_eq!(
+            updated,
+            "# local\n127.0.0.1\tlocalhost\n10.0.0.5\tapi.local\n127.0.0.1\tapp.local\n"
+        );
+        assert_eq!(
+            remove_hosts_entry(&updated, "app.local"),
+            "# local\n127.0.0.1\tlocalhost\n10.0.0.5\tapi.local\n"
+        );
+    }
+}
@@ -0,0 +1,395 @@
+// src-tauri/src/nodes/doc_text_node.rs
+use super::value_store;
+use serde_json::json;
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::Path;
+
+// 📄 문서 텍스트 추출 노드 (.docx / .hwpx / .hwp)
+// AI 요약·검색 워크플로우에 문서 내용을 넣기 위해 본문 텍스트만 뽑아냄 (서식·이미지는 무시)
+// - docx, hwpx: zip 안의 XML (word/document.xml, Contents/section*.xml)
+// - hwp(5.0): OLE 복합 파일의 BodyText/Section* 스트림 (압축된 레코드 → 문단 텍스트 레코드)
+// 형식은 확장자가 아니라 파일 내용으로 판단
+
+const ZIP_MAGIC: &[u8] = b"PK\x03\x04";
+const OLE_MAGIC: &[u8] = &[0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1];
+const HWPTAG_PARA_TEXT: u32 = 16 + 51;
+
+fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let decoded = after.find(';').and_then(|end| {
+            let entity = &after[..end];
+            let c = match entity {
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "amp" => Some('&'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                _ => entity
+                    .strip_prefix("#x")
+                    .or_else(|| entity.strip_prefix("#X"))
+                    .map(|hex| u32::from_str_radix(hex, 16))
+                    .or_else(|| entity.strip_prefix('#').map(|dec| dec.parse::<u32>()))
+                    .and_then(|code| code.ok())
+                    .and_then(char::from_u32),
+            };
+            c.map(|c| (c, end))
+        });
+        match decoded {
+            Some((c, end)) => {
+                result.push(c);
+                rest = &after[end + 1..];
+            }
+            None => {
+                result.push('&');
+                rest = after;
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+// 문서 XML 에서 텍스트 요소(<w:t>, <hp:t>)만 모으고 문단 끝은 줄바꿈으로
+pub fn xml_text(xml: &str) -> String {
+    let mut text = String::new();
+    let mut in_text = false;
+    let mut rest = xml;
+
+    while let Some(lt) = rest.find('<') {
+        if in_text {
+            text.push_str(&decode_entities(&rest[..lt]));
+        }
+        let after = &rest[lt + 1..];
+        let Some(gt) = after.find('>') else {
+            break;
+        };
+        let tag = &after[..gt];
+        rest = &after[gt + 1..];
+        if tag.starts_with('?') || tag.starts_with('!') {
+            continue;
+        }
+
+        let closing = tag.starts_with('/');
+        let self_closing = tag.ends_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or("");
+        let local = name.rsplit(':').next().unwrap_or(name);
+        let has_attributes = tag
+            .trim_end_matches('/')
+            .trim()
+            .contains(char::is_whitespace);
+
+        match (local, closing) {
+            ("t", false) if !self_closing => in_text = true,
+            ("t", true) => in_text = false,
+            ("p", true) => text.push('\n'),
+            // 빈 문단 <w:p/>
+            ("p", false) if self_closing => text.push('\n'),
+            // docx 의 탭 정의(<w:tabs><w:tab w:val=…/>)는 제외, hwpx 탭은 텍스트 안에만 있음
+            ("tab", false) if in_text || !has_attributes => text.push('\t'),
+            ("br" | "cr" | "lineBreak", false) => text.push('\n'),
+            _ => {}
+        }
+    }
+    text
+}
+
+// HWP 문단 텍스트 레코드 (UTF-16LE, 제어 문자는 1칸 또는 8칸 차지)
+pub fn hwp_para_text(payload: &[u8]) -> String {
+    let units: Vec<u16> = payload
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+        .collect();
+    let mut text: Vec<u16> = Vec::with_capacity(units.len());
+    let mut index = 0;
+    while index < units.len() {
+        let unit = units[index];
+        index += match unit {
+            // 문자 컨트롤 (1칸)
+            0 | 24..=31 => 1,
+            10 | 13 => {
+                text.push('\n' as u16);
+                1
+            }
+            // 탭은 인라인 컨트롤 (8칸)
+            9 => {
+                text.push('\t' as u16);
+                8
+            }
+            // 표·그림 등 확장/인라인 컨트롤 (8칸)
+            1..=8 | 11 | 12 | 14..=23 => 8,
+            _ => {
+                text.push(unit);
+                1
+            }
+        };
+    }
+    String::from_utf16_lossy(&text)
+}
+
+// 섹션 스트림(압축 해제된)의 레코드를 따라가며 문단 텍스트만 수집
+pub fn hwp_records_text(data: &[u8]) -> String {
+    let mut text = String::new();
+    let mut pos = 0;
+    while pos + 4 <= data.len() {
+        let header = u32::from_le_bytes([data[pos], data[pos + 1], data[pos + 2], data[pos + 3]]);
+        pos += 4;
+        let tag = header & 0x3FF;
+        let mut size = (header >> 20) as usize;
+        if size == 0xFFF {
+            if pos + 4 > data.len() {
+                break;
+            }
+            size = u32::from_le_bytes([data[pos], data[pos + 1], data[pos + 2], data[pos + 3]])
+                as usize;
+            pos += 4;
+        }
+        if pos + size > data.len() {
+            break;
+        }
+        if tag == HWPTAG_PARA_TEXT {
+            text.push_str(&hwp_para_text(&data[pos..pos + size]));
+        }
+        pos += size;
+    }
+    text
+}
+
+fn read_zip_entry<R: Read + std::io::Seek>(
+    archive: &mut zip::ZipArchive<R>,
+    name: &str,
+) -> Result<String, String> {
+    let mut entry = archive
+        .by_name(name)
+        .map_err(|e| format!("문서 항목 읽기 실패 ({}): {}", name, e))?;
+    let mut xml = String::new();
+    entry
+        .read_to_string(&mut xml)
+        .map_err(|e| format!("문서 항목 읽기 실패 ({}): {}", name, e))?;
+    Ok(xml)
+}
+
+fn extract_zip(path: &Path) -> Result<(&'static str, String), String> {
+    let file = File::open(path).map_err(|e| format!("파일 열기 실패: {}", e))?;
+    let mut archive = zip::ZipArchive::new(BufReader::new(file))
+        .map_err(|e| format!("INVALID_DOCUMENT: {}", e))?;
+
+    if archive.file_names().any(|name| name == "word/document.xml") {
+        return Ok((
+            "docx",
+            xml_text(&read_zip_entry(&mut archive, "word/document.xml")?),
+        ));
+    }
+
+    // hwpx: Contents/section0.xml, section1.xml … 순서대로
+    let mut sections: Vec<(u32, String)> = archive
+        .file_names()
+        .filter_map(|name| {
+            let number = name
+                .strip_prefix("Contents/section")?
+                .strip_suffix(".xml")?
+                .parse::<u32>()
+                .ok()?;
+            Some((number, name.to_string()))
+        })
+        .collect();
+    if sections.is_empty() {
+        return Err("UNSUPPORTED_FORMAT".to_string());
+    }
+    sections.sort();
+    let mut text = String::new();
+    for (_, name) in sections {
+        text.push_str(&xml_text(&read_zip_entry(&mut archive, &name)?));
+    }
+    Ok(("hwpx", text))
+}
+
+fn extract_hwp(path: &Path) -> Result<(&'static str, String), String> {
+    let mut compound = cfb::open(path).map_err(|e| format!("INVALID_DOCUMENT: {}", e))?;
+    if !compound.exists("/FileHeader") {
+        // 구형 .doc / .xls 등 다른 OLE 문서
+        return Err("UNSUPPORTED_FORMAT".to_string());
+    }
+
+    let mut header = Vec::new();
+    compound
+        .open_stream("/FileHeader")
+        .and_then(|mut stream| stream.read_to_end(&mut header))
+        .map_err(|e| format!("HWP 헤더 읽기 실패: {}", e))?;
+    if !header.starts_with(b"HWP Document File") || header.len() < 40 {
+        return Err("UNSUPPORTED_FORMAT".to_string());
+    }
+    let flags = u32::from_le_bytes([header[36], header[37], header[38], header[39]]);
+    if flags & 0x02 != 0 {
+        return Err("HWP_PASSWORD_PROTECTED".to_string());
+    }
+    if flags & 0x04 != 0 {
+        return Err("HWP_DISTRIBUTION_NOT_SUPPORTED".to_string());
+    }
+    let compressed = flags & 0x01 != 0;
+
+    let mut text = String::new();
+    for index in 0.. {
+        let name = format!("/BodyText/Section{}", index);
+        if !compound.exists(&name) {
+            break;
+        }
+        let mut raw = Vec::new();
+        compound
+            .open_stream(&name)
+            .and_then(|mut stream| stream.read_to_end(&mut raw))
+            .map_err(|e| format!("HWP 본문 읽기 실패: {}", e))?;
+        let data = if compressed {
+            let mut data = Vec::new();
+            flate2::read::DeflateDecoder::new(raw.as_slice())
+                .read_to_end(&mut data)
+                .map_err(|e| format!("HWP 본문 압축 해제 실패: {}", e))?;
+            data
+        } else {
+            raw
+        };
+        text.push_str(&hwp_records_text(&data));
+    }
+    Ok(("hwp", text))
+}
+
+pub fn extract_text(path: &Path) -> Result<(&'static str, String), String> {
+    let mut magic = [0u8; 8];
+    let read = File::open(path)
+        .and_then(|mut file| file.read(&mut magic))
+        .map_err(|e| format!("파일 열기 실패: {}", e))?;
+    let magic = &magic[..read];
+
+    let (format, text) = if magic.starts_with(ZIP_MAGIC) {
+        extract_zip(path)?
+    } else if magic.starts_with(OLE_MAGIC) {
+        extract_hwp(path)?
+    } else {
+        return Err("UNSUPPORTED_FORMAT".to_string());
+    };
+
+    // 문단 사이 빈 줄이 여러 개 겹치지 않도록 정리
+    let mut cleaned = String::with_capacity(text.len());
+    let mut blank_lines = 0;
+    for line in text.lines().map(str::trim_end) {
+        if line.trim().is_empty() {
+            blank_lines += 1;
+            if blank_lines > 1 {
+                continue;
+            }
+        } else {
+            blank_lines = 0;
+        }
+        cleaned.push_str(line);
+        cleaned.push('\n');
+    }
+    Ok((format, cleaned.trim().to_string()))
+}
+
+#[tauri::command]
+pub async fn doc_text_node(path: String) -> Result<String, String> {
+    let path = super::safe_path::resolve_existing(&path);
+    if !path.is_file() {
+        return Err("FILE_NOT_FOUND".to_string());
+    }
+    println!("📄 Doc Text Node: {}", path.display());
+
+    let (format, text) = tokio::task::spawn_blocking(move || extract_text(&path))
+        .await
+        .map_err(|e| format!("문서 텍스트 추출 실패: {}", e))??;
+
+    let mut result = json!({
+        "format": format,
+        "text": text,
+        "chars": text.chars().count()
+    });
+
+    // 너무 긴 문서는 보관소에 두고 미리보기 + 핸들만 전달
+    if let Some(stored) = value_store::offload(&text) {
+        result["text"] = json!(stored.preview);
+        result["outputHandle"] = json!(stored.handle);
+        result["truncated"] = json!(true);
+    }
+
+    Ok(result.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::{parse_output, path_str, temp_dir};
+    use std::fs;
+
+    #[test]
+    fn doc_text_reads_paragraphs_from_document_xml() {
+        let docx = r#"<w:document><w:body><w:p><w:pPr><w:tabs><w:tab w:val="left" w:pos="720"/></w:tabs></w:pPr><w:r><w:t xml:space="preserve">회의 &amp; </w:t></w:r><w:r><w:tab/><w:t>안건</w:t></w:r></w:p><w:p><w:r><w:t>둘째 줄</w:t></w:r></w:p></w:body></w:document>"#;
+        assert_eq!(xml_text(docx), "회의 & \t안건\n둘째 줄\n");
+
+        let hwpx =
+            r#"<hs:sec><hp:p><hp:run><hp:t>가<hp:tab width="4000"/>나</hp:t></hp:run></hp:p></hs:sec>"#;
+        assert_eq!(xml_text(hwpx), "가\t나\n");
+    }
+
+    #[test]
+    fn doc_text_decodes_hwp_paragraph_records() {
+        let mut payload: Vec<u8> = Vec::new();
+        let mut push = |units: &[u16]| {
+            for unit in units {
+                payload.extend(unit.to_le_bytes());
+            }
+        };
+        push(&"보고서".encode_utf16().collect::<Vec<_>>());
+        // 표 같은 확장 컨트롤은 8칸을 차지하고 텍스트에 나오지 않음
+        push(&[11, 0, 0, 0, 0, 0, 0, 11]);
+        push(&[13]);
+
+        let mut section = Vec::new();
+        section.extend((67u32 | ((payload.len() as u32) << 20)).to_le_bytes());
+        section.extend(&payload);
+        // 다른 레코드(문단 헤더 등)는 건너뜀
+        section.extend((66u32 | (2 << 20)).to_le_bytes());
+        section.extend([0, 0]);
+
+        assert_eq!(hwp_records_text(&section), "보고서\n");
+    }
+
+    #[tokio::test]
+    async fn doc_text_extracts_docx_file() {
+        use std::io::Write;
+
+        let dir = temp_dir();
+        let path = dir.path().join("memo.docx");
+        let mut zip = zip::ZipWriter::new(fs::File::create(&path).unwrap());
+        zip.start_file("word/document.xml", zip::write::FileOptions::default())
+            .unwrap();
+        zip.write_all(
+            r#"<w:document><w:body><w:p><w:r><w:t>첫 문단</w:t></w:r></w:p><w:p/><w:p/><w:p><w:r><w:t>끝</w:t></w:r></w:p></w:body></w:document>"#.as_bytes(),
+        )
+        .unwrap();
+        zip.finish().unwrap();
+
+        let output = parse_output(&doc_text_node(path_str(&path)).await.unwrap());
+        assert_eq!(output["format"], "docx");
+        assert_eq!(output["text"], "첫 문단\n\n끝");
+
+        let plain = dir.path().join("memo.txt");
+        fs::write(&plain, "plain").unwrap();
+        assert_eq!(
+            doc_text_node(path_str(&plain))
+                .await
+                .unwrap_err(),
+            "UNSUPPORTED_FORMAT"
+        );
+    }
+}
@@ -0,0 +1,239 @@
+// src-tauri/src/nodes/doctor.rs
+use super::{atomic_store, diagnostics, os_command, secrets, tools};
+use serde::Serialize;
+use std::net::{TcpStream, ToSocketAddrs};
+use std::path::Path;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+
+// 🩺 시작 시 점검 / 의존성 닥터
+// "노드 X 가 말없이 실패함" 을 사용자가 스스로 진단할 수 있도록 구조화된 보고서 반환
+// - tools:    번들 도구(yt-dlp, ffmpeg, cloudflared) 실행 여부 + 버전
+// - network:  주요 호스트 TCP 연결 (DNS 포함)
+// - paths:    store / logs / 임시 폴더 쓰기 가능 여부
+// - keyring:  OS 키체인 쓰기 / 읽기 / 삭제
+// 앱 시작 시 백그라운드로 한 번 실행해 실패 항목이 있으면 "doctor-report" 이벤트 전송
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+const NETWORK_TARGETS: [(&str, &str); 3] = [
+    ("github", "github.com:443"),
+    ("youtube", "www.youtube.com:443"),
+    ("cloudflare_tunnel", "region1.v2.argotunnel.com:7844"),
+];
+const KEYRING_PROBE: &str = "doctor.probe";
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct DoctorCheck {
+    pub category: String, // "tools" | "network" | "paths" | "keyring"
+    pub name: String,
+    pub status: String, // "ok" | "warn" | "fail"
+    pub message: String,
+    pub hint: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DoctorReport {
+    pub generated_at: String,
+    pub ok: bool,
+    pub failed: usize,
+    pub warnings: usize,
+    pub checks: Vec<DoctorCheck>,
+}
+
+fn check(category: &str, name: &str, result: Result<String, String>, hint: &str) -> DoctorCheck {
+    let (status, message, hint) = match result {
+        Ok(message) => ("ok", message, None),
+        Err(message) => ("fail", message, Some(hint.to_string())),
+    };
+    DoctorCheck {
+        category: category.to_string(),
+        name: name.to_string(),
+        status: status.to_string(),
+        message,
+        hint,
+    }
+}
+
+fn version_args(tool: &str) -> &'static [&'static str] {
+    match tool {
+        "ffmpeg" => &["-version"],
+        _ => &["--version"],
+    }
+}
+
+fn check_tools() -> Vec<DoctorCheck> {
+    diagnostics::BUNDLED_TOOLS
+        .iter()
+        .map(|tool| {
+            let path = os_command::bundled_binary(tool);
+            let bundled = Path::new(&path).is_absolute();
+            let result = os_command::run(&path, version_args(tool)).map(|output| {
+                let version = output.lines().next().unwrap_or("").trim().to_string();
+                let source = if !bundled {
+                    "PATH"
+                } else if Path::new(&path).starts_with(tools::tools_dir()) {
+                    "도구 관리자"
+                } else {
+                    "번들"
+                };
+                format!("{} ({}: {})", version, source, path)
+            });
+            let mut checked = check(
+                "tools",
+                tool,
+                result,
+                "install_tool 로 설치하거나 도구를 PATH 에 추가하세요",
+            );
+            // PATH 에서 찾았으면 동작은 하지만 버전이 앱과 다를 수 있음
+            if checked.status == "ok" && !bundled {
+                checked.status = "warn".to_string();
+                checked.hint = Some("번들 도구를 찾지 못해 시스템 PATH 의 도구를 사용합니다".to_string());
+            }
+            checked
+        })
+        .collect()
+}
+
+pub fn probe_host(address: &str, timeout: Duration) -> Result<String, String> {
+    let started = std::time::Instant::now();
+    let addrs: Vec<_> = address
+        .to_socket_addrs()
+        .map_err(|e| format!("DNS 조회 실패: {}", e))?
+        .collect();
+    let mut last_error = "주소 없음".to_string();
+    for addr in addrs {
+        match TcpStream::connect_timeout(&addr, timeout) {
+            Ok(_) => return Ok(format!("{} ({}ms)", addr, started.elapsed().as_millis())),
+            Err(e) => last_error = e.to_string(),
+        }
+    }
+    Err(format!("연결 실패: {}", last_error))
+}
+
+fn check_network() -> Vec<DoctorCheck> {
+    let handles: Vec<_> = NETWORK_TARGETS
+        .iter()
+        .map(|&(name, address)| {
+            std::thread::spawn(move || {
+                check(
+                    "network",
+                    name,
+                    probe_host(address, CONNECT_TIMEOUT),
+                    "인터넷 연결, 방화벽, 프록시 설정을 확인하세요",
+                )
+            })
+        })
+        .collect();
+    handles.into_iter().filter_map(|h| h.join().ok()).collect()
+}
+
+// 임시 파일을 만들었다 지워 보는 방식 (권한 / 디스크 가득 참 / 읽기 전용 폴더 감지)
+pub fn probe_writable(dir: &Path) -> Result<String, String> {
+    std::fs::create_dir_all(dir).map_err(|e| format!("폴더 생성 실패: {}", e))?;
+    let probe = dir.join(format!(".doctor-{}.tmp", std::process::id()));
+    std::fs::write(&probe, b"ok").map_err(|e| format!("쓰기 실패: {}", e))?;
+    let _ = std::fs::remove_file(&probe);
+    Ok(dir.to_string_lossy().to_string())
+}
+
+fn check_paths() -> Vec<DoctorCheck> {
+    [
+        ("store", atomic_store::store_dir()),
+        ("logs", diagnostics::logs_dir()),
+        ("temp", std::env::temp_dir()),
+    ]
+    .iter()
+    .map(|(name, dir)| {
+        check(
+            "paths",
+            name,
+            probe_writable(dir),
+            "폴더 권한과 남은 디스크 공간을 확인하세요",
+        )
+    })
+    .collect()
+}
+
+fn check_keyring() -> DoctorCheck {
+    check(
+        "keyring",
+        "os_keyring",
+        secrets::probe(KEYRING_PROBE).map(|_| "쓰기 / 읽기 / 삭제 정상".to_string()),
+        "Linux 는 Secret Service(gnome-keyring 등)가 실행 중인지 확인하세요",
+    )
+}
+
+pub fn summarize(checks: Vec<DoctorCheck>) -> DoctorReport {
+    let failed = checks.iter().filter(|c| c.status == "fail").count();
+    let warnings = checks.iter().filter(|c| c.status == "warn").count();
+    DoctorReport {
+        generated_at: chrono::Local::now().to_rfc3339(),
+        ok: failed == 0,
+        failed,
+        warnings,
+        checks,
+    }
+}
+
+pub fn run_checks() -> DoctorReport {
+    let mut checks = check_tools();
+    checks.extend(check_network());
+    checks.extend(check_paths());
+    checks.push(check_keyring());
+    summarize(checks)
+}
+
+// 앱 시작 시 호출 (문제가 있을 때만 알림)
+pub fn check_on_launch(app_handle: &AppHandle) {
+    let app_handle = app_handle.clone();
+    std::thread::spawn(move || {
+        let report = run_checks();
+        println!(
+            "🩺 시작 점검: 실패 {} / 경고 {} / 전체 {}",
+            report.failed,
+            report.warnings,
+            report.checks.len()
+        );
+        if !report.ok {
+            let _ = app_handle.emit("doctor-report", &report);
+        }
+    });
+}
+
+#[tauri::command]
+pub async fn doctor() -> Result<DoctorReport, String> {
+    tokio::task::spawn_blocking(run_checks)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::temp_dir;
+    use std::fs;
+
+    #[test]
+    fn doctor_probes_writable_dirs_and_summarizes_failures() {
+        let dir = temp_dir();
+        let nested = dir.path().join("app").join("store");
+        assert!(probe_writable(&nested).is_ok());
+        assert_eq!(fs::read_dir(&nested).unwrap().count(), 0);
+
+        let blocker = dir.path().join("file.txt");
+        fs::write(&blocker, "x").unwrap();
+        assert!(probe_writable(&blocker.join("sub")).is_err());
+
+        let item = |status: &str| DoctorCheck {
+            category: "tools".to_string(),
+            name: "ffmpeg".to_string(),
+            status: status.to_string(),
+            message: String::new(),
+            hint: None,
+        };
+        let report = summarize(vec![item("ok"), item("warn"), item("fail")]);
+        assert!(!report.ok);
+        assert_eq!((report.failed, report.warnings), (1, 1));
+        assert!(summarize(vec![item("ok"), item("warn")]).ok);
+    }
+}
@@ -0,0 +1,301 @@
+// src-tauri/src/nodes/download_registry.rs
+use super::atomic_store;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+// 📚 다운로드 기록 (중복 다운로드 방지)
+// URL 목록이 겹치는 워크플로우를 다시 실행해도 이미 받은 항목은 건너뛰도록
+// 정규화한 URL 과 받은 파일의 내용 해시(SHA-256)를 store/download_history.json 에 보관
+// - URL: 추적용 파라미터(utm_*, si 등)와 #조각 제거, youtu.be/shorts 주소는 watch?v= 로 통일
+// - 내용 해시: 다른 URL 로 같은 파일을 받으면 새 파일을 지우고 기존 파일을 알려줌
+
+const TRACKING_PARAMS: &[&str] = &[
+    "si",
+    "feature",
+    "igshid",
+    "igsh",
+    "fbclid",
+    "gclid",
+    "ref",
+    "ref_src",
+    "is_from_webapp",
+    "sender_device",
+    "_r",
+    "_t",
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DownloadEntry {
+    pub url: String,
+    // "video" | "podcast" …
+    pub source: String,
+    pub file: Option<String>,
+    pub sha256: Option<String>,
+    pub size: Option<u64>,
+    pub downloaded_at: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DownloadHistory {
+    pub entries: BTreeMap<String, DownloadEntry>,
+}
+
+static HISTORY: std::sync::OnceLock<Mutex<DownloadHistory>> = std::sync::OnceLock::new();
+
+fn history_path() -> PathBuf {
+    atomic_store::store_dir().join("download_history.json")
+}
+
+fn get_history() -> &'static Mutex<DownloadHistory> {
+    HISTORY.get_or_init(|| {
+        Mutex::new(atomic_store::read_json_verified(&history_path()).unwrap_or_default())
+    })
+}
+
+pub fn normalize_url(url: &str) -> String {
+    let trimmed = url.trim();
+    let Ok(mut parsed) = url::Url::parse(trimmed) else {
+        return trimmed.to_string();
+    };
+    parsed.set_fragment(None);
+    let host = parsed.host_str().unwrap_or("").to_lowercase();
+    let host = host.strip_prefix("www.").unwrap_or(&host).to_string();
+    let host = host.strip_prefix("m.").unwrap_or(&host).to_string();
+
+    // 유튜브 영상 주소 통일
+    let youtube_id = match host.as_str() {
+        "youtu.be" => parsed
+            .path_segments()
+            .and_then(|mut s| s.next())
+            .map(str::to_string),
+        "youtube.com" => {
+            let segments: Vec<&str> = parsed
+                .path_segments()
+                .map(|s| s.collect())
+                .unwrap_or_default();
+            match segments.as_slice() {
+                ["shorts", id, ..] | ["live", id, ..] | ["embed", id, ..] => Some(id.to_string()),
+                ["watch", ..] => parsed
+                    .query_pairs()
+                    .find(|(k, _)| k == "v")
+                    .map(|(_, v)| v.to_string()),
+                _ => None,
+            }
+        }
+        _ => None,
+    };
+    if let Some(id) = youtube_id.filter(|id| !id.is_empty()) {
+        return format!("https://youtube.com/watch?v={}", id);
+    }
+
+    let kept: Vec<(String, String)> = parsed
+        .query_pairs()
+        .filter(|(key, _)| {
+            let key = key.to_lowercase();
+            !key.starts_with("utm_") && !TRACKING_PARAMS.contains(&key.as_str())
+        })
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+    if kept.is_empty() {
+        parsed.set_query(None);
+    } else {
+        parsed.query_pairs_mut().clear().extend_pairs(kept);
+    }
+    let _ = parsed.set_host(Some(&host));
+
+    let mut normalized = parsed.to_string();
+    if normalized.ends_with('/') && parsed.path() != "/" {
+        normalized.pop();
+    }
+    normalized
+}
+
+pub fn file_sha256(path: &Path) -> Result<(String, u64), String> {
+    let mut file = std::fs::File::open(path).map_err(|e| format!("파일 열기 실패: {}", e))?;
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0u8; 1024 * 1024];
+    let mut size = 0u64;
+    loop {
+        let read = file
+            .read(&mut buffer)
+            .map_err(|e| format!("파일 읽기 실패: {}", e))?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+        size += read as u64;
+    }
+    Ok((format!("{:x}", hasher.finalize()), size))
+}
+
+impl DownloadHistory {
+    pub fn find_url(&self, url: &str) -> Option<&DownloadEntry> {
+        self.entries.get(&normalize_url(url))
+    }
+
+    pub fn find_hash(&self, sha256: &str) -> Option<&DownloadEntry> {
+        self.entries
+            .values()
+            .find(|entry| entry.sha256.as_deref() == Some(sha256))
+    }
+
+    pub fn insert(&mut self, entry: DownloadEntry) {
+        self.entries.insert(normalize_url(&entry.url), entry);
+    }
+}
+
+// 이미 받은 URL 이면 기록 반환
+pub fn already_downloaded(url: &str) -> Option<DownloadEntry> {
+    get_history().lock().ok()?.find_url(url).cloned()
+}
+
+// 다운로드 완료 기록. 같은 내용의 파일이 이미 있으면 새 파일은 지우고 기존 기록 반환
+pub fn record_download(url: &str, file: Option<&Path>, source: &str) -> Option<DownloadEntry> {
+    let hashed = file.and_then(|path| match file_sha256(path) {
+        Ok(hash) => Some(hash),
+        Err(e) => {
+            println!("⚠️ 다운로드 해시 계산 실패: {}", e);
+            None
+        }
+    });
+
+    let mut history = get_history().lock().ok()?;
+    let duplicate = hashed.as_ref().and_then(|(sha256, _)| {
+        history
+            .find_hash(sha256)
+            .filter(|existing| {
+                existing.file.as_deref() != file.map(|f| f.to_string_lossy()).as_deref()
+                    && existing
+                        .file
+                        .as_ref()
+                        .is_some_and(|f| Path::new(f).exists())
+            })
+            .cloned()
+    });
+
+    if let (Some(existing), Some(path)) = (&duplicate, file) {
+        println!(
+            "♻️ 같은 내용의 파일이 이미 있음: {} (새 파일 삭제)",
+            existing.file.as_deref().unwrap_or("")
+        );
+        let _ = std::fs::remove_file(path);
+    }
+
+    let entry = DownloadEntry {
+        url: url.to_string(),
+        source: source.to_string(),
+        file: duplicate
+            .as_ref()
+            .and_then(|existing| existing.file.clone())
+            .or_else(|| file.map(|f| f.to_string_lossy().to_string())),
+        sha256: hashed.as_ref().map(|(sha256, _)| sha256.clone()),
+        size: hashed.map(|(_, size)| size),
+        downloaded_at: chrono::Local::now().to_rfc3339(),
+    };
+    history.insert(entry);
+    if let Err(e) = atomic_store::write_json_atomic(&history_path(), &*history) {
+        println!("⚠️ 다운로드 기록 저장 실패: {}", e);
+    }
+    duplicate
+}
+
+#[tauri::command]
+pub fn check_download_history(urls: Vec<String>) -> Result<String, String> {
+    let history = get_history().lock().map_err(|e| e.to_string())?;
+    let results: Vec<_> = urls
+        .iter()
+        .map(|url| json!({ "url": url, "downloaded": history.find_url(url) }))
+        .collect();
+    Ok(json!({ "results": results }).to_string())
+}
+
+#[tauri::command]
+pub fn get_download_history(limit: Option<usize>) -> Result<String, String> {
+    let history = get_history().lock().map_err(|e| e.to_string())?;
+    let mut entries: Vec<&DownloadEntry> = history.entries.values().collect();
+    entries.sort_by(|a, b| b.downloaded_at.cmp(&a.downloaded_at));
+    entries.truncate(limit.unwrap_or(200));
+    Ok(json!({ "entries": entries, "total": history.entries.len() }).to_string())
+}
+
+// source 를 주면 해당 종류만 삭제 (받은 파일은 지우지 않음)
+#[tauri::command]
+pub fn clear_download_history(source: Option<String>) -> Result<String, String> {
+    let mut history = get_history().lock().map_err(|e| e.to_string())?;
+    let before = history.entries.len();
+    match source.as_deref().map(str::trim).filter(|s| !s.is_empty()) {
+        Some(source) => history.entries.retain(|_, entry| entry.source != source),
+        None => history.entries.clear(),
+    }
+    let removed = before - history.entries.len();
+    atomic_store::write_json_atomic(&history_path(), &*history)?;
+    println!("🧹 다운로드 기록 {}개 삭제", removed);
+    Ok(json!({ "removed": removed }).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::{path_str, temp_dir};
+    use std::fs;
+
+    #[test]
+    fn download_registry_normalizes_urls() {
+        let canonical = "https://youtube.com/watch?v=abc123";
+        assert_eq!(
+            normalize_url("https://youtu.be/abc123?si=xyz"),
+            canonical
+        );
+        assert_eq!(
+            normalize_url("https://www.youtube.com/watch?v=abc123&feature=share"),
+            canonical
+        );
+        assert_eq!(
+            normalize_url("https://m.youtube.com/shorts/abc123"),
+            canonical
+        );
+        assert_eq!(
+            normalize_url("https://Example.com/ep1.mp3?utm_source=rss&id=7#t=30"),
+            "https://example.com/ep1.mp3?id=7"
+        );
+        assert_eq!(
+            normalize_url("https://www.instagram.com/reel/XYZ/?igsh=abc"),
+            "https://instagram.com/reel/XYZ"
+        );
+    }
+
+    #[test]
+    fn download_registry_finds_entries_by_url_and_hash() {
+        use {DownloadEntry, DownloadHistory};
+
+        let dir = temp_dir();
+        let file = dir.path().join("a.bin");
+        fs::write(&file, b"hello").unwrap();
+        let (sha256, size) = file_sha256(&file).unwrap();
+        assert_eq!(size, 5);
+        assert_eq!(
+            sha256,
+            "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+        );
+
+        let mut history = DownloadHistory::default();
+        history.insert(DownloadEntry {
+            url: "https://youtu.be/abc123".into(),
+            source: "video".into(),
+            file: Some(path_str(&file)),
+            sha256: Some(sha256.clone()),
+            size: Some(size),
+            downloaded_at: "2026-01-01T00:00:00+09:00".into(),
+        });
+        assert!(history
+            .find_url("https://www.youtube.com/watch?v=abc123&si=1")
+            .is_some());
+        assert!(history.find_url("https://youtu.be/other").is_none());
+        assert_eq!(history.find_hash(&sha256).unwrap().source, "video");
+    }
+}
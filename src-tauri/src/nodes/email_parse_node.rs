@@ -0,0 +1,381 @@
+// src-tauri/src/nodes/email_parse_node.rs
+use super::safe_path;
+use mail_parser::{Address, MessageParser, MimeHeaders};
+use serde::Serialize;
+use serde_json::json;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+// ✉️ 이메일 파일 파싱 노드 (.eml / Outlook .msg)
+// 끌어다 놓은 메일을 헤더 / 본문 / 첨부파일 구조로 나누고 첨부파일은 디스크에 저장
+// - .eml: MIME 메시지 (mail-parser)
+// - .msg: OLE 복합 파일의 MAPI 속성 스트림 (전송 헤더가 있으면 헤더 기준)
+// 첨부파일 기본 저장 위치: <메일 폴더>/<메일 이름>_attachments
+
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct EmailAddress {
+    pub name: Option<String>,
+    pub address: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AttachmentInfo {
+    pub name: String,
+    pub content_type: Option<String>,
+    pub size: usize,
+    pub path: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ParsedEmail {
+    pub format: String,
+    pub subject: Option<String>,
+    pub from: Vec<EmailAddress>,
+    pub to: Vec<EmailAddress>,
+    pub cc: Vec<EmailAddress>,
+    pub reply_to: Vec<EmailAddress>,
+    pub date: Option<String>,
+    pub message_id: Option<String>,
+    pub text: String,
+    pub html: Option<String>,
+    pub attachments: Vec<AttachmentInfo>,
+}
+
+fn convert_addresses(address: Option<&Address>) -> Vec<EmailAddress> {
+    let Some(address) = address else {
+        return Vec::new();
+    };
+    let list: Vec<&mail_parser::Addr> = match address {
+        Address::List(list) => list.iter().collect(),
+        Address::Group(groups) => groups.iter().flat_map(|g| g.addresses.iter()).collect(),
+    };
+    list.into_iter()
+        .map(|addr| EmailAddress {
+            name: addr.name.as_ref().map(|n| n.to_string()),
+            address: addr.address.as_ref().map(|a| a.to_string()),
+        })
+        .collect()
+}
+
+// .eml 파싱 (첨부파일 내용은 따로 반환)
+pub fn parse_eml(bytes: &[u8]) -> Result<(ParsedEmail, Vec<Vec<u8>>), String> {
+    let message = MessageParser::default()
+        .parse(bytes)
+        .ok_or("INVALID_EMAIL")?;
+
+    let mut contents = Vec::new();
+    let attachments = message
+        .attachments()
+        .enumerate()
+        .map(|(index, part)| {
+            let data = part.contents().to_vec();
+            let info = AttachmentInfo {
+                name: part
+                    .attachment_name()
+                    .map(str::to_string)
+                    .unwrap_or_else(|| format!("attachment-{}", index + 1)),
+                content_type: part.content_type().map(|ct| match ct.subtype() {
+                    Some(subtype) => format!("{}/{}", ct.ctype(), subtype),
+                    None => ct.ctype().to_string(),
+                }),
+                size: data.len(),
+                path: None,
+            };
+            contents.push(data);
+            info
+        })
+        .collect();
+
+    let email = ParsedEmail {
+        format: "eml".to_string(),
+        subject: message.subject().map(str::to_string),
+        from: convert_addresses(message.from()),
+        to: convert_addresses(message.to()),
+        cc: convert_addresses(message.cc()),
+        reply_to: convert_addresses(message.reply_to()),
+        date: message.date().map(|d| d.to_rfc3339()),
+        message_id: message.message_id().map(str::to_string),
+        text: message
+            .body_text(0)
+            .map(|t| t.trim().to_string())
+            .unwrap_or_default(),
+        html: message.body_html(0).map(|h| h.to_string()),
+        attachments,
+    };
+    Ok((email, contents))
+}
+
+type MsgFile = cfb::CompoundFile<std::fs::File>;
+
+fn read_stream(file: &mut MsgFile, path: &str) -> Option<Vec<u8>> {
+    if !file.exists(path) {
+        return None;
+    }
+    let mut data = Vec::new();
+    file.open_stream(path).ok()?.read_to_end(&mut data).ok()?;
+    Some(data)
+}
+
+// MAPI 문자열 속성: 001F(UTF-16LE) 우선, 없으면 001E(8비트, UTF-8 아니면 CP949 로 가정)
+fn msg_string(file: &mut MsgFile, storage: &str, property: &str) -> Option<String> {
+    if let Some(data) = read_stream(file, &format!("{}/__substg1.0_{}001F", storage, property)) {
+        let units: Vec<u16> = data
+            .chunks_exact(2)
+            .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+            .collect();
+        return Some(
+            String::from_utf16_lossy(&units)
+                .trim_end_matches('\0')
+                .to_string(),
+        );
+    }
+    let data = read_stream(file, &format!("{}/__substg1.0_{}001E", storage, property))?;
+    let text = match String::from_utf8(data.clone()) {
+        Ok(text) => text,
+        Err(_) => encoding_rs::EUC_KR.decode(&data).0.to_string(),
+    };
+    Some(text.trim_end_matches('\0').to_string())
+}
+
+fn display_names(names: Option<String>) -> Vec<EmailAddress> {
+    names
+        .unwrap_or_default()
+        .split(';')
+        .map(str::trim)
+        .filter(|n| !n.is_empty())
+        .map(|name| EmailAddress {
+            name: Some(name.to_string()),
+            address: None,
+        })
+        .collect()
+}
+
+pub fn parse_msg(path: &Path) -> Result<(ParsedEmail, Vec<Vec<u8>>), String> {
+    let mut file = cfb::open(path).map_err(|e| format!("INVALID_EMAIL: {}", e))?;
+    let root = "";
+
+    // 전송 헤더가 있으면 주소/날짜는 헤더에서 (표시 이름만 있는 속성보다 정확)
+    let headers = msg_string(&mut file, root, "007D")
+        .and_then(|headers| {
+            let raw = format!("{}\r\n\r\n", headers.trim_end());
+            parse_eml(raw.as_bytes()).ok().map(|(email, _)| email)
+        })
+        .unwrap_or_default();
+
+    let sender = EmailAddress {
+        name: msg_string(&mut file, root, "0C1A"),
+        address: msg_string(&mut file, root, "5D01")
+            .or_else(|| msg_string(&mut file, root, "0C1F")),
+    };
+    let html = read_stream(&mut file, "/__substg1.0_10130102")
+        .map(|data| String::from_utf8_lossy(&data).to_string())
+        .or_else(|| msg_string(&mut file, root, "1013"));
+
+    let mut attachments = Vec::new();
+    let mut contents = Vec::new();
+    for index in 0.. {
+        let storage = format!("/__attach_version1.0_#{:08X}", index);
+        if !file.exists(&storage) {
+            break;
+        }
+        // 첨부된 메일(.msg 안의 .msg)처럼 데이터 스트림이 없는 첨부는 건너뜀
+        let Some(data) = read_stream(&mut file, &format!("{}/__substg1.0_37010102", storage))
+        else {
+            continue;
+        };
+        let name = msg_string(&mut file, &storage, "3707")
+            .or_else(|| msg_string(&mut file, &storage, "3704"))
+            .filter(|n| !n.trim().is_empty())
+            .unwrap_or_else(|| format!("attachment-{}", index + 1));
+        attachments.push(AttachmentInfo {
+            name,
+            content_type: msg_string(&mut file, &storage, "370E"),
+            size: data.len(),
+            path: None,
+        });
+        contents.push(data);
+    }
+
+    let email = ParsedEmail {
+        format: "msg".to_string(),
+        subject: msg_string(&mut file, root, "0037").or(headers.subject),
+        from: if headers.from.is_empty() {
+            vec![sender]
+        } else {
+            headers.from
+        },
+        to: if headers.to.is_empty() {
+            display_names(msg_string(&mut file, root, "0E04"))
+        } else {
+            headers.to
+        },
+        cc: if headers.cc.is_empty() {
+            display_names(msg_string(&mut file, root, "0E03"))
+        } else {
+            headers.cc
+        },
+        reply_to: headers.reply_to,
+        date: headers.date,
+        message_id: msg_string(&mut file, root, "1035").or(headers.message_id),
+        text: msg_string(&mut file, root, "1000")
+            .map(|t| t.trim().to_string())
+            .unwrap_or_default(),
+        html,
+        attachments,
+    };
+    Ok((email, contents))
+}
+
+// 첨부파일 이름에서 경로와 쓸 수 없는 문자 제거
+pub fn safe_file_name(name: &str) -> String {
+    let base = name.rsplit(['/', '\\']).next().unwrap_or(name);
+    safe_path::sanitize_file_name(base, "attachment")
+}
+
+// 같은 이름이 있으면 "이름 (2).확장자"
+fn unique_path(dir: &Path, name: &str) -> PathBuf {
+    let candidate = dir.join(name);
+    if !candidate.exists() {
+        return candidate;
+    }
+    let path = Path::new(name);
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let ext = path
+        .extension()
+        .map(|e| format!(".{}", e.to_string_lossy()))
+        .unwrap_or_default();
+    (2..)
+        .map(|n| dir.join(format!("{} ({}){}", stem, n, ext)))
+        .find(|p| !p.exists())
+        .unwrap_or(candidate)
+}
+
+fn default_attachment_dir(path: &Path) -> PathBuf {
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| "email".to_string());
+    path.parent()
+        .unwrap_or(Path::new("."))
+        .join(format!("{}_attachments", safe_file_name(&stem)))
+}
+
+fn parse_email_file(
+    path: &Path,
+    output_dir: Option<PathBuf>,
+    extract_attachments: bool,
+) -> Result<ParsedEmail, String> {
+    let bytes = std::fs::read(path).map_err(|_| "FILE_NOT_FOUND".to_string())?;
+    let is_ole = bytes.starts_with(&[0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1]);
+    let (mut email, contents) = if is_ole {
+        parse_msg(path)?
+    } else {
+        parse_eml(&bytes)?
+    };
+
+    if extract_attachments && !contents.is_empty() {
+        let dir = output_dir.unwrap_or_else(|| default_attachment_dir(path));
+        std::fs::create_dir_all(&dir).map_err(|e| format!("첨부파일 폴더 생성 실패: {}", e))?;
+        for (info, data) in email.attachments.iter_mut().zip(contents) {
+            let target = unique_path(&dir, &safe_file_name(&info.name));
+            std::fs::write(&target, data).map_err(|e| format!("첨부파일 저장 실패: {}", e))?;
+            info.path = Some(target.to_string_lossy().to_string());
+        }
+    }
+    Ok(email)
+}
+
+#[tauri::command]
+pub async fn email_parse_node(
+    path: String,
+    output_dir: Option<String>,
+    extract_attachments: Option<bool>,
+) -> Result<String, String> {
+    let path = safe_path::resolve_existing(&path);
+    if !path.is_file() {
+        return Err("FILE_NOT_FOUND".to_string());
+    }
+    let output_dir = output_dir
+        .filter(|d| !d.trim().is_empty())
+        .map(|d| safe_path::resolve_existing(&d));
+    println!("✉️ Email Parse Node: {}", path.display());
+
+    let email = tokio::task::spawn_blocking(move || {
+        parse_email_file(&path, output_dir, extract_attachments.unwrap_or(true))
+    })
+    .await
+    .map_err(|e| format!("이메일 파싱 실패: {}", e))??;
+
+    println!(
+        "✅ 이메일 파싱 완료: {:?} (첨부 {}개)",
+        email.subject,
+        email.attachments.len()
+    );
+    Ok(json!(email).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::{parse_output, path_str, temp_dir};
+    use std::fs;
+
+    #[test]
+    fn email_parse_sanitizes_attachment_names() {
+        assert_eq!(
+            safe_file_name("../../etc/passwd"),
+            "passwd"
+        );
+        assert_eq!(
+            safe_file_name("C:\\temp\\보고서.pdf"),
+            "보고서.pdf"
+        );
+        assert_eq!(safe_file_name("a:b?.txt"), "a_b_.txt");
+        assert_eq!(safe_file_name(".."), "attachment");
+    }
+
+    #[tokio::test]
+    async fn email_parse_reads_eml_and_extracts_attachments() {
+        let dir = temp_dir();
+        let eml = dir.path().join("invoice.eml");
+        let message = "From: \"Kim\" <kim@example.com>\r\n\
+To: a@example.com, \"Lee\" <lee@example.com>\r\n\
+Subject: =?UTF-8?B?7LKt6rWs7ISc?=\r\n\
+Date: Mon, 5 Oct 2026 09:30:00 +0900\r\n\
+Message-ID: <abc@example.com>\r\n\
+MIME-Version: 1.0\r\n\
+Content-Type: multipart/mixed; boundary=\"XX\"\r\n\
+\r\n\
+--XX\r\n\
+Content-Type: text/plain; charset=utf-8\r\n\
+\r\n\
+Please see attached.\r\n\
+--XX\r\n\
+Content-Type: text/plain; name=\"../notes.txt\"\r\n\
+Content-Disposition: attachment; filename=\"../notes.txt\"\r\n\
+Content-Transfer-Encoding: base64\r\n\
+\r\n\
+aGVsbG8=\r\n\
+--XX--\r\n";
+        fs::write(&eml, message).unwrap();
+
+        let output = parse_output(
+            &email_parse_node(path_str(&eml), None, None)
+                .await
+                .unwrap(),
+        );
+        assert_eq!(output["format"], "eml");
+        assert_eq!(output["subject"], "청구서");
+        assert_eq!(output["from"][0]["address"], "kim@example.com");
+        assert_eq!(output["to"].as_array().unwrap().len(), 2);
+        assert_eq!(output["text"], "Please see attached.");
+        assert_eq!(output["attachments"][0]["size"], 5);
+
+        let saved = dir.path().join("invoice_attachments").join("notes.txt");
+        assert_eq!(output["attachments"][0]["path"], path_str(&saved));
+        assert_eq!(fs::read_to_string(saved).unwrap(), "hello");
+    }
+}
@@ -0,0 +1,228 @@
+// src-tauri/src/nodes/encoding_node.rs
+use super::safe_path;
+use encoding_rs::{EUC_KR, UTF_16BE, UTF_16LE, UTF_8};
+use serde_json::json;
+use std::fs;
+
+// 🔤 문자 인코딩 감지 / 변환 노드
+// EUC-KR(CP949) 로 저장된 한글 파일을 UTF-8 로 가정한 노드가 읽으면 글자가 깨지므로
+// 파일의 인코딩을 감지하고 원하는 인코딩으로 변환
+// encoding: "utf-8" | "utf-8-bom" | "euc-kr"(= cp949) | "utf-16le" | "utf-16be"
+
+const UTF8_BOM: &[u8] = &[0xEF, 0xBB, 0xBF];
+const UTF16LE_BOM: &[u8] = &[0xFF, 0xFE];
+const UTF16BE_BOM: &[u8] = &[0xFE, 0xFF];
+
+// BOM → UTF-8 유효성 → UTF-16(BOM 없음) 패턴 → EUC-KR 순으로 판단
+pub fn detect_encoding(bytes: &[u8]) -> &'static str {
+    if bytes.starts_with(UTF8_BOM) {
+        return "utf-8-bom";
+    }
+    if bytes.starts_with(UTF16LE_BOM) {
+        return "utf-16le";
+    }
+    if bytes.starts_with(UTF16BE_BOM) {
+        return "utf-16be";
+    }
+
+    // 영문 위주 UTF-16 은 한쪽 바이트가 0 인 경우가 많음
+    if bytes.len() >= 4 {
+        let sample = &bytes[..bytes.len().min(4096) & !1];
+        let even_zeros = sample.iter().step_by(2).filter(|b| **b == 0).count();
+        let odd_zeros = sample
+            .iter()
+            .skip(1)
+            .step_by(2)
+            .filter(|b| **b == 0)
+            .count();
+        let half = sample.len() / 2;
+        if odd_zeros * 10 > half * 3 && even_zeros * 10 < half {
+            return "utf-16le";
+        }
+        if even_zeros * 10 > half * 3 && odd_zeros * 10 < half {
+            return "utf-16be";
+        }
+    }
+
+    if std::str::from_utf8(bytes).is_ok() {
+        return "utf-8";
+    }
+
+    // EUC-KR 로도 오류 없이 디코딩되지 않으면 알 수 없음
+    let (_, had_errors) = EUC_KR.decode_without_bom_handling(bytes);
+    if had_errors {
+        "unknown"
+    } else {
+        "euc-kr"
+    }
+}
+
+fn normalize_encoding_name(name: &str) -> Result<&'static str, String> {
+    match name.trim().to_lowercase().replace('_', "-").as_str() {
+        "utf-8" | "utf8" => Ok("utf-8"),
+        "utf-8-bom" | "utf8-bom" | "utf-8-sig" => Ok("utf-8-bom"),
+        "euc-kr" | "euckr" | "cp949" | "ms949" | "uhc" | "ks-c-5601-1987" => Ok("euc-kr"),
+        "utf-16le" | "utf-16" | "unicode" => Ok("utf-16le"),
+        "utf-16be" => Ok("utf-16be"),
+        other => Err(format!("UNSUPPORTED_ENCODING: {}", other)),
+    }
+}
+
+pub fn decode_bytes(bytes: &[u8], encoding: &str) -> Result<String, String> {
+    let (text, had_errors) = match encoding {
+        "utf-8" | "utf-8-bom" => UTF_8.decode_with_bom_removal(bytes),
+        "utf-16le" => UTF_16LE.decode_with_bom_removal(bytes),
+        "utf-16be" => UTF_16BE.decode_with_bom_removal(bytes),
+        // encoding_rs 의 EUC-KR 은 WHATWG 기준으로 CP949 확장 한글까지 포함
+        "euc-kr" => EUC_KR.decode_without_bom_handling(bytes),
+        other => return Err(format!("UNSUPPORTED_ENCODING: {}", other)),
+    };
+    if had_errors {
+        return Err(format!(
+            "DECODE_ERROR: {} 로 읽을 수 없는 바이트가 있습니다",
+            encoding
+        ));
+    }
+    Ok(text.into_owned())
+}
+
+pub fn encode_text(text: &str, encoding: &str, lossy: bool) -> Result<Vec<u8>, String> {
+    match encoding {
+        "utf-8" => Ok(text.as_bytes().to_vec()),
+        "utf-8-bom" => Ok([UTF8_BOM, text.as_bytes()].concat()),
+        // encoding_rs 는 UTF-16 인코더가 없으므로 직접 변환 (BOM 포함)
+        "utf-16le" => Ok(UTF16LE_BOM
+            .iter()
+            .copied()
+            .chain(text.encode_utf16().flat_map(|u| u.to_le_bytes()))
+            .collect()),
+        "utf-16be" => Ok(UTF16BE_BOM
+            .iter()
+            .copied()
+            .chain(text.encode_utf16().flat_map(|u| u.to_be_bytes()))
+            .collect()),
+        "euc-kr" => {
+            let (bytes, _, had_errors) = EUC_KR.encode(text);
+            if had_errors && !lossy {
+                return Err(
+                    "UNMAPPABLE_CHARACTERS: EUC-KR 로 표현할 수 없는 문자가 있습니다 (이모지 등)"
+                        .to_string(),
+                );
+            }
+            Ok(bytes.into_owned())
+        }
+        other => Err(format!("UNSUPPORTED_ENCODING: {}", other)),
+    }
+}
+
+#[tauri::command]
+pub fn encoding_node(
+    file_path: String,
+    action: Option<String>,
+    target_encoding: Option<String>,
+    source_encoding: Option<String>,
+    output_path: Option<String>,
+    lossy: Option<bool>,
+) -> Result<String, String> {
+    let action = action
+        .map(|a| a.trim().to_lowercase())
+        .filter(|a| !a.is_empty())
+        .unwrap_or_else(|| "detect".to_string());
+    println!("🔤 Encoding Node: {} {}", action, file_path);
+
+    let source_path = safe_path::resolve_existing(&file_path);
+    let bytes = fs::read(&source_path).map_err(|_| "FILE_NOT_FOUND".to_string())?;
+    let detected = detect_encoding(&bytes);
+
+    let source = match source_encoding.as_deref().filter(|s| !s.trim().is_empty()) {
+        Some(name) => normalize_encoding_name(name)?,
+        None if detected == "unknown" => return Err("UNKNOWN_ENCODING".to_string()),
+        None => detected,
+    };
+
+    match action.as_str() {
+        "detect" => {
+            let text = decode_bytes(&bytes, source)?;
+            Ok(json!({
+                "file_path": file_path,
+                "encoding": detected,
+                "text": text
+            })
+            .to_string())
+        }
+        "convert" => {
+            let target = normalize_encoding_name(target_encoding.as_deref().unwrap_or("utf-8"))?;
+            let text = decode_bytes(&bytes, source)?;
+            let converted = encode_text(&text, target, lossy.unwrap_or(false))?;
+
+            // 출력 경로가 없으면 원본 파일을 덮어씀
+            let output_path = match output_path.filter(|p| !p.trim().is_empty()) {
+                Some(path) => safe_path::prepare_new(&path)?,
+                None => source_path,
+            }
+            .to_string_lossy()
+            .to_string();
+            fs::write(&output_path, &converted).map_err(|_| "FILE_WRITE_ERROR".to_string())?;
+
+            println!("✅ 인코딩 변환: {} → {} ({})", source, target, output_path);
+            Ok(json!({
+                "file_path": output_path,
+                "source_encoding": source,
+                "target_encoding": target,
+                "bytes_written": converted.len()
+            })
+            .to_string())
+        }
+        other => Err(format!("UNSUPPORTED_ACTION: {}", other)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::{parse_output, path_str, temp_dir};
+    use std::fs;
+
+    #[test]
+    fn encoding_node_converts_euc_kr_file_to_utf8() {
+        let dir = temp_dir();
+        let file = dir.path().join("legacy.txt");
+        // "한글 테스트" (EUC-KR)
+        fs::write(
+            &file,
+            [
+                0xC7, 0xD1, 0xB1, 0xDB, 0x20, 0xC5, 0xD7, 0xBD, 0xBA, 0xC6, 0xAE,
+            ],
+        )
+        .unwrap();
+
+        let detected =
+            parse_output(&encoding_node(path_str(&file), None, None, None, None, None).unwrap());
+        assert_eq!(detected["encoding"], "euc-kr");
+        assert_eq!(detected["text"], "한글 테스트");
+
+        encoding_node(
+            path_str(&file),
+            Some("convert".to_string()),
+            Some("utf-8".to_string()),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(fs::read_to_string(&file).unwrap(), "한글 테스트");
+    }
+
+    #[test]
+    fn encoding_detection_recognizes_boms_and_utf16() {
+        assert_eq!(
+            detect_encoding(b"\xEF\xBB\xBFabc"),
+            "utf-8-bom"
+        );
+        assert_eq!(detect_encoding(b"a\0b\0c\0d\0"), "utf-16le");
+        assert_eq!(
+            detect_encoding("plain 한글".as_bytes()),
+            "utf-8"
+        );
+    }
+}
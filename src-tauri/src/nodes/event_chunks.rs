@@ -0,0 +1,120 @@
+// src-tauri/src/nodes/event_chunks.rs
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+
+// 📦 큰 이벤트 분할 전송
+// 긴 명령 출력 / 큰 JSON 을 IPC 메시지 하나로 보내면 웹뷰가 역직렬화하는 동안 멈추므로
+// 직렬화한 JSON 이 CHUNK_THRESHOLD 를 넘으면 순서 번호가 붙은 조각으로 나눠 보냄:
+// - "event-chunk"       { stream_id, event, index, data }     (data 는 JSON 문자열의 일부, UTF-8 경계에서 자름)
+// - "event-chunk-done"  { stream_id, event, chunks, size }    (완료 표시 - 조각을 index 순서로 이어 붙여 JSON.parse)
+// 프론트엔드는 이어 붙인 결과를 원래 event 이름으로 처리, 작은 이벤트는 지금처럼 그대로 전송
+
+pub const CHUNK_THRESHOLD: usize = 64 * 1024;
+pub const CHUNK_BYTES: usize = 32 * 1024;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EventChunk<'a> {
+    pub stream_id: &'a str,
+    pub event: &'a str,
+    pub index: usize,
+    pub data: &'a str,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EventChunkDone<'a> {
+    pub stream_id: &'a str,
+    pub event: &'a str,
+    pub chunks: usize,
+    pub size: usize,
+}
+
+// max_bytes 이하 조각으로 (멀티바이트 문자는 자르지 않음)
+pub fn split_chunks(text: &str, max_bytes: usize) -> Vec<&str> {
+    let max_bytes = max_bytes.max(4);
+    let mut chunks = Vec::with_capacity(text.len() / max_bytes + 1);
+    let mut rest = text;
+    while !rest.is_empty() {
+        let mut end = max_bytes.min(rest.len());
+        while !rest.is_char_boundary(end) {
+            end -= 1;
+        }
+        let (chunk, tail) = rest.split_at(end);
+        chunks.push(chunk);
+        rest = tail;
+    }
+    chunks
+}
+
+fn new_stream_id() -> String {
+    static COUNTER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+    format!(
+        "chunks-{}-{}",
+        chrono::Utc::now().timestamp_millis(),
+        COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+    )
+}
+
+// app_handle.emit 대신 사용 (큰 결과가 실릴 수 있는 이벤트)
+pub fn emit_chunked<S: Serialize + ?Sized>(
+    app_handle: &AppHandle,
+    event: &str,
+    payload: &S,
+) -> Result<(), String> {
+    let json = serde_json::to_string(payload).map_err(|e| e.to_string())?;
+    if json.len() <= CHUNK_THRESHOLD {
+        return app_handle.emit(event, payload).map_err(|e| e.to_string());
+    }
+
+    let stream_id = new_stream_id();
+    let chunks = split_chunks(&json, CHUNK_BYTES);
+    for (index, data) in chunks.iter().enumerate() {
+        app_handle
+            .emit(
+                "event-chunk",
+                EventChunk {
+                    stream_id: &stream_id,
+                    event,
+                    index,
+                    data,
+                },
+            )
+            .map_err(|e| e.to_string())?;
+    }
+    app_handle
+        .emit(
+            "event-chunk-done",
+            EventChunkDone {
+                stream_id: &stream_id,
+                event,
+                chunks: chunks.len(),
+                size: json.len(),
+            },
+        )
+        .map_err(|e| e.to_string())?;
+    println!(
+        "📦 큰 이벤트 분할 전송: {} ({} bytes, {}조각)",
+        event,
+        json.len(),
+        chunks.len()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn event_chunks_split_on_char_boundaries_and_rejoin() {
+        let payload = serde_json::to_string(&json!({ "output": "가나다abc".repeat(20_000) })).unwrap();
+        let chunks = split_chunks(&payload, CHUNK_BYTES);
+
+        assert!(chunks.len() > 1);
+        assert!(chunks.iter().all(|c| c.len() <= CHUNK_BYTES && !c.is_empty()));
+        assert_eq!(chunks.concat(), payload);
+        assert!(split_chunks("", 10).is_empty());
+        // 조각 크기가 한 글자보다 작아도 글자를 자르지 않음
+        assert_eq!(split_chunks("가나", 1), vec!["가", "나"]);
+    }
+}
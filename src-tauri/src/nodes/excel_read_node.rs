@@ -0,0 +1,287 @@
+// src-tauri/src/nodes/excel_read_node.rs
+use calamine::{open_workbook_auto, Data, Reader};
+use serde_json::{json, Map, Value};
+use std::path::Path;
+
+// 📗 엑셀 읽기 노드 (.xlsx / .xlsm / .xls / .ods)
+// 시트의 행을 JSON 으로 반환 → CSV 로 변환하지 않고 기존 엑셀 파일로 워크플로우 구동
+// - sheet: 시트 이름 (비우면 첫 번째 시트)
+// - range: "A1:D20", "B3"(여기서부터 끝까지), "A:C"(열 전체)
+// - header: true(기본)면 첫 행을 열 이름으로 써서 객체 배열, false 면 배열의 배열
+
+// "AB" → 27번째 열(0부터 26)
+fn column_index(letters: &str) -> Option<u32> {
+    if letters.is_empty() || letters.len() > 3 {
+        return None;
+    }
+    letters
+        .chars()
+        .try_fold(0u32, |acc, c| {
+            c.is_ascii_alphabetic()
+                .then(|| acc * 26 + (c.to_ascii_uppercase() as u32 - 'A' as u32 + 1))
+        })
+        .map(|n| n - 1)
+}
+
+// "B12" → (행, 열) 0부터, 행이 없으면 None ("B")
+fn parse_cell(cell: &str) -> Result<(Option<u32>, u32), String> {
+    let cell = cell.trim();
+    let split = cell
+        .find(|c: char| c.is_ascii_digit())
+        .unwrap_or(cell.len());
+    let (letters, digits) = cell.split_at(split);
+    let column = column_index(letters).ok_or_else(|| format!("INVALID_RANGE: {}", cell))?;
+    let row = match digits {
+        "" => None,
+        digits => match digits.parse::<u32>() {
+            Ok(row) if row > 0 => Some(row - 1),
+            _ => return Err(format!("INVALID_RANGE: {}", cell)),
+        },
+    };
+    Ok((row, column))
+}
+
+// (시작 행, 시작 열), (끝 행, 끝 열) - 끝이 None 이면 시트 끝까지
+pub type CellRange = ((u32, u32), (Option<u32>, Option<u32>));
+
+pub fn parse_range(range: &str) -> Result<CellRange, String> {
+    let range = range.trim();
+    let (start, end) = match range.split_once(':') {
+        Some((start, end)) => (start, Some(end)),
+        None => (range, None),
+    };
+    let (start_row, start_col) = parse_cell(start)?;
+    let (end_row, end_col) = match end {
+        Some(end) => {
+            let (row, col) = parse_cell(end)?;
+            (row, Some(col))
+        }
+        None => (None, None),
+    };
+    if end_col.is_some_and(|c| c < start_col) || end_row.zip(start_row).is_some_and(|(e, s)| e < s)
+    {
+        return Err(format!("INVALID_RANGE: {}", range));
+    }
+    Ok(((start_row.unwrap_or(0), start_col), (end_row, end_col)))
+}
+
+fn cell_to_json(cell: &Data) -> Value {
+    match cell {
+        Data::Empty => Value::Null,
+        Data::String(s) => json!(s),
+        Data::Bool(b) => json!(b),
+        Data::Int(i) => json!(i),
+        // 엑셀은 모든 숫자를 실수로 저장 → 정수면 정수로
+        Data::Float(f) if f.fract() == 0.0 && f.abs() < 9.0e15 => json!(*f as i64),
+        Data::Float(f) => json!(f),
+        Data::DateTime(dt) => match dt.as_datetime() {
+            Some(datetime) if datetime.time() == chrono::NaiveTime::MIN => {
+                json!(datetime.format("%Y-%m-%d").to_string())
+            }
+            Some(datetime) => json!(datetime.format("%Y-%m-%dT%H:%M:%S").to_string()),
+            None => json!(dt.as_f64()),
+        },
+        Data::DateTimeIso(s) | Data::DurationIso(s) => json!(s),
+        Data::Error(e) => json!(e.to_string()),
+    }
+}
+
+// 첫 행을 열 이름으로 (빈 이름은 col_N, 중복은 _2, _3 …)
+pub fn rows_to_objects(rows: Vec<Vec<Value>>) -> (Vec<String>, Vec<Value>) {
+    let mut rows = rows.into_iter();
+    let mut headers: Vec<String> = Vec::new();
+    for (index, cell) in rows.next().unwrap_or_default().iter().enumerate() {
+        let base = match cell {
+            Value::Null => format!("col_{}", index + 1),
+            other => super::template::value_to_text(other).trim().to_string(),
+        };
+        let base = if base.is_empty() {
+            format!("col_{}", index + 1)
+        } else {
+            base
+        };
+        let mut name = base.clone();
+        let mut suffix = 2;
+        while headers.contains(&name) {
+            name = format!("{}_{}", base, suffix);
+            suffix += 1;
+        }
+        headers.push(name);
+    }
+
+    let objects = rows
+        .map(|row| {
+            let object: Map<String, Value> = headers
+                .iter()
+                .enumerate()
+                .map(|(index, header)| {
+                    (
+                        header.clone(),
+                        row.get(index).cloned().unwrap_or(Value::Null),
+                    )
+                })
+                .collect();
+            Value::Object(object)
+        })
+        .collect();
+    (headers, objects)
+}
+
+fn is_blank_row(row: &[Value]) -> bool {
+    row.iter()
+        .all(|cell| cell.is_null() || cell.as_str().is_some_and(|s| s.trim().is_empty()))
+}
+
+struct SheetData {
+    name: String,
+    sheet_names: Vec<String>, // 통합 문서의 모든 시트 이름
+    rows: Vec<Vec<Value>>,
+}
+
+fn read_sheet(path: &Path, sheet: Option<&str>, range: Option<&str>) -> Result<SheetData, String> {
+    let mut workbook =
+        open_workbook_auto(path).map_err(|e| format!("엑셀 파일 열기 실패: {}", e))?;
+    let sheet_names = workbook.sheet_names().to_vec();
+    let sheet_name = match sheet {
+        Some(name) => sheet_names
+            .iter()
+            .find(|s| s.as_str() == name || s.eq_ignore_ascii_case(name))
+            .cloned()
+            .ok_or_else(|| format!("SHEET_NOT_FOUND: {}", name))?,
+        None => sheet_names.first().cloned().ok_or("EMPTY_WORKBOOK")?,
+    };
+    let mut cells = workbook
+        .worksheet_range(&sheet_name)
+        .map_err(|e| format!("시트 읽기 실패: {}", e))?;
+
+    if let Some(range) = range {
+        let ((start_row, start_col), (end_row, end_col)) = parse_range(range)?;
+        let (last_row, last_col) = cells.end().unwrap_or((0, 0));
+        // 범위가 데이터 밖이면 빈 결과
+        if cells.is_empty() || start_row > last_row || start_col > last_col {
+            return Ok(SheetData {
+                name: sheet_name,
+                sheet_names,
+                rows: Vec::new(),
+            });
+        }
+        cells = cells.range(
+            (start_row, start_col),
+            (end_row.unwrap_or(last_row), end_col.unwrap_or(last_col)),
+        );
+    }
+
+    let rows = cells
+        .rows()
+        .map(|row| row.iter().map(cell_to_json).collect::<Vec<Value>>())
+        .collect();
+    Ok(SheetData {
+        name: sheet_name,
+        sheet_names,
+        rows,
+    })
+}
+
+#[tauri::command]
+pub async fn excel_read_node(
+    path: String,
+    sheet: Option<String>,
+    range: Option<String>,
+    header: Option<bool>,
+    skip_empty_rows: Option<bool>,
+) -> Result<String, String> {
+    let path = super::safe_path::resolve_existing(&path);
+    if !path.is_file() {
+        return Err("FILE_NOT_FOUND".to_string());
+    }
+    let sheet = sheet
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty());
+    let range = range
+        .map(|r| r.trim().to_string())
+        .filter(|r| !r.is_empty());
+    let header = header.unwrap_or(true);
+
+    println!(
+        "📗 Excel Read Node: {} [{:?} {:?}]",
+        path.display(),
+        sheet,
+        range
+    );
+
+    let SheetData {
+        name: sheet_name,
+        sheet_names,
+        mut rows,
+    } = tokio::task::spawn_blocking(move || read_sheet(&path, sheet.as_deref(), range.as_deref()))
+        .await
+        .map_err(|e| format!("엑셀 읽기 실패: {}", e))??;
+
+    if skip_empty_rows.unwrap_or(true) {
+        rows.retain(|row| !is_blank_row(row));
+    }
+
+    let (headers, rows) = if header {
+        rows_to_objects(rows)
+    } else {
+        (Vec::new(), rows.into_iter().map(Value::Array).collect())
+    };
+
+    Ok(json!({
+        "sheet": sheet_name,
+        "sheets": sheet_names,
+        "headers": headers,
+        "rows": rows,
+        "row_count": rows.len()
+    })
+    .to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::{path_str, temp_dir};
+    use serde_json::json;
+
+    #[test]
+    fn excel_read_parses_ranges() {
+        assert_eq!(
+            parse_range("B2:D10").unwrap(),
+            ((1, 1), (Some(9), Some(3)))
+        );
+        assert_eq!(
+            parse_range("a3").unwrap(),
+            ((2, 0), (None, None))
+        );
+        assert_eq!(
+            parse_range("AA:AC").unwrap(),
+            ((0, 26), (None, Some(28)))
+        );
+        assert!(parse_range("D1:B1").is_err());
+        assert!(parse_range("A0").is_err());
+        assert!(parse_range("1A").is_err());
+    }
+
+    #[test]
+    fn excel_read_uses_first_row_as_headers() {
+        let (headers, rows) = rows_to_objects(vec![
+            vec![json!("이름"), json!(null), json!("이름")],
+            vec![json!("홍길동"), json!(30)],
+        ]);
+        assert_eq!(headers, vec!["이름", "col_2", "이름_2"]);
+        assert_eq!(
+            rows,
+            vec![json!({ "이름": "홍길동", "col_2": 30, "이름_2": null })]
+        );
+    }
+
+    #[tokio::test]
+    async fn excel_read_reports_missing_file() {
+        let dir = temp_dir();
+        let missing = dir.path().join("nope.xlsx");
+        let err = excel_read_node(path_str(&missing), None, None, None, None)
+            .await
+            .unwrap_err();
+        assert_eq!(err, "FILE_NOT_FOUND");
+    }
+}
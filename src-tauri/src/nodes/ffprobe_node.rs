@@ -0,0 +1,152 @@
+// src-tauri/src/nodes/ffprobe_node.rs
+use super::{os_command, safe_path};
+use serde_json::{json, Value};
+use std::path::Path;
+
+// 🎞️ 동영상 정보 / 썸네일 노드
+// 번들된 ffprobe 로 길이, 해상도, 코덱, 비트레이트를 JSON 으로 반환하고
+// thumbnail_path 를 주면 번들된 ffmpeg 로 해당 시점의 프레임을 이미지로 저장
+
+fn parse_number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+// "30000/1001" → 29.97
+fn parse_frame_rate(value: &Value) -> Option<f64> {
+    let text = value.as_str()?;
+    let (num, den) = text.split_once('/')?;
+    let (num, den): (f64, f64) = (num.parse().ok()?, den.parse().ok()?);
+    (den > 0.0).then(|| (num / den * 100.0).round() / 100.0)
+}
+
+// ffprobe JSON → 워크플로우에서 분기하기 쉬운 요약
+pub fn summarize_probe(probe: &Value) -> Value {
+    let streams = probe["streams"].as_array().cloned().unwrap_or_default();
+    let video = streams.iter().find(|s| s["codec_type"] == "video");
+    let audio = streams.iter().find(|s| s["codec_type"] == "audio");
+    let format = &probe["format"];
+
+    json!({
+        "duration_secs": parse_number(&format["duration"]),
+        "size_bytes": parse_number(&format["size"]).map(|s| s as u64),
+        "bitrate": parse_number(&format["bit_rate"]).map(|b| b as u64),
+        "container": format["format_name"],
+        "width": video.and_then(|v| v["width"].as_u64()),
+        "height": video.and_then(|v| v["height"].as_u64()),
+        "video_codec": video.map(|v| v["codec_name"].clone()),
+        "frame_rate": video.and_then(|v| parse_frame_rate(&v["avg_frame_rate"])),
+        "audio_codec": audio.map(|a| a["codec_name"].clone()),
+        "audio_channels": audio.and_then(|a| a["channels"].as_u64()),
+        "sample_rate": audio.and_then(|a| parse_number(&a["sample_rate"])).map(|r| r as u64),
+        "has_video": video.is_some(),
+        "has_audio": audio.is_some()
+    })
+}
+
+#[tauri::command]
+pub async fn ffprobe_node(
+    path: String,
+    thumbnail_path: Option<String>,
+    thumbnail_at_secs: Option<f64>,
+) -> Result<String, String> {
+    let path = safe_path::resolve_existing(&path)
+        .to_string_lossy()
+        .to_string();
+    if !Path::new(&path).is_file() {
+        return Err("FILE_NOT_FOUND".to_string());
+    }
+    println!("🎞️ FFprobe Node: {}", path);
+
+    let probe_path = path.clone();
+    let output = tokio::task::spawn_blocking(move || {
+        os_command::run(
+            &os_command::bundled_binary("ffprobe"),
+            &[
+                "-v",
+                "error",
+                "-print_format",
+                "json",
+                "-show_format",
+                "-show_streams",
+                &probe_path,
+            ],
+        )
+    })
+    .await
+    .map_err(|e| format!("ffprobe 실행 실패: {}", e))??;
+
+    let probe: Value =
+        serde_json::from_str(&output).map_err(|e| format!("ffprobe 출력 파싱 실패: {}", e))?;
+    let mut summary = summarize_probe(&probe);
+
+    // 썸네일 추출 (기본: 길이의 10% 지점, 너무 앞이면 검은 화면이 많음)
+    if let Some(thumbnail_path) = thumbnail_path.filter(|p| !p.trim().is_empty()) {
+        if !summary["has_video"].as_bool().unwrap_or(false) {
+            return Err("NO_VIDEO_STREAM".to_string());
+        }
+        let duration = summary["duration_secs"].as_f64().unwrap_or(0.0);
+        let at = thumbnail_at_secs
+            .unwrap_or(duration * 0.1)
+            .clamp(0.0, (duration - 0.1).max(0.0));
+
+        let target = safe_path::prepare_new(&thumbnail_path)?
+            .to_string_lossy()
+            .to_string();
+        super::file_creator_node::ensure_parent_dir(&target)?;
+        let source = path.clone();
+        tokio::task::spawn_blocking(move || {
+            os_command::run(
+                &os_command::bundled_binary("ffmpeg"),
+                &[
+                    "-y",
+                    "-v",
+                    "error",
+                    "-ss",
+                    &format!("{:.3}", at),
+                    "-i",
+                    &source,
+                    "-frames:v",
+                    "1",
+                    &target,
+                ],
+            )
+        })
+        .await
+        .map_err(|e| format!("ffmpeg 실행 실패: {}", e))??;
+
+        summary["thumbnail_path"] = json!(thumbnail_path.trim());
+        summary["thumbnail_at_secs"] = json!(at);
+    }
+
+    summary["path"] = json!(path);
+    Ok(summary.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn ffprobe_summary_extracts_stream_properties() {
+        let probe = json!({
+            "streams": [
+                { "codec_type": "video", "codec_name": "h264", "width": 1920, "height": 1080, "avg_frame_rate": "30000/1001" },
+                { "codec_type": "audio", "codec_name": "aac", "channels": 2, "sample_rate": "48000" }
+            ],
+            "format": { "duration": "12.5", "size": "1048576", "bit_rate": "671088", "format_name": "mov,mp4" }
+        });
+
+        let summary = summarize_probe(&probe);
+
+        assert_eq!(summary["width"], 1920);
+        assert_eq!(summary["frame_rate"], 29.97);
+        assert_eq!(summary["duration_secs"], 12.5);
+        assert_eq!(summary["audio_codec"], "aac");
+        assert_eq!(summary["sample_rate"], 48000);
+    }
+}
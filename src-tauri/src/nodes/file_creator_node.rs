@@ -1,41 +1,155 @@
+use super::safe_path;
+use base64::{engine::general_purpose, Engine as _};
 use std::fs;
 use std::path::Path;
 
+// 폴더 경로 + 파일명 → 전체 경로
+pub(crate) fn build_full_path(file_path: &str, file_name: &str) -> String {
+    if file_path.trim().is_empty() {
+        format!("./{}", file_name.trim())
+    } else {
+        let separator = if file_path.ends_with('/') || file_path.ends_with('\\') {
+            ""
+        } else {
+            "/"
+        };
+        format!("{}{}{}", file_path.trim(), separator, file_name.trim())
+    }
+}
+
+// 상위 디렉토리가 없으면 생성 (긴 경로는 확장 접두사로)
+pub(crate) fn ensure_parent_dir(full_path: &str) -> Result<(), String> {
+    let full_path = safe_path::extended(Path::new(full_path));
+    if let Some(parent_dir) = full_path.parent() {
+        if !parent_dir.exists() && fs::create_dir_all(parent_dir).is_err() {
+            return Err("DIRECTORY_CREATE_ERROR".to_string());
+        }
+    }
+    Ok(())
+}
+
+// 파일 내용 디코딩 ("text" 기본, "base64"면 원본 바이트로 변환)
+pub(crate) fn decode_content(
+    file_content: String,
+    content_encoding: Option<&str>,
+) -> Result<Vec<u8>, String> {
+    match content_encoding.map(|e| e.trim().to_lowercase()).as_deref() {
+        None | Some("") | Some("text") | Some("utf-8") | Some("utf8") => {
+            Ok(file_content.into_bytes())
+        }
+        Some("base64") => {
+            // data URL (data:image/png;base64,....) 형식도 허용
+            let payload = match file_content.find(";base64,") {
+                Some(index) if file_content.starts_with("data:") => &file_content[index + 8..],
+                _ => file_content.as_str(),
+            };
+            let cleaned: String = payload.chars().filter(|c| !c.is_whitespace()).collect();
+            general_purpose::STANDARD
+                .decode(cleaned)
+                .map_err(|_| "INVALID_BASE64_CONTENT".to_string())
+        }
+        Some(other) => Err(format!("UNSUPPORTED_CONTENT_ENCODING: {}", other)),
+    }
+}
+
 #[tauri::command]
 pub fn file_creator_node(
     file_path: String,
     file_name: String,
     file_content: String,
+    content_encoding: Option<String>,
 ) -> Result<String, String> {
     // 입력값 검증
     if file_name.trim().is_empty() {
         return Err("EMPTY_FILENAME".to_string());
     }
 
-    // 전체 경로 생성
-    let full_path = if file_path.trim().is_empty() {
-        format!("./{}", file_name.trim())
-    } else {
-        let separator = if file_path.ends_with('/') || file_path.ends_with('\\') {
-            ""
-        } else {
-            "/"
-        };
-        format!("{}{}{}", file_path.trim(), separator, file_name.trim())
-    };
+    // 전체 경로 생성 (NFC 정규화, 예약 이름 / 금지 문자 검증)
+    let full_path = safe_path::prepare_new(&build_full_path(&file_path, &file_name))?;
+
+    // 내용 디코딩 (이미지, PDF 등 바이너리는 base64로 전달)
+    let bytes = decode_content(file_content, content_encoding.as_deref())?;
 
     // 디렉토리 생성
-    if let Some(parent_dir) = Path::new(&full_path).parent() {
-        if !parent_dir.exists() {
-            if let Err(_) = fs::create_dir_all(parent_dir) {
-                return Err("DIRECTORY_CREATE_ERROR".to_string());
-            }
-        }
-    }
+    ensure_parent_dir(&full_path.to_string_lossy())?;
 
     // 파일 생성
-    match fs::write(&full_path, file_content) {
+    match fs::write(&full_path, bytes) {
         Ok(_) => Ok("SUCCESS".to_string()),
         Err(_) => Err("FILE_CREATE_ERROR".to_string()),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::{path_str, temp_dir};
+    use base64::engine::general_purpose;
+    use std::fs;
+
+    #[test]
+    fn file_creator_writes_file_and_creates_parent_dirs() {
+        let dir = temp_dir();
+        let nested = dir.path().join("a").join("b");
+
+        let result = file_creator_node(
+            path_str(&nested),
+            "hello.txt".to_string(),
+            "안녕하세요".to_string(),
+            None,
+        );
+
+        assert_eq!(result, Ok("SUCCESS".to_string()));
+        assert_eq!(
+            fs::read_to_string(nested.join("hello.txt")).unwrap(),
+            "안녕하세요"
+        );
+    }
+
+    #[test]
+    fn file_creator_rejects_empty_file_name() {
+        let dir = temp_dir();
+
+        let result = file_creator_node(
+            path_str(dir.path()),
+            "  ".to_string(),
+            "x".to_string(),
+            None,
+        );
+
+        assert_eq!(result, Err("EMPTY_FILENAME".to_string()));
+    }
+
+    #[test]
+    fn file_creator_writes_raw_bytes_from_base64() {
+        let dir = temp_dir();
+        let bytes: Vec<u8> = vec![0x89, b'P', b'N', b'G', 0x00, 0xff, 0x10];
+
+        let result = file_creator_node(
+            path_str(dir.path()),
+            "image.bin".to_string(),
+            format!(
+                "data:image/png;base64,{}",
+                general_purpose::STANDARD.encode(&bytes)
+            ),
+            Some("base64".to_string()),
+        );
+
+        assert_eq!(result, Ok("SUCCESS".to_string()));
+        assert_eq!(fs::read(dir.path().join("image.bin")).unwrap(), bytes);
+    }
+
+    #[test]
+    fn file_creator_rejects_invalid_base64() {
+        let dir = temp_dir();
+
+        let result = file_creator_node(
+            path_str(dir.path()),
+            "broken.bin".to_string(),
+            "not base64!".to_string(),
+            Some("base64".to_string()),
+        );
+
+        assert_eq!(result, Err("INVALID_BASE64_CONTENT".to_string()));
+    }
+}
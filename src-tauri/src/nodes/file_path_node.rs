@@ -1,13 +1,146 @@
 // src-tauri/src/nodes/file_path_node.rs
+use super::{atomic_store, safe_path};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
 use std::fs;
 use std::path::{Path, PathBuf};
 use tauri::command;
 
+// 패턴 하나가 너무 많은 파일로 펼쳐지지 않도록 기본 제한
+const DEFAULT_MAX_MATCHES: usize = 500;
+// 하위 폴더 탐색 깊이 상한 (외장 드라이브 전체를 훑지 않도록)
+const MAX_SEARCH_DEPTH: usize = 8;
+
+// 파일명만 입력했을 때 찾아볼 위치 설정 (store/file_search_settings.json)
+// roots → (include_defaults 면) 바탕화면, 다운로드, 문서, 홈, 현재 폴더 순서로 검색
+// depth 0 은 각 위치 바로 아래만, 1 이상이면 그 깊이까지 하위 폴더도 검색
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FileSearchSettings {
+    pub roots: Vec<String>,
+    pub include_defaults: bool,
+    pub depth: usize,
+}
+
+impl Default for FileSearchSettings {
+    fn default() -> Self {
+        Self {
+            roots: Vec::new(),
+            include_defaults: true,
+            depth: 0,
+        }
+    }
+}
+
+fn settings_path() -> PathBuf {
+    atomic_store::store_dir().join("file_search_settings.json")
+}
+
+fn load_settings() -> FileSearchSettings {
+    atomic_store::read_json_verified(&settings_path()).unwrap_or_default()
+}
+
+// 실제 검색에 쓰는 위치 목록 (없는 폴더는 제외 - 빠진 외장 드라이브 등)
+#[derive(Debug, Clone)]
+pub struct SearchScope {
+    pub roots: Vec<PathBuf>,
+    pub depth: usize,
+}
+
+impl SearchScope {
+    pub fn new(
+        call_roots: &[String],
+        call_depth: Option<usize>,
+        settings: &FileSearchSettings,
+    ) -> Self {
+        let mut roots: Vec<PathBuf> = Vec::new();
+        let configured = call_roots
+            .iter()
+            .chain(&settings.roots)
+            .map(|root| root.trim())
+            .filter(|root| !root.is_empty())
+            .map(PathBuf::from);
+        let defaults = if settings.include_defaults {
+            default_locations()
+        } else {
+            Vec::new()
+        };
+        for root in configured.chain(defaults) {
+            if root.is_dir() && !roots.contains(&root) {
+                roots.push(root);
+            }
+        }
+        Self {
+            roots,
+            depth: call_depth.unwrap_or(settings.depth).min(MAX_SEARCH_DEPTH),
+        }
+    }
+
+    // 파일명과 일치하는 첫 파일 (각 위치 바로 아래 → 하위 폴더 순)
+    pub fn find_file(&self, name: &Path, case_insensitive: bool) -> Option<PathBuf> {
+        for root in &self.roots {
+            if let Some(found) =
+                find_existing(&root.join(name), case_insensitive).filter(|p| p.is_file())
+            {
+                return Some(found);
+            }
+        }
+        if self.depth == 0 {
+            return None;
+        }
+        let wanted = name.to_string_lossy().to_string();
+        for root in &self.roots {
+            let found = walkdir::WalkDir::new(root)
+                .min_depth(2)
+                .max_depth(self.depth + 1)
+                .into_iter()
+                // 숨김 폴더(.git 등)는 건너뜀
+                .filter_entry(|entry| !entry.file_name().to_string_lossy().starts_with('.'))
+                .filter_map(Result::ok)
+                .find(|entry| {
+                    let file_name = entry.file_name().to_string_lossy();
+                    entry.file_type().is_file()
+                        && if case_insensitive {
+                            file_name.to_lowercase() == wanted.to_lowercase()
+                        } else {
+                            file_name == wanted
+                        }
+                });
+            if let Some(entry) = found {
+                return Some(entry.into_path());
+            }
+        }
+        None
+    }
+
+    fn describe(&self) -> String {
+        self.roots
+            .iter()
+            .map(|root| root.display().to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
 #[command]
-pub async fn file_path_node(file_paths: Vec<String>) -> Result<String, String> {
+pub async fn file_path_node(
+    file_paths: Vec<String>,
+    case_insensitive: Option<bool>,
+    max_matches: Option<usize>,
+    search_roots: Option<Vec<String>>,
+    search_depth: Option<usize>,
+) -> Result<String, String> {
     println!("📁 FilePathNode 실행 시작");
     println!("📝 입력된 경로 개수: {}", file_paths.len());
 
+    let case_insensitive = case_insensitive.unwrap_or(false);
+    let max_matches = max_matches.unwrap_or(DEFAULT_MAX_MATCHES).max(1);
+    let scope = SearchScope::new(
+        &search_roots.unwrap_or_default(),
+        search_depth,
+        &load_settings(),
+    );
+
     if file_paths.is_empty() {
         return Err("선택된 파일이 없습니다".to_string());
     }
@@ -18,7 +151,22 @@ pub async fn file_path_node(file_paths: Vec<String>) -> Result<String, String> {
     for path_str in file_paths {
         println!("🔍 경로 검증 중: {}", path_str);
 
-        match verify_and_normalize_path(&path_str) {
+        // 글롭 패턴 (예: Desktop/*.psd) 은 일치하는 파일 목록으로 펼침
+        if is_glob_pattern(&path_str) {
+            match expand_glob(&path_str, &scope, case_insensitive, max_matches) {
+                Ok(matches) => {
+                    println!("✅ 패턴 일치: {} → {}개", path_str, matches.len());
+                    verified_paths.extend(matches);
+                }
+                Err(error) => {
+                    errors.push(format!("❌ {}: {}", path_str, error));
+                    println!("❌ 패턴 일치 없음: {} - {}", path_str, error);
+                }
+            }
+            continue;
+        }
+
+        match verify_and_normalize_path(&path_str, &scope, case_insensitive) {
             Ok(normalized_path) => {
                 verified_paths.push(normalized_path);
                 println!("✅ 유효한 경로: {}", path_str);
@@ -57,39 +205,155 @@ pub async fn file_path_node(file_paths: Vec<String>) -> Result<String, String> {
     Ok(result)
 }
 
-fn verify_and_normalize_path(path_str: &str) -> Result<String, String> {
+pub fn is_glob_pattern(path_str: &str) -> bool {
+    path_str.contains(['*', '?', '['])
+}
+
+fn match_options(case_insensitive: bool) -> glob::MatchOptions {
+    glob::MatchOptions {
+        case_sensitive: !case_insensitive,
+        require_literal_separator: true,
+        require_literal_leading_dot: false,
+    }
+}
+
+// 기본 검색 위치
+fn default_locations() -> Vec<PathBuf> {
+    vec![
+        dirs::desktop_dir(),
+        dirs::download_dir(),
+        dirs::document_dir(),
+        dirs::home_dir(),
+        std::env::current_dir().ok(),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+// 경로가 그대로 없으면 대소문자 무시로 다시 찾기 (경로 구성요소마다 폴더 안에서 이름 비교)
+// glob 은 와일드카드 없는 구성요소를 그대로 존재 확인만 해서 대소문자 옵션이 적용되지 않음
+pub fn find_existing(candidate: &Path, case_insensitive: bool) -> Option<PathBuf> {
+    if candidate.exists() {
+        return Some(candidate.to_path_buf());
+    }
+    if !case_insensitive {
+        return None;
+    }
+    let mut found = PathBuf::new();
+    for component in candidate.components() {
+        let exact = found.join(component);
+        if exact.exists() {
+            found = exact;
+            continue;
+        }
+        let wanted = component.as_os_str().to_string_lossy().to_lowercase();
+        let dir = if found.as_os_str().is_empty() {
+            Path::new(".")
+        } else {
+            found.as_path()
+        };
+        let entry = std::fs::read_dir(dir)
+            .ok()?
+            .flatten()
+            .find(|entry| entry.file_name().to_string_lossy().to_lowercase() == wanted)?;
+        found.push(entry.file_name());
+    }
+    Some(found)
+}
+
+// 글롭 패턴 → 일치하는 파일 (절대 경로면 그대로, 상대 경로면 일반 위치 기준)
+pub fn expand_glob(
+    pattern: &str,
+    scope: &SearchScope,
+    case_insensitive: bool,
+    max_matches: usize,
+) -> Result<Vec<String>, String> {
+    let pattern = pattern.trim();
+    let bases = if Path::new(pattern).is_absolute() {
+        vec![PathBuf::new()]
+    } else {
+        scope.roots.clone()
+    };
+
+    let mut matches: Vec<String> = Vec::new();
+    let mut truncated = false;
+    'bases: for base in bases {
+        let full_pattern = if base.as_os_str().is_empty() {
+            pattern.to_string()
+        } else {
+            // 기준 폴더 이름에 든 [ ] 등은 패턴으로 해석되지 않도록
+            format!(
+                "{}/{}",
+                glob::Pattern::escape(&base.to_string_lossy()),
+                pattern
+            )
+        };
+        let paths = glob::glob_with(&full_pattern, match_options(case_insensitive))
+            .map_err(|e| format!("잘못된 패턴입니다: {}", e))?;
+        for path in paths.filter_map(Result::ok).filter(|p| p.is_file()) {
+            let path = path.canonicalize().unwrap_or(path);
+            let unified = unify_separators(path.to_string_lossy().to_string());
+            if matches.contains(&unified) {
+                continue;
+            }
+            if matches.len() >= max_matches {
+                truncated = true;
+                break 'bases;
+            }
+            matches.push(unified);
+        }
+    }
+
+    if truncated {
+        println!(
+            "⚠️ 일치하는 파일이 너무 많아 {}개까지만 사용합니다: {}",
+            max_matches, pattern
+        );
+    }
+    if matches.is_empty() {
+        return Err(format!("패턴과 일치하는 파일이 없습니다: '{}'", pattern));
+    }
+    matches.sort();
+    Ok(matches)
+}
+
+fn unify_separators(path_string: String) -> String {
+    if cfg!(windows) {
+        // Windows에서는 백슬래시를 슬래시로 변환 (선택사항)
+        path_string.replace('\\', "/")
+    } else {
+        path_string
+    }
+}
+
+fn verify_and_normalize_path(
+    path_str: &str,
+    scope: &SearchScope,
+    case_insensitive: bool,
+) -> Result<String, String> {
     // 빈 경로 체크
     if path_str.trim().is_empty() {
         return Err("빈 경로입니다".to_string());
     }
 
-    let mut path = PathBuf::from(path_str.trim());
+    let mut path = safe_path::resolve_existing(path_str);
 
     // 파일명만 있는 경우 (확장자 포함) 일반적인 위치에서 찾기
     if !path.is_absolute() && !path_str.contains('/') && !path_str.contains('\\') {
-        // 파일명만 있는 경우, 일반적인 위치들에서 찾기
-        let search_paths = vec![
-            dirs::desktop_dir(),
-            dirs::download_dir(),
-            dirs::document_dir(),
-            dirs::home_dir(),
-            std::env::current_dir().ok(),
-        ];
-
-        for search_dir in search_paths.into_iter().flatten() {
-            let potential_path = search_dir.join(&path);
-            if potential_path.exists() && potential_path.is_file() {
-                println!("🔍 파일 발견: {} → {}", path_str, potential_path.display());
-                path = potential_path;
-                break;
-            }
+        // 파일명만 있는 경우, 검색 위치들에서 찾기
+        if let Some(found) = scope.find_file(&path, case_insensitive) {
+            println!("🔍 파일 발견: {} → {}", path_str, found.display());
+            path = found;
         }
 
         // 여전히 찾을 수 없으면 에러
         if !path.exists() {
             return Err(format!(
-                "파일을 찾을 수 없습니다: '{}' (검색 위치: 바탕화면, 다운로드, 문서, 홈 폴더)",
-                path_str
+                "파일을 찾을 수 없습니다: '{}' (검색 위치: {}, 깊이 {})",
+                path_str,
+                scope.describe(),
+                scope.depth
             ));
         }
     }
@@ -105,6 +369,9 @@ fn verify_and_normalize_path(path_str: &str) -> Result<String, String> {
         }
     };
 
+    // 대소문자만 다른 경우 실제 파일 이름으로
+    let absolute_path = find_existing(&absolute_path, case_insensitive).unwrap_or(absolute_path);
+
     // 경로 정규화 (. 및 .. 제거)
     let normalized_path = match absolute_path.canonicalize() {
         Ok(canonical) => canonical,
@@ -140,14 +407,7 @@ fn verify_and_normalize_path(path_str: &str) -> Result<String, String> {
     let path_string = normalized_path.to_string_lossy().to_string();
 
     // Windows 경로를 Unix 스타일로 변환 (선택적)
-    let unified_path = if cfg!(windows) {
-        // Windows에서는 백슬래시를 슬래시로 변환 (선택사항)
-        path_string.replace('\\', "/")
-    } else {
-        path_string
-    };
-
-    Ok(unified_path)
+    Ok(unify_separators(path_string))
 }
 
 fn normalize_path_manually(path: &Path) -> Result<PathBuf, String> {
@@ -194,6 +454,53 @@ fn normalize_path_manually(path: &Path) -> Result<PathBuf, String> {
     Ok(PathBuf::from(result))
 }
 
+#[tauri::command]
+pub fn get_file_search_settings() -> Result<String, String> {
+    let settings = load_settings();
+    Ok(json!({
+        "roots": settings.roots,
+        "include_defaults": settings.include_defaults,
+        "depth": settings.depth,
+        "default_roots": default_locations()
+            .iter()
+            .map(|p| p.to_string_lossy().to_string())
+            .collect::<Vec<_>>()
+    })
+    .to_string())
+}
+
+#[tauri::command]
+pub fn set_file_search_settings(
+    roots: Vec<String>,
+    include_defaults: Option<bool>,
+    depth: Option<usize>,
+) -> Result<String, String> {
+    let settings = FileSearchSettings {
+        roots: roots
+            .into_iter()
+            .map(|root| root.trim().to_string())
+            .filter(|root| !root.is_empty())
+            .collect(),
+        include_defaults: include_defaults.unwrap_or(true),
+        depth: depth.unwrap_or(0).min(MAX_SEARCH_DEPTH),
+    };
+    if settings.roots.is_empty() && !settings.include_defaults {
+        return Err("NO_SEARCH_ROOTS".to_string());
+    }
+    atomic_store::write_json_atomic(&settings_path(), &settings)?;
+    println!(
+        "📁 파일 검색 위치 변경: {}개 (기본 위치 {}, 깊이 {})",
+        settings.roots.len(),
+        if settings.include_defaults {
+            "포함"
+        } else {
+            "제외"
+        },
+        settings.depth
+    );
+    serde_json::to_string(&settings).map_err(|e| e.to_string())
+}
+
 // 파일 정보 추가 확인 함수 (나중에 확장용)
 #[allow(dead_code)]
 fn get_file_info(path: &Path) -> Result<FileInfo, String> {
@@ -212,3 +519,126 @@ struct FileInfo {
     is_readonly: bool,
     modified: Option<std::time::SystemTime>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::{path_str, temp_dir};
+    use std::fs;
+
+    #[tokio::test]
+    async fn file_path_node_normalizes_existing_files() {
+        let dir = temp_dir();
+        let file = dir.path().join("data.csv");
+        fs::write(&file, "a,b").unwrap();
+
+        let output = file_path_node(vec![path_str(&file)], None, None, None, None)
+            .await
+            .unwrap();
+
+        assert!(output.ends_with("data.csv"));
+    }
+
+    #[tokio::test]
+    async fn file_path_node_fails_when_nothing_is_valid() {
+        let dir = temp_dir();
+
+        let result = file_path_node(
+            vec![path_str(&dir.path().join("nope.txt"))],
+            None,
+            None,
+            None,
+            None,
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn file_path_node_expands_glob_patterns_with_limit() {
+        let dir = temp_dir();
+        for name in ["a.psd", "b.PSD", "c.psd", "notes.txt"] {
+            fs::write(dir.path().join(name), "x").unwrap();
+        }
+        let pattern = format!("{}/*.psd", path_str(dir.path()));
+
+        let output = file_path_node(vec![pattern.clone()], None, None, None, None)
+            .await
+            .unwrap();
+        assert_eq!(output.lines().count(), 2);
+
+        let output = file_path_node(vec![pattern.clone()], Some(true), None, None, None)
+            .await
+            .unwrap();
+        assert_eq!(output.lines().count(), 3);
+        assert!(!output.contains("notes.txt"));
+
+        let output = file_path_node(vec![pattern], Some(true), Some(2), None, None)
+            .await
+            .unwrap();
+        assert_eq!(output.lines().count(), 2);
+    }
+
+    #[tokio::test]
+    async fn file_path_node_resolves_names_case_insensitively() {
+        let dir = temp_dir();
+        fs::write(dir.path().join("Report.PDF"), "x").unwrap();
+        let requested = path_str(&dir.path().join("report.pdf"));
+
+        let output = file_path_node(vec![requested.clone()], Some(true), None, None, None)
+            .await
+            .unwrap();
+        assert!(output.to_lowercase().ends_with("report.pdf"));
+
+        // 대소문자를 구분하는 파일 시스템에서는 기본값으로 찾지 않음
+        if cfg!(target_os = "linux") {
+            assert!(output.ends_with("Report.PDF"));
+            assert!(file_path_node(vec![requested], None, None, None, None)
+                .await
+                .is_err());
+        }
+    }
+
+    #[tokio::test]
+    async fn file_path_node_searches_custom_roots_to_depth() {
+        let dir = temp_dir();
+        let nested = dir.path().join("project").join("assets");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(nested.join("logo_5055.psd"), "x").unwrap();
+        let roots = Some(vec![path_str(dir.path())]);
+
+        let shallow = file_path_node(
+            vec!["logo_5055.psd".to_string()],
+            None,
+            None,
+            roots.clone(),
+            Some(1),
+        )
+        .await;
+        assert!(shallow.is_err());
+
+        let output = file_path_node(
+            vec!["logo_5055.psd".to_string()],
+            None,
+            None,
+            roots.clone(),
+            Some(2),
+        )
+        .await
+        .unwrap();
+        assert!(output.ends_with("logo_5055.psd"));
+
+        // 글롭도 지정한 위치 기준으로 펼침
+        let output = file_path_node(
+            vec!["project/assets/*.psd".to_string()],
+            None,
+            None,
+            roots,
+            None,
+        )
+        .await
+        .unwrap();
+        assert!(output.ends_with("logo_5055.psd"));
+    }
+}
@@ -0,0 +1,206 @@
+// src-tauri/src/nodes/file_stream_write.rs
+use serde_json::json;
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use super::file_creator_node::{build_full_path, decode_content, ensure_parent_dir};
+use super::safe_path;
+
+// 대용량 파일 청크 쓰기 (begin → append_chunk 반복 → finish)
+// 쓰는 동안은 "<파일명>.part"에 기록하고 finish 시점에 최종 이름으로 교체
+struct ActiveWrite {
+    file: File,
+    part_path: PathBuf,
+    final_path: PathBuf,
+    content_encoding: Option<String>,
+    bytes_written: u64,
+    chunks: u64,
+}
+
+static ACTIVE_WRITES: std::sync::OnceLock<Mutex<HashMap<String, ActiveWrite>>> =
+    std::sync::OnceLock::new();
+static WRITE_COUNTER: AtomicU64 = AtomicU64::new(1);
+
+fn get_active_writes() -> &'static Mutex<HashMap<String, ActiveWrite>> {
+    ACTIVE_WRITES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn part_path_for(final_path: &Path) -> PathBuf {
+    let mut name = final_path
+        .file_name()
+        .map(|n| n.to_os_string())
+        .unwrap_or_default();
+    name.push(".part");
+    final_path.with_file_name(name)
+}
+
+#[tauri::command]
+pub fn begin_file_write(
+    file_path: String,
+    file_name: String,
+    content_encoding: Option<String>,
+) -> Result<String, String> {
+    if file_name.trim().is_empty() {
+        return Err("EMPTY_FILENAME".to_string());
+    }
+
+    let final_path = safe_path::prepare_new(&build_full_path(&file_path, &file_name))?;
+    ensure_parent_dir(&final_path.to_string_lossy())?;
+
+    let part_path = part_path_for(&final_path);
+    let file = File::create(&part_path).map_err(|_| "FILE_CREATE_ERROR".to_string())?;
+
+    let write_id = format!(
+        "write_{}_{}",
+        chrono::Local::now().timestamp_millis(),
+        WRITE_COUNTER.fetch_add(1, Ordering::SeqCst)
+    );
+
+    println!("📝 스트리밍 쓰기 시작: {} ({})", final_path.display(), write_id);
+
+    let mut writes = get_active_writes()
+        .lock()
+        .map_err(|_| "WRITE_REGISTRY_LOCK_ERROR".to_string())?;
+    writes.insert(
+        write_id.clone(),
+        ActiveWrite {
+            file,
+            part_path,
+            final_path,
+            content_encoding,
+            bytes_written: 0,
+            chunks: 0,
+        },
+    );
+
+    Ok(write_id)
+}
+
+// base64 인코딩이면 각 청크는 독립적으로 디코딩 가능해야 함 (4의 배수 길이)
+#[tauri::command]
+pub fn append_chunk(write_id: String, chunk: String) -> Result<u64, String> {
+    let mut writes = get_active_writes()
+        .lock()
+        .map_err(|_| "WRITE_REGISTRY_LOCK_ERROR".to_string())?;
+    let active = writes
+        .get_mut(&write_id)
+        .ok_or_else(|| format!("WRITE_NOT_FOUND: {}", write_id))?;
+
+    let bytes = decode_content(chunk, active.content_encoding.as_deref())?;
+    active
+        .file
+        .write_all(&bytes)
+        .map_err(|_| "FILE_WRITE_ERROR".to_string())?;
+
+    active.bytes_written += bytes.len() as u64;
+    active.chunks += 1;
+    Ok(active.bytes_written)
+}
+
+#[tauri::command]
+pub fn finish_file_write(write_id: String) -> Result<serde_json::Value, String> {
+    let active = get_active_writes()
+        .lock()
+        .map_err(|_| "WRITE_REGISTRY_LOCK_ERROR".to_string())?
+        .remove(&write_id)
+        .ok_or_else(|| format!("WRITE_NOT_FOUND: {}", write_id))?;
+
+    let ActiveWrite {
+        mut file,
+        part_path,
+        final_path,
+        bytes_written,
+        chunks,
+        ..
+    } = active;
+
+    file.flush().map_err(|_| "FILE_WRITE_ERROR".to_string())?;
+    file.sync_all()
+        .map_err(|_| "FILE_WRITE_ERROR".to_string())?;
+    drop(file);
+
+    fs::rename(&part_path, &final_path).map_err(|_| "FILE_RENAME_ERROR".to_string())?;
+
+    println!(
+        "✅ 스트리밍 쓰기 완료: {} ({} bytes, {} chunks)",
+        final_path.display(),
+        bytes_written,
+        chunks
+    );
+
+    Ok(json!({
+        "path": final_path.to_string_lossy(),
+        "bytes_written": bytes_written,
+        "chunks": chunks
+    }))
+}
+
+// 중단 시 .part 파일 정리
+#[tauri::command]
+pub fn abort_file_write(write_id: String) -> Result<String, String> {
+    let active = get_active_writes()
+        .lock()
+        .map_err(|_| "WRITE_REGISTRY_LOCK_ERROR".to_string())?
+        .remove(&write_id);
+
+    match active {
+        Some(active) => {
+            let part_path = active.part_path.clone();
+            drop(active);
+            if let Err(e) = fs::remove_file(&part_path) {
+                println!("⚠️ 임시 파일 삭제 실패: {}", e);
+            }
+            Ok("Write aborted".to_string())
+        }
+        None => Err(format!("WRITE_NOT_FOUND: {}", write_id)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::{path_str, temp_dir};
+    use base64::{engine::general_purpose, Engine as _};
+    use std::fs;
+
+    #[test]
+    fn streaming_write_assembles_chunks_and_renames_part_file() {
+        let dir = temp_dir();
+
+        let write_id = begin_file_write(
+            path_str(dir.path()),
+            "big.bin".to_string(),
+            Some("base64".to_string()),
+        )
+        .unwrap();
+        assert!(dir.path().join("big.bin.part").exists());
+
+        for chunk in [&b"hello "[..], &b"world!"[..]] {
+            append_chunk(write_id.clone(), general_purpose::STANDARD.encode(chunk)).unwrap();
+        }
+        let summary = finish_file_write(write_id).unwrap();
+
+        assert_eq!(summary["bytes_written"], 12);
+        assert!(!dir.path().join("big.bin.part").exists());
+        assert_eq!(
+            fs::read(dir.path().join("big.bin")).unwrap(),
+            b"hello world!"
+        );
+    }
+
+    #[test]
+    fn streaming_write_abort_removes_part_file() {
+        let dir = temp_dir();
+
+        let write_id = begin_file_write(path_str(dir.path()), "x.txt".to_string(), None).unwrap();
+        append_chunk(write_id.clone(), "partial".to_string()).unwrap();
+        abort_file_write(write_id.clone()).unwrap();
+
+        assert!(!dir.path().join("x.txt.part").exists());
+        assert!(append_chunk(write_id, "more".to_string()).is_err());
+    }
+}
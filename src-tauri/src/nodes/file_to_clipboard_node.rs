@@ -1,6 +1,6 @@
 // src-tauri/src/nodes/file_to_clipboard_node.rs
+use super::safe_path;
 use tauri::command;
-use std::path::Path;
 use std::process::Command;
 
 #[command]
@@ -15,9 +15,9 @@ pub async fn file_to_clipboard_node(file_paths: Vec<String>) -> Result<String, S
     // 파일들이 존재하는지 확인
     let mut valid_paths = Vec::new();
     for file_path in &file_paths {
-        let path = Path::new(file_path.trim());
+        let path = safe_path::resolve_existing(file_path);
         if path.exists() {
-            valid_paths.push(file_path.trim().to_string());
+            valid_paths.push(path.to_string_lossy().to_string());
             println!("✅ 파일 확인: {}", file_path);
         } else {
             println!("❌ 파일이 존재하지 않음: {}", file_path);
@@ -73,7 +73,7 @@ fn copy_files_to_clipboard(file_paths: &[String]) -> Result<(), String> {
             println!("🔧 시도 중: {}", ps_cmd);
             
             match Command::new(ps_cmd)
-                .args(&["-Command", &command])
+                .args(["-Command", &command])
                 .output()
             {
                 Ok(output) => {
@@ -112,7 +112,7 @@ fn copy_files_to_clipboard(file_paths: &[String]) -> Result<(), String> {
         );
         
         match Command::new("osascript")
-            .args(&["-e", &script])
+            .args(["-e", &script])
             .output()
         {
             Ok(output) => {
@@ -136,7 +136,7 @@ fn copy_files_to_clipboard(file_paths: &[String]) -> Result<(), String> {
         let paths_string = file_paths.join("\n");
         
         match Command::new("xclip")
-            .args(&["-selection", "clipboard", "-t", "text/uri-list"])
+            .args(["-selection", "clipboard", "-t", "text/uri-list"])
             .arg("-i")
             .env("CLIPBOARD_CONTENT", &paths_string)
             .output()
@@ -0,0 +1,365 @@
+// src-tauri/src/nodes/gallery_node.rs
+use super::{os_command, safe_path};
+use serde_json::json;
+use std::path::{Path, PathBuf};
+
+// 🖼️ 갤러리 인덱스 노드
+// 다운로드한 이미지/동영상 폴더에 썸네일이 달린 정적 HTML(index.html)을 만들어
+// 브라우저로 바로 훑어볼 수 있게 함
+// - 썸네일은 <폴더>/.thumbnails 에 저장 (원본보다 새 썸네일이 있으면 재사용)
+// - 이미지: image 크레이트로 축소, 동영상: 번들된 ffmpeg 로 1초 지점 프레임 추출
+// - 링크는 모두 상대 경로라 폴더째 옮겨도 갤러리가 그대로 동작
+
+const THUMBNAIL_DIR: &str = ".thumbnails";
+const THUMBNAIL_SIZE: u32 = 320;
+
+const IMAGE_EXTENSIONS: &[&str] = &["jpg", "jpeg", "png", "gif", "webp", "bmp", "tif", "tiff"];
+const VIDEO_EXTENSIONS: &[&str] = &["mp4", "mov", "m4v", "webm", "mkv", "avi", "wmv", "3gp"];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MediaKind {
+    Image,
+    Video,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GalleryItem {
+    // 출력 HTML 기준 상대 경로 ('/' 구분)
+    pub href: String,
+    pub thumbnail: Option<String>,
+    pub name: String,
+    pub kind: MediaKind,
+    pub size: u64,
+}
+
+pub fn media_kind(path: &Path) -> Option<MediaKind> {
+    let ext = path.extension()?.to_string_lossy().to_lowercase();
+    if IMAGE_EXTENSIONS.contains(&ext.as_str()) {
+        Some(MediaKind::Image)
+    } else if VIDEO_EXTENSIONS.contains(&ext.as_str()) {
+        Some(MediaKind::Video)
+    } else {
+        None
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// 상대 경로를 href 로 (세그먼트별 퍼센트 인코딩, '/' 는 유지)
+// 출력 폴더와 드라이브가 달라 절대 경로가 남으면 file:// URL 로
+pub fn href_for(relative: &Path) -> String {
+    if relative.is_absolute() {
+        if let Ok(url) = url::Url::from_file_path(relative) {
+            return url.to_string();
+        }
+    }
+    relative
+        .components()
+        .map(|part| {
+            let text = part.as_os_str().to_string_lossy();
+            let mut encoded = String::new();
+            for byte in text.bytes() {
+                match byte {
+                    b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                        encoded.push(byte as char)
+                    }
+                    _ => encoded.push_str(&format!("%{:02X}", byte)),
+                }
+            }
+            encoded
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn format_size(bytes: u64) -> String {
+    match bytes {
+        b if b >= 1024 * 1024 * 1024 => format!("{:.1} GB", b as f64 / 1024f64.powi(3)),
+        b if b >= 1024 * 1024 => format!("{:.1} MB", b as f64 / 1024f64.powi(2)),
+        b if b >= 1024 => format!("{:.0} KB", b as f64 / 1024.0),
+        b => format!("{} B", b),
+    }
+}
+
+pub fn render_gallery(title: &str, items: &[GalleryItem]) -> String {
+    let mut tiles = String::new();
+    for item in items {
+        let preview = match &item.thumbnail {
+            Some(thumb) => format!(
+                r#"<img src="{}" alt="{}" loading="lazy">"#,
+                escape_html(thumb),
+                escape_html(&item.name)
+            ),
+            None => r#"<div class="none">미리보기 없음</div>"#.to_string(),
+        };
+        let badge = if item.kind == MediaKind::Video {
+            r#"<span class="badge">▶</span>"#
+        } else {
+            ""
+        };
+        tiles.push_str(&format!(
+            r#"<a class="tile" href="{href}" target="_blank">{preview}{badge}<span class="name" title="{name}">{name}</span><span class="size">{size}</span></a>
+"#,
+            href = escape_html(&item.href),
+            name = escape_html(&item.name),
+            size = format_size(item.size),
+        ));
+    }
+
+    let images = items.iter().filter(|i| i.kind == MediaKind::Image).count();
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="ko">
+<head>
+<meta charset="utf-8">
+<title>{title}</title>
+<style>
+body {{ font-family: sans-serif; margin: 24px; background: #111; color: #eee; }}
+h1 {{ font-size: 20px; margin: 0 0 4px; }}
+.meta {{ color: #999; margin-bottom: 16px; font-size: 13px; }}
+.grid {{ display: grid; grid-template-columns: repeat(auto-fill, minmax(200px, 1fr)); gap: 12px; }}
+.tile {{ position: relative; display: flex; flex-direction: column; background: #1d1d1d; border-radius: 6px; overflow: hidden; color: inherit; text-decoration: none; }}
+.tile:hover {{ outline: 2px solid #4a9eff; }}
+.tile img, .tile .none {{ width: 100%; aspect-ratio: 1; object-fit: cover; background: #000; }}
+.tile .none {{ display: flex; align-items: center; justify-content: center; color: #666; font-size: 12px; }}
+.badge {{ position: absolute; top: 8px; right: 8px; background: rgba(0,0,0,.7); border-radius: 4px; padding: 2px 6px; font-size: 12px; }}
+.name {{ padding: 6px 8px 0; font-size: 12px; white-space: nowrap; overflow: hidden; text-overflow: ellipsis; }}
+.size {{ padding: 2px 8px 8px; font-size: 11px; color: #888; }}
+</style>
+</head>
+<body>
+<h1>{title}</h1>
+<div class="meta">이미지 {images}개 · 동영상 {videos}개 · {generated}</div>
+<div class="grid">
+{tiles}</div>
+</body>
+</html>
+"#,
+        title = escape_html(title),
+        images = images,
+        videos = items.len() - images,
+        generated = chrono::Local::now().format("%Y-%m-%d %H:%M"),
+        tiles = tiles,
+    )
+}
+
+fn is_fresh(thumbnail: &Path, source: &Path) -> bool {
+    let modified = |p: &Path| std::fs::metadata(p).and_then(|m| m.modified()).ok();
+    matches!((modified(thumbnail), modified(source)), (Some(t), Some(s)) if t >= s)
+}
+
+pub fn video_thumbnail_args(source: &Path, target: &Path, at_secs: &str) -> Vec<String> {
+    vec![
+        "-y".to_string(),
+        "-v".to_string(),
+        "error".to_string(),
+        "-ss".to_string(),
+        at_secs.to_string(),
+        "-i".to_string(),
+        source.to_string_lossy().to_string(),
+        "-frames:v".to_string(),
+        "1".to_string(),
+        "-vf".to_string(),
+        format!("scale={}:-2", THUMBNAIL_SIZE),
+        target.to_string_lossy().to_string(),
+    ]
+}
+
+fn make_thumbnail(source: &Path, target: &Path, kind: MediaKind) -> Result<(), String> {
+    if is_fresh(target, source) {
+        return Ok(());
+    }
+    match kind {
+        MediaKind::Image => {
+            let image = image::open(source).map_err(|e| format!("이미지 열기 실패: {}", e))?;
+            image
+                .thumbnail(THUMBNAIL_SIZE, THUMBNAIL_SIZE)
+                .to_rgb8()
+                .save(target)
+                .map_err(|e| format!("썸네일 저장 실패: {}", e))
+        }
+        MediaKind::Video => {
+            let ffmpeg = os_command::bundled_binary("ffmpeg");
+            // 1초보다 짧은 영상은 첫 프레임으로 재시도
+            let mut last_error = String::new();
+            for at in ["1", "0"] {
+                let args = video_thumbnail_args(source, target, at);
+                let arg_refs: Vec<&str> = args.iter().map(String::as_str).collect();
+                match os_command::run(&ffmpeg, &arg_refs) {
+                    Ok(_) => return Ok(()),
+                    Err(e) => last_error = e,
+                }
+            }
+            Err(last_error)
+        }
+    }
+}
+
+fn collect_media(folder: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = walkdir::WalkDir::new(folder)
+        .into_iter()
+        // 숨김 폴더(.thumbnails 포함)는 건너뜀
+        .filter_entry(|entry| {
+            entry.depth() == 0 || !entry.file_name().to_string_lossy().starts_with('.')
+        })
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file() && media_kind(entry.path()).is_some())
+        .map(|entry| entry.into_path())
+        .collect();
+    files.sort();
+    files
+}
+
+fn thumbnail_name(relative: &Path) -> String {
+    // 하위 폴더의 같은 이름 파일이 겹치지 않도록 경로를 이름에 포함
+    let flat = relative.to_string_lossy().replace(['/', '\\'], "__");
+    format!("{}.jpg", flat)
+}
+
+fn build_gallery(folder: &Path, output: &Path) -> Result<serde_json::Value, String> {
+    let output_dir = output.parent().unwrap_or(folder).to_path_buf();
+    let thumbnail_dir = folder.join(THUMBNAIL_DIR);
+    std::fs::create_dir_all(&thumbnail_dir).map_err(|e| format!("썸네일 폴더 생성 실패: {}", e))?;
+
+    let mut items = Vec::new();
+    let mut failed = Vec::new();
+    for path in collect_media(folder) {
+        let Some(kind) = media_kind(&path) else {
+            continue;
+        };
+        let relative = path.strip_prefix(folder).unwrap_or(&path).to_path_buf();
+        let thumb_path = thumbnail_dir.join(thumbnail_name(&relative));
+        let thumbnail = match make_thumbnail(&path, &thumb_path, kind) {
+            Ok(()) => Some(thumb_path),
+            Err(e) => {
+                println!("⚠️ 썸네일 생성 실패 ({}): {}", relative.display(), e);
+                failed.push(relative.to_string_lossy().to_string());
+                None
+            }
+        };
+        items.push(GalleryItem {
+            href: href_for(&relative_to(&path, &output_dir)),
+            thumbnail: thumbnail.map(|t| href_for(&relative_to(&t, &output_dir))),
+            name: path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default(),
+            kind,
+            size: std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0),
+        });
+    }
+
+    let title = folder
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| "Gallery".to_string());
+    std::fs::write(output, render_gallery(&title, &items))
+        .map_err(|e| format!("갤러리 저장 실패: {}", e))?;
+
+    let images = items.iter().filter(|i| i.kind == MediaKind::Image).count();
+    Ok(json!({
+        "output": output.to_string_lossy(),
+        "images": images,
+        "videos": items.len() - images,
+        "thumbnail_failed": failed
+    }))
+}
+
+// output 폴더 기준 상대 경로 (공통 조상이 없으면 절대 경로 그대로)
+fn relative_to(path: &Path, base: &Path) -> PathBuf {
+    if let Ok(relative) = path.strip_prefix(base) {
+        return relative.to_path_buf();
+    }
+    let (path_parts, base_parts): (Vec<_>, Vec<_>) =
+        (path.components().collect(), base.components().collect());
+    let common = path_parts
+        .iter()
+        .zip(&base_parts)
+        .take_while(|(a, b)| a == b)
+        .count();
+    if common == 0 {
+        return path.to_path_buf();
+    }
+    let mut relative = PathBuf::new();
+    for _ in common..base_parts.len() {
+        relative.push("..");
+    }
+    for part in &path_parts[common..] {
+        relative.push(part);
+    }
+    relative
+}
+
+#[tauri::command]
+pub async fn gallery_node(folder: String, output: Option<String>) -> Result<String, String> {
+    let folder = safe_path::resolve_existing(&folder);
+    if !folder.is_dir() {
+        return Err("FOLDER_NOT_FOUND".to_string());
+    }
+    let output = match output.filter(|o| !o.trim().is_empty()) {
+        Some(output) => safe_path::prepare_new(&output)?,
+        None => folder.join("index.html"),
+    };
+    super::file_creator_node::ensure_parent_dir(&output.to_string_lossy())?;
+
+    println!(
+        "🖼️ Gallery Node: {} → {}",
+        folder.display(),
+        output.display()
+    );
+    let summary = tokio::task::spawn_blocking(move || build_gallery(&folder, &output))
+        .await
+        .map_err(|e| format!("갤러리 생성 실패: {}", e))??;
+    println!(
+        "✅ 갤러리 생성 완료: 이미지 {}개, 동영상 {}개",
+        summary["images"], summary["videos"]
+    );
+    Ok(summary.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::{parse_output, path_str, temp_dir};
+    use std::fs;
+
+    #[test]
+    fn gallery_href_encodes_segments() {
+        let href = href_for(std::path::Path::new("sub dir/영상 #1.mp4"));
+        assert_eq!(href, "sub%20dir/%EC%98%81%EC%83%81%20%231.mp4");
+        assert_eq!(
+            media_kind(std::path::Path::new("a.JPG")),
+            Some(MediaKind::Image)
+        );
+        assert_eq!(
+            media_kind(std::path::Path::new("a.txt")),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn gallery_node_writes_index_with_thumbnails() {
+        let dir = temp_dir();
+        image::RgbImage::from_pixel(800, 600, image::Rgb([200, 10, 10]))
+            .save(dir.path().join("photo.png"))
+            .unwrap();
+        fs::write(dir.path().join("notes.txt"), "skip").unwrap();
+
+        let result = parse_output(&gallery_node(path_str(dir.path()), None).await.unwrap());
+        assert_eq!(result["images"], 1);
+        assert_eq!(result["videos"], 0);
+
+        let html = fs::read_to_string(dir.path().join("index.html")).unwrap();
+        assert!(html.contains(r#"href="photo.png""#));
+        assert!(html.contains(r#"src=".thumbnails/photo.png.jpg""#));
+        assert!(!html.contains("notes.txt"));
+
+        let thumb = image::open(dir.path().join(".thumbnails").join("photo.png.jpg")).unwrap();
+        assert_eq!((thumb.width(), thumb.height()), (320, 240));
+    }
+}
@@ -0,0 +1,315 @@
+// src-tauri/src/nodes/geofence_trigger.rs
+use super::control_page;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter};
+use tokio::sync::RwLock;
+use warp::http::StatusCode;
+use warp::{Filter, Rejection, Reply};
+
+// 📍 지오펜스 트리거
+// 폰이 웹서버의 /location 으로 현재 좌표를 보내면(Tasker, 단축어, OwnTracks 등)
+// 설정한 반경 안으로 들어오거나 나갈 때 "geofence-event" 이벤트로 전달
+// 예: "집 근처에 도착하면 NAS 백업 시작"
+// - 처음 받은 좌표는 상태만 기록 (앱 시작 직후 잘못 발동하지 않도록)
+// - 경계에서 들락날락하지 않도록 나갈 때는 반경의 10% 를 더 벗어나야 함
+// - 정확도(accuracy)가 반경보다 나쁜 좌표는 무시
+
+const EXIT_HYSTERESIS: f64 = 1.1;
+const EARTH_RADIUS_M: f64 = 6_371_000.0;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LocationReport {
+    #[serde(alias = "lat")]
+    pub latitude: f64,
+    #[serde(alias = "lon", alias = "lng")]
+    pub longitude: f64,
+    #[serde(default, alias = "acc")]
+    pub accuracy: Option<f64>,
+    #[serde(default, alias = "tid")]
+    pub device: Option<String>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+struct GeofenceEvent {
+    node_id: String,
+    event: String, // "enter" | "exit"
+    device: String,
+    latitude: f64,
+    longitude: f64,
+    distance_m: f64,
+    timestamp: u64,
+}
+
+struct GeofenceHandle {
+    latitude: f64,
+    longitude: f64,
+    radius_m: f64,
+    device_filter: Option<String>,
+    app_handle: AppHandle,
+    // 기기별 마지막 안/밖 상태
+    inside: HashMap<String, bool>,
+}
+
+type GeofenceRegistry = Arc<RwLock<HashMap<String, GeofenceHandle>>>;
+
+static GEOFENCE_REGISTRY: std::sync::OnceLock<GeofenceRegistry> = std::sync::OnceLock::new();
+
+fn get_geofence_registry() -> &'static GeofenceRegistry {
+    GEOFENCE_REGISTRY.get_or_init(|| Arc::new(RwLock::new(HashMap::new())))
+}
+
+// 두 좌표 사이 거리 (미터, 하버사인)
+pub fn distance_m(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    let (phi1, phi2) = (lat1.to_radians(), lat2.to_radians());
+    let d_phi = (lat2 - lat1).to_radians();
+    let d_lambda = (lon2 - lon1).to_radians();
+    let a = (d_phi / 2.0).sin().powi(2) + phi1.cos() * phi2.cos() * (d_lambda / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_M * a.sqrt().asin()
+}
+
+// (새 상태, 발생한 이벤트)
+pub fn evaluate(
+    previous: Option<bool>,
+    distance: f64,
+    radius: f64,
+) -> (bool, Option<&'static str>) {
+    let inside = match previous {
+        Some(true) => distance <= radius * EXIT_HYSTERESIS,
+        _ => distance <= radius,
+    };
+    let event = match (previous, inside) {
+        (Some(false), true) => Some("enter"),
+        (Some(true), false) => Some("exit"),
+        _ => None,
+    };
+    (inside, event)
+}
+
+fn valid_coordinates(latitude: f64, longitude: f64) -> bool {
+    (-90.0..=90.0).contains(&latitude) && (-180.0..=180.0).contains(&longitude)
+}
+
+// 받은 좌표를 모든 지오펜스에 적용하고 발생한 이벤트 목록 반환
+pub async fn report_location(report: LocationReport) -> Result<Vec<Value>, String> {
+    if !valid_coordinates(report.latitude, report.longitude) {
+        return Err("INVALID_COORDINATES".to_string());
+    }
+    let device = report
+        .device
+        .as_deref()
+        .map(str::trim)
+        .filter(|d| !d.is_empty())
+        .unwrap_or("phone")
+        .to_string();
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64;
+
+    let mut fired = Vec::new();
+    let mut geofences = get_geofence_registry().write().await;
+    for (node_id, fence) in geofences.iter_mut() {
+        if fence
+            .device_filter
+            .as_deref()
+            .is_some_and(|filter| !filter.eq_ignore_ascii_case(&device))
+        {
+            continue;
+        }
+        if report
+            .accuracy
+            .is_some_and(|accuracy| accuracy > fence.radius_m)
+        {
+            println!(
+                "📍 정확도가 낮은 좌표 무시 ({}m, node {})",
+                report.accuracy.unwrap_or_default(),
+                node_id
+            );
+            continue;
+        }
+
+        let distance = distance_m(
+            fence.latitude,
+            fence.longitude,
+            report.latitude,
+            report.longitude,
+        );
+        let previous = fence.inside.get(&device).copied();
+        let (inside, event) = evaluate(previous, distance, fence.radius_m);
+        fence.inside.insert(device.clone(), inside);
+
+        if let Some(event) = event {
+            let geofence_event = GeofenceEvent {
+                node_id: node_id.clone(),
+                event: event.to_string(),
+                device: device.clone(),
+                latitude: report.latitude,
+                longitude: report.longitude,
+                distance_m: distance.round(),
+                timestamp,
+            };
+            println!(
+                "📍 지오펜스 {}: {} ({}m, node {})",
+                event,
+                device,
+                distance.round(),
+                node_id
+            );
+            if let Err(e) = fence.app_handle.emit("geofence-event", &geofence_event) {
+                eprintln!("❌ Failed to emit geofence event: {}", e);
+            }
+            fired.push(json!(geofence_event));
+        }
+    }
+    Ok(fired)
+}
+
+// 📍 POST /location (원격 제어와 같은 토큰으로 보호)
+pub fn location_route(
+    token: Option<String>,
+) -> impl Filter<Extract = (Box<dyn Reply>,), Error = Rejection> + Clone {
+    let token = token.filter(|t| !t.trim().is_empty()).map(Arc::new);
+    warp::path("location")
+        .and(warp::path::end())
+        .and(warp::post())
+        .and(control_page::authorized(token))
+        .and(warp::body::json())
+        .and_then(|report: LocationReport| async move {
+            let reply: Box<dyn Reply> = match report_location(report).await {
+                Ok(events) => Box::new(warp::reply::json(
+                    &json!({ "status": "ok", "events": events }),
+                )),
+                Err(e) => Box::new(warp::reply::with_status(
+                    warp::reply::json(&json!({ "status": "error", "error": e })),
+                    StatusCode::BAD_REQUEST,
+                )),
+            };
+            Ok::<_, Rejection>(reply)
+        })
+        .recover(control_page::handle_rejection)
+        .unify()
+}
+
+#[tauri::command]
+pub async fn start_geofence_trigger(
+    app_handle: AppHandle,
+    node_id: String,
+    latitude: f64,
+    longitude: f64,
+    radius_m: Option<f64>,
+    device_filter: Option<String>,
+) -> Result<String, String> {
+    if !valid_coordinates(latitude, longitude) {
+        return Err("INVALID_COORDINATES".to_string());
+    }
+    let radius_m = radius_m.unwrap_or(200.0);
+    if radius_m <= 0.0 {
+        return Err("INVALID_RADIUS".to_string());
+    }
+    let device_filter = device_filter
+        .map(|f| f.trim().to_string())
+        .filter(|f| !f.is_empty());
+
+    // 같은 노드의 기존 지오펜스는 교체
+    get_geofence_registry().write().await.insert(
+        node_id.clone(),
+        GeofenceHandle {
+            latitude,
+            longitude,
+            radius_m,
+            device_filter,
+            app_handle,
+            inside: HashMap::new(),
+        },
+    );
+
+    println!(
+        "📍 지오펜스 시작: ({}, {}) 반경 {}m (node {})",
+        latitude, longitude, radius_m, node_id
+    );
+    Ok("Geofence trigger started".to_string())
+}
+
+#[tauri::command]
+pub async fn stop_geofence_trigger(node_id: String) -> Result<String, String> {
+    match get_geofence_registry().write().await.remove(&node_id) {
+        Some(_) => {
+            println!("🛑 지오펜스 중지 (node {})", node_id);
+            Ok("Geofence trigger stopped".to_string())
+        }
+        None => Ok("No geofence trigger running for this node".to_string()),
+    }
+}
+
+#[tauri::command]
+pub async fn get_geofence_status(node_id: String) -> Result<Value, String> {
+    let geofences = get_geofence_registry().read().await;
+    Ok(match geofences.get(&node_id) {
+        Some(fence) => json!({
+            "running": true,
+            "latitude": fence.latitude,
+            "longitude": fence.longitude,
+            "radius_m": fence.radius_m,
+            "device_filter": fence.device_filter,
+            "inside": fence.inside
+        }),
+        None => json!({ "running": false }),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn geofence_trigger_measures_distance() {
+        // 서울시청 ↔ 광화문 (약 1.07km)
+        let distance = distance_m(37.5663, 126.9779, 37.5759, 126.9769);
+        assert!((distance - 1070.0).abs() < 30.0, "distance = {}", distance);
+        assert_eq!(distance_m(10.0, 20.0, 10.0, 20.0), 0.0);
+    }
+
+    #[test]
+    fn geofence_trigger_detects_enter_and_exit_with_hysteresis() {
+        // 첫 좌표는 상태만 기록
+        assert_eq!(evaluate(None, 50.0, 100.0), (true, None));
+        assert_eq!(
+            evaluate(None, 500.0, 100.0),
+            (false, None)
+        );
+
+        assert_eq!(
+            evaluate(Some(false), 90.0, 100.0),
+            (true, Some("enter"))
+        );
+        // 반경을 살짝 넘은 정도로는 나간 것으로 보지 않음
+        assert_eq!(
+            evaluate(Some(true), 105.0, 100.0),
+            (true, None)
+        );
+        assert_eq!(
+            evaluate(Some(true), 120.0, 100.0),
+            (false, Some("exit"))
+        );
+        assert_eq!(
+            evaluate(Some(false), 105.0, 100.0),
+            (false, None)
+        );
+    }
+
+    #[test]
+    fn geofence_trigger_accepts_owntracks_payloads() {
+        let report: LocationReport = serde_json::from_value(
+            json!({ "_type": "location", "lat": 37.5, "lon": 127.0, "acc": 12, "tid": "ph" }),
+        )
+        .unwrap();
+        assert_eq!(report.latitude, 37.5);
+        assert_eq!(report.longitude, 127.0);
+        assert_eq!(report.accuracy, Some(12.0));
+        assert_eq!(report.device.as_deref(), Some("ph"));
+    }
+}
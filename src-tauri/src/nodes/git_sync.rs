@@ -0,0 +1,376 @@
+// src-tauri/src/nodes/git_sync.rs
+use super::{atomic_store, control_page, disk_preflight, os_command};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Emitter};
+
+// 🔀 Git 으로 워크플로우 폴더 동기화 (선택 기능)
+// 워크플로우 폴더(store/workflows 또는 설정한 폴더)를 Git 저장소로 관리해 여러 PC 에서 버전 관리 + 동기화
+// - 저장할 때: 워크플로우 폴더 안에 저장되면 자동 커밋 (+ 원격이 있으면 push)
+// - 앱 시작할 때: 원격에서 pull
+// - 충돌: 병합을 취소해 로컬 파일은 그대로 두고 "git-sync-conflict" 이벤트로 충돌 파일 목록 알림
+// 설정은 store/git_sync_settings.json, 시스템에 설치된 git 을 사용
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GitSyncSettings {
+    pub enabled: bool,
+    pub directory: Option<String>,
+    pub remote: Option<String>,
+    pub branch: String,
+    pub auto_commit: bool,
+    pub pull_on_launch: bool,
+    pub push: bool,
+}
+
+impl Default for GitSyncSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            directory: None,
+            remote: None,
+            branch: "main".to_string(),
+            auto_commit: true,
+            pull_on_launch: true,
+            push: true,
+        }
+    }
+}
+
+impl GitSyncSettings {
+    pub fn workflows_dir(&self) -> PathBuf {
+        self.directory
+            .as_deref()
+            .map(str::trim)
+            .filter(|d| !d.is_empty())
+            .map(PathBuf::from)
+            .unwrap_or_else(control_page::default_workflows_dir)
+    }
+
+    fn remote(&self) -> Option<&str> {
+        self.remote
+            .as_deref()
+            .map(str::trim)
+            .filter(|r| !r.is_empty())
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct SyncReport {
+    pub committed: Option<String>,
+    pub pulled: bool,
+    pub pushed: bool,
+    pub conflicts: Vec<String>,
+}
+
+fn settings_path() -> PathBuf {
+    atomic_store::store_dir().join("git_sync_settings.json")
+}
+
+pub fn load_settings() -> GitSyncSettings {
+    atomic_store::read_json_verified(&settings_path()).unwrap_or_default()
+}
+
+fn git(dir: &Path, args: &[&str]) -> Result<String, String> {
+    let dir = dir.to_string_lossy();
+    let mut full = vec!["-C", dir.as_ref()];
+    full.extend_from_slice(args);
+    os_command::run("git", &full)
+}
+
+// "git status --porcelain" → (상태, 경로)
+pub fn parse_porcelain(status: &str) -> Vec<(String, String)> {
+    status
+        .lines()
+        .filter(|line| line.len() > 3)
+        .map(|line| {
+            let path = line[3..].trim();
+            // 이름 변경은 "old -> new"
+            let path = path.rsplit(" -> ").next().unwrap_or(path);
+            (
+                line[..2].trim().to_string(),
+                path.trim_matches('"').to_string(),
+            )
+        })
+        .collect()
+}
+
+pub fn ensure_repo(dir: &Path, settings: &GitSyncSettings) -> Result<(), String> {
+    std::fs::create_dir_all(dir).map_err(|_| "DIRECTORY_CREATE_ERROR".to_string())?;
+    if !dir.join(".git").exists() {
+        git(dir, &["init"])?;
+        git(dir, &["checkout", "-B", &settings.branch])?;
+        println!("🔀 워크플로우 Git 저장소 생성: {}", dir.display());
+    }
+    if let Some(remote) = settings.remote() {
+        match git(dir, &["remote", "get-url", "origin"]).ok() {
+            Some(current) if current == remote => {}
+            Some(_) => {
+                git(dir, &["remote", "set-url", "origin", remote])?;
+            }
+            None => {
+                git(dir, &["remote", "add", "origin", remote])?;
+            }
+        }
+    }
+    Ok(())
+}
+
+// 사용자 정보가 설정되지 않은 PC 에서도 커밋되도록
+fn identity_args<'a>(dir: &Path) -> Vec<&'a str> {
+    if git(dir, &["config", "user.email"]).is_ok_and(|e| !e.is_empty()) {
+        Vec::new()
+    } else {
+        vec![
+            "-c",
+            "user.name=automation-gui",
+            "-c",
+            "user.email=automation-gui@localhost",
+        ]
+    }
+}
+
+// 바뀐 파일이 있으면 커밋, 커밋 해시 반환
+pub fn commit_all(dir: &Path, message: &str) -> Result<Option<String>, String> {
+    git(dir, &["add", "-A"])?;
+    if git(dir, &["status", "--porcelain"])?.is_empty() {
+        return Ok(None);
+    }
+    let mut args = identity_args(dir);
+    args.extend(["commit", "-q", "-m", message]);
+    git(dir, &args)?;
+    Ok(Some(git(dir, &["rev-parse", "--short", "HEAD"])?))
+}
+
+// 원격 변경을 병합, 충돌이면 병합을 취소하고 충돌 파일 목록 반환
+pub fn pull(dir: &Path, settings: &GitSyncSettings) -> Result<(bool, Vec<String>), String> {
+    if settings.remote().is_none() {
+        return Ok((false, Vec::new()));
+    }
+    // 받아올 크기는 미리 알 수 없으므로 예비 공간만큼은 남아있는지 확인
+    disk_preflight::ensure_free_space(dir, 0)?;
+    git(dir, &["fetch", "-q", "origin"])?;
+    let remote_ref = format!("origin/{}", settings.branch);
+    if git(dir, &["rev-parse", "--verify", "-q", &remote_ref]).is_err() {
+        // 원격에 아직 브랜치가 없음 (첫 push 전)
+        return Ok((false, Vec::new()));
+    }
+    let before = git(dir, &["rev-parse", "HEAD"]).unwrap_or_default();
+    let mut args = identity_args(dir);
+    args.extend([
+        "merge",
+        "--no-edit",
+        "--allow-unrelated-histories",
+        remote_ref.as_str(),
+    ]);
+    if let Err(e) = git(dir, &args) {
+        let conflicts: Vec<String> = git(dir, &["diff", "--name-only", "--diff-filter=U"])
+            .unwrap_or_default()
+            .lines()
+            .map(str::to_string)
+            .collect();
+        let _ = git(dir, &["merge", "--abort"]);
+        if conflicts.is_empty() {
+            return Err(e);
+        }
+        return Ok((false, conflicts));
+    }
+    let after = git(dir, &["rev-parse", "HEAD"]).unwrap_or_default();
+    Ok((before != after, Vec::new()))
+}
+
+pub fn sync(settings: &GitSyncSettings, message: &str) -> Result<SyncReport, String> {
+    let dir = settings.workflows_dir();
+    ensure_repo(&dir, settings)?;
+    let mut report = SyncReport {
+        committed: commit_all(&dir, message)?,
+        ..Default::default()
+    };
+    let (pulled, conflicts) = pull(&dir, settings)?;
+    report.pulled = pulled;
+    report.conflicts = conflicts;
+    if settings.push && settings.remote().is_some() && report.conflicts.is_empty() {
+        git(&dir, &["push", "-q", "-u", "origin", &settings.branch])?;
+        report.pushed = true;
+    }
+    Ok(report)
+}
+
+fn report_result(app_handle: &AppHandle, result: &Result<SyncReport, String>) {
+    match result {
+        Ok(report) if !report.conflicts.is_empty() => {
+            println!("⚠️ Git 동기화 충돌: {:?}", report.conflicts);
+            let _ = app_handle.emit("git-sync-conflict", report);
+        }
+        Ok(report) => println!(
+            "🔀 Git 동기화: 커밋 {:?}, pull {}, push {}",
+            report.committed, report.pulled, report.pushed
+        ),
+        Err(e) => {
+            println!("⚠️ Git 동기화 실패: {}", e);
+            let _ = app_handle.emit("git-sync-error", e);
+        }
+    }
+}
+
+// 워크플로우 저장 후 호출: 동기화 폴더 안의 파일이면 백그라운드로 커밋
+pub fn on_workflow_saved(app_handle: &AppHandle, saved_path: &Path) {
+    let settings = load_settings();
+    if !settings.enabled
+        || !settings.auto_commit
+        || !saved_path.starts_with(settings.workflows_dir())
+    {
+        return;
+    }
+    let app_handle = app_handle.clone();
+    let name = saved_path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    std::thread::spawn(move || {
+        let result = sync(&settings, &format!("Update {}", name));
+        report_result(&app_handle, &result);
+    });
+}
+
+// 앱 시작 시 원격 변경 가져오기
+pub fn pull_on_launch(app_handle: &AppHandle) {
+    let settings = load_settings();
+    if !settings.enabled || !settings.pull_on_launch || settings.remote().is_none() {
+        return;
+    }
+    let app_handle = app_handle.clone();
+    std::thread::spawn(move || {
+        let result = sync(&settings, "Sync on launch");
+        report_result(&app_handle, &result);
+    });
+}
+
+#[tauri::command]
+pub fn get_git_sync_settings() -> Result<GitSyncSettings, String> {
+    Ok(load_settings())
+}
+
+#[tauri::command]
+pub fn set_git_sync_settings(settings: GitSyncSettings) -> Result<String, String> {
+    if settings.branch.trim().is_empty() || settings.branch.starts_with('-') {
+        return Err(format!("INVALID_BRANCH: {}", settings.branch));
+    }
+    if settings.enabled {
+        os_command::run("git", &["--version"]).map_err(|_| "GIT_NOT_INSTALLED".to_string())?;
+        ensure_repo(&settings.workflows_dir(), &settings)?;
+    }
+    atomic_store::write_json_atomic(&settings_path(), &settings)?;
+    Ok("SUCCESS".to_string())
+}
+
+#[tauri::command]
+pub async fn git_sync_now(
+    app_handle: AppHandle,
+    message: Option<String>,
+) -> Result<SyncReport, String> {
+    let settings = load_settings();
+    if !settings.enabled {
+        return Err("GIT_SYNC_DISABLED".to_string());
+    }
+    let message = message.unwrap_or_else(|| "Manual sync".to_string());
+    let result = tokio::task::spawn_blocking(move || sync(&settings, &message))
+        .await
+        .map_err(|e| e.to_string())?;
+    report_result(&app_handle, &result);
+    result
+}
+
+#[tauri::command]
+pub fn git_sync_status() -> Result<String, String> {
+    let settings = load_settings();
+    let dir = settings.workflows_dir();
+    if !dir.join(".git").exists() {
+        return Ok(json!({ "enabled": settings.enabled, "initialized": false }).to_string());
+    }
+    let changes: Vec<Value> = parse_porcelain(&git(&dir, &["status", "--porcelain"])?)
+        .into_iter()
+        .map(|(status, path)| json!({ "status": status, "path": path }))
+        .collect();
+    let last_commit = git(&dir, &["log", "-1", "--format=%h %s (%cr)"]).ok();
+    Ok(json!({
+        "enabled": settings.enabled,
+        "initialized": true,
+        "directory": dir.to_string_lossy(),
+        "branch": git(&dir, &["rev-parse", "--abbrev-ref", "HEAD"]).ok(),
+        "changes": changes,
+        "last_commit": last_commit
+    })
+    .to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::{path_str, temp_dir};
+    use std::fs;
+
+    #[test]
+    fn git_sync_parses_porcelain_status() {
+        assert_eq!(
+            parse_porcelain(
+                " M a.flow.json\nR  old.json -> new.json\n?? \"공백 있는.json\"\n"
+            ),
+            vec![
+                ("M".to_string(), "a.flow.json".to_string()),
+                ("R".to_string(), "new.json".to_string()),
+                ("??".to_string(), "공백 있는.json".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn git_sync_commits_and_reports_conflicts() {
+        if std::process::Command::new("git")
+            .arg("--version")
+            .output()
+            .is_err()
+        {
+            return;
+        }
+        let dir = temp_dir();
+        let remote = dir.path().join("remote.git");
+        std::process::Command::new("git")
+            .args(["init", "-q", "--bare", &path_str(&remote)])
+            .status()
+            .unwrap();
+        let settings = |name: &str| GitSyncSettings {
+            enabled: true,
+            directory: Some(path_str(&dir.path().join(name))),
+            remote: Some(path_str(&remote)),
+            ..Default::default()
+        };
+        let (a, b) = (settings("a"), settings("b"));
+
+        fs::create_dir_all(a.workflows_dir()).unwrap();
+        fs::write(a.workflows_dir().join("x.flow.json"), "{\"v\":1}").unwrap();
+        let first = sync(&a, "first").unwrap();
+        assert!(first.committed.is_some() && first.pushed);
+
+        let cloned = sync(&b, "init").unwrap();
+        assert!(cloned.pulled);
+        assert_eq!(
+            fs::read_to_string(b.workflows_dir().join("x.flow.json")).unwrap(),
+            "{\"v\":1}"
+        );
+        assert_eq!(sync(&b, "noop").unwrap().committed, None);
+
+        fs::write(a.workflows_dir().join("x.flow.json"), "{\"v\":2}").unwrap();
+        sync(&a, "a edit").unwrap();
+        fs::write(b.workflows_dir().join("x.flow.json"), "{\"v\":3}").unwrap();
+        let conflicted = sync(&b, "b edit").unwrap();
+        assert_eq!(conflicted.conflicts, vec!["x.flow.json".to_string()]);
+        assert!(!conflicted.pushed);
+        // 병합을 취소해 로컬 내용 유지
+        assert_eq!(
+            fs::read_to_string(b.workflows_dir().join("x.flow.json")).unwrap(),
+            "{\"v\":3}"
+        );
+    }
+}
@@ -0,0 +1,208 @@
+// src-tauri/src/nodes/http_client.rs
+use super::atomic_store;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+// 🌐 공용 HTTP 클라이언트
+// 노드마다 호출할 때 reqwest::Client::new() 를 만들면 매번 TCP + TLS 연결을 새로 맺으므로
+// AI / HTTP 노드가 같은 클라이언트(연결 풀, keep-alive)를 나눠 씀 - 대화가 많은 워크플로우의 지연 감소
+// - client():        요청 전체 시간 제한 포함 (API 호출)
+// - stream_client(): 연결 시간 제한만 (SSE 처럼 오래 열려 있는 응답)
+// 설정은 store/http_client.json, prewarm 이 켜져 있으면 앱 시작 시 warm_urls 로 미리 연결해 둠
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct HttpClientSettings {
+    pub connect_timeout_secs: u64,
+    pub request_timeout_secs: u64,
+    pub pool_idle_timeout_secs: u64,
+    pub pool_max_idle_per_host: usize,
+    pub tcp_keepalive_secs: u64,
+    pub prewarm: bool,
+    pub warm_urls: Vec<String>,
+}
+
+impl Default for HttpClientSettings {
+    fn default() -> Self {
+        Self {
+            connect_timeout_secs: 10,
+            request_timeout_secs: 180,
+            pool_idle_timeout_secs: 90,
+            pool_max_idle_per_host: 8,
+            tcp_keepalive_secs: 60,
+            prewarm: true,
+            warm_urls: vec!["https://api.anthropic.com".to_string()],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WarmResult {
+    pub url: String,
+    pub ok: bool,
+    pub elapsed_ms: u64,
+    pub error: Option<String>,
+}
+
+#[derive(Clone)]
+struct Clients {
+    normal: reqwest::Client,
+    stream: reqwest::Client,
+}
+
+static CLIENTS: std::sync::OnceLock<Mutex<Option<Clients>>> = std::sync::OnceLock::new();
+
+fn settings_path() -> PathBuf {
+    atomic_store::store_dir().join("http_client.json")
+}
+
+pub fn load_settings() -> HttpClientSettings {
+    atomic_store::read_json_verified(&settings_path()).unwrap_or_default()
+}
+
+pub fn validate_settings(settings: &HttpClientSettings) -> Result<(), String> {
+    if settings.connect_timeout_secs == 0 || settings.request_timeout_secs == 0 {
+        return Err("INVALID_TIMEOUT: 0초는 사용할 수 없음".to_string());
+    }
+    if settings.connect_timeout_secs > settings.request_timeout_secs {
+        return Err("INVALID_TIMEOUT: 연결 제한이 요청 제한보다 김".to_string());
+    }
+    if let Some(url) = settings
+        .warm_urls
+        .iter()
+        .find(|url| !url.starts_with("https://"))
+    {
+        return Err(format!("INVALID_WARM_URL: {}", url));
+    }
+    Ok(())
+}
+
+fn builder(settings: &HttpClientSettings) -> reqwest::ClientBuilder {
+    reqwest::Client::builder()
+        .connect_timeout(Duration::from_secs(settings.connect_timeout_secs))
+        .pool_idle_timeout(Duration::from_secs(settings.pool_idle_timeout_secs))
+        .pool_max_idle_per_host(settings.pool_max_idle_per_host)
+        .tcp_keepalive(Duration::from_secs(settings.tcp_keepalive_secs))
+}
+
+fn build(settings: &HttpClientSettings) -> Result<Clients, String> {
+    let normal = builder(settings)
+        .timeout(Duration::from_secs(settings.request_timeout_secs))
+        .build()
+        .map_err(|e| format!("HTTP 클라이언트 생성 실패: {}", e))?;
+    let stream = builder(settings)
+        .build()
+        .map_err(|e| format!("HTTP 클라이언트 생성 실패: {}", e))?;
+    Ok(Clients { normal, stream })
+}
+
+fn current() -> Clients {
+    let mut slot = match CLIENTS.get_or_init(|| Mutex::new(None)).lock() {
+        Ok(slot) => slot,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    if let Some(clients) = slot.as_ref() {
+        return clients.clone();
+    }
+    // 저장된 설정이 잘못됐으면 기본값으로
+    let clients = build(&load_settings())
+        .or_else(|_| build(&HttpClientSettings::default()))
+        .unwrap_or_else(|_| Clients {
+            normal: reqwest::Client::new(),
+            stream: reqwest::Client::new(),
+        });
+    *slot = Some(clients.clone());
+    clients
+}
+
+// 일반 요청용 (clone 은 같은 연결 풀을 공유)
+pub fn client() -> reqwest::Client {
+    current().normal
+}
+
+// 오래 열려 있는 스트리밍 응답용 (요청 전체 시간 제한 없음)
+pub fn stream_client() -> reqwest::Client {
+    current().stream
+}
+
+// warm_urls 에 HEAD 요청을 보내 연결을 풀에 남겨 둠 (응답 상태는 상관없음)
+pub async fn warm_up(urls: &[String]) -> Vec<WarmResult> {
+    let client = client();
+    let mut results = Vec::with_capacity(urls.len());
+    for url in urls {
+        let started = Instant::now();
+        let outcome = client
+            .head(url)
+            .timeout(Duration::from_secs(10))
+            .send()
+            .await;
+        results.push(WarmResult {
+            url: url.clone(),
+            ok: outcome.is_ok(),
+            elapsed_ms: started.elapsed().as_millis() as u64,
+            error: outcome.err().map(|e| e.to_string()),
+        });
+    }
+    results
+}
+
+pub fn warm_on_launch() {
+    let settings = load_settings();
+    if !settings.prewarm || settings.warm_urls.is_empty() {
+        return;
+    }
+    tauri::async_runtime::spawn(async move {
+        let results = warm_up(&settings.warm_urls).await;
+        let ready = results.iter().filter(|r| r.ok).count();
+        println!("🌐 HTTP 연결 예열: {}/{}", ready, results.len());
+    });
+}
+
+#[tauri::command]
+pub fn get_http_client_settings() -> Result<HttpClientSettings, String> {
+    Ok(load_settings())
+}
+
+// 저장 후 클라이언트 교체 (진행 중인 요청은 이전 클라이언트로 끝까지)
+#[tauri::command]
+pub fn set_http_client_settings(settings: HttpClientSettings) -> Result<String, String> {
+    validate_settings(&settings)?;
+    let clients = build(&settings)?;
+    atomic_store::write_json_atomic(&settings_path(), &settings)?;
+    *CLIENTS
+        .get_or_init(|| Mutex::new(None))
+        .lock()
+        .map_err(|e| e.to_string())? = Some(clients);
+    println!("🌐 HTTP 클라이언트 설정 변경");
+    Ok("SUCCESS".to_string())
+}
+
+#[tauri::command]
+pub async fn warm_http_connections() -> Result<Vec<WarmResult>, String> {
+    Ok(warm_up(&load_settings().warm_urls).await)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn http_client_settings_default_and_validate() {
+        let settings: HttpClientSettings =
+            serde_json::from_value(json!({ "request_timeout_secs": 30 })).unwrap();
+        assert_eq!(settings.request_timeout_secs, 30);
+        assert_eq!(settings.connect_timeout_secs, 10);
+        assert!(settings.prewarm);
+        assert!(validate_settings(&settings).is_ok());
+
+        let mut bad = settings.clone();
+        bad.connect_timeout_secs = 60;
+        assert!(validate_settings(&bad).unwrap_err().starts_with("INVALID_TIMEOUT"));
+        let mut bad = settings;
+        bad.warm_urls = vec!["http://api.example.com".to_string()];
+        assert!(validate_settings(&bad).unwrap_err().starts_with("INVALID_WARM_URL"));
+    }
+}
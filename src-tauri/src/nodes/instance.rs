@@ -0,0 +1,229 @@
+// src-tauri/src/nodes/instance.rs
+use super::os_schedule::{self, RUN_WORKFLOW_ARG, SCHEDULE_TASK_ARG};
+use serde::Serialize;
+use std::path::Path;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager};
+
+// 🪟 단일 인스턴스 + 실행 요청 전달
+// 앱이 이미 실행 중일 때 다시 실행되거나 trigger:// 링크가 열리면 새 인스턴스를 띄우지 않고
+// (서버/감시자 중복 방지) 기존 인스턴스에 "instance-request" 이벤트로 요청을 넘김
+// - automation-gui --run-workflow <경로>
+// - trigger://run?workflow=<경로>  (그 외 trigger:// 주소는 deep_link 로 그대로 전달)
+// OS 예약 실행(--schedule-task)은 전달 전에 실행 조건(주말/공휴일)을 확인
+// 링크로 온 실행(url 이 있는 요청)은 아무 웹 페이지에서나 열 수 있으므로 프론트엔드가 확인을 받은 뒤 실행
+
+pub const DEEP_LINK_SCHEME: &str = "trigger";
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct InstanceRequest {
+    // "run_workflow" | "deep_link" | "focus"
+    pub kind: String,
+    pub workflow_path: Option<String>,
+    pub url: Option<String>,
+    // OS 예약 작업 이름 (예약 실행일 때만)
+    pub schedule_task: Option<String>,
+}
+
+static INITIAL_REQUEST: std::sync::OnceLock<Mutex<Option<InstanceRequest>>> =
+    std::sync::OnceLock::new();
+
+fn resolve_path(path: &str, cwd: &Path) -> String {
+    let path = Path::new(path);
+    if path.is_absolute() {
+        path.to_string_lossy().to_string()
+    } else {
+        cwd.join(path).to_string_lossy().to_string()
+    }
+}
+
+fn parse_deep_link(url: &str, cwd: &Path) -> InstanceRequest {
+    let workflow = url::Url::parse(url).ok().and_then(|parsed| {
+        let action = parsed.host_str().unwrap_or("").to_string();
+        (action == "run")
+            .then(|| {
+                parsed
+                    .query_pairs()
+                    .find(|(key, _)| key == "workflow")
+                    .map(|(_, value)| value.to_string())
+            })
+            .flatten()
+    });
+
+    InstanceRequest {
+        kind: if workflow.is_some() {
+            "run_workflow"
+        } else {
+            "deep_link"
+        }
+        .to_string(),
+        workflow_path: workflow.map(|w| resolve_path(&w, cwd)),
+        url: Some(url.to_string()),
+        schedule_task: None,
+    }
+}
+
+// 실행 인자 해석 (첫 번째 인자는 실행 파일 경로)
+pub fn parse_request(args: &[String], cwd: &Path) -> InstanceRequest {
+    let prefix = format!("{}://", DEEP_LINK_SCHEME);
+    let schedule_task = args
+        .iter()
+        .position(|arg| arg == SCHEDULE_TASK_ARG)
+        .and_then(|index| args.get(index + 1))
+        .cloned();
+    for (index, arg) in args.iter().enumerate().skip(1) {
+        if arg == RUN_WORKFLOW_ARG {
+            if let Some(path) = args.get(index + 1) {
+                return InstanceRequest {
+                    kind: "run_workflow".to_string(),
+                    workflow_path: Some(resolve_path(path, cwd)),
+                    url: None,
+                    schedule_task,
+                };
+            }
+        }
+        if arg.to_lowercase().starts_with(&prefix) {
+            return parse_deep_link(arg, cwd);
+        }
+    }
+    InstanceRequest {
+        kind: "focus".to_string(),
+        workflow_path: None,
+        url: None,
+        schedule_task: None,
+    }
+}
+
+fn initial_request() -> &'static Mutex<Option<InstanceRequest>> {
+    INITIAL_REQUEST.get_or_init(|| {
+        let args: Vec<String> = std::env::args().collect();
+        let cwd = std::env::current_dir().unwrap_or_default();
+        let request = parse_request(&args, &cwd);
+        Mutex::new((request.kind != "focus").then_some(request))
+    })
+}
+
+// 앱을 시작시킨 요청 (한 번만 꺼낼 수 있음)
+pub fn take_initial_request() -> Option<InstanceRequest> {
+    initial_request()
+        .lock()
+        .ok()
+        .and_then(|mut request| request.take())
+}
+
+fn focus_main_window(app: &AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.unminimize();
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+}
+
+fn forward(app: &AppHandle, request: InstanceRequest) {
+    println!("🪟 실행 요청 전달: {:?}", request);
+    focus_main_window(app);
+    if let Err(e) = app.emit("instance-request", &request) {
+        println!("⚠️ 실행 요청 전달 실패: {}", e);
+    }
+}
+
+// 원격 제어 페이지 등 앱 밖에서 온 실행 요청 (창을 앞으로 가져오지 않음)
+pub fn request_workflow_run(app: &AppHandle, workflow_path: &Path) -> Result<(), String> {
+    let request = InstanceRequest {
+        kind: "run_workflow".to_string(),
+        workflow_path: Some(workflow_path.to_string_lossy().to_string()),
+        url: None,
+        schedule_task: None,
+    };
+    println!("🪟 실행 요청 전달: {:?}", request);
+    app.emit("instance-request", &request)
+        .map_err(|e| format!("실행 요청 전달 실패: {}", e))
+}
+
+// 예약 실행인데 조건에 맞지 않으면 None (실행하지 않음)
+async fn check_schedule(request: InstanceRequest) -> Option<InstanceRequest> {
+    let Some(task_name) = request.schedule_task.as_deref() else {
+        return Some(request);
+    };
+    match os_schedule::skip_scheduled_run(task_name).await {
+        Some(reason) => {
+            println!("⏭️ 예약 실행 건너뜀: {} - {}", task_name, reason);
+            None
+        }
+        None => Some(request),
+    }
+}
+
+// single-instance 플러그인 콜백: 두 번째 실행의 인자를 기존 인스턴스로 전달
+pub fn handle_second_instance(app: &AppHandle, argv: Vec<String>, cwd: String) {
+    let request = parse_request(&argv, Path::new(&cwd));
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        if let Some(request) = check_schedule(request).await {
+            forward(&app, request);
+        }
+    });
+}
+
+// 실행 중에 열린 trigger:// 링크 (macOS 는 인자가 아닌 이벤트로 전달됨)
+pub fn handle_open_urls(app: &AppHandle, urls: &[String]) {
+    let cwd = std::env::current_dir().unwrap_or_default();
+    for url in urls {
+        forward(app, parse_deep_link(url, &cwd));
+    }
+}
+
+#[tauri::command]
+pub async fn take_launch_request() -> Option<InstanceRequest> {
+    check_schedule(take_initial_request()?).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn instance_parses_forwarded_launch_arguments() {
+        use std::path::Path;
+        let args = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let cwd = Path::new("/home/user");
+
+        let run = parse_request(&args(&["app", "--run-workflow", "flows/a.json"]), cwd);
+        assert_eq!(run.kind, "run_workflow");
+        assert_eq!(
+            run.workflow_path.as_deref(),
+            Some(Path::new("/home/user/flows/a.json").to_str().unwrap())
+        );
+        assert_eq!(run.schedule_task, None);
+
+        let scheduled = parse_request(
+            &args(&[
+                "app",
+                "--run-workflow",
+                "/tmp/a.json",
+                "--schedule-task",
+                "nightly",
+            ]),
+            cwd,
+        );
+        assert_eq!(scheduled.kind, "run_workflow");
+        assert_eq!(scheduled.schedule_task.as_deref(), Some("nightly"));
+
+        let link = parse_request(
+            &args(&["app", "trigger://run?workflow=%2Ftmp%2Fb.json"]),
+            cwd,
+        );
+        assert_eq!(link.kind, "run_workflow");
+        assert_eq!(link.workflow_path.as_deref(), Some("/tmp/b.json"));
+        assert_eq!(
+            link.url.as_deref(),
+            Some("trigger://run?workflow=%2Ftmp%2Fb.json")
+        );
+
+        let other = parse_request(&args(&["app", "trigger://open/settings"]), cwd);
+        assert_eq!(other.kind, "deep_link");
+        assert_eq!(other.workflow_path, None);
+
+        assert_eq!(parse_request(&args(&["app"]), cwd).kind, "focus");
+    }
+}
@@ -0,0 +1,170 @@
+// src-tauri/src/nodes/json_schema.rs
+use serde_json::Value;
+
+// 노드 출력 검증용 최소 JSON Schema 검사기
+// 지원 키워드: type, properties, required, additionalProperties(false), items,
+//             enum, const, minimum, maximum, minLength, maxLength, minItems, maxItems
+// 반환값: "경로: 문제" 형태의 오류 목록 (비어 있으면 통과)
+pub fn validate(value: &Value, schema: &Value) -> Vec<String> {
+    let mut errors = Vec::new();
+    validate_at(value, schema, "$", &mut errors);
+    errors
+}
+
+fn type_matches(value: &Value, expected: &str) -> bool {
+    match expected {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "boolean" => value.is_boolean(),
+        "null" => value.is_null(),
+        _ => true,
+    }
+}
+
+fn validate_at(value: &Value, schema: &Value, path: &str, errors: &mut Vec<String>) {
+    let schema = match schema.as_object() {
+        Some(schema) => schema,
+        None => return,
+    };
+
+    // type (문자열 또는 문자열 배열)
+    if let Some(expected) = schema.get("type") {
+        let allowed: Vec<&str> = match expected {
+            Value::String(t) => vec![t.as_str()],
+            Value::Array(types) => types.iter().filter_map(|t| t.as_str()).collect(),
+            _ => Vec::new(),
+        };
+        if !allowed.is_empty() && !allowed.iter().any(|t| type_matches(value, t)) {
+            errors.push(format!("{}: expected type {}", path, allowed.join("|")));
+            return;
+        }
+    }
+
+    if let Some(Value::Array(options)) = schema.get("enum") {
+        if !options.contains(value) {
+            errors.push(format!(
+                "{}: value not in enum {}",
+                path,
+                Value::Array(options.clone())
+            ));
+        }
+    }
+
+    if let Some(expected) = schema.get("const") {
+        if expected != value {
+            errors.push(format!("{}: expected const {}", path, expected));
+        }
+    }
+
+    if let Some(number) = value.as_f64() {
+        if let Some(minimum) = schema.get("minimum").and_then(|m| m.as_f64()) {
+            if number < minimum {
+                errors.push(format!("{}: {} < minimum {}", path, number, minimum));
+            }
+        }
+        if let Some(maximum) = schema.get("maximum").and_then(|m| m.as_f64()) {
+            if number > maximum {
+                errors.push(format!("{}: {} > maximum {}", path, number, maximum));
+            }
+        }
+    }
+
+    if let Some(text) = value.as_str() {
+        let length = text.chars().count() as u64;
+        if let Some(min) = schema.get("minLength").and_then(|m| m.as_u64()) {
+            if length < min {
+                errors.push(format!("{}: shorter than minLength {}", path, min));
+            }
+        }
+        if let Some(max) = schema.get("maxLength").and_then(|m| m.as_u64()) {
+            if length > max {
+                errors.push(format!("{}: longer than maxLength {}", path, max));
+            }
+        }
+    }
+
+    if let Some(items) = value.as_array() {
+        if let Some(min) = schema.get("minItems").and_then(|m| m.as_u64()) {
+            if (items.len() as u64) < min {
+                errors.push(format!("{}: fewer than minItems {}", path, min));
+            }
+        }
+        if let Some(max) = schema.get("maxItems").and_then(|m| m.as_u64()) {
+            if (items.len() as u64) > max {
+                errors.push(format!("{}: more than maxItems {}", path, max));
+            }
+        }
+        if let Some(item_schema) = schema.get("items") {
+            for (index, item) in items.iter().enumerate() {
+                validate_at(item, item_schema, &format!("{}[{}]", path, index), errors);
+            }
+        }
+    }
+
+    if let Some(object) = value.as_object() {
+        if let Some(Value::Array(required)) = schema.get("required") {
+            for key in required.iter().filter_map(|k| k.as_str()) {
+                if !object.contains_key(key) {
+                    errors.push(format!("{}: missing required property '{}'", path, key));
+                }
+            }
+        }
+
+        let properties = schema.get("properties").and_then(|p| p.as_object());
+        if let Some(properties) = properties {
+            for (key, property_schema) in properties {
+                if let Some(property_value) = object.get(key) {
+                    validate_at(
+                        property_value,
+                        property_schema,
+                        &format!("{}.{}", path, key),
+                        errors,
+                    );
+                }
+            }
+        }
+
+        if schema.get("additionalProperties") == Some(&Value::Bool(false)) {
+            for key in object.keys() {
+                if !properties.is_some_and(|p| p.contains_key(key)) {
+                    errors.push(format!("{}: unexpected property '{}'", path, key));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn json_schema_reports_nested_violations() {
+        let schema = json!({
+            "type": "object",
+            "required": ["name", "items"],
+            "additionalProperties": false,
+            "properties": {
+                "name": { "type": "string", "minLength": 1 },
+                "items": { "type": "array", "items": { "type": "integer", "minimum": 0 } }
+            }
+        });
+
+        assert!(validate(&json!({ "name": "a", "items": [1, 2] }), &schema).is_empty());
+
+        let errors = validate(&json!({ "items": [1, -1, "x"], "extra": true }), &schema);
+        assert_eq!(
+            errors,
+            vec![
+                "$: missing required property 'name'",
+                "$.items[1]: -1 < minimum 0",
+                "$.items[2]: expected type integer",
+                "$: unexpected property 'extra'",
+            ]
+        );
+    }
+}
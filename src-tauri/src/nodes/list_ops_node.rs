@@ -0,0 +1,252 @@
+// src-tauri/src/nodes/list_ops_node.rs
+use super::template::value_to_text;
+use regex::Regex;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::cmp::Ordering;
+
+// 📋 리스트 처리 노드
+// 입력: JSON 배열 또는 줄 단위 텍스트 → 연산을 순서대로 적용 → 같은 형식으로 출력
+// 연산: sort(natural/numeric/text) | unique | filter(regex) | take | skip | shuffle | reverse
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum ListOperation {
+    Sort {
+        #[serde(default)]
+        mode: Option<String>,
+        #[serde(default)]
+        descending: bool,
+    },
+    Unique,
+    Filter {
+        pattern: String,
+        #[serde(default)]
+        invert: bool,
+    },
+    Take {
+        count: usize,
+    },
+    Skip {
+        count: usize,
+    },
+    Shuffle {
+        #[serde(default)]
+        seed: Option<u64>,
+    },
+    Reverse,
+}
+
+// 숫자 부분은 숫자로 비교 ("file2" < "file10")
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let mut a_chars = a.chars().peekable();
+    let mut b_chars = b.chars().peekable();
+
+    loop {
+        match (a_chars.peek().copied(), b_chars.peek().copied()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let take_number = |chars: &mut std::iter::Peekable<std::str::Chars>| {
+                    let mut digits = String::new();
+                    while let Some(c) = chars.peek().copied().filter(char::is_ascii_digit) {
+                        digits.push(c);
+                        chars.next();
+                    }
+                    digits
+                };
+                let x_num = take_number(&mut a_chars);
+                let y_num = take_number(&mut b_chars);
+                let x_trimmed = x_num.trim_start_matches('0');
+                let y_trimmed = y_num.trim_start_matches('0');
+
+                let ordering = x_trimmed
+                    .len()
+                    .cmp(&y_trimmed.len())
+                    .then_with(|| x_trimmed.cmp(y_trimmed));
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            (Some(x), Some(y)) => {
+                let ordering = x
+                    .to_lowercase()
+                    .cmp(y.to_lowercase())
+                    .then_with(|| x.cmp(&y));
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+                a_chars.next();
+                b_chars.next();
+            }
+        }
+    }
+}
+
+fn numeric_key(value: &Value) -> f64 {
+    match value {
+        Value::Number(n) => n.as_f64().unwrap_or(f64::NAN),
+        other => value_to_text(other).trim().parse().unwrap_or(f64::NAN),
+    }
+}
+
+// 숫자가 아닌 항목(NaN)은 정렬 방향과 관계없이 뒤로
+fn numeric_cmp(a: &Value, b: &Value, descending: bool) -> Ordering {
+    let (x, y) = (numeric_key(a), numeric_key(b));
+    match (x.is_nan(), y.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        _ if descending => y.partial_cmp(&x).unwrap_or(Ordering::Equal),
+        _ => x.partial_cmp(&y).unwrap_or(Ordering::Equal),
+    }
+}
+
+// 외부 crate 없이 쓰는 xorshift (seed 를 주면 매번 같은 순서)
+fn shuffle(items: &mut [Value], seed: u64) {
+    let mut state = seed.max(1);
+    for i in (1..items.len()).rev() {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        let j = (state % (i as u64 + 1)) as usize;
+        items.swap(i, j);
+    }
+}
+
+pub fn apply_operations(
+    mut items: Vec<Value>,
+    operations: &[ListOperation],
+) -> Result<Vec<Value>, String> {
+    for operation in operations {
+        match operation {
+            ListOperation::Sort { mode, descending } => {
+                match mode.as_deref().unwrap_or("natural") {
+                    "natural" => {
+                        items.sort_by(|a, b| natural_cmp(&value_to_text(a), &value_to_text(b)))
+                    }
+                    "numeric" => {
+                        items.sort_by(|a, b| numeric_cmp(a, b, *descending));
+                        continue;
+                    }
+                    "text" => items.sort_by_key(value_to_text),
+                    other => return Err(format!("UNSUPPORTED_SORT_MODE: {}", other)),
+                }
+                if *descending {
+                    items.reverse();
+                }
+            }
+            ListOperation::Unique => {
+                let mut seen = std::collections::HashSet::new();
+                items.retain(|item| seen.insert(item.to_string()));
+            }
+            ListOperation::Filter { pattern, invert } => {
+                let regex = Regex::new(pattern).map_err(|e| format!("INVALID_REGEX: {}", e))?;
+                items.retain(|item| regex.is_match(&value_to_text(item)) != *invert);
+            }
+            ListOperation::Take { count } => items.truncate(*count),
+            ListOperation::Skip { count } => {
+                items.drain(..(*count).min(items.len()));
+            }
+            ListOperation::Shuffle { seed } => {
+                let seed = seed.unwrap_or_else(|| {
+                    std::time::SystemTime::now()
+                        .duration_since(std::time::UNIX_EPOCH)
+                        .unwrap_or_default()
+                        .as_nanos() as u64
+                });
+                shuffle(&mut items, seed);
+            }
+            ListOperation::Reverse => items.reverse(),
+        }
+    }
+    Ok(items)
+}
+
+#[tauri::command]
+pub fn list_ops_node(input: String, operations: Vec<Value>) -> Result<String, String> {
+    let operations: Vec<ListOperation> = operations
+        .into_iter()
+        .map(|op| {
+            serde_json::from_value(op.clone())
+                .map_err(|e| format!("INVALID_OPERATION: {} ({})", op, e))
+        })
+        .collect::<Result<_, _>>()?;
+
+    // JSON 배열이면 배열로, 아니면 줄 단위 텍스트로 처리
+    let (items, is_json) = match serde_json::from_str::<Value>(input.trim()) {
+        Ok(Value::Array(items)) => (items, true),
+        _ => (
+            input
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(|line| Value::String(line.to_string()))
+                .collect(),
+            false,
+        ),
+    };
+
+    let input_count = items.len();
+    let items = apply_operations(items, &operations)?;
+    println!(
+        "📋 List Ops Node: {} → {}개 ({}개 연산)",
+        input_count,
+        items.len(),
+        operations.len()
+    );
+
+    let output = if is_json {
+        Value::Array(items.clone()).to_string()
+    } else {
+        items
+            .iter()
+            .map(value_to_text)
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    Ok(json!({
+        "output": output,
+        "items": items,
+        "count": items.len()
+    })
+    .to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::parse_output;
+    use serde_json::json;
+
+    #[test]
+    fn list_ops_applies_operations_in_order_on_text_lines() {
+        let output = list_ops_node(
+            "file10.txt\nfile2.txt\nnotes.md\nfile2.txt\nfile1.txt\n".to_string(),
+            vec![
+                json!({ "op": "filter", "pattern": "\\.txt$" }),
+                json!({ "op": "unique" }),
+                json!({ "op": "sort", "mode": "natural" }),
+                json!({ "op": "skip", "count": 1 }),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(parse_output(&output)["output"], "file2.txt\nfile10.txt");
+    }
+
+    #[test]
+    fn list_ops_sorts_json_arrays_numerically() {
+        let output = list_ops_node(
+            "[\"10\", 2, \"x\", 33, 1.5]".to_string(),
+            vec![
+                json!({ "op": "sort", "mode": "numeric", "descending": true }),
+                json!({ "op": "take", "count": 3 }),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(parse_output(&output)["items"], json!([33, "10", 2]));
+    }
+}
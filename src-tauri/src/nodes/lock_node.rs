@@ -0,0 +1,282 @@
+// src-tauri/src/nodes/lock_node.rs
+use serde::Serialize;
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::{Mutex, OwnedSemaphorePermit, Semaphore};
+
+// 🔒 이름 있는 뮤텍스 / 세마포어
+// 같은 자원(폴더, 장치)을 건드리는 워크플로우끼리 순서대로 접근하도록
+// acquire_lock 으로 lease_id 를 받고, 작업이 끝나면 release_lock
+// permits = 1 이면 뮤텍스, 2 이상이면 동시에 그 수만큼 허용
+// 워크플로우가 중간에 죽어도 영원히 잠기지 않도록 ttl_secs 가 지나면 자동 해제
+
+const DEFAULT_TIMEOUT_SECS: u64 = 60;
+const DEFAULT_TTL_SECS: u64 = 600;
+const MAX_PERMITS: u32 = 64;
+
+struct NamedLock {
+    permits: u32,
+    semaphore: Arc<Semaphore>,
+}
+
+struct Lease {
+    name: String,
+    holder: Option<String>,
+    acquired_at: chrono::DateTime<chrono::Local>,
+    expires_at: chrono::DateTime<chrono::Local>,
+    _permit: OwnedSemaphorePermit,
+    expiry_abort: tokio::task::AbortHandle,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct LeaseInfo {
+    pub lease_id: String,
+    pub name: String,
+    pub holder: Option<String>,
+    pub acquired_at: String,
+    pub expires_at: String,
+}
+
+static LOCKS: std::sync::OnceLock<Mutex<HashMap<String, NamedLock>>> = std::sync::OnceLock::new();
+static LEASES: std::sync::OnceLock<Mutex<HashMap<String, Lease>>> = std::sync::OnceLock::new();
+static LEASE_COUNTER: AtomicU64 = AtomicU64::new(1);
+
+fn get_locks() -> &'static Mutex<HashMap<String, NamedLock>> {
+    LOCKS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn get_leases() -> &'static Mutex<HashMap<String, Lease>> {
+    LEASES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+async fn semaphore_for(name: &str, permits: u32) -> Result<Arc<Semaphore>, String> {
+    let mut locks = get_locks().lock().await;
+    if let Some(existing) = locks.get(name) {
+        if existing.permits == permits {
+            return Ok(existing.semaphore.clone());
+        }
+        // 아무도 잡고 있지 않을 때만 허용 수를 바꿈
+        if existing.semaphore.available_permits() as u32 != existing.permits {
+            return Err(format!(
+                "LOCK_PERMITS_MISMATCH: {} (사용 중인 허용 수 {})",
+                name, existing.permits
+            ));
+        }
+    }
+    let semaphore = Arc::new(Semaphore::new(permits as usize));
+    locks.insert(
+        name.to_string(),
+        NamedLock {
+            permits,
+            semaphore: semaphore.clone(),
+        },
+    );
+    Ok(semaphore)
+}
+
+// 다른 노드에서도 사용: 잠금을 얻을 때까지 기다린 뒤 lease_id 반환
+pub async fn acquire(
+    name: &str,
+    permits: u32,
+    timeout: Duration,
+    ttl: Duration,
+    holder: Option<String>,
+) -> Result<String, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("LOCK_NAME_REQUIRED".to_string());
+    }
+    let permits = permits.clamp(1, MAX_PERMITS);
+    let semaphore = semaphore_for(name, permits).await?;
+
+    let permit = match tokio::time::timeout(timeout, semaphore.acquire_owned()).await {
+        Ok(Ok(permit)) => permit,
+        Ok(Err(_)) => return Err(format!("LOCK_CLOSED: {}", name)),
+        Err(_) => return Err(format!("LOCK_TIMEOUT: {}", name)),
+    };
+
+    let lease_id = format!(
+        "lease_{}_{}",
+        chrono::Local::now().timestamp_millis(),
+        LEASE_COUNTER.fetch_add(1, Ordering::SeqCst)
+    );
+
+    let expiry_id = lease_id.clone();
+    let expiry = tokio::spawn(async move {
+        tokio::time::sleep(ttl).await;
+        if let Some(lease) = get_leases().lock().await.remove(&expiry_id) {
+            println!(
+                "⏰ 잠금 자동 해제 (TTL 만료): {} ({})",
+                lease.name, expiry_id
+            );
+        }
+    });
+
+    let now = chrono::Local::now();
+    get_leases().lock().await.insert(
+        lease_id.clone(),
+        Lease {
+            name: name.to_string(),
+            holder,
+            acquired_at: now,
+            expires_at: now + chrono::Duration::from_std(ttl).unwrap_or(chrono::Duration::zero()),
+            _permit: permit,
+            expiry_abort: expiry.abort_handle(),
+        },
+    );
+    println!("🔒 잠금 획득: {} ({})", name, lease_id);
+    Ok(lease_id)
+}
+
+pub async fn release(lease_id: &str) -> bool {
+    match get_leases().lock().await.remove(lease_id) {
+        Some(lease) => {
+            lease.expiry_abort.abort();
+            println!("🔓 잠금 해제: {} ({})", lease.name, lease_id);
+            true
+        }
+        None => false,
+    }
+}
+
+#[tauri::command]
+pub async fn acquire_lock(
+    name: String,
+    permits: Option<u32>,
+    timeout_secs: Option<u64>,
+    ttl_secs: Option<u64>,
+    holder: Option<String>,
+) -> Result<String, String> {
+    let started = Instant::now();
+    let lease_id = acquire(
+        &name,
+        permits.unwrap_or(1),
+        Duration::from_secs(timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS)),
+        Duration::from_secs(ttl_secs.unwrap_or(DEFAULT_TTL_SECS).max(1)),
+        holder,
+    )
+    .await?;
+    Ok(json!({
+        "lease_id": lease_id,
+        "name": name.trim(),
+        "waited_ms": started.elapsed().as_millis() as u64
+    })
+    .to_string())
+}
+
+#[tauri::command]
+pub async fn release_lock(lease_id: String) -> Result<String, String> {
+    // 이미 TTL 로 풀렸을 수도 있으니 에러가 아닌 released=false
+    let released = release(&lease_id).await;
+    Ok(json!({ "lease_id": lease_id, "released": released }).to_string())
+}
+
+#[tauri::command]
+pub async fn list_locks() -> Result<String, String> {
+    let leases = get_leases().lock().await;
+    let mut list: Vec<LeaseInfo> = leases
+        .iter()
+        .map(|(id, lease)| LeaseInfo {
+            lease_id: id.clone(),
+            name: lease.name.clone(),
+            holder: lease.holder.clone(),
+            acquired_at: lease.acquired_at.to_rfc3339(),
+            expires_at: lease.expires_at.to_rfc3339(),
+        })
+        .collect();
+    list.sort_by(|a, b| a.acquired_at.cmp(&b.acquired_at));
+    Ok(json!({ "leases": list }).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::parse_output;
+
+    #[tokio::test]
+    async fn lock_node_serializes_access() {
+        let first = parse_output(
+            &acquire_lock("test-mutex".into(), None, Some(1), Some(60), None)
+                .await
+                .unwrap(),
+        );
+        let lease_id = first["lease_id"].as_str().unwrap().to_string();
+
+        let blocked = acquire_lock("test-mutex".into(), None, Some(0), Some(60), None).await;
+        assert!(blocked.unwrap_err().starts_with("LOCK_TIMEOUT"));
+
+        let released = parse_output(&release_lock(lease_id.clone()).await.unwrap());
+        assert_eq!(released["released"], true);
+        // 두 번 해제해도 에러가 아님
+        let again = parse_output(&release_lock(lease_id).await.unwrap());
+        assert_eq!(again["released"], false);
+
+        let second = parse_output(
+            &acquire_lock("test-mutex".into(), None, Some(1), Some(60), None)
+                .await
+                .unwrap(),
+        );
+        release_lock(second["lease_id"].as_str().unwrap().into())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn lock_node_semaphore_allows_permits() {
+        let a = acquire(
+            "test-sem",
+            2,
+            std::time::Duration::from_secs(1),
+            std::time::Duration::from_secs(60),
+            None,
+        )
+        .await
+        .unwrap();
+        let b = acquire(
+            "test-sem",
+            2,
+            std::time::Duration::from_secs(1),
+            std::time::Duration::from_secs(60),
+            None,
+        )
+        .await
+        .unwrap();
+        let third = acquire(
+            "test-sem",
+            2,
+            std::time::Duration::from_millis(10),
+            std::time::Duration::from_secs(60),
+            None,
+        )
+        .await;
+        assert!(third.is_err());
+        assert!(release(&a).await);
+        assert!(release(&b).await);
+    }
+
+    #[tokio::test]
+    async fn lock_node_ttl_releases_stale_lease() {
+        acquire(
+            "test-ttl",
+            1,
+            std::time::Duration::from_secs(1),
+            std::time::Duration::from_millis(50),
+            None,
+        )
+        .await
+        .unwrap();
+        let next = acquire(
+            "test-ttl",
+            1,
+            std::time::Duration::from_secs(2),
+            std::time::Duration::from_secs(60),
+            None,
+        )
+        .await
+        .unwrap();
+        assert!(release(&next).await);
+    }
+}
@@ -0,0 +1,161 @@
+// src-tauri/src/nodes/log_node.rs
+use super::{chat_web_server_node, run_history};
+use serde_json::{json, Value};
+
+// 📝 로그 노드
+// 워크플로우 작성자가 의도적으로 남기는 로그 (노드 출력에 끼워 넣지 않아도 되도록)
+// run_id 가 있으면 실행 기록(store/runs/<run_id>.json)의 logs 에 저장되고 "run_log" 실행 이벤트로 방송
+// → 대시보드(control_page)와 관전 모드 채팅 페이지에 실시간 표시, 실행 보고서에도 포함
+// chat_node_id 가 있으면 채팅 페이지로도 보냄 (min_chat_level 이상만)
+
+pub const LEVELS: [&str; 4] = ["debug", "info", "warn", "error"];
+
+pub fn normalize_level(level: Option<&str>) -> Result<&'static str, String> {
+    let level = level.map(|l| l.trim().to_lowercase()).unwrap_or_default();
+    match level.as_str() {
+        "" | "info" => Ok("info"),
+        "debug" | "trace" => Ok("debug"),
+        "warn" | "warning" => Ok("warn"),
+        "error" | "err" => Ok("error"),
+        other => Err(format!("INVALID_LOG_LEVEL: {}", other)),
+    }
+}
+
+pub fn level_rank(level: &str) -> usize {
+    LEVELS.iter().position(|l| *l == level).unwrap_or(1)
+}
+
+fn level_icon(level: &str) -> &'static str {
+    match level {
+        "debug" => "🔍",
+        "warn" => "⚠️",
+        "error" => "❌",
+        _ => "ℹ️",
+    }
+}
+
+// 채팅/콘솔용 한 줄 표시: "⚠️ [node-1] 메시지 {context}"
+pub fn format_line(level: &str, node_id: Option<&str>, message: &str, context: &Value) -> String {
+    let mut line = level_icon(level).to_string();
+    if let Some(node_id) = node_id.filter(|n| !n.is_empty()) {
+        line.push_str(&format!(" [{}]", node_id));
+    }
+    line.push(' ');
+    line.push_str(message);
+    if !context.is_null() && context != &json!({}) {
+        line.push(' ');
+        line.push_str(&context.to_string());
+    }
+    line
+}
+
+#[tauri::command]
+pub async fn log_node(
+    level: Option<String>,
+    message: String,
+    context: Option<Value>,
+    run_id: Option<String>,
+    node_id: Option<String>,
+    chat_node_id: Option<String>,
+    min_chat_level: Option<String>,
+) -> Result<String, String> {
+    let level = normalize_level(level.as_deref())?;
+    let context = context.unwrap_or(Value::Null);
+    let line = format_line(level, node_id.as_deref(), &message, &context);
+    println!("📝 {}", line);
+
+    let run_id = run_id
+        .map(|r| r.trim().to_string())
+        .filter(|r| !r.is_empty());
+    if let Some(run_id) = &run_id {
+        run_history::append_log(
+            run_id,
+            run_history::RunLogEntry {
+                timestamp: chrono::Utc::now().timestamp_millis(),
+                level: level.to_string(),
+                node_id: node_id.clone(),
+                message: message.clone(),
+                context: context.clone(),
+            },
+        )?;
+    }
+
+    let mut mirrored = false;
+    if let Some(chat_node_id) = chat_node_id
+        .map(|c| c.trim().to_string())
+        .filter(|c| !c.is_empty())
+    {
+        let min_level = normalize_level(min_chat_level.as_deref())?;
+        if level_rank(level) >= level_rank(min_level) {
+            // 채팅 전송 실패가 워크플로우를 멈추지는 않음
+            match chat_web_server_node::send_to_mobile_with_type(
+                chat_node_id,
+                line.clone(),
+                "log".to_string(),
+            )
+            .await
+            {
+                Ok(_) => mirrored = true,
+                Err(e) => println!("⚠️ 로그 채팅 전송 실패: {}", e),
+            }
+        }
+    }
+
+    Ok(json!({
+        "level": level,
+        "message": message,
+        "context": context,
+        "run_id": run_id,
+        "mirrored": mirrored
+    })
+    .to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::parse_output;
+    use serde_json::json;
+
+    #[test]
+    fn log_node_normalizes_levels() {
+        assert_eq!(normalize_level(None).unwrap(), "info");
+        assert_eq!(normalize_level(Some("WARNING")).unwrap(), "warn");
+        assert_eq!(normalize_level(Some("err")).unwrap(), "error");
+        assert!(normalize_level(Some("loud")).is_err());
+        assert!(level_rank("error") > level_rank("warn"));
+        assert!(level_rank("debug") < level_rank("info"));
+    }
+
+    #[test]
+    fn log_node_formats_line_with_context() {
+        assert_eq!(
+            format_line("warn", Some("n1"), "느림", &json!({ "ms": 900 })),
+            "⚠️ [n1] 느림 {\"ms\":900}"
+        );
+        assert_eq!(
+            format_line("info", None, "완료", &serde_json::Value::Null),
+            "ℹ️ 완료"
+        );
+    }
+
+    #[tokio::test]
+    async fn log_node_without_run_returns_entry() {
+        let output = parse_output(
+            &log_node(
+                Some("debug".into()),
+                "hello".into(),
+                Some(json!({ "a": 1 })),
+                None,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap(),
+        );
+        assert_eq!(output["level"], "debug");
+        assert_eq!(output["mirrored"], false);
+        assert_eq!(output["context"]["a"], 1);
+    }
+}
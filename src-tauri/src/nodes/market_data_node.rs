@@ -0,0 +1,337 @@
+// src-tauri/src/nodes/market_data_node.rs
+use super::{http_client, node_cache};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+// 📈 주식 / 코인 시세 노드
+// provider: yahoo(주식·ETF·환율, 예: AAPL, 005930.KS, KRW=X) | coingecko(코인 id, 예: bitcoin) | upbit(KRW-BTC)
+// 현재가 + 전일 대비 변동을 반환, 알림 노드와 연결해 가격 알림 워크플로우 구성
+// - 캐시: 종목별 결과를 cache_secs(기본 60초) 동안 node_cache 에 보관
+// - 요청 제한: 공급자별 최소 요청 간격을 지켜 무료 API 차단을 피함
+
+const YAHOO_CHART_URL: &str = "https://query1.finance.yahoo.com/v8/finance/chart";
+const COINGECKO_PRICE_URL: &str = "https://api.coingecko.com/api/v3/simple/price";
+const UPBIT_TICKER_URL: &str = "https://api.upbit.com/v1/ticker";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Quote {
+    pub symbol: String,
+    pub price: f64,
+    pub previous_close: Option<f64>,
+    pub change: Option<f64>,
+    pub change_percent: Option<f64>,
+    pub currency: Option<String>,
+}
+
+static LAST_REQUEST: Mutex<Option<HashMap<String, Instant>>> = Mutex::new(None);
+
+fn min_interval(provider: &str) -> Duration {
+    match provider {
+        // 무료 요금제: 분당 약 30회
+        "coingecko" => Duration::from_millis(2000),
+        "upbit" => Duration::from_millis(150),
+        _ => Duration::from_millis(500),
+    }
+}
+
+// 공급자별 마지막 요청 이후 최소 간격만큼 대기
+async fn wait_for_slot(provider: &str) {
+    let wait = {
+        let Ok(mut guard) = LAST_REQUEST.lock() else {
+            return;
+        };
+        let last = guard.get_or_insert_with(HashMap::new);
+        let now = Instant::now();
+        let next = last
+            .get(provider)
+            .map(|t| *t + min_interval(provider))
+            .filter(|next| *next > now)
+            .unwrap_or(now);
+        last.insert(provider.to_string(), next);
+        next - now
+    };
+    if !wait.is_zero() {
+        tokio::time::sleep(wait).await;
+    }
+}
+
+fn encode(text: &str) -> String {
+    url::form_urlencoded::byte_serialize(text.as_bytes()).collect()
+}
+
+fn round2(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+fn quote(symbol: &str, price: f64, previous_close: Option<f64>, currency: Option<String>) -> Quote {
+    let change = previous_close.map(|prev| price - prev);
+    let change_percent = previous_close
+        .filter(|prev| *prev != 0.0)
+        .map(|prev| round2((price - prev) / prev * 100.0));
+    Quote {
+        symbol: symbol.to_string(),
+        price,
+        previous_close,
+        change,
+        change_percent,
+        currency,
+    }
+}
+
+pub fn parse_yahoo_chart(symbol: &str, body: &Value) -> Result<Quote, String> {
+    let meta = &body["chart"]["result"][0]["meta"];
+    let price = meta["regularMarketPrice"]
+        .as_f64()
+        .ok_or_else(|| format!("SYMBOL_NOT_FOUND: {}", symbol))?;
+    let previous_close = meta["chartPreviousClose"]
+        .as_f64()
+        .or(meta["previousClose"].as_f64());
+    let currency = meta["currency"].as_str().map(str::to_string);
+    Ok(quote(symbol, price, previous_close, currency))
+}
+
+// 응답: { "bitcoin": { "usd": 65000, "usd_24h_change": -1.2 } }
+pub fn parse_coingecko(ids: &[String], vs_currency: &str, body: &Value) -> Vec<Quote> {
+    ids.iter()
+        .filter_map(|id| {
+            let entry = &body[id.as_str()];
+            let price = entry[vs_currency].as_f64()?;
+            let change_percent = entry[format!("{}_24h_change", vs_currency).as_str()].as_f64();
+            let previous_close = change_percent.map(|pct| price / (1.0 + pct / 100.0));
+            Some(Quote {
+                symbol: id.clone(),
+                price,
+                previous_close,
+                change: previous_close.map(|prev| price - prev),
+                change_percent: change_percent.map(round2),
+                currency: Some(vs_currency.to_uppercase()),
+            })
+        })
+        .collect()
+}
+
+// 응답: [{ "market": "KRW-BTC", "trade_price": ..., "prev_closing_price": ... }]
+pub fn parse_upbit(body: &Value) -> Vec<Quote> {
+    body.as_array()
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| {
+                    let market = item["market"].as_str()?;
+                    let currency = market.split('-').next().map(str::to_string);
+                    Some(quote(
+                        market,
+                        item["trade_price"].as_f64()?,
+                        item["prev_closing_price"].as_f64(),
+                        currency,
+                    ))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+async fn get_json(client: &reqwest::Client, url: &str, provider: &str) -> Result<Value, String> {
+    wait_for_slot(provider).await;
+    let response = client
+        .get(url)
+        .header("User-Agent", "Mozilla/5.0 (automation-gui)")
+        .timeout(Duration::from_secs(15))
+        .send()
+        .await
+        .map_err(|e| format!("시세 요청 실패: {}", e))?;
+    let status = response.status();
+    if status.as_u16() == 429 {
+        return Err(format!("RATE_LIMITED: {}", provider));
+    }
+    if !status.is_success() {
+        return Err(format!("MARKET_API_ERROR ({})", status.as_u16()));
+    }
+    response
+        .json()
+        .await
+        .map_err(|e| format!("시세 응답 파싱 실패: {}", e))
+}
+
+async fn fetch_quotes(
+    provider: &str,
+    symbols: &[String],
+    vs_currency: &str,
+) -> Result<Vec<Quote>, String> {
+    let client = http_client::client();
+    match provider {
+        "yahoo" => {
+            let mut quotes = Vec::new();
+            for symbol in symbols {
+                let url = format!(
+                    "{}/{}?range=1d&interval=1d",
+                    YAHOO_CHART_URL,
+                    encode(symbol)
+                );
+                let body = get_json(&client, &url, provider).await?;
+                quotes.push(parse_yahoo_chart(symbol, &body)?);
+            }
+            Ok(quotes)
+        }
+        "coingecko" => {
+            let url = format!(
+                "{}?ids={}&vs_currencies={}&include_24hr_change=true",
+                COINGECKO_PRICE_URL,
+                encode(&symbols.join(",")),
+                vs_currency
+            );
+            let body = get_json(&client, &url, provider).await?;
+            Ok(parse_coingecko(symbols, vs_currency, &body))
+        }
+        "upbit" => {
+            let url = format!(
+                "{}?markets={}",
+                UPBIT_TICKER_URL,
+                encode(&symbols.join(","))
+            );
+            let body = get_json(&client, &url, provider).await?;
+            Ok(parse_upbit(&body))
+        }
+        other => Err(format!("UNSUPPORTED_PROVIDER: {}", other)),
+    }
+}
+
+#[tauri::command]
+pub async fn market_data_node(
+    symbols: Vec<String>,
+    provider: Option<String>,
+    vs_currency: Option<String>,
+    cache_secs: Option<u64>,
+) -> Result<String, String> {
+    let provider = provider
+        .as_deref()
+        .map(|p| p.trim().to_lowercase())
+        .filter(|p| !p.is_empty())
+        .unwrap_or_else(|| "yahoo".to_string());
+    let vs_currency = vs_currency
+        .as_deref()
+        .map(|c| c.trim().to_lowercase())
+        .filter(|c| !c.is_empty())
+        .unwrap_or_else(|| "usd".to_string());
+    let cache_secs = cache_secs.unwrap_or(60);
+
+    // 공급자가 돌려주는 표기에 맞춤 (upbit: KRW-BTC, coingecko: bitcoin)
+    let mut seen = HashSet::new();
+    let symbols: Vec<String> = symbols
+        .iter()
+        .map(|s| match provider.as_str() {
+            "upbit" => s.trim().to_uppercase(),
+            "coingecko" => s.trim().to_lowercase(),
+            _ => s.trim().to_string(),
+        })
+        .filter(|s| !s.is_empty() && seen.insert(s.clone()))
+        .collect();
+    if symbols.is_empty() {
+        return Err("EMPTY_SYMBOLS".to_string());
+    }
+
+    println!("📈 Market Data Node: {} {:?}", provider, symbols);
+
+    // 캐시에 있는 종목은 건너뛰고 나머지만 요청
+    let cache_type = format!("market_data:{}", provider);
+    let cache_key = |symbol: &str| json!({ "symbol": symbol, "vs": vs_currency });
+    let mut quotes: HashMap<String, (Quote, bool)> = HashMap::new();
+    let mut missing = Vec::new();
+    for symbol in &symbols {
+        let cached = node_cache::lookup(&cache_type, &cache_key(symbol))
+            .and_then(|output| serde_json::from_str::<Quote>(&output).ok());
+        match cached {
+            Some(quote) => {
+                quotes.insert(symbol.clone(), (quote, true));
+            }
+            None => missing.push(symbol.clone()),
+        }
+    }
+
+    if !missing.is_empty() {
+        for quote in fetch_quotes(&provider, &missing, &vs_currency).await? {
+            if let Ok(serialized) = serde_json::to_string(&quote) {
+                node_cache::store(
+                    &cache_type,
+                    &cache_key(&quote.symbol),
+                    &serialized,
+                    cache_secs,
+                );
+            }
+            quotes.insert(quote.symbol.clone(), (quote, false));
+        }
+    }
+
+    let not_found: Vec<&String> = symbols
+        .iter()
+        .filter(|s| !quotes.contains_key(*s))
+        .collect();
+    let results: Vec<Value> = symbols
+        .iter()
+        .filter_map(|symbol| quotes.get(symbol))
+        .map(|(quote, cached)| {
+            let mut value = json!(quote);
+            value["cached"] = json!(cached);
+            value
+        })
+        .collect();
+
+    println!(
+        "✅ 시세 조회 완료: {}개 (없음: {:?})",
+        results.len(),
+        not_found
+    );
+    Ok(json!({
+        "provider": provider,
+        "quotes": results,
+        "not_found": not_found,
+        "timestamp": chrono::Utc::now().to_rfc3339()
+    })
+    .to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn market_data_node_parses_provider_responses() {
+        let yahoo = json!({
+            "chart": { "result": [{ "meta": {
+                "currency": "KRW",
+                "regularMarketPrice": 71000.0,
+                "chartPreviousClose": 70000.0
+            }}]}
+        });
+        let quote = parse_yahoo_chart("005930.KS", &yahoo).unwrap();
+        assert_eq!(quote.price, 71000.0);
+        assert_eq!(quote.change, Some(1000.0));
+        assert_eq!(quote.change_percent, Some(1.43));
+        assert_eq!(quote.currency.as_deref(), Some("KRW"));
+        assert!(
+            parse_yahoo_chart("NOPE", &json!({ "chart": { "result": null } }))
+                .unwrap_err()
+                .starts_with("SYMBOL_NOT_FOUND")
+        );
+
+        let gecko = json!({ "bitcoin": { "usd": 60000.0, "usd_24h_change": 20.0 } });
+        let quotes = parse_coingecko(
+            &["bitcoin".to_string(), "missing".to_string()],
+            "usd",
+            &gecko,
+        );
+        assert_eq!(quotes.len(), 1);
+        assert_eq!(quotes[0].previous_close, Some(50000.0));
+        assert_eq!(quotes[0].change_percent, Some(20.0));
+
+        let upbit = json!([{ "market": "KRW-BTC", "trade_price": 90000000.0, "prev_closing_price": 100000000.0 }]);
+        let quotes = parse_upbit(&upbit);
+        assert_eq!(quotes[0].symbol, "KRW-BTC");
+        assert_eq!(quotes[0].change_percent, Some(-10.0));
+        assert_eq!(quotes[0].currency.as_deref(), Some("KRW"));
+    }
+}
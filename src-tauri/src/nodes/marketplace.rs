@@ -0,0 +1,481 @@
+// src-tauri/src/nodes/marketplace.rs
+use super::control_page;
+use super::{atomic_store, safe_path, workflow_signing};
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+// 🛒 워크플로우 / 플러그인 마켓플레이스 클라이언트
+// 원격 JSON 인덱스(커뮤니티 목록)를 받아 검색하고, 항목을 내려받아 검증한 뒤 설치
+// 인덱스 형식: { "items": [{ "id", "kind": "workflow"|"plugin", "name", "description", "author",
+//                            "version", "tags", "url", "sha256", "signature" }] }
+// - sha256 이 있으면 내려받은 내용과 반드시 일치해야 함
+// - 워크플로우는 서명 검증(workflow_signing): 변조는 항상 거부, 서명 없음/신뢰하지 않는 서명자는 allow_unsigned 일 때만
+// - 플러그인은 실행 파일일 수 있으므로 항목의 signature(내용 전체에 대한 ed25519 분리 서명)로 같은 기준 적용
+// - 설치 위치: 워크플로우 → store/workflows/<id>.flow.json, 플러그인 → store/plugins/<id>/<파일>
+// 인덱스는 store/marketplace_index.json 에 캐시해 오프라인에서도 검색 가능
+
+const MAX_DOWNLOAD_BYTES: usize = 20 * 1024 * 1024;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct MarketplaceSettings {
+    index_url: Option<String>,
+    #[serde(default)]
+    allow_unsigned: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IndexItem {
+    pub id: String,
+    #[serde(default = "default_kind")]
+    pub kind: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub author: Option<String>,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub url: String,
+    #[serde(default)]
+    pub sha256: Option<String>,
+    // 플러그인 분리 서명
+    #[serde(default)]
+    pub signature: Option<workflow_signing::DetachedSignature>,
+}
+
+fn default_kind() -> String {
+    "workflow".to_string()
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct CachedIndex {
+    index_url: String,
+    fetched_at: String,
+    items: Vec<IndexItem>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct InstalledItem {
+    kind: String,
+    version: Option<String>,
+    path: String,
+    installed_at: String,
+    signature_status: Option<String>,
+}
+
+fn settings_path() -> PathBuf {
+    atomic_store::store_dir().join("marketplace_settings.json")
+}
+
+fn index_cache_path() -> PathBuf {
+    atomic_store::store_dir().join("marketplace_index.json")
+}
+
+fn installed_path() -> PathBuf {
+    atomic_store::store_dir().join("marketplace_installed.json")
+}
+
+fn load_settings() -> MarketplaceSettings {
+    atomic_store::read_json_verified(&settings_path()).unwrap_or_default()
+}
+
+fn load_installed() -> BTreeMap<String, InstalledItem> {
+    atomic_store::read_json_verified(&installed_path()).unwrap_or_default()
+}
+
+// 배열만 있는 인덱스도 허용, 잘못된 항목은 건너뜀
+pub fn parse_index(content: &str) -> Result<Vec<IndexItem>, String> {
+    let value: Value =
+        serde_json::from_str(content).map_err(|e| format!("INVALID_MARKETPLACE_INDEX: {}", e))?;
+    let items = match value {
+        Value::Array(items) => items,
+        Value::Object(mut map) => match map.remove("items") {
+            Some(Value::Array(items)) => items,
+            _ => return Err("INVALID_MARKETPLACE_INDEX: items 없음".to_string()),
+        },
+        _ => return Err("INVALID_MARKETPLACE_INDEX".to_string()),
+    };
+    Ok(items
+        .into_iter()
+        .filter_map(|item| serde_json::from_value::<IndexItem>(item).ok())
+        .filter(|item| !item.id.trim().is_empty())
+        .collect())
+}
+
+// 이름/설명/태그/작성자에 모든 검색어가 들어 있는 항목
+pub fn search_items<'a>(
+    items: &'a [IndexItem],
+    query: &str,
+    kind: Option<&str>,
+    tag: Option<&str>,
+) -> Vec<&'a IndexItem> {
+    let terms: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+    items
+        .iter()
+        .filter(|item| kind.is_none_or(|k| item.kind.eq_ignore_ascii_case(k)))
+        .filter(|item| tag.is_none_or(|t| item.tags.iter().any(|it| it.eq_ignore_ascii_case(t))))
+        .filter(|item| {
+            let haystack = format!(
+                "{} {} {} {} {}",
+                item.id,
+                item.name,
+                item.description,
+                item.tags.join(" "),
+                item.author.as_deref().unwrap_or_default()
+            )
+            .to_lowercase();
+            terms.iter().all(|term| haystack.contains(term))
+        })
+        .collect()
+}
+
+pub fn sha256_hex(data: &[u8]) -> String {
+    Sha256::digest(data)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+// 설치 허용 여부 (서명 상태 기준)
+pub fn check_signature_policy(status: &str, allow_unsigned: bool) -> Result<(), String> {
+    match status {
+        "valid" => Ok(()),
+        "tampered" => Err("WORKFLOW_SIGNATURE_INVALID".to_string()),
+        _ if allow_unsigned => Ok(()),
+        other => Err(format!("WORKFLOW_NOT_TRUSTED: {}", other)),
+    }
+}
+
+async fn download(url: &str) -> Result<Vec<u8>, String> {
+    let client = reqwest::Client::builder()
+        .user_agent("automation-gui-marketplace/1.0")
+        .timeout(std::time::Duration::from_secs(60))
+        .build()
+        .map_err(|e| e.to_string())?;
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| format!("다운로드 실패: {}", e))?;
+    if !response.status().is_success() {
+        return Err(format!("다운로드 실패: HTTP {}", response.status()));
+    }
+    // 크기를 미리 밝힌 응답은 받기 전에 거부
+    if response
+        .content_length()
+        .is_some_and(|length| length > MAX_DOWNLOAD_BYTES as u64)
+    {
+        return Err("DOWNLOAD_TOO_LARGE".to_string());
+    }
+    read_capped(response.bytes_stream(), MAX_DOWNLOAD_BYTES).await
+}
+
+// 본문을 조각 단위로 읽다가 한도를 넘는 순간 중단 (악성 레지스트리가 메모리를 채우지 못하도록)
+async fn read_capped<S, B, E>(mut stream: S, limit: usize) -> Result<Vec<u8>, String>
+where
+    S: futures_util::Stream<Item = Result<B, E>> + Unpin,
+    B: AsRef<[u8]>,
+    E: std::fmt::Display,
+{
+    let mut content = Vec::new();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|e| format!("다운로드 실패: {}", e))?;
+        if content.len() + chunk.as_ref().len() > limit {
+            return Err("DOWNLOAD_TOO_LARGE".to_string());
+        }
+        content.extend_from_slice(chunk.as_ref());
+    }
+    Ok(content)
+}
+
+async fn refresh_index() -> Result<CachedIndex, String> {
+    let index_url = load_settings()
+        .index_url
+        .filter(|u| !u.trim().is_empty())
+        .ok_or("MARKETPLACE_INDEX_NOT_CONFIGURED")?;
+    let content = download(&index_url).await?;
+    let items = parse_index(&String::from_utf8_lossy(&content))?;
+    let cached = CachedIndex {
+        index_url,
+        fetched_at: chrono::Local::now().to_rfc3339(),
+        items,
+    };
+    atomic_store::write_json_atomic(&index_cache_path(), &cached)?;
+    println!("🛒 마켓플레이스 인덱스 갱신: {}개", cached.items.len());
+    Ok(cached)
+}
+
+// 캐시가 없으면 새로 받음
+async fn cached_index() -> Result<CachedIndex, String> {
+    match atomic_store::read_json_verified::<CachedIndex>(&index_cache_path()) {
+        Some(cached) => Ok(cached),
+        None => refresh_index().await,
+    }
+}
+
+async fn find_item(id: &str) -> Result<IndexItem, String> {
+    cached_index()
+        .await?
+        .items
+        .into_iter()
+        .find(|item| item.id == id)
+        .ok_or_else(|| format!("MARKETPLACE_ITEM_NOT_FOUND: {}", id))
+}
+
+// 내려받고 해시 / 서명 검증 → (내용, 서명 상태)
+async fn fetch_verified(
+    item: &IndexItem,
+) -> Result<(Vec<u8>, workflow_signing::Verification), String> {
+    if !matches!(item.kind.as_str(), "workflow" | "plugin") {
+        return Err(format!("UNSUPPORTED_ITEM_KIND: {}", item.kind));
+    }
+    let content = download(&item.url).await?;
+    if let Some(expected) = item.sha256.as_deref().filter(|h| !h.trim().is_empty()) {
+        let actual = sha256_hex(&content);
+        if !actual.eq_ignore_ascii_case(expected.trim()) {
+            return Err(format!(
+                "HASH_MISMATCH: {} (예상 {}, 실제 {})",
+                item.id, expected, actual
+            ));
+        }
+    }
+    let trusted = workflow_signing::trusted_keys();
+    if item.kind == "plugin" {
+        let verification =
+            workflow_signing::verify_detached(&content, item.signature.as_ref(), &trusted);
+        return Ok((content, verification));
+    }
+    let workflow: Value = serde_json::from_slice(&content)
+        .map_err(|_| "잘못된 워크플로우 파일 형식입니다".to_string())?;
+    let verification = workflow_signing::verify(&workflow, &trusted);
+    Ok((content, verification))
+}
+
+#[tauri::command]
+pub fn get_marketplace_settings() -> Result<String, String> {
+    let settings = load_settings();
+    Ok(
+        json!({ "index_url": settings.index_url, "allow_unsigned": settings.allow_unsigned })
+            .to_string(),
+    )
+}
+
+#[tauri::command]
+pub fn set_marketplace_settings(
+    index_url: Option<String>,
+    allow_unsigned: Option<bool>,
+) -> Result<String, String> {
+    let index_url = index_url
+        .map(|u| u.trim().to_string())
+        .filter(|u| !u.is_empty());
+    if let Some(url) = &index_url {
+        url::Url::parse(url).map_err(|_| format!("INVALID_URL: {}", url))?;
+    }
+    let settings = MarketplaceSettings {
+        index_url,
+        allow_unsigned: allow_unsigned.unwrap_or(false),
+    };
+    atomic_store::write_json_atomic(&settings_path(), &settings)?;
+    Ok("SUCCESS".to_string())
+}
+
+#[tauri::command]
+pub async fn marketplace_search(
+    query: Option<String>,
+    kind: Option<String>,
+    tag: Option<String>,
+    refresh: Option<bool>,
+) -> Result<String, String> {
+    let index = if refresh.unwrap_or(false) {
+        refresh_index().await?
+    } else {
+        cached_index().await?
+    };
+    let installed = load_installed();
+    let results: Vec<Value> = search_items(
+        &index.items,
+        query.as_deref().unwrap_or(""),
+        kind.as_deref(),
+        tag.as_deref(),
+    )
+    .into_iter()
+    .map(|item| {
+        let mut value = json!(item);
+        value["installed_version"] = json!(installed.get(&item.id).map(|i| i.version.clone()));
+        value["installed"] = json!(installed.contains_key(&item.id));
+        value
+    })
+    .collect();
+    Ok(
+        json!({ "fetched_at": index.fetched_at, "count": results.len(), "items": results })
+            .to_string(),
+    )
+}
+
+// 설치 전 미리보기: 내용과 서명 상태
+#[tauri::command]
+pub async fn marketplace_fetch(id: String) -> Result<String, String> {
+    let item = find_item(&id).await?;
+    let (content, verification) = fetch_verified(&item).await?;
+    let content = if item.kind == "workflow" {
+        serde_json::from_slice::<Value>(&content).unwrap_or(Value::Null)
+    } else {
+        json!({ "size": content.len() })
+    };
+    Ok(json!({ "item": item, "verification": verification, "content": content }).to_string())
+}
+
+#[tauri::command]
+pub async fn marketplace_install(
+    id: String,
+    allow_unsigned: Option<bool>,
+) -> Result<String, String> {
+    let item = find_item(&id).await?;
+    let (content, verification) = fetch_verified(&item).await?;
+    let allow_unsigned = allow_unsigned.unwrap_or_else(|| load_settings().allow_unsigned);
+    check_signature_policy(&verification.status, allow_unsigned).map_err(|e| {
+        if item.kind == "plugin" {
+            e.replacen("WORKFLOW_", "PLUGIN_", 1)
+        } else {
+            e
+        }
+    })?;
+
+    let safe_id = safe_path::sanitize_file_name(&item.id, "item");
+    let target = match item.kind.as_str() {
+        "workflow" => control_page::default_workflows_dir().join(format!("{}.flow.json", safe_id)),
+        "plugin" => {
+            let file_name = url::Url::parse(&item.url)
+                .ok()
+                .and_then(|u| u.path_segments()?.next_back().map(str::to_string))
+                .filter(|n| !n.is_empty())
+                .unwrap_or_else(|| format!("{}.bin", safe_id));
+            atomic_store::store_dir()
+                .join("plugins")
+                .join(&safe_id)
+                .join(safe_path::sanitize_file_name(&file_name, "plugin.bin"))
+        }
+        other => return Err(format!("UNSUPPORTED_ITEM_KIND: {}", other)),
+    };
+    if let Some(parent) = target.parent() {
+        std::fs::create_dir_all(parent).map_err(|_| "DIRECTORY_CREATE_ERROR".to_string())?;
+    }
+    atomic_store::write_atomic(&target, &content)?;
+
+    let mut installed = load_installed();
+    installed.insert(
+        item.id.clone(),
+        InstalledItem {
+            kind: item.kind.clone(),
+            version: item.version.clone(),
+            path: target.to_string_lossy().to_string(),
+            installed_at: chrono::Local::now().to_rfc3339(),
+            signature_status: Some(verification.status.clone()),
+        },
+    );
+    atomic_store::write_json_atomic(&installed_path(), &installed)?;
+    println!("🛒 마켓플레이스 설치: {} → {}", item.id, target.display());
+    Ok(json!({
+        "id": item.id,
+        "path": target.to_string_lossy(),
+        "verification": verification
+    })
+    .to_string())
+}
+
+#[tauri::command]
+pub fn marketplace_list_installed() -> Result<String, String> {
+    Ok(json!(load_installed()).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_index() -> Vec<IndexItem> {
+        parse_index(
+            r#"{ "items": [
+            { "id": "daily-backup", "name": "매일 백업", "description": "폴더를 zip 으로", "tags": ["backup", "file"], "url": "https://example.com/a.flow.json" },
+            { "id": "yt-archive", "kind": "workflow", "name": "YouTube Archive", "author": "kim", "tags": ["video"], "url": "https://example.com/b.flow.json" },
+            { "id": "ocr", "kind": "plugin", "name": "OCR plugin", "url": "https://example.com/ocr.zip" },
+            { "name": "id 없음", "url": "https://example.com/c" }
+        ] }"#,
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn marketplace_download_stops_at_size_limit() {
+        let chunks = || {
+            futures_util::stream::iter(vec![
+                Ok::<_, String>(vec![1u8; 6]),
+                Ok(vec![2u8; 6]),
+            ])
+        };
+        assert_eq!(read_capped(chunks(), 12).await.unwrap().len(), 12);
+        assert_eq!(
+            read_capped(chunks(), 10).await.unwrap_err(),
+            "DOWNLOAD_TOO_LARGE"
+        );
+        let failing = futures_util::stream::iter(vec![Err::<Vec<u8>, _>("reset")]);
+        assert!(read_capped(failing, 10).await.unwrap_err().contains("reset"));
+    }
+
+    #[test]
+    fn marketplace_parses_index_and_skips_invalid_items() {
+        let items = sample_index();
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[0].kind, "workflow");
+        assert_eq!(items[2].kind, "plugin");
+
+        let bare = parse_index(r#"[{ "id": "x", "name": "X", "url": "u" }]"#).unwrap();
+        assert_eq!(bare.len(), 1);
+        assert!(parse_index("{}").is_err());
+    }
+
+    #[test]
+    fn marketplace_searches_by_terms_kind_and_tag() {
+        let items = sample_index();
+        let ids = |found: Vec<&IndexItem>| -> Vec<String> {
+            found.into_iter().map(|item| item.id.clone()).collect()
+        };
+        assert_eq!(
+            ids(search_items(&items, "백업", None, None)),
+            vec!["daily-backup"]
+        );
+        assert_eq!(
+            ids(search_items(&items, "youtube KIM", None, None)),
+            vec!["yt-archive"]
+        );
+        assert_eq!(
+            ids(search_items(&items, "", Some("plugin"), None)),
+            vec!["ocr"]
+        );
+        assert_eq!(
+            ids(search_items(&items, "", None, Some("VIDEO"))),
+            vec!["yt-archive"]
+        );
+        assert_eq!(search_items(&items, "", None, None).len(), 3);
+    }
+
+    #[test]
+    fn marketplace_verifies_hash_and_signature_policy() {
+        assert_eq!(
+            sha256_hex(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert!(check_signature_policy("valid", false).is_ok());
+        assert!(check_signature_policy("untrusted", false).is_err());
+        assert!(check_signature_policy("unsigned", true).is_ok());
+        assert_eq!(
+            check_signature_policy("tampered", true).unwrap_err(),
+            "WORKFLOW_SIGNATURE_INVALID"
+        );
+    }
+}
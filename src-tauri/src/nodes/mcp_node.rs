@@ -0,0 +1,351 @@
+// src-tauri/src/nodes/mcp_node.rs
+use super::http_client;
+use futures_util::StreamExt;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, Lines};
+use tokio::process::{Child, ChildStdin, ChildStdout};
+use tokio::sync::mpsc;
+
+// 🧩 MCP(Model Context Protocol) 클라이언트 노드
+// stdio(로컬 명령 실행) 또는 SSE(원격 서버)로 MCP 서버에 연결해
+// 도구 목록을 조회(list_tools)하거나 JSON 인자로 도구를 호출(call_tool)
+
+pub(crate) const PROTOCOL_VERSION: &str = "2024-11-05";
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+
+pub(crate) struct StdioTransport {
+    _child: Child,
+    stdin: ChildStdin,
+    lines: Lines<BufReader<ChildStdout>>,
+}
+
+pub(crate) struct SseTransport {
+    client: reqwest::Client,
+    post_url: String,
+    messages: mpsc::UnboundedReceiver<String>,
+    reader: tokio::task::JoinHandle<()>,
+}
+
+// Stdio 쪽이 훨씬 커서 Box 로 감쌈
+pub(crate) enum McpTransport {
+    Stdio(Box<StdioTransport>),
+    Sse(SseTransport),
+}
+
+impl Drop for SseTransport {
+    fn drop(&mut self) {
+        self.reader.abort();
+    }
+}
+
+impl McpTransport {
+    pub(crate) async fn send(&mut self, message: &Value) -> Result<(), String> {
+        match self {
+            McpTransport::Stdio(t) => {
+                let line = format!("{}\n", message);
+                t.stdin
+                    .write_all(line.as_bytes())
+                    .await
+                    .map_err(|e| format!("MCP 서버에 쓰기 실패: {}", e))?;
+                t.stdin.flush().await.map_err(|e| e.to_string())
+            }
+            McpTransport::Sse(t) => {
+                let response = t
+                    .client
+                    .post(&t.post_url)
+                    .json(message)
+                    .send()
+                    .await
+                    .map_err(|e| format!("MCP 요청 실패: {}", e))?;
+                if !response.status().is_success() {
+                    return Err(format!("MCP_HTTP_ERROR: {}", response.status()));
+                }
+                Ok(())
+            }
+        }
+    }
+
+    pub(crate) async fn next_message(&mut self) -> Result<Value, String> {
+        loop {
+            let raw = match self {
+                McpTransport::Stdio(t) => t
+                    .lines
+                    .next_line()
+                    .await
+                    .map_err(|e| e.to_string())?
+                    .ok_or("MCP 서버가 종료되었습니다")?,
+                McpTransport::Sse(t) => t
+                    .messages
+                    .recv()
+                    .await
+                    .ok_or("MCP SSE 연결이 끊어졌습니다")?,
+            };
+            // stdio 서버가 로그를 stdout 으로 찍는 경우는 건너뜀
+            if let Ok(value) = serde_json::from_str::<Value>(raw.trim()) {
+                return Ok(value);
+            }
+        }
+    }
+}
+
+struct McpClient {
+    transport: McpTransport,
+    next_id: u64,
+}
+
+impl McpClient {
+    async fn request(&mut self, method: &str, params: Value) -> Result<Value, String> {
+        self.next_id += 1;
+        let id = self.next_id;
+        self.transport
+            .send(&json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params }))
+            .await?;
+
+        let wait = async {
+            loop {
+                let message = self.transport.next_message().await?;
+                // 알림이나 다른 요청의 응답은 무시
+                if message["id"].as_u64() != Some(id) {
+                    continue;
+                }
+                if let Some(error) = message.get("error") {
+                    return Err(format!(
+                        "MCP_ERROR {}: {}",
+                        error["code"],
+                        error["message"].as_str().unwrap_or("unknown error")
+                    ));
+                }
+                return Ok(message["result"].clone());
+            }
+        };
+
+        tokio::time::timeout(REQUEST_TIMEOUT, wait)
+            .await
+            .map_err(|_| format!("MCP_TIMEOUT: {}", method))?
+    }
+
+    async fn notify(&mut self, method: &str) -> Result<(), String> {
+        self.transport
+            .send(&json!({ "jsonrpc": "2.0", "method": method }))
+            .await
+    }
+
+    async fn initialize(&mut self) -> Result<Value, String> {
+        let result = self
+            .request(
+                "initialize",
+                json!({
+                    "protocolVersion": PROTOCOL_VERSION,
+                    "capabilities": {},
+                    "clientInfo": { "name": "automation-gui", "version": env!("CARGO_PKG_VERSION") }
+                }),
+            )
+            .await?;
+        self.notify("notifications/initialized").await?;
+        Ok(result)
+    }
+}
+
+fn connect_stdio(
+    command: &str,
+    args: &[String],
+    env: &HashMap<String, String>,
+) -> Result<McpTransport, String> {
+    let mut cmd = tokio::process::Command::new(command);
+    cmd.args(args)
+        .envs(env)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .kill_on_drop(true);
+
+    #[cfg(target_os = "windows")]
+    {
+        #[allow(unused_imports)]
+        use std::os::windows::process::CommandExt;
+        cmd.creation_flags(0x08000000); // CREATE_NO_WINDOW
+    }
+
+    let mut child = cmd
+        .spawn()
+        .map_err(|e| format!("MCP 서버 실행 실패 ({}): {}", command, e))?;
+    let stdin = child.stdin.take().ok_or("stdin 연결 실패")?;
+    let stdout = child.stdout.take().ok_or("stdout 연결 실패")?;
+
+    Ok(McpTransport::Stdio(Box::new(StdioTransport {
+        _child: child,
+        stdin,
+        lines: BufReader::new(stdout).lines(),
+    })))
+}
+
+// SSE 스트림에서 (event, data) 를 읽어 "endpoint" 는 한 번 돌려주고 "message" 는 채널로 전달
+pub(crate) async fn connect_sse(url: &str) -> Result<McpTransport, String> {
+    // 응답이 계속 열려 있으므로 전체 시간 제한 없는 공용 클라이언트
+    let client = http_client::stream_client();
+    let response = client
+        .get(url)
+        .header("Accept", "text/event-stream")
+        .send()
+        .await
+        .map_err(|e| format!("MCP SSE 연결 실패: {}", e))?;
+    if !response.status().is_success() {
+        return Err(format!("MCP_HTTP_ERROR: {}", response.status()));
+    }
+
+    let (endpoint_tx, mut endpoint_rx) = mpsc::unbounded_channel::<String>();
+    let (message_tx, message_rx) = mpsc::unbounded_channel::<String>();
+
+    let reader = tokio::spawn(async move {
+        let mut stream = response.bytes_stream();
+        let mut buffer = String::new();
+        let mut event = String::new();
+        let mut data = String::new();
+
+        while let Some(Ok(chunk)) = stream.next().await {
+            buffer.push_str(&String::from_utf8_lossy(&chunk));
+
+            while let Some(newline) = buffer.find('\n') {
+                let line = buffer[..newline].trim_end_matches('\r').to_string();
+                buffer.drain(..=newline);
+
+                if line.is_empty() {
+                    // 빈 줄 = 이벤트 끝
+                    let target = if event == "endpoint" {
+                        &endpoint_tx
+                    } else {
+                        &message_tx
+                    };
+                    let _ = target.send(std::mem::take(&mut data));
+                    event.clear();
+                } else if let Some(value) = line.strip_prefix("event:") {
+                    event = value.trim().to_string();
+                } else if let Some(value) = line.strip_prefix("data:") {
+                    if !data.is_empty() {
+                        data.push('\n');
+                    }
+                    data.push_str(value.trim_start());
+                }
+            }
+        }
+    });
+
+    let endpoint = tokio::time::timeout(REQUEST_TIMEOUT, endpoint_rx.recv())
+        .await
+        .map_err(|_| "MCP_TIMEOUT: endpoint 이벤트를 받지 못했습니다".to_string())?
+        .ok_or("MCP SSE 연결이 끊어졌습니다")?;
+
+    // endpoint 는 상대 경로일 수 있음
+    let post_url = url::Url::parse(url)
+        .and_then(|base| base.join(endpoint.trim()))
+        .map_err(|e| format!("잘못된 endpoint: {}", e))?
+        .to_string();
+
+    Ok(McpTransport::Sse(SseTransport {
+        client,
+        post_url,
+        messages: message_rx,
+        reader,
+    }))
+}
+
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn mcp_node(
+    transport: String,
+    action: String,
+    command: Option<String>,
+    args: Option<Vec<String>>,
+    env: Option<HashMap<String, String>>,
+    url: Option<String>,
+    tool_name: Option<String>,
+    arguments: Option<Value>,
+) -> Result<String, String> {
+    let transport = transport.trim().to_lowercase();
+    let action = action.trim().to_lowercase();
+    println!("🧩 MCP Node: {} {} {:?}", transport, action, tool_name);
+
+    let connection = match transport.as_str() {
+        "stdio" => {
+            let command = command
+                .as_deref()
+                .map(str::trim)
+                .filter(|c| !c.is_empty())
+                .ok_or("EMPTY_COMMAND")?;
+            connect_stdio(command, &args.unwrap_or_default(), &env.unwrap_or_default())?
+        }
+        "sse" => {
+            let url = url
+                .as_deref()
+                .map(str::trim)
+                .filter(|u| !u.is_empty())
+                .ok_or("EMPTY_URL")?;
+            connect_sse(url).await?
+        }
+        other => return Err(format!("UNSUPPORTED_TRANSPORT: {}", other)),
+    };
+
+    let mut client = McpClient {
+        transport: connection,
+        next_id: 0,
+    };
+    let server_info = client.initialize().await?;
+
+    let result = match action.as_str() {
+        "list_tools" => {
+            let listed = client.request("tools/list", json!({})).await?;
+            json!({
+                "server": server_info["serverInfo"],
+                "tools": listed["tools"]
+            })
+        }
+        "call_tool" => {
+            let tool_name = tool_name
+                .as_deref()
+                .map(str::trim)
+                .filter(|t| !t.is_empty())
+                .ok_or("EMPTY_TOOL_NAME")?;
+            let arguments = match arguments {
+                None | Some(Value::Null) => json!({}),
+                Some(Value::String(s)) => {
+                    serde_json::from_str(&s).map_err(|e| format!("INVALID_ARGUMENTS: {}", e))?
+                }
+                Some(value) => value,
+            };
+
+            let called = client
+                .request(
+                    "tools/call",
+                    json!({ "name": tool_name, "arguments": arguments }),
+                )
+                .await?;
+
+            // content 중 text 항목을 이어붙여 다음 노드에서 쓰기 쉽게 제공
+            let text: Vec<&str> = called["content"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter(|c| c["type"] == "text")
+                .filter_map(|c| c["text"].as_str())
+                .collect();
+
+            let is_error = called["isError"].as_bool().unwrap_or(false);
+            if is_error {
+                return Err(format!("MCP_TOOL_ERROR: {}", text.join("\n")));
+            }
+
+            json!({
+                "tool": tool_name,
+                "text": text.join("\n"),
+                "content": called["content"],
+                "structured": called.get("structuredContent")
+            })
+        }
+        other => return Err(format!("UNSUPPORTED_ACTION: {}", other)),
+    };
+
+    Ok(result.to_string())
+}
@@ -0,0 +1,475 @@
+// src-tauri/src/nodes/mcp_server.rs
+use super::access_log;
+use super::atomic_store;
+use super::event_chunks;
+use super::mcp_node::{self, PROTOCOL_VERSION};
+use super::origin_policy;
+use super::workflow_params::{prepare_workflow_run, WorkflowParameter};
+use super::workflow_signing;
+use super::workspace;
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::AppHandle;
+use tokio::sync::{mpsc, oneshot, Mutex, RwLock};
+use warp::Filter;
+
+// 🧩 MCP 서버 모드
+// 게시(publish)한 워크플로우를 MCP 도구로 노출해 외부 AI 어시스턴트(Claude Desktop 등)가 호출
+// - SSE: 앱 안에서 http://127.0.0.1:<port>/sse 로 서비스
+// - stdio: `automation-gui --mcp-stdio [--port N]` 으로 실행하면 GUI 없이 실행 중인 앱의 SSE 서버로 중계
+// 도구 호출 → "mcp-run-workflow" 이벤트로 프론트엔드 엔진이 실행 → complete_mcp_tool_call 로 결과 반환
+
+pub const DEFAULT_MCP_PORT: u16 = 3917;
+const TOOL_CALL_TIMEOUT: Duration = Duration::from_secs(600);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PublishedWorkflow {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub file_path: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+struct McpRunWorkflowEvent {
+    call_id: String,
+    tool: String,
+    workflow_path: String,
+    workflow: String, // 입력값이 주입된 워크플로우 JSON
+}
+
+struct McpServerHandle {
+    port: u16,
+    abort_handle: tokio::task::AbortHandle,
+}
+
+type PendingCalls = Arc<Mutex<HashMap<String, oneshot::Sender<Result<String, String>>>>>;
+type Sessions = Arc<Mutex<HashMap<String, mpsc::UnboundedSender<String>>>>;
+
+static PENDING_CALLS: std::sync::OnceLock<PendingCalls> = std::sync::OnceLock::new();
+static MCP_SERVER: std::sync::OnceLock<Arc<RwLock<Option<McpServerHandle>>>> =
+    std::sync::OnceLock::new();
+
+fn get_pending_calls() -> &'static PendingCalls {
+    PENDING_CALLS.get_or_init(|| Arc::new(Mutex::new(HashMap::new())))
+}
+
+fn get_mcp_server() -> &'static Arc<RwLock<Option<McpServerHandle>>> {
+    MCP_SERVER.get_or_init(|| Arc::new(RwLock::new(None)))
+}
+
+fn unique_id(prefix: &str) -> String {
+    static COUNTER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+    let count = COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    format!("{}-{:x}-{}", prefix, nanos, count)
+}
+
+// ===== 게시된 워크플로우 목록 (store/mcp_tools.json) =====
+
+fn tools_path() -> PathBuf {
+    atomic_store::store_dir().join("mcp_tools.json")
+}
+
+fn load_published() -> Vec<PublishedWorkflow> {
+    atomic_store::read_json_verified(&tools_path()).unwrap_or_default()
+}
+
+fn save_published(tools: &[PublishedWorkflow]) -> Result<(), String> {
+    atomic_store::write_json_atomic(&tools_path(), &tools)
+}
+
+// 워크플로우 입력 파라미터 → 도구 inputSchema (JSON Schema)
+fn input_schema(workflow: &Value) -> Value {
+    let params: Vec<WorkflowParameter> = workflow
+        .get("parameters")
+        .and_then(|p| serde_json::from_value(p.clone()).ok())
+        .unwrap_or_default();
+
+    let mut properties = serde_json::Map::new();
+    let mut required = Vec::new();
+
+    for param in &params {
+        let mut schema = match param.param_type.as_str() {
+            "number" => json!({ "type": "number" }),
+            "enum" => json!({ "type": "string", "enum": param.options }),
+            "file_path" => json!({ "type": "string", "description": "Absolute file path" }),
+            _ => json!({ "type": "string" }),
+        };
+        if let Some(description) = &param.description {
+            schema["description"] = json!(description);
+        }
+        if let Some(default) = param.default.as_ref().filter(|d| !d.is_null()) {
+            schema["default"] = default.clone();
+        }
+        if param.required {
+            required.push(param.name.clone());
+        }
+        properties.insert(param.name.clone(), schema);
+    }
+
+    json!({ "type": "object", "properties": properties, "required": required })
+}
+
+fn tool_definitions() -> Vec<Value> {
+    load_published()
+        .into_iter()
+        .filter_map(|tool| {
+            // 파일이 사라진 워크플로우는 목록에서 제외
+            let content = std::fs::read_to_string(&tool.file_path).ok()?;
+            let workflow: Value = serde_json::from_str(&content).ok()?;
+            Some(json!({
+                "name": tool.name,
+                "description": tool.description.unwrap_or_else(|| format!("Run workflow {}", tool.name)),
+                "inputSchema": input_schema(&workflow)
+            }))
+        })
+        .collect()
+}
+
+async fn call_tool(app_handle: &AppHandle, name: &str, arguments: Value) -> Result<String, String> {
+    let tool = load_published()
+        .into_iter()
+        .find(|t| t.name == name)
+        .ok_or_else(|| format!("UNKNOWN_TOOL: {}", name))?;
+
+    let content = std::fs::read_to_string(&tool.file_path)
+        .map_err(|e| format!("워크플로우 파일 읽기 실패: {}", e))?;
+    // 원격 호출은 확인할 사람이 없으므로 서명이 변조된 워크플로우는 실행하지 않음
+    if workflow_signing::check_file_content(&content).is_some_and(|v| v.status == "tampered") {
+        return Err(format!("WORKFLOW_SIGNATURE_INVALID: {}", tool.name));
+    }
+    let content = workspace::expand_workflow_text(&content, std::path::Path::new(&tool.file_path))?;
+    let workflow = prepare_workflow_run(content, Some(arguments))?;
+
+    let call_id = unique_id("mcp");
+    let (tx, rx) = oneshot::channel();
+    get_pending_calls().lock().await.insert(call_id.clone(), tx);
+
+    let event = McpRunWorkflowEvent {
+        call_id: call_id.clone(),
+        tool: tool.name.clone(),
+        workflow_path: tool.file_path.clone(),
+        workflow,
+    };
+    if let Err(e) = event_chunks::emit_chunked(app_handle, "mcp-run-workflow", &event) {
+        get_pending_calls().lock().await.remove(&call_id);
+        return Err(format!("워크플로우 실행 요청 실패: {}", e));
+    }
+    println!(
+        "🧩 MCP 도구 호출 → 워크플로우 실행: {} ({})",
+        tool.name, call_id
+    );
+
+    match tokio::time::timeout(TOOL_CALL_TIMEOUT, rx).await {
+        Ok(Ok(result)) => result,
+        Ok(Err(_)) => Err("WORKFLOW_CANCELLED".to_string()),
+        Err(_) => {
+            get_pending_calls().lock().await.remove(&call_id);
+            Err("WORKFLOW_TIMEOUT".to_string())
+        }
+    }
+}
+
+// JSON-RPC 메시지 처리 (알림이면 None)
+async fn handle_message(app_handle: &AppHandle, message: Value) -> Option<Value> {
+    let id = message.get("id").cloned()?;
+    let method = message["method"].as_str().unwrap_or_default();
+    let params = message.get("params").cloned().unwrap_or(Value::Null);
+
+    let result: Result<Value, (i64, String)> = match method {
+        "initialize" => Ok(json!({
+            "protocolVersion": PROTOCOL_VERSION,
+            "capabilities": { "tools": { "listChanged": false } },
+            "serverInfo": { "name": "automation-gui", "version": env!("CARGO_PKG_VERSION") }
+        })),
+        "ping" => Ok(json!({})),
+        "tools/list" => Ok(json!({ "tools": tool_definitions() })),
+        "tools/call" => {
+            let name = params["name"].as_str().unwrap_or_default();
+            let arguments = params
+                .get("arguments")
+                .cloned()
+                .unwrap_or_else(|| json!({}));
+
+            // 도구 실행 실패는 프로토콜 에러가 아닌 isError 결과로 전달
+            Ok(match call_tool(app_handle, name, arguments).await {
+                Ok(output) => {
+                    json!({ "content": [{ "type": "text", "text": output }], "isError": false })
+                }
+                Err(e) => json!({ "content": [{ "type": "text", "text": e }], "isError": true }),
+            })
+        }
+        other => Err((-32601, format!("Method not found: {}", other))),
+    };
+
+    Some(match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err((code, message)) => {
+            json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+        }
+    })
+}
+
+async fn run_sse_server(app_handle: AppHandle, port: u16) {
+    let sessions: Sessions = Arc::new(Mutex::new(HashMap::new()));
+
+    // GET /sse: 세션 생성 후 메시지 전송 주소(endpoint) 안내
+    let sse_sessions = sessions.clone();
+    let sse_route = warp::path("sse").and(warp::get()).and_then(move || {
+        let sessions = sse_sessions.clone();
+        async move {
+            let session_id = unique_id("session");
+            let (tx, rx) = mpsc::unbounded_channel::<String>();
+            sessions.lock().await.insert(session_id.clone(), tx);
+            println!("🧩 MCP 클라이언트 연결: {}", session_id);
+
+            let endpoint = warp::sse::Event::default()
+                .event("endpoint")
+                .data(format!("/messages?session_id={}", session_id));
+            let messages = futures_util::stream::unfold(rx, |mut rx| async move {
+                rx.recv()
+                    .await
+                    .map(|m| (warp::sse::Event::default().event("message").data(m), rx))
+            });
+            let stream = futures_util::stream::once(async move { endpoint })
+                .chain(messages)
+                .map(Ok::<_, Infallible>);
+
+            Ok::<_, Infallible>(warp::sse::reply(warp::sse::keep_alive().stream(stream)))
+        }
+    });
+
+    // POST /messages?session_id=...: 요청 처리 후 응답은 SSE 로 전송
+    let message_sessions = sessions.clone();
+    let message_route = warp::path("messages")
+        .and(warp::post())
+        .and(warp::query::<HashMap<String, String>>())
+        .and(warp::body::json())
+        .and_then(move |query: HashMap<String, String>, message: Value| {
+            let sessions = message_sessions.clone();
+            let app_handle = app_handle.clone();
+            async move {
+                let session_id = query.get("session_id").cloned().unwrap_or_default();
+                let Some(tx) = sessions.lock().await.get(&session_id).cloned() else {
+                    return Ok::<_, Infallible>(warp::http::StatusCode::NOT_FOUND);
+                };
+
+                tokio::spawn(async move {
+                    if let Some(response) = handle_message(&app_handle, message).await {
+                        if tx.send(response.to_string()).is_err() {
+                            sessions.lock().await.remove(&session_id);
+                        }
+                    }
+                });
+                Ok(warp::http::StatusCode::ACCEPTED)
+            }
+        });
+
+    // 브라우저의 다른 웹페이지가 로컬 MCP 서버를 호출하지 못하도록 Origin 제한
+    let origin_check =
+        origin_policy::origin_filter(Arc::new(origin_policy::OriginPolicy::new(None, false)));
+    let routes = origin_check
+        .and(sse_route.or(message_route))
+        .map(|origin, reply| origin_policy::with_cors_headers(origin, reply))
+        .recover(origin_policy::handle_rejection)
+        .with(access_log::warp_logger("mcp_server".to_string()));
+
+    // 로컬 어시스턴트 전용 (외부 네트워크에 노출하지 않음)
+    let addr = std::net::SocketAddr::from(([127, 0, 0, 1], port));
+    println!("🧩 MCP 서버 시작: http://{}/sse", addr);
+    warp::serve(routes).run(addr).await;
+}
+
+// ===== stdio 브리지 =====
+
+// `--mcp-stdio [--port N]` 으로 실행되었으면 포트 반환
+pub fn stdio_bridge_port() -> Option<u16> {
+    let args: Vec<String> = std::env::args().collect();
+    if !args.iter().any(|a| a == "--mcp-stdio") {
+        return None;
+    }
+    let port = args
+        .iter()
+        .position(|a| a == "--port")
+        .and_then(|i| args.get(i + 1))
+        .and_then(|p| p.parse().ok())
+        .unwrap_or(DEFAULT_MCP_PORT);
+    Some(port)
+}
+
+// stdin 의 JSON-RPC 를 실행 중인 앱의 SSE 서버로 전달하고 응답을 stdout 으로 출력
+// (stdout 은 프로토콜 전용이므로 로그는 stderr 로)
+pub fn run_stdio_bridge(port: u16) -> i32 {
+    let runtime = match tokio::runtime::Runtime::new() {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            eprintln!("❌ 런타임 생성 실패: {}", e);
+            return 1;
+        }
+    };
+
+    runtime.block_on(async move {
+        use tokio::io::{AsyncBufReadExt, AsyncWriteExt};
+
+        let url = format!("http://127.0.0.1:{}/sse", port);
+        let mut transport = match mcp_node::connect_sse(&url).await {
+            Ok(transport) => transport,
+            Err(e) => {
+                eprintln!("❌ 앱의 MCP 서버에 연결할 수 없습니다 ({}): {}", url, e);
+                return 1;
+            }
+        };
+
+        let mut stdin = tokio::io::BufReader::new(tokio::io::stdin()).lines();
+        let mut stdout = tokio::io::stdout();
+
+        loop {
+            tokio::select! {
+                line = stdin.next_line() => {
+                    let Ok(Some(line)) = line else { return 0 };
+                    match serde_json::from_str::<Value>(line.trim()) {
+                        Ok(message) => {
+                            if let Err(e) = transport.send(&message).await {
+                                eprintln!("❌ 요청 전달 실패: {}", e);
+                                return 1;
+                            }
+                        }
+                        Err(_) if line.trim().is_empty() => {}
+                        Err(e) => eprintln!("⚠️ 잘못된 JSON 무시: {}", e),
+                    }
+                }
+                message = transport.next_message() => {
+                    let Ok(message) = message else { return 1 };
+                    let line = format!("{}\n", message);
+                    if stdout.write_all(line.as_bytes()).await.is_err() || stdout.flush().await.is_err() {
+                        return 1;
+                    }
+                }
+            }
+        }
+    })
+}
+
+// ===== Tauri 명령 =====
+
+#[tauri::command]
+pub async fn start_mcp_server(app_handle: AppHandle, port: Option<u16>) -> Result<String, String> {
+    let port = port.unwrap_or(DEFAULT_MCP_PORT);
+    let _ = stop_mcp_server().await;
+
+    std::net::TcpListener::bind(("127.0.0.1", port))
+        .map_err(|e| format!("PORT_IN_USE: {} ({})", port, e))?;
+
+    let task = tokio::spawn(run_sse_server(app_handle, port));
+    *get_mcp_server().write().await = Some(McpServerHandle {
+        port,
+        abort_handle: task.abort_handle(),
+    });
+
+    Ok(json!({ "port": port, "sse_url": format!("http://127.0.0.1:{}/sse", port) }).to_string())
+}
+
+#[tauri::command]
+pub async fn stop_mcp_server() -> Result<String, String> {
+    match get_mcp_server().write().await.take() {
+        Some(handle) => {
+            handle.abort_handle.abort();
+            println!("🛑 MCP 서버 중지 (port {})", handle.port);
+            Ok("MCP server stopped".to_string())
+        }
+        None => Ok("MCP server is not running".to_string()),
+    }
+}
+
+#[tauri::command]
+pub async fn get_mcp_server_status() -> Result<Value, String> {
+    Ok(match get_mcp_server().read().await.as_ref() {
+        Some(handle) if !handle.abort_handle.is_finished() => json!({
+            "running": true,
+            "port": handle.port,
+            "sse_url": format!("http://127.0.0.1:{}/sse", handle.port)
+        }),
+        _ => json!({ "running": false }),
+    })
+}
+
+#[tauri::command]
+pub fn publish_workflow_tool(
+    name: String,
+    file_path: String,
+    description: Option<String>,
+) -> Result<String, String> {
+    let name = name.trim().to_string();
+    // MCP 도구 이름 규칙: 영문/숫자/_/- 1~64자
+    if name.is_empty()
+        || name.len() > 64
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        return Err(format!("INVALID_TOOL_NAME: {}", name));
+    }
+    if !std::path::Path::new(&file_path).is_file() {
+        return Err(format!("파일을 찾을 수 없습니다: {}", file_path));
+    }
+
+    let mut tools = load_published();
+    tools.retain(|t| t.name != name);
+    tools.push(PublishedWorkflow {
+        name: name.clone(),
+        description: description.filter(|d| !d.trim().is_empty()),
+        file_path,
+    });
+    save_published(&tools)?;
+
+    println!("🧩 MCP 도구 게시: {}", name);
+    Ok("SUCCESS".to_string())
+}
+
+#[tauri::command]
+pub fn unpublish_workflow_tool(name: String) -> Result<String, String> {
+    let mut tools = load_published();
+    let before = tools.len();
+    tools.retain(|t| t.name != name);
+    if tools.len() == before {
+        return Err(format!("UNKNOWN_TOOL: {}", name));
+    }
+    save_published(&tools)?;
+    Ok("SUCCESS".to_string())
+}
+
+#[tauri::command]
+pub fn list_published_tools() -> Result<Vec<PublishedWorkflow>, String> {
+    Ok(load_published())
+}
+
+// 프론트엔드 엔진이 워크플로우 실행을 마치면 호출
+#[tauri::command]
+pub async fn complete_mcp_tool_call(
+    call_id: String,
+    output: Option<String>,
+    error: Option<String>,
+) -> Result<String, String> {
+    let sender = get_pending_calls()
+        .lock()
+        .await
+        .remove(&call_id)
+        .ok_or_else(|| format!("UNKNOWN_CALL: {}", call_id))?;
+
+    let result = match error {
+        Some(error) => Err(error),
+        None => Ok(output.unwrap_or_default()),
+    };
+    let _ = sender.send(result);
+    Ok("SUCCESS".to_string())
+}
@@ -0,0 +1,190 @@
+// src-tauri/src/nodes/media_control_node.rs
+use super::os_command;
+use serde_json::json;
+
+// 🔊 볼륨 / 미디어 키 제어 노드
+// action: set_volume(level 0-100) | mute | unmute | toggle_mute | play_pause | next | previous | stop
+
+const ACTIONS: &[&str] = &[
+    "set_volume",
+    "mute",
+    "unmute",
+    "toggle_mute",
+    "play_pause",
+    "next",
+    "previous",
+    "stop",
+];
+
+// Windows: 가상 키 입력(keybd_event)으로 미디어 키 / 볼륨 키 전송
+#[cfg(target_os = "windows")]
+fn send_virtual_keys(keys: &[(u8, u32)]) -> Result<(), String> {
+    let presses: Vec<String> = keys
+        .iter()
+        .map(|(vk, count)| {
+            format!(
+                "for ($i = 0; $i -lt {}; $i++) {{ [MediaKeys]::Press({}) }}",
+                count, vk
+            )
+        })
+        .collect();
+
+    let script = format!(
+        r#"
+Add-Type @'
+using System.Runtime.InteropServices;
+public static class MediaKeys {{
+    [DllImport("user32.dll")] static extern void keybd_event(byte vk, byte scan, uint flags, System.UIntPtr extra);
+    public static void Press(byte vk) {{
+        keybd_event(vk, 0, 1, System.UIntPtr.Zero);
+        keybd_event(vk, 0, 3, System.UIntPtr.Zero);
+    }}
+}}
+'@
+{}
+"#,
+        presses.join("\n")
+    );
+
+    os_command::powershell(&script).map(|_| ())
+}
+
+// Windows: 기본 출력 장치의 IAudioEndpointVolume (음소거 키는 토글이라 mute/unmute 를 보장할 수 없음)
+#[cfg(target_os = "windows")]
+const ENDPOINT_VOLUME_SCRIPT: &str = r#"
+Add-Type @'
+using System.Runtime.InteropServices;
+[Guid("5CDF2C82-841E-4546-9722-0CF74078229A"), InterfaceType(ComInterfaceType.InterfaceIsIUnknown)]
+interface IAudioEndpointVolume {
+    int _0(); int _1(); int _2(); int _3();
+    int SetMasterVolumeLevelScalar(float level, System.Guid context);
+    int _5();
+    int GetMasterVolumeLevelScalar(out float level);
+    int _7(); int _8(); int _9(); int _10();
+    int SetMute([MarshalAs(UnmanagedType.Bool)] bool mute, System.Guid context);
+    int GetMute([MarshalAs(UnmanagedType.Bool)] out bool mute);
+}
+[Guid("D666063F-1587-4E43-81F1-B948E807363F"), InterfaceType(ComInterfaceType.InterfaceIsIUnknown)]
+interface IMMDevice {
+    int Activate(ref System.Guid id, int context, int parameters, out IAudioEndpointVolume volume);
+}
+[Guid("A95664D2-9614-4F35-A746-DE8DB63617E6"), InterfaceType(ComInterfaceType.InterfaceIsIUnknown)]
+interface IMMDeviceEnumerator {
+    int _0();
+    int GetDefaultAudioEndpoint(int flow, int role, out IMMDevice device);
+}
+[ComImport, Guid("BCDE0395-E52F-467C-8E3D-C4579291692E")] class MMDeviceEnumerator { }
+public static class EndpointVolume {
+    static IAudioEndpointVolume Open() {
+        var enumerator = new MMDeviceEnumerator() as IMMDeviceEnumerator;
+        IMMDevice device;
+        Marshal.ThrowExceptionForHR(enumerator.GetDefaultAudioEndpoint(0, 1, out device));
+        IAudioEndpointVolume volume;
+        var id = typeof(IAudioEndpointVolume).GUID;
+        Marshal.ThrowExceptionForHR(device.Activate(ref id, 23, 0, out volume));
+        return volume;
+    }
+    public static bool Mute {
+        get { bool muted; Marshal.ThrowExceptionForHR(Open().GetMute(out muted)); return muted; }
+        set { Marshal.ThrowExceptionForHR(Open().SetMute(value, System.Guid.Empty)); }
+    }
+}
+'@
+"#;
+
+#[cfg(target_os = "windows")]
+fn set_endpoint_mute(statement: &str) -> Result<(), String> {
+    os_command::powershell(&format!("{}\n{}", ENDPOINT_VOLUME_SCRIPT, statement)).map(|_| ())
+}
+
+#[cfg(target_os = "windows")]
+fn perform(action: &str, level: u32) -> Result<(), String> {
+    const VK_VOLUME_MUTE: u8 = 0xAD;
+    const VK_VOLUME_DOWN: u8 = 0xAE;
+    const VK_VOLUME_UP: u8 = 0xAF;
+    const VK_MEDIA_NEXT_TRACK: u8 = 0xB0;
+    const VK_MEDIA_PREV_TRACK: u8 = 0xB1;
+    const VK_MEDIA_STOP: u8 = 0xB2;
+    const VK_MEDIA_PLAY_PAUSE: u8 = 0xB3;
+
+    match action {
+        // 볼륨 키 한 번 = 2% → 0까지 내린 뒤 목표치만큼 올림
+        "set_volume" => send_virtual_keys(&[(VK_VOLUME_DOWN, 50), (VK_VOLUME_UP, level / 2)]),
+        // 음소거는 현재 상태와 관계없이 지정한 상태로 (음소거 키는 토글이므로 토글에만 사용)
+        "mute" => set_endpoint_mute("[EndpointVolume]::Mute = $true"),
+        "unmute" => set_endpoint_mute("[EndpointVolume]::Mute = $false"),
+        "toggle_mute" => send_virtual_keys(&[(VK_VOLUME_MUTE, 1)]),
+        "play_pause" => send_virtual_keys(&[(VK_MEDIA_PLAY_PAUSE, 1)]),
+        "next" => send_virtual_keys(&[(VK_MEDIA_NEXT_TRACK, 1)]),
+        "previous" => send_virtual_keys(&[(VK_MEDIA_PREV_TRACK, 1)]),
+        "stop" => send_virtual_keys(&[(VK_MEDIA_STOP, 1)]),
+        _ => Err(format!("UNSUPPORTED_ACTION: {}", action)),
+    }
+}
+
+// macOS: AppleScript 로 볼륨 제어, 재생 제어는 Music 앱 대상
+#[cfg(target_os = "macos")]
+fn perform(action: &str, level: u32) -> Result<(), String> {
+    let script = match action {
+        "set_volume" => format!("set volume output volume {}", level),
+        "mute" => "set volume with output muted".to_string(),
+        "unmute" => "set volume without output muted".to_string(),
+        "toggle_mute" => {
+            "set volume output muted (not (output muted of (get volume settings)))".to_string()
+        }
+        "play_pause" => "tell application \"Music\" to playpause".to_string(),
+        "next" => "tell application \"Music\" to next track".to_string(),
+        "previous" => "tell application \"Music\" to previous track".to_string(),
+        "stop" => "tell application \"Music\" to stop".to_string(),
+        _ => return Err(format!("UNSUPPORTED_ACTION: {}", action)),
+    };
+    os_command::run("osascript", &["-e", &script]).map(|_| ())
+}
+
+// Linux: PulseAudio/PipeWire(pactl) + MPRIS(playerctl)
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn perform(action: &str, level: u32) -> Result<(), String> {
+    let volume = format!("{}%", level);
+    let args: Vec<&str> = match action {
+        "set_volume" => vec!["pactl", "set-sink-volume", "@DEFAULT_SINK@", &volume],
+        "mute" => vec!["pactl", "set-sink-mute", "@DEFAULT_SINK@", "1"],
+        "unmute" => vec!["pactl", "set-sink-mute", "@DEFAULT_SINK@", "0"],
+        "toggle_mute" => vec!["pactl", "set-sink-mute", "@DEFAULT_SINK@", "toggle"],
+        "play_pause" => vec!["playerctl", "play-pause"],
+        "next" => vec!["playerctl", "next"],
+        "previous" => vec!["playerctl", "previous"],
+        "stop" => vec!["playerctl", "stop"],
+        _ => return Err(format!("UNSUPPORTED_ACTION: {}", action)),
+    };
+    os_command::run(args[0], &args[1..]).map(|_| ())
+}
+
+#[tauri::command]
+pub async fn media_control_node(action: String, level: Option<u32>) -> Result<String, String> {
+    let action = action.trim().to_lowercase();
+    println!("🔊 Media Control Node: {} {:?}", action, level);
+
+    if !ACTIONS.contains(&action.as_str()) {
+        return Err(format!("UNSUPPORTED_ACTION: {}", action));
+    }
+
+    let level = match (action.as_str(), level) {
+        ("set_volume", None) => return Err("VOLUME_LEVEL_REQUIRED".to_string()),
+        ("set_volume", Some(level)) if level > 100 => {
+            return Err("VOLUME_LEVEL_OUT_OF_RANGE".to_string())
+        }
+        (_, level) => level.unwrap_or(0),
+    };
+
+    let task_action = action.clone();
+    tokio::task::spawn_blocking(move || perform(&task_action, level))
+        .await
+        .map_err(|e| format!("미디어 제어 실패: {}", e))??;
+
+    Ok(json!({
+        "action": action,
+        "level": if action == "set_volume" { Some(level) } else { None },
+        "success": true
+    })
+    .to_string())
+}
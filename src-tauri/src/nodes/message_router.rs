@@ -0,0 +1,430 @@
+// src-tauri/src/nodes/message_router.rs
+use super::{atomic_store, control_page, event_chunks};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tauri::AppHandle;
+use warp::http::StatusCode;
+use warp::{Filter, Rejection, Reply};
+
+// 🔀 들어온 메시지 라우팅
+// 채팅 / 웹훅 / 이메일로 들어온 메시지를 사용자 규칙(출처, 보낸 사람, 키워드, 정규식)과 순서대로 비교해
+// 맞는 규칙의 대상(워크플로우 또는 노드)으로 "routed-message" 이벤트를 보냄 → 프론트엔드 엔진이 실행
+// 채팅 서버 하나로 여러 자동화를 돌릴 수 있음 (노드마다 서버를 따로 띄우지 않아도 됨)
+// - 규칙은 store/message_routes.json, 위에서부터 비교하고 stop 이 켜진 규칙이 맞으면 거기서 멈춤
+// - 조건은 모두 만족해야 함 (비어 있는 조건은 통과), 키워드는 하나라도 포함되면 통과 (대소문자 무시)
+// - 맞는 규칙이 없으면 원래대로 처리 (채팅은 chat-message-received)
+// 웹훅: 채팅 서버의 POST /hook (웹훅 전용 토큰), 이메일: 파싱 결과를 route_inbound_message 로 전달
+// 워크플로우 대상은 이름(워크플로우 폴더 기준) 또는 절대 경로 → 찾은 파일 경로를 workflow_path 로 함께 보냄
+// (열려 있지 않은 워크플로우면 프론트엔드가 불러와 실행)
+
+pub const SOURCES: &[&str] = &["chat", "webhook", "email"];
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum RouteTarget {
+    Workflow { workflow: String },
+    Node { node_id: String },
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RouteRule {
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    // 비어 있으면 모든 출처
+    #[serde(default)]
+    pub sources: Vec<String>,
+    // 보낸 사람 glob (예: "*@example.com")
+    #[serde(default)]
+    pub sender: Option<String>,
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    #[serde(default)]
+    pub regex: Option<String>,
+    pub target: RouteTarget,
+    #[serde(default = "default_true")]
+    pub stop: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InboundMessage {
+    pub source: String,
+    #[serde(default)]
+    pub sender: Option<String>,
+    #[serde(default)]
+    pub subject: Option<String>,
+    pub text: String,
+    // 메시지를 받은 노드 (채팅 서버 노드 등)
+    #[serde(default)]
+    pub node_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RouteMatch {
+    pub rule_id: String,
+    pub target: RouteTarget,
+    // 정규식 캡처 (이름 있는 그룹은 이름으로, 나머지는 번호로)
+    pub captures: serde_json::Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct RoutedMessage<'a> {
+    rule_id: &'a str,
+    target: &'a RouteTarget,
+    captures: &'a serde_json::Map<String, Value>,
+    message: &'a InboundMessage,
+    workflow_path: Option<String>,
+}
+
+fn routes_path() -> PathBuf {
+    atomic_store::store_dir().join("message_routes.json")
+}
+
+pub fn load_rules() -> Vec<RouteRule> {
+    atomic_store::read_json_verified(&routes_path()).unwrap_or_default()
+}
+
+pub fn validate_rule(rule: &RouteRule) -> Result<(), String> {
+    if rule.id.trim().is_empty() {
+        return Err("INVALID_ROUTE: id 가 비어 있음".to_string());
+    }
+    if let Some(source) = rule.sources.iter().find(|s| !SOURCES.contains(&s.as_str())) {
+        return Err(format!("INVALID_ROUTE_SOURCE: {}", source));
+    }
+    if let Some(pattern) = rule.sender.as_deref() {
+        glob::Pattern::new(pattern).map_err(|e| format!("INVALID_SENDER_PATTERN: {}", e))?;
+    }
+    if let Some(pattern) = rule.regex.as_deref() {
+        Regex::new(pattern).map_err(|e| format!("INVALID_ROUTE_REGEX: {}", e))?;
+    }
+    let target_empty = match &rule.target {
+        RouteTarget::Workflow { workflow } => workflow.trim().is_empty(),
+        RouteTarget::Node { node_id } => node_id.trim().is_empty(),
+    };
+    if target_empty {
+        return Err("INVALID_ROUTE_TARGET".to_string());
+    }
+    Ok(())
+}
+
+// 규칙 하나 비교 (맞으면 정규식 캡처 반환)
+pub fn match_rule(
+    rule: &RouteRule,
+    message: &InboundMessage,
+) -> Option<serde_json::Map<String, Value>> {
+    if !rule.enabled {
+        return None;
+    }
+    if !rule.sources.is_empty() && !rule.sources.iter().any(|s| s == &message.source) {
+        return None;
+    }
+    if let Some(pattern) = rule.sender.as_deref().filter(|p| !p.trim().is_empty()) {
+        let sender = message.sender.as_deref().unwrap_or("").to_lowercase();
+        let pattern = glob::Pattern::new(&pattern.to_lowercase()).ok()?;
+        if !pattern.matches(&sender) {
+            return None;
+        }
+    }
+
+    // 제목이 있으면 본문과 함께 비교
+    let haystack = match message.subject.as_deref() {
+        Some(subject) if !subject.is_empty() => format!("{}\n{}", subject, message.text),
+        _ => message.text.clone(),
+    };
+    let keywords: Vec<&String> = rule.keywords.iter().filter(|k| !k.trim().is_empty()).collect();
+    if !keywords.is_empty() {
+        let lower = haystack.to_lowercase();
+        if !keywords.iter().any(|k| lower.contains(&k.trim().to_lowercase())) {
+            return None;
+        }
+    }
+
+    let mut captures = serde_json::Map::new();
+    if let Some(pattern) = rule.regex.as_deref().filter(|p| !p.is_empty()) {
+        let regex = Regex::new(pattern).ok()?;
+        let found = regex.captures(&haystack)?;
+        let names: Vec<Option<&str>> = regex.capture_names().collect();
+        for (index, group) in found.iter().enumerate() {
+            let Some(group) = group else { continue };
+            let key = names[index]
+                .map(str::to_string)
+                .unwrap_or_else(|| index.to_string());
+            captures.insert(key, json!(group.as_str()));
+        }
+    }
+    Some(captures)
+}
+
+pub fn match_rules(rules: &[RouteRule], message: &InboundMessage) -> Vec<RouteMatch> {
+    let mut matches = Vec::new();
+    for rule in rules {
+        if let Some(captures) = match_rule(rule, message) {
+            matches.push(RouteMatch {
+                rule_id: rule.id.clone(),
+                target: rule.target.clone(),
+                captures,
+            });
+            if rule.stop {
+                break;
+            }
+        }
+    }
+    matches
+}
+
+// 워크플로우 대상 → 파일 경로 (절대 경로는 그대로, 이름은 워크플로우 폴더에서 검색)
+pub fn resolve_workflow_path(workflow: &str, dir: &Path) -> Option<String> {
+    let workflow = workflow.trim();
+    let path = Path::new(workflow);
+    if path.is_absolute() {
+        return control_page::is_workflow_file(path).then(|| workflow.to_string());
+    }
+    control_page::list_workflows(dir)
+        .into_iter()
+        .find(|entry| entry["name"] == workflow || entry["path"] == workflow)
+        .and_then(|entry| entry["path"].as_str().map(|relative| dir.join(relative)))
+        .map(|path| path.to_string_lossy().to_string())
+}
+
+// 규칙에 맞으면 대상마다 이벤트 전송 (맞는 규칙이 없으면 빈 목록 - 호출한 쪽이 기본 처리)
+pub fn route_message(app_handle: &AppHandle, message: &InboundMessage) -> Vec<RouteMatch> {
+    let matches = match_rules(&load_rules(), message);
+    for found in &matches {
+        let event = RoutedMessage {
+            rule_id: &found.rule_id,
+            target: &found.target,
+            captures: &found.captures,
+            message,
+            workflow_path: match &found.target {
+                RouteTarget::Workflow { workflow } => {
+                    resolve_workflow_path(workflow, &control_page::default_workflows_dir())
+                }
+                RouteTarget::Node { .. } => None,
+            },
+        };
+        match event_chunks::emit_chunked(app_handle, "routed-message", &event) {
+            Ok(()) => println!("🔀 {} 메시지 → 규칙 {}", message.source, found.rule_id),
+            Err(e) => eprintln!("❌ Failed to emit routed message: {}", e),
+        }
+    }
+    matches
+}
+
+// 🪝 POST /hook (JSON {text|message, sender, subject} 또는 일반 텍스트 본문)
+pub fn webhook_route(
+    app_handle: AppHandle,
+    token: Option<String>,
+) -> impl Filter<Extract = (Box<dyn Reply>,), Error = Rejection> + Clone {
+    let token = token.filter(|t| !t.trim().is_empty()).map(Arc::new);
+    warp::path("hook")
+        .and(warp::path::end())
+        .and(warp::post())
+        .and(control_page::authorized(token))
+        .and(warp::body::content_length_limit(1024 * 1024))
+        .and(warp::body::bytes())
+        .map(move |body: warp::hyper::body::Bytes| {
+            let message = webhook_message(&body);
+            let matches = route_message(&app_handle, &message);
+            let (status, code) = if matches.is_empty() {
+                ("unrouted", StatusCode::NOT_FOUND)
+            } else {
+                ("routed", StatusCode::ACCEPTED)
+            };
+            Box::new(warp::reply::with_status(
+                warp::reply::json(&json!({ "status": status, "matches": matches })),
+                code,
+            )) as Box<dyn Reply>
+        })
+        .recover(control_page::handle_rejection)
+        .unify()
+}
+
+pub fn webhook_message(body: &[u8]) -> InboundMessage {
+    let text = String::from_utf8_lossy(body).to_string();
+    let field = |value: &Value, key: &str| value[key].as_str().map(str::to_string);
+    match serde_json::from_str::<Value>(&text) {
+        Ok(value) if value.is_object() => InboundMessage {
+            source: "webhook".to_string(),
+            sender: field(&value, "sender").or_else(|| field(&value, "from")),
+            subject: field(&value, "subject"),
+            text: field(&value, "text")
+                .or_else(|| field(&value, "message"))
+                .unwrap_or(text),
+            node_id: None,
+        },
+        _ => InboundMessage {
+            source: "webhook".to_string(),
+            text,
+            ..Default::default()
+        },
+    }
+}
+
+#[tauri::command]
+pub fn list_message_routes() -> Result<Vec<RouteRule>, String> {
+    Ok(load_rules())
+}
+
+// 같은 id 면 교체 (순서 유지), 없으면 맨 뒤에 추가
+#[tauri::command]
+pub fn save_message_route(rule: RouteRule) -> Result<Vec<RouteRule>, String> {
+    validate_rule(&rule)?;
+    let mut rules = load_rules();
+    match rules.iter_mut().find(|r| r.id == rule.id) {
+        Some(existing) => *existing = rule,
+        None => rules.push(rule),
+    }
+    atomic_store::write_json_atomic(&routes_path(), &rules)?;
+    Ok(rules)
+}
+
+#[tauri::command]
+pub fn delete_message_route(id: String) -> Result<Vec<RouteRule>, String> {
+    let mut rules = load_rules();
+    let before = rules.len();
+    rules.retain(|r| r.id != id);
+    if rules.len() == before {
+        return Err(format!("ROUTE_NOT_FOUND: {}", id));
+    }
+    atomic_store::write_json_atomic(&routes_path(), &rules)?;
+    Ok(rules)
+}
+
+// 규칙 순서 변경 (ids 에 없는 규칙은 뒤에 그대로)
+#[tauri::command]
+pub fn reorder_message_routes(ids: Vec<String>) -> Result<Vec<RouteRule>, String> {
+    let mut rules = load_rules();
+    rules.sort_by_key(|r| ids.iter().position(|id| id == &r.id).unwrap_or(usize::MAX));
+    atomic_store::write_json_atomic(&routes_path(), &rules)?;
+    Ok(rules)
+}
+
+// 실행하지 않고 어떤 규칙에 걸리는지만 확인
+#[tauri::command]
+pub fn test_message_route(message: InboundMessage) -> Result<Vec<RouteMatch>, String> {
+    Ok(match_rules(&load_rules(), &message))
+}
+
+// 이메일 등 프론트엔드에서 받은 메시지 라우팅
+#[tauri::command]
+pub fn route_inbound_message(
+    app_handle: AppHandle,
+    message: InboundMessage,
+) -> Result<Vec<RouteMatch>, String> {
+    if !SOURCES.contains(&message.source.as_str()) {
+        return Err(format!("INVALID_ROUTE_SOURCE: {}", message.source));
+    }
+    Ok(route_message(&app_handle, &message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn route_rule(value: serde_json::Value) -> RouteRule {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn message_router_matches_rules_in_order() {
+        let rules = vec![
+            route_rule(json!({
+                "id": "invoice",
+                "sources": ["email"],
+                "sender": "*@billing.example.com",
+                "keywords": ["invoice"],
+                "target": { "kind": "workflow", "workflow": "invoices.json" }
+            })),
+            route_rule(json!({
+                "id": "download",
+                "regex": r"^dl (?P<url>https?://\S+)",
+                "target": { "kind": "node", "node_id": "yt-1" }
+            })),
+            route_rule(json!({
+                "id": "log-all",
+                "stop": false,
+                "target": { "kind": "workflow", "workflow": "log.json" }
+            })),
+        ];
+        for rule in &rules {
+            validate_rule(rule).unwrap();
+        }
+
+        let email = InboundMessage {
+            source: "email".to_string(),
+            sender: Some("Bot@Billing.example.com".to_string()),
+            subject: Some("Your INVOICE".to_string()),
+            text: "attached".to_string(),
+            node_id: None,
+        };
+        let matches = match_rules(&rules, &email);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].rule_id, "invoice");
+
+        let chat = InboundMessage {
+            source: "chat".to_string(),
+            text: "dl https://example.com/v".to_string(),
+            ..Default::default()
+        };
+        let matches = match_rules(&rules, &chat);
+        assert_eq!(matches[0].rule_id, "download");
+        assert_eq!(matches[0].captures["url"], "https://example.com/v");
+
+        // 채팅이지만 다른 규칙에 안 맞으면 stop 이 꺼진 규칙만
+        let other = InboundMessage {
+            source: "chat".to_string(),
+            text: "hello".to_string(),
+            ..Default::default()
+        };
+        let ids: Vec<_> = match_rules(&rules, &other)
+            .into_iter()
+            .map(|m| m.rule_id)
+            .collect();
+        assert_eq!(ids, vec!["log-all"]);
+    }
+
+    #[test]
+    fn message_router_rejects_bad_rules_and_parses_webhooks() {
+        let bad = route_rule(json!({
+            "id": "x",
+            "regex": "(",
+            "target": { "kind": "workflow", "workflow": "a.json" }
+        }));
+        assert!(validate_rule(&bad).unwrap_err().starts_with("INVALID_ROUTE_REGEX"));
+        let bad = route_rule(json!({
+            "id": "x",
+            "sources": ["sms"],
+            "target": { "kind": "workflow", "workflow": "a.json" }
+        }));
+        assert!(validate_rule(&bad).unwrap_err().starts_with("INVALID_ROUTE_SOURCE"));
+
+        let message = webhook_message(br#"{"from":"ci","message":"build failed"}"#);
+        assert_eq!(message.source, "webhook");
+        assert_eq!(message.sender.as_deref(), Some("ci"));
+        assert_eq!(message.text, "build failed");
+        assert_eq!(webhook_message(b"plain text").text, "plain text");
+    }
+
+    #[test]
+    fn message_router_resolves_workflow_targets() {
+        let dir = crate::nodes::test_support::temp_dir();
+        std::fs::create_dir_all(dir.path().join("team")).unwrap();
+        let nested = dir.path().join("team").join("backup.flow.json");
+        std::fs::write(&nested, "{}").unwrap();
+
+        let expected = nested.to_string_lossy().to_string();
+        assert_eq!(resolve_workflow_path("backup", dir.path()), Some(expected.clone()));
+        assert_eq!(resolve_workflow_path(&expected, dir.path()), Some(expected));
+        assert_eq!(resolve_workflow_path("missing", dir.path()), None);
+    }
+}
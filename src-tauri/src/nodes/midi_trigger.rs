@@ -0,0 +1,452 @@
+// src-tauri/src/nodes/midi_trigger.rs
+use super::{control_page, instance};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter};
+use tokio::sync::{mpsc, RwLock};
+
+// 🎛️ MIDI / Stream Deck 버튼 트리거
+// 컨트롤 서피스(MIDI 패드·노브, Elgato Stream Deck HID)의 버튼 입력을 "midi-trigger" 이벤트로 전달하고
+// 매핑(bindings)에 연결된 워크플로우를 바로 실행 요청 (외부 연동 프로그램 없이 물리 버튼으로 실행)
+// 매핑이 없는 입력도 이벤트로 나가므로 프론트엔드에서 "버튼 학습"에 사용
+
+const ELGATO_VENDOR_ID: u16 = 0x0fd9;
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ButtonEvent {
+    // "note" | "cc" | "program" | "button"(Stream Deck)
+    pub kind: String,
+    // MIDI 채널 1-16 (Stream Deck 은 0)
+    pub channel: u8,
+    pub number: u8,
+    pub value: u8,
+    pub pressed: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Binding {
+    // 비우면 종류 무관
+    #[serde(default)]
+    pub kind: Option<String>,
+    #[serde(default)]
+    pub channel: Option<u8>,
+    pub number: u8,
+    // 실행할 워크플로우 (상대 경로는 store/workflows 기준)
+    pub workflow: String,
+    // true 면 버튼을 뗄 때 실행
+    #[serde(default)]
+    pub on_release: bool,
+}
+
+#[derive(Debug, Serialize, Clone)]
+struct MidiTriggerEvent {
+    node_id: String,
+    source: String,
+    device: String,
+    #[serde(flatten)]
+    button: ButtonEvent,
+    workflow: Option<String>,
+    timestamp: u64,
+}
+
+struct MidiTriggerHandle {
+    source: String,
+    device: String,
+    bindings: usize,
+    stop: Arc<AtomicBool>,
+    abort_handle: tokio::task::AbortHandle,
+}
+
+type MidiTriggerRegistry = Arc<RwLock<HashMap<String, MidiTriggerHandle>>>;
+
+static MIDI_TRIGGER_REGISTRY: std::sync::OnceLock<MidiTriggerRegistry> = std::sync::OnceLock::new();
+
+fn get_midi_trigger_registry() -> &'static MidiTriggerRegistry {
+    MIDI_TRIGGER_REGISTRY.get_or_init(|| Arc::new(RwLock::new(HashMap::new())))
+}
+
+// MIDI 채널 메시지 해석 (Note On velocity 0 은 Note Off 로 취급)
+pub fn parse_midi_message(message: &[u8]) -> Option<ButtonEvent> {
+    let status = *message.first()?;
+    let channel = (status & 0x0F) + 1;
+    let data1 = message.get(1).copied().unwrap_or(0) & 0x7F;
+    let data2 = message.get(2).copied().unwrap_or(0) & 0x7F;
+    let (kind, value) = match status & 0xF0 {
+        0x90 => ("note", data2),
+        0x80 => ("note", 0),
+        0xB0 => ("cc", data2),
+        // 프로그램 체인지는 누름만 있음
+        0xC0 => ("program", 127),
+        _ => return None,
+    };
+    Some(ButtonEvent {
+        kind: kind.to_string(),
+        channel,
+        number: data1,
+        value,
+        pressed: value > 0,
+    })
+}
+
+// Stream Deck 입력 리포트에서 키 상태가 시작되는 위치 (구형/Mini 는 1, 그 외 4바이트 헤더)
+pub fn streamdeck_key_offset(product_id: u16) -> usize {
+    match product_id {
+        0x0060 | 0x0063 | 0x0090 => 1,
+        _ => 4,
+    }
+}
+
+// 이전 키 상태와 비교해 바뀐 키만 이벤트로
+pub fn streamdeck_changes(previous: &[bool], report: &[u8], offset: usize) -> Vec<ButtonEvent> {
+    if report.first() != Some(&0x01) {
+        return Vec::new();
+    }
+    report
+        .iter()
+        .skip(offset)
+        .enumerate()
+        .filter(|(index, state)| previous.get(*index).copied().unwrap_or(false) != (**state != 0))
+        .map(|(index, state)| ButtonEvent {
+            kind: "button".to_string(),
+            channel: 0,
+            number: index as u8,
+            value: if *state != 0 { 127 } else { 0 },
+            pressed: *state != 0,
+        })
+        .collect()
+}
+
+pub fn binding_matches(binding: &Binding, event: &ButtonEvent) -> bool {
+    binding
+        .kind
+        .as_deref()
+        .is_none_or(|kind| kind.eq_ignore_ascii_case(&event.kind))
+        && binding.channel.is_none_or(|ch| ch == event.channel)
+        && binding.number == event.number
+        && event.pressed != binding.on_release
+}
+
+fn resolve_workflow(workflow: &str) -> PathBuf {
+    let path = Path::new(workflow);
+    if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        control_page::default_workflows_dir().join(path)
+    }
+}
+
+fn matches_device(name: &str, filter: &str) -> bool {
+    filter.is_empty() || name.to_lowercase().contains(&filter.to_lowercase())
+}
+
+// MIDI 입력 포트 연결 (MidiInput 은 스레드 간 이동이 안 되는 백엔드가 있어 연결 스레드 안에서 생성)
+fn spawn_midi_reader(
+    device: &str,
+    stop: Arc<AtomicBool>,
+    events: mpsc::UnboundedSender<ButtonEvent>,
+) -> Result<String, String> {
+    let device = device.to_string();
+    let (ready_tx, ready_rx) = std::sync::mpsc::channel::<Result<String, String>>();
+    std::thread::spawn(move || {
+        let connect = || {
+            let input = midir::MidiInput::new("automation-gui").map_err(|e| e.to_string())?;
+            let port = input
+                .ports()
+                .into_iter()
+                .find(|port| {
+                    input
+                        .port_name(port)
+                        .is_ok_and(|name| matches_device(&name, &device))
+                })
+                .ok_or_else(|| format!("MIDI_DEVICE_NOT_FOUND: {}", device))?;
+            let port_name = input.port_name(&port).unwrap_or_default();
+            let connection = input
+                .connect(
+                    &port,
+                    "automation-gui-trigger",
+                    move |_, message, _| {
+                        if let Some(event) = parse_midi_message(message) {
+                            let _ = events.send(event);
+                        }
+                    },
+                    (),
+                )
+                .map_err(|e| format!("MIDI 연결 실패: {}", e))?;
+            Ok::<_, String>((port_name, connection))
+        };
+        match connect() {
+            Ok((port_name, connection)) => {
+                let _ = ready_tx.send(Ok(port_name));
+                while !stop.load(Ordering::Relaxed) {
+                    std::thread::sleep(std::time::Duration::from_millis(200));
+                }
+                connection.close();
+            }
+            Err(e) => {
+                let _ = ready_tx.send(Err(e));
+            }
+        }
+    });
+    ready_rx
+        .recv()
+        .map_err(|e| format!("MIDI 연결 실패: {}", e))?
+}
+
+// Stream Deck (HID) 읽기 스레드
+fn spawn_streamdeck_reader(
+    device: &str,
+    stop: Arc<AtomicBool>,
+    events: mpsc::UnboundedSender<ButtonEvent>,
+) -> Result<String, String> {
+    let api = hidapi::HidApi::new().map_err(|e| format!("HID 초기화 실패: {}", e))?;
+    let info = api
+        .device_list()
+        .filter(|info| info.vendor_id() == ELGATO_VENDOR_ID)
+        .find(|info| {
+            matches_device(info.product_string().unwrap_or(""), device)
+                || info.serial_number().is_some_and(|s| s == device)
+        })
+        .ok_or_else(|| format!("STREAM_DECK_NOT_FOUND: {}", device))?;
+    let name = info.product_string().unwrap_or("Stream Deck").to_string();
+    let offset = streamdeck_key_offset(info.product_id());
+    let hid = info
+        .open_device(&api)
+        .map_err(|e| format!("Stream Deck 열기 실패: {}", e))?;
+
+    std::thread::spawn(move || {
+        let mut buffer = [0u8; 512];
+        let mut keys: Vec<bool> = Vec::new();
+        while !stop.load(Ordering::Relaxed) {
+            let read = match hid.read_timeout(&mut buffer, 200) {
+                Ok(read) => read,
+                Err(e) => {
+                    println!("⚠️ Stream Deck 읽기 실패: {}", e);
+                    break;
+                }
+            };
+            if read == 0 {
+                continue;
+            }
+            let report = &buffer[..read];
+            for event in streamdeck_changes(&keys, report, offset) {
+                let _ = events.send(event);
+            }
+            keys = report
+                .iter()
+                .skip(offset)
+                .map(|state| *state != 0)
+                .collect();
+        }
+    });
+    Ok(name)
+}
+
+async fn run_dispatch_loop(
+    app_handle: AppHandle,
+    node_id: String,
+    source: String,
+    device: String,
+    bindings: Vec<Binding>,
+    mut events: mpsc::UnboundedReceiver<ButtonEvent>,
+) {
+    while let Some(button) = events.recv().await {
+        let workflow = bindings
+            .iter()
+            .find(|binding| binding_matches(binding, &button))
+            .map(|binding| binding.workflow.clone());
+
+        if let Some(workflow) = &workflow {
+            println!(
+                "🎛️ 버튼 트리거 (node {}): {} {} → {}",
+                node_id, button.kind, button.number, workflow
+            );
+            if let Err(e) = instance::request_workflow_run(&app_handle, &resolve_workflow(workflow))
+            {
+                eprintln!("❌ {}", e);
+            }
+        }
+
+        let event = MidiTriggerEvent {
+            node_id: node_id.clone(),
+            source: source.clone(),
+            device: device.clone(),
+            button,
+            workflow,
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as u64,
+        };
+        if let Err(e) = app_handle.emit("midi-trigger", &event) {
+            eprintln!("❌ Failed to emit MIDI trigger event: {}", e);
+        }
+    }
+}
+
+// 연결 가능한 MIDI 입력 포트 / Stream Deck 목록
+#[tauri::command]
+pub async fn list_control_surfaces() -> Result<String, String> {
+    tokio::task::spawn_blocking(|| {
+        let midi: Vec<String> = midir::MidiInput::new("automation-gui")
+            .map(|input| {
+                input
+                    .ports()
+                    .iter()
+                    .filter_map(|port| input.port_name(port).ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+        let streamdeck: Vec<serde_json::Value> = hidapi::HidApi::new()
+            .map(|api| {
+                api.device_list()
+                    .filter(|info| info.vendor_id() == ELGATO_VENDOR_ID)
+                    .map(|info| {
+                        serde_json::json!({
+                            "name": info.product_string().unwrap_or("Stream Deck"),
+                            "serial": info.serial_number()
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        serde_json::json!({ "midi": midi, "streamdeck": streamdeck }).to_string()
+    })
+    .await
+    .map_err(|e| format!("컨트롤러 목록 조회 실패: {}", e))
+}
+
+#[tauri::command]
+pub async fn start_midi_trigger(
+    app_handle: AppHandle,
+    node_id: String,
+    source: Option<String>,
+    device: Option<String>,
+    bindings: Option<Vec<Binding>>,
+) -> Result<String, String> {
+    let source = source
+        .map(|s| s.trim().to_lowercase())
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| "midi".to_string());
+    let device = device.map(|d| d.trim().to_string()).unwrap_or_default();
+    let bindings = bindings.unwrap_or_default();
+
+    // 같은 노드의 기존 트리거는 교체
+    let _ = stop_midi_trigger(node_id.clone()).await;
+
+    let stop = Arc::new(AtomicBool::new(false));
+    let (tx, rx) = mpsc::unbounded_channel();
+    let reader_stop = stop.clone();
+    let reader_source = source.clone();
+    let device_name = tokio::task::spawn_blocking(move || match reader_source.as_str() {
+        "midi" => spawn_midi_reader(&device, reader_stop, tx),
+        "streamdeck" | "hid" => spawn_streamdeck_reader(&device, reader_stop, tx),
+        other => Err(format!("UNSUPPORTED_SOURCE: {}", other)),
+    })
+    .await
+    .map_err(|e| format!("컨트롤러 연결 실패: {}", e))??;
+
+    let task = tokio::spawn(run_dispatch_loop(
+        app_handle,
+        node_id.clone(),
+        source.clone(),
+        device_name.clone(),
+        bindings.clone(),
+        rx,
+    ));
+
+    get_midi_trigger_registry().write().await.insert(
+        node_id.clone(),
+        MidiTriggerHandle {
+            source,
+            device: device_name.clone(),
+            bindings: bindings.len(),
+            stop,
+            abort_handle: task.abort_handle(),
+        },
+    );
+
+    println!(
+        "🎛️ 버튼 트리거 시작 (node {}): {} / 매핑 {}개",
+        node_id,
+        device_name,
+        bindings.len()
+    );
+    Ok(serde_json::json!({ "device": device_name, "bindings": bindings.len() }).to_string())
+}
+
+#[tauri::command]
+pub async fn stop_midi_trigger(node_id: String) -> Result<String, String> {
+    match get_midi_trigger_registry().write().await.remove(&node_id) {
+        Some(handle) => {
+            handle.stop.store(true, Ordering::Relaxed);
+            handle.abort_handle.abort();
+            println!("🛑 버튼 트리거 중지 (node {})", node_id);
+            Ok("MIDI trigger stopped".to_string())
+        }
+        None => Ok("No MIDI trigger running for this node".to_string()),
+    }
+}
+
+#[tauri::command]
+pub async fn get_midi_trigger_status(node_id: String) -> Result<serde_json::Value, String> {
+    let triggers = get_midi_trigger_registry().read().await;
+
+    Ok(match triggers.get(&node_id) {
+        Some(handle) => serde_json::json!({
+            "running": !handle.abort_handle.is_finished(),
+            "source": handle.source,
+            "device": handle.device,
+            "bindings": handle.bindings
+        }),
+        None => serde_json::json!({ "running": false }),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn midi_trigger_parses_messages_and_matches_bindings() {
+        let press = parse_midi_message(&[0x91, 36, 100]).unwrap();
+        assert_eq!(press.kind, "note");
+        assert_eq!(press.channel, 2);
+        assert_eq!(press.number, 36);
+        assert!(press.pressed);
+
+        // Note On velocity 0 = 뗌
+        let release = parse_midi_message(&[0x91, 36, 0]).unwrap();
+        assert!(!release.pressed);
+        assert!(parse_midi_message(&[0xF8]).is_none());
+
+        let binding: Binding =
+            serde_json::from_value(json!({ "kind": "note", "number": 36, "workflow": "stream.json" }))
+                .unwrap();
+        assert!(binding_matches(&binding, &press));
+        assert!(!binding_matches(&binding, &release));
+
+        let cc = parse_midi_message(&[0xB0, 36, 127]).unwrap();
+        assert!(!binding_matches(&binding, &cc));
+    }
+
+    #[test]
+    fn midi_trigger_reports_only_changed_stream_deck_keys() {
+        let offset = streamdeck_key_offset(0x0080);
+        assert_eq!(offset, 4);
+
+        let report = [0x01, 0x00, 0x0F, 0x00, 0, 1, 0, 0];
+        let changes = streamdeck_changes(&[], &report, offset);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].number, 1);
+        assert!(changes[0].pressed);
+
+        let released = [0x01, 0x00, 0x0F, 0x00, 0, 0, 0, 0];
+        let changes = streamdeck_changes(&[false, true, false, false], &released, offset);
+        assert_eq!(changes.len(), 1);
+        assert!(!changes[0].pressed);
+    }
+}
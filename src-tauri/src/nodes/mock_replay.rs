@@ -0,0 +1,160 @@
+// src-tauri/src/nodes/mock_replay.rs
+use serde_json::{json, Map, Value};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::RwLock;
+
+use super::{atomic_store, workflow_signing};
+
+// 외부 서비스(HTTP, AI, 이메일) 응답 녹화/재생 모드
+// - off: 실제 호출만
+// - record: 실제 호출 후 응답을 store/mocks/<service>.json에 저장
+// - replay: 저장된 응답만 사용 (오프라인 개발, API 쿼터 절약)
+// 녹화 키는 요청을 키 정렬한 JSON 의 SHA-256 (빌드/실행이 바뀌어도 같은 요청이면 같은 키)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MockMode {
+    Off,
+    Record,
+    Replay,
+}
+
+impl MockMode {
+    fn as_str(&self) -> &'static str {
+        match self {
+            MockMode::Off => "off",
+            MockMode::Record => "record",
+            MockMode::Replay => "replay",
+        }
+    }
+}
+
+static MOCK_MODE: RwLock<MockMode> = RwLock::new(MockMode::Off);
+
+pub fn current_mode() -> MockMode {
+    MOCK_MODE.read().map(|mode| *mode).unwrap_or(MockMode::Off)
+}
+
+pub fn recording_key(service: &str, request: &Value) -> String {
+    let digest = Sha256::digest(workflow_signing::canonical_json(request).as_bytes());
+    let hex: String = digest.iter().map(|b| format!("{:02x}", b)).collect();
+    format!("{}:{}", service, hex)
+}
+
+fn get_mock_file_path(service: &str) -> PathBuf {
+    atomic_store::store_dir()
+        .join("mocks")
+        .join(format!("{}.json", service))
+}
+
+fn load_recordings(service: &str) -> Map<String, Value> {
+    atomic_store::read_json_verified(&get_mock_file_path(service)).unwrap_or_default()
+}
+
+fn save_recordings(service: &str, recordings: &Map<String, Value>) -> Result<(), String> {
+    atomic_store::write_json_atomic(&get_mock_file_path(service), recordings)
+}
+
+// replay 모드면 녹화된 응답 반환, 녹화가 없으면 에러 (실제 호출로 넘어가지 않음)
+pub fn replay(service: &str, request: &Value) -> Result<Option<Value>, String> {
+    if current_mode() != MockMode::Replay {
+        return Ok(None);
+    }
+
+    let key = recording_key(service, request);
+    match load_recordings(service).remove(&key) {
+        Some(entry) => {
+            println!("🎭 Mock replay: {} ({})", service, key);
+            Ok(entry.get("response").cloned())
+        }
+        None => Err(format!("MOCK_RECORDING_NOT_FOUND: {} ({})", service, key)),
+    }
+}
+
+// record 모드일 때만 응답 저장
+pub fn record(service: &str, request: &Value, response: &Value) {
+    if current_mode() != MockMode::Record {
+        return;
+    }
+
+    let key = recording_key(service, request);
+    let mut recordings = load_recordings(service);
+    recordings.insert(
+        key.clone(),
+        json!({
+            "request": request,
+            "response": response,
+            "recorded_at": chrono::Local::now().to_rfc3339()
+        }),
+    );
+
+    match save_recordings(service, &recordings) {
+        Ok(_) => println!("🎙️ Mock recorded: {} ({})", service, key),
+        Err(e) => println!("⚠️ Mock 녹화 실패: {}", e),
+    }
+}
+
+#[tauri::command]
+pub fn set_mock_mode(mode: String) -> Result<String, String> {
+    let new_mode = match mode.trim().to_lowercase().as_str() {
+        "off" => MockMode::Off,
+        "record" => MockMode::Record,
+        "replay" => MockMode::Replay,
+        other => return Err(format!("INVALID_MOCK_MODE: {}", other)),
+    };
+
+    let mut current = MOCK_MODE
+        .write()
+        .map_err(|_| "MOCK_MODE_LOCK_ERROR".to_string())?;
+    *current = new_mode;
+
+    println!("🎭 Mock mode: {}", new_mode.as_str());
+    Ok(new_mode.as_str().to_string())
+}
+
+#[tauri::command]
+pub fn get_mock_mode() -> Result<String, String> {
+    Ok(current_mode().as_str().to_string())
+}
+
+#[tauri::command]
+pub fn clear_mock_recordings(service: Option<String>) -> Result<String, String> {
+    match service.as_deref().map(str::trim) {
+        Some(name) if !name.is_empty() => {
+            let file_path = get_mock_file_path(name);
+            atomic_store::remove_with_sidecars(&file_path)
+                .map_err(|e| format!("Failed to clear recordings: {}", e))?;
+            Ok(format!("Recordings cleared for {}", name))
+        }
+        _ => {
+            let mock_file = get_mock_file_path("_");
+            let mocks_dir = mock_file.parent().unwrap_or(&mock_file);
+            if mocks_dir.exists() {
+                fs::remove_dir_all(mocks_dir)
+                    .map_err(|e| format!("Failed to clear recordings: {}", e))?;
+            }
+            Ok("All recordings cleared".to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn mock_replay_recording_key_is_stable_sha256() {
+        let key = recording_key("claude", &json!({ "b": 2, "a": 1 }));
+        assert_eq!(
+            key,
+            recording_key("claude", &json!({ "a": 1, "b": 2 }))
+        );
+        // 키 정렬 JSON {"a":1,"b":2} 의 SHA-256 (실행마다 달라지는 해시가 아님)
+        assert_eq!(
+            key,
+            "claude:43258cff783fe7036d8a43033f830adfc60ec037382473548ac742b888292777"
+        );
+        assert_ne!(key, recording_key("soap", &json!({ "a": 1, "b": 2 })));
+    }
+}
@@ -0,0 +1,407 @@
+// src-tauri/src/nodes/mock_server_node.rs
+use super::template;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Emitter};
+use tokio::sync::RwLock;
+use warp::http::{Response, StatusCode};
+use warp::Filter;
+
+// 🧪 HTTP 목(mock) 서버 노드
+// 지정한 경로에 미리 정해 둔 JSON 응답을 돌려주는 API 스텁 (프론트엔드 개발용)
+// - 경로 패턴: /api/users/:id (이름 붙은 세그먼트), /static/* (나머지 전부)
+// - 응답 본문의 문자열 안 {{params.id}} / {{query.page}} / {{body.name}} 은 요청 값으로 치환
+// - 모든 요청은 기록되고 "mock-server-request" 이벤트로 전달
+// 기본은 127.0.0.1 에만 열림 (bind_all 이면 같은 네트워크에서 접근 가능)
+
+const MAX_LOG_ENTRIES: usize = 200;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MockRoute {
+    // GET/POST/... 또는 "*"(모든 메서드)
+    #[serde(default = "default_method")]
+    pub method: String,
+    pub path: String,
+    #[serde(default = "default_status")]
+    pub status: u16,
+    #[serde(default)]
+    pub body: Value,
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    // 느린 API 흉내 (ms)
+    #[serde(default)]
+    pub delay_ms: u64,
+}
+
+fn default_method() -> String {
+    "*".to_string()
+}
+
+fn default_status() -> u16 {
+    200
+}
+
+struct MockServerHandle {
+    port: u16,
+    url: String,
+    routes: Arc<RwLock<Vec<MockRoute>>>,
+    log: Arc<Mutex<VecDeque<Value>>>,
+    abort_handle: tokio::task::AbortHandle,
+}
+
+type MockServerRegistry = Arc<RwLock<HashMap<String, MockServerHandle>>>;
+
+static MOCK_SERVER_REGISTRY: std::sync::OnceLock<MockServerRegistry> = std::sync::OnceLock::new();
+
+fn get_mock_server_registry() -> &'static MockServerRegistry {
+    MOCK_SERVER_REGISTRY.get_or_init(|| Arc::new(RwLock::new(HashMap::new())))
+}
+
+pub fn validate_routes(routes: Vec<MockRoute>) -> Result<Vec<MockRoute>, String> {
+    routes
+        .into_iter()
+        .map(|mut route| {
+            route.method = route.method.trim().to_uppercase();
+            if !route.path.starts_with('/') {
+                return Err(format!("INVALID_MOCK_ROUTE: {}", route.path));
+            }
+            if StatusCode::from_u16(route.status).is_err() {
+                return Err(format!("INVALID_MOCK_STATUS: {}", route.status));
+            }
+            Ok(route)
+        })
+        .collect()
+}
+
+// 경로 패턴 매칭, 성공하면 :이름 세그먼트 값 반환 (* 는 "wildcard" 로)
+pub fn match_path(pattern: &str, path: &str) -> Option<Map<String, Value>> {
+    let pattern: Vec<&str> = pattern.split('/').filter(|s| !s.is_empty()).collect();
+    let path: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+    let mut params = Map::new();
+
+    for (index, segment) in pattern.iter().enumerate() {
+        if *segment == "*" {
+            params.insert(
+                "wildcard".to_string(),
+                json!(path[index.min(path.len())..].join("/")),
+            );
+            return Some(params);
+        }
+        let actual = path.get(index)?;
+        match segment.strip_prefix(':') {
+            Some(name) => {
+                params.insert(name.to_string(), json!(actual));
+            }
+            None if segment == actual => {}
+            None => return None,
+        }
+    }
+    (pattern.len() == path.len()).then_some(params)
+}
+
+pub fn find_route<'a>(
+    routes: &'a [MockRoute],
+    method: &str,
+    path: &str,
+) -> Option<(&'a MockRoute, Map<String, Value>)> {
+    routes.iter().find_map(|route| {
+        (route.method == "*" || route.method.eq_ignore_ascii_case(method))
+            .then(|| match_path(&route.path, path))
+            .flatten()
+            .map(|params| (route, params))
+    })
+}
+
+// 본문 안 문자열의 {{...}} 치환 ("{{body}}" 처럼 자리표시자 하나뿐이면 값의 타입을 그대로 유지)
+pub fn render_body(body: &Value, context: &Value) -> Value {
+    match body {
+        Value::String(text) => {
+            let trimmed = text.trim();
+            let single = trimmed
+                .strip_prefix("{{")
+                .and_then(|rest| rest.strip_suffix("}}"))
+                .filter(|inner| !inner.contains("{{") && !inner.contains("}}"));
+            match single.and_then(|path| template::lookup(context, path.trim())) {
+                Some(value) => value.clone(),
+                None => Value::String(template::render(text, context)),
+            }
+        }
+        Value::Array(items) => {
+            Value::Array(items.iter().map(|v| render_body(v, context)).collect())
+        }
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, value)| (key.clone(), render_body(value, context)))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+fn parse_query(query: &str) -> Map<String, Value> {
+    url::form_urlencoded::parse(query.as_bytes())
+        .map(|(key, value)| (key.to_string(), json!(value)))
+        .collect()
+}
+
+fn parse_request_body(bytes: &[u8]) -> Value {
+    if bytes.is_empty() {
+        return Value::Null;
+    }
+    serde_json::from_slice(bytes)
+        .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(bytes).to_string()))
+}
+
+fn cors(builder: warp::http::response::Builder) -> warp::http::response::Builder {
+    builder
+        .header("access-control-allow-origin", "*")
+        .header("access-control-allow-methods", "*")
+        .header("access-control-allow-headers", "*")
+}
+
+struct MockContext {
+    app_handle: AppHandle,
+    node_id: String,
+    routes: Arc<RwLock<Vec<MockRoute>>>,
+    log: Arc<Mutex<VecDeque<Value>>>,
+}
+
+async fn handle_request(
+    context: Arc<MockContext>,
+    method: warp::http::Method,
+    path: warp::path::FullPath,
+    query: String,
+    body: warp::hyper::body::Bytes,
+) -> Result<Response<String>, warp::Rejection> {
+    let method = method.as_str().to_string();
+    let path = path.as_str().to_string();
+    let query = parse_query(&query);
+    let request_body = parse_request_body(&body);
+
+    let matched = {
+        let routes = context.routes.read().await;
+        find_route(&routes, &method, &path).map(|(route, params)| (route.clone(), params))
+    };
+
+    let (status, response) = match matched {
+        Some((route, params)) => {
+            if route.delay_ms > 0 {
+                tokio::time::sleep(std::time::Duration::from_millis(route.delay_ms)).await;
+            }
+            let template_context =
+                json!({ "params": params, "query": query, "body": request_body });
+            let rendered = render_body(&route.body, &template_context);
+            let (content_type, text) = match &rendered {
+                Value::String(text) => ("text/plain; charset=utf-8", text.clone()),
+                other => ("application/json", other.to_string()),
+            };
+            let mut builder = cors(Response::builder())
+                .status(route.status)
+                .header("content-type", content_type);
+            for (key, value) in &route.headers {
+                builder = builder.header(key.as_str(), value.as_str());
+            }
+            (route.status, builder.body(text))
+        }
+        // 등록되지 않은 OPTIONS 는 CORS 사전 요청으로 응답
+        None if method == "OPTIONS" => (
+            204,
+            cors(Response::builder()).status(204).body(String::new()),
+        ),
+        None => (
+            404,
+            cors(Response::builder())
+                .status(404)
+                .header("content-type", "application/json")
+                .body(
+                    json!({ "error": "NO_MOCK_ROUTE", "method": method, "path": path }).to_string(),
+                ),
+        ),
+    };
+
+    let entry = json!({
+        "timestamp": chrono::Local::now().to_rfc3339(),
+        "method": method,
+        "path": path,
+        "query": query,
+        "body": request_body,
+        "status": status
+    });
+    println!("🧪 Mock {} {} → {}", method, path, status);
+    if let Ok(mut log) = context.log.lock() {
+        if log.len() >= MAX_LOG_ENTRIES {
+            log.pop_front();
+        }
+        log.push_back(entry.clone());
+    }
+    let _ = context.app_handle.emit(
+        "mock-server-request",
+        &json!({ "node_id": context.node_id, "request": entry }),
+    );
+
+    response.map_err(|_| warp::reject::reject())
+}
+
+#[tauri::command]
+pub async fn mock_server_node(
+    app_handle: AppHandle,
+    node_id: String,
+    routes: Vec<MockRoute>,
+    port: Option<u16>,
+    bind_all: Option<bool>,
+) -> Result<String, String> {
+    let routes = validate_routes(routes)?;
+    let route_count = routes.len();
+
+    // 같은 노드의 기존 서버는 교체
+    let _ = stop_mock_server(node_id.clone()).await;
+
+    let host = if bind_all.unwrap_or(false) {
+        [0, 0, 0, 0]
+    } else {
+        [127, 0, 0, 1]
+    };
+    let routes = Arc::new(RwLock::new(routes));
+    let log = Arc::new(Mutex::new(VecDeque::new()));
+    let context = Arc::new(MockContext {
+        app_handle,
+        node_id: node_id.clone(),
+        routes: routes.clone(),
+        log: log.clone(),
+    });
+
+    let filter = warp::method()
+        .and(warp::path::full())
+        .and(warp::query::raw().or(warp::any().map(String::new)).unify())
+        .and(warp::body::bytes())
+        .and_then(move |method, path, query, body| {
+            handle_request(context.clone(), method, path, query, body)
+        });
+
+    let (addr, server) = warp::serve(filter)
+        .try_bind_ephemeral((host, port.unwrap_or(0)))
+        .map_err(|e| format!("PORT_UNAVAILABLE: {}", e))?;
+    let task = tokio::spawn(server);
+
+    let url = format!("http://localhost:{}", addr.port());
+    get_mock_server_registry().write().await.insert(
+        node_id.clone(),
+        MockServerHandle {
+            port: addr.port(),
+            url: url.clone(),
+            routes,
+            log,
+            abort_handle: task.abort_handle(),
+        },
+    );
+
+    println!(
+        "🧪 Mock 서버 시작 (node {}): {} / 경로 {}개",
+        node_id, addr, route_count
+    );
+    Ok(json!({ "url": url, "port": addr.port(), "routes": route_count }).to_string())
+}
+
+// 실행 중인 서버의 응답 목록 교체 (재시작 없이)
+#[tauri::command]
+pub async fn update_mock_routes(node_id: String, routes: Vec<MockRoute>) -> Result<String, String> {
+    let routes = validate_routes(routes)?;
+    let servers = get_mock_server_registry().read().await;
+    let handle = servers
+        .get(&node_id)
+        .ok_or_else(|| format!("MOCK_SERVER_NOT_RUNNING: {}", node_id))?;
+    let count = routes.len();
+    *handle.routes.write().await = routes;
+    Ok(json!({ "routes": count }).to_string())
+}
+
+#[tauri::command]
+pub async fn get_mock_server_log(node_id: String, limit: Option<usize>) -> Result<String, String> {
+    let servers = get_mock_server_registry().read().await;
+    let handle = servers
+        .get(&node_id)
+        .ok_or_else(|| format!("MOCK_SERVER_NOT_RUNNING: {}", node_id))?;
+    let log = handle.log.lock().map_err(|e| e.to_string())?;
+    let limit = limit.unwrap_or(MAX_LOG_ENTRIES);
+    let requests: Vec<&Value> = log.iter().rev().take(limit).collect();
+    Ok(json!({ "requests": requests, "total": log.len() }).to_string())
+}
+
+#[tauri::command]
+pub async fn stop_mock_server(node_id: String) -> Result<String, String> {
+    match get_mock_server_registry().write().await.remove(&node_id) {
+        Some(handle) => {
+            handle.abort_handle.abort();
+            println!("🛑 Mock 서버 중지 (node {}, 포트 {})", node_id, handle.port);
+            Ok("Mock server stopped".to_string())
+        }
+        None => Ok("No mock server running for this node".to_string()),
+    }
+}
+
+#[tauri::command]
+pub async fn get_mock_server_info(node_id: String) -> Result<Value, String> {
+    let servers = get_mock_server_registry().read().await;
+    Ok(match servers.get(&node_id) {
+        Some(handle) => json!({
+            "running": !handle.abort_handle.is_finished(),
+            "url": handle.url,
+            "port": handle.port,
+            "routes": handle.routes.read().await.len()
+        }),
+        None => json!({ "running": false }),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn mock_server_matches_route_patterns() {
+        let params = match_path("/api/users/:id", "/api/users/42/").unwrap();
+        assert_eq!(params["id"], "42");
+        assert!(match_path("/api/users/:id", "/api/users").is_none());
+        assert!(match_path("/api/users", "/api/users/42").is_none());
+
+        let params = match_path("/files/*", "/files/a/b.txt").unwrap();
+        assert_eq!(params["wildcard"], "a/b.txt");
+
+        let routes = validate_routes(
+            serde_json::from_value(json!([
+                { "method": "post", "path": "/items", "status": 201 },
+                { "path": "/items/:id" }
+            ]))
+            .unwrap(),
+        )
+        .unwrap();
+        let (route, _) = find_route(&routes, "POST", "/items").unwrap();
+        assert_eq!(route.status, 201);
+        assert!(find_route(&routes, "GET", "/items").is_none());
+        assert!(find_route(&routes, "DELETE", "/items/7").is_some());
+
+        let invalid = serde_json::from_value(json!([{ "path": "items" }])).unwrap();
+        assert!(validate_routes(invalid)
+            .unwrap_err()
+            .starts_with("INVALID_MOCK_ROUTE"));
+    }
+
+    #[test]
+    fn mock_server_renders_response_templates() {
+        let context = json!({
+            "params": { "id": "7" },
+            "query": {},
+            "body": { "tags": ["a", "b"] }
+        });
+        let body =
+            json!({ "id": "{{params.id}}", "label": "item {{params.id}}", "tags": "{{body.tags}}" });
+        let rendered = render_body(&body, &context);
+        assert_eq!(
+            rendered,
+            json!({ "id": "7", "label": "item 7", "tags": ["a", "b"] })
+        );
+    }
+}
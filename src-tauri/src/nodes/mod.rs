@@ -11,9 +11,116 @@ pub mod run_command_node;
 pub mod text_file_editor_node;
 pub mod text_merger_node;
 pub mod video_download_node;
+pub mod workflow_params;
 pub mod workflow_storage;
+pub mod node_cache;
+pub mod mock_replay;
+pub mod file_stream_write;
+pub mod atomic_store;
+pub mod summarize_node;
+pub mod json_schema;
+pub mod ai_extract_node;
+pub mod voice_trigger;
+pub mod notification_node;
+pub mod presence_node;
+pub mod os_command;
+pub mod media_control_node;
+pub mod wifi_node;
+pub mod usb_watcher;
+pub mod display_node;
+pub mod dns_node;
+pub mod port_check_node;
+pub mod template;
+pub mod ticket_node;
+pub mod mcp_node;
+pub mod mcp_server;
+pub mod encoding_node;
+pub mod normalize_text_node;
+pub mod list_ops_node;
+pub mod table_node;
+pub mod pdf_builder_node;
+pub mod photo_sort_node;
+pub mod ffprobe_node;
+pub mod watermark_node;
+pub mod clipboard_image_node;
+pub mod color_node;
+pub mod secrets;
+pub mod password_node;
+pub mod totp_node;
+pub mod service_node;
+pub mod os_schedule;
+pub mod diagnostics;
+pub mod telemetry;
+pub mod resource_guard;
+pub mod disk_preflight;
+pub mod path_guard;
+pub mod origin_policy;
+pub mod access_log;
+pub mod workflow_lock;
+pub mod instance;
+pub mod shell_session_node;
+pub mod node_output;
+pub mod value_store;
+pub mod run_history;
+pub mod control_page;
+pub mod push_node;
+pub mod barcode_node;
+pub mod schedule_conditions;
+pub mod geofence_trigger;
+pub mod market_data_node;
+pub mod now_playing_node;
+pub mod midi_trigger;
+pub mod mock_server_node;
+pub mod soap_node;
+pub mod excel_read_node;
+pub mod doc_text_node;
+pub mod vcard_node;
+pub mod email_parse_node;
+pub mod torrent_node;
+pub mod podcast_node;
+pub mod download_registry;
+pub mod gallery_node;
+pub mod safe_path;
+pub mod workspace;
+pub mod placeholders;
+pub mod approval_node;
+pub mod prompt_node;
+pub mod timer_node;
+pub mod rate_limiter;
+pub mod lock_node;
+pub mod cache_node;
+pub mod aggregate_node;
+pub mod log_node;
+pub mod assert_node;
+pub mod snapshot_node;
+pub mod redact_node;
+pub mod secret_scan;
+pub mod workflow_signing;
+pub mod marketplace;
+pub mod profiles;
+pub mod git_sync;
+pub mod cloud_sync;
+pub mod run_share;
+pub mod node_docs;
+pub mod workflow_history;
+pub mod run_trace;
+pub mod run_debugger;
+pub mod run_watch;
+pub mod run_cleanup;
+pub mod doctor;
+pub mod tools;
+pub mod event_chunks;
+pub mod worker_pool;
+pub mod run_queue;
+pub mod http_client;
+pub mod static_cache;
+pub mod ws_limits;
+pub mod message_router;
+pub mod workflow_builder;
+#[cfg(test)]
+mod test_support; // 🧪 단위 테스트 공용 헬퍼
 // 함수들을 재export (자동 등록을 위해)
-pub use cli_ai_node::{cli_ai_node, update_cli_result, clear_conversation_history}; // 🆕 CLI AI 노드 + 업데이트 함수
+pub use cli_ai_node::{cli_ai_node, update_cli_result, clear_conversation_history, list_ai_sessions, get_ai_history_limits, set_ai_history_limits, prune_ai_sessions}; // 🆕 CLI AI 노드 + 업데이트 함수
 pub use chat_web_server_node::{
     chat_web_server_node,
     get_chat_server_info,   // 🆕 추가
@@ -26,14 +133,109 @@ pub use chat_web_server_node::{
 };
 pub use cli_node::cli_node; // 🆕 CLI 노드 추가
 pub use file_creator_node::file_creator_node;
-pub use file_path_node::file_path_node; // 🆕 추가
+pub use file_path_node::{file_path_node, get_file_search_settings, set_file_search_settings}; // 🆕 추가
 pub use file_to_clipboard_node::file_to_clipboard_node;
-pub use qr_code_node::qr_code_node;
+pub use qr_code_node::{qr_code_node, qr_code_batch_node};
 pub use run_command_node::run_command_node;
 pub use text_file_editor_node::text_file_editor_node;
 pub use text_merger_node::text_merger_node;
 pub use video_download_node::video_download_node;
+pub use workflow_params::{get_workflow_parameters, prepare_workflow_run};
 pub use workflow_storage::{load_workflow_from_desktop, save_workflow_to_desktop, load_specific_workflow};
+pub use node_cache::{get_cached_node_output, set_cached_node_output, clear_node_cache};
+pub use mock_replay::{set_mock_mode, get_mock_mode, clear_mock_recordings};
+pub use file_stream_write::{begin_file_write, append_chunk, finish_file_write, abort_file_write};
+pub use summarize_node::summarize_node;
+pub use ai_extract_node::ai_extract_node;
+pub use voice_trigger::{start_voice_trigger, stop_voice_trigger, get_voice_trigger_status};
+pub use notification_node::notification_node;
+pub use presence_node::presence_node;
+pub use media_control_node::media_control_node;
+pub use wifi_node::wifi_node;
+pub use usb_watcher::{start_usb_watcher, stop_usb_watcher, list_usb_devices, get_usb_watcher_status};
+pub use display_node::display_node;
+pub use dns_node::dns_node;
+pub use port_check_node::port_check_node;
+pub use ticket_node::ticket_node;
+pub use mcp_node::mcp_node;
+pub use mcp_server::{start_mcp_server, stop_mcp_server, get_mcp_server_status, publish_workflow_tool, unpublish_workflow_tool, list_published_tools, complete_mcp_tool_call};
+pub use encoding_node::encoding_node;
+pub use normalize_text_node::normalize_text_node;
+pub use list_ops_node::list_ops_node;
+pub use table_node::table_node;
+pub use pdf_builder_node::pdf_builder_node;
+pub use photo_sort_node::photo_sort_node;
+pub use ffprobe_node::ffprobe_node;
+pub use watermark_node::watermark_node;
+pub use clipboard_image_node::clipboard_image_node;
+pub use color_node::color_node;
+pub use secrets::{set_secret, delete_secret, list_secrets};
+pub use password_node::password_node;
+pub use totp_node::totp_node;
+pub use service_node::service_node;
+pub use os_schedule::{export_os_schedule, remove_os_schedule, list_os_schedules, take_launch_workflow};
+pub use diagnostics::{generate_diagnostics_bundle, set_crash_reporting};
+pub use telemetry::{set_telemetry_enabled, record_node_usage, record_workflow_usage, get_usage_stats, reset_usage_stats};
+pub use resource_guard::{get_resource_limits, set_resource_limits, get_resource_usage};
+pub use disk_preflight::check_disk_space;
+pub use access_log::{get_server_access_log, clear_server_access_log};
+pub use workflow_lock::{acquire_workflow_lock, release_workflow_lock, get_workflow_lock};
+pub use instance::take_launch_request;
+pub use shell_session_node::{shell_session_node, reset_shell_session, list_shell_sessions};
+pub use node_output::{normalize_node_output, coerce_node_output};
+pub use value_store::{store_large_value, get_value_preview, read_value_chunk, release_value, clear_value_store};
+pub use run_history::{start_run, record_node_run, finish_run, get_run, export_run_report, publish_run_event, list_runs};
+pub use push_node::push_node;
+pub use barcode_node::barcode_node;
+pub use schedule_conditions::{check_schedule_conditions, list_holidays, get_sun_times};
+pub use geofence_trigger::{start_geofence_trigger, stop_geofence_trigger, get_geofence_status};
+pub use market_data_node::market_data_node;
+pub use now_playing_node::now_playing_node;
+pub use midi_trigger::{list_control_surfaces, start_midi_trigger, stop_midi_trigger, get_midi_trigger_status};
+pub use mock_server_node::{mock_server_node, update_mock_routes, get_mock_server_log, stop_mock_server, get_mock_server_info};
+pub use soap_node::soap_node;
+pub use excel_read_node::excel_read_node;
+pub use doc_text_node::doc_text_node;
+pub use vcard_node::vcard_node;
+pub use email_parse_node::email_parse_node;
+pub use torrent_node::{torrent_node, cancel_torrent, list_torrents};
+pub use podcast_node::podcast_node;
+pub use download_registry::{check_download_history, get_download_history, clear_download_history};
+pub use gallery_node::gallery_node;
+pub use workspace::resolve_workspace;
+pub use placeholders::{expand_node_params, set_variable, delete_variable, list_variables};
+pub use approval_node::{approval_node, respond_approval, cancel_approval, list_pending_approvals};
+pub use prompt_node::prompt_node;
+pub use timer_node::{timer_node, cancel_timer, get_timer_status, list_timers};
+pub use rate_limiter::{acquire_rate_token, get_rate_buckets, reset_rate_bucket};
+pub use lock_node::{acquire_lock, release_lock, list_locks};
+pub use cache_node::cache_node;
+pub use aggregate_node::aggregate_node;
+pub use log_node::log_node;
+pub use assert_node::assert_node;
+pub use snapshot_node::snapshot_node;
+pub use redact_node::redact_node;
+pub use secret_scan::{scan_workflow_secrets, move_secrets_to_manager};
+pub use workflow_signing::{sign_workflow, verify_workflow, get_signing_public_key, trust_signer, untrust_signer, list_trusted_signers};
+pub use marketplace::{get_marketplace_settings, set_marketplace_settings, marketplace_search, marketplace_fetch, marketplace_install, marketplace_list_installed};
+pub use profiles::{list_profiles, create_profile, switch_profile, delete_profile};
+pub use git_sync::{get_git_sync_settings, set_git_sync_settings, git_sync_now, git_sync_status};
+pub use cloud_sync::{get_cloud_sync_settings, set_cloud_sync_settings, sync_now};
+pub use run_share::{share_run, revoke_run_share, list_run_shares};
+pub use node_docs::{get_node_docs, list_node_docs};
+pub use workflow_history::{list_workflow_versions, get_workflow_version, annotate_workflow_version};
+pub use run_trace::{set_run_tracing, get_run_tracing, list_node_traces, get_node_trace, replay_node, complete_node_replay};
+pub use run_debugger::{set_breakpoints, get_breakpoints, debug_before_node, step_run, continue_run, abort_run, pause_run, list_paused_runs};
+pub use run_watch::{add_watch, remove_watch, list_watches};
+pub use run_cleanup::{register_cleanup, unregister_cleanup, list_cleanups, run_cleanup_now};
+pub use doctor::doctor;
+pub use tools::{list_tools, set_tool_pin, install_tool, remove_tool};
+pub use worker_pool::{get_worker_pool, set_worker_pool};
+pub use run_queue::{acquire_run_slot, release_run_slot, yield_run_slot, list_run_queue, set_workflow_priority, set_max_concurrent_runs};
+pub use http_client::{get_http_client_settings, set_http_client_settings, warm_http_connections};
+pub use ws_limits::{get_ws_limits, set_ws_limits};
+pub use message_router::{list_message_routes, save_message_route, delete_message_route, reorder_message_routes, test_message_route, route_inbound_message};
+pub use workflow_builder::build_workflow_from_prompt;
 
 // 나중에 추가될 노드들을 위한 매크로 자동 생성 준비
 // 새로운 노드 추가 시:
@@ -0,0 +1,206 @@
+// src-tauri/src/nodes/node_cache.rs
+use super::workflow_signing;
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+// 노드 출력 캐시 (옵트인) - 개발 중 반복 실행 시 비싼 노드(HTTP, AI 등) 결과 재사용
+// 최대 MAX_CACHE_ENTRIES 개, 넘치면 만료된 항목 → 가장 오래 안 쓴 항목 순으로 제거
+const MAX_CACHE_ENTRIES: usize = 500;
+
+struct CacheEntry {
+    node_type: String,
+    output: String,
+    expires_at: Instant,
+    last_used: Instant,
+}
+
+struct NodeCache {
+    capacity: usize,
+    entries: HashMap<String, CacheEntry>,
+}
+
+impl NodeCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+        }
+    }
+
+    fn get(&mut self, key: &str) -> Option<String> {
+        let now = Instant::now();
+        match self.entries.get_mut(key) {
+            Some(entry) if entry.expires_at > now => {
+                entry.last_used = now;
+                Some(entry.output.clone())
+            }
+            Some(_) => {
+                // 만료된 항목은 즉시 제거
+                self.entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn insert(&mut self, key: String, node_type: &str, output: &str, ttl: Duration) {
+        let now = Instant::now();
+        if !self.entries.contains_key(&key) && self.entries.len() >= self.capacity {
+            self.entries.retain(|_, entry| entry.expires_at > now);
+        }
+        while !self.entries.contains_key(&key) && self.entries.len() >= self.capacity {
+            let Some(oldest) = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone())
+            else {
+                break;
+            };
+            self.entries.remove(&oldest);
+        }
+        self.entries.insert(
+            key,
+            CacheEntry {
+                node_type: node_type.to_string(),
+                output: output.to_string(),
+                expires_at: now + ttl,
+                last_used: now,
+            },
+        );
+    }
+}
+
+static NODE_CACHE: std::sync::OnceLock<Mutex<NodeCache>> = std::sync::OnceLock::new();
+
+fn get_node_cache() -> &'static Mutex<NodeCache> {
+    NODE_CACHE.get_or_init(|| Mutex::new(NodeCache::new(MAX_CACHE_ENTRIES)))
+}
+
+// 캐시 키 = 노드 타입 + 입력의 SHA-256 (JSON 키 순서와 무관, Rust 버전이 바뀌어도 같은 값)
+pub fn make_cache_key(node_type: &str, inputs: &Value) -> String {
+    let digest = Sha256::digest(workflow_signing::canonical_json(inputs).as_bytes());
+    let hex: String = digest.iter().map(|b| format!("{:02x}", b)).collect();
+    format!("{}:{}", node_type, hex)
+}
+
+pub fn lookup(node_type: &str, inputs: &Value) -> Option<String> {
+    let key = make_cache_key(node_type, inputs);
+    get_node_cache().lock().ok()?.get(&key)
+}
+
+pub fn store(node_type: &str, inputs: &Value, output: &str, ttl_secs: u64) {
+    if ttl_secs == 0 {
+        return;
+    }
+
+    let key = make_cache_key(node_type, inputs);
+    if let Ok(mut cache) = get_node_cache().lock() {
+        cache.insert(key, node_type, output, Duration::from_secs(ttl_secs));
+    }
+}
+
+#[tauri::command]
+pub fn get_cached_node_output(node_type: String, inputs: Value) -> Result<Option<String>, String> {
+    Ok(lookup(&node_type, &inputs))
+}
+
+#[tauri::command]
+pub fn set_cached_node_output(
+    node_type: String,
+    inputs: Value,
+    output: String,
+    ttl_secs: u64,
+) -> Result<String, String> {
+    if node_type.trim().is_empty() {
+        return Err("EMPTY_NODE_TYPE".to_string());
+    }
+
+    store(&node_type, &inputs, &output, ttl_secs);
+    Ok(make_cache_key(&node_type, &inputs))
+}
+
+// node_type이 없으면 전체 캐시 삭제
+#[tauri::command]
+pub fn clear_node_cache(node_type: Option<String>) -> Result<Value, String> {
+    let mut cache = get_node_cache()
+        .lock()
+        .map_err(|_| "CACHE_LOCK_ERROR".to_string())?;
+
+    let before = cache.entries.len();
+    match node_type.as_deref().map(str::trim) {
+        Some(target) if !target.is_empty() => {
+            cache.entries.retain(|_, entry| entry.node_type != target)
+        }
+        _ => cache.entries.clear(),
+    }
+    let removed = before - cache.entries.len();
+
+    println!("🧹 노드 캐시 {}개 항목 삭제", removed);
+
+    Ok(json!({
+        "removed": removed,
+        "remaining": cache.entries.len()
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn node_cache_round_trip_and_clear() {
+        let inputs = json!({ "b": 2, "a": 1 });
+        set_cached_node_output(
+            "test_cache_node".to_string(),
+            inputs,
+            "cached".to_string(),
+            60,
+        )
+        .unwrap();
+
+        // 키 순서가 달라도 같은 입력으로 취급
+        let hit =
+            get_cached_node_output("test_cache_node".to_string(), json!({ "a": 1, "b": 2 })).unwrap();
+        assert_eq!(hit.as_deref(), Some("cached"));
+
+        clear_node_cache(Some("test_cache_node".to_string())).unwrap();
+        let miss =
+            get_cached_node_output("test_cache_node".to_string(), json!({ "a": 1, "b": 2 })).unwrap();
+        assert_eq!(miss, None);
+    }
+
+    #[test]
+    fn node_cache_key_is_stable_sha256() {
+        let key = make_cache_key("text", &json!({ "b": 2, "a": 1 }));
+        assert_eq!(key, make_cache_key("text", &json!({ "a": 1, "b": 2 })));
+        assert_eq!(key.len(), "text:".len() + 64);
+        assert_ne!(key, make_cache_key("text", &json!({ "a": 1, "b": 3 })));
+    }
+
+    #[test]
+    fn node_cache_evicts_least_recently_used() {
+        let mut cache = NodeCache::new(2);
+        let ttl = Duration::from_secs(60);
+        cache.insert("a".to_string(), "t", "1", ttl);
+        cache.insert("b".to_string(), "t", "2", ttl);
+        std::thread::sleep(Duration::from_millis(2));
+        assert_eq!(cache.get("a").as_deref(), Some("1"));
+
+        cache.insert("c".to_string(), "t", "3", ttl);
+        assert_eq!(cache.entries.len(), 2);
+        assert_eq!(cache.get("b"), None);
+        assert_eq!(cache.get("a").as_deref(), Some("1"));
+
+        // 만료된 항목이 있으면 그것부터 제거
+        cache.insert("x".to_string(), "t", "x", Duration::ZERO);
+        assert_eq!(cache.entries.len(), 2);
+        cache.insert("d".to_string(), "t", "4", ttl);
+        assert_eq!(cache.get("x"), None);
+        assert_eq!(cache.entries.len(), 2);
+    }
+}
@@ -0,0 +1,391 @@
+// src-tauri/src/nodes/node_docs.rs
+use super::atomic_store;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::path::Path;
+
+// 📖 노드 문서 / 예제 (에디터 인라인 도움말)
+// 기본 노드 문서는 여기 내장, 플러그인은 store/plugins/<id>/manifest.json 의 "node_docs" 배열로 함께 배포
+// { "node_docs": [{ "node_type", "title", "description", "parameters": [...], "examples": [...] }] }
+// 노드 종류는 snake_case(file_creator_node) / 프론트엔드 camelCase(fileCreatorNode) 모두 허용
+// 같은 노드 종류면 내장 문서가 우선 (플러그인이 기본 노드 설명을 덮어쓰지 못하게)
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ParamDoc {
+    pub name: String,
+    #[serde(default)]
+    pub r#type: String,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub default: Option<Value>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ExampleDoc {
+    pub title: String,
+    #[serde(default)]
+    pub inputs: Value,
+    #[serde(default)]
+    pub output: Option<Value>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct NodeDoc {
+    pub node_type: String,
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub parameters: Vec<ParamDoc>,
+    #[serde(default)]
+    pub examples: Vec<ExampleDoc>,
+    // "builtin" | "plugin:<id>"
+    #[serde(default)]
+    pub source: String,
+}
+
+// fileCreatorNode → file_creator_node
+pub fn normalize_node_type(node_type: &str) -> String {
+    let mut out = String::new();
+    for (i, c) in node_type.trim().chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 && !out.ends_with('_') {
+                out.push('_');
+            }
+            out.extend(c.to_lowercase());
+        } else if c == '-' || c == ' ' {
+            out.push('_');
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+fn param(name: &str, kind: &str, required: bool, description: &str) -> ParamDoc {
+    ParamDoc {
+        name: name.to_string(),
+        r#type: kind.to_string(),
+        required,
+        description: description.to_string(),
+        default: None,
+    }
+}
+
+fn example(title: &str, inputs: Value, output: Value) -> ExampleDoc {
+    ExampleDoc {
+        title: title.to_string(),
+        inputs,
+        output: Some(output),
+    }
+}
+
+fn builtin(
+    node_type: &str,
+    title: &str,
+    description: &str,
+    parameters: Vec<ParamDoc>,
+    examples: Vec<ExampleDoc>,
+) -> NodeDoc {
+    NodeDoc {
+        node_type: node_type.to_string(),
+        title: title.to_string(),
+        description: description.to_string(),
+        parameters,
+        examples,
+        source: "builtin".to_string(),
+    }
+}
+
+pub fn builtin_docs() -> Vec<NodeDoc> {
+    vec![
+        builtin(
+            "file_creator_node",
+            "File Creator",
+            "지정한 폴더에 파일을 만듭니다. 폴더가 없으면 함께 만들고, 같은 이름의 파일이 있으면 덮어씁니다.",
+            vec![
+                param("file_path", "string", false, "저장할 폴더 (비우면 현재 작업 폴더)"),
+                param("file_name", "string", true, "파일 이름 (확장자 포함)"),
+                param("file_content", "string", true, "파일 내용"),
+                param("content_encoding", "string", false, "\"base64\" 면 내용을 디코딩해 바이너리로 저장"),
+            ],
+            vec![example(
+                "메모 저장",
+                json!({ "file_path": "C:/notes", "file_name": "todo.txt", "file_content": "우유 사기" }),
+                json!("SUCCESS"),
+            )],
+        ),
+        builtin(
+            "text_file_editor_node",
+            "Text File Editor",
+            "기존 텍스트 파일을 새 이름과 내용으로 다시 저장합니다. 이름이 바뀌면 원본 파일은 지웁니다.",
+            vec![
+                param("file_path", "string", true, "수정할 파일 경로"),
+                param("new_file_name", "string", true, "새 파일 이름 (같은 이름이면 내용만 교체)"),
+                param("new_file_content", "string", true, "새 내용"),
+            ],
+            vec![example(
+                "완료 목록으로 바꾸기",
+                json!({ "file_path": "C:/notes/todo.txt", "new_file_name": "done.txt", "new_file_content": "우유 사기 (완료)" }),
+                json!("SUCCESS"),
+            )],
+        ),
+        builtin(
+            "text_merger_node",
+            "Text Merger",
+            "두 텍스트를 구분자로 이어 붙입니다.",
+            vec![
+                param("text1", "string", false, "앞에 올 텍스트"),
+                param("text2", "string", false, "뒤에 올 텍스트"),
+                param("separator", "string", false, "사이에 넣을 구분자"),
+            ],
+            vec![example(
+                "이름 합치기",
+                json!({ "text1": "홍", "text2": "길동", "separator": "" }),
+                json!("홍길동"),
+            )],
+        ),
+        builtin(
+            "file_path_node",
+            "File Path",
+            "파일 경로나 파일 이름을 실제 경로로 확인합니다. 이름만 주면 검색 위치에서 찾고, glob 패턴(*.txt)도 지원합니다.",
+            vec![
+                param("file_paths", "string[]", true, "경로, 파일 이름 또는 glob 패턴 목록"),
+                param("case_insensitive", "boolean", false, "대소문자 무시"),
+                param("max_matches", "number", false, "패턴 하나당 최대 결과 수"),
+                param("search_roots", "string[]", false, "이름만 줬을 때 찾아볼 폴더 (비우면 설정값)"),
+                param("search_depth", "number", false, "검색할 하위 폴더 깊이"),
+            ],
+            vec![example(
+                "다운로드 폴더의 PDF",
+                json!({ "file_paths": ["C:/Users/me/Downloads/*.pdf"] }),
+                json!("C:/Users/me/Downloads/a.pdf\nC:/Users/me/Downloads/b.pdf"),
+            )],
+        ),
+        builtin(
+            "file_to_clipboard_node",
+            "File to Clipboard",
+            "파일들을 클립보드에 복사해 탐색기 / 메신저에 바로 붙여 넣을 수 있게 합니다.",
+            vec![param("file_paths", "string[]", true, "복사할 파일 경로 목록")],
+            vec![example(
+                "보고서 복사",
+                json!({ "file_paths": ["C:/reports/weekly.pdf"] }),
+                json!("1개 파일이 클립보드에 복사되었습니다!"),
+            )],
+        ),
+        builtin(
+            "run_command_node",
+            "Run Command",
+            "프로그램이나 명령을 실행하고 종료 코드와 표준 출력 / 에러를 돌려줍니다.",
+            vec![
+                param("command", "string", true, "실행할 프로그램"),
+                param("args", "string[]", false, "인자 목록 (셸을 거치지 않고 그대로 전달)"),
+                param("cwd", "string", false, "작업 폴더"),
+                param("elevated", "boolean", false, "관리자 권한으로 실행 (UAC / sudo 확인 창)"),
+                param("priority", "string", false, "프로세스 우선순위 (normal | low | idle)"),
+            ],
+            vec![example(
+                "git 상태 확인",
+                json!({ "command": "git", "args": ["status", "--short"], "cwd": "C:/project" }),
+                json!({ "status": 0, "stdout": " M README.md\n", "stderr": "" }),
+            )],
+        ),
+        builtin(
+            "qr_code_node",
+            "QR Code",
+            "텍스트나 URL 을 QR 코드 PNG 이미지(base64)로 만듭니다.",
+            vec![param("url", "string", true, "QR 코드에 담을 내용")],
+            vec![example(
+                "링크 QR",
+                json!({ "url": "https://example.com" }),
+                json!({ "url": "https://example.com", "image_base64": "iVBORw0KGgo..." }),
+            )],
+        ),
+        builtin(
+            "video_download_node",
+            "Video Download",
+            "yt-dlp 로 영상을 내려받습니다. 여러 URL 은 한 줄에 하나씩 적습니다.",
+            vec![
+                param("urls", "string", true, "영상 URL 목록"),
+                param("folder_name", "string", false, "다운로드 폴더 안에 만들 하위 폴더"),
+                param("download_path", "string", true, "저장할 폴더"),
+                param("priority", "string", false, "프로세스 우선순위"),
+                param("stamp_metadata", "boolean", false, "원본 URL 등 출처 정보를 파일에 기록"),
+                param("skip_downloaded", "boolean", false, "이미 받은 URL 은 건너뜀"),
+            ],
+            vec![example(
+                "재생목록 받기",
+                json!({ "urls": "https://youtu.be/xxxx", "folder_name": "music", "download_path": "D:/videos" }),
+                json!("D:/videos/music"),
+            )],
+        ),
+        builtin(
+            "cache_node",
+            "Cache",
+            "TTL 이 있는 키-값 캐시입니다. 비싼 API 결과를 잠시 저장해 두고 다시 쓸 때 사용합니다.",
+            vec![
+                param("action", "string", true, "get | set | delete | clear"),
+                param("key", "string", false, "캐시 키"),
+                param("value", "any", false, "set 에 저장할 값"),
+                param("ttl_secs", "number", false, "유효 시간 (초)"),
+                param("namespace", "string", false, "키를 묶을 이름 공간"),
+            ],
+            vec![example(
+                "환율 10분 캐시",
+                json!({ "action": "set", "key": "usd_krw", "value": 1380.5, "ttl_secs": 600 }),
+                json!({ "key": "usd_krw", "stored": true, "expires_at": 1767226200 }),
+            )],
+        ),
+        builtin(
+            "log_node",
+            "Log",
+            "실행 기록에 남는 로그를 씁니다. 채팅 웹서버로 휴대폰에도 보낼 수 있습니다.",
+            vec![
+                param("level", "string", false, "debug | info | warn | error (기본 info)"),
+                param("message", "string", true, "로그 메시지"),
+                param("context", "object", false, "함께 기록할 값"),
+                param("run_id", "string", false, "실행 ID (실행 기록에 추가)"),
+                param("chat_node_id", "string", false, "로그를 보낼 채팅 웹서버 노드"),
+            ],
+            vec![example(
+                "경고 남기기",
+                json!({ "level": "warn", "message": "재시도 1/3", "context": { "attempt": 1 } }),
+                json!({ "level": "warn", "message": "재시도 1/3", "context": { "attempt": 1 }, "run_id": null, "mirrored": false }),
+            )],
+        ),
+    ]
+}
+
+// 플러그인 manifest 의 node_docs (잘못된 항목은 건너뜀)
+pub fn parse_plugin_docs(plugin_id: &str, manifest: &str) -> Vec<NodeDoc> {
+    let Ok(manifest) = serde_json::from_str::<Value>(manifest) else {
+        return Vec::new();
+    };
+    let Some(Value::Array(docs)) = manifest.get("node_docs") else {
+        return Vec::new();
+    };
+    docs.iter()
+        .filter_map(|doc| serde_json::from_value::<NodeDoc>(doc.clone()).ok())
+        .filter(|doc| !doc.node_type.trim().is_empty())
+        .map(|mut doc| {
+            doc.node_type = normalize_node_type(&doc.node_type);
+            doc.source = format!("plugin:{}", plugin_id);
+            doc
+        })
+        .collect()
+}
+
+fn plugin_docs(plugins_dir: &Path) -> Vec<NodeDoc> {
+    let Ok(entries) = std::fs::read_dir(plugins_dir) else {
+        return Vec::new();
+    };
+    let mut docs = Vec::new();
+    for entry in entries.filter_map(|e| e.ok()).filter(|e| e.path().is_dir()) {
+        let plugin_id = entry.file_name().to_string_lossy().to_string();
+        if let Ok(manifest) = std::fs::read_to_string(entry.path().join("manifest.json")) {
+            docs.extend(parse_plugin_docs(&plugin_id, &manifest));
+        }
+    }
+    docs.sort_by(|a, b| a.source.cmp(&b.source));
+    docs
+}
+
+// 내장 문서 + 플러그인 문서 (같은 노드 종류는 먼저 나온 것만)
+pub fn all_docs(plugins_dir: &Path) -> Vec<NodeDoc> {
+    let mut docs = builtin_docs();
+    for doc in plugin_docs(plugins_dir) {
+        if !docs.iter().any(|d| d.node_type == doc.node_type) {
+            docs.push(doc);
+        }
+    }
+    docs
+}
+
+fn plugins_dir() -> std::path::PathBuf {
+    atomic_store::store_dir().join("plugins")
+}
+
+#[tauri::command]
+pub fn get_node_docs(node_type: String) -> Result<NodeDoc, String> {
+    let wanted = normalize_node_type(&node_type);
+    all_docs(&plugins_dir())
+        .into_iter()
+        .find(|doc| doc.node_type == wanted)
+        .ok_or_else(|| format!("NODE_DOCS_NOT_FOUND: {}", node_type))
+}
+
+// 목록용 요약 (제목 / 설명 / 출처)
+#[tauri::command]
+pub fn list_node_docs() -> Result<Vec<Value>, String> {
+    Ok(all_docs(&plugins_dir())
+        .iter()
+        .map(|doc| {
+            json!({
+                "node_type": doc.node_type,
+                "title": doc.title,
+                "description": doc.description,
+                "source": doc.source
+            })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::temp_dir;
+    use serde_json::json;
+    use std::fs;
+
+    #[test]
+    fn node_docs_accepts_frontend_node_type_names() {
+        assert_eq!(
+            normalize_node_type("fileCreatorNode"),
+            "file_creator_node"
+        );
+        assert_eq!(normalize_node_type("cli_node"), "cli_node");
+        let doc = get_node_docs("fileCreatorNode".to_string()).unwrap();
+        assert_eq!(doc.source, "builtin");
+        assert!(doc.parameters.iter().any(|p| p.name == "file_name" && p.required));
+        assert!(get_node_docs("noSuchNode".to_string()).is_err());
+    }
+
+    #[test]
+    fn node_docs_merges_plugin_manifests_without_overriding_builtins() {
+        let dir = temp_dir();
+        let plugin = dir.path().join("weather");
+        fs::create_dir_all(&plugin).unwrap();
+        fs::write(
+            plugin.join("manifest.json"),
+            json!({
+                "name": "weather",
+                "node_docs": [
+                    {
+                        "node_type": "weatherNode",
+                        "title": "Weather",
+                        "parameters": [{ "name": "city", "type": "string", "required": true }],
+                        "examples": [{ "title": "서울", "inputs": { "city": "Seoul" } }]
+                    },
+                    { "node_type": "file_creator_node", "title": "Hijacked" },
+                    { "title": "node_type 없음" }
+                ]
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        let docs = all_docs(dir.path());
+        let weather = docs.iter().find(|d| d.node_type == "weather_node").unwrap();
+        assert_eq!(weather.source, "plugin:weather");
+        assert_eq!(weather.examples[0].inputs, json!({ "city": "Seoul" }));
+        let file_creator: Vec<_> = docs
+            .iter()
+            .filter(|d| d.node_type == "file_creator_node")
+            .collect();
+        assert_eq!(file_creator.len(), 1);
+        assert_eq!(file_creator[0].source, "builtin");
+    }
+}
@@ -0,0 +1,207 @@
+// src-tauri/src/nodes/node_output.rs
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::Path;
+
+// 📦 노드 출력 표준 봉투 어댑터: {"type": "text"|"json"|"file_path"|"file_list"|"binary_ref", "value": ...}
+// 노드 명령은 지금처럼 문자열 / JSON 문자열을 반환하고 (프론트엔드 노드가 그 형식을 파싱하므로 그대로 둠)
+// 실행 엔진이 노드 사이 연결에서 normalize_node_output 으로 봉투로 바꾼 뒤
+// 받는 쪽 입력 타입이 다르면 coerce_node_output 으로 맞춤 - 봉투를 직접 반환하는 노드는 없음
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum NodeOutput {
+    Text(String),
+    Json(Value),
+    FilePath(String),
+    FileList(Vec<String>),
+    // 큰 바이너리는 값 대신 임시 파일 경로/식별자로 전달
+    BinaryRef(String),
+}
+
+impl NodeOutput {
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            NodeOutput::Text(_) => "text",
+            NodeOutput::Json(_) => "json",
+            NodeOutput::FilePath(_) => "file_path",
+            NodeOutput::FileList(_) => "file_list",
+            NodeOutput::BinaryRef(_) => "binary_ref",
+        }
+    }
+
+    // 기존 노드의 원시 출력 → 봉투 (이미 봉투면 그대로)
+    pub fn from_legacy(raw: &str) -> NodeOutput {
+        let trimmed = raw.trim();
+        if trimmed.starts_with('{') || trimmed.starts_with('[') {
+            if let Ok(value) = serde_json::from_str::<Value>(trimmed) {
+                if let Ok(envelope) = serde_json::from_value::<NodeOutput>(value.clone()) {
+                    return envelope;
+                }
+                return NodeOutput::Json(value);
+            }
+        }
+
+        let lines: Vec<&str> = trimmed
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty())
+            .collect();
+        let looks_like_file =
+            |line: &&str| Path::new(line).is_absolute() && Path::new(line).exists();
+        match lines.as_slice() {
+            [single] if looks_like_file(single) => NodeOutput::FilePath(single.to_string()),
+            [_, _, ..] if lines.iter().all(looks_like_file) => {
+                NodeOutput::FileList(lines.iter().map(|l| l.to_string()).collect())
+            }
+            _ => NodeOutput::Text(raw.to_string()),
+        }
+    }
+
+    pub fn to_text(&self) -> String {
+        match self {
+            NodeOutput::Text(text) | NodeOutput::FilePath(text) | NodeOutput::BinaryRef(text) => {
+                text.clone()
+            }
+            NodeOutput::Json(Value::String(text)) => text.clone(),
+            NodeOutput::Json(value) => value.to_string(),
+            NodeOutput::FileList(files) => files.join("\n"),
+        }
+    }
+
+    // 받는 노드의 입력 타입에 맞게 변환 (불가능하면 TYPE_MISMATCH)
+    pub fn coerce(self, target: &str) -> Result<NodeOutput, String> {
+        let mismatch = |from: &str| Err(format!("TYPE_MISMATCH: {} → {}", from, target));
+        let from = self.type_name();
+        if from == target {
+            return Ok(self);
+        }
+
+        match (self, target) {
+            (NodeOutput::BinaryRef(_), _) => mismatch(from),
+            (output, "text") => Ok(NodeOutput::Text(output.to_text())),
+            (NodeOutput::Text(text), "json") => serde_json::from_str(text.trim())
+                .map(NodeOutput::Json)
+                .or(Ok(NodeOutput::Json(Value::String(text)))),
+            (NodeOutput::FilePath(path), "json") => Ok(NodeOutput::Json(Value::String(path))),
+            (NodeOutput::FileList(files), "json") => Ok(NodeOutput::Json(files.into())),
+            (NodeOutput::Text(text), "file_path") => match text.trim() {
+                path if !path.is_empty() && !path.contains('\n') => {
+                    Ok(NodeOutput::FilePath(path.to_string()))
+                }
+                _ => mismatch(from),
+            },
+            (NodeOutput::Json(Value::String(path)), "file_path") => Ok(NodeOutput::FilePath(path)),
+            (NodeOutput::FileList(files), "file_path") if files.len() == 1 => {
+                Ok(NodeOutput::FilePath(files[0].clone()))
+            }
+            (NodeOutput::Text(text), "file_list") => Ok(NodeOutput::FileList(
+                text.lines()
+                    .map(str::trim)
+                    .filter(|l| !l.is_empty())
+                    .map(str::to_string)
+                    .collect(),
+            )),
+            (NodeOutput::FilePath(path), "file_list") => Ok(NodeOutput::FileList(vec![path])),
+            (NodeOutput::Json(Value::Array(items)), "file_list") => items
+                .into_iter()
+                .map(|item| item.as_str().map(str::to_string))
+                .collect::<Option<Vec<_>>>()
+                .map(NodeOutput::FileList)
+                .map_or_else(|| mismatch("json"), Ok),
+            (NodeOutput::FilePath(path), "binary_ref") => Ok(NodeOutput::BinaryRef(path)),
+            _ => mismatch(from),
+        }
+    }
+}
+
+#[tauri::command]
+pub fn normalize_node_output(output: String) -> NodeOutput {
+    NodeOutput::from_legacy(&output)
+}
+
+#[tauri::command]
+pub fn coerce_node_output(output: NodeOutput, target: String) -> Result<NodeOutput, String> {
+    output.coerce(target.trim())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::{path_str, temp_dir};
+    use serde_json::json;
+    use std::fs;
+
+    #[test]
+    fn node_output_wraps_legacy_outputs() {
+        use NodeOutput;
+        let dir = temp_dir();
+        let a = dir.path().join("a.txt");
+        let b = dir.path().join("b.txt");
+        fs::write(&a, "a").unwrap();
+        fs::write(&b, "b").unwrap();
+
+        assert_eq!(
+            NodeOutput::from_legacy("hello"),
+            NodeOutput::Text("hello".to_string())
+        );
+        assert_eq!(
+            NodeOutput::from_legacy(r#"{"count": 2}"#),
+            NodeOutput::Json(json!({ "count": 2 }))
+        );
+        assert_eq!(
+            NodeOutput::from_legacy(r#"{"type": "file_path", "value": "/x.txt"}"#),
+            NodeOutput::FilePath("/x.txt".to_string())
+        );
+        assert_eq!(
+            NodeOutput::from_legacy(&path_str(&a)),
+            NodeOutput::FilePath(path_str(&a))
+        );
+        assert_eq!(
+            NodeOutput::from_legacy(&format!("{}\n{}\n", path_str(&a), path_str(&b))),
+            NodeOutput::FileList(vec![path_str(&a), path_str(&b)])
+        );
+
+        let envelope = serde_json::to_value(NodeOutput::FileList(vec!["x".to_string()])).unwrap();
+        assert_eq!(envelope, json!({ "type": "file_list", "value": ["x"] }));
+    }
+
+    #[test]
+    fn node_output_coerces_between_types() {
+        use NodeOutput;
+        let list = NodeOutput::FileList(vec!["/a".to_string(), "/b".to_string()]);
+        assert_eq!(
+            list.clone().coerce("text").unwrap(),
+            NodeOutput::Text("/a\n/b".to_string())
+        );
+        assert_eq!(
+            list.clone().coerce("json").unwrap(),
+            NodeOutput::Json(json!(["/a", "/b"]))
+        );
+        assert!(list
+            .coerce("file_path")
+            .unwrap_err()
+            .starts_with("TYPE_MISMATCH"));
+
+        assert_eq!(
+            NodeOutput::Text("/a\n\n/b".to_string())
+                .coerce("file_list")
+                .unwrap(),
+            NodeOutput::FileList(vec!["/a".to_string(), "/b".to_string()])
+        );
+        assert_eq!(
+            NodeOutput::Text("[1,2]".to_string())
+                .coerce("json")
+                .unwrap(),
+            NodeOutput::Json(json!([1, 2]))
+        );
+        assert_eq!(
+            NodeOutput::Json(json!("/c")).coerce("file_path").unwrap(),
+            NodeOutput::FilePath("/c".to_string())
+        );
+        assert!(NodeOutput::Json(json!([1])).coerce("file_list").is_err());
+        assert!(NodeOutput::BinaryRef("blob".to_string())
+            .coerce("text")
+            .is_err());
+    }
+}
@@ -0,0 +1,208 @@
+// src-tauri/src/nodes/normalize_text_node.rs
+use super::safe_path;
+use serde_json::json;
+use std::fs;
+
+// 🧹 줄바꿈 / 공백 정리 노드 (diff, 업로드, git 커밋 전 정리용)
+// - 줄바꿈: "lf" | "crlf" | "keep"
+// - BOM 제거, 줄 끝 공백 제거
+// - 들여쓰기: "spaces"(탭 → 공백) | "tabs"(줄 앞 공백 → 탭) | "keep"
+
+#[derive(Debug, Clone)]
+pub struct NormalizeOptions {
+    pub line_ending: String,
+    pub strip_bom: bool,
+    pub trim_trailing: bool,
+    pub indent: String,
+    pub tab_width: usize,
+}
+
+// 줄 앞 들여쓰기만 변환 (문자열 안의 탭은 유지)
+fn convert_indent(line: &str, indent: &str, tab_width: usize) -> String {
+    let body_start = line
+        .find(|c: char| c != ' ' && c != '\t')
+        .unwrap_or(line.len());
+    let (leading, body) = line.split_at(body_start);
+
+    // 들여쓰기 폭 계산 (탭은 다음 탭 위치까지)
+    let width = leading.chars().fold(0, |col, c| {
+        if c == '\t' {
+            (col / tab_width + 1) * tab_width
+        } else {
+            col + 1
+        }
+    });
+
+    let new_leading = match indent {
+        "spaces" => " ".repeat(width),
+        "tabs" => format!(
+            "{}{}",
+            "\t".repeat(width / tab_width),
+            " ".repeat(width % tab_width)
+        ),
+        _ => leading.to_string(),
+    };
+    format!("{}{}", new_leading, body)
+}
+
+pub fn normalize_text(text: &str, options: &NormalizeOptions) -> String {
+    let text = if options.strip_bom {
+        text.trim_start_matches('\u{FEFF}')
+    } else {
+        text
+    };
+
+    // 원본 줄바꿈 유지용: 첫 줄바꿈이 CRLF 인지 확인
+    let newline = match options.line_ending.as_str() {
+        "crlf" => "\r\n",
+        "lf" => "\n",
+        _ if text.contains("\r\n") => "\r\n",
+        _ => "\n",
+    };
+    let tab_width = options.tab_width.max(1);
+
+    // 줄 목록을 따로 만들지 않고 결과 버퍼 하나에 바로 씀 (큰 파일에서 사본이 여러 벌 생기지 않도록)
+    let ends_with_newline = text.ends_with('\n');
+    let body = if ends_with_newline {
+        &text[..text.len() - 1]
+    } else {
+        text
+    };
+    let mut result = String::with_capacity(text.len() + text.len() / 16);
+    for (i, line) in body.split('\n').enumerate() {
+        if i > 0 {
+            result.push_str(newline);
+        }
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        let converted;
+        let line = if options.indent != "keep" {
+            converted = convert_indent(line, &options.indent, tab_width);
+            converted.as_str()
+        } else {
+            line
+        };
+        result.push_str(if options.trim_trailing {
+            line.trim_end()
+        } else {
+            line
+        });
+    }
+    if ends_with_newline {
+        result.push_str(newline);
+    }
+    result
+}
+
+#[tauri::command]
+pub fn normalize_text_node(
+    file_paths: Vec<String>,
+    line_ending: Option<String>,
+    strip_bom: Option<bool>,
+    trim_trailing: Option<bool>,
+    indent: Option<String>,
+    tab_width: Option<usize>,
+) -> Result<String, String> {
+    if file_paths.is_empty() {
+        return Err("NO_FILES".to_string());
+    }
+
+    let options = NormalizeOptions {
+        line_ending: line_ending
+            .unwrap_or_else(|| "lf".to_string())
+            .to_lowercase(),
+        strip_bom: strip_bom.unwrap_or(true),
+        trim_trailing: trim_trailing.unwrap_or(true),
+        indent: indent.unwrap_or_else(|| "keep".to_string()).to_lowercase(),
+        tab_width: tab_width.unwrap_or(4),
+    };
+    if !["lf", "crlf", "keep"].contains(&options.line_ending.as_str()) {
+        return Err(format!("UNSUPPORTED_LINE_ENDING: {}", options.line_ending));
+    }
+    if !["spaces", "tabs", "keep"].contains(&options.indent.as_str()) {
+        return Err(format!("UNSUPPORTED_INDENT: {}", options.indent));
+    }
+
+    println!("🧹 Normalize Text Node: {}개 파일", file_paths.len());
+
+    let mut results = Vec::new();
+    let mut changed_count = 0;
+
+    for path in &file_paths {
+        let resolved = safe_path::resolve_existing(path);
+        let path = path.trim();
+        let outcome = fs::read(&resolved)
+            .map_err(|_| "FILE_NOT_FOUND".to_string())
+            .and_then(|bytes| {
+                // 텍스트가 아닌 파일(UTF-8 아님)은 건드리지 않음
+                String::from_utf8(bytes).map_err(|_| "NOT_UTF8_TEXT".to_string())
+            })
+            .and_then(|original| {
+                let normalized = normalize_text(&original, &options);
+                if normalized == original {
+                    return Ok(false);
+                }
+                fs::write(&resolved, normalized).map_err(|_| "FILE_WRITE_ERROR".to_string())?;
+                Ok(true)
+            });
+
+        match outcome {
+            Ok(changed) => {
+                if changed {
+                    changed_count += 1;
+                }
+                results.push(json!({ "file_path": path, "changed": changed }));
+            }
+            Err(e) => results.push(json!({ "file_path": path, "changed": false, "error": e })),
+        }
+    }
+
+    println!("✅ {}개 파일 정리됨", changed_count);
+    Ok(json!({ "files": results, "changed_count": changed_count }).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::{parse_output, path_str, temp_dir};
+    use std::fs;
+
+    #[test]
+    fn normalize_text_converts_line_endings_bom_and_indentation() {
+        let options = NormalizeOptions {
+            line_ending: "lf".to_string(),
+            strip_bom: true,
+            trim_trailing: true,
+            indent: "spaces".to_string(),
+            tab_width: 4,
+        };
+
+        let normalized = normalize_text(
+            "\u{FEFF}fn main() {  \r\n\tlet a = \"\t\";\r\n}\r\n",
+            &options,
+        );
+
+        assert_eq!(normalized, "fn main() {\n    let a = \"\t\";\n}\n");
+    }
+
+    #[test]
+    fn normalize_text_node_reports_changed_files() {
+        let dir = temp_dir();
+        let dirty = dir.path().join("dirty.txt");
+        let clean = dir.path().join("clean.txt");
+        fs::write(&dirty, "a \r\nb\r\n").unwrap();
+        fs::write(&clean, "a\nb\n").unwrap();
+
+        let output = normalize_text_node(
+            vec![path_str(&dirty), path_str(&clean)],
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(parse_output(&output)["changed_count"], 1);
+        assert_eq!(fs::read_to_string(&dirty).unwrap(), "a\nb\n");
+    }
+}
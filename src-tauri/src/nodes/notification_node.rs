@@ -0,0 +1,237 @@
+// src-tauri/src/nodes/notification_node.rs
+use base64::{engine::general_purpose, Engine as _};
+use serde_json::json;
+use tauri::{AppHandle, Emitter};
+
+// 🔔 알림 노드
+// - Windows: 토스트 알림 + 버튼/답장 입력창, 사용자의 선택은 "notification-action" 이벤트로 전달
+// - macOS / Linux: 기본 알림만 지원 (버튼/답장은 무시)
+
+// XML 특수문자 이스케이프 (토스트 XML에 사용자 텍스트 삽입용)
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+fn build_toast_xml(
+    title: &str,
+    message: &str,
+    actions: &[String],
+    reply_placeholder: Option<&str>,
+) -> String {
+    let mut action_xml = String::new();
+
+    if let Some(placeholder) = reply_placeholder {
+        action_xml.push_str(&format!(
+            r#"<input id="reply" type="text" placeHolderContent="{}"/>"#,
+            escape_xml(placeholder)
+        ));
+    }
+    for action in actions {
+        let action = escape_xml(action);
+        // 답장 입력창이 있으면 버튼이 입력값과 함께 제출됨
+        let input_binding = if reply_placeholder.is_some() {
+            r#" hint-inputId="reply""#
+        } else {
+            ""
+        };
+        action_xml.push_str(&format!(
+            r#"<action content="{}" arguments="{}" activationType="foreground"{}/>"#,
+            action, action, input_binding
+        ));
+    }
+
+    let actions_block = if action_xml.is_empty() {
+        String::new()
+    } else {
+        format!("<actions>{}</actions>", action_xml)
+    };
+
+    format!(
+        r#"<toast><visual><binding template="ToastGeneric"><text>{}</text><text>{}</text></binding></visual>{}</toast>"#,
+        escape_xml(title),
+        escape_xml(message),
+        actions_block
+    )
+}
+
+// 토스트를 띄우고 Activated/Dismissed 이벤트를 기다려 결과를 JSON 한 줄로 출력하는 스크립트
+fn build_toast_script(toast_xml: &str, timeout_secs: u64) -> String {
+    format!(
+        r#"
+[Windows.UI.Notifications.ToastNotificationManager, Windows.UI.Notifications, ContentType = WindowsRuntime] | Out-Null
+[Windows.Data.Xml.Dom.XmlDocument, Windows.Data.Xml.Dom.XmlDocument, ContentType = WindowsRuntime] | Out-Null
+$xml = New-Object Windows.Data.Xml.Dom.XmlDocument
+$xml.LoadXml('{}')
+$toast = [Windows.UI.Notifications.ToastNotification]::new($xml)
+Register-ObjectEvent -InputObject $toast -EventName Activated -SourceIdentifier ToastActivated | Out-Null
+Register-ObjectEvent -InputObject $toast -EventName Dismissed -SourceIdentifier ToastDismissed | Out-Null
+$appId = '{{1AC14E77-02E7-4E5D-B744-2EB1AE5198B7}}\WindowsPowerShell\v1.0\powershell.exe'
+[Windows.UI.Notifications.ToastNotificationManager]::CreateToastNotifier($appId).Show($toast)
+$event = Wait-Event -Timeout {}
+if ($event -eq $null) {{
+    '{{"result":"timeout"}}'
+}} elseif ($event.SourceIdentifier -eq 'ToastActivated') {{
+    $eventArgs = $event.SourceEventArgs
+    $reply = ''
+    if ($eventArgs.UserInput -ne $null -and $eventArgs.UserInput.ContainsKey('reply')) {{ $reply = [string]$eventArgs.UserInput['reply'] }}
+    @{{ result = 'activated'; action = $eventArgs.Arguments; reply = $reply }} | ConvertTo-Json -Compress
+}} else {{
+    '{{"result":"dismissed"}}'
+}}
+"#,
+        toast_xml.replace('\'', "''"),
+        timeout_secs
+    )
+}
+
+// PowerShell -EncodedCommand 용 UTF-16LE base64 (따옴표/한글 깨짐 방지)
+fn encode_powershell_command(script: &str) -> String {
+    let utf16: Vec<u8> = script
+        .encode_utf16()
+        .flat_map(|unit| unit.to_le_bytes())
+        .collect();
+    general_purpose::STANDARD.encode(utf16)
+}
+
+async fn show_windows_toast(
+    app_handle: AppHandle,
+    node_id: String,
+    toast_xml: String,
+    timeout_secs: u64,
+) -> Result<(), String> {
+    let script = build_toast_script(&toast_xml, timeout_secs);
+
+    let mut cmd = tokio::process::Command::new("powershell.exe");
+    cmd.args([
+        "-NoProfile",
+        "-NonInteractive",
+        "-EncodedCommand",
+        &encode_powershell_command(&script),
+    ]);
+
+    #[cfg(target_os = "windows")]
+    {
+        #[allow(unused_imports)]
+        use std::os::windows::process::CommandExt;
+        cmd.creation_flags(0x08000000); // CREATE_NO_WINDOW
+    }
+
+    let child = cmd
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("PowerShell 실행 실패: {}", e))?;
+
+    // 사용자 응답은 기다리지 않고 백그라운드에서 이벤트로 전달
+    tokio::spawn(async move {
+        let outcome = match child.wait_with_output().await {
+            Ok(output) => {
+                let stdout = String::from_utf8_lossy(&output.stdout);
+                serde_json::from_str::<serde_json::Value>(stdout.trim()).unwrap_or_else(|_| {
+                    json!({
+                        "result": "error",
+                        "error": String::from_utf8_lossy(&output.stderr).trim().to_string()
+                    })
+                })
+            }
+            Err(e) => json!({ "result": "error", "error": e.to_string() }),
+        };
+
+        let mut payload = outcome;
+        payload["node_id"] = json!(node_id);
+        println!("🔔 토스트 결과 (node {}): {}", node_id, payload);
+
+        if let Err(e) = app_handle.emit("notification-action", &payload) {
+            eprintln!("❌ Failed to emit notification action: {}", e);
+        }
+    });
+
+    Ok(())
+}
+
+fn show_basic_notification(title: &str, message: &str) -> Result<(), String> {
+    #[cfg(target_os = "macos")]
+    {
+        let script = format!(
+            "display notification \"{}\" with title \"{}\"",
+            message.replace('"', "\\\""),
+            title.replace('"', "\\\"")
+        );
+        std::process::Command::new("osascript")
+            .args(["-e", &script])
+            .output()
+            .map_err(|e| format!("osascript 실행 실패: {}", e))?;
+        Ok(())
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        std::process::Command::new("notify-send")
+            .args([title, message])
+            .output()
+            .map_err(|e| format!("notify-send 실행 실패: {}", e))?;
+        Ok(())
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "linux")))]
+    {
+        let _ = (title, message);
+        Err("지원하지 않는 운영체제입니다".to_string())
+    }
+}
+
+#[tauri::command]
+pub async fn notification_node(
+    app_handle: AppHandle,
+    node_id: Option<String>,
+    title: String,
+    message: String,
+    actions: Option<Vec<String>>,
+    reply_placeholder: Option<String>,
+    timeout_secs: Option<u64>,
+) -> Result<String, String> {
+    let node_id = node_id.unwrap_or_else(|| "unknown".to_string());
+    println!("🔔 Notification Node: {} - {}", title, message);
+
+    if title.trim().is_empty() && message.trim().is_empty() {
+        return Err("EMPTY_NOTIFICATION".to_string());
+    }
+
+    let actions: Vec<String> = actions
+        .unwrap_or_default()
+        .into_iter()
+        .map(|a| a.trim().to_string())
+        .filter(|a| !a.is_empty())
+        .take(5) // Windows 토스트 버튼 최대 5개
+        .collect();
+    let reply_placeholder = reply_placeholder.filter(|p| !p.trim().is_empty());
+    let interactive = !actions.is_empty() || reply_placeholder.is_some();
+
+    if cfg!(target_os = "windows") {
+        let toast_xml = build_toast_xml(&title, &message, &actions, reply_placeholder.as_deref());
+        show_windows_toast(
+            app_handle,
+            node_id.clone(),
+            toast_xml,
+            timeout_secs.unwrap_or(300),
+        )
+        .await?;
+    } else {
+        if interactive {
+            println!("⚠️ 버튼/답장 알림은 Windows에서만 지원됩니다 - 기본 알림으로 표시");
+        }
+        show_basic_notification(&title, &message)?;
+    }
+
+    Ok(json!({
+        "node_id": node_id,
+        "shown": true,
+        "interactive": interactive && cfg!(target_os = "windows"),
+        "actions": actions
+    })
+    .to_string())
+}
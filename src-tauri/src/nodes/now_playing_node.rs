@@ -0,0 +1,323 @@
+// src-tauri/src/nodes/now_playing_node.rs
+use super::os_command;
+use serde::Serialize;
+use serde_json::json;
+
+// 🎵 현재 재생 중인 곡 노드 (방송 오버레이 / 재생 기록용)
+// Windows: SMTC(GlobalSystemMediaTransportControls), macOS: Spotify/Music AppleScript, Linux: MPRIS(playerctl)
+// action: get(기본) | play_pause | play | pause | next | previous
+// player: 특정 플레이어만 대상 (예: spotify) - 비우면 현재 세션
+
+const ACTIONS: &[&str] = &["get", "play_pause", "play", "pause", "next", "previous"];
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct NowPlaying {
+    // playing | paused | stopped
+    pub status: String,
+    pub title: String,
+    pub artist: String,
+    pub album: String,
+    pub player: String,
+    pub position_ms: Option<u64>,
+    pub duration_ms: Option<u64>,
+}
+
+impl NowPlaying {
+    // 오버레이에 바로 쓰는 "아티스트 - 제목"
+    pub fn display_text(&self) -> String {
+        match (self.artist.is_empty(), self.title.is_empty()) {
+            (false, false) => format!("{} - {}", self.artist, self.title),
+            (true, false) => self.title.clone(),
+            (false, true) => self.artist.clone(),
+            (true, true) => String::new(),
+        }
+    }
+}
+
+// OS 마다 다른 재생 상태 이름을 통일
+pub fn normalize_status(status: &str) -> String {
+    match status.trim().to_lowercase().as_str() {
+        "playing" | "4" => "playing",
+        "paused" | "5" => "paused",
+        _ => "stopped",
+    }
+    .to_string()
+}
+
+fn parse_ms(value: Option<&str>) -> Option<u64> {
+    value
+        .map(str::trim)
+        .and_then(|v| v.parse::<f64>().ok())
+        .filter(|v| *v > 0.0)
+        .map(|v| v.round() as u64)
+}
+
+// 플랫폼 스크립트 출력 한 줄 해석
+// status \t title \t artist \t album \t player \t position_ms \t duration_ms (재생 중인 세션이 없으면 빈 줄)
+pub fn parse_track_line(line: &str) -> Option<NowPlaying> {
+    let line = line.trim_end_matches(['\r', '\n']);
+    if line.trim().is_empty() {
+        return None;
+    }
+    let fields: Vec<&str> = line.split('\t').collect();
+    let field = |index: usize| {
+        fields
+            .get(index)
+            .map(|f| f.trim())
+            .unwrap_or("")
+            .to_string()
+    };
+    let track = NowPlaying {
+        status: normalize_status(&field(0)),
+        title: field(1),
+        artist: field(2),
+        album: field(3),
+        player: field(4),
+        position_ms: parse_ms(fields.get(5).copied()),
+        duration_ms: parse_ms(fields.get(6).copied()),
+    };
+    (!track.title.is_empty() || !track.artist.is_empty()).then_some(track)
+}
+
+// Windows: WinRT 비동기 호출을 PowerShell 에서 기다리기 위한 AsTask 헬퍼 + 세션 선택
+#[cfg(target_os = "windows")]
+fn smtc_script(player: Option<&str>, body: &str) -> String {
+    format!(
+        r#"
+Add-Type -AssemblyName System.Runtime.WindowsRuntime
+$asTask = [System.WindowsRuntimeSystemExtensions].GetMethods() | Where-Object {{
+    $_.Name -eq 'AsTask' -and $_.GetParameters().Count -eq 1 -and $_.GetParameters()[0].ParameterType.Name -eq 'IAsyncOperation`1'
+}} | Select-Object -First 1
+function Await($op, [Type]$type) {{
+    $task = $asTask.MakeGenericMethod($type).Invoke($null, @($op))
+    $task.Wait(-1) | Out-Null
+    $task.Result
+}}
+$managerType = [Windows.Media.Control.GlobalSystemMediaTransportControlsSessionManager, Windows.Media.Control, ContentType = WindowsRuntime]
+$manager = Await ($managerType::RequestAsync()) $managerType
+$filter = {}
+if ($filter) {{
+    $session = $manager.GetSessions() | Where-Object {{ $_.SourceAppUserModelId -like "*$filter*" }} | Select-Object -First 1
+}} else {{
+    $session = $manager.GetCurrentSession()
+}}
+if (-not $session) {{ return }}
+{}
+"#,
+        os_command::ps_quote(player.unwrap_or("")),
+        body
+    )
+}
+
+#[cfg(target_os = "windows")]
+fn read_track(player: Option<&str>) -> Result<Option<NowPlaying>, String> {
+    let body = r#"
+$props = Await ($session.TryGetMediaPropertiesAsync()) ([Windows.Media.Control.GlobalSystemMediaTransportControlsSessionMediaProperties])
+$timeline = $session.GetTimelineProperties()
+$fields = @(
+    $session.GetPlaybackInfo().PlaybackStatus,
+    $props.Title,
+    $props.Artist,
+    $props.AlbumTitle,
+    $session.SourceAppUserModelId,
+    [int64]$timeline.Position.TotalMilliseconds,
+    [int64]$timeline.EndTime.TotalMilliseconds
+)
+($fields | ForEach-Object { "$_" -replace "`t", " " }) -join "`t"
+"#;
+    let output = os_command::powershell(&smtc_script(player, body))?;
+    Ok(parse_track_line(&output))
+}
+
+#[cfg(target_os = "windows")]
+fn control(action: &str, player: Option<&str>) -> Result<bool, String> {
+    let method = match action {
+        "play_pause" => "TryTogglePlayPauseAsync",
+        "play" => "TryPlayAsync",
+        "pause" => "TryPauseAsync",
+        "next" => "TrySkipNextAsync",
+        "previous" => "TrySkipPreviousAsync",
+        _ => return Err(format!("UNSUPPORTED_ACTION: {}", action)),
+    };
+    let body = format!("Await ($session.{}()) ([bool])", method);
+    let output = os_command::powershell(&smtc_script(player, &body))?;
+    match output.trim().to_lowercase().as_str() {
+        "" => Ok(false),
+        result => Ok(result == "true"),
+    }
+}
+
+// macOS: Spotify 가 실행 중이면 Spotify, 아니면 Music 앱
+#[cfg(target_os = "macos")]
+fn mac_app(player: Option<&str>) -> Result<&'static str, String> {
+    match player.map(|p| p.trim().to_lowercase()) {
+        Some(p) if p.contains("spotify") => Ok("Spotify"),
+        Some(p) if p.contains("music") || p.contains("itunes") => Ok("Music"),
+        Some(p) if !p.is_empty() => Err(format!("UNSUPPORTED_PLAYER: {}", p)),
+        _ => {
+            let spotify_running = os_command::run_optional(
+                "osascript",
+                &["-e", "application \"Spotify\" is running"],
+            )
+            .is_some_and(|out| out == "true");
+            Ok(if spotify_running { "Spotify" } else { "Music" })
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn read_track(player: Option<&str>) -> Result<Option<NowPlaying>, String> {
+    let app = mac_app(player)?;
+    // Spotify 의 duration 은 ms, Music 은 초 단위
+    let duration = if app == "Spotify" {
+        "(duration of current track) as integer"
+    } else {
+        "((duration of current track) * 1000) as integer"
+    };
+    let script = format!(
+        r#"
+if application "{app}" is not running then return ""
+tell application "{app}"
+    set st to (player state as string)
+    if st is "stopped" then return ""
+    set pos to ((player position) * 1000) as integer
+    set dur to {duration}
+    return st & tab & (name of current track) & tab & (artist of current track) & tab & (album of current track) & tab & "{app}" & tab & pos & tab & dur
+end tell
+"#
+    );
+    let output = os_command::run("osascript", &["-e", &script])?;
+    Ok(parse_track_line(&output))
+}
+
+#[cfg(target_os = "macos")]
+fn control(action: &str, player: Option<&str>) -> Result<bool, String> {
+    let app = mac_app(player)?;
+    let command = match action {
+        "play_pause" => "playpause",
+        "play" => "play",
+        "pause" => "pause",
+        "next" => "next track",
+        "previous" => "previous track",
+        _ => return Err(format!("UNSUPPORTED_ACTION: {}", action)),
+    };
+    let script =
+        format!("if application \"{app}\" is running then tell application \"{app}\" to {command}");
+    os_command::run("osascript", &["-e", &script]).map(|_| true)
+}
+
+// Linux: playerctl (MPRIS), 위치/길이는 마이크로초 단위
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn playerctl(player: Option<&str>, args: &[&str]) -> Result<String, String> {
+    let mut all: Vec<&str> = Vec::new();
+    if let Some(player) = player.filter(|p| !p.trim().is_empty()) {
+        all.extend(["--player", player]);
+    }
+    all.extend(args);
+    os_command::run("playerctl", &all)
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn read_track(player: Option<&str>) -> Result<Option<NowPlaying>, String> {
+    let format = "{{status}}\t{{title}}\t{{artist}}\t{{album}}\t{{playerName}}\t{{position}}\t{{mpris:length}}";
+    // 재생 중인 플레이어가 없으면 playerctl 이 실패 → 재생 없음으로 처리
+    let Ok(output) = playerctl(player, &["metadata", "--format", format]) else {
+        return Ok(None);
+    };
+    Ok(parse_track_line(&output).map(|mut track| {
+        track.position_ms = track.position_ms.map(|us| us / 1000);
+        track.duration_ms = track.duration_ms.map(|us| us / 1000);
+        track
+    }))
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn control(action: &str, player: Option<&str>) -> Result<bool, String> {
+    let command = match action {
+        "play_pause" => "play-pause",
+        "play" => "play",
+        "pause" => "pause",
+        "next" => "next",
+        "previous" => "previous",
+        _ => return Err(format!("UNSUPPORTED_ACTION: {}", action)),
+    };
+    playerctl(player, &[command])
+        .map(|_| true)
+        .map_err(|_| "NO_MEDIA_SESSION".to_string())
+}
+
+#[tauri::command]
+pub async fn now_playing_node(
+    action: Option<String>,
+    player: Option<String>,
+) -> Result<String, String> {
+    let action = action
+        .map(|a| a.trim().to_lowercase())
+        .filter(|a| !a.is_empty())
+        .unwrap_or_else(|| "get".to_string());
+    let player = player.filter(|p| !p.trim().is_empty());
+    println!("🎵 Now Playing Node: {} {:?}", action, player);
+
+    if !ACTIONS.contains(&action.as_str()) {
+        return Err(format!("UNSUPPORTED_ACTION: {}", action));
+    }
+
+    let task_action = action.clone();
+    let track = tokio::task::spawn_blocking(move || {
+        let player = player.as_deref();
+        if task_action != "get" {
+            if !control(&task_action, player)? {
+                return Err("NO_MEDIA_SESSION".to_string());
+            }
+            // 플레이어가 상태를 갱신할 시간을 준 뒤 다시 읽음
+            std::thread::sleep(std::time::Duration::from_millis(400));
+        }
+        read_track(player)
+    })
+    .await
+    .map_err(|e| format!("재생 정보 조회 실패: {}", e))??;
+
+    Ok(json!({
+        "action": action,
+        "playing": track.as_ref().is_some_and(|t| t.status == "playing"),
+        "text": track.as_ref().map(|t| t.display_text()).unwrap_or_default(),
+        "track": track
+    })
+    .to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn now_playing_parses_track_line_from_platform_scripts() {
+        let track = parse_track_line(
+            "Playing\tSong\tArtist\tAlbum\tSpotify.exe\t61500\t200000\r\n",
+        )
+        .unwrap();
+        assert_eq!(track.status, "playing");
+        assert_eq!(track.player, "Spotify.exe");
+        assert_eq!(track.position_ms, Some(61500));
+        assert_eq!(track.duration_ms, Some(200000));
+        assert_eq!(track.display_text(), "Artist - Song");
+
+        // 길이를 모르는 스트림 / 제목만 있는 경우
+        let track = parse_track_line("paused\tRadio\t\t\tvlc\t\t").unwrap();
+        assert_eq!(track.status, "paused");
+        assert_eq!(track.duration_ms, None);
+        assert_eq!(track.display_text(), "Radio");
+
+        assert!(parse_track_line("").is_none());
+        assert!(parse_track_line("Stopped\t\t\t\t\t0\t0").is_none());
+        assert_eq!(normalize_status("Changing"), "stopped");
+    }
+
+    #[tokio::test]
+    async fn now_playing_rejects_unknown_action() {
+        let err = now_playing_node(Some("shuffle".into()), None)
+            .await
+            .unwrap_err();
+        assert!(err.starts_with("UNSUPPORTED_ACTION"));
+    }
+}
@@ -0,0 +1,260 @@
+// src-tauri/src/nodes/origin_policy.rs
+use std::net::IpAddr;
+use std::sync::{Arc, RwLock};
+use warp::http::StatusCode;
+use warp::{Filter, Rejection, Reply};
+
+// 🌐 로컬 서버 Origin 제한 (CORS)
+// - 같은 출처(Origin 의 host == Host 헤더) 요청은 허용 → 채팅 페이지는 allowed_origins 없이 동작
+//   단, Host 가 localhost / IP 주소 / 현재 터널 주소일 때만 (DNS 리바인딩: evil.example → 127.0.0.1 이면 Host 도 evil.example)
+// - 다른 출처는 allowed_origins 에 있는 것만 허용 ("*" = 모두 허용, 터널 사용 중에는 경고)
+// - 기본값: 터널 사용 중이면 같은 출처만, 아니면 같은 출처 + 앱 웹뷰/개발 서버
+// 악성 웹페이지가 브라우저를 통해 로컬 엔드포인트(WebSocket 포함)를 조작하는 것을 막기 위함
+
+const LOCAL_APP_ORIGINS: [&str; 3] = [
+    "tauri://localhost",
+    "http://tauri.localhost",
+    "http://localhost:1420",
+];
+
+#[derive(Debug)]
+pub struct OriginPolicy {
+    allow_any: bool,
+    allowed: Vec<String>,
+    // 터널이 켜진 뒤 알게 되는 공개 호스트 (예: abc.trycloudflare.com)
+    tunnel_host: RwLock<Option<String>>,
+}
+
+#[derive(Debug)]
+struct OriginRejected;
+
+impl warp::reject::Reject for OriginRejected {}
+
+// "HTTPS://Example.com:443/" → "https://example.com"
+pub fn normalize_origin(origin: &str) -> Option<String> {
+    let origin = origin.trim().trim_end_matches('/').to_lowercase();
+    let (scheme, rest) = origin.split_once("://")?;
+    if scheme.is_empty() || rest.is_empty() || rest.contains('/') {
+        return None;
+    }
+    let host = match (scheme, rest.rsplit_once(':')) {
+        ("http", Some((host, "80"))) | ("https", Some((host, "443"))) => host.to_string(),
+        _ => rest.to_string(),
+    };
+    Some(format!("{}://{}", scheme, host))
+}
+
+fn origin_host(origin: &str) -> Option<&str> {
+    origin.split_once("://").map(|(_, host)| host)
+}
+
+fn strip_default_port(host: &str) -> &str {
+    host.strip_suffix(":80")
+        .or_else(|| host.strip_suffix(":443"))
+        .unwrap_or(host)
+}
+
+// "127.0.0.1:8080" → "127.0.0.1", "[::1]:8080" → "::1"
+fn host_name(host: &str) -> &str {
+    if let Some(rest) = host.strip_prefix('[') {
+        return rest.split(']').next().unwrap_or(rest);
+    }
+    match host.rsplit_once(':') {
+        Some((name, port)) if port.chars().all(|c| c.is_ascii_digit()) => name,
+        _ => host,
+    }
+}
+
+impl OriginPolicy {
+    pub fn new(configured: Option<Vec<String>>, tunnel_active: bool) -> Self {
+        let configured: Vec<String> = configured
+            .unwrap_or_default()
+            .iter()
+            .map(|o| o.trim().to_string())
+            .filter(|o| !o.is_empty())
+            .collect();
+
+        if configured.is_empty() {
+            let allowed = if tunnel_active {
+                Vec::new()
+            } else {
+                LOCAL_APP_ORIGINS.iter().map(|o| o.to_string()).collect()
+            };
+            return Self {
+                allow_any: false,
+                allowed,
+                tunnel_host: RwLock::new(None),
+            };
+        }
+
+        let allow_any = configured.iter().any(|o| o == "*");
+        if allow_any && tunnel_active {
+            println!(
+                "⚠️ 터널 사용 중 모든 Origin 허용(*) - 외부 웹페이지가 서버를 조작할 수 있습니다"
+            );
+        }
+        Self {
+            allow_any,
+            allowed: configured
+                .iter()
+                .filter_map(|o| normalize_origin(o))
+                .collect(),
+            tunnel_host: RwLock::new(None),
+        }
+    }
+
+    // 터널 주소가 생기거나 꺼질 때 갱신
+    pub fn set_tunnel_url(&self, url: Option<&str>) {
+        let host = url
+            .and_then(normalize_origin)
+            .and_then(|origin| origin_host(&origin).map(|host| host.to_string()));
+        if let Ok(mut slot) = self.tunnel_host.write() {
+            *slot = host;
+        }
+    }
+
+    // 같은 출처로 믿을 수 있는 Host: localhost, IP 주소(리바인딩 불가), 현재 터널 호스트
+    fn trusted_host(&self, host: &str) -> bool {
+        let name = host_name(host);
+        name == "localhost"
+            || name.parse::<IpAddr>().is_ok()
+            || self
+                .tunnel_host
+                .read()
+                .is_ok_and(|tunnel| tunnel.as_deref() == Some(host))
+    }
+
+    // Origin 헤더가 없으면(브라우저가 아닌 클라이언트, 같은 출처 GET) 허용
+    pub fn allows(&self, origin: Option<&str>, host: Option<&str>) -> bool {
+        let Some(origin) = origin else {
+            return true;
+        };
+        if self.allow_any {
+            return true;
+        }
+        let Some(origin) = normalize_origin(origin) else {
+            return false;
+        };
+        let same_origin = host.is_some_and(|host| {
+            let host = host.trim().to_lowercase();
+            let host = strip_default_port(&host);
+            self.trusted_host(host) && origin_host(&origin) == Some(host)
+        });
+        same_origin || self.allowed.contains(&origin)
+    }
+}
+
+// 허용된 경우 응답에 붙일 Origin 을 넘겨주고, 아니면 403
+pub fn origin_filter(
+    policy: Arc<OriginPolicy>,
+) -> impl Filter<Extract = (Option<String>,), Error = Rejection> + Clone {
+    warp::header::optional::<String>("origin")
+        .and(warp::header::optional::<String>("host"))
+        .and_then(move |origin: Option<String>, host: Option<String>| {
+            let policy = policy.clone();
+            async move {
+                if policy.allows(origin.as_deref(), host.as_deref()) {
+                    Ok(origin)
+                } else {
+                    println!("🚫 허용되지 않은 Origin 차단: {:?}", origin);
+                    Err(warp::reject::custom(OriginRejected))
+                }
+            }
+        })
+}
+
+pub fn with_cors_headers<R: Reply + 'static>(origin: Option<String>, reply: R) -> Box<dyn Reply> {
+    match origin {
+        Some(origin) => Box::new(warp::reply::with_header(
+            warp::reply::with_header(reply, "access-control-allow-origin", origin),
+            "vary",
+            "Origin",
+        )),
+        None => Box::new(reply),
+    }
+}
+
+// OPTIONS 사전 요청 응답
+pub fn preflight(origin: Option<String>) -> Box<dyn Reply> {
+    let reply = warp::reply::with_header(
+        warp::reply::with_header(
+            warp::reply::with_status(warp::reply(), StatusCode::NO_CONTENT),
+            "access-control-allow-methods",
+            "GET, POST",
+        ),
+        "access-control-allow-headers",
+        "content-type",
+    );
+    with_cors_headers(origin, reply)
+}
+
+// Origin 거부는 403, 나머지는 warp 기본 처리
+pub async fn handle_rejection(rejection: Rejection) -> Result<Box<dyn Reply>, Rejection> {
+    if rejection.find::<OriginRejected>().is_some() {
+        return Ok(Box::new(warp::reply::with_status(
+            "Origin not allowed",
+            StatusCode::FORBIDDEN,
+        )));
+    }
+    Err(rejection)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn origin_policy_normalizes_origins() {
+        assert_eq!(
+            normalize_origin("HTTPS://Example.com:443/").as_deref(),
+            Some("https://example.com")
+        );
+        assert_eq!(
+            normalize_origin("http://localhost:1420").as_deref(),
+            Some("http://localhost:1420")
+        );
+        assert_eq!(normalize_origin("null"), None);
+        assert_eq!(normalize_origin("https://a.com/path"), None);
+    }
+
+    #[test]
+    fn origin_policy_is_strict_when_tunnel_active() {
+        let tunnel = OriginPolicy::new(None, true);
+        tunnel.set_tunnel_url(Some("https://abc.trycloudflare.com"));
+        let host = Some("abc.trycloudflare.com");
+        assert!(tunnel.allows(Some("https://abc.trycloudflare.com"), host));
+        assert!(tunnel.allows(None, host));
+        assert!(!tunnel.allows(Some("https://evil.example"), host));
+        assert!(!tunnel.allows(Some("http://localhost:1420"), host));
+
+        let local = OriginPolicy::new(None, false);
+        assert!(local.allows(Some("http://localhost:1420"), Some("192.168.0.5:8080")));
+        assert!(local.allows(Some("http://192.168.0.5:8080"), Some("192.168.0.5:8080")));
+        assert!(!local.allows(Some("https://evil.example"), Some("192.168.0.5:8080")));
+
+        let configured =
+            OriginPolicy::new(Some(vec!["https://app.example.com/".to_string()]), true);
+        assert!(configured.allows(Some("https://app.example.com"), host));
+        assert!(!configured.allows(Some("https://other.example.com"), host));
+
+        let any = OriginPolicy::new(Some(vec!["*".to_string()]), false);
+        assert!(any.allows(Some("https://evil.example"), None));
+    }
+
+    #[test]
+    fn origin_policy_rejects_dns_rebinding_hosts() {
+        let local = OriginPolicy::new(None, false);
+        assert!(local.allows(Some("http://localhost:8080"), Some("localhost:8080")));
+        assert!(local.allows(Some("http://[::1]:8080"), Some("[::1]:8080")));
+        // evil.example 이 127.0.0.1 로 풀려도 Host 는 evil.example
+        assert!(!local.allows(Some("http://evil.example:8080"), Some("evil.example:8080")));
+
+        let tunnel = OriginPolicy::new(None, true);
+        let host = Some("abc.trycloudflare.com");
+        assert!(!tunnel.allows(Some("https://abc.trycloudflare.com"), host));
+        tunnel.set_tunnel_url(Some("https://abc.trycloudflare.com"));
+        assert!(tunnel.allows(Some("https://abc.trycloudflare.com"), host));
+        tunnel.set_tunnel_url(None);
+        assert!(!tunnel.allows(Some("https://abc.trycloudflare.com"), host));
+    }
+}
@@ -0,0 +1,429 @@
+// src-tauri/src/nodes/os_command.rs
+use std::process::Command;
+
+// 🖥️ OS 유틸리티 노드 공용 명령 실행 헬퍼
+// Windows에서는 콘솔 창이 뜨지 않도록 CREATE_NO_WINDOW 로 실행
+
+fn build_command(program: &str, args: &[&str]) -> Command {
+    let mut cmd = Command::new(program);
+    cmd.args(args);
+
+    #[cfg(target_os = "windows")]
+    {
+        #[allow(unused_imports)]
+        use std::os::windows::process::CommandExt;
+        cmd.creation_flags(0x08000000); // CREATE_NO_WINDOW
+    }
+
+    cmd
+}
+
+// 명령 실행 후 stdout 반환 (실패 시 stderr 포함 에러)
+pub fn run(program: &str, args: &[&str]) -> Result<String, String> {
+    let output = build_command(program, args)
+        .output()
+        .map_err(|e| format!("{} 실행 실패: {}", program, e))?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        Err(format!(
+            "{} 실패: {}",
+            program,
+            if stderr.is_empty() { stdout } else { stderr }
+        ))
+    }
+}
+
+// 실패해도 상관없는 조회용 (None 반환)
+pub fn run_optional(program: &str, args: &[&str]) -> Option<String> {
+    run(program, args).ok()
+}
+
+// PowerShell 스크립트 실행
+pub fn powershell(script: &str) -> Result<String, String> {
+    run(
+        "powershell",
+        &[
+            "-NoProfile",
+            "-NonInteractive",
+            "-ExecutionPolicy",
+            "Bypass",
+            "-Command",
+            script,
+        ],
+    )
+}
+
+// PowerShell 작은따옴표 문자열 이스케이프
+pub fn ps_quote(text: &str) -> String {
+    format!("'{}'", text.replace('\'', "''"))
+}
+
+// 실행 파일 옆 binaries 폴더의 번들 도구 경로 (Windows 는 .exe), 없으면 PATH 에서 찾도록 이름 그대로
+pub fn bundled_binary(name: &str) -> String {
+    let file_name = if cfg!(target_os = "windows") {
+        format!("{}.exe", name)
+    } else {
+        name.to_string()
+    };
+
+    // binaries 폴더 → 도구 관리자가 설치한 폴더 → PATH
+    std::env::current_exe()
+        .ok()
+        .and_then(|exe| {
+            exe.parent()
+                .map(|dir| dir.join("binaries").join(&file_name))
+        })
+        .filter(|path| path.exists())
+        .or_else(|| super::tools::managed_path(&file_name))
+        .map(|path| path.to_string_lossy().to_string())
+        .unwrap_or(file_name)
+}
+
+// 🛡️ 관리자 권한 실행 결과
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ElevatedOutput {
+    pub status: i32,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+fn cancelled_error(message: &str) -> bool {
+    let lower = message.to_lowercase();
+    [
+        "canceled by the user",
+        "cancelled by the user",
+        "사용자가 취소",
+        "a password is required",
+        "no password was provided",
+        "incorrect password attempt",
+    ]
+    .iter()
+    .any(|pattern| lower.contains(pattern))
+}
+
+// 관리자 권한으로 명령 실행 (사용자에게 UAC / 암호 입력 창을 띄움)
+// - Windows: Start-Process -Verb RunAs 로 UAC 요청, 출력은 임시 파일로 리다이렉트해서 회수
+// - Unix: sudo -A + askpass 스크립트 (macOS osascript / zenity / kdialog / ssh-askpass)
+// 사용자가 취소하면 ELEVATION_CANCELLED
+pub fn run_elevated(
+    program: &str,
+    args: &[String],
+    cwd: Option<&str>,
+) -> Result<ElevatedOutput, String> {
+    #[cfg(target_os = "windows")]
+    {
+        // 인자 안의 & | > ^ %VAR% 등이 셸 문법으로 해석되지 않도록 메타문자를 모두 이스케이프
+        let mut line = vec![windows_arg_quote(program)];
+        line.extend(args.iter().map(|arg| windows_arg_quote(arg)));
+        run_elevated_cmd(&cmd_escape(&line.join(" ")), cwd)
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let mut argv = vec![program.to_string()];
+        argv.extend(args.iter().cloned());
+        sudo_askpass(&argv, cwd)
+    }
+}
+
+// 셸 명령 문자열을 관리자 권한으로 실행 (cmd /C 또는 sh -c)
+pub fn run_elevated_shell(command_line: &str, cwd: Option<&str>) -> Result<ElevatedOutput, String> {
+    #[cfg(target_os = "windows")]
+    {
+        run_elevated_cmd(command_line, cwd)
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let argv = vec!["sh".to_string(), "-c".to_string(), command_line.to_string()];
+        sudo_askpass(&argv, cwd)
+    }
+}
+
+// cmd /S /C 로 UAC 실행, command_line 은 cmd 가 그대로 해석하는 문자열
+#[cfg(target_os = "windows")]
+fn run_elevated_cmd(command_line: &str, cwd: Option<&str>) -> Result<ElevatedOutput, String> {
+    let stamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or(0);
+    let temp = std::env::temp_dir();
+    let out_path = temp.join(format!(
+        "automation_elevated_{}_{}.out",
+        std::process::id(),
+        stamp
+    ));
+    let err_path = temp.join(format!(
+        "automation_elevated_{}_{}.err",
+        std::process::id(),
+        stamp
+    ));
+
+    let cd = match cwd {
+        Some(dir) => format!(
+            "cd /d {} && ",
+            cmd_escape(&windows_arg_quote(checked_cwd(dir)?))
+        ),
+        None => String::new(),
+    };
+    let cmd_args = format!(
+        "/S /C \"{}{} > \"{}\" 2> \"{}\"\"",
+        cd,
+        command_line,
+        out_path.display(),
+        err_path.display()
+    );
+    let script = format!(
+        "$p = Start-Process -FilePath 'cmd.exe' -ArgumentList {} -Verb RunAs -Wait -PassThru -WindowStyle Hidden; $p.ExitCode",
+        ps_quote(&cmd_args)
+    );
+
+    let result = powershell(&script);
+    let stdout = std::fs::read_to_string(&out_path).unwrap_or_default();
+    let stderr = std::fs::read_to_string(&err_path).unwrap_or_default();
+    let _ = std::fs::remove_file(&out_path);
+    let _ = std::fs::remove_file(&err_path);
+
+    let exit_code = result.map_err(|e| {
+        if cancelled_error(&e) {
+            "ELEVATION_CANCELLED: 사용자가 관리자 권한 요청을 취소했습니다".to_string()
+        } else {
+            format!("ELEVATION_FAILED: {}", e)
+        }
+    })?;
+
+    Ok(ElevatedOutput {
+        status: exit_code.trim().parse().unwrap_or(-1),
+        stdout,
+        stderr,
+    })
+}
+
+// 작업 폴더: Windows 경로에는 " 가 들어갈 수 없으므로 있으면 거부
+#[cfg(any(target_os = "windows", test))]
+fn checked_cwd(dir: &str) -> Result<&str, String> {
+    if dir.contains('"') {
+        return Err(format!("INVALID_CWD: {}", dir));
+    }
+    Ok(dir)
+}
+
+// 인자 하나를 CommandLineToArgvW 규칙으로 감쌈 (따옴표 앞 / 끝의 역슬래시는 두 배로)
+#[cfg(any(target_os = "windows", test))]
+fn windows_arg_quote(arg: &str) -> String {
+    if !arg.is_empty() && !arg.contains(|c: char| c.is_whitespace() || c == '"') {
+        return arg.to_string();
+    }
+    let mut quoted = String::from("\"");
+    let mut backslashes = 0;
+    for c in arg.chars() {
+        match c {
+            '\\' => backslashes += 1,
+            '"' => {
+                quoted.push_str(&"\\".repeat(backslashes * 2 + 1));
+                quoted.push('"');
+                backslashes = 0;
+            }
+            _ => {
+                quoted.push_str(&"\\".repeat(backslashes));
+                quoted.push(c);
+                backslashes = 0;
+            }
+        }
+    }
+    quoted.push_str(&"\\".repeat(backslashes * 2));
+    quoted.push('"');
+    quoted
+}
+
+// cmd 메타문자를 모두 ^ 로 이스케이프 (따옴표까지 → cmd 가 따옴표 구간을 만들지 않아 전부 글자 그대로)
+#[cfg(any(target_os = "windows", test))]
+fn cmd_escape(line: &str) -> String {
+    let mut escaped = String::with_capacity(line.len() * 2);
+    for c in line.chars() {
+        if matches!(c, '(' | ')' | '%' | '!' | '^' | '"' | '<' | '>' | '&' | '|') {
+            escaped.push('^');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(not(target_os = "windows"))]
+const ASKPASS_SCRIPT: &str = r#"#!/bin/sh
+PROMPT="${1:-관리자 암호를 입력하세요}"
+if [ "$(uname)" = "Darwin" ]; then
+  exec osascript -e 'on run argv' -e 'text returned of (display dialog (item 1 of argv) default answer "" with hidden answer with title "Automation GUI")' -e 'end run' "$PROMPT"
+elif command -v zenity >/dev/null 2>&1; then
+  exec zenity --password --title="$PROMPT"
+elif command -v kdialog >/dev/null 2>&1; then
+  exec kdialog --password "$PROMPT"
+elif command -v ssh-askpass >/dev/null 2>&1; then
+  exec ssh-askpass "$PROMPT"
+fi
+exit 1
+"#;
+
+#[cfg(not(target_os = "windows"))]
+fn sudo_askpass(argv: &[String], cwd: Option<&str>) -> Result<ElevatedOutput, String> {
+    use std::io::Write;
+    use std::os::unix::fs::OpenOptionsExt;
+
+    // 이미 root 면 그대로 실행
+    let is_root = run_optional("id", &["-u"]).is_some_and(|uid| uid == "0");
+    // askpass 스크립트는 본인만 접근 가능한(0700) 새 임시 폴더에 만들고 실행이 끝나면 폴더째 삭제
+    let mut askpass_dir = None;
+    let mut cmd = if is_root {
+        let mut cmd = Command::new(&argv[0]);
+        cmd.args(&argv[1..]);
+        cmd
+    } else {
+        let dir = tempfile::Builder::new()
+            .prefix("automation_askpass_")
+            .tempdir()
+            .map_err(|e| format!("askpass 생성 실패: {}", e))?;
+        let askpass = dir.path().join("askpass.sh");
+        std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .mode(0o700)
+            .open(&askpass)
+            .and_then(|mut file| file.write_all(ASKPASS_SCRIPT.as_bytes()))
+            .map_err(|e| format!("askpass 생성 실패: {}", e))?;
+
+        // 어떤 명령을 실행하려는지 암호 창에 명시
+        let prompt = format!(
+            "Automation GUI 가 관리자 권한으로 다음 명령을 실행하려고 합니다:\n{}\n\n암호:",
+            argv.join(" ")
+        );
+        let mut cmd = Command::new("sudo");
+        cmd.arg("-A")
+            .arg("-p")
+            .arg(prompt.replace('%', "%%"))
+            .arg("--")
+            .args(argv);
+        cmd.env("SUDO_ASKPASS", &askpass);
+        askpass_dir = Some(dir);
+        cmd
+    };
+    if let Some(dir) = cwd {
+        cmd.current_dir(dir);
+    }
+
+    let output = cmd.output();
+    drop(askpass_dir);
+    let output = output.map_err(|e| format!("ELEVATION_FAILED: {}", e))?;
+    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+    if !output.status.success() && cancelled_error(&stderr) {
+        return Err("ELEVATION_CANCELLED: 사용자가 관리자 권한 요청을 취소했습니다".to_string());
+    }
+
+    Ok(ElevatedOutput {
+        status: output.status.code().unwrap_or(-1),
+        stdout: String::from_utf8_lossy(&output.stdout).to_string(),
+        stderr,
+    })
+}
+
+// 🐢 자식 프로세스 우선순위 (게임/화상회의 중 백그라운드 작업이 끊김을 만들지 않도록)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessPriority {
+    Normal,
+    BelowNormal,
+    Idle,
+}
+
+impl ProcessPriority {
+    // "normal" | "below_normal"(= "low") | "idle", 없으면 normal
+    pub fn parse(value: Option<&str>) -> Result<Self, String> {
+        match value
+            .map(|v| v.trim().to_lowercase().replace('-', "_"))
+            .as_deref()
+        {
+            None | Some("") | Some("normal") => Ok(Self::Normal),
+            Some("below_normal") | Some("low") => Ok(Self::BelowNormal),
+            Some("idle") | Some("lowest") => Ok(Self::Idle),
+            Some(other) => Err(format!("INVALID_PRIORITY: {}", other)),
+        }
+    }
+
+    // 실행된 프로세스의 CPU / IO 우선순위를 낮춤 (실패해도 작업은 계속)
+    // - Windows: PriorityClass (BelowNormal / Idle)
+    // - Unix: renice 10 / 19, Linux 는 ionice 도 적용 (best-effort 최저 / idle)
+    pub fn apply(self, pid: Option<u32>) {
+        let Some(pid) = pid else {
+            return;
+        };
+        if self == Self::Normal {
+            return;
+        }
+        let pid = pid.to_string();
+
+        #[cfg(target_os = "windows")]
+        {
+            let class = if self == Self::Idle {
+                "Idle"
+            } else {
+                "BelowNormal"
+            };
+            let script = format!("(Get-Process -Id {}).PriorityClass = '{}'", pid, class);
+            // PowerShell 시작이 느리므로 자식 프로세스를 기다리게 하지 않음
+            std::thread::spawn(move || {
+                if let Err(e) = powershell(&script) {
+                    println!("⚠️ 우선순위 변경 실패 (pid {}): {}", pid, e);
+                }
+            });
+        }
+
+        #[cfg(not(target_os = "windows"))]
+        {
+            let nice = if self == Self::Idle { "19" } else { "10" };
+            if let Err(e) = run("renice", &["-n", nice, "-p", &pid]) {
+                println!("⚠️ 우선순위 변경 실패 (pid {}): {}", pid, e);
+            }
+
+            #[cfg(target_os = "linux")]
+            {
+                let io_args: &[&str] = if self == Self::Idle {
+                    &["-c", "3", "-p", &pid]
+                } else {
+                    &["-c", "2", "-n", "7", "-p", &pid]
+                };
+                let _ = run("ionice", io_args);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn os_command_quotes_windows_arguments() {
+        assert_eq!(windows_arg_quote("plain"), "plain");
+        assert_eq!(windows_arg_quote(""), "\"\"");
+        assert_eq!(windows_arg_quote("a b"), "\"a b\"");
+        assert_eq!(windows_arg_quote("say \"hi\""), r#""say \"hi\"""#);
+        assert_eq!(windows_arg_quote(r"C:\my dir\"), r#""C:\my dir\\""#);
+    }
+
+    #[test]
+    fn os_command_escapes_every_cmd_metacharacter() {
+        let line = format!(
+            "{} {}",
+            windows_arg_quote("tool.exe"),
+            windows_arg_quote("a & del x | more > y %PATH% ^ !v! (z)")
+        );
+        assert_eq!(
+            cmd_escape(&line),
+            "tool.exe ^\"a ^& del x ^| more ^> y ^%PATH^% ^^ ^!v^! ^(z^)^\""
+        );
+        assert!(checked_cwd(r"C:\work").is_ok());
+        assert!(checked_cwd("C:\\work\" & calc").is_err());
+    }
+}
@@ -0,0 +1,520 @@
+// src-tauri/src/nodes/os_schedule.rs
+use super::{atomic_store, instance, os_command, schedule_conditions};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::path::PathBuf;
+
+// ⏰ OS 예약 작업 내보내기
+// 워크플로우 스케줄을 OS 기본 스케줄러(Windows 작업 스케줄러 / macOS launchd / Linux cron)에 등록해
+// 앱이 꺼져 있어도 "앱 실행파일 --run-workflow <경로>" 로 실행되게 함
+// 앱은 시작 시 take_launch_workflow 로 실행할 워크플로우를 받아 바로 실행
+// conditions(주말/공휴일 건너뛰기)는 예약 실행 시점에 앱이 확인 (--schedule-task <이름> 으로 구분)
+
+pub const RUN_WORKFLOW_ARG: &str = "--run-workflow";
+pub const SCHEDULE_TASK_ARG: &str = "--schedule-task";
+const CRON_MARKER: &str = "# automation-gui:";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduleSpec {
+    // "daily" | "weekly" | "hourly" | "interval" | "startup" | "logon"
+    pub kind: String,
+    // "HH:MM" (daily / weekly)
+    pub time: Option<String>,
+    // ["mon", "wed", ...] (weekly)
+    pub weekdays: Option<Vec<String>>,
+    // 분 단위 (interval)
+    pub interval_minutes: Option<u32>,
+    // 실행 조건 (없으면 항상 실행)
+    #[serde(default)]
+    pub conditions: Option<schedule_conditions::ScheduleConditions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OsScheduleEntry {
+    pub task_name: String,
+    pub workflow_path: String,
+    pub schedule: ScheduleSpec,
+    pub created_at: i64,
+}
+
+const WEEKDAYS: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+fn index_path() -> PathBuf {
+    atomic_store::store_dir().join("os_schedules.json")
+}
+
+fn load_index() -> Vec<OsScheduleEntry> {
+    atomic_store::read_json_verified(&index_path()).unwrap_or_default()
+}
+
+fn validate_task_name(name: &str) -> Result<&str, String> {
+    let name = name.trim();
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(format!(
+            "INVALID_TASK_NAME: {} (영문/숫자/-/_ 만 가능)",
+            name
+        ));
+    }
+    Ok(name)
+}
+
+pub fn parse_time(time: Option<&str>) -> Result<(u32, u32), String> {
+    let time = time.unwrap_or("09:00").trim();
+    let (hour, minute) = time
+        .split_once(':')
+        .ok_or_else(|| format!("INVALID_TIME: {}", time))?;
+    let hour: u32 = hour
+        .parse()
+        .map_err(|_| format!("INVALID_TIME: {}", time))?;
+    let minute: u32 = minute
+        .parse()
+        .map_err(|_| format!("INVALID_TIME: {}", time))?;
+    if hour > 23 || minute > 59 {
+        return Err(format!("INVALID_TIME: {}", time));
+    }
+    Ok((hour, minute))
+}
+
+fn weekday_indices(spec: &ScheduleSpec) -> Result<Vec<usize>, String> {
+    let days = spec.weekdays.clone().unwrap_or_default();
+    if days.is_empty() {
+        return Err("MISSING_WEEKDAYS".to_string());
+    }
+    days.iter()
+        .map(|day| {
+            let key: String = day.trim().to_lowercase().chars().take(3).collect();
+            WEEKDAYS
+                .iter()
+                .position(|d| *d == key)
+                .ok_or_else(|| format!("INVALID_WEEKDAY: {}", day))
+        })
+        .collect()
+}
+
+fn interval(spec: &ScheduleSpec) -> Result<u32, String> {
+    match spec.interval_minutes {
+        Some(minutes) if minutes > 0 => Ok(minutes),
+        _ => Err("INVALID_INTERVAL: interval_minutes 는 1 이상".to_string()),
+    }
+}
+
+// cron 표현식 (Linux)
+pub fn to_cron(spec: &ScheduleSpec) -> Result<String, String> {
+    match spec.kind.as_str() {
+        "daily" => {
+            let (hour, minute) = parse_time(spec.time.as_deref())?;
+            Ok(format!("{} {} * * *", minute, hour))
+        }
+        "weekly" => {
+            let (hour, minute) = parse_time(spec.time.as_deref())?;
+            let days: Vec<String> = weekday_indices(spec)?
+                .iter()
+                .map(|d| d.to_string())
+                .collect();
+            Ok(format!("{} {} * * {}", minute, hour, days.join(",")))
+        }
+        "hourly" => Ok("0 * * * *".to_string()),
+        "interval" => {
+            let minutes = interval(spec)?;
+            if minutes < 60 {
+                Ok(format!("*/{} * * * *", minutes))
+            } else if minutes % 60 == 0 && minutes < 24 * 60 {
+                Ok(format!("0 */{} * * *", minutes / 60))
+            } else {
+                Err(
+                    "UNSUPPORTED_INTERVAL: cron 은 60분 미만 또는 시간 단위 간격만 지원"
+                        .to_string(),
+                )
+            }
+        }
+        "startup" | "logon" => Ok("@reboot".to_string()),
+        other => Err(format!("UNSUPPORTED_SCHEDULE: {}", other)),
+    }
+}
+
+// 기존 crontab 에서 같은 작업 줄을 교체 (line 이 None 이면 제거)
+pub fn update_crontab(existing: &str, task_name: &str, line: Option<&str>) -> String {
+    let marker = format!("{}{}", CRON_MARKER, task_name);
+    let mut lines: Vec<String> = existing
+        .lines()
+        .filter(|l| !l.trim_end().ends_with(&marker))
+        .map(str::to_string)
+        .collect();
+    if let Some(line) = line {
+        lines.push(format!("{} {}", line, marker));
+    }
+    let mut result = lines.join("\n");
+    if !result.is_empty() {
+        result.push('\n');
+    }
+    result
+}
+
+fn current_exe() -> Result<String, String> {
+    std::env::current_exe()
+        .map(|p| p.to_string_lossy().to_string())
+        .map_err(|e| format!("실행 파일 경로 확인 실패: {}", e))
+}
+
+#[cfg(target_os = "windows")]
+fn register(task_name: &str, workflow_path: &str, spec: &ScheduleSpec) -> Result<(), String> {
+    let task = format!("AutomationGUI\\{}", task_name);
+    let action = format!(
+        "\"{}\" {} \"{}\" {} {}",
+        current_exe()?,
+        RUN_WORKFLOW_ARG,
+        workflow_path,
+        SCHEDULE_TASK_ARG,
+        task_name
+    );
+    let mut args: Vec<String> = vec![
+        "/Create".into(),
+        "/F".into(),
+        "/TN".into(),
+        task,
+        "/TR".into(),
+        action,
+    ];
+
+    match spec.kind.as_str() {
+        "daily" | "weekly" => {
+            let (hour, minute) = parse_time(spec.time.as_deref())?;
+            if spec.kind == "daily" {
+                args.extend(["/SC".into(), "DAILY".into()]);
+            } else {
+                let days: Vec<String> = weekday_indices(spec)?
+                    .iter()
+                    .map(|d| WEEKDAYS[*d].to_uppercase())
+                    .collect();
+                args.extend(["/SC".into(), "WEEKLY".into(), "/D".into(), days.join(",")]);
+            }
+            args.extend(["/ST".into(), format!("{:02}:{:02}", hour, minute)]);
+        }
+        "hourly" => args.extend(["/SC".into(), "HOURLY".into()]),
+        "interval" => args.extend([
+            "/SC".into(),
+            "MINUTE".into(),
+            "/MO".into(),
+            interval(spec)?.to_string(),
+        ]),
+        "startup" => args.extend(["/SC".into(), "ONSTART".into()]),
+        "logon" => args.extend(["/SC".into(), "ONLOGON".into()]),
+        other => return Err(format!("UNSUPPORTED_SCHEDULE: {}", other)),
+    }
+
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    os_command::run("schtasks", &args).map(|_| ())
+}
+
+#[cfg(target_os = "windows")]
+fn unregister(task_name: &str) -> Result<(), String> {
+    let task = format!("AutomationGUI\\{}", task_name);
+    os_command::run("schtasks", &["/Delete", "/F", "/TN", &task]).map(|_| ())
+}
+
+#[cfg(target_os = "macos")]
+fn plist_path(task_name: &str) -> Result<PathBuf, String> {
+    dirs::home_dir()
+        .map(|home| {
+            home.join("Library/LaunchAgents")
+                .join(format!("com.automation-gui.{}.plist", task_name))
+        })
+        .ok_or_else(|| "홈 디렉토리를 찾을 수 없습니다".to_string())
+}
+
+#[cfg(target_os = "macos")]
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(target_os = "macos")]
+fn register(task_name: &str, workflow_path: &str, spec: &ScheduleSpec) -> Result<(), String> {
+    let calendar = |hour: Option<u32>, minute: u32, weekday: Option<usize>| {
+        let mut dict = format!("<dict><key>Minute</key><integer>{}</integer>", minute);
+        if let Some(hour) = hour {
+            dict.push_str(&format!("<key>Hour</key><integer>{}</integer>", hour));
+        }
+        if let Some(weekday) = weekday {
+            dict.push_str(&format!("<key>Weekday</key><integer>{}</integer>", weekday));
+        }
+        dict.push_str("</dict>");
+        dict
+    };
+
+    let trigger = match spec.kind.as_str() {
+        "daily" => {
+            let (hour, minute) = parse_time(spec.time.as_deref())?;
+            format!(
+                "<key>StartCalendarInterval</key>{}",
+                calendar(Some(hour), minute, None)
+            )
+        }
+        "weekly" => {
+            let (hour, minute) = parse_time(spec.time.as_deref())?;
+            let dicts: String = weekday_indices(spec)?
+                .into_iter()
+                .map(|d| calendar(Some(hour), minute, Some(d)))
+                .collect();
+            format!("<key>StartCalendarInterval</key><array>{}</array>", dicts)
+        }
+        "hourly" => format!(
+            "<key>StartCalendarInterval</key>{}",
+            calendar(None, 0, None)
+        ),
+        "interval" => format!(
+            "<key>StartInterval</key><integer>{}</integer>",
+            interval(spec)? * 60
+        ),
+        "startup" | "logon" => "<key>RunAtLoad</key><true/>".to_string(),
+        other => return Err(format!("UNSUPPORTED_SCHEDULE: {}", other)),
+    };
+
+    let plist = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+<key>Label</key><string>com.automation-gui.{}</string>
+<key>ProgramArguments</key><array><string>{}</string><string>{}</string><string>{}</string><string>{}</string><string>{}</string></array>
+{}
+</dict>
+</plist>
+"#,
+        task_name,
+        xml_escape(&current_exe()?),
+        RUN_WORKFLOW_ARG,
+        xml_escape(workflow_path),
+        SCHEDULE_TASK_ARG,
+        task_name,
+        trigger
+    );
+
+    let path = plist_path(task_name)?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("LaunchAgents 폴더 생성 실패: {}", e))?;
+    }
+    let path_str = path.to_string_lossy().to_string();
+    let _ = os_command::run("launchctl", &["unload", &path_str]);
+    std::fs::write(&path, plist).map_err(|e| format!("plist 저장 실패: {}", e))?;
+    os_command::run("launchctl", &["load", "-w", &path_str]).map(|_| ())
+}
+
+#[cfg(target_os = "macos")]
+fn unregister(task_name: &str) -> Result<(), String> {
+    let path = plist_path(task_name)?;
+    let _ = os_command::run("launchctl", &["unload", &path.to_string_lossy()]);
+    if path.exists() {
+        std::fs::remove_file(&path).map_err(|e| format!("plist 삭제 실패: {}", e))?;
+    }
+    Ok(())
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn write_crontab(content: &str) -> Result<(), String> {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    let mut child = Command::new("crontab")
+        .arg("-")
+        .stdin(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("crontab 실행 실패: {}", e))?;
+    child
+        .stdin
+        .take()
+        .ok_or("crontab stdin 열기 실패")?
+        .write_all(content.as_bytes())
+        .map_err(|e| format!("crontab 쓰기 실패: {}", e))?;
+    let output = child
+        .wait_with_output()
+        .map_err(|e| format!("crontab 실패: {}", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "crontab 실패: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn shell_quote(text: &str) -> String {
+    format!("'{}'", text.replace('\'', "'\\''"))
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn register(task_name: &str, workflow_path: &str, spec: &ScheduleSpec) -> Result<(), String> {
+    // GUI 앱이므로 기본 디스플레이를 지정 (cron 환경에는 DISPLAY 가 없음)
+    let line = format!(
+        "{} DISPLAY=:0 {} {} {} {} {}",
+        to_cron(spec)?,
+        shell_quote(&current_exe()?),
+        RUN_WORKFLOW_ARG,
+        shell_quote(workflow_path),
+        SCHEDULE_TASK_ARG,
+        task_name
+    );
+    // cron 은 % 를 줄바꿈으로 해석
+    let line = line.replace('%', "\\%");
+    let existing = os_command::run_optional("crontab", &["-l"]).unwrap_or_default();
+    write_crontab(&update_crontab(&existing, task_name, Some(&line)))
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn unregister(task_name: &str) -> Result<(), String> {
+    let existing = os_command::run_optional("crontab", &["-l"]).unwrap_or_default();
+    write_crontab(&update_crontab(&existing, task_name, None))
+}
+
+#[tauri::command]
+pub async fn export_os_schedule(
+    task_name: String,
+    workflow_path: String,
+    schedule: ScheduleSpec,
+) -> Result<String, String> {
+    let task_name = validate_task_name(&task_name)?.to_string();
+    let workflow = std::path::Path::new(workflow_path.trim());
+    if !workflow.is_file() {
+        return Err(format!("WORKFLOW_NOT_FOUND: {}", workflow_path));
+    }
+    let workflow_path = workflow
+        .canonicalize()
+        .map(|p| p.to_string_lossy().trim_start_matches(r"\\?\").to_string())
+        .map_err(|e| format!("워크플로우 경로 확인 실패: {}", e))?;
+
+    println!("⏰ OS 예약 작업 등록: {} ({})", task_name, schedule.kind);
+    let (name, path, spec) = (task_name.clone(), workflow_path.clone(), schedule.clone());
+    tokio::task::spawn_blocking(move || register(&name, &path, &spec))
+        .await
+        .map_err(|e| format!("예약 작업 등록 실패: {}", e))??;
+
+    let mut index = load_index();
+    index.retain(|entry| entry.task_name != task_name);
+    index.push(OsScheduleEntry {
+        task_name: task_name.clone(),
+        workflow_path: workflow_path.clone(),
+        schedule,
+        created_at: chrono::Utc::now().timestamp(),
+    });
+    atomic_store::write_json_atomic(&index_path(), &index)?;
+
+    println!("✅ OS 예약 작업 등록 완료: {}", task_name);
+    Ok(json!({ "task_name": task_name, "workflow_path": workflow_path }).to_string())
+}
+
+#[tauri::command]
+pub async fn remove_os_schedule(task_name: String) -> Result<String, String> {
+    let task_name = validate_task_name(&task_name)?.to_string();
+    let name = task_name.clone();
+    tokio::task::spawn_blocking(move || unregister(&name))
+        .await
+        .map_err(|e| format!("예약 작업 삭제 실패: {}", e))??;
+
+    let mut index = load_index();
+    index.retain(|entry| entry.task_name != task_name);
+    atomic_store::write_json_atomic(&index_path(), &index)?;
+
+    println!("🗑️ OS 예약 작업 삭제: {}", task_name);
+    Ok("SUCCESS".to_string())
+}
+
+#[tauri::command]
+pub fn list_os_schedules() -> Result<Vec<OsScheduleEntry>, String> {
+    Ok(load_index())
+}
+
+pub fn find_schedule(task_name: &str) -> Option<OsScheduleEntry> {
+    load_index()
+        .into_iter()
+        .find(|entry| entry.task_name == task_name)
+}
+
+// 예약 실행이면 조건 확인 - 건너뛸 이유가 있으면 Some(이유)
+// 조건 확인 자체가 실패하면(공휴일 API 오류 등) 실행하는 쪽을 택함
+pub async fn skip_scheduled_run(task_name: &str) -> Option<String> {
+    let conditions = find_schedule(task_name)?.schedule.conditions?;
+    match schedule_conditions::check(&conditions, chrono::Local::now()).await {
+        Ok(reason) => reason,
+        Err(e) => {
+            println!("⚠️ 실행 조건 확인 실패, 그대로 실행: {}", e);
+            None
+        }
+    }
+}
+
+// 예약 작업으로 실행된 경우 실행할 워크플로우 경로 (프론트엔드가 시작 시 호출)
+// trigger:// 링크까지 포함한 전체 요청은 instance::take_launch_request
+#[tauri::command]
+pub async fn take_launch_workflow() -> Option<String> {
+    instance::take_launch_request()
+        .await
+        .and_then(|request| request.workflow_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule(
+        kind: &str,
+        time: Option<&str>,
+        weekdays: &[&str],
+        interval: Option<u32>,
+    ) -> ScheduleSpec {
+        ScheduleSpec {
+            kind: kind.to_string(),
+            time: time.map(str::to_string),
+            weekdays: Some(weekdays.iter().map(|d| d.to_string()).collect()),
+            interval_minutes: interval,
+            conditions: None,
+        }
+    }
+
+    #[test]
+    fn os_schedule_renders_cron_expressions() {
+        assert_eq!(
+            to_cron(&schedule("daily", Some("07:30"), &[], None)).unwrap(),
+            "30 7 * * *"
+        );
+        assert_eq!(
+            to_cron(&schedule("weekly", Some("18:05"), &["Mon", "friday"], None)).unwrap(),
+            "5 18 * * 1,5"
+        );
+        assert_eq!(
+            to_cron(&schedule("interval", None, &[], Some(15))).unwrap(),
+            "*/15 * * * *"
+        );
+        assert_eq!(
+            to_cron(&schedule("interval", None, &[], Some(120))).unwrap(),
+            "0 */2 * * *"
+        );
+        assert_eq!(
+            to_cron(&schedule("startup", None, &[], None)).unwrap(),
+            "@reboot"
+        );
+        assert!(to_cron(&schedule("daily", Some("25:00"), &[], None)).is_err());
+        assert!(to_cron(&schedule("weekly", Some("10:00"), &["xyz"], None)).is_err());
+    }
+
+    #[test]
+    fn os_schedule_replaces_only_its_crontab_line() {
+        let existing = "0 1 * * * backup.sh\n0 2 * * * old # automation-gui:report\n";
+        let updated = update_crontab(existing, "report", Some("0 3 * * * new"));
+        assert_eq!(
+            updated,
+            "0 1 * * * backup.sh\n0 3 * * * new # automation-gui:report\n"
+        );
+        assert_eq!(
+            update_crontab(&updated, "report", None),
+            "0 1 * * * backup.sh\n"
+        );
+    }
+}
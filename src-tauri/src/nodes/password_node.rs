@@ -0,0 +1,251 @@
+// src-tauri/src/nodes/password_node.rs
+use super::secrets;
+use rand::rngs::OsRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde_json::json;
+
+// 🔑 비밀번호 / 패스프레이즈 생성 노드
+// - password: 길이, 문자 종류(소문자/대문자/숫자/기호), 헷갈리는 문자 제외 정책을 만족하도록 생성
+// - passphrase: diceware 방식 단어 조합 (wordlist_path 로 EFF 단어 목록 등 사용 가능)
+// store_as 를 주면 생성 즉시 비밀값 저장소에 저장하고 결과에는 값을 노출하지 않음
+
+const LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
+const UPPERCASE: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const DIGITS: &str = "0123456789";
+const SYMBOLS: &str = "!@#$%^&*()-_=+[]{};:,.?/~";
+const AMBIGUOUS: &str = "Il1O0o|`'\"";
+
+// 내장 단어 목록 (단어당 약 8비트), 더 강한 문구는 EFF 목록 파일 사용 권장
+const BUILTIN_WORDS: &[&str] = &[
+    "acid", "acorn", "actor", "agent", "alarm", "album", "alpha", "amber", "angle", "apple",
+    "april", "arena", "arrow", "atlas", "audio", "award", "bacon", "badge", "baker", "bamboo",
+    "banjo", "baron", "basin", "beach", "berry", "bison", "blade", "blank", "blaze", "bloom",
+    "board", "bonus", "boost", "brave", "bread", "brick", "bridge", "brook", "brush", "cabin",
+    "cable", "cactus", "camel", "candy", "canoe", "cargo", "carol", "cedar", "chalk", "charm",
+    "chess", "chief", "cider", "cinema", "citrus", "civic", "claim", "clay", "cliff", "cloud",
+    "clover", "coast", "cobra", "cocoa", "comet", "coral", "cotton", "crane", "crisp", "crown",
+    "curve", "cycle", "daisy", "dance", "delta", "denim", "depot", "diary", "dingo", "disco",
+    "dolphin", "donut", "dragon", "drift", "drum", "eagle", "earth", "easel", "echo", "elbow",
+    "elder", "ember", "empire", "engine", "epic", "equal", "fable", "falcon", "fancy", "feast",
+    "fern", "ferry", "fiber", "field", "flame", "flint", "flora", "flute", "focus", "forest",
+    "fossil", "frost", "fruit", "galaxy", "garden", "gecko", "giant", "ginger", "glacier", "globe",
+    "glove", "grape", "gravel", "guitar", "habit", "harbor", "harvest", "hazel", "helmet", "hero",
+    "honey", "horizon", "hotel", "humble", "husky", "icon", "igloo", "index", "island", "ivory",
+    "jacket", "jaguar", "jelly", "jewel", "jockey", "jolly", "judge", "juice", "jungle", "kayak",
+    "kernel", "kettle", "kiwi", "koala", "label", "ladder", "lagoon", "lantern", "laser", "lemon",
+    "lilac", "linen", "lizard", "llama", "lobby", "locket", "lotus", "lunar", "magnet", "mango",
+    "maple", "marble", "meadow", "melon", "mentor", "metro", "mint", "mirror", "mocha", "molar",
+    "motor", "mural", "nectar", "needle", "noble", "north", "novel", "nutmeg", "oasis", "ocean",
+    "olive", "omega", "onion", "opera", "orbit", "orchid", "otter", "oxygen", "paddle", "panda",
+    "paper", "parrot", "pasta", "pearl", "pepper", "piano", "pilot", "pixel", "planet", "plaza",
+    "plum", "polar", "poppy", "prism", "pulse", "quartz", "quest", "quiet", "quilt", "radar",
+    "radio", "raven", "reef", "ribbon", "river", "robin", "rocket", "rodeo", "royal", "ruby",
+    "saddle", "salad", "salmon", "sandal", "satin", "scout", "shadow", "shell", "silver", "sketch",
+    "solar", "spice", "spiral", "sprout", "squid", "stone", "storm", "sugar", "summit", "sunset",
+    "swamp", "tango", "temple", "thunder", "tiger", "timber", "toast", "topaz", "torch", "tulip",
+    "tundra", "turtle", "umbrella", "unicorn", "valley", "velvet",
+];
+
+fn build_charset(classes: &[&str], exclude: &str) -> String {
+    classes
+        .iter()
+        .flat_map(|class| class.chars())
+        .filter(|c| !exclude.contains(*c))
+        .collect()
+}
+
+pub fn generate_password(
+    length: usize,
+    classes: &[&str],
+    exclude: &str,
+) -> Result<(String, f64), String> {
+    let filtered: Vec<Vec<char>> = classes
+        .iter()
+        .map(|class| {
+            class
+                .chars()
+                .filter(|c| !exclude.contains(*c))
+                .collect::<Vec<_>>()
+        })
+        .filter(|chars| !chars.is_empty())
+        .collect();
+    if filtered.is_empty() {
+        return Err("EMPTY_CHARSET".to_string());
+    }
+    if length < filtered.len() {
+        return Err(format!("LENGTH_TOO_SHORT: 최소 {}자 필요", filtered.len()));
+    }
+
+    let all: Vec<char> = build_charset(classes, exclude).chars().collect();
+    let mut rng = OsRng;
+
+    // 각 문자 종류를 최소 1개씩 포함한 뒤 나머지는 전체에서 뽑고 섞음
+    let mut chars: Vec<char> = filtered
+        .iter()
+        .map(|class| class[rng.gen_range(0..class.len())])
+        .collect();
+    while chars.len() < length {
+        chars.push(all[rng.gen_range(0..all.len())]);
+    }
+    chars.shuffle(&mut rng);
+
+    let entropy = length as f64 * (all.len() as f64).log2();
+    Ok((chars.into_iter().collect(), entropy))
+}
+
+fn load_wordlist(path: Option<&str>) -> Result<Vec<String>, String> {
+    let Some(path) = path.filter(|p| !p.trim().is_empty()) else {
+        return Ok(BUILTIN_WORDS.iter().map(|w| w.to_string()).collect());
+    };
+    let content =
+        std::fs::read_to_string(path.trim()).map_err(|e| format!("WORDLIST_READ_ERROR: {}", e))?;
+
+    // EFF 형식("11111\tabacus")이면 마지막 칸만 사용
+    let mut words: Vec<String> = content
+        .lines()
+        .filter_map(|line| line.split_whitespace().last())
+        .map(str::to_string)
+        .collect();
+    words.sort();
+    words.dedup();
+    if words.len() < 100 {
+        return Err("WORDLIST_TOO_SMALL: 최소 100개 단어가 필요합니다".to_string());
+    }
+    Ok(words)
+}
+
+pub fn generate_passphrase(
+    words: &[String],
+    count: usize,
+    separator: &str,
+    capitalize: bool,
+    add_number: bool,
+) -> (String, f64) {
+    let mut rng = OsRng;
+    let mut chosen: Vec<String> = (0..count)
+        .map(|_| {
+            let word = &words[rng.gen_range(0..words.len())];
+            if capitalize {
+                let mut chars = word.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_uppercase().chain(chars).collect())
+                    .unwrap_or_default()
+            } else {
+                word.clone()
+            }
+        })
+        .collect();
+
+    let mut entropy = count as f64 * (words.len() as f64).log2();
+    if add_number {
+        chosen.push(rng.gen_range(0..100).to_string());
+        entropy += 100f64.log2();
+    }
+    (chosen.join(separator), entropy)
+}
+
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub fn password_node(
+    mode: Option<String>,
+    length: Option<usize>,
+    lowercase: Option<bool>,
+    uppercase: Option<bool>,
+    digits: Option<bool>,
+    symbols: Option<bool>,
+    exclude_ambiguous: Option<bool>,
+    exclude_chars: Option<String>,
+    word_count: Option<usize>,
+    separator: Option<String>,
+    wordlist_path: Option<String>,
+    store_as: Option<String>,
+) -> Result<String, String> {
+    let mode = mode
+        .unwrap_or_else(|| "password".to_string())
+        .to_lowercase();
+    println!("🔑 Password Node: {}", mode);
+
+    let (value, entropy) = match mode.as_str() {
+        "password" => {
+            let length = length.unwrap_or(20);
+            if !(4..=256).contains(&length) {
+                return Err("INVALID_LENGTH: 4~256".to_string());
+            }
+            let mut classes = Vec::new();
+            if lowercase.unwrap_or(true) {
+                classes.push(LOWERCASE);
+            }
+            if uppercase.unwrap_or(true) {
+                classes.push(UPPERCASE);
+            }
+            if digits.unwrap_or(true) {
+                classes.push(DIGITS);
+            }
+            if symbols.unwrap_or(true) {
+                classes.push(SYMBOLS);
+            }
+
+            let mut exclude = exclude_chars.unwrap_or_default();
+            if exclude_ambiguous.unwrap_or(false) {
+                exclude.push_str(AMBIGUOUS);
+            }
+            generate_password(length, &classes, &exclude)?
+        }
+        "passphrase" => {
+            let count = word_count.unwrap_or(6);
+            if !(3..=20).contains(&count) {
+                return Err("INVALID_WORD_COUNT: 3~20".to_string());
+            }
+            let words = load_wordlist(wordlist_path.as_deref())?;
+            generate_passphrase(
+                &words,
+                count,
+                separator.as_deref().unwrap_or("-"),
+                uppercase.unwrap_or(false),
+                digits.unwrap_or(false),
+            )
+        }
+        other => return Err(format!("UNSUPPORTED_MODE: {}", other)),
+    };
+
+    let entropy_bits = entropy.floor();
+    match store_as.map(|s| s.trim().to_string()).filter(|s| !s.is_empty()) {
+        Some(name) => {
+            secrets::set(&name, &value)?;
+            println!("🔐 생성한 값을 비밀값 '{}' 로 저장", name);
+            Ok(json!({ "stored_as": name, "length": value.chars().count(), "entropy_bits": entropy_bits }).to_string())
+        }
+        None => Ok(json!({ "value": value, "length": value.chars().count(), "entropy_bits": entropy_bits }).to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn password_satisfies_charset_policy() {
+        for _ in 0..50 {
+            let (password, entropy) =
+                generate_password(12, &["abc", "XYZ", "0123456789"], "0").unwrap();
+            assert_eq!(password.chars().count(), 12);
+            assert!(password.chars().any(|c| "abc".contains(c)));
+            assert!(password.chars().any(|c| "XYZ".contains(c)));
+            assert!(password.chars().any(|c| c.is_ascii_digit()));
+            assert!(!password.contains('0'));
+            assert!(entropy > 0.0);
+        }
+        assert!(generate_password(2, &["a", "b", "c"], "").is_err());
+        assert!(generate_password(8, &["ab"], "ab").is_err());
+    }
+
+    #[test]
+    fn passphrase_uses_requested_word_count() {
+        let words: Vec<String> = (0..128).map(|i| format!("w{}", i)).collect();
+        let (phrase, entropy) = generate_passphrase(&words, 5, " ", false, false);
+        assert_eq!(phrase.split(' ').count(), 5);
+        assert_eq!(entropy, 35.0);
+    }
+}
@@ -0,0 +1,166 @@
+// src-tauri/src/nodes/path_guard.rs
+use std::path::{Component, Path, PathBuf};
+
+// 🛡️ 서버 파일 경로 검증 (정적 파일 / 업로드 라우트 공용)
+// 요청 경로를 디코딩 → 위험한 구성요소(.., 절대 경로, 드라이브 문자, NUL) 거부 →
+// 루트 기준으로 합친 뒤 canonicalize 해서 심볼릭 링크로 루트 밖을 가리키는 경우도 거부
+// 터널로 외부에 공개되는 서버이므로 의심스러우면 항상 거부
+
+fn percent_decode(input: &str) -> Result<String, String> {
+    let bytes = input.as_bytes();
+    let mut output = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = bytes
+                .get(i + 1..i + 3)
+                .and_then(|h| std::str::from_utf8(h).ok())
+                .and_then(|h| u8::from_str_radix(h, 16).ok())
+                .ok_or("PATH_REJECTED: 잘못된 퍼센트 인코딩")?;
+            output.push(hex);
+            i += 3;
+        } else {
+            output.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(output).map_err(|_| "PATH_REJECTED: UTF-8 아님".to_string())
+}
+
+// 루트 기준 상대 경로로 정리 (파일 시스템 접근 없음)
+pub fn sanitize_relative(requested: &str) -> Result<PathBuf, String> {
+    let decoded = percent_decode(requested)?;
+    if decoded.contains('\0') {
+        return Err("PATH_REJECTED: NUL 문자".to_string());
+    }
+    // 이중 인코딩(%252e%252e) 우회 차단
+    if decoded.contains('%') && percent_decode(&decoded).ok().as_deref() != Some(decoded.as_str()) {
+        return Err("PATH_REJECTED: 이중 인코딩".to_string());
+    }
+
+    let mut relative = PathBuf::new();
+    // Windows 구분자도 구분자로 취급
+    for part in decoded.split(['/', '\\']) {
+        if part.is_empty() || part == "." {
+            continue;
+        }
+        if part == ".." || part.contains(':') {
+            return Err(format!("PATH_TRAVERSAL_REJECTED: {}", requested));
+        }
+        let mut components = Path::new(part).components();
+        match (components.next(), components.next()) {
+            (Some(Component::Normal(name)), None) => relative.push(name),
+            _ => return Err(format!("PATH_TRAVERSAL_REJECTED: {}", requested)),
+        }
+    }
+    Ok(relative)
+}
+
+fn canonical_root(root: &Path) -> Result<PathBuf, String> {
+    root.canonicalize()
+        .map_err(|e| format!("ROOT_NOT_FOUND: {} ({})", root.display(), e))
+}
+
+// 이미 존재하는 파일/폴더 (정적 파일 제공용)
+pub fn resolve_within(root: &Path, requested: &str) -> Result<PathBuf, String> {
+    let root = canonical_root(root)?;
+    let candidate = root.join(sanitize_relative(requested)?);
+    let resolved = candidate
+        .canonicalize()
+        .map_err(|_| format!("NOT_FOUND: {}", requested))?;
+    if !resolved.starts_with(&root) {
+        return Err(format!("PATH_TRAVERSAL_REJECTED: {}", requested));
+    }
+    Ok(resolved)
+}
+
+// 새로 만들 파일 (업로드용): 상위 폴더가 루트 안에 있어야 함
+// 채팅 서버에 업로드 경로가 생기면 사용 (정적 파일은 resolve_within)
+#[allow(dead_code)]
+pub fn resolve_new_within(root: &Path, requested: &str) -> Result<PathBuf, String> {
+    let root = canonical_root(root)?;
+    let relative = sanitize_relative(requested)?;
+    let file_name = relative
+        .file_name()
+        .ok_or_else(|| format!("PATH_REJECTED: 파일 이름 없음 ({})", requested))?
+        .to_os_string();
+    let parent = root.join(relative.parent().unwrap_or(Path::new("")));
+    let parent = parent
+        .canonicalize()
+        .map_err(|_| format!("NOT_FOUND: {}", requested))?;
+    if !parent.starts_with(&root) {
+        return Err(format!("PATH_TRAVERSAL_REJECTED: {}", requested));
+    }
+
+    let target = parent.join(file_name);
+    // 기존 심볼릭 링크를 덮어써서 루트 밖에 쓰는 것 방지
+    if target
+        .symlink_metadata()
+        .is_ok_and(|m| m.file_type().is_symlink())
+    {
+        return Err(format!("PATH_TRAVERSAL_REJECTED: {}", requested));
+    }
+    Ok(target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::temp_dir;
+    use std::fs;
+
+    #[test]
+    fn path_guard_rejects_traversal_attempts() {
+        let dir = temp_dir();
+        let root = dir.path().join("public");
+        fs::create_dir_all(root.join("sub")).unwrap();
+        fs::write(root.join("sub").join("file.txt"), "ok").unwrap();
+        fs::write(dir.path().join("secret.txt"), "secret").unwrap();
+
+        let resolved = resolve_within(&root, "sub/file.txt").unwrap();
+        assert_eq!(fs::read_to_string(resolved).unwrap(), "ok");
+        assert!(resolve_within(&root, "./sub//file.txt").is_ok());
+        assert!(resolve_within(&root, "sub%2Ffile.txt").is_ok());
+
+        for attack in [
+            "../secret.txt",
+            "sub/../../secret.txt",
+            "%2e%2e/secret.txt",
+            "%2E%2E%2Fsecret.txt",
+            "%252e%252e/secret.txt",
+            "..\\secret.txt",
+            "sub\\..\\..\\secret.txt",
+            "C:\\Windows\\win.ini",
+            "c:secret.txt",
+            "sub/file.txt%00.png",
+            "%zz",
+        ] {
+            let result = resolve_within(&root, attack);
+            assert!(result.is_err(), "{} 허용됨: {:?}", attack, result);
+        }
+
+        // 절대 경로는 루트 기준 상대 경로로 취급
+        assert!(resolve_within(&root, "/sub/file.txt").is_ok());
+        assert!(resolve_within(&root, "/etc/passwd").is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn path_guard_rejects_symlinks_escaping_root() {
+        let dir = temp_dir();
+        let root = dir.path().join("public");
+        fs::create_dir_all(&root).unwrap();
+        fs::write(dir.path().join("secret.txt"), "secret").unwrap();
+        std::os::unix::fs::symlink(dir.path().join("secret.txt"), root.join("link.txt")).unwrap();
+        std::os::unix::fs::symlink(dir.path(), root.join("outside")).unwrap();
+
+        assert!(resolve_within(&root, "link.txt").is_err());
+        assert!(resolve_within(&root, "outside/secret.txt").is_err());
+        assert!(resolve_new_within(&root, "outside/new.txt").is_err());
+        assert!(resolve_new_within(&root, "link.txt").is_err());
+        assert_eq!(
+            resolve_new_within(&root, "upload.txt").unwrap(),
+            root.canonicalize().unwrap().join("upload.txt")
+        );
+    }
+}
@@ -0,0 +1,497 @@
+// src-tauri/src/nodes/pdf_builder_node.rs
+use super::safe_path;
+use super::table_node::display_width;
+use super::template::{self, value_to_text};
+use printpdf::{
+    BuiltinFont, Image, ImageTransform, IndirectFontRef, Line, Mm, PdfDocument,
+    PdfDocumentReference, PdfLayerReference, Point,
+};
+use serde_json::{json, Value};
+use std::fs::File;
+use std::io::BufWriter;
+
+// 🧾 PDF 문서 생성 노드 (견적서, 보고서 등)
+// 템플릿(JSON)의 블록을 위에서부터 배치하고 "{{path}}" 자리에 context 값을 채움
+// 템플릿 예:
+// {
+//   "page": { "size": "A4", "margin_mm": 15 },
+//   "header": "{{company}} 견적서", "footer": "{{page}} / {{pages}}",
+//   "blocks": [
+//     { "type": "text", "text": "고객: {{customer.name}}", "size": 12, "align": "left" },
+//     { "type": "table", "source": "items", "columns": ["품목", "수량", "금액"] },
+//     { "type": "image", "path": "C:/logo.png", "width_mm": 40 },
+//     { "type": "spacer", "height_mm": 5 },
+//     { "type": "page_break" }
+//   ]
+// }
+
+const PT_TO_MM: f32 = 0.3528;
+// 폰트 메트릭 없이 줄바꿈하기 위한 근사치 (반각 문자 = 0.55em, 한글 등 전각 = 1.1em)
+const HALF_WIDTH_EM: f32 = 0.55;
+const DEFAULT_FONT_SIZE: f32 = 11.0;
+
+// 한글을 표시하려면 TrueType 폰트가 필요 (기본 폰트는 영문만 지원)
+pub(crate) const SYSTEM_FONT_CANDIDATES: &[&str] = &[
+    "C:\\Windows\\Fonts\\malgun.ttf",
+    "C:\\Windows\\Fonts\\gulim.ttc",
+    "/Library/Fonts/NanumGothic.ttf",
+    "/System/Library/Fonts/Supplemental/AppleGothic.ttf",
+    "/usr/share/fonts/truetype/nanum/NanumGothic.ttf",
+    "/usr/share/fonts/opentype/noto/NotoSansCJK-Regular.ttc",
+];
+
+struct Layout {
+    doc: PdfDocumentReference,
+    font: IndirectFontRef,
+    layers: Vec<PdfLayerReference>,
+    page_width: f32,
+    page_height: f32,
+    margin: f32,
+    cursor_y: f32, // 현재 위치 (페이지 아래에서부터의 mm)
+}
+
+impl Layout {
+    fn layer(&self) -> &PdfLayerReference {
+        self.layers.last().expect("at least one page")
+    }
+
+    fn content_width(&self) -> f32 {
+        self.page_width - self.margin * 2.0
+    }
+
+    fn content_top(&self) -> f32 {
+        // 머리글 공간 확보
+        self.page_height - self.margin - 6.0
+    }
+
+    fn content_bottom(&self) -> f32 {
+        self.margin + 6.0
+    }
+
+    fn new_page(&mut self) {
+        let (page, layer) = self.doc.add_page(
+            Mm(self.page_width),
+            Mm(self.page_height),
+            format!("Page {}", self.layers.len() + 1),
+        );
+        self.layers.push(self.doc.get_page(page).get_layer(layer));
+        self.cursor_y = self.content_top();
+    }
+
+    // 남은 공간이 부족하면 새 페이지
+    fn ensure_space(&mut self, height: f32) {
+        if self.cursor_y - height < self.content_bottom() {
+            self.new_page();
+        }
+    }
+
+    fn text_width(&self, text: &str, size: f32) -> f32 {
+        display_width(text) as f32 * size * HALF_WIDTH_EM * PT_TO_MM
+    }
+
+    fn write_text(&self, text: &str, size: f32, x: f32, baseline_y: f32) {
+        self.layer()
+            .use_text(text, size, Mm(x), Mm(baseline_y), &self.font);
+    }
+
+    fn draw_line(&self, x1: f32, y1: f32, x2: f32, y2: f32) {
+        self.layer().set_outline_thickness(0.5);
+        self.layer().add_line(Line {
+            points: vec![
+                (Point::new(Mm(x1), Mm(y1)), false),
+                (Point::new(Mm(x2), Mm(y2)), false),
+            ],
+            is_closed: false,
+        });
+    }
+}
+
+// 폭에 맞춰 줄바꿈 (공백 단위, 긴 단어는 글자 단위로 자름)
+fn wrap_text(text: &str, max_units: usize) -> Vec<String> {
+    let max_units = max_units.max(1);
+    let mut lines = Vec::new();
+
+    for paragraph in text.split('\n') {
+        let mut current = String::new();
+        for word in paragraph.split(' ') {
+            let candidate = if current.is_empty() {
+                word.to_string()
+            } else {
+                format!("{} {}", current, word)
+            };
+            if display_width(&candidate) <= max_units {
+                current = candidate;
+                continue;
+            }
+            if !current.is_empty() {
+                lines.push(std::mem::take(&mut current));
+            }
+            for c in word.chars() {
+                if display_width(&current) + display_width(&c.to_string()) > max_units {
+                    lines.push(std::mem::take(&mut current));
+                }
+                current.push(c);
+            }
+        }
+        lines.push(current);
+    }
+    lines
+}
+
+fn truncate_to_width(text: &str, max_units: usize) -> String {
+    if display_width(text) <= max_units {
+        return text.to_string();
+    }
+    let mut result = String::new();
+    for c in text.chars() {
+        if display_width(&result) + display_width(&c.to_string()) + 1 > max_units {
+            break;
+        }
+        result.push(c);
+    }
+    result.push('…');
+    result
+}
+
+fn units_for_width(width_mm: f32, size: f32) -> usize {
+    (width_mm / (size * HALF_WIDTH_EM * PT_TO_MM))
+        .floor()
+        .max(1.0) as usize
+}
+
+fn render_text_block(layout: &mut Layout, block: &Value, context: &Value) {
+    let size = block["size"]
+        .as_f64()
+        .map(|s| s as f32)
+        .unwrap_or(DEFAULT_FONT_SIZE);
+    let text = template::render(block["text"].as_str().unwrap_or(""), context);
+    let line_height = size * 1.4 * PT_TO_MM;
+    let content_width = layout.content_width();
+
+    for line in wrap_text(&text, units_for_width(content_width, size)) {
+        layout.ensure_space(line_height);
+        let x = match block["align"].as_str() {
+            Some("center") => {
+                layout.margin + (content_width - layout.text_width(&line, size)) / 2.0
+            }
+            Some("right") => layout.margin + content_width - layout.text_width(&line, size),
+            _ => layout.margin,
+        };
+        layout.cursor_y -= line_height;
+        layout.write_text(&line, size, x, layout.cursor_y + line_height * 0.25);
+    }
+}
+
+fn table_rows(block: &Value, context: &Value) -> Vec<Value> {
+    let rows = match block.get("source").and_then(|s| s.as_str()) {
+        Some(path) => template::lookup(context, path)
+            .cloned()
+            .unwrap_or(Value::Null),
+        None => block.get("rows").cloned().unwrap_or(Value::Null),
+    };
+    rows.as_array().cloned().unwrap_or_default()
+}
+
+fn render_table_block(layout: &mut Layout, block: &Value, context: &Value) -> Result<(), String> {
+    let size = block["size"]
+        .as_f64()
+        .map(|s| s as f32)
+        .unwrap_or(DEFAULT_FONT_SIZE - 1.0);
+    let rows = table_rows(block, context);
+
+    let columns: Vec<String> = match block["columns"].as_array() {
+        Some(columns) => columns.iter().map(value_to_text).collect(),
+        None => rows
+            .first()
+            .and_then(|r| r.as_object())
+            .map(|o| o.keys().cloned().collect())
+            .unwrap_or_default(),
+    };
+    if columns.is_empty() {
+        return Ok(());
+    }
+
+    let cells: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| {
+            columns
+                .iter()
+                .enumerate()
+                .map(|(i, column)| {
+                    let value = match row {
+                        Value::Object(map) => map.get(column).cloned(),
+                        Value::Array(items) => items.get(i).cloned(),
+                        _ => None,
+                    };
+                    value.map(|v| value_to_text(&v)).unwrap_or_default()
+                })
+                .collect()
+        })
+        .collect();
+
+    // 열 너비: 내용 길이에 비례 (최소 4칸)
+    let natural: Vec<usize> = (0..columns.len())
+        .map(|i| {
+            cells
+                .iter()
+                .map(|row| display_width(&row[i]))
+                .chain(std::iter::once(display_width(&columns[i])))
+                .max()
+                .unwrap_or(0)
+                .max(4)
+        })
+        .collect();
+    let total: usize = natural.iter().sum();
+    let content_width = layout.content_width();
+    let widths: Vec<f32> = natural
+        .iter()
+        .map(|w| content_width * *w as f32 / total as f32)
+        .collect();
+
+    let row_height = size * 1.6 * PT_TO_MM;
+    let padding = 1.5;
+
+    let draw_row = |layout: &mut Layout, row: &[String], header: bool| {
+        layout.ensure_space(row_height);
+        layout.cursor_y -= row_height;
+        let mut x = layout.margin;
+        for (i, cell) in row.iter().enumerate() {
+            let units = units_for_width(widths[i] - padding * 2.0, size);
+            layout.write_text(
+                &truncate_to_width(cell, units),
+                size,
+                x + padding,
+                layout.cursor_y + row_height * 0.3,
+            );
+            x += widths[i];
+        }
+        if header {
+            let y = layout.cursor_y;
+            layout.draw_line(layout.margin, y, layout.margin + content_width, y);
+        }
+    };
+
+    draw_row(layout, &columns, true);
+    for row in &cells {
+        draw_row(layout, row, false);
+    }
+    let y = layout.cursor_y;
+    layout.draw_line(layout.margin, y, layout.margin + content_width, y);
+    layout.cursor_y -= 2.0;
+    Ok(())
+}
+
+fn render_image_block(layout: &mut Layout, block: &Value, context: &Value) -> Result<(), String> {
+    let path = template::render(block["path"].as_str().unwrap_or(""), context);
+    let image =
+        image::open(path.trim()).map_err(|e| format!("IMAGE_LOAD_ERROR: {} ({})", path, e))?;
+
+    const DPI: f32 = 300.0;
+    let natural_width = image.width() as f32 / DPI * 25.4;
+    let natural_height = image.height() as f32 / DPI * 25.4;
+    let width = block["width_mm"]
+        .as_f64()
+        .map(|w| w as f32)
+        .unwrap_or(natural_width)
+        .min(layout.content_width());
+    let scale = width / natural_width;
+    let height = natural_height * scale;
+
+    layout.ensure_space(height);
+    layout.cursor_y -= height;
+
+    let x = match block["align"].as_str() {
+        Some("center") => layout.margin + (layout.content_width() - width) / 2.0,
+        Some("right") => layout.margin + layout.content_width() - width,
+        _ => layout.margin,
+    };
+
+    Image::from_dynamic_image(&image).add_to_layer(
+        layout.layer().clone(),
+        ImageTransform {
+            translate_x: Some(Mm(x)),
+            translate_y: Some(Mm(layout.cursor_y)),
+            scale_x: Some(scale),
+            scale_y: Some(scale),
+            dpi: Some(DPI),
+            ..Default::default()
+        },
+    );
+    layout.cursor_y -= 2.0;
+    Ok(())
+}
+
+fn load_font(
+    doc: &PdfDocumentReference,
+    font_path: Option<&str>,
+) -> Result<IndirectFontRef, String> {
+    let candidates = font_path
+        .into_iter()
+        .chain(SYSTEM_FONT_CANDIDATES.iter().copied());
+
+    for path in candidates {
+        if let Ok(file) = File::open(path) {
+            match doc.add_external_font(file) {
+                Ok(font) => return Ok(font),
+                Err(e) => println!("⚠️ 폰트 로드 실패 ({}): {}", path, e),
+            }
+        }
+    }
+
+    println!("⚠️ TrueType 폰트를 찾지 못해 기본 폰트 사용 (한글 표시 불가)");
+    doc.add_builtin_font(BuiltinFont::Helvetica)
+        .map_err(|e| format!("폰트 로드 실패: {}", e))
+}
+
+pub fn build_pdf(
+    template_value: &Value,
+    context: &Value,
+    output_path: &str,
+) -> Result<usize, String> {
+    let (page_width, page_height) = match template_value["page"]["size"].as_str().unwrap_or("A4") {
+        "A4" | "a4" => (210.0, 297.0),
+        "A5" | "a5" => (148.0, 210.0),
+        "Letter" | "letter" => (215.9, 279.4),
+        other => return Err(format!("UNSUPPORTED_PAGE_SIZE: {}", other)),
+    };
+    let margin = template_value["page"]["margin_mm"].as_f64().unwrap_or(15.0) as f32;
+    let title = template::render(
+        template_value["title"].as_str().unwrap_or("Document"),
+        context,
+    );
+
+    let (doc, page, layer) = PdfDocument::new(&title, Mm(page_width), Mm(page_height), "Page 1");
+    let font = load_font(&doc, template_value["font_path"].as_str())?;
+    let first_layer = doc.get_page(page).get_layer(layer);
+
+    let mut layout = Layout {
+        doc,
+        font,
+        layers: vec![first_layer],
+        page_width,
+        page_height,
+        margin,
+        cursor_y: 0.0,
+    };
+    layout.cursor_y = layout.content_top();
+
+    let blocks = template_value["blocks"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default();
+    for (index, block) in blocks.iter().enumerate() {
+        match block["type"].as_str().unwrap_or("text") {
+            "text" => render_text_block(&mut layout, block, context),
+            "table" => render_table_block(&mut layout, block, context)?,
+            "image" => render_image_block(&mut layout, block, context)?,
+            "spacer" => layout.cursor_y -= block["height_mm"].as_f64().unwrap_or(5.0) as f32,
+            "page_break" => layout.new_page(),
+            other => return Err(format!("UNSUPPORTED_BLOCK: #{} {}", index, other)),
+        }
+    }
+
+    // 머리글/바닥글은 전체 페이지 수를 안 뒤에 작성
+    let pages = layout.layers.len();
+    for (i, layer) in layout.layers.iter().enumerate() {
+        let mut page_context = context.clone();
+        if let Value::Object(map) = &mut page_context {
+            map.insert("page".to_string(), json!(i + 1));
+            map.insert("pages".to_string(), json!(pages));
+        } else {
+            page_context = json!({ "page": i + 1, "pages": pages });
+        }
+
+        if let Some(header) = template_value["header"].as_str() {
+            let text = template::render(header, &page_context);
+            layer.use_text(
+                text,
+                9.0,
+                Mm(margin),
+                Mm(page_height - margin),
+                &layout.font,
+            );
+        }
+        if let Some(footer) = template_value["footer"].as_str() {
+            let text = template::render(footer, &page_context);
+            let x = (page_width - layout.text_width(&text, 9.0)) / 2.0;
+            layer.use_text(text, 9.0, Mm(x), Mm(margin - 3.0), &layout.font);
+        }
+    }
+
+    super::file_creator_node::ensure_parent_dir(output_path)?;
+    let file = File::create(output_path).map_err(|_| "FILE_CREATE_ERROR".to_string())?;
+    layout
+        .doc
+        .save(&mut BufWriter::new(file))
+        .map_err(|e| format!("PDF 저장 실패: {}", e))?;
+
+    Ok(pages)
+}
+
+#[tauri::command]
+pub async fn pdf_builder_node(
+    template: Value,
+    context: Option<Value>,
+    output_path: String,
+) -> Result<String, String> {
+    // 이전 노드 출력이 JSON 문자열로 들어오는 경우도 허용
+    let parse = |value: Value, name: &str| match value {
+        Value::String(s) => {
+            serde_json::from_str::<Value>(&s).map_err(|e| format!("INVALID_{}: {}", name, e))
+        }
+        other => Ok(other),
+    };
+    let template_value = parse(template, "TEMPLATE")?;
+    let context = parse(context.unwrap_or(Value::Null), "CONTEXT")?;
+
+    if output_path.trim().is_empty() {
+        return Err("EMPTY_OUTPUT_PATH".to_string());
+    }
+    println!("🧾 PDF Builder Node: {}", output_path);
+
+    let path = safe_path::prepare_new(&output_path)?
+        .to_string_lossy()
+        .to_string();
+    let target = path.clone();
+    let pages = tokio::task::spawn_blocking(move || build_pdf(&template_value, &context, &target))
+        .await
+        .map_err(|e| format!("PDF 생성 실패: {}", e))??;
+
+    println!("✅ PDF 생성 완료: {} ({}페이지)", path, pages);
+    Ok(json!({ "file_path": path, "pages": pages }).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::{parse_output, path_str, temp_dir};
+    use serde_json::json;
+    use std::fs;
+
+    #[tokio::test]
+    async fn pdf_builder_paginates_long_tables() {
+        let dir = temp_dir();
+        let output = dir.path().join("report.pdf");
+        let items: Vec<_> = (0..120)
+            .map(|i| json!({ "item": format!("Item {}", i), "qty": i }))
+            .collect();
+
+        let result = pdf_builder_node(
+            json!({
+                "title": "Report",
+                "footer": "{{page}} / {{pages}}",
+                "blocks": [
+                    { "type": "text", "text": "Customer: {{customer}}", "size": 14 },
+                    { "type": "table", "source": "items", "columns": ["item", "qty"] }
+                ]
+            }),
+            Some(json!({ "customer": "ACME", "items": items })),
+            path_str(&output),
+        )
+        .await
+        .unwrap();
+
+        assert!(parse_output(&result)["pages"].as_u64().unwrap() > 1);
+        assert!(fs::read(&output).unwrap().starts_with(b"%PDF"));
+    }
+}
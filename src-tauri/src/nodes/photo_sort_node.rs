@@ -0,0 +1,383 @@
+// src-tauri/src/nodes/photo_sort_node.rs
+use super::{disk_preflight, safe_path};
+use chrono::{DateTime, Datelike, Local, NaiveDate};
+use serde_json::json;
+use std::fs;
+use std::io::{BufReader, Read};
+use std::path::{Path, PathBuf};
+
+// 🗂️ 사진 정리 노드
+// 사진/동영상을 촬영일(EXIF) 또는 수정일 기준 폴더 구조로 이동/복사
+// pattern 토큰: {year} {month} {day} {camera} {ext} (기본 "{year}/{year}-{month}")
+// 같은 이름이 있으면 내용이 같으면 건너뛰고, 다르면 "이름 (1).jpg" 로 저장
+// dry_run 이면 실제로 옮기지 않고 계획만 보고
+
+const IMAGE_EXTENSIONS: &[&str] = &[
+    "jpg", "jpeg", "png", "heic", "heif", "gif", "webp", "tif", "tiff", "bmp", "dng", "cr2", "cr3",
+    "nef", "arw", "raf", "orf", "rw2",
+];
+const VIDEO_EXTENSIONS: &[&str] = &[
+    "mp4", "mov", "m4v", "avi", "mkv", "3gp", "mts", "m2ts", "wmv",
+];
+
+struct MediaInfo {
+    date: NaiveDate,
+    date_source: &'static str, // "exif" | "modified"
+    camera: Option<String>,
+}
+
+fn extension_of(path: &Path) -> Option<String> {
+    path.extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .filter(|e| {
+            IMAGE_EXTENSIONS.contains(&e.as_str()) || VIDEO_EXTENSIONS.contains(&e.as_str())
+        })
+}
+
+fn ascii_field(exif: &exif::Exif, tag: exif::Tag) -> Option<String> {
+    let field = exif.get_field(tag, exif::In::PRIMARY)?;
+    match &field.value {
+        exif::Value::Ascii(values) => values.first().map(|bytes| {
+            String::from_utf8_lossy(bytes)
+                .trim_matches(char::from(0))
+                .trim()
+                .to_string()
+        }),
+        _ => None,
+    }
+    .filter(|s| !s.is_empty())
+}
+
+fn read_media_info(path: &Path) -> MediaInfo {
+    // EXIF 촬영일 우선
+    let exif = fs::File::open(path).ok().and_then(|file| {
+        exif::Reader::new()
+            .read_from_container(&mut BufReader::new(file))
+            .ok()
+    });
+
+    let exif_date = exif.as_ref().and_then(|exif| {
+        [
+            exif::Tag::DateTimeOriginal,
+            exif::Tag::DateTimeDigitized,
+            exif::Tag::DateTime,
+        ]
+        .iter()
+        .find_map(|tag| {
+            let field = exif.get_field(*tag, exif::In::PRIMARY)?;
+            let exif::Value::Ascii(values) = &field.value else {
+                return None;
+            };
+            let dt = exif::DateTime::from_ascii(values.first()?).ok()?;
+            NaiveDate::from_ymd_opt(dt.year as i32, dt.month as u32, dt.day as u32)
+        })
+    });
+    let camera = exif
+        .as_ref()
+        .and_then(|exif| ascii_field(exif, exif::Tag::Model));
+
+    match exif_date {
+        Some(date) => MediaInfo {
+            date,
+            date_source: "exif",
+            camera,
+        },
+        None => {
+            let modified = fs::metadata(path)
+                .and_then(|m| m.modified())
+                .map(DateTime::<Local>::from)
+                .unwrap_or_else(|_| Local::now());
+            MediaInfo {
+                date: modified.date_naive(),
+                date_source: "modified",
+                camera,
+            }
+        }
+    }
+}
+
+// 폴더 이름에 쓸 수 없는 문자 제거
+fn sanitize_segment(text: &str) -> String {
+    safe_path::sanitize_file_name(text, "")
+}
+
+pub fn render_pattern(pattern: &str, date: NaiveDate, camera: Option<&str>, ext: &str) -> PathBuf {
+    let rendered = pattern
+        .replace("{year}", &format!("{:04}", date.year()))
+        .replace("{month}", &format!("{:02}", date.month()))
+        .replace("{day}", &format!("{:02}", date.day()))
+        .replace("{camera}", &sanitize_segment(camera.unwrap_or("Unknown")))
+        .replace("{ext}", ext);
+
+    rendered
+        .split(['/', '\\'])
+        .filter(|segment| !segment.trim().is_empty() && *segment != "..")
+        .map(sanitize_segment)
+        .collect()
+}
+
+fn same_content(a: &Path, b: &Path) -> bool {
+    let (Ok(meta_a), Ok(meta_b)) = (fs::metadata(a), fs::metadata(b)) else {
+        return false;
+    };
+    if meta_a.len() != meta_b.len() {
+        return false;
+    }
+    let (Ok(mut file_a), Ok(mut file_b)) = (fs::File::open(a), fs::File::open(b)) else {
+        return false;
+    };
+
+    let mut buf_a = vec![0u8; 64 * 1024];
+    let mut buf_b = vec![0u8; 64 * 1024];
+    loop {
+        let read_a = file_a.read(&mut buf_a).unwrap_or(0);
+        let read_b = file_b.read(&mut buf_b).unwrap_or(0);
+        if read_a != read_b || buf_a[..read_a] != buf_b[..read_b] {
+            return false;
+        }
+        if read_a == 0 {
+            return true;
+        }
+    }
+}
+
+// 충돌 처리: 같은 내용이면 None(중복), 아니면 비어있는 " (n)" 이름
+fn resolve_collision(source: &Path, target: PathBuf, planned: &[PathBuf]) -> Option<PathBuf> {
+    if !target.exists() && !planned.contains(&target) {
+        return Some(target);
+    }
+    if target.exists() && same_content(source, &target) {
+        return None;
+    }
+
+    let stem = target
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let ext = target
+        .extension()
+        .map(|e| format!(".{}", e.to_string_lossy()))
+        .unwrap_or_default();
+
+    (1..)
+        .map(|n| target.with_file_name(format!("{} ({}){}", stem, n, ext)))
+        .find(|candidate| !candidate.exists() && !planned.contains(candidate))
+}
+
+fn transfer(source: &Path, target: &Path, copy: bool) -> Result<(), String> {
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent).map_err(|_| "DIRECTORY_CREATE_ERROR".to_string())?;
+    }
+    if copy {
+        fs::copy(source, target)
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    } else {
+        // 다른 드라이브(SD 카드 → 디스크)면 rename 이 실패하므로 복사 후 삭제
+        fs::rename(source, target).or_else(|_| {
+            fs::copy(source, target)
+                .and_then(|_| fs::remove_file(source))
+                .map_err(|e| e.to_string())
+        })
+    }
+}
+
+#[tauri::command]
+pub async fn photo_sort_node(
+    source: String,
+    destination: String,
+    pattern: Option<String>,
+    mode: Option<String>,
+    dry_run: Option<bool>,
+    recursive: Option<bool>,
+) -> Result<String, String> {
+    let source_dir = PathBuf::from(source.trim());
+    let destination_dir = PathBuf::from(destination.trim());
+    if !source_dir.is_dir() {
+        return Err("SOURCE_FOLDER_NOT_FOUND".to_string());
+    }
+    if destination.trim().is_empty() {
+        return Err("EMPTY_DESTINATION".to_string());
+    }
+
+    let pattern = pattern
+        .filter(|p| !p.trim().is_empty())
+        .unwrap_or_else(|| "{year}/{year}-{month}".to_string());
+    let copy = match mode.as_deref().unwrap_or("move") {
+        "move" => false,
+        "copy" => true,
+        other => return Err(format!("UNSUPPORTED_MODE: {}", other)),
+    };
+    let dry_run = dry_run.unwrap_or(false);
+    let max_depth = if recursive.unwrap_or(true) {
+        usize::MAX
+    } else {
+        1
+    };
+
+    println!(
+        "🗂️ Photo Sort Node: {} → {} ({}, dry_run: {})",
+        source_dir.display(),
+        destination_dir.display(),
+        pattern,
+        dry_run
+    );
+
+    tokio::task::spawn_blocking(move || {
+        // 복사는 원본 크기만큼 공간이 더 필요하므로 시작 전에 확인
+        if copy && !dry_run {
+            let total: u64 = walkdir::WalkDir::new(&source_dir)
+                .max_depth(max_depth)
+                .into_iter()
+                .filter_map(|e| e.ok())
+                .filter(|e| e.file_type().is_file() && extension_of(e.path()).is_some())
+                .filter_map(|e| e.metadata().ok())
+                .map(|m| m.len())
+                .sum();
+            disk_preflight::ensure_free_space(&destination_dir, total)?;
+        }
+
+        let mut planned: Vec<PathBuf> = Vec::new();
+        let mut entries = Vec::new();
+        let (mut moved, mut duplicates, mut failed) = (0, 0, 0);
+
+        let files = walkdir::WalkDir::new(&source_dir)
+            .max_depth(max_depth)
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file())
+            // 정리 대상 폴더가 원본 안에 있으면 다시 처리하지 않음
+            .filter(|e| !e.path().starts_with(&destination_dir));
+
+        for entry in files {
+            let path = entry.path();
+            let Some(ext) = extension_of(path) else {
+                continue;
+            };
+
+            let info = read_media_info(path);
+            let folder = destination_dir.join(render_pattern(
+                &pattern,
+                info.date,
+                info.camera.as_deref(),
+                &ext,
+            ));
+            let target = folder.join(entry.file_name());
+
+            let mut report = json!({
+                "source": path.to_string_lossy(),
+                "date": info.date.to_string(),
+                "date_source": info.date_source,
+                "camera": info.camera,
+            });
+
+            match resolve_collision(path, target, &planned) {
+                None => {
+                    duplicates += 1;
+                    report["status"] = json!("duplicate");
+                }
+                Some(target) => {
+                    report["target"] = json!(target.to_string_lossy());
+                    if dry_run {
+                        report["status"] = json!("planned");
+                        moved += 1;
+                    } else {
+                        match transfer(path, &target, copy) {
+                            Ok(()) => {
+                                report["status"] = json!(if copy { "copied" } else { "moved" });
+                                moved += 1;
+                            }
+                            Err(e) => {
+                                report["status"] = json!("failed");
+                                report["error"] = json!(e);
+                                failed += 1;
+                            }
+                        }
+                    }
+                    planned.push(target);
+                }
+            }
+            entries.push(report);
+        }
+
+        println!(
+            "✅ 사진 정리: {}개 처리, {}개 중복, {}개 실패",
+            moved, duplicates, failed
+        );
+        Ok(json!({
+            "dry_run": dry_run,
+            "processed": moved,
+            "duplicates": duplicates,
+            "failed": failed,
+            "files": entries
+        })
+        .to_string())
+    })
+    .await
+    .map_err(|e| format!("사진 정리 실패: {}", e))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::{parse_output, path_str, temp_dir};
+    use std::fs;
+
+    #[tokio::test]
+    async fn photo_sort_dry_run_plans_date_folders_and_skips_duplicates() {
+        let source = temp_dir();
+        let destination = temp_dir();
+        let photo = source.path().join("IMG_0001.jpg");
+        fs::write(&photo, b"not really a jpeg").unwrap();
+        fs::write(source.path().join("notes.txt"), "ignored").unwrap();
+
+        // EXIF 가 없으면 수정일 기준
+        let modified = std::time::UNIX_EPOCH + std::time::Duration::from_secs(1_684_000_000); // 2023-05-13
+        fs::File::options()
+            .write(true)
+            .open(&photo)
+            .unwrap()
+            .set_modified(modified)
+            .unwrap();
+
+        // 같은 내용이 이미 정리되어 있는 경우
+        let existing_dir = destination.path().join("2023").join("2023-05");
+        fs::create_dir_all(&existing_dir).unwrap();
+        fs::write(existing_dir.join("IMG_0001.jpg"), b"not really a jpeg").unwrap();
+
+        let output = photo_sort_node(
+            path_str(source.path()),
+            path_str(destination.path()),
+            None,
+            None,
+            Some(true),
+            None,
+        )
+        .await
+        .unwrap();
+        let value = parse_output(&output);
+
+        assert_eq!(value["duplicates"], 1);
+        assert_eq!(value["files"][0]["date_source"], "modified");
+        assert!(photo.exists());
+    }
+
+    #[test]
+    fn photo_sort_pattern_sanitizes_camera_names() {
+        let date = chrono::NaiveDate::from_ymd_opt(2024, 1, 9).unwrap();
+
+        let path = render_pattern(
+            "{camera}/{year}/{month}-{day}",
+            date,
+            Some("EOS R5: Mk/II"),
+            "jpg",
+        );
+
+        assert_eq!(
+            path,
+            std::path::PathBuf::from("EOS R5_ Mk_II")
+                .join("2024")
+                .join("01-09")
+        );
+    }
+}
@@ -0,0 +1,255 @@
+// src-tauri/src/nodes/placeholders.rs
+use super::{atomic_store, secrets, template};
+use serde_json::{json, Map, Value};
+use std::path::PathBuf;
+
+// 🧩 노드 파라미터 플레이스홀더 확장 (노드 실행 직전 공용 단계)
+// - ${env:NAME}      환경 변수
+// - ${var:NAME}      변수 저장소 (store/variables.json + 실행 중 넘긴 값, 점 경로 가능: ${var:user.name})
+// - ${secret:NAME}   OS 키체인 비밀값 (secrets)
+// - ${date:FORMAT}   현재 시각 (chrono 형식, 비우면 %Y-%m-%d)
+// 값이 없으면 PLACEHOLDER_NOT_FOUND 오류, 처리하지 않는 종류(${input:…}, ${workspace} 등)는 그대로 둠
+// 문자열 전체가 ${var:…} 하나면 숫자/객체 등 원래 타입 그대로 주입
+// 비밀값이 든 결과는 해당 노드 호출에만 쓰고 저장/로그에 남기지 않음
+
+const DEFAULT_DATE_FORMAT: &str = "%Y-%m-%d";
+
+pub struct Resolver<'a> {
+    pub variables: &'a Value,
+    pub secret: &'a dyn Fn(&str) -> Result<String, String>,
+    pub now: chrono::DateTime<chrono::Local>,
+}
+
+fn variables_path() -> PathBuf {
+    atomic_store::store_dir().join("variables.json")
+}
+
+pub fn load_variables() -> Map<String, Value> {
+    atomic_store::read_json_verified(&variables_path()).unwrap_or_default()
+}
+
+fn validate_name(name: &str) -> Result<&str, String> {
+    let name = name.trim();
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-'))
+    {
+        return Err(format!("INVALID_VARIABLE_NAME: {}", name));
+    }
+    Ok(name)
+}
+
+impl Resolver<'_> {
+    // "env:HOME" → Some(값), 처리하지 않는 종류면 None
+    fn resolve(&self, expr: &str) -> Option<Result<Value, String>> {
+        let (kind, arg) = expr.split_once(':')?;
+        let not_found = || format!("PLACEHOLDER_NOT_FOUND: {}", expr);
+        let resolved = match kind.trim() {
+            "env" => std::env::var(arg.trim())
+                .map(Value::String)
+                .map_err(|_| not_found()),
+            "var" => template::lookup(self.variables, arg.trim())
+                .filter(|v| !v.is_null())
+                .cloned()
+                .ok_or_else(not_found),
+            "secret" => (self.secret)(arg.trim()).map(Value::String),
+            "date" => {
+                let format = if arg.trim().is_empty() {
+                    DEFAULT_DATE_FORMAT
+                } else {
+                    arg
+                };
+                format_date(&self.now, format).map(Value::String)
+            }
+            _ => return None,
+        };
+        Some(resolved)
+    }
+
+    pub fn expand_text(&self, text: &str) -> Result<String, String> {
+        let mut result = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(start) = rest.find("${") {
+            result.push_str(&rest[..start]);
+            let after = &rest[start + 2..];
+            let Some(end) = after.find('}') else {
+                result.push_str(&rest[start..]);
+                return Ok(result);
+            };
+            let expr = &after[..end];
+            match self.resolve(expr) {
+                Some(value) => result.push_str(&template::value_to_text(&value?)),
+                None => result.push_str(&rest[start..start + 2 + end + 1]),
+            }
+            rest = &after[end + 1..];
+        }
+        result.push_str(rest);
+        Ok(result)
+    }
+
+    pub fn expand_value(&self, value: &mut Value) -> Result<(), String> {
+        match value {
+            Value::String(text) => {
+                if !text.contains("${") {
+                    return Ok(());
+                }
+                // 문자열 전체가 ${var:…} 하나면 원래 타입 그대로
+                let trimmed = text.trim();
+                if let Some(expr) = trimmed
+                    .strip_prefix("${var:")
+                    .and_then(|rest| rest.strip_suffix('}'))
+                    .filter(|expr| !expr.contains('}'))
+                {
+                    if let Some(found) = self.resolve(&format!("var:{}", expr)) {
+                        *value = found?;
+                        return Ok(());
+                    }
+                }
+                *text = self.expand_text(text)?;
+            }
+            Value::Array(items) => {
+                for item in items {
+                    self.expand_value(item)?;
+                }
+            }
+            Value::Object(map) => {
+                for item in map.values_mut() {
+                    self.expand_value(item)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+// 잘못된 형식 지정자가 있으면 chrono 가 패닉하지 않도록 먼저 검사
+pub fn format_date(now: &chrono::DateTime<chrono::Local>, format: &str) -> Result<String, String> {
+    use chrono::format::{Item, StrftimeItems};
+    if StrftimeItems::new(format).any(|item| matches!(item, Item::Error)) {
+        return Err(format!("INVALID_DATE_FORMAT: {}", format));
+    }
+    Ok(now.format(format).to_string())
+}
+
+// 저장된 변수 + 실행 중 넘긴 변수 (같은 이름이면 실행 값 우선)
+fn merged_variables(run_variables: Option<Value>) -> Value {
+    let mut variables = load_variables();
+    if let Some(Value::Object(run)) = run_variables {
+        variables.extend(run);
+    }
+    Value::Object(variables)
+}
+
+// 노드 실행 직전에 호출: 파라미터 객체 전체의 플레이스홀더 확장
+#[tauri::command]
+pub fn expand_node_params(params: Value, variables: Option<Value>) -> Result<Value, String> {
+    let variables = merged_variables(variables);
+    let resolver = Resolver {
+        variables: &variables,
+        secret: &secrets::get,
+        now: chrono::Local::now(),
+    };
+    let mut params = params;
+    resolver.expand_value(&mut params)?;
+    Ok(params)
+}
+
+#[tauri::command]
+pub fn set_variable(name: String, value: Value) -> Result<String, String> {
+    let name = validate_name(&name)?.to_string();
+    let mut variables = load_variables();
+    variables.insert(name.clone(), value);
+    atomic_store::write_json_atomic(&variables_path(), &variables)?;
+    println!("🧩 변수 저장: {}", name);
+    Ok("SUCCESS".to_string())
+}
+
+#[tauri::command]
+pub fn delete_variable(name: String) -> Result<String, String> {
+    let name = validate_name(&name)?.to_string();
+    let mut variables = load_variables();
+    variables.remove(&name);
+    atomic_store::write_json_atomic(&variables_path(), &variables)?;
+    println!("🗑️ 변수 삭제: {}", name);
+    Ok("SUCCESS".to_string())
+}
+
+#[tauri::command]
+pub fn list_variables() -> Result<Value, String> {
+    Ok(json!(load_variables()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn fake_secret(name: &str) -> Result<String, String> {
+        match name {
+            "API_TOKEN" => Ok("s3cret".to_string()),
+            other => Err(format!("SECRET_NOT_FOUND: {}", other)),
+        }
+    }
+
+    #[test]
+    fn placeholders_expand_env_var_secret_and_date() {
+        std::env::set_var("PLACEHOLDER_TEST_5057", "from-env");
+        let variables = json!({ "user": { "name": "민수" }, "count": 3, "tags": ["a", "b"] });
+        let now = chrono::TimeZone::with_ymd_and_hms(&chrono::Local, 2026, 3, 7, 9, 5, 0).unwrap();
+        let resolver = Resolver {
+            variables: &variables,
+            secret: &fake_secret,
+            now,
+        };
+
+        let mut params = json!({
+            "path": "${env:PLACEHOLDER_TEST_5057}/${date:%Y%m%d}.txt",
+            "greeting": "안녕 ${var:user.name} (${date:})",
+            "headers": { "Authorization": "Bearer ${secret:API_TOKEN}" },
+            "count": "${var:count}",
+            "tags": "${var:tags}",
+            "later": "${input:file} ${workspace}/x"
+        });
+        resolver.expand_value(&mut params).unwrap();
+
+        assert_eq!(params["path"], "from-env/20260307.txt");
+        assert_eq!(params["greeting"], "안녕 민수 (2026-03-07)");
+        assert_eq!(params["headers"]["Authorization"], "Bearer s3cret");
+        // 값 하나짜리는 원래 타입 그대로
+        assert_eq!(params["count"], 3);
+        assert_eq!(params["tags"], json!(["a", "b"]));
+        // 다른 단계에서 처리하는 플레이스홀더는 그대로
+        assert_eq!(params["later"], "${input:file} ${workspace}/x");
+    }
+
+    #[test]
+    fn placeholders_report_missing_values() {
+        let variables = json!({});
+        let resolver = Resolver {
+            variables: &variables,
+            secret: &fake_secret,
+            now: chrono::Local::now(),
+        };
+
+        let err = resolver.expand_text("${var:missing}").unwrap_err();
+        assert_eq!(err, "PLACEHOLDER_NOT_FOUND: var:missing");
+        assert!(resolver
+            .expand_text("${env:SURELY_UNSET_VAR_5057}")
+            .unwrap_err()
+            .starts_with("PLACEHOLDER_NOT_FOUND"));
+        assert!(resolver
+            .expand_text("${secret:OTHER}")
+            .unwrap_err()
+            .starts_with("SECRET_NOT_FOUND"));
+        assert!(resolver
+            .expand_text("${date:%Q}")
+            .unwrap_err()
+            .starts_with("INVALID_DATE_FORMAT"));
+        assert_eq!(
+            resolver.expand_text("unclosed ${var:x").unwrap(),
+            "unclosed ${var:x"
+        );
+    }
+}
@@ -0,0 +1,403 @@
+// src-tauri/src/nodes/podcast_node.rs
+use super::{atomic_store, disk_preflight, download_registry, safe_path};
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use tokio::io::AsyncWriteExt;
+
+// 🎙️ 팟캐스트(RSS 첨부 오디오) 다운로드 노드
+// 지난 실행 이후 새로 올라온 에피소드만 받아 ID3 태그(제목/프로그램/날짜)를 붙이고
+// keep_last_n 이 있으면 최신 N개만 남기고 이전에 받은 파일은 삭제
+// 피드별 상태(본 GUID, 받은 파일)는 store/podcasts/<피드 해시>.json
+// 처음 실행할 때는 최신 에피소드 몇 개만 받고 나머지는 "이미 본 것"으로 기록 (과거 수백 개 일괄 다운로드 방지)
+
+const DEFAULT_MAX_NEW: usize = 3;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Episode {
+    pub guid: String,
+    pub title: String,
+    pub url: String,
+    pub mime_type: String,
+    pub length: u64,
+    pub published: Option<chrono::DateTime<chrono::FixedOffset>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DownloadedEpisode {
+    pub guid: String,
+    pub title: String,
+    pub file: String,
+    pub published: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PodcastState {
+    pub feed_url: String,
+    pub seen: Vec<String>,
+    pub downloaded: Vec<DownloadedEpisode>,
+    pub last_run: Option<String>,
+}
+
+fn state_path(feed_url: &str) -> PathBuf {
+    let hash = format!("{:x}", Sha256::digest(feed_url.as_bytes()));
+    atomic_store::store_dir()
+        .join("podcasts")
+        .join(format!("{}.json", &hash[..16]))
+}
+
+// 피드의 오디오 첨부 에피소드 (최신순)
+pub fn parse_feed(content: &[u8]) -> Result<(String, Vec<Episode>), String> {
+    let channel = rss::Channel::read_from(content).map_err(|e| format!("INVALID_FEED: {}", e))?;
+    let mut episodes: Vec<Episode> = channel
+        .items()
+        .iter()
+        .filter_map(|item| {
+            let enclosure = item.enclosure()?;
+            let url = enclosure.url().trim().to_string();
+            if url.is_empty() {
+                return None;
+            }
+            Some(Episode {
+                // GUID 가 없는 피드는 첨부 URL 을 식별자로
+                guid: item
+                    .guid()
+                    .map(|g| g.value().trim().to_string())
+                    .filter(|g| !g.is_empty())
+                    .unwrap_or_else(|| url.clone()),
+                title: item.title().unwrap_or("").trim().to_string(),
+                url,
+                mime_type: enclosure.mime_type().to_string(),
+                length: enclosure.length().trim().parse().unwrap_or(0),
+                published: item
+                    .pub_date()
+                    .and_then(|d| chrono::DateTime::parse_from_rfc2822(d.trim()).ok()),
+            })
+        })
+        .collect();
+    // 날짜가 없으면 피드 순서 유지 (안정 정렬)
+    episodes.sort_by_key(|e| std::cmp::Reverse(e.published));
+    Ok((channel.title().trim().to_string(), episodes))
+}
+
+// 아직 보지 않은 에피소드 중 최신 limit 개
+pub fn select_new<'a>(
+    episodes: &'a [Episode],
+    seen: &HashSet<String>,
+    limit: usize,
+) -> Vec<&'a Episode> {
+    episodes
+        .iter()
+        .filter(|episode| !seen.contains(&episode.guid))
+        .take(limit)
+        .collect()
+}
+
+fn sanitize(text: &str) -> String {
+    let cleaned: String = safe_path::sanitize_file_name(text, "episode")
+        .chars()
+        .take(120)
+        .collect();
+    cleaned.trim_end_matches(['.', ' ']).to_string()
+}
+
+fn extension_for(episode: &Episode) -> String {
+    let from_url = url::Url::parse(&episode.url).ok().and_then(|u| {
+        Path::new(u.path())
+            .extension()
+            .map(|e| e.to_string_lossy().to_lowercase())
+            .filter(|e| e.len() <= 4 && e.chars().all(|c| c.is_ascii_alphanumeric()))
+    });
+    from_url.unwrap_or_else(|| {
+        match episode.mime_type.as_str() {
+            "audio/mp4" | "audio/x-m4a" | "audio/m4a" => "m4a",
+            "audio/ogg" => "ogg",
+            "audio/aac" => "aac",
+            "video/mp4" => "mp4",
+            _ => "mp3",
+        }
+        .to_string()
+    })
+}
+
+// "2026-10-05 에피소드 제목.mp3"
+pub fn episode_file_name(episode: &Episode) -> String {
+    let title = if episode.title.is_empty() {
+        "episode"
+    } else {
+        episode.title.as_str()
+    };
+    let name = match episode.published {
+        Some(date) => format!("{} {}", date.format("%Y-%m-%d"), title),
+        None => title.to_string(),
+    };
+    format!("{}.{}", sanitize(&name), extension_for(episode))
+}
+
+// 최신 keep 개만 남기고 나머지는 삭제 대상으로 (downloaded 는 최신순)
+pub fn prune(
+    downloaded: Vec<DownloadedEpisode>,
+    keep: Option<usize>,
+) -> (Vec<DownloadedEpisode>, Vec<DownloadedEpisode>) {
+    match keep {
+        Some(keep) if downloaded.len() > keep => {
+            let mut kept = downloaded;
+            let removed = kept.split_off(keep);
+            (kept, removed)
+        }
+        _ => (downloaded, Vec::new()),
+    }
+}
+
+// ID3 태그는 MP3 에만 (m4a/ogg 앞에 붙이면 파일이 깨짐)
+fn tag_episode(path: &Path, podcast: &str, episode: &Episode) {
+    use id3::TagLike;
+
+    let is_mp3 = path
+        .extension()
+        .is_some_and(|e| e.eq_ignore_ascii_case("mp3"));
+    if !is_mp3 {
+        return;
+    }
+
+    let mut tag = id3::Tag::read_from_path(path).unwrap_or_else(|_| id3::Tag::new());
+    if !episode.title.is_empty() {
+        tag.set_title(episode.title.as_str());
+    }
+    tag.set_album(podcast);
+    if tag.artist().is_none() {
+        tag.set_artist(podcast);
+    }
+    tag.set_genre("Podcast");
+    if let Some(date) = episode.published {
+        tag.set_year(chrono::Datelike::year(&date));
+    }
+    if let Err(e) = tag.write_to_path(path, id3::Version::Id3v24) {
+        println!("⚠️ 태그 쓰기 실패 ({}): {}", path.display(), e);
+    }
+}
+
+async fn download_episode(
+    client: &reqwest::Client,
+    episode: &Episode,
+    target: &Path,
+) -> Result<(), String> {
+    let response = client
+        .get(&episode.url)
+        .send()
+        .await
+        .map_err(|e| format!("에피소드 다운로드 실패: {}", e))?;
+    if !response.status().is_success() {
+        return Err(format!(
+            "EPISODE_HTTP_ERROR: {} ({})",
+            response.status(),
+            episode.url
+        ));
+    }
+
+    // 중간에 끊기면 반쪽 파일이 남지 않도록 .part 로 받은 뒤 이름 변경
+    let part = target.with_extension("part");
+    let mut file = tokio::fs::File::create(&part)
+        .await
+        .map_err(|e| format!("파일 생성 실패: {}", e))?;
+    let mut stream = response.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|e| format!("에피소드 다운로드 실패: {}", e))?;
+        file.write_all(&chunk)
+            .await
+            .map_err(|e| format!("파일 쓰기 실패: {}", e))?;
+    }
+    file.flush()
+        .await
+        .map_err(|e| format!("파일 쓰기 실패: {}", e))?;
+    drop(file);
+    tokio::fs::rename(&part, target)
+        .await
+        .map_err(|e| format!("파일 이름 변경 실패: {}", e))
+}
+
+#[tauri::command]
+pub async fn podcast_node(
+    feed_url: String,
+    destination: String,
+    keep_last_n: Option<usize>,
+    max_new: Option<usize>,
+) -> Result<String, String> {
+    let feed_url = feed_url.trim().to_string();
+    url::Url::parse(&feed_url).map_err(|_| format!("INVALID_FEED_URL: {}", feed_url))?;
+    let destination = PathBuf::from(destination.trim());
+    std::fs::create_dir_all(&destination).map_err(|e| format!("폴더 생성 실패: {}", e))?;
+    let keep_last_n = keep_last_n.filter(|n| *n > 0);
+    let limit = max_new.or(keep_last_n).unwrap_or(DEFAULT_MAX_NEW).max(1);
+
+    println!("🎙️ Podcast Node: {} → {}", feed_url, destination.display());
+
+    let client = reqwest::Client::builder()
+        .user_agent("automation-gui-podcast/1.0")
+        .build()
+        .map_err(|e| e.to_string())?;
+    let feed = client
+        .get(&feed_url)
+        .timeout(std::time::Duration::from_secs(30))
+        .send()
+        .await
+        .map_err(|e| format!("피드 가져오기 실패: {}", e))?
+        .bytes()
+        .await
+        .map_err(|e| format!("피드 가져오기 실패: {}", e))?;
+    let (podcast, episodes) = parse_feed(&feed)?;
+
+    let path = state_path(&feed_url);
+    let mut state: PodcastState = atomic_store::read_json_verified(&path).unwrap_or_default();
+    state.feed_url = feed_url.clone();
+    let mut seen: HashSet<String> = state.seen.iter().cloned().collect();
+
+    let new_episodes: Vec<Episode> = select_new(&episodes, &seen, limit)
+        .into_iter()
+        .cloned()
+        .collect();
+    disk_preflight::ensure_free_space(&destination, new_episodes.iter().map(|e| e.length).sum())?;
+
+    let mut downloaded = Vec::new();
+    let mut skipped = Vec::new();
+    let mut errors = Vec::new();
+    let mut failed: HashSet<&str> = HashSet::new();
+    for episode in &new_episodes {
+        // 다른 워크플로우/피드에서 이미 받은 오디오는 다시 받지 않음
+        if let Some(existing) = download_registry::already_downloaded(&episode.url) {
+            println!("⏭️ 이미 받은 에피소드 건너뜀: {}", episode.title);
+            skipped.push(json!({ "title": episode.title, "file": existing.file }));
+            continue;
+        }
+        let target = destination.join(episode_file_name(episode));
+        println!("⬇️ 에피소드 다운로드: {}", episode.title);
+        match download_episode(&client, episode, &target).await {
+            Ok(()) => {
+                let tag_path = target.clone();
+                let (podcast, tag_episode_info) = (podcast.clone(), episode.clone());
+                let _ = tokio::task::spawn_blocking(move || {
+                    tag_episode(&tag_path, &podcast, &tag_episode_info)
+                })
+                .await;
+                let (record_url, record_path) = (episode.url.clone(), target.clone());
+                let duplicate = tokio::task::spawn_blocking(move || {
+                    download_registry::record_download(&record_url, Some(&record_path), "podcast")
+                })
+                .await
+                .ok()
+                .flatten();
+                if let Some(existing) = duplicate {
+                    skipped.push(json!({ "title": episode.title, "file": existing.file }));
+                    continue;
+                }
+                downloaded.push(DownloadedEpisode {
+                    guid: episode.guid.clone(),
+                    title: episode.title.clone(),
+                    file: target.to_string_lossy().to_string(),
+                    published: episode.published.map(|d| d.to_rfc3339()),
+                });
+            }
+            // 실패한 에피소드는 seen 에 넣지 않아 다음 실행에서 다시 시도
+            Err(e) => {
+                println!("❌ {}", e);
+                failed.insert(episode.guid.as_str());
+                errors.push(json!({ "title": episode.title, "error": e }));
+            }
+        }
+    }
+
+    // 받은 것 + 이번에 건너뛴 과거 에피소드는 모두 본 것으로 (실패한 것만 제외)
+    for episode in &episodes {
+        if !failed.contains(episode.guid.as_str()) && seen.insert(episode.guid.clone()) {
+            state.seen.push(episode.guid.clone());
+        }
+    }
+
+    let mut all_downloaded = downloaded.clone();
+    all_downloaded.append(&mut state.downloaded);
+    let (kept, removed) = prune(all_downloaded, keep_last_n);
+    for episode in &removed {
+        if let Err(e) = std::fs::remove_file(&episode.file) {
+            println!("⚠️ 이전 에피소드 삭제 실패 ({}): {}", episode.file, e);
+        }
+    }
+    state.downloaded = kept;
+    state.last_run = Some(chrono::Local::now().to_rfc3339());
+    atomic_store::write_json_atomic(&path, &state)?;
+
+    println!(
+        "✅ 팟캐스트 완료: 새 에피소드 {}개, 삭제 {}개",
+        downloaded.len(),
+        removed.len()
+    );
+    Ok(json!({
+        "podcast": podcast,
+        "downloaded": downloaded,
+        "skipped": skipped,
+        "removed": removed.iter().map(|e| &e.file).collect::<Vec<_>>(),
+        "errors": errors,
+        "files": state.downloaded.iter().map(|e| &e.file).collect::<Vec<_>>()
+    })
+    .to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PODCAST_FEED: &str = r#"<?xml version="1.0"?>
+<rss version="2.0"><channel><title>주간 자동화</title>
+<item><title>2화: 예약 실행</title><guid>ep-2</guid><pubDate>Mon, 12 Oct 2026 09:00:00 +0900</pubDate>
+<enclosure url="https://cdn.example.com/ep2.mp3?token=1" type="audio/mpeg" length="1000"/></item>
+<item><title>공지</title><guid>notice</guid></item>
+<item><title>1화: 시작/소개</title><guid>ep-1</guid><pubDate>Mon, 05 Oct 2026 09:00:00 +0900</pubDate>
+<enclosure url="https://cdn.example.com/audio/ep1" type="audio/x-m4a" length="900"/></item>
+</channel></rss>"#;
+
+    #[test]
+    fn podcast_parses_audio_episodes_newest_first() {
+        let (title, episodes) = parse_feed(PODCAST_FEED.as_bytes()).unwrap();
+        assert_eq!(title, "주간 자동화");
+        // 첨부 없는 글은 제외
+        assert_eq!(episodes.len(), 2);
+        assert_eq!(episodes[0].guid, "ep-2");
+        assert_eq!(episodes[0].length, 1000);
+
+        assert_eq!(
+            episode_file_name(&episodes[0]),
+            "2026-10-12 2화_ 예약 실행.mp3"
+        );
+        assert_eq!(
+            episode_file_name(&episodes[1]),
+            "2026-10-05 1화_ 시작_소개.m4a"
+        );
+
+        let seen = ["ep-2".to_string()].into_iter().collect();
+        let new = select_new(&episodes, &seen, 3);
+        assert_eq!(new.len(), 1);
+        assert_eq!(new[0].guid, "ep-1");
+    }
+
+    #[test]
+    fn podcast_prunes_to_latest_episodes() {
+        let record = |guid: &str| DownloadedEpisode {
+            guid: guid.to_string(),
+            title: guid.to_string(),
+            file: format!("{}.mp3", guid),
+            published: None,
+        };
+        let (kept, removed) = prune(vec![record("c"), record("b"), record("a")], Some(2));
+        assert_eq!(
+            kept.iter().map(|e| e.guid.as_str()).collect::<Vec<_>>(),
+            ["c", "b"]
+        );
+        assert_eq!(removed[0].guid, "a");
+
+        let (kept, removed) = prune(vec![record("a")], None);
+        assert_eq!(kept.len(), 1);
+        assert!(removed.is_empty());
+    }
+}
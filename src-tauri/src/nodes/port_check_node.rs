@@ -0,0 +1,145 @@
+// src-tauri/src/nodes/port_check_node.rs
+use serde_json::json;
+use std::time::{Duration, Instant};
+use tokio::net::TcpStream;
+
+// 🔍 포트 확인 노드
+// 포트별 열림/닫힘을 확인하고, wait_until_open 이면 모든 포트가 열릴 때까지
+// 지수 백오프로 재시도 (배포 후 서비스가 실제로 접속 가능해질 때까지 대기)
+
+const INITIAL_BACKOFF_MS: u64 = 250;
+const MAX_BACKOFF_MS: u64 = 5000;
+
+async fn is_port_open(host: &str, port: u16, timeout: Duration) -> bool {
+    matches!(
+        tokio::time::timeout(timeout, TcpStream::connect((host, port))).await,
+        Ok(Ok(_))
+    )
+}
+
+async fn check_ports(host: &str, ports: &[u16], timeout: Duration) -> Vec<(u16, bool)> {
+    let checks = ports
+        .iter()
+        .map(|&port| async move { (port, is_port_open(host, port, timeout).await) });
+    futures::future::join_all(checks).await
+}
+
+#[tauri::command]
+pub async fn port_check_node(
+    host: String,
+    ports: Vec<u16>,
+    timeout_ms: Option<u64>,
+    wait_until_open: Option<bool>,
+    max_wait_secs: Option<u64>,
+) -> Result<String, String> {
+    let host = host.trim().to_string();
+    if host.is_empty() {
+        return Err("EMPTY_HOST".to_string());
+    }
+    if ports.is_empty() {
+        return Err("NO_PORTS".to_string());
+    }
+
+    let timeout = Duration::from_millis(timeout_ms.unwrap_or(1000).max(50));
+    let wait = wait_until_open.unwrap_or(false);
+    let deadline = Instant::now() + Duration::from_secs(max_wait_secs.unwrap_or(60));
+    println!("🔍 Port Check Node: {} {:?} (wait: {})", host, ports, wait);
+
+    let started = Instant::now();
+    let mut attempts = 0u32;
+    let mut backoff = INITIAL_BACKOFF_MS;
+
+    let results = loop {
+        attempts += 1;
+        let results = check_ports(&host, &ports, timeout).await;
+        let all_open = results.iter().all(|(_, open)| *open);
+
+        if !wait || all_open || Instant::now() >= deadline {
+            break results;
+        }
+
+        // 남은 시간보다 오래 자지 않도록 제한
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        tokio::time::sleep(Duration::from_millis(backoff).min(remaining)).await;
+        backoff = (backoff * 2).min(MAX_BACKOFF_MS);
+    };
+
+    let all_open = results.iter().all(|(_, open)| *open);
+    if wait && !all_open {
+        let closed: Vec<String> = results
+            .iter()
+            .filter(|(_, open)| !open)
+            .map(|(port, _)| port.to_string())
+            .collect();
+        return Err(format!(
+            "WAIT_TIMEOUT: {}:{} 포트가 열리지 않았습니다",
+            host,
+            closed.join(",")
+        ));
+    }
+
+    let ports_json: Vec<_> = results
+        .iter()
+        .map(|(port, open)| json!({ "port": port, "open": open }))
+        .collect();
+
+    Ok(json!({
+        "host": host,
+        "ports": ports_json,
+        "all_open": all_open,
+        "attempts": attempts,
+        "elapsed_ms": started.elapsed().as_millis() as u64
+    })
+    .to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::parse_output;
+
+    #[tokio::test]
+    async fn port_check_reports_open_and_closed_ports() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let open_port = listener.local_addr().unwrap().port();
+        let closed_port = {
+            let probe = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            probe.local_addr().unwrap().port()
+        };
+
+        let output = port_check_node(
+            "127.0.0.1".to_string(),
+            vec![open_port, closed_port],
+            Some(500),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+        let value = parse_output(&output);
+
+        assert_eq!(value["ports"][0]["open"], true);
+        assert_eq!(value["ports"][1]["open"], false);
+        assert_eq!(value["all_open"], false);
+    }
+
+    #[tokio::test]
+    async fn port_check_wait_times_out_on_closed_port() {
+        let closed_port = {
+            let probe = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            probe.local_addr().unwrap().port()
+        };
+
+        let error = port_check_node(
+            "127.0.0.1".to_string(),
+            vec![closed_port],
+            Some(200),
+            Some(true),
+            Some(1),
+        )
+        .await
+        .unwrap_err();
+
+        assert!(error.starts_with("WAIT_TIMEOUT"));
+    }
+}
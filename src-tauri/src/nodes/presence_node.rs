@@ -0,0 +1,136 @@
+// src-tauri/src/nodes/presence_node.rs
+use super::os_command;
+use serde_json::json;
+
+// 👤 사용자 상태 노드
+// 유휴 시간, 전체화면 앱, 집중 지원(방해 금지) 상태를 확인해
+// 알림이 많은 워크플로우가 사용자가 한가할 때까지 알림을 미룰 수 있게 함
+
+#[derive(Debug, Default)]
+struct PresenceInfo {
+    idle_seconds: Option<u64>,
+    fullscreen: Option<bool>,
+    focus_assist: Option<bool>,
+}
+
+// Windows: GetLastInputInfo(유휴 시간) + SHQueryUserNotificationState(전체화면/집중 지원)
+#[cfg(target_os = "windows")]
+fn query_presence() -> PresenceInfo {
+    const SCRIPT: &str = r#"
+Add-Type @'
+using System;
+using System.Runtime.InteropServices;
+public static class Presence {
+    [StructLayout(LayoutKind.Sequential)]
+    struct LASTINPUTINFO { public uint cbSize; public uint dwTime; }
+    [DllImport("user32.dll")] static extern bool GetLastInputInfo(ref LASTINPUTINFO plii);
+    [DllImport("shell32.dll")] public static extern int SHQueryUserNotificationState(out int state);
+    public static uint IdleMillis() {
+        var info = new LASTINPUTINFO();
+        info.cbSize = (uint)Marshal.SizeOf(info);
+        GetLastInputInfo(ref info);
+        return (uint)Environment.TickCount - info.dwTime;
+    }
+}
+'@
+$state = 0
+[Presence]::SHQueryUserNotificationState([ref]$state) | Out-Null
+"$([Presence]::IdleMillis()) $state"
+"#;
+
+    let output = match os_command::powershell(SCRIPT) {
+        Ok(output) => output,
+        Err(_) => return PresenceInfo::default(),
+    };
+
+    let mut parts = output.split_whitespace();
+    let idle_ms = parts.next().and_then(|v| v.parse::<u64>().ok());
+    let state = parts.next().and_then(|v| v.parse::<i32>().ok());
+
+    // QUNS_BUSY(2) 전체화면 앱, QUNS_RUNNING_D3D_FULL_SCREEN(3), QUNS_PRESENTATION_MODE(4),
+    // QUNS_QUIET_TIME(6) 집중 지원 / 방해 금지
+    PresenceInfo {
+        idle_seconds: idle_ms.map(|ms| ms / 1000),
+        fullscreen: state.map(|s| matches!(s, 2..=4)),
+        focus_assist: state.map(|s| s == 6),
+    }
+}
+
+// macOS: IOHIDSystem의 HIDIdleTime(나노초), 집중 모드는 Focus 설정 DB 확인
+#[cfg(target_os = "macos")]
+fn query_presence() -> PresenceInfo {
+    let idle_seconds = os_command::run_optional("ioreg", &["-c", "IOHIDSystem", "-d", "4"])
+        .and_then(|output| {
+            output
+                .lines()
+                .find(|line| line.contains("HIDIdleTime"))
+                .and_then(|line| line.rsplit('=').next())
+                .and_then(|value| value.trim().parse::<u64>().ok())
+                .map(|ns| ns / 1_000_000_000)
+        });
+
+    let focus_assist = dirs::home_dir().and_then(|home| {
+        let assertions = home.join("Library/DoNotDisturb/DB/Assertions.json");
+        let content = std::fs::read_to_string(assertions).ok()?;
+        let value: serde_json::Value = serde_json::from_str(&content).ok()?;
+        let records = value["data"][0]["storeAssertionRecords"].as_array();
+        Some(records.is_some_and(|r| !r.is_empty()))
+    });
+
+    PresenceInfo {
+        idle_seconds,
+        fullscreen: None,
+        focus_assist,
+    }
+}
+
+// Linux: xprintidle(밀리초), GNOME 알림 배너 설정으로 방해 금지 판단
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn query_presence() -> PresenceInfo {
+    let idle_seconds = os_command::run_optional("xprintidle", &[])
+        .and_then(|ms| ms.parse::<u64>().ok())
+        .map(|ms| ms / 1000);
+
+    let focus_assist = os_command::run_optional(
+        "gsettings",
+        &["get", "org.gnome.desktop.notifications", "show-banners"],
+    )
+    .map(|value| value == "false");
+
+    PresenceInfo {
+        idle_seconds,
+        fullscreen: None,
+        focus_assist,
+    }
+}
+
+#[tauri::command]
+pub async fn presence_node(active_threshold_secs: Option<u64>) -> Result<String, String> {
+    // 이 시간(초) 안에 입력이 있었으면 "사용 중"으로 판단
+    let threshold = active_threshold_secs.unwrap_or(60);
+
+    let info = tokio::task::spawn_blocking(query_presence)
+        .await
+        .map_err(|e| format!("상태 확인 실패: {}", e))?;
+
+    let active = info.idle_seconds.map(|idle| idle < threshold);
+    let fullscreen = info.fullscreen.unwrap_or(false);
+    let focus_assist = info.focus_assist.unwrap_or(false);
+
+    // 전체화면/방해 금지 중이면 방해하지 않음, 그 외에는 자리에 있을 때만 알림
+    let available = !fullscreen && !focus_assist && active.unwrap_or(true);
+
+    println!(
+        "👤 Presence: idle={:?}s fullscreen={:?} focus_assist={:?} → available={}",
+        info.idle_seconds, info.fullscreen, info.focus_assist, available
+    );
+
+    Ok(json!({
+        "active": active,
+        "idle_seconds": info.idle_seconds,
+        "fullscreen": info.fullscreen,
+        "focus_assist": info.focus_assist,
+        "available": available
+    })
+    .to_string())
+}
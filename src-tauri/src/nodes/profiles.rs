@@ -0,0 +1,180 @@
+// src-tauri/src/nodes/profiles.rs
+use super::atomic_store;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::path::{Path, PathBuf};
+
+// 👤 프로필 (업무용 / 개인용 자동화 분리)
+// 프로필마다 설정, 비밀값(키체인 서비스 이름), 워크플로우 폴더, 실행 기록을 따로 보관
+// - default 프로필: 기존 store 폴더 그대로 (업그레이드해도 데이터 위치가 바뀌지 않음)
+// - 그 외: store/profiles/<이름>/
+// 현재 프로필은 store/active_profile.json 에 기록, 프로세스가 시작될 때 한 번만 읽음
+// → 이미 열린 DB / 서버 / 감시자가 섞이지 않도록 switch_profile 은 앱을 재시작해서 적용
+
+pub const DEFAULT_PROFILE: &str = "default";
+const MAX_PROFILE_NAME: usize = 40;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct ActiveProfile {
+    name: String,
+}
+
+static ACTIVE_PROFILE: std::sync::OnceLock<String> = std::sync::OnceLock::new();
+
+pub fn validate_profile_name(name: &str) -> Result<&str, String> {
+    let name = name.trim();
+    if name.is_empty()
+        || name.chars().count() > MAX_PROFILE_NAME
+        || name.starts_with('.')
+        || !name
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.'))
+    {
+        return Err(format!("INVALID_PROFILE_NAME: {}", name));
+    }
+    Ok(name)
+}
+
+// 프로필별 데이터 폴더
+pub fn profile_dir(base: &Path, name: &str) -> PathBuf {
+    if name == DEFAULT_PROFILE {
+        base.to_path_buf()
+    } else {
+        base.join("profiles").join(name)
+    }
+}
+
+fn active_profile_path() -> PathBuf {
+    atomic_store::base_store_dir().join("active_profile.json")
+}
+
+pub fn active_profile() -> &'static str {
+    ACTIVE_PROFILE.get_or_init(|| {
+        atomic_store::read_json_verified::<ActiveProfile>(&active_profile_path())
+            .map(|p| p.name)
+            .filter(|name| validate_profile_name(name).is_ok())
+            .filter(|name| {
+                name == DEFAULT_PROFILE
+                    || profile_dir(&atomic_store::base_store_dir(), name).is_dir()
+            })
+            .unwrap_or_else(|| DEFAULT_PROFILE.to_string())
+    })
+}
+
+// 키체인 서비스 이름 (default 는 기존 이름 유지)
+pub fn keyring_service(base: &str) -> String {
+    match active_profile() {
+        DEFAULT_PROFILE => base.to_string(),
+        name => format!("{}.{}", base, name),
+    }
+}
+
+fn profile_names() -> Vec<String> {
+    let mut names = vec![DEFAULT_PROFILE.to_string()];
+    if let Ok(entries) = std::fs::read_dir(atomic_store::base_store_dir().join("profiles")) {
+        let mut others: Vec<String> = entries
+            .filter_map(|e| e.ok())
+            .filter(|e| e.path().is_dir())
+            .map(|e| e.file_name().to_string_lossy().to_string())
+            .filter(|name| validate_profile_name(name).is_ok() && name != DEFAULT_PROFILE)
+            .collect();
+        others.sort();
+        names.extend(others);
+    }
+    names
+}
+
+#[tauri::command]
+pub fn list_profiles() -> Result<String, String> {
+    let active = active_profile();
+    let base = atomic_store::base_store_dir();
+    let profiles: Vec<_> = profile_names()
+        .into_iter()
+        .map(|name| {
+            json!({
+                "name": name,
+                "active": name == active,
+                "path": profile_dir(&base, &name).to_string_lossy()
+            })
+        })
+        .collect();
+    Ok(json!({ "active": active, "profiles": profiles }).to_string())
+}
+
+#[tauri::command]
+pub fn create_profile(name: String) -> Result<String, String> {
+    let name = validate_profile_name(&name)?;
+    let dir = profile_dir(&atomic_store::base_store_dir(), name);
+    if name == DEFAULT_PROFILE || dir.is_dir() {
+        return Err(format!("PROFILE_EXISTS: {}", name));
+    }
+    std::fs::create_dir_all(dir.join("workflows"))
+        .map_err(|_| "DIRECTORY_CREATE_ERROR".to_string())?;
+    println!("👤 프로필 생성: {}", name);
+    Ok(dir.to_string_lossy().to_string())
+}
+
+// 다음 실행부터 적용 (restart 가 true 면 바로 재시작)
+#[tauri::command]
+pub fn switch_profile(
+    app_handle: tauri::AppHandle,
+    name: String,
+    restart: Option<bool>,
+) -> Result<String, String> {
+    let name = validate_profile_name(&name)?;
+    if !profile_names().iter().any(|p| p == name) {
+        return Err(format!("PROFILE_NOT_FOUND: {}", name));
+    }
+    atomic_store::write_json_atomic(
+        &active_profile_path(),
+        &ActiveProfile {
+            name: name.to_string(),
+        },
+    )?;
+    println!("👤 프로필 전환: {} → {}", active_profile(), name);
+    if restart.unwrap_or(true) && name != active_profile() {
+        app_handle.restart();
+    }
+    Ok(json!({ "active": name, "restart_required": name != active_profile() }).to_string())
+}
+
+// 현재 사용 중인 프로필과 default 는 삭제 불가
+#[tauri::command]
+pub fn delete_profile(name: String) -> Result<String, String> {
+    let name = validate_profile_name(&name)?;
+    if name == DEFAULT_PROFILE || name == active_profile() {
+        return Err(format!("PROFILE_IN_USE: {}", name));
+    }
+    let dir = profile_dir(&atomic_store::base_store_dir(), name);
+    if !dir.is_dir() {
+        return Err(format!("PROFILE_NOT_FOUND: {}", name));
+    }
+    std::fs::remove_dir_all(&dir).map_err(|e| format!("프로필 삭제 실패: {}", e))?;
+    println!("🗑️ 프로필 삭제: {} (키체인의 비밀값은 남아 있음)", name);
+    Ok("SUCCESS".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn profiles_validate_names() {
+        assert_eq!(validate_profile_name(" work ").unwrap(), "work");
+        assert!(validate_profile_name("개인-2").is_ok());
+        assert!(validate_profile_name("").is_err());
+        assert!(validate_profile_name("../etc").is_err());
+        assert!(validate_profile_name(".hidden").is_err());
+        assert!(validate_profile_name(&"a".repeat(41)).is_err());
+    }
+
+    #[test]
+    fn profiles_keep_default_in_store_root() {
+        let base = std::path::Path::new("/data/store");
+        assert_eq!(profile_dir(base, "default"), base);
+        assert_eq!(
+            profile_dir(base, "work"),
+            base.join("profiles").join("work")
+        );
+    }
+}
@@ -0,0 +1,381 @@
+// src-tauri/src/nodes/prompt_node.rs
+use super::os_command;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use tauri::AppHandle;
+use tauri_plugin_dialog::DialogExt;
+
+// 📝 사용자 입력 노드
+// 실행 중간에 네이티브 입력 창으로 값을 물어보고 { 이름: 값 } JSON 으로 반환
+// 필드 type: "text" | "number" | "file_path" | "folder_path" | "dropdown"
+// - text / number / dropdown: Windows WinForms, macOS AppleScript, Linux zenity
+// - file_path / folder_path: 파일 선택 창
+// 잘못된 값(숫자 아님, 범위 밖, 필수 값 비어 있음)은 이유를 보여주고 다시 물어봄
+// 취소하면 PROMPT_CANCELLED
+
+const MAX_ATTEMPTS: usize = 3;
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct PromptField {
+    pub name: String,
+    #[serde(default)]
+    pub label: Option<String>,
+    #[serde(rename = "type", default = "default_field_type")]
+    pub field_type: String,
+    #[serde(default)]
+    pub options: Vec<String>,
+    #[serde(default)]
+    pub default: Option<Value>,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(default)]
+    pub min: Option<f64>,
+    #[serde(default)]
+    pub max: Option<f64>,
+}
+
+fn default_field_type() -> String {
+    "text".to_string()
+}
+
+impl PromptField {
+    fn label(&self) -> &str {
+        self.label
+            .as_deref()
+            .filter(|l| !l.trim().is_empty())
+            .unwrap_or(&self.name)
+    }
+
+    fn default_text(&self) -> String {
+        self.default
+            .as_ref()
+            .map(super::template::value_to_text)
+            .unwrap_or_default()
+    }
+}
+
+pub fn parse_fields(fields: Value) -> Result<Vec<PromptField>, String> {
+    let fields: Vec<PromptField> =
+        serde_json::from_value(fields).map_err(|e| format!("INVALID_PROMPT_FIELDS: {}", e))?;
+    if fields.is_empty() {
+        return Err("EMPTY_PROMPT_FIELDS".to_string());
+    }
+    let mut seen = std::collections::HashSet::new();
+    for field in &fields {
+        let name = field.name.trim();
+        if name.is_empty() {
+            return Err("EMPTY_FIELD_NAME".to_string());
+        }
+        if !seen.insert(name.to_string()) {
+            return Err(format!("DUPLICATE_FIELD: {}", name));
+        }
+        match field.field_type.as_str() {
+            "text" | "number" | "file_path" | "folder_path" => {}
+            "dropdown" if field.options.is_empty() => {
+                return Err(format!("DROPDOWN_WITHOUT_OPTIONS: {}", name))
+            }
+            "dropdown" => {}
+            other => return Err(format!("UNKNOWN_FIELD_TYPE: {} ({})", name, other)),
+        }
+    }
+    Ok(fields)
+}
+
+// 입력 문자열 → 필드 타입에 맞는 값 (빈 값은 Null)
+pub fn coerce_answer(field: &PromptField, raw: &str) -> Result<Value, String> {
+    let raw = raw.trim();
+    if raw.is_empty() {
+        return if field.required {
+            Err("값을 입력해 주세요".to_string())
+        } else {
+            Ok(Value::Null)
+        };
+    }
+    match field.field_type.as_str() {
+        "number" => {
+            let number: f64 = raw
+                .replace(',', "")
+                .parse()
+                .map_err(|_| "숫자를 입력해 주세요".to_string())?;
+            if field.min.is_some_and(|min| number < min)
+                || field.max.is_some_and(|max| number > max)
+            {
+                return Err(format!(
+                    "{} ~ {} 범위의 숫자를 입력해 주세요",
+                    field.min.map(|m| m.to_string()).unwrap_or_default(),
+                    field.max.map(|m| m.to_string()).unwrap_or_default()
+                ));
+            }
+            // 정수면 정수로
+            if number.fract() == 0.0 && number.abs() < i64::MAX as f64 {
+                Ok(Value::from(number as i64))
+            } else {
+                Ok(Value::from(number))
+            }
+        }
+        "dropdown" => field
+            .options
+            .iter()
+            .find(|option| option.as_str() == raw)
+            .map(|option| Value::String(option.clone()))
+            .ok_or_else(|| format!("선택지 중에서 골라 주세요: {}", field.options.join(", "))),
+        _ => Ok(Value::String(raw.to_string())),
+    }
+}
+
+fn applescript_quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+// Windows: WinForms 입력 창 (한글 깨짐 방지로 결과는 UTF-8 base64)
+pub fn winforms_script(title: &str, label: &str, default: &str, options: &[String]) -> String {
+    let input = if options.is_empty() {
+        format!(
+            "$input = New-Object System.Windows.Forms.TextBox; $input.Text = {}",
+            os_command::ps_quote(default)
+        )
+    } else {
+        let items: Vec<String> = options.iter().map(|o| os_command::ps_quote(o)).collect();
+        format!(
+            "$input = New-Object System.Windows.Forms.ComboBox; $input.DropDownStyle = 'DropDownList'; $input.Items.AddRange(@({})); $input.SelectedItem = {}; if ($input.SelectedIndex -lt 0) {{ $input.SelectedIndex = 0 }}",
+            items.join(", "),
+            os_command::ps_quote(default)
+        )
+    };
+    format!(
+        r#"Add-Type -AssemblyName System.Windows.Forms
+$form = New-Object System.Windows.Forms.Form
+$form.Text = {title}
+$form.Width = 420; $form.Height = 170; $form.StartPosition = 'CenterScreen'; $form.TopMost = $true
+$form.FormBorderStyle = 'FixedDialog'; $form.MaximizeBox = $false; $form.MinimizeBox = $false
+$label = New-Object System.Windows.Forms.Label; $label.Text = {label}; $label.Left = 12; $label.Top = 12; $label.Width = 380; $label.Height = 34
+{input}
+$input.Left = 12; $input.Top = 50; $input.Width = 380
+$ok = New-Object System.Windows.Forms.Button; $ok.Text = '확인'; $ok.Left = 232; $ok.Top = 86; $ok.DialogResult = 'OK'
+$cancel = New-Object System.Windows.Forms.Button; $cancel.Text = '취소'; $cancel.Left = 316; $cancel.Top = 86; $cancel.DialogResult = 'Cancel'
+$form.AcceptButton = $ok; $form.CancelButton = $cancel
+$form.Controls.AddRange(@($label, $input, $ok, $cancel))
+if ($form.ShowDialog() -eq 'OK') {{ 'OK:' + [Convert]::ToBase64String([Text.Encoding]::UTF8.GetBytes([string]$input.Text)) }} else {{ 'CANCEL' }}"#,
+        title = os_command::ps_quote(title),
+        label = os_command::ps_quote(label),
+        input = input,
+    )
+}
+
+// 텍스트/선택 입력 창 (None = 취소)
+fn ask(
+    title: &str,
+    label: &str,
+    default: &str,
+    options: &[String],
+) -> Result<Option<String>, String> {
+    if cfg!(target_os = "windows") {
+        use base64::{engine::general_purpose, Engine as _};
+        let output = os_command::powershell(&winforms_script(title, label, default, options))?;
+        return match output.trim().strip_prefix("OK:") {
+            Some(encoded) => general_purpose::STANDARD
+                .decode(encoded)
+                .map(|bytes| Some(String::from_utf8_lossy(&bytes).to_string()))
+                .map_err(|e| format!("입력값 해석 실패: {}", e)),
+            None => Ok(None),
+        };
+    }
+
+    let result = if cfg!(target_os = "macos") {
+        let script = if options.is_empty() {
+            format!(
+                "text returned of (display dialog {} default answer {} with title {})",
+                applescript_quote(label),
+                applescript_quote(default),
+                applescript_quote(title)
+            )
+        } else {
+            let items: Vec<String> = options.iter().map(|o| applescript_quote(o)).collect();
+            let default = if options.iter().any(|o| o == default) {
+                default
+            } else {
+                &options[0]
+            };
+            format!(
+                "set picked to choose from list {{{}}} with title {} with prompt {} default items {{{}}}\nif picked is false then error number -128\nitem 1 of picked",
+                items.join(", "),
+                applescript_quote(title),
+                applescript_quote(label),
+                applescript_quote(default)
+            )
+        };
+        os_command::run("osascript", &["-e", &script])
+    } else {
+        let mut args: Vec<String> = vec![
+            "--title".into(),
+            title.into(),
+            "--text".into(),
+            label.into(),
+        ];
+        if options.is_empty() {
+            args.extend(["--entry".into(), "--entry-text".into(), default.into()]);
+        } else {
+            args.extend(["--list".into(), "--column".into(), "선택".into()]);
+            args.extend(options.iter().cloned());
+        }
+        let arg_refs: Vec<&str> = args.iter().map(String::as_str).collect();
+        os_command::run("zenity", &arg_refs)
+    };
+
+    match result {
+        Ok(text) => Ok(Some(text)),
+        // 도구 자체를 실행하지 못한 경우만 오류, 나머지(종료 코드 1 등)는 취소
+        Err(e) if e.contains("실행 실패") => Err(format!("PROMPT_UNAVAILABLE: {}", e)),
+        Err(_) => Ok(None),
+    }
+}
+
+fn pick_path(app_handle: &AppHandle, field: &PromptField) -> Option<String> {
+    let dialog = app_handle.dialog().file().set_title(field.label());
+    let picked = if field.field_type == "folder_path" {
+        dialog.blocking_pick_folder()
+    } else {
+        dialog.blocking_pick_file()
+    };
+    picked
+        .as_ref()
+        .and_then(|path| path.as_path())
+        .map(|path| path.to_string_lossy().to_string())
+}
+
+fn ask_field(app_handle: &AppHandle, title: &str, field: &PromptField) -> Result<Value, String> {
+    let mut hint: Option<String> = None;
+    for _ in 0..MAX_ATTEMPTS {
+        let label = match &hint {
+            Some(hint) => format!("{}\n⚠️ {}", field.label(), hint),
+            None => field.label().to_string(),
+        };
+        let answer = match field.field_type.as_str() {
+            "file_path" | "folder_path" => pick_path(app_handle, field),
+            "dropdown" => ask(title, &label, &field.default_text(), &field.options)?,
+            _ => ask(title, &label, &field.default_text(), &[])?,
+        };
+        let Some(answer) = answer else {
+            return Err(format!("PROMPT_CANCELLED: {}", field.name));
+        };
+        match coerce_answer(field, &answer) {
+            Ok(value) => return Ok(value),
+            Err(e) => hint = Some(e),
+        }
+    }
+    Err(format!(
+        "INVALID_PROMPT_INPUT: {} ({})",
+        field.name,
+        hint.unwrap_or_default()
+    ))
+}
+
+#[tauri::command]
+pub async fn prompt_node(
+    app_handle: AppHandle,
+    fields: Value,
+    title: Option<String>,
+) -> Result<String, String> {
+    let fields = parse_fields(fields)?;
+    let title = title
+        .filter(|t| !t.trim().is_empty())
+        .unwrap_or_else(|| "입력 요청".to_string());
+    println!("📝 Prompt Node: {}개 항목 입력 대기", fields.len());
+
+    let answers = tokio::task::spawn_blocking(move || {
+        let mut answers = Map::new();
+        for field in &fields {
+            let value = ask_field(&app_handle, &title, field)?;
+            answers.insert(field.name.trim().to_string(), value);
+        }
+        Ok::<_, String>(answers)
+    })
+    .await
+    .map_err(|e| format!("입력 창 실행 실패: {}", e))??;
+
+    println!("✅ 입력 완료: {}개", answers.len());
+    Ok(Value::Object(answers).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn prompt_fields_are_validated() {
+        let fields = parse_fields(json!([
+            { "name": "title", "label": "제목" },
+            { "name": "count", "type": "number", "min": 1, "max": 10, "required": true },
+            { "name": "mode", "type": "dropdown", "options": ["빠르게", "정확하게"] }
+        ]))
+        .unwrap();
+        assert_eq!(fields[0].field_type, "text");
+
+        assert!(parse_fields(json!([]))
+            .unwrap_err()
+            .starts_with("EMPTY_PROMPT_FIELDS"));
+        assert!(
+            parse_fields(json!([{ "name": "a" }, { "name": "a" }]))
+                .unwrap_err()
+                .starts_with("DUPLICATE_FIELD")
+        );
+        assert!(
+            parse_fields(json!([{ "name": "a", "type": "dropdown" }]))
+                .unwrap_err()
+                .starts_with("DROPDOWN_WITHOUT_OPTIONS")
+        );
+        assert!(
+            parse_fields(json!([{ "name": "a", "type": "color" }]))
+                .unwrap_err()
+                .starts_with("UNKNOWN_FIELD_TYPE")
+        );
+    }
+
+    #[test]
+    fn prompt_answers_are_coerced_to_field_types() {
+        let fields = parse_fields(json!([
+            { "name": "title" },
+            { "name": "count", "type": "number", "min": 1, "max": 10, "required": true },
+            { "name": "ratio", "type": "number" },
+            { "name": "mode", "type": "dropdown", "options": ["빠르게", "정확하게"] }
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            coerce_answer(&fields[0], " 안녕 ").unwrap(),
+            json!("안녕")
+        );
+        assert_eq!(
+            coerce_answer(&fields[0], "").unwrap(),
+            json!(null)
+        );
+        assert_eq!(
+            coerce_answer(&fields[1], "7").unwrap(),
+            json!(7)
+        );
+        assert!(coerce_answer(&fields[1], "").is_err());
+        assert!(coerce_answer(&fields[1], "11").is_err());
+        assert!(coerce_answer(&fields[1], "일곱").is_err());
+        assert_eq!(
+            coerce_answer(&fields[2], "1,234.5").unwrap(),
+            json!(1234.5)
+        );
+        assert_eq!(
+            coerce_answer(&fields[3], "정확하게").unwrap(),
+            json!("정확하게")
+        );
+        assert!(coerce_answer(&fields[3], "느리게").is_err());
+    }
+
+    #[test]
+    fn prompt_winforms_script_quotes_user_text() {
+        let script = winforms_script("입력", "It's 이름?", "기본", &[]);
+        assert!(script.contains("$label.Text = 'It''s 이름?'"));
+        assert!(script.contains("$input.Text = '기본'"));
+
+        let options = vec!["a".to_string(), "b'c".to_string()];
+        let script = winforms_script("입력", "선택", "a", &options);
+        assert!(script.contains("AddRange(@('a', 'b''c'))"));
+    }
+}
@@ -0,0 +1,229 @@
+// src-tauri/src/nodes/push_node.rs
+use super::{http_client, template};
+use serde_json::{json, Value};
+
+// 📲 모바일 푸시 알림 노드 (ntfy / Gotify)
+// 채팅 페이지를 열어두지 않아도 워크플로우 완료 등을 폰으로 알림
+// - ntfy: server_url(기본 https://ntfy.sh) + topic, 비공개 토픽이면 token(Bearer)
+// - gotify: server_url + token(앱 토큰)
+// priority: 1~5 또는 min/low/default/high/urgent, click_url 은 알림을 눌렀을 때 열 주소
+// title / message 는 "{{path}}" 템플릿으로 이전 노드 데이터(context)를 삽입
+
+const NTFY_DEFAULT_SERVER: &str = "https://ntfy.sh";
+
+// 1(최저) ~ 5(긴급), 기본 3
+pub fn parse_priority(priority: Option<&str>) -> Result<u8, String> {
+    let priority = priority.map(str::trim).filter(|p| !p.is_empty());
+    match priority.map(str::to_lowercase).as_deref() {
+        None | Some("default") | Some("normal") => Ok(3),
+        Some("min") => Ok(1),
+        Some("low") => Ok(2),
+        Some("high") => Ok(4),
+        Some("urgent") | Some("max") => Ok(5),
+        Some(other) => other
+            .parse::<u8>()
+            .ok()
+            .filter(|p| (1..=5).contains(p))
+            .ok_or_else(|| format!("INVALID_PRIORITY: {}", other)),
+    }
+}
+
+// Gotify 는 0~10 (8 이상이면 폰에서 소리/팝업)
+pub fn gotify_priority(priority: u8) -> u8 {
+    match priority {
+        1 => 1,
+        2 => 3,
+        3 => 5,
+        4 => 8,
+        _ => 10,
+    }
+}
+
+pub fn ntfy_payload(
+    topic: &str,
+    title: &str,
+    message: &str,
+    priority: u8,
+    click_url: Option<&str>,
+    tags: &[String],
+) -> Value {
+    let mut payload = json!({
+        "topic": topic,
+        "message": message,
+        "priority": priority
+    });
+    if !title.is_empty() {
+        payload["title"] = json!(title);
+    }
+    if let Some(url) = click_url {
+        payload["click"] = json!(url);
+    }
+    if !tags.is_empty() {
+        payload["tags"] = json!(tags);
+    }
+    payload
+}
+
+pub fn gotify_payload(title: &str, message: &str, priority: u8, click_url: Option<&str>) -> Value {
+    let mut payload = json!({
+        "message": message,
+        "priority": gotify_priority(priority)
+    });
+    if !title.is_empty() {
+        payload["title"] = json!(title);
+    }
+    if let Some(url) = click_url {
+        payload["extras"] = json!({
+            "client::notification": { "click": { "url": url } }
+        });
+    }
+    payload
+}
+
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn push_node(
+    provider: String,
+    message: String,
+    title: Option<String>,
+    server_url: Option<String>,
+    topic: Option<String>,
+    token: Option<String>,
+    priority: Option<String>,
+    click_url: Option<String>,
+    tags: Option<Vec<String>>,
+    context: Option<Value>,
+) -> Result<String, String> {
+    let provider = provider.trim().to_lowercase();
+    let context = context.unwrap_or(Value::Null);
+    let message = template::render(&message, &context);
+    let title = template::render(title.as_deref().unwrap_or(""), &context);
+    if message.trim().is_empty() {
+        return Err("EMPTY_MESSAGE".to_string());
+    }
+
+    let priority = parse_priority(priority.as_deref())?;
+    let click_url = click_url
+        .as_deref()
+        .map(str::trim)
+        .filter(|u| !u.is_empty());
+    if let Some(url) = click_url {
+        url::Url::parse(url).map_err(|_| format!("INVALID_CLICK_URL: {}", url))?;
+    }
+    let token = token.as_deref().map(str::trim).filter(|t| !t.is_empty());
+    let server_url = server_url
+        .as_deref()
+        .map(|u| u.trim().trim_end_matches('/'))
+        .filter(|u| !u.is_empty());
+
+    println!("📲 Push Node: {} (priority {})", provider, priority);
+
+    let client = http_client::client();
+    let request = match provider.as_str() {
+        "ntfy" => {
+            let topic = topic
+                .as_deref()
+                .map(str::trim)
+                .filter(|t| !t.is_empty())
+                .ok_or("EMPTY_TOPIC")?;
+            let payload = ntfy_payload(
+                topic,
+                &title,
+                &message,
+                priority,
+                click_url,
+                &tags.unwrap_or_default(),
+            );
+            let request = client
+                .post(server_url.unwrap_or(NTFY_DEFAULT_SERVER))
+                .json(&payload);
+            match token {
+                Some(token) => request.bearer_auth(token),
+                None => request,
+            }
+        }
+        "gotify" => {
+            let server_url = server_url.ok_or("EMPTY_SERVER_URL")?;
+            let token = token.ok_or("EMPTY_TOKEN")?;
+            client
+                .post(format!("{}/message", server_url))
+                .header("X-Gotify-Key", token)
+                .json(&gotify_payload(&title, &message, priority, click_url))
+        }
+        _ => return Err(format!("UNSUPPORTED_PROVIDER: {}", provider)),
+    };
+
+    let response = request
+        .timeout(std::time::Duration::from_secs(15))
+        .send()
+        .await
+        .map_err(|e| format!("푸시 전송 실패: {}", e))?;
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+    if !status.is_success() {
+        return Err(format!("PUSH_API_ERROR ({}): {}", status.as_u16(), body));
+    }
+
+    let id = serde_json::from_str::<Value>(&body)
+        .ok()
+        .map(|sent| sent["id"].clone())
+        .unwrap_or(Value::Null);
+    println!("✅ 푸시 알림 전송 완료");
+
+    Ok(json!({
+        "provider": provider,
+        "id": id,
+        "title": title,
+        "message": message,
+        "priority": priority
+    })
+    .to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn push_node_parses_priorities() {
+        assert_eq!(parse_priority(None).unwrap(), 3);
+        assert_eq!(parse_priority(Some("urgent")).unwrap(), 5);
+        assert_eq!(parse_priority(Some(" 2 ")).unwrap(), 2);
+        assert!(parse_priority(Some("9"))
+            .unwrap_err()
+            .starts_with("INVALID_PRIORITY"));
+        assert_eq!(gotify_priority(4), 8);
+    }
+
+    #[test]
+    fn push_node_builds_provider_payloads() {
+        let ntfy = ntfy_payload(
+            "backups",
+            "완료",
+            "NAS 백업 끝",
+            4,
+            Some("https://nas.local"),
+            &["white_check_mark".to_string()],
+        );
+        assert_eq!(
+            ntfy,
+            json!({
+                "topic": "backups",
+                "title": "완료",
+                "message": "NAS 백업 끝",
+                "priority": 4,
+                "click": "https://nas.local",
+                "tags": ["white_check_mark"]
+            })
+        );
+
+        let gotify = gotify_payload("", "hi", 3, Some("https://x.dev"));
+        assert_eq!(gotify["priority"], 5);
+        assert!(gotify.get("title").is_none());
+        assert_eq!(
+            gotify["extras"]["client::notification"]["click"]["url"],
+            "https://x.dev"
+        );
+    }
+}
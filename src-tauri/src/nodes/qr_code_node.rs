@@ -1,7 +1,8 @@
 use base64::{engine::general_purpose, Engine as _};
 use image::{ImageBuffer, Rgb, RgbImage};
-use qrcode::QrCode;
+use qrcode::{Color, QrCode};
 use serde::Serialize;
+use super::worker_pool;
 
 // QR코드 결과 (간단)
 #[derive(Debug, Serialize)]
@@ -10,62 +11,41 @@ pub struct QrCodeResult {
     pub url: String,
 }
 
-// QR코드 생성 (메모리에서만)
+// 배치 생성 결과 (항목별 성공/실패)
+#[derive(Debug, Serialize)]
+pub struct QrCodeBatchItem {
+    pub url: String,
+    pub image_base64: Option<String>,
+    pub error: Option<String>,
+}
+
+// QR코드 1모듈당 픽셀 크기 (8배 확대)
+const QR_SCALE: usize = 8;
+
+// QR코드 생성 (메모리에서만) - QR 매트릭스에서 이미지 버퍼로 직접 렌더링
 fn generate_qr_image(text: &str) -> Result<String, String> {
     // QR코드 생성
     let qr_code =
         QrCode::new(text.as_bytes()).map_err(|e| format!("QR generation failed: {}", e))?;
 
-    // 문자열로 렌더링
-    let qr_string = qr_code
-        .render::<char>()
-        .quiet_zone(false)
-        .module_dimensions(1, 1)
-        .build();
-
-    // 이미지 변환
-    let lines: Vec<&str> = qr_string.lines().collect();
-    let height = lines.len();
-    let width = if height > 0 {
-        lines[0].chars().count()
-    } else {
-        0
-    };
-
-    if width == 0 || height == 0 {
+    let width = qr_code.width();
+    if width == 0 {
         return Err("Invalid QR dimensions".to_string());
     }
 
-    // 8배 확대
-    let scale = 8;
-    let img_width = (width * scale) as u32;
-    let img_height = (height * scale) as u32;
-
-    // 흰색 배경 이미지
-    let mut img: RgbImage = ImageBuffer::new(img_width, img_height);
-    for pixel in img.pixels_mut() {
-        *pixel = Rgb([255, 255, 255]);
-    }
+    // 모듈 색상 배열 (행 우선, width * width)
+    let colors = qr_code.to_colors();
+    let img_size = (width * QR_SCALE) as u32;
 
-    // 검은색 QR 패턴 그리기
-    for (y, line) in lines.iter().enumerate() {
-        for (x, ch) in line.chars().enumerate() {
-            if ch == '█' {
-                let start_x = (x * scale) as u32;
-                let start_y = (y * scale) as u32;
-
-                for dy in 0..scale {
-                    for dx in 0..scale {
-                        let px = start_x + dx as u32;
-                        let py = start_y + dy as u32;
-                        if px < img_width && py < img_height {
-                            img.put_pixel(px, py, Rgb([0, 0, 0]));
-                        }
-                    }
-                }
-            }
+    // 픽셀 좌표 → 모듈 좌표로 바로 매핑 (흰 배경 + 검은 패턴)
+    let img: RgbImage = ImageBuffer::from_fn(img_size, img_size, |x, y| {
+        let module_x = x as usize / QR_SCALE;
+        let module_y = y as usize / QR_SCALE;
+        match colors[module_y * width + module_x] {
+            Color::Dark => Rgb([0, 0, 0]),
+            Color::Light => Rgb([255, 255, 255]),
         }
-    }
+    });
 
     // PNG로 인코딩
     let mut png_data = Vec::new();
@@ -73,7 +53,7 @@ fn generate_qr_image(text: &str) -> Result<String, String> {
         use image::ImageEncoder;
         let encoder = image::codecs::png::PngEncoder::new(&mut png_data);
         encoder
-            .write_image(&img, img_width, img_height, image::ColorType::Rgb8)
+            .write_image(&img, img_size, img_size, image::ColorType::Rgb8)
             .map_err(|e| format!("PNG encoding failed: {}", e))?;
     }
 
@@ -96,3 +76,82 @@ pub async fn qr_code_node(url: String) -> Result<QrCodeResult, String> {
         Err(error) => Err(error),
     }
 }
+
+// 여러 QR코드를 병렬로 생성 (CPU 코어 수만큼 동시 처리)
+#[tauri::command]
+pub async fn qr_code_batch_node(urls: Vec<String>) -> Result<Vec<QrCodeBatchItem>, String> {
+    let urls: Vec<String> = urls
+        .into_iter()
+        .map(|url| url.trim().to_string())
+        .filter(|url| !url.is_empty())
+        .collect();
+
+    if urls.is_empty() {
+        return Err("URL cannot be empty".to_string());
+    }
+
+    println!("🔳 QR 배치 생성 시작: {}개", urls.len());
+
+    // 렌더링은 CPU 작업이므로 공용 워커 풀에서 병렬 실행
+    let items = worker_pool::run_blocking(move || {
+        worker_pool::par_map(urls, |url| match generate_qr_image(&url) {
+            Ok(image_base64) => QrCodeBatchItem {
+                url,
+                image_base64: Some(image_base64),
+                error: None,
+            },
+            Err(error) => QrCodeBatchItem {
+                url,
+                image_base64: None,
+                error: Some(error),
+            },
+        })
+    })
+    .await
+    .map_err(|e| format!("QR batch task failed: {}", e))??;
+
+    let failed = items.iter().filter(|item| item.error.is_some()).count();
+    println!("✅ QR 배치 생성 완료 (실패: {}개)", failed);
+
+    Ok(items)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::engine::general_purpose;
+
+    #[tokio::test]
+    async fn qr_code_node_returns_png() {
+        let result = qr_code_node("https://example.com".to_string())
+            .await
+            .unwrap();
+        let png = general_purpose::STANDARD
+            .decode(&result.image_base64)
+            .unwrap();
+
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(result.url, "https://example.com");
+    }
+
+    #[tokio::test]
+    async fn qr_code_node_rejects_empty_url() {
+        assert!(qr_code_node(" ".to_string()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn qr_code_batch_node_keeps_input_order() {
+        let urls: Vec<String> = (0..20)
+            .map(|i| format!("https://example.com/{}", i))
+            .collect();
+
+        let items = qr_code_batch_node(urls.clone()).await.unwrap();
+
+        assert_eq!(items.len(), urls.len());
+        for (item, url) in items.iter().zip(&urls) {
+            assert_eq!(&item.url, url);
+            assert!(item.image_base64.is_some());
+            assert!(item.error.is_none());
+        }
+    }
+}
@@ -0,0 +1,262 @@
+// src-tauri/src/nodes/rate_limiter.rs
+use super::atomic_store;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use tokio::sync::Mutex;
+
+// 🚦 요청 속도 제한 (토큰 버킷)
+// 외부 API 를 부르기 전에 acquire 로 토큰을 받아 할당량을 넘지 않도록 대기
+// rate: "60/min", "5/s", "1000/day", "10/15min" (버스트 허용량 = 개수)
+// 버킷 상태는 store/rate_limits.json 에 저장해 앱을 다시 켜도 예약 실행이 할당량을 지킴
+
+const DEFAULT_MAX_WAIT_SECS: u64 = 300;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Bucket {
+    pub tokens: f64,
+    pub capacity: f64,
+    pub period_ms: u64,
+    pub updated_at_ms: i64,
+}
+
+impl Bucket {
+    pub fn new(capacity: u32, period_ms: u64, now_ms: i64) -> Self {
+        Self {
+            tokens: capacity as f64,
+            capacity: capacity as f64,
+            period_ms,
+            updated_at_ms: now_ms,
+        }
+    }
+
+    fn refill_per_ms(&self) -> f64 {
+        self.capacity / self.period_ms.max(1) as f64
+    }
+
+    pub fn refill(&mut self, now_ms: i64) {
+        let elapsed = (now_ms - self.updated_at_ms).max(0) as f64;
+        self.tokens = (self.tokens + elapsed * self.refill_per_ms()).min(self.capacity);
+        self.updated_at_ms = now_ms.max(self.updated_at_ms);
+    }
+
+    // 토큰을 가져가거나, 부족하면 기다려야 할 시간(ms)
+    pub fn try_take(&mut self, cost: f64, now_ms: i64) -> Result<(), u64> {
+        self.refill(now_ms);
+        if self.tokens + 1e-9 >= cost {
+            self.tokens -= cost;
+            Ok(())
+        } else {
+            Err(((cost - self.tokens) / self.refill_per_ms()).ceil() as u64)
+        }
+    }
+
+    // 설정이 바뀌면 남은 토큰 비율을 유지하며 새 한도로
+    pub fn reconfigure(&mut self, capacity: u32, period_ms: u64) {
+        let capacity = capacity as f64;
+        if self.capacity != capacity || self.period_ms != period_ms {
+            let ratio = if self.capacity > 0.0 {
+                self.tokens / self.capacity
+            } else {
+                1.0
+            };
+            self.capacity = capacity;
+            self.period_ms = period_ms;
+            self.tokens = (ratio * capacity).min(capacity);
+        }
+    }
+}
+
+// "60/min" → (60, 60_000)
+pub fn parse_rate(rate: &str) -> Result<(u32, u64), String> {
+    let invalid = || format!("INVALID_RATE: {} (예: 60/min, 5/s, 1000/day)", rate);
+    let (count, period) = rate.trim().split_once('/').ok_or_else(invalid)?;
+    let count: u32 = count.trim().parse().map_err(|_| invalid())?;
+    if count == 0 {
+        return Err(invalid());
+    }
+    let period = period.trim().to_lowercase();
+    let split = period
+        .find(|c: char| !c.is_ascii_digit())
+        .ok_or_else(invalid)?;
+    let multiplier: u64 = if split == 0 {
+        1
+    } else {
+        period[..split].parse().map_err(|_| invalid())?
+    };
+    let unit_ms: u64 = match &period[split..] {
+        "ms" => 1,
+        "s" | "sec" | "second" => 1_000,
+        "m" | "min" | "minute" => 60_000,
+        "h" | "hour" => 3_600_000,
+        "d" | "day" => 86_400_000,
+        _ => return Err(invalid()),
+    };
+    Ok((count, (multiplier * unit_ms).max(1)))
+}
+
+static BUCKETS: std::sync::OnceLock<Mutex<HashMap<String, Bucket>>> = std::sync::OnceLock::new();
+
+fn buckets_path() -> PathBuf {
+    atomic_store::store_dir().join("rate_limits.json")
+}
+
+fn get_buckets() -> &'static Mutex<HashMap<String, Bucket>> {
+    BUCKETS.get_or_init(|| {
+        Mutex::new(atomic_store::read_json_verified(&buckets_path()).unwrap_or_default())
+    })
+}
+
+fn validate_bucket(bucket: &str) -> Result<&str, String> {
+    let bucket = bucket.trim();
+    if bucket.is_empty()
+        || !bucket
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | ':'))
+    {
+        return Err(format!("INVALID_BUCKET_NAME: {}", bucket));
+    }
+    Ok(bucket)
+}
+
+// 다른 노드에서 API 호출 전에 사용: 토큰을 받을 때까지 대기, 기다린 시간(ms) 반환
+pub async fn acquire(
+    bucket: &str,
+    rate: &str,
+    cost: u32,
+    max_wait_secs: u64,
+) -> Result<u64, String> {
+    let bucket = validate_bucket(bucket)?.to_string();
+    let (capacity, period_ms) = parse_rate(rate)?;
+    let cost = cost.max(1);
+    if cost > capacity {
+        return Err(format!("COST_EXCEEDS_CAPACITY: {} > {}", cost, capacity));
+    }
+    let started = std::time::Instant::now();
+    let max_wait_ms = max_wait_secs.saturating_mul(1000);
+
+    loop {
+        let wait_ms = {
+            let mut buckets = get_buckets().lock().await;
+            let now_ms = chrono::Utc::now().timestamp_millis();
+            let entry = buckets
+                .entry(bucket.clone())
+                .or_insert_with(|| Bucket::new(capacity, period_ms, now_ms));
+            entry.reconfigure(capacity, period_ms);
+            match entry.try_take(cost as f64, now_ms) {
+                Ok(()) => {
+                    if let Err(e) = atomic_store::write_json_atomic(&buckets_path(), &*buckets) {
+                        println!("⚠️ 속도 제한 상태 저장 실패: {}", e);
+                    }
+                    return Ok(started.elapsed().as_millis() as u64);
+                }
+                Err(wait_ms) => wait_ms,
+            }
+        };
+
+        let waited = started.elapsed().as_millis() as u64;
+        if waited + wait_ms > max_wait_ms {
+            return Err(format!(
+                "RATE_LIMIT_WAIT_EXCEEDED: {} ({}ms 더 기다려야 함)",
+                bucket, wait_ms
+            ));
+        }
+        println!("🚦 속도 제한 대기: {} {}ms", bucket, wait_ms);
+        tokio::time::sleep(std::time::Duration::from_millis(wait_ms.max(1))).await;
+    }
+}
+
+#[tauri::command]
+pub async fn acquire_rate_token(
+    bucket: String,
+    rate: String,
+    cost: Option<u32>,
+    max_wait_secs: Option<u64>,
+) -> Result<String, String> {
+    let waited_ms = acquire(
+        &bucket,
+        &rate,
+        cost.unwrap_or(1),
+        max_wait_secs.unwrap_or(DEFAULT_MAX_WAIT_SECS),
+    )
+    .await?;
+    let remaining = get_buckets()
+        .lock()
+        .await
+        .get(bucket.trim())
+        .map(|b| b.tokens.floor() as u64);
+    Ok(
+        json!({ "bucket": bucket.trim(), "waited_ms": waited_ms, "remaining": remaining })
+            .to_string(),
+    )
+}
+
+#[tauri::command]
+pub async fn get_rate_buckets() -> Result<String, String> {
+    let mut buckets = get_buckets().lock().await;
+    let now_ms = chrono::Utc::now().timestamp_millis();
+    let summary: serde_json::Map<String, serde_json::Value> = buckets
+        .iter_mut()
+        .map(|(name, bucket)| {
+            bucket.refill(now_ms);
+            (
+                name.clone(),
+                json!({
+                    "tokens": bucket.tokens.floor() as u64,
+                    "capacity": bucket.capacity as u64,
+                    "period_ms": bucket.period_ms
+                }),
+            )
+        })
+        .collect();
+    Ok(serde_json::Value::Object(summary).to_string())
+}
+
+#[tauri::command]
+pub async fn reset_rate_bucket(bucket: String) -> Result<String, String> {
+    let mut buckets = get_buckets().lock().await;
+    let removed = buckets.remove(bucket.trim()).is_some();
+    atomic_store::write_json_atomic(&buckets_path(), &*buckets)?;
+    Ok(json!({ "reset": removed }).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rate_limiter_parses_rates() {
+        assert_eq!(parse_rate("60/min").unwrap(), (60, 60_000));
+        assert_eq!(parse_rate("5/s").unwrap(), (5, 1_000));
+        assert_eq!(
+            parse_rate("1000/day").unwrap(),
+            (1000, 86_400_000)
+        );
+        assert_eq!(parse_rate("10/15min").unwrap(), (10, 900_000));
+        assert!(parse_rate("0/min").is_err());
+        assert!(parse_rate("abc").is_err());
+        assert!(parse_rate("5/week").is_err());
+    }
+
+    #[test]
+    fn rate_limiter_bucket_refills_over_time() {
+        let mut bucket = Bucket::new(2, 1_000, 0);
+        assert!(bucket.try_take(1.0, 0).is_ok());
+        assert!(bucket.try_take(1.0, 0).is_ok());
+        assert_eq!(bucket.try_take(1.0, 0), Err(500));
+        assert!(bucket.try_take(1.0, 500).is_ok());
+        // 용량 이상으로 쌓이지 않음
+        bucket.refill(10_000);
+        assert_eq!(bucket.tokens, 2.0);
+    }
+
+    #[test]
+    fn rate_limiter_reconfigure_keeps_ratio() {
+        let mut bucket = Bucket::new(10, 1_000, 0);
+        bucket.try_take(5.0, 0).unwrap();
+        bucket.reconfigure(20, 1_000);
+        assert_eq!(bucket.capacity, 20.0);
+        assert_eq!(bucket.tokens, 10.0);
+    }
+}
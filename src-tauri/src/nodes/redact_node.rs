@@ -0,0 +1,212 @@
+// src-tauri/src/nodes/redact_node.rs
+use regex::{Captures, Regex};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+
+// 🙈 개인정보 가림(redact) 노드
+// 클라우드 AI 나 로그로 보내기 전에 이메일, 전화번호, 주민등록번호, 카드번호, 사용자 정규식을 가림
+// rules: 생략하면 기본 규칙 전부, 문자열("email", "phone", "rrn", "card") 또는
+//        { "name": "사번", "pattern": "EMP-\\d+", "replacement": "EMP-***" } 객체
+// style: label(기본, "[EMAIL]") | partial(일부만 가림, "h***@example.com")
+
+pub const BUILTIN_RULES: [&str; 4] = ["rrn", "card", "email", "phone"];
+
+pub struct RedactRule {
+    pub name: String,
+    pub regex: Regex,
+    pub replacement: Option<String>,
+}
+
+fn builtin_pattern(name: &str) -> Option<&'static str> {
+    Some(match name {
+        // 생년월일 6자리 + 성별 1~8 + 6자리
+        "rrn" => r"\b\d{2}(?:0[1-9]|1[0-2])(?:0[1-9]|[12]\d|3[01])[-\s]?[1-8]\d{6}\b",
+        "card" => r"\b(?:\d{4}[-\s]?){3}\d{4}\b",
+        "email" => r"[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}",
+        // 휴대폰(010…), 지역번호(02, 031…), +82 국제 형식
+        "phone" => r"(?:\+82[-.\s]?|\b0)(?:1[016789]|2|[3-6][1-5])[-.\s]?\d{3,4}[-.\s]?\d{4}\b",
+        _ => return None,
+    })
+}
+
+pub fn parse_rules(rules: Option<&[Value]>) -> Result<Vec<RedactRule>, String> {
+    let defaults: Vec<Value> = BUILTIN_RULES.iter().map(|r| json!(r)).collect();
+    let rules = rules.filter(|r| !r.is_empty()).unwrap_or(&defaults);
+
+    rules
+        .iter()
+        .map(|rule| match rule {
+            Value::String(name) => {
+                let name = name.trim().to_lowercase();
+                let pattern = builtin_pattern(&name)
+                    .ok_or_else(|| format!("UNKNOWN_REDACT_RULE: {}", name))?;
+                Ok(RedactRule {
+                    name,
+                    regex: Regex::new(pattern).map_err(|e| format!("INVALID_REGEX: {}", e))?,
+                    replacement: None,
+                })
+            }
+            Value::Object(map) => {
+                let pattern = map
+                    .get("pattern")
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| format!("INVALID_REDACT_RULE: {}", rule))?;
+                Ok(RedactRule {
+                    name: map
+                        .get("name")
+                        .and_then(Value::as_str)
+                        .unwrap_or("custom")
+                        .to_string(),
+                    regex: Regex::new(pattern).map_err(|e| format!("INVALID_REGEX: {}", e))?,
+                    replacement: map
+                        .get("replacement")
+                        .and_then(Value::as_str)
+                        .map(str::to_string),
+                })
+            }
+            other => Err(format!("INVALID_REDACT_RULE: {}", other)),
+        })
+        .collect()
+}
+
+// 숫자만 * 로 바꾸고 구분자와 뒤쪽 keep_last 개 숫자는 남김
+fn mask_digits(text: &str, keep_first: usize, keep_last: usize) -> String {
+    let total = text.chars().filter(|c| c.is_ascii_digit()).count();
+    let mut seen = 0;
+    text.chars()
+        .map(|c| {
+            if !c.is_ascii_digit() {
+                return c;
+            }
+            seen += 1;
+            if seen <= keep_first || seen > total.saturating_sub(keep_last) {
+                c
+            } else {
+                '*'
+            }
+        })
+        .collect()
+}
+
+pub fn partial_mask(rule: &str, matched: &str) -> String {
+    match rule {
+        "email" => match matched.split_once('@') {
+            Some((local, domain)) => {
+                let first: String = local.chars().take(1).collect();
+                format!("{}***@{}", first, domain)
+            }
+            None => "***".to_string(),
+        },
+        // 생년월일 + 성별 자리까지만
+        "rrn" => mask_digits(matched, 7, 0),
+        "phone" | "card" => mask_digits(matched, 0, 4),
+        _ => "*".repeat(matched.chars().count().min(8)),
+    }
+}
+
+// 규칙 순서대로 적용, 규칙별 가린 개수와 함께 반환
+pub fn redact(
+    text: &str,
+    rules: &[RedactRule],
+    partial: bool,
+) -> (String, BTreeMap<String, usize>) {
+    let mut counts = BTreeMap::new();
+    let mut result = text.to_string();
+    for rule in rules {
+        let mut count = 0;
+        result = rule
+            .regex
+            .replace_all(&result, |caps: &Captures| {
+                count += 1;
+                let mut replaced = String::new();
+                match &rule.replacement {
+                    Some(replacement) => caps.expand(replacement, &mut replaced),
+                    None if partial => replaced = partial_mask(&rule.name, &caps[0]),
+                    None => replaced = format!("[{}]", rule.name.to_uppercase()),
+                }
+                replaced
+            })
+            .into_owned();
+        if count > 0 {
+            *counts.entry(rule.name.clone()).or_insert(0) += count;
+        }
+    }
+    (result, counts)
+}
+
+#[tauri::command]
+pub fn redact_node(
+    text: String,
+    rules: Option<Vec<Value>>,
+    style: Option<String>,
+) -> Result<String, String> {
+    let partial = match style.as_deref().map(str::trim) {
+        None | Some("") | Some("label") => false,
+        Some("partial") => true,
+        Some(other) => return Err(format!("INVALID_REDACT_STYLE: {}", other)),
+    };
+    let rules = parse_rules(rules.as_deref())?;
+    let (redacted, counts) = redact(&text, &rules, partial);
+    let total: usize = counts.values().sum();
+    println!("🙈 Redact Node: {}곳 가림 {:?}", total, counts);
+
+    Ok(json!({
+        "text": redacted,
+        "redacted_count": total,
+        "counts": counts
+    })
+    .to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::parse_output;
+    use serde_json::json;
+
+    #[test]
+    fn redact_node_masks_builtin_pii_with_labels() {
+        let output = parse_output(
+            &redact_node(
+                "연락처 010-1234-5678, 메일 hong.gd@example.co.kr, 주민 900101-1234567, 카드 1234-5678-9012-3456, 주문 20261016".into(),
+                None,
+                None,
+            )
+            .unwrap(),
+        );
+        assert_eq!(
+            output["text"],
+            "연락처 [PHONE], 메일 [EMAIL], 주민 [RRN], 카드 [CARD], 주문 20261016"
+        );
+        assert_eq!(output["redacted_count"], 4);
+        assert_eq!(output["counts"]["phone"], 1);
+    }
+
+    #[test]
+    fn redact_node_partial_style_keeps_hints() {
+        let rules = parse_rules(None).unwrap();
+        let (text, _) = redact(
+            "hong@example.com / 010-1234-5678 / 900101-1234567",
+            &rules,
+            true,
+        );
+        assert_eq!(text, "h***@example.com / ***-****-5678 / 900101-1******");
+    }
+
+    #[test]
+    fn redact_node_applies_custom_rules() {
+        let rules = vec![
+            json!({ "name": "employee", "pattern": "EMP-(\\d{2})\\d+", "replacement": "EMP-${1}**" }),
+            json!({ "pattern": "비밀\\S*" }),
+            json!("email"),
+        ];
+        let rules = parse_rules(Some(&rules)).unwrap();
+        let (text, counts) =
+            redact("EMP-123456 비밀번호 a@b.io 010-1111-2222", &rules, false);
+        assert_eq!(text, "EMP-12** [CUSTOM] [EMAIL] 010-1111-2222");
+        assert_eq!(counts.get("employee"), Some(&1));
+
+        assert!(parse_rules(Some(&[json!("ssn")])).is_err());
+        assert!(parse_rules(Some(&[json!({ "pattern": "(" })])).is_err());
+    }
+}
@@ -0,0 +1,347 @@
+// src-tauri/src/nodes/resource_guard.rs
+use super::atomic_store;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use sysinfo::{Pid, System};
+
+// 🛑 자식 프로세스 리소스 제한
+// 다운로드/ffmpeg/스크립트 등 실행 중 띄운 프로세스(하위 프로세스 포함)의
+// CPU 시간, 메모리, 디스크 쓰기량을 감시하고 한도를 넘으면 정상 종료 요청 → 유예 후 강제 종료
+// 한도는 store/resource_limits.json (0 또는 없음 = 제한 없음)
+
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+const GRACE_PERIOD: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ResourceLimits {
+    pub max_cpu_secs: Option<u64>,
+    pub max_memory_mb: Option<u64>,
+    pub max_disk_write_mb: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ResourceUsage {
+    pub label: String,
+    pub pid: u32,
+    pub cpu_secs: f64,
+    pub memory_mb: u64,
+    pub peak_memory_mb: u64,
+    pub disk_write_mb: u64,
+}
+
+type WatchRegistry = Arc<Mutex<HashMap<u32, ResourceUsage>>>;
+
+static WATCH_REGISTRY: std::sync::OnceLock<WatchRegistry> = std::sync::OnceLock::new();
+
+fn get_watch_registry() -> &'static WatchRegistry {
+    WATCH_REGISTRY.get_or_init(|| Arc::new(Mutex::new(HashMap::new())))
+}
+
+fn limits_path() -> PathBuf {
+    atomic_store::store_dir().join("resource_limits.json")
+}
+
+pub fn load_limits() -> ResourceLimits {
+    atomic_store::read_json_verified(&limits_path()).unwrap_or_default()
+}
+
+// 한도 초과 항목 (없으면 None)
+pub fn check_limits(usage: &ResourceUsage, limits: &ResourceLimits) -> Option<String> {
+    let exceeded = |limit: Option<u64>, value: f64| limit.filter(|l| *l > 0 && value > *l as f64);
+
+    if let Some(limit) = exceeded(limits.max_memory_mb, usage.memory_mb as f64) {
+        return Some(format!("memory {}MB > {}MB", usage.memory_mb, limit));
+    }
+    if let Some(limit) = exceeded(limits.max_cpu_secs, usage.cpu_secs) {
+        return Some(format!("cpu {:.0}s > {}s", usage.cpu_secs, limit));
+    }
+    if let Some(limit) = exceeded(limits.max_disk_write_mb, usage.disk_write_mb as f64) {
+        return Some(format!(
+            "disk write {}MB > {}MB",
+            usage.disk_write_mb, limit
+        ));
+    }
+    None
+}
+
+// 감시 대상 프로세스 + 모든 하위 프로세스
+fn process_tree(system: &System, root: Pid) -> Vec<Pid> {
+    let mut tree = vec![root];
+    let mut seen: HashSet<Pid> = HashSet::from([root]);
+    let mut index = 0;
+    while index < tree.len() {
+        let parent = tree[index];
+        for (pid, process) in system.processes() {
+            if process.parent() == Some(parent) && seen.insert(*pid) {
+                tree.push(*pid);
+            }
+        }
+        index += 1;
+    }
+    tree
+}
+
+fn terminate_tree(system: &System, root: Pid) {
+    let tree = process_tree(system, root);
+
+    // 1) 정상 종료 요청
+    #[cfg(target_os = "windows")]
+    {
+        let _ = super::os_command::run("taskkill", &["/PID", &root.as_u32().to_string(), "/T"]);
+    }
+    #[cfg(not(target_os = "windows"))]
+    {
+        for pid in tree.iter().rev() {
+            if let Some(process) = system.process(*pid) {
+                let _ = process.kill_with(sysinfo::Signal::Term);
+            }
+        }
+    }
+
+    // 2) 유예 시간 후에도 살아 있으면 강제 종료
+    std::thread::sleep(GRACE_PERIOD);
+    let mut system = System::new();
+    system.refresh_processes();
+    for pid in tree.iter().rev() {
+        if let Some(process) = system.process(*pid) {
+            process.kill();
+        }
+    }
+}
+
+// 실행 정리에서 호출: 프로세스 트리 종료 (이미 없으면 false)
+pub fn terminate_pid(pid: u32) -> bool {
+    let mut system = System::new();
+    system.refresh_processes();
+    let pid = Pid::from_u32(pid);
+    if system.process(pid).is_none() {
+        return false;
+    }
+    terminate_tree(&system, pid);
+    true
+}
+
+// 프로세스 감시 핸들: drop 되면 감시 스레드를 깨워 끝날 때까지 기다림
+pub struct ProcessGuard {
+    pid: Option<u32>,
+    stop: Arc<AtomicBool>,
+    violation: Arc<Mutex<Option<String>>>,
+    usage: Arc<Mutex<ResourceUsage>>,
+    watcher: Option<std::thread::JoinHandle<()>>,
+}
+
+impl ProcessGuard {
+    // 한도 초과로 종료됐으면 RESOURCE_LIMIT_EXCEEDED 에러
+    pub fn check(&self) -> Result<(), String> {
+        match self.violation.lock().ok().and_then(|v| v.clone()) {
+            Some(reason) => Err(format!("RESOURCE_LIMIT_EXCEEDED: {}", reason)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for ProcessGuard {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+        // 샘플링 중이던 스레드가 지난 pid 를 다시 등록하지 않도록 종료까지 대기
+        if let Some(watcher) = self.watcher.take() {
+            watcher.thread().unpark();
+            let _ = watcher.join();
+        }
+        if let Some(pid) = self.pid {
+            if let Ok(mut registry) = get_watch_registry().lock() {
+                registry.remove(&pid);
+            }
+        }
+    }
+}
+
+// 프로세스 감시 시작 (pid 를 모르면 아무것도 하지 않는 빈 감시)
+pub fn watch(pid: Option<u32>, label: &str) -> ProcessGuard {
+    let guard = ProcessGuard {
+        pid,
+        stop: Arc::new(AtomicBool::new(false)),
+        violation: Arc::new(Mutex::new(None)),
+        usage: Arc::new(Mutex::new(ResourceUsage {
+            label: label.to_string(),
+            pid: pid.unwrap_or(0),
+            ..Default::default()
+        })),
+        watcher: None,
+    };
+    let Some(pid) = pid else {
+        return guard;
+    };
+
+    let mut guard = guard;
+    let limits = load_limits();
+    let stop = guard.stop.clone();
+    let violation = guard.violation.clone();
+    let usage = guard.usage.clone();
+    let root = Pid::from_u32(pid);
+
+    guard.watcher = Some(std::thread::spawn(move || {
+        let mut system = System::new();
+        let mut cpu_secs = 0.0;
+        // 이미 종료된 하위 프로세스의 디스크 쓰기량도 합산
+        let mut written: HashMap<Pid, u64> = HashMap::new();
+
+        while !stop.load(Ordering::SeqCst) {
+            system.refresh_processes();
+            if system.process(root).is_none() {
+                break;
+            }
+
+            let tree = process_tree(&system, root);
+            let mut memory = 0;
+            let mut cpu_percent = 0.0;
+            for pid in &tree {
+                if let Some(process) = system.process(*pid) {
+                    memory += process.memory();
+                    cpu_percent += process.cpu_usage() as f64;
+                    *written.entry(*pid).or_insert(0) = process.disk_usage().total_written_bytes;
+                }
+            }
+            cpu_secs += cpu_percent / 100.0 * POLL_INTERVAL.as_secs_f64();
+
+            let snapshot = {
+                let mut usage = match usage.lock() {
+                    Ok(usage) => usage,
+                    Err(_) => break,
+                };
+                usage.cpu_secs = cpu_secs;
+                usage.memory_mb = memory / (1024 * 1024);
+                usage.peak_memory_mb = usage.peak_memory_mb.max(usage.memory_mb);
+                usage.disk_write_mb = written.values().sum::<u64>() / (1024 * 1024);
+                usage.clone()
+            };
+            if stop.load(Ordering::SeqCst) {
+                break;
+            }
+            if let Ok(mut registry) = get_watch_registry().lock() {
+                registry.insert(pid, snapshot.clone());
+            }
+
+            if let Some(reason) = check_limits(&snapshot, &limits) {
+                println!(
+                    "🛑 리소스 한도 초과 [{}] pid={}: {}",
+                    snapshot.label, pid, reason
+                );
+                if let Ok(mut slot) = violation.lock() {
+                    *slot = Some(reason);
+                }
+                terminate_tree(&system, root);
+                break;
+            }
+
+            // drop 시 unpark 로 바로 깨어남
+            std::thread::park_timeout(POLL_INTERVAL);
+        }
+
+        if let Ok(mut registry) = get_watch_registry().lock() {
+            registry.remove(&pid);
+        }
+    }));
+
+    guard
+}
+
+#[tauri::command]
+pub fn get_resource_limits() -> Result<ResourceLimits, String> {
+    Ok(load_limits())
+}
+
+#[tauri::command]
+pub fn set_resource_limits(limits: ResourceLimits) -> Result<String, String> {
+    atomic_store::write_json_atomic(&limits_path(), &limits)?;
+    println!("🛑 리소스 한도 설정: {:?}", limits);
+    Ok("SUCCESS".to_string())
+}
+
+// 현재 감시 중인 프로세스들의 사용량
+#[tauri::command]
+pub fn get_resource_usage() -> Result<Vec<ResourceUsage>, String> {
+    let registry = get_watch_registry().lock().map_err(|e| e.to_string())?;
+    Ok(registry.values().cloned().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resource_limits_report_first_exceeded() {
+        let limits = ResourceLimits {
+            max_cpu_secs: Some(60),
+            max_memory_mb: Some(512),
+            max_disk_write_mb: Some(0),
+        };
+        let mut usage = ResourceUsage {
+            cpu_secs: 10.0,
+            memory_mb: 100,
+            disk_write_mb: 99_999,
+            ..Default::default()
+        };
+        // 0 은 제한 없음
+        assert_eq!(check_limits(&usage, &limits), None);
+
+        usage.cpu_secs = 61.0;
+        assert_eq!(
+            check_limits(&usage, &limits).unwrap(),
+            "cpu 61s > 60s"
+        );
+
+        usage.memory_mb = 600;
+        assert_eq!(
+            check_limits(&usage, &limits).unwrap(),
+            "memory 600MB > 512MB"
+        );
+    }
+
+    #[test]
+    fn process_priority_parses_levels() {
+        use crate::nodes::os_command::ProcessPriority;
+        assert_eq!(
+            ProcessPriority::parse(None).unwrap(),
+            ProcessPriority::Normal
+        );
+        assert_eq!(
+            ProcessPriority::parse(Some("Below-Normal")).unwrap(),
+            ProcessPriority::BelowNormal
+        );
+        assert_eq!(
+            ProcessPriority::parse(Some("low")).unwrap(),
+            ProcessPriority::BelowNormal
+        );
+        assert_eq!(
+            ProcessPriority::parse(Some("idle")).unwrap(),
+            ProcessPriority::Idle
+        );
+        assert!(ProcessPriority::parse(Some("realtime")).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn resource_guard_drop_stops_watcher_and_clears_usage() {
+        let mut child = std::process::Command::new("sleep")
+            .arg("5")
+            .spawn()
+            .unwrap();
+        let pid = child.id();
+        let guard = watch(Some(pid), "sleep");
+        std::thread::sleep(Duration::from_millis(300));
+
+        drop(guard);
+        assert!(!get_watch_registry().lock().unwrap().contains_key(&pid));
+        // 샘플 주기가 지나도 다시 등록되지 않음
+        std::thread::sleep(POLL_INTERVAL + Duration::from_millis(200));
+        assert!(!get_watch_registry().lock().unwrap().contains_key(&pid));
+
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+}
@@ -0,0 +1,277 @@
+// src-tauri/src/nodes/run_cleanup.rs
+use super::{chat_web_server_node, resource_guard, run_history};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+// 🧹 실행별 정리 작업 등록부
+// 노드가 임시 파일 / 띄운 서버(터널 포함) / 자식 프로세스를 만들면 run_id 와 함께 정리 작업을 등록
+// finish_run 이 성공 / 실패 / 취소 모두에서 등록 역순으로 실행 → 중단된 실행이 cloudflared 나 임시 파일을 남기지 않음
+// 노드가 스스로 정리했으면 unregister_cleanup 으로 빼 둠 (이미 없는 대상은 건너뜀으로 보고)
+// 결과는 "run_cleanup" 실행 이벤트로 방송
+
+const MAX_CLEANUPS_PER_RUN: usize = 200;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum CleanupAction {
+    RemovePath { path: String },
+    StopChatServer { node_id: String },
+    KillProcess { pid: u32 },
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct CleanupEntry {
+    pub cleanup_id: String,
+    pub node_id: Option<String>,
+    pub action: CleanupAction,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CleanupReport {
+    pub cleanup_id: String,
+    pub action: CleanupAction,
+    pub status: String, // "done" | "skipped" | "failed"
+    pub message: Option<String>,
+}
+
+static CLEANUPS: std::sync::OnceLock<Mutex<HashMap<String, Vec<CleanupEntry>>>> =
+    std::sync::OnceLock::new();
+
+fn get_cleanups() -> &'static Mutex<HashMap<String, Vec<CleanupEntry>>> {
+    CLEANUPS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn new_cleanup_id() -> String {
+    static COUNTER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+    format!(
+        "cleanup-{}",
+        COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+    )
+}
+
+// 루트 / 홈 폴더처럼 통째로 지우면 안 되는 경로는 등록 단계에서 거부
+pub fn validate_action(action: &CleanupAction) -> Result<(), String> {
+    match action {
+        CleanupAction::RemovePath { path } => {
+            let path = Path::new(path.trim());
+            let home = std::env::var_os("HOME")
+                .or_else(|| std::env::var_os("USERPROFILE"))
+                .map(PathBuf::from);
+            if !path.is_absolute()
+                || path.parent().is_none()
+                || home.is_some_and(|home| home == path)
+            {
+                return Err(format!("CLEANUP_PATH_REJECTED: {}", path.display()));
+            }
+        }
+        CleanupAction::StopChatServer { node_id } if node_id.trim().is_empty() => {
+            return Err("INVALID_NODE_ID".to_string());
+        }
+        CleanupAction::KillProcess { pid } if *pid == 0 || *pid == std::process::id() => {
+            return Err(format!("CLEANUP_PID_REJECTED: {}", pid));
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+// 노드에서 직접 호출 (run_id 가 없으면 등록하지 않음)
+pub fn register(
+    run_id: Option<&str>,
+    node_id: Option<&str>,
+    action: CleanupAction,
+) -> Result<Option<String>, String> {
+    let Some(run_id) = run_id.map(str::trim).filter(|r| !r.is_empty()) else {
+        return Ok(None);
+    };
+    run_history::validate_run_id(run_id)?;
+    validate_action(&action)?;
+    let mut cleanups = get_cleanups().lock().map_err(|e| e.to_string())?;
+    let entries = cleanups.entry(run_id.to_string()).or_default();
+    if entries.len() >= MAX_CLEANUPS_PER_RUN {
+        return Err(format!("TOO_MANY_CLEANUPS: 최대 {}개", MAX_CLEANUPS_PER_RUN));
+    }
+    let cleanup_id = new_cleanup_id();
+    entries.push(CleanupEntry {
+        cleanup_id: cleanup_id.clone(),
+        node_id: node_id.map(str::to_string),
+        action,
+    });
+    Ok(Some(cleanup_id))
+}
+
+// 노드가 스스로 정리한 대상 빼기 (서버 중지 등)
+pub fn unregister_where(predicate: impl Fn(&CleanupEntry) -> bool) {
+    if let Ok(mut cleanups) = get_cleanups().lock() {
+        for entries in cleanups.values_mut() {
+            entries.retain(|entry| !predicate(entry));
+        }
+        cleanups.retain(|_, entries| !entries.is_empty());
+    }
+}
+
+pub fn pending(run_id: &str) -> Vec<CleanupEntry> {
+    get_cleanups()
+        .lock()
+        .ok()
+        .and_then(|cleanups| cleanups.get(run_id).cloned())
+        .unwrap_or_default()
+}
+
+// 등록부에서 꺼낸 뒤 역순 실행 (같은 실행에 두 번 불려도 한 번만 정리)
+pub fn take(run_id: &str) -> Vec<CleanupEntry> {
+    let mut entries = get_cleanups()
+        .lock()
+        .ok()
+        .and_then(|mut cleanups| cleanups.remove(run_id))
+        .unwrap_or_default();
+    entries.reverse();
+    entries
+}
+
+fn remove_path(path: &Path) -> Result<bool, String> {
+    let result = match std::fs::symlink_metadata(path) {
+        Err(_) => return Ok(false),
+        Ok(meta) if meta.is_dir() => std::fs::remove_dir_all(path),
+        Ok(_) => std::fs::remove_file(path),
+    };
+    result
+        .map(|_| true)
+        .map_err(|e| format!("삭제 실패: {}", e))
+}
+
+async fn execute(action: &CleanupAction) -> Result<bool, String> {
+    match action {
+        CleanupAction::RemovePath { path } => remove_path(Path::new(path.trim())),
+        CleanupAction::StopChatServer { node_id } => {
+            if !chat_web_server_node::get_chat_server_status(node_id.clone()).await? {
+                return Ok(false);
+            }
+            chat_web_server_node::stop_chat_server_node(node_id.clone())
+                .await
+                .map(|_| true)
+        }
+        CleanupAction::KillProcess { pid } => {
+            let pid = *pid;
+            // 정상 종료 요청 후 유예 시간을 기다리므로 블로킹 스레드에서
+            tokio::task::spawn_blocking(move || resource_guard::terminate_pid(pid))
+                .await
+                .map_err(|e| e.to_string())
+        }
+    }
+}
+
+pub async fn run_cleanups(run_id: String) -> Vec<CleanupReport> {
+    let entries = take(&run_id);
+    if entries.is_empty() {
+        return Vec::new();
+    }
+    println!("🧹 실행 정리 시작: {} ({}개)", run_id, entries.len());
+    let mut reports = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let (status, message) = match execute(&entry.action).await {
+            Ok(true) => ("done", None),
+            Ok(false) => ("skipped", None),
+            Err(e) => {
+                println!("⚠️ 정리 실패 ({}): {}", entry.cleanup_id, e);
+                ("failed", Some(e))
+            }
+        };
+        reports.push(CleanupReport {
+            cleanup_id: entry.cleanup_id,
+            action: entry.action,
+            status: status.to_string(),
+            message,
+        });
+    }
+    let _ = run_history::publish_run_event(json!({
+        "event": "run_cleanup",
+        "run_id": run_id,
+        "results": reports
+    }));
+    reports
+}
+
+// finish_run 에서 호출 (등록된 작업이 있을 때만 백그라운드로 실행)
+pub fn schedule(run_id: &str) {
+    if pending(run_id).is_empty() {
+        return;
+    }
+    tauri::async_runtime::spawn(run_cleanups(run_id.to_string()));
+}
+
+// 프론트엔드 / 스크립트 노드가 직접 만든 자원 등록
+#[tauri::command]
+pub fn register_cleanup(
+    run_id: String,
+    action: CleanupAction,
+    node_id: Option<String>,
+) -> Result<String, String> {
+    register(Some(&run_id), node_id.as_deref(), action)?.ok_or_else(|| "INVALID_RUN_ID".to_string())
+}
+
+#[tauri::command]
+pub fn unregister_cleanup(cleanup_id: String) -> Result<String, String> {
+    unregister_where(|entry| entry.cleanup_id == cleanup_id);
+    Ok("SUCCESS".to_string())
+}
+
+#[tauri::command]
+pub fn list_cleanups(run_id: String) -> Result<Vec<CleanupEntry>, String> {
+    Ok(pending(&run_id))
+}
+
+// 실행을 끝내지 않고 지금 바로 정리 (엔진이 멈췄을 때 수동 정리)
+#[tauri::command]
+pub async fn run_cleanup_now(run_id: String) -> Result<Vec<CleanupReport>, String> {
+    run_history::validate_run_id(&run_id)?;
+    Ok(run_cleanups(run_id).await)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::{path_str, temp_dir};
+    use std::fs;
+
+    #[test]
+    fn run_cleanup_rejects_dangerous_targets() {
+        use {validate_action, CleanupAction};
+        assert!(validate_action(&CleanupAction::RemovePath { path: "relative/tmp".into() }).is_err());
+        assert!(validate_action(&CleanupAction::RemovePath { path: "/".into() }).is_err());
+        assert!(validate_action(&CleanupAction::KillProcess { pid: std::process::id() }).is_err());
+        assert!(validate_action(&CleanupAction::StopChatServer { node_id: " ".into() }).is_err());
+        assert!(validate_action(&CleanupAction::StopChatServer { node_id: "chat-1".into() }).is_ok());
+    }
+
+    #[tokio::test]
+    async fn run_cleanup_removes_registered_paths_in_reverse_order() {
+        use CleanupAction;
+        let dir = temp_dir();
+        let work = dir.path().join("work");
+        fs::create_dir_all(&work).unwrap();
+        let file = work.join("partial.bin");
+        fs::write(&file, "x").unwrap();
+
+        let run_id = "cleanup-test-1";
+        let remove = |path: &std::path::Path| CleanupAction::RemovePath {
+            path: path_str(path),
+        };
+        assert_eq!(register(None, None, remove(&file)), Ok(None));
+        register(Some(run_id), Some("dl"), remove(&work)).unwrap();
+        register(Some(run_id), Some("dl"), remove(&file)).unwrap();
+        assert_eq!(pending(run_id).len(), 2);
+
+        let reports = run_cleanups(run_id.to_string()).await;
+        let statuses: Vec<&str> = reports.iter().map(|r| r.status.as_str()).collect();
+        assert_eq!(statuses, ["done", "done"]);
+        assert_eq!(reports[0].action, remove(&file));
+        assert!(!work.exists());
+
+        // 이미 정리된 실행은 다시 실행해도 아무것도 하지 않음
+        assert!(run_cleanups(run_id.to_string()).await.is_empty());
+    }
+}
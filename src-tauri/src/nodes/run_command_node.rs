@@ -2,6 +2,7 @@ use serde::Serialize;
 use tauri::command;
 use std::process::{Command, Stdio};
 use std::path::PathBuf;
+use super::{os_command, resource_guard};
 
 #[derive(Debug, Serialize)]
 pub struct RunCommandResult {
@@ -14,8 +15,16 @@ pub struct RunCommandResult {
 pub async fn run_command_node(
     command: String,
     args: Option<Vec<String>>,
-    cwd: Option<String>
+    cwd: Option<String>,
+    elevated: Option<bool>,
+    priority: Option<String>
 ) -> Result<RunCommandResult, String> {
+    // 관리자 권한 실행 (UAC / sudo 암호 창으로 사용자 확인)
+    if elevated.unwrap_or(false) {
+        let output = os_command::run_elevated(&command, args.as_deref().unwrap_or(&[]), cwd.as_deref())?;
+        return Ok(RunCommandResult { status: output.status, stdout: output.stdout, stderr: output.stderr });
+    }
+
     let mut cmd = Command::new(&command);
     if let Some(args) = &args {
         cmd.args(args);
@@ -26,7 +35,14 @@ pub async fn run_command_node(
     cmd.stdout(Stdio::piped());
     cmd.stderr(Stdio::piped());
 
-    match cmd.output() {
+    let priority = os_command::ProcessPriority::parse(priority.as_deref())?;
+    let child = cmd.spawn().map_err(|e| format!("Failed to execute command: {}", e))?;
+    priority.apply(Some(child.id()));
+    let guard = resource_guard::watch(Some(child.id()), &command);
+    let result = child.wait_with_output();
+    guard.check()?;
+
+    match result {
         Ok(output) => {
             let stdout = String::from_utf8_lossy(&output.stdout).to_string();
             let stderr = String::from_utf8_lossy(&output.stderr).to_string();
@@ -0,0 +1,297 @@
+// src-tauri/src/nodes/run_debugger.rs
+use super::{atomic_store, event_chunks, run_history};
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tauri::AppHandle;
+use tokio::sync::oneshot;
+
+// 🐞 브레이크포인트 / 단계 실행
+// 브레이크포인트는 워크플로우 이름별 노드 ID 목록 (store/breakpoints.json)
+// 프론트엔드 실행 엔진은 노드를 실행하기 직전에 debug_before_node 를 await:
+// - 브레이크포인트가 걸린 노드이거나 단계 실행 중이면 "run-paused" 이벤트를 보내고 멈춤 (실행 이벤트로도 방송)
+// - step_run     이 노드를 실행하고 다음 노드에서 다시 멈춤
+// - continue_run 다음 브레이크포인트까지 계속
+// - abort_run    RUN_ABORTED 에러로 끝냄 (엔진은 finish_run(cancelled))
+// - pause_run    실행 중인 워크플로우를 다음 노드에서 멈춤
+// 멈춘 동안 엔진이 넘긴 values(노드 입력 / 이전 노드 출력)는 list_paused_runs 로 확인
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Resume {
+    Step,
+    Continue,
+    Abort,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PausedRun {
+    pub run_id: String,
+    pub workflow: String,
+    pub node_id: String,
+    pub node_type: String,
+    pub reason: String, // "breakpoint" | "step"
+    pub values: Value,
+    pub paused_at: String,
+}
+
+struct PausedEntry {
+    info: PausedRun,
+    sender: oneshot::Sender<Resume>,
+}
+
+#[derive(Default)]
+struct DebugState {
+    stepping: HashSet<String>,
+    paused: HashMap<String, PausedEntry>,
+}
+
+static DEBUG_STATE: std::sync::OnceLock<Mutex<DebugState>> = std::sync::OnceLock::new();
+
+fn get_state() -> &'static Mutex<DebugState> {
+    DEBUG_STATE.get_or_init(|| Mutex::new(DebugState::default()))
+}
+
+fn breakpoints_path() -> PathBuf {
+    atomic_store::store_dir().join("breakpoints.json")
+}
+
+fn load_breakpoints() -> BTreeMap<String, BTreeSet<String>> {
+    atomic_store::read_json_verified(&breakpoints_path()).unwrap_or_default()
+}
+
+// 멈출 이유 (없으면 그대로 실행)
+pub fn pause_reason(
+    stepping: bool,
+    breakpoints: &BTreeSet<String>,
+    node_id: &str,
+) -> Option<&'static str> {
+    if stepping {
+        Some("step")
+    } else if breakpoints.contains(node_id) {
+        Some("breakpoint")
+    } else {
+        None
+    }
+}
+
+pub fn is_stepping(run_id: &str) -> bool {
+    get_state()
+        .lock()
+        .map(|state| state.stepping.contains(run_id))
+        .unwrap_or(false)
+}
+
+// 재개될 때까지 대기 (제한 시간 없음 - 사람이 확인하는 단계)
+pub async fn wait_paused(info: PausedRun) -> Result<&'static str, String> {
+    let run_id = info.run_id.clone();
+    let (tx, rx) = oneshot::channel();
+    {
+        let mut state = get_state().lock().map_err(|e| e.to_string())?;
+        if state.paused.contains_key(&run_id) {
+            return Err(format!("RUN_ALREADY_PAUSED: {}", run_id));
+        }
+        state.paused.insert(run_id.clone(), PausedEntry { info, sender: tx });
+    }
+    let resume = rx.await.unwrap_or(Resume::Abort);
+    let mut state = get_state().lock().map_err(|e| e.to_string())?;
+    match resume {
+        Resume::Step => {
+            state.stepping.insert(run_id);
+            Ok("step")
+        }
+        Resume::Continue => {
+            state.stepping.remove(&run_id);
+            Ok("run")
+        }
+        Resume::Abort => {
+            state.stepping.remove(&run_id);
+            Err("RUN_ABORTED".to_string())
+        }
+    }
+}
+
+pub fn resume(run_id: &str, resume: Resume) -> Result<(), String> {
+    let entry = get_state()
+        .lock()
+        .map_err(|e| e.to_string())?
+        .paused
+        .remove(run_id)
+        .ok_or_else(|| format!("RUN_NOT_PAUSED: {}", run_id))?;
+    let _ = entry.sender.send(resume);
+    Ok(())
+}
+
+// finish_run 에서 호출 (단계 실행 표시 정리)
+pub fn forget_run(run_id: &str) {
+    if let Ok(mut state) = get_state().lock() {
+        state.stepping.remove(run_id);
+    }
+}
+
+pub fn paused_runs() -> Vec<PausedRun> {
+    get_state()
+        .lock()
+        .map(|state| state.paused.values().map(|e| e.info.clone()).collect())
+        .unwrap_or_default()
+}
+
+#[tauri::command]
+pub fn set_breakpoints(workflow: String, node_ids: Vec<String>) -> Result<String, String> {
+    let mut breakpoints = load_breakpoints();
+    let node_ids: BTreeSet<String> = node_ids
+        .into_iter()
+        .map(|id| id.trim().to_string())
+        .filter(|id| !id.is_empty())
+        .collect();
+    if node_ids.is_empty() {
+        breakpoints.remove(&workflow);
+    } else {
+        breakpoints.insert(workflow.clone(), node_ids);
+    }
+    atomic_store::write_json_atomic(&breakpoints_path(), &breakpoints)?;
+    Ok("SUCCESS".to_string())
+}
+
+#[tauri::command]
+pub fn get_breakpoints(workflow: String) -> Result<Vec<String>, String> {
+    Ok(load_breakpoints()
+        .remove(&workflow)
+        .map(|ids| ids.into_iter().collect())
+        .unwrap_or_default())
+}
+
+// 엔진이 노드 실행 직전에 호출: "run" 이면 계속, "step" 이면 다음 노드에서 다시 멈춤, 에러면 실행 중단
+#[tauri::command]
+pub async fn debug_before_node(
+    app_handle: AppHandle,
+    run_id: String,
+    workflow: String,
+    node_id: String,
+    node_type: Option<String>,
+    values: Option<Value>,
+) -> Result<String, String> {
+    let breakpoints = load_breakpoints().remove(&workflow).unwrap_or_default();
+    let Some(reason) = pause_reason(is_stepping(&run_id), &breakpoints, &node_id) else {
+        return Ok("run".to_string());
+    };
+    let info = PausedRun {
+        run_id: run_id.clone(),
+        workflow,
+        node_id: node_id.clone(),
+        node_type: node_type.unwrap_or_default(),
+        reason: reason.to_string(),
+        values: values.unwrap_or(Value::Null),
+        paused_at: chrono::Local::now().to_rfc3339(),
+    };
+    println!("🐞 실행 일시 정지: {} @ {} ({})", run_id, node_id, reason);
+    let _ = event_chunks::emit_chunked(&app_handle, "run-paused", &info);
+    let _ = run_history::publish_run_event(json!({
+        "event": "run_paused",
+        "run_id": run_id,
+        "node_id": node_id,
+        "reason": reason
+    }));
+
+    let result = wait_paused(info).await;
+    let _ = run_history::publish_run_event(json!({
+        "event": "run_resumed",
+        "run_id": run_id,
+        "node_id": node_id,
+        "mode": result.as_deref().unwrap_or("abort")
+    }));
+    result.map(str::to_string)
+}
+
+#[tauri::command]
+pub fn step_run(run_id: String) -> Result<String, String> {
+    resume(&run_id, Resume::Step)?;
+    Ok("SUCCESS".to_string())
+}
+
+#[tauri::command]
+pub fn continue_run(run_id: String) -> Result<String, String> {
+    resume(&run_id, Resume::Continue)?;
+    Ok("SUCCESS".to_string())
+}
+
+#[tauri::command]
+pub fn abort_run(run_id: String) -> Result<String, String> {
+    resume(&run_id, Resume::Abort)?;
+    Ok("SUCCESS".to_string())
+}
+
+// 실행 중인 워크플로우를 다음 노드 직전에 멈춤
+#[tauri::command]
+pub fn pause_run(run_id: String) -> Result<String, String> {
+    run_history::validate_run_id(&run_id)?;
+    get_state()
+        .lock()
+        .map_err(|e| e.to_string())?
+        .stepping
+        .insert(run_id);
+    Ok("SUCCESS".to_string())
+}
+
+#[tauri::command]
+pub fn list_paused_runs() -> Result<Vec<PausedRun>, String> {
+    Ok(paused_runs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn run_debugger_pauses_on_breakpoints_or_while_stepping() {
+        let breakpoints: std::collections::BTreeSet<String> = ["delete-1".to_string()].into();
+        assert_eq!(
+            pause_reason(false, &breakpoints, "delete-1"),
+            Some("breakpoint")
+        );
+        assert_eq!(pause_reason(false, &breakpoints, "read-1"), None);
+        assert_eq!(
+            pause_reason(true, &breakpoints, "read-1"),
+            Some("step")
+        );
+    }
+
+    #[tokio::test]
+    async fn run_debugger_step_continue_and_abort() {
+        let paused = |run_id: &str| PausedRun {
+            run_id: run_id.to_string(),
+            workflow: "cleanup".to_string(),
+            node_id: "delete-1".to_string(),
+            node_type: "run_command_node".to_string(),
+            reason: "breakpoint".to_string(),
+            values: json!({ "path": "/tmp/old" }),
+            paused_at: String::new(),
+        };
+
+        let waiting = tokio::spawn(wait_paused(paused("dbg-step")));
+        while !paused_runs().iter().any(|p| p.run_id == "dbg-step") {
+            tokio::task::yield_now().await;
+        }
+        step_run("dbg-step".to_string()).unwrap();
+        assert_eq!(waiting.await.unwrap(), Ok("step"));
+        assert!(is_stepping("dbg-step"));
+
+        let waiting = tokio::spawn(wait_paused(paused("dbg-step")));
+        while !paused_runs().iter().any(|p| p.run_id == "dbg-step") {
+            tokio::task::yield_now().await;
+        }
+        continue_run("dbg-step".to_string()).unwrap();
+        assert_eq!(waiting.await.unwrap(), Ok("run"));
+        assert!(!is_stepping("dbg-step"));
+
+        let waiting = tokio::spawn(wait_paused(paused("dbg-abort")));
+        while !paused_runs().iter().any(|p| p.run_id == "dbg-abort") {
+            tokio::task::yield_now().await;
+        }
+        abort_run("dbg-abort".to_string()).unwrap();
+        assert_eq!(waiting.await.unwrap(), Err("RUN_ABORTED".to_string()));
+        assert!(continue_run("dbg-abort".to_string()).is_err());
+    }
+}
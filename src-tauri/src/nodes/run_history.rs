@@ -0,0 +1,572 @@
+// src-tauri/src/nodes/run_history.rs
+use super::{atomic_store, diagnostics, run_cleanup, run_debugger, run_trace, run_watch, value_store};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use tokio::sync::broadcast;
+
+// 🧾 워크플로우 실행 기록 + 실행 보고서 내보내기
+// 프론트엔드 실행 엔진이 start_run → record_node_run(노드마다) → finish_run 순서로 호출
+// 각 단계는 실행 이벤트로도 방송되어 관전 모드 웹페이지 등이 실시간으로 상태를 표시
+// 기록은 store/runs/<run_id>.json, 보고서는 HTML / Markdown 으로 내보내 팀원 공유나 티켓 첨부에 사용
+
+const SUMMARY_CHARS: usize = 300;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NodeRun {
+    pub node_id: String,
+    pub node_type: String,
+    pub status: String,  // "success" | "error" | "skipped"
+    pub started_at: i64, // unix ms
+    pub finished_at: i64,
+    #[serde(default)]
+    pub inputs: Value,
+    #[serde(default)]
+    pub output: Option<String>,
+    #[serde(default)]
+    pub error: Option<String>,
+    #[serde(default)]
+    pub artifacts: Vec<String>,
+}
+
+impl NodeRun {
+    pub fn duration_ms(&self) -> i64 {
+        (self.finished_at - self.started_at).max(0)
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RunRecord {
+    pub run_id: String,
+    pub workflow: String,
+    pub status: String, // "running" | "success" | "error" | "cancelled"
+    pub started_at: String,
+    pub finished_at: Option<String>,
+    pub nodes: Vec<NodeRun>,
+    #[serde(default)]
+    pub logs: Vec<RunLogEntry>,
+}
+
+// log_node 가 남기는 작성자 로그 (노드 출력과 별도)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RunLogEntry {
+    pub timestamp: i64, // unix ms
+    pub level: String,  // "debug" | "info" | "warn" | "error"
+    #[serde(default)]
+    pub node_id: Option<String>,
+    pub message: String,
+    #[serde(default)]
+    pub context: Value,
+}
+
+// 같은 실행 파일을 여러 노드가 동시에 갱신하지 않도록 직렬화
+static RUNS_LOCK: Mutex<()> = Mutex::new(());
+static NEXT_RUN: AtomicU64 = AtomicU64::new(0);
+static RUN_EVENTS: std::sync::OnceLock<broadcast::Sender<String>> = std::sync::OnceLock::new();
+
+fn run_events() -> &'static broadcast::Sender<String> {
+    RUN_EVENTS.get_or_init(|| broadcast::channel(256).0)
+}
+
+pub fn subscribe_events() -> broadcast::Receiver<String> {
+    run_events().subscribe()
+}
+
+// 구독자가 없으면 보내기 실패는 무시
+fn publish(event: Value) {
+    let _ = run_events().send(event.to_string());
+}
+
+fn runs_dir() -> PathBuf {
+    atomic_store::store_dir().join("runs")
+}
+
+pub fn validate_run_id(run_id: &str) -> Result<&str, String> {
+    if run_id.is_empty()
+        || !run_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        return Err("INVALID_RUN_ID".to_string());
+    }
+    Ok(run_id)
+}
+
+fn run_path(run_id: &str) -> Result<PathBuf, String> {
+    Ok(runs_dir().join(format!("{}.json", validate_run_id(run_id)?)))
+}
+
+pub fn load_run(run_id: &str) -> Result<RunRecord, String> {
+    atomic_store::read_json_verified(&run_path(run_id)?).ok_or_else(|| "RUN_NOT_FOUND".to_string())
+}
+
+// 최근 실행 요약 (run_id 가 시작 시각으로 시작하므로 파일 이름 역순 = 최신순)
+pub fn recent_runs(limit: usize) -> Vec<Value> {
+    let Ok(entries) = fs::read_dir(runs_dir()) else {
+        return Vec::new();
+    };
+    let mut ids: Vec<String> = entries
+        .filter_map(|e| e.ok())
+        .filter_map(|e| {
+            e.file_name()
+                .to_string_lossy()
+                .strip_suffix(".json")
+                .map(str::to_string)
+        })
+        .collect();
+    ids.sort_unstable_by(|a, b| b.cmp(a));
+
+    ids.iter()
+        .filter_map(|id| load_run(id).ok())
+        .take(limit)
+        .map(|record| {
+            json!({
+                "run_id": record.run_id,
+                "workflow": record.workflow,
+                "status": record.status,
+                "started_at": record.started_at,
+                "finished_at": record.finished_at,
+                "nodes": record.nodes.len(),
+                "failed_nodes": record.nodes.iter().filter(|n| n.status == "error").count(),
+                "duration_ms": total_duration_ms(&record)
+            })
+        })
+        .collect()
+}
+
+fn update_run(run_id: &str, update: impl FnOnce(&mut RunRecord)) -> Result<(), String> {
+    let _guard = RUNS_LOCK.lock().map_err(|e| e.to_string())?;
+    let mut record = load_run(run_id)?;
+    update(&mut record);
+    atomic_store::write_json_atomic(&run_path(run_id)?, &record)
+}
+
+fn summarize(text: &str) -> String {
+    let (preview, truncated) = value_store::preview(text.trim(), SUMMARY_CHARS);
+    if truncated {
+        format!("{}…", preview)
+    } else {
+        preview
+    }
+}
+
+// 공유용 보고서에는 토큰/비밀번호 같은 입력 값을 가림
+fn redacted_inputs(inputs: &Value) -> String {
+    let mut inputs = inputs.clone();
+    diagnostics::redact_json(&mut inputs);
+    match inputs {
+        Value::Null => String::new(),
+        other => summarize(&other.to_string()),
+    }
+}
+
+fn format_duration(ms: i64) -> String {
+    if ms < 1000 {
+        format!("{} ms", ms)
+    } else {
+        format!("{:.2} s", ms as f64 / 1000.0)
+    }
+}
+
+fn total_duration_ms(record: &RunRecord) -> i64 {
+    let start = record.nodes.iter().map(|n| n.started_at).min();
+    let end = record.nodes.iter().map(|n| n.finished_at).max();
+    match (start, end) {
+        (Some(start), Some(end)) => (end - start).max(0),
+        _ => 0,
+    }
+}
+
+fn status_icon(status: &str) -> &'static str {
+    match status {
+        "success" => "✅",
+        "error" => "❌",
+        "skipped" => "⏭️",
+        _ => "⏳",
+    }
+}
+
+fn escape_markdown_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace(['\r', '\n'], " ")
+}
+
+pub fn render_markdown(record: &RunRecord) -> String {
+    let mut out = format!(
+        "# 실행 보고서: {}\n\n- 실행 ID: `{}`\n- 상태: {} {}\n- 시작: {}\n- 종료: {}\n- 총 소요 시간: {}\n\n",
+        record.workflow,
+        record.run_id,
+        status_icon(&record.status),
+        record.status,
+        record.started_at,
+        record.finished_at.as_deref().unwrap_or("-"),
+        format_duration(total_duration_ms(record))
+    );
+
+    out.push_str(
+        "## 노드 타임라인\n\n| # | 노드 | 종류 | 상태 | 소요 시간 |\n|---|---|---|---|---|\n",
+    );
+    for (i, node) in record.nodes.iter().enumerate() {
+        out.push_str(&format!(
+            "| {} | {} | {} | {} {} | {} |\n",
+            i + 1,
+            escape_markdown_cell(&node.node_id),
+            escape_markdown_cell(&node.node_type),
+            status_icon(&node.status),
+            node.status,
+            format_duration(node.duration_ms())
+        ));
+    }
+
+    out.push_str("\n## 노드 상세\n");
+    for node in &record.nodes {
+        out.push_str(&format!("\n### {} ({})\n\n", node.node_id, node.node_type));
+        let inputs = redacted_inputs(&node.inputs);
+        if !inputs.is_empty() {
+            out.push_str(&format!("**입력**\n\n```\n{}\n```\n\n", inputs));
+        }
+        if let Some(output) = &node.output {
+            out.push_str(&format!("**출력**\n\n```\n{}\n```\n\n", summarize(output)));
+        }
+        if let Some(error) = &node.error {
+            out.push_str(&format!("**에러**\n\n```\n{}\n```\n\n", summarize(error)));
+        }
+        if !node.artifacts.is_empty() {
+            out.push_str("**산출물**\n\n");
+            for artifact in &node.artifacts {
+                out.push_str(&format!("- `{}`\n", artifact));
+            }
+            out.push('\n');
+        }
+    }
+
+    if !record.logs.is_empty() {
+        out.push_str("\n## 로그\n\n| 시각 | 레벨 | 노드 | 메시지 |\n|---|---|---|---|\n");
+        for log in &record.logs {
+            out.push_str(&format!(
+                "| {} | {} | {} | {} |\n",
+                format_log_time(log.timestamp),
+                log.level,
+                escape_markdown_cell(log.node_id.as_deref().unwrap_or("-")),
+                escape_markdown_cell(&summarize(&log.message))
+            ));
+        }
+    }
+    out
+}
+
+fn format_log_time(timestamp_ms: i64) -> String {
+    chrono::DateTime::from_timestamp_millis(timestamp_ms)
+        .map(|t| {
+            t.with_timezone(&chrono::Local)
+                .format("%H:%M:%S%.3f")
+                .to_string()
+        })
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+pub fn render_html(record: &RunRecord) -> String {
+    let total = total_duration_ms(record).max(1) as f64;
+    let first_start = record.nodes.iter().map(|n| n.started_at).min().unwrap_or(0);
+
+    let mut rows = String::new();
+    let mut details = String::new();
+    for (i, node) in record.nodes.iter().enumerate() {
+        // 타임라인 막대: 전체 실행 구간 대비 시작 위치 / 길이 (%)
+        let left = (node.started_at - first_start) as f64 / total * 100.0;
+        let width = (node.duration_ms() as f64 / total * 100.0).max(0.5);
+        rows.push_str(&format!(
+            "<tr class=\"{status}\"><td>{n}</td><td>{id}</td><td>{kind}</td><td>{icon} {status}</td><td>{duration}</td>\
+<td class=\"bar\"><span style=\"margin-left:{left:.1}%;width:{width:.1}%\"></span></td></tr>\n",
+            n = i + 1,
+            id = escape_html(&node.node_id),
+            kind = escape_html(&node.node_type),
+            icon = status_icon(&node.status),
+            status = escape_html(&node.status),
+            duration = format_duration(node.duration_ms()),
+        ));
+
+        details.push_str(&format!(
+            "<section><h3>{} <small>{}</small></h3>\n",
+            escape_html(&node.node_id),
+            escape_html(&node.node_type)
+        ));
+        let inputs = redacted_inputs(&node.inputs);
+        let blocks = [
+            ("입력", Some(inputs).filter(|s| !s.is_empty())),
+            ("출력", node.output.as_deref().map(summarize)),
+            ("에러", node.error.as_deref().map(summarize)),
+        ];
+        for (label, content) in blocks {
+            if let Some(content) = content {
+                details.push_str(&format!(
+                    "<h4>{}</h4><pre>{}</pre>\n",
+                    label,
+                    escape_html(&content)
+                ));
+            }
+        }
+        if !node.artifacts.is_empty() {
+            details.push_str("<h4>산출물</h4><ul>");
+            for artifact in &node.artifacts {
+                details.push_str(&format!("<li><code>{}</code></li>", escape_html(artifact)));
+            }
+            details.push_str("</ul>\n");
+        }
+        details.push_str("</section>\n");
+    }
+
+    let logs = if record.logs.is_empty() {
+        String::new()
+    } else {
+        let mut logs = String::from(
+            "<h2>로그</h2>\n<table>\n<tr><th>시각</th><th>레벨</th><th>노드</th><th>메시지</th></tr>\n",
+        );
+        for log in &record.logs {
+            logs.push_str(&format!(
+                "<tr class=\"{level}\"><td>{time}</td><td>{level}</td><td>{node}</td><td>{message}</td></tr>\n",
+                time = format_log_time(log.timestamp),
+                level = escape_html(&log.level),
+                node = escape_html(log.node_id.as_deref().unwrap_or("-")),
+                message = escape_html(&summarize(&log.message)),
+            ));
+        }
+        logs.push_str("</table>\n");
+        logs
+    };
+
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="ko">
+<head>
+<meta charset="utf-8">
+<title>실행 보고서: {workflow}</title>
+<style>
+body {{ font-family: -apple-system, "Segoe UI", sans-serif; margin: 2rem; color: #222; }}
+table {{ border-collapse: collapse; width: 100%; }}
+th, td {{ border-bottom: 1px solid #ddd; padding: 6px 8px; text-align: left; }}
+tr.error td {{ background: #fdecea; }}
+td.bar {{ width: 40%; }}
+td.bar span {{ display: block; height: 10px; background: #4a90e2; border-radius: 3px; }}
+tr.warn td {{ background: #fff8e1; }}
+tr.error td.bar span {{ background: #d9534f; }}
+pre {{ background: #f6f8fa; padding: 8px; white-space: pre-wrap; word-break: break-all; }}
+</style>
+</head>
+<body>
+<h1>실행 보고서: {workflow}</h1>
+<ul>
+<li>실행 ID: <code>{run_id}</code></li>
+<li>상태: {icon} {status}</li>
+<li>시작: {started}</li>
+<li>종료: {finished}</li>
+<li>총 소요 시간: {duration}</li>
+</ul>
+<h2>노드 타임라인</h2>
+<table>
+<tr><th>#</th><th>노드</th><th>종류</th><th>상태</th><th>소요 시간</th><th>타임라인</th></tr>
+{rows}</table>
+<h2>노드 상세</h2>
+{details}{logs}</body>
+</html>
+"#,
+        workflow = escape_html(&record.workflow),
+        run_id = escape_html(&record.run_id),
+        icon = status_icon(&record.status),
+        status = escape_html(&record.status),
+        started = escape_html(&record.started_at),
+        finished = escape_html(record.finished_at.as_deref().unwrap_or("-")),
+        duration = format_duration(total_duration_ms(record)),
+    )
+}
+
+#[tauri::command]
+pub fn start_run(workflow: String) -> Result<String, String> {
+    let now = chrono::Local::now();
+    let run_id = format!(
+        "{}-{}",
+        now.format("%Y%m%d-%H%M%S-%3f"),
+        NEXT_RUN.fetch_add(1, Ordering::Relaxed)
+    );
+    let record = RunRecord {
+        run_id: run_id.clone(),
+        workflow,
+        status: "running".to_string(),
+        started_at: now.to_rfc3339(),
+        ..Default::default()
+    };
+
+    fs::create_dir_all(runs_dir()).map_err(|_| "DIRECTORY_CREATE_ERROR".to_string())?;
+    atomic_store::write_json_atomic(&run_path(&run_id)?, &record)?;
+    println!("🧾 실행 기록 시작: {} ({})", record.workflow, run_id);
+    publish(json!({
+        "event": "run_started",
+        "run_id": run_id,
+        "workflow": record.workflow,
+        "started_at": record.started_at
+    }));
+    Ok(run_id)
+}
+
+#[tauri::command]
+pub fn record_node_run(run_id: String, node: NodeRun) -> Result<(), String> {
+    let event = json!({
+        "event": "node_finished",
+        "run_id": run_id,
+        "node_id": node.node_id,
+        "node_type": node.node_type,
+        "status": node.status,
+        "duration_ms": node.duration_ms(),
+        "error": node.error
+    });
+    // ⏪ 시간 여행 디버깅이 켜져 있으면 입출력 원본 보관
+    run_trace::capture(&run_id, &node);
+    update_run(&run_id, |record| record.nodes.push(node.clone()))?;
+    publish(event);
+    // 👀 감시식이 등록된 실행이면 새 값 방송
+    run_watch::on_node_recorded(&run_id, &node);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn finish_run(run_id: String, status: String) -> Result<(), String> {
+    let mut workflow = String::new();
+    update_run(&run_id, |record| {
+        record.status = status.clone();
+        record.finished_at = Some(chrono::Local::now().to_rfc3339());
+        workflow = record.workflow.clone();
+    })?;
+    run_debugger::forget_run(&run_id);
+    run_watch::forget_run(&run_id);
+    // 🧹 성공 / 실패 / 취소 모두 등록된 정리 작업 실행
+    run_cleanup::schedule(&run_id);
+    publish(json!({
+        "event": "run_finished",
+        "run_id": run_id,
+        "workflow": workflow,
+        "status": status
+    }));
+    Ok(())
+}
+
+pub fn append_log(run_id: &str, entry: RunLogEntry) -> Result<(), String> {
+    let event = json!({
+        "event": "run_log",
+        "run_id": run_id,
+        "timestamp": entry.timestamp,
+        "level": entry.level,
+        "node_id": entry.node_id,
+        "message": entry.message,
+        "context": entry.context
+    });
+    update_run(run_id, |record| record.logs.push(entry))?;
+    publish(event);
+    Ok(())
+}
+
+// 실행 엔진이 노드 시작 등 기록에 남기지 않는 진행 상황을 방송할 때 사용
+#[tauri::command]
+pub fn publish_run_event(event: Value) -> Result<(), String> {
+    if !event.is_object() || !event["event"].is_string() {
+        return Err("INVALID_RUN_EVENT".to_string());
+    }
+    publish(event);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn list_runs(limit: Option<usize>) -> Result<Vec<Value>, String> {
+    Ok(recent_runs(limit.unwrap_or(20)))
+}
+
+#[tauri::command]
+pub fn get_run(run_id: String) -> Result<RunRecord, String> {
+    load_run(&run_id)
+}
+
+// format: "html" | "markdown" (기본 markdown)
+// output_path 가 있으면 파일로 저장하고 경로 반환, 없으면 보고서 내용 반환
+#[tauri::command]
+pub fn export_run_report(
+    run_id: String,
+    format: Option<String>,
+    output_path: Option<String>,
+) -> Result<String, String> {
+    let record = load_run(&run_id)?;
+    let report = match format.as_deref().unwrap_or("markdown") {
+        "html" => render_html(&record),
+        "markdown" | "md" => render_markdown(&record),
+        other => return Err(format!("UNSUPPORTED_FORMAT: {}", other)),
+    };
+
+    match output_path.filter(|p| !p.trim().is_empty()) {
+        Some(path) => {
+            let path = PathBuf::from(path.trim());
+            if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                fs::create_dir_all(parent).map_err(|_| "DIRECTORY_CREATE_ERROR".to_string())?;
+            }
+            fs::write(&path, report).map_err(|e| format!("보고서 저장 실패: {}", e))?;
+            println!("📄 실행 보고서 저장: {}", path.display());
+            Ok(path.to_string_lossy().to_string())
+        }
+        None => Ok(report),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::sample_run;
+    use serde_json::json;
+
+    #[test]
+    fn run_history_renders_markdown_report() {
+        let report = render_markdown(&sample_run());
+        assert!(report.contains("# 실행 보고서: 백업 <daily>"));
+        assert!(report.contains("| 1 | cli-1 | cli_node | ✅ success | 250 ms |"));
+        assert!(report.contains("| 2 | http-2 | http_node | ❌ error | 2.75 s |"));
+        assert!(report.contains("총 소요 시간: 3.00 s"));
+        assert!(report.contains("TIMEOUT"));
+        assert!(report.contains("`/tmp/out.txt`"));
+        assert!(!report.contains("abc123"));
+        assert!(report.contains("## 로그"));
+        assert!(report.contains("| warn | http-2 | 재시도 <1/3> |"));
+    }
+
+    #[test]
+    fn run_history_renders_escaped_html_report() {
+        let report = render_html(&sample_run());
+        assert!(report.starts_with("<!DOCTYPE html>"));
+        assert!(report.contains("백업 &lt;daily&gt;"));
+        assert!(!report.contains("<daily>"));
+        assert!(report.contains("<tr class=\"error\">"));
+        assert!(!report.contains("abc123"));
+        assert!(report.contains("재시도 &lt;1/3&gt;"));
+    }
+
+    #[test]
+    fn run_history_broadcasts_run_events() {
+        let mut events = subscribe_events();
+        publish_run_event(
+            json!({ "event": "node_started", "run_id": "r1", "node_id": "cli-1" }),
+        )
+        .unwrap();
+        let received: serde_json::Value = serde_json::from_str(&events.try_recv().unwrap()).unwrap();
+        assert_eq!(received["event"], "node_started");
+        assert_eq!(received["node_id"], "cli-1");
+
+        assert_eq!(
+            publish_run_event(json!("not an event")).unwrap_err(),
+            "INVALID_RUN_EVENT"
+        );
+    }
+}
@@ -0,0 +1,342 @@
+// src-tauri/src/nodes/run_queue.rs
+use super::atomic_store;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tokio::sync::oneshot;
+
+// 🚥 실행 우선순위 레인
+// 실행 엔진은 워크플로우를 시작하기 전에 acquire_run_slot 으로 자리를 받고, 끝나면 release_run_slot
+// 동시에 도는 실행은 max_concurrent 개, 나머지는 high / normal / low 레인에서 대기 (같은 레인은 먼저 온 순서)
+// 실행 자체는 끊지 않고 "다음에 누구를 시작할지" 만 앞당김:
+// - 자리가 나면 가장 높은 레인의 대기자부터 시작
+// - 긴 일괄 작업은 노드 사이에서 yield_run_slot 을 부르면, 더 높은 레인 대기자가 있을 때 자리를 넘기고 다시 줄을 섬
+//   → 단축키로 누른 실행이 예약된 다운로드 묶음 뒤에 갇히지 않음
+// 워크플로우별 기본 우선순위는 store/run_priorities.json (트리거가 priority 를 넘기면 그 값 우선)
+
+const DEFAULT_MAX_CONCURRENT: usize = 2;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum Priority {
+    Low,
+    Normal,
+    High,
+}
+
+impl Priority {
+    pub fn parse(text: Option<&str>) -> Result<Option<Self>, String> {
+        match text.map(|t| t.trim().to_lowercase()).as_deref() {
+            None | Some("") => Ok(None),
+            Some("high") => Ok(Some(Priority::High)),
+            Some("normal") => Ok(Some(Priority::Normal)),
+            Some("low") => Ok(Some(Priority::Low)),
+            Some(other) => Err(format!("INVALID_PRIORITY: {}", other)),
+        }
+    }
+
+    fn lane(self) -> usize {
+        match self {
+            Priority::High => 0,
+            Priority::Normal => 1,
+            Priority::Low => 2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct PrioritySettings {
+    #[serde(default)]
+    max_concurrent: Option<usize>,
+    #[serde(default)]
+    workflows: BTreeMap<String, Priority>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct Slot {
+    pub ticket: String,
+    pub workflow: String,
+    pub priority: Priority,
+}
+
+// 대기열 상태 (await 없이 결정만 - 깨우기는 RunScheduler 가 담당)
+#[derive(Debug, Default)]
+pub struct RunQueue {
+    pub max_concurrent: usize,
+    pub running: Vec<Slot>,
+    pub lanes: [VecDeque<Slot>; 3],
+}
+
+impl RunQueue {
+    pub fn new(max_concurrent: usize) -> Self {
+        Self {
+            max_concurrent: max_concurrent.max(1),
+            ..Default::default()
+        }
+    }
+
+    fn has_waiting_at_or_above(&self, priority: Priority) -> bool {
+        self.lanes[..=priority.lane()].iter().any(|lane| !lane.is_empty())
+    }
+
+    // 바로 시작하면 true, 아니면 대기열 끝에 넣고 false
+    pub fn enqueue(&mut self, slot: Slot) -> bool {
+        if self.running.len() < self.max_concurrent
+            && !self.has_waiting_at_or_above(slot.priority)
+        {
+            self.running.push(slot);
+            return true;
+        }
+        self.lanes[slot.priority.lane()].push_back(slot);
+        false
+    }
+
+    // 빈 자리만큼 높은 레인부터 꺼내 실행 목록으로 옮김
+    pub fn dispatch(&mut self) -> Vec<Slot> {
+        let mut granted = Vec::new();
+        while self.running.len() < self.max_concurrent {
+            let Some(slot) = self.lanes.iter_mut().find_map(|lane| lane.pop_front()) else {
+                break;
+            };
+            self.running.push(slot.clone());
+            granted.push(slot);
+        }
+        granted
+    }
+
+    pub fn release(&mut self, ticket: &str) -> bool {
+        let before = self.running.len() + self.waiting_count();
+        self.running.retain(|slot| slot.ticket != ticket);
+        for lane in self.lanes.iter_mut() {
+            lane.retain(|slot| slot.ticket != ticket);
+        }
+        before != self.running.len() + self.waiting_count()
+    }
+
+    pub fn waiting_count(&self) -> usize {
+        self.lanes.iter().map(VecDeque::len).sum()
+    }
+
+    // 더 높은 레인에 대기자가 있으면 자리를 내놓고 자기 레인 맨 앞에서 다시 대기
+    pub fn yield_slot(&mut self, ticket: &str) -> bool {
+        let Some(index) = self.running.iter().position(|slot| slot.ticket == ticket) else {
+            return false;
+        };
+        let priority = self.running[index].priority;
+        if !self.lanes[..priority.lane()].iter().any(|lane| !lane.is_empty()) {
+            return false;
+        }
+        let slot = self.running.remove(index);
+        self.lanes[priority.lane()].push_front(slot);
+        true
+    }
+}
+
+struct RunScheduler {
+    queue: RunQueue,
+    waiters: HashMap<String, oneshot::Sender<()>>,
+}
+
+static SCHEDULER: std::sync::OnceLock<Mutex<RunScheduler>> = std::sync::OnceLock::new();
+
+fn settings_path() -> PathBuf {
+    atomic_store::store_dir().join("run_priorities.json")
+}
+
+fn load_settings() -> PrioritySettings {
+    atomic_store::read_json_verified(&settings_path()).unwrap_or_default()
+}
+
+fn get_scheduler() -> &'static Mutex<RunScheduler> {
+    SCHEDULER.get_or_init(|| {
+        let max = load_settings().max_concurrent.unwrap_or(DEFAULT_MAX_CONCURRENT);
+        Mutex::new(RunScheduler {
+            queue: RunQueue::new(max),
+            waiters: HashMap::new(),
+        })
+    })
+}
+
+fn new_ticket() -> String {
+    static COUNTER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+    format!(
+        "slot-{}-{}",
+        chrono::Utc::now().timestamp_millis(),
+        COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+    )
+}
+
+// 자리가 난 대기자 깨우기 (이미 포기한 대기자는 자리를 돌려받아 다음 사람에게)
+fn wake(scheduler: &mut RunScheduler) {
+    loop {
+        let granted = scheduler.queue.dispatch();
+        if granted.is_empty() {
+            return;
+        }
+        for slot in granted {
+            let delivered = scheduler
+                .waiters
+                .remove(&slot.ticket)
+                .is_some_and(|sender| sender.send(()).is_ok());
+            if !delivered {
+                scheduler.queue.release(&slot.ticket);
+            }
+        }
+    }
+}
+
+async fn wait_for_slot(slot: Slot) -> Result<bool, String> {
+    let ticket = slot.ticket.clone();
+    let rx = {
+        let mut scheduler = get_scheduler().lock().map_err(|e| e.to_string())?;
+        if scheduler.queue.enqueue(slot) {
+            return Ok(false);
+        }
+        let (tx, rx) = oneshot::channel();
+        scheduler.waiters.insert(ticket.clone(), tx);
+        rx
+    };
+    rx.await
+        .map(|_| true)
+        .map_err(|_| format!("RUN_SLOT_CANCELLED: {}", ticket))
+}
+
+// 실행 시작 전에 호출, 자리를 받으면 ticket 반환 (finish 후 release_run_slot)
+#[tauri::command]
+pub async fn acquire_run_slot(
+    workflow: String,
+    priority: Option<String>,
+) -> Result<String, String> {
+    let priority = Priority::parse(priority.as_deref())?
+        .or_else(|| load_settings().workflows.get(&workflow).copied())
+        .unwrap_or(Priority::Normal);
+    let ticket = new_ticket();
+    let waited = wait_for_slot(Slot {
+        ticket: ticket.clone(),
+        workflow: workflow.clone(),
+        priority,
+    })
+    .await?;
+    if waited {
+        println!("🚥 대기 후 실행: {} ({:?})", workflow, priority);
+    }
+    Ok(ticket)
+}
+
+#[tauri::command]
+pub fn release_run_slot(ticket: String) -> Result<String, String> {
+    let mut scheduler = get_scheduler().lock().map_err(|e| e.to_string())?;
+    scheduler.waiters.remove(&ticket);
+    scheduler.queue.release(&ticket);
+    wake(&mut scheduler);
+    Ok("SUCCESS".to_string())
+}
+
+// 노드 사이 호출: 더 급한 실행이 기다리면 자리를 넘기고 다시 받을 때까지 대기 ("yielded" | "continue")
+#[tauri::command]
+pub async fn yield_run_slot(ticket: String) -> Result<String, String> {
+    let rx = {
+        let mut scheduler = get_scheduler().lock().map_err(|e| e.to_string())?;
+        if !scheduler.queue.yield_slot(&ticket) {
+            return Ok("continue".to_string());
+        }
+        let (tx, rx) = oneshot::channel();
+        scheduler.waiters.insert(ticket.clone(), tx);
+        wake(&mut scheduler);
+        rx
+    };
+    rx.await.map_err(|_| format!("RUN_SLOT_CANCELLED: {}", ticket))?;
+    Ok("yielded".to_string())
+}
+
+#[tauri::command]
+pub fn list_run_queue() -> Result<serde_json::Value, String> {
+    let scheduler = get_scheduler().lock().map_err(|e| e.to_string())?;
+    let queue = &scheduler.queue;
+    Ok(serde_json::json!({
+        "max_concurrent": queue.max_concurrent,
+        "running": queue.running,
+        "waiting": queue.lanes.iter().flatten().collect::<Vec<_>>()
+    }))
+}
+
+// 워크플로우 기본 우선순위 (priority 가 없으면 설정 삭제 → normal)
+#[tauri::command]
+pub fn set_workflow_priority(
+    workflow: String,
+    priority: Option<String>,
+) -> Result<String, String> {
+    let mut settings = load_settings();
+    match Priority::parse(priority.as_deref())? {
+        Some(priority) => {
+            settings.workflows.insert(workflow, priority);
+        }
+        None => {
+            settings.workflows.remove(&workflow);
+        }
+    }
+    atomic_store::write_json_atomic(&settings_path(), &settings)?;
+    Ok("SUCCESS".to_string())
+}
+
+#[tauri::command]
+pub fn set_max_concurrent_runs(max_concurrent: usize) -> Result<String, String> {
+    let max_concurrent = max_concurrent.max(1);
+    let mut settings = load_settings();
+    settings.max_concurrent = Some(max_concurrent);
+    atomic_store::write_json_atomic(&settings_path(), &settings)?;
+    let mut scheduler = get_scheduler().lock().map_err(|e| e.to_string())?;
+    scheduler.queue.max_concurrent = max_concurrent;
+    wake(&mut scheduler);
+    Ok("SUCCESS".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slot(ticket: &str, priority: Priority) -> Slot {
+        Slot {
+            ticket: ticket.to_string(),
+            workflow: format!("wf-{}", ticket),
+            priority,
+        }
+    }
+
+    #[test]
+    fn run_queue_starts_higher_lanes_first() {
+        use Priority::*;
+        let mut queue = RunQueue::new(1);
+        assert!(queue.enqueue(slot("batch", Low)));
+        assert!(!queue.enqueue(slot("batch-2", Low)));
+        assert!(!queue.enqueue(slot("normal", Normal)));
+        assert!(!queue.enqueue(slot("hotkey", High)));
+
+        assert!(queue.release("batch"));
+        let granted: Vec<_> = queue.dispatch().into_iter().map(|s| s.ticket).collect();
+        assert_eq!(granted, vec!["hotkey"]);
+        queue.release("hotkey");
+        assert_eq!(queue.dispatch()[0].ticket, "normal");
+        assert_eq!(Priority::parse(Some(" HIGH ")).unwrap(), Some(High));
+        assert!(Priority::parse(Some("urgent")).is_err());
+    }
+
+    #[test]
+    fn run_queue_yield_hands_slot_to_waiting_higher_priority() {
+        use Priority::*;
+        let mut queue = RunQueue::new(1);
+        assert!(queue.enqueue(slot("batch", Low)));
+        // 기다리는 사람이 없으면 계속 실행
+        assert!(!queue.yield_slot("batch"));
+
+        assert!(!queue.enqueue(slot("hotkey", High)));
+        assert!(queue.yield_slot("batch"));
+        assert_eq!(queue.dispatch()[0].ticket, "hotkey");
+        // 자리를 넘긴 실행은 같은 레인 맨 앞에서 다시 대기
+        assert!(!queue.enqueue(slot("batch-2", Low)));
+        queue.release("hotkey");
+        assert_eq!(queue.dispatch()[0].ticket, "batch");
+        assert_eq!(queue.waiting_count(), 1);
+    }
+}
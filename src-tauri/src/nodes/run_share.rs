@@ -0,0 +1,415 @@
+// src-tauri/src/nodes/run_share.rs
+use super::{chat_web_server_node, control_page, diagnostics, run_history};
+use futures_util::{sink::SinkExt, stream::StreamExt};
+use rand::RngCore;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::sync::Mutex;
+use tokio::sync::broadcast;
+use warp::{Filter, Rejection, Reply};
+
+// 🔗 실행 공유 (/share/<run_id>?token=<토큰>)
+// 채팅 웹서버(터널 포함)로 팀원에게 특정 실행의 실시간 로그 / 노드 진행 / 산출물을 읽기 전용으로 보여줌
+// - share_run 이 실행마다 별도 토큰을 만들고, 토큰이 없거나 틀리거나 만료되면 404 (공유 여부도 드러내지 않음)
+// - 입력 값은 diagnostics::redact_json 으로 가리고, 산출물은 전체 경로 대신 파일 이름만 노출
+// - 산출물 파일은 실행 기록에 남은 것만 번호로 내려받기 가능
+// 공유 목록은 메모리에만 보관 (앱을 다시 시작하면 모든 공유 링크가 무효)
+
+const DEFAULT_SHARE_MINUTES: i64 = 24 * 60;
+const MAX_SHARE_MINUTES: i64 = 7 * 24 * 60;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RunShare {
+    pub run_id: String,
+    pub token: String,
+    pub created_at: i64, // unix 초
+    pub expires_at: i64,
+}
+
+static SHARES: Mutex<BTreeMap<String, RunShare>> = Mutex::new(BTreeMap::new());
+
+fn new_token() -> String {
+    let mut bytes = [0u8; 24];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn active_share(run_id: &str) -> Option<RunShare> {
+    let now = chrono::Utc::now().timestamp();
+    let mut shares = SHARES.lock().ok()?;
+    shares.retain(|_, share| share.expires_at > now);
+    shares.get(run_id).cloned()
+}
+
+// 공유 페이지에 내보낼 실행 기록 (입력 / 로그 컨텍스트의 민감한 값 가림, 산출물은 파일 이름만)
+pub fn shared_view(record: &run_history::RunRecord) -> Value {
+    let mut record = record.clone();
+    let mut artifacts = Vec::new();
+    for node in &mut record.nodes {
+        diagnostics::redact_json(&mut node.inputs);
+        for artifact in &mut node.artifacts {
+            let name = Path::new(artifact.as_str())
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default();
+            artifacts.push(json!({
+                "index": artifacts.len(),
+                "node_id": node.node_id,
+                "name": name
+            }));
+            *artifact = name;
+        }
+    }
+    for log in &mut record.logs {
+        diagnostics::redact_json(&mut log.context);
+    }
+    json!({ "run": record, "artifacts": artifacts })
+}
+
+// 실행 이벤트 중 공유한 실행의 것만 (로그 컨텍스트는 가림)
+pub fn filter_event(event: &str, run_id: &str) -> Option<String> {
+    let mut event: Value = serde_json::from_str(event).ok()?;
+    if event["run_id"] != run_id {
+        return None;
+    }
+    if let Some(context) = event.get_mut("context") {
+        diagnostics::redact_json(context);
+    }
+    Some(event.to_string())
+}
+
+// /share/<run_id> + 토큰 확인 → run_id
+fn shared_run() -> impl Filter<Extract = (String,), Error = Rejection> + Clone {
+    warp::path::param::<String>()
+        .and(warp::header::optional::<String>("authorization"))
+        .and(warp::query::<HashMap<String, String>>())
+        .and_then(
+            |run_id: String, authorization: Option<String>, query: HashMap<String, String>| async move {
+                let provided = control_page::provided_token(authorization.as_deref(), &query);
+                match active_share(&run_id) {
+                    Some(share)
+                        if provided
+                            .is_some_and(|p| control_page::token_matches(&share.token, &p)) =>
+                    {
+                        Ok(run_id)
+                    }
+                    _ => Err(warp::reject::not_found()),
+                }
+            },
+        )
+}
+
+async fn serve_artifact(run_id: String, index: usize) -> Result<Box<dyn Reply>, Rejection> {
+    let record = run_history::load_run(&run_id).map_err(|_| warp::reject::not_found())?;
+    let path = record
+        .nodes
+        .iter()
+        .flat_map(|node| node.artifacts.iter())
+        .nth(index)
+        .map(std::path::PathBuf::from)
+        .filter(|path| path.is_file())
+        .ok_or_else(warp::reject::not_found)?;
+    let body = tokio::fs::read(&path)
+        .await
+        .map_err(|_| warp::reject::not_found())?;
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().replace('"', ""))
+        .unwrap_or_default();
+    let response = warp::http::Response::builder()
+        .header("content-type", chat_web_server_node::content_type_for(&path))
+        .header("x-content-type-options", "nosniff")
+        // HTML 산출물이 공유 페이지 권한으로 스크립트를 실행하지 못하게
+        .header("content-security-policy", "sandbox")
+        .header("content-disposition", format!("inline; filename=\"{}\"", name))
+        .body(body)
+        .map_err(|_| warp::reject::not_found())?;
+    Ok(Box::new(response))
+}
+
+async fn relay_shared_events(websocket: warp::ws::WebSocket, run_id: String) {
+    let (mut sender, _receiver) = websocket.split();
+    let mut events = run_history::subscribe_events();
+    loop {
+        match events.recv().await {
+            Ok(event) => {
+                let Some(event) = filter_event(&event, &run_id) else {
+                    continue;
+                };
+                if sender.send(warp::ws::Message::text(event)).await.is_err() {
+                    break;
+                }
+            }
+            Err(broadcast::error::RecvError::Lagged(_)) => continue,
+            Err(broadcast::error::RecvError::Closed) => break,
+        }
+    }
+}
+
+pub fn routes() -> impl Filter<Extract = (Box<dyn Reply>,), Error = Rejection> + Clone {
+    let page = warp::path("share")
+        .and(shared_run())
+        .and(warp::path::end())
+        .and(warp::get())
+        .map(|run_id: String| {
+            Box::new(warp::reply::html(create_share_html(&run_id))) as Box<dyn Reply>
+        });
+
+    let run = warp::path("share")
+        .and(shared_run())
+        .and(warp::path("run"))
+        .and(warp::path::end())
+        .and(warp::get())
+        .map(|run_id: String| match run_history::load_run(&run_id) {
+            Ok(record) => Box::new(warp::reply::json(&shared_view(&record))) as Box<dyn Reply>,
+            Err(_) => Box::new(warp::http::StatusCode::NOT_FOUND) as Box<dyn Reply>,
+        });
+
+    let artifact = warp::path("share")
+        .and(shared_run())
+        .and(warp::path("artifacts"))
+        .and(warp::path::param::<usize>())
+        .and(warp::path::end())
+        .and(warp::get())
+        .and_then(serve_artifact);
+
+    let events = warp::path("share")
+        .and(shared_run())
+        .and(warp::path("ws"))
+        .and(warp::ws())
+        .map(|run_id: String, ws: warp::ws::Ws| {
+            Box::new(ws.on_upgrade(move |websocket| relay_shared_events(websocket, run_id)))
+                as Box<dyn Reply>
+        });
+
+    page.or(run).unify().or(artifact).unify().or(events).unify()
+}
+
+// 실행 공유 링크 생성 (같은 실행을 다시 공유하면 새 토큰으로 교체 → 이전 링크 무효)
+// 채팅 웹서버가 실행 중이면 터널 / 로컬 주소를 붙인 전체 링크도 함께 반환
+#[tauri::command]
+pub async fn share_run(run_id: String, expires_minutes: Option<i64>) -> Result<String, String> {
+    run_history::load_run(&run_id)?;
+    let minutes = expires_minutes
+        .unwrap_or(DEFAULT_SHARE_MINUTES)
+        .clamp(1, MAX_SHARE_MINUTES);
+    let now = chrono::Utc::now().timestamp();
+    let share = RunShare {
+        run_id: run_id.clone(),
+        token: new_token(),
+        created_at: now,
+        expires_at: now + minutes * 60,
+    };
+    SHARES
+        .lock()
+        .map_err(|e| e.to_string())?
+        .insert(run_id.clone(), share.clone());
+
+    let path = format!("/share/{}?token={}", run_id, share.token);
+    let urls: Vec<String> = chat_web_server_node::public_base_urls()
+        .await
+        .iter()
+        .map(|base| format!("{}{}", base.trim_end_matches('/'), path))
+        .collect();
+    println!("🔗 실행 공유: {} ({}분)", run_id, minutes);
+    Ok(json!({
+        "run_id": run_id,
+        "token": share.token,
+        "expires_at": share.expires_at,
+        "path": path,
+        "urls": urls
+    })
+    .to_string())
+}
+
+#[tauri::command]
+pub fn revoke_run_share(run_id: String) -> Result<String, String> {
+    let removed = SHARES
+        .lock()
+        .map_err(|e| e.to_string())?
+        .remove(&run_id)
+        .is_some();
+    if !removed {
+        return Err(format!("SHARE_NOT_FOUND: {}", run_id));
+    }
+    println!("🔗 실행 공유 해제: {}", run_id);
+    Ok("SUCCESS".to_string())
+}
+
+#[tauri::command]
+pub fn list_run_shares() -> Result<Vec<RunShare>, String> {
+    let now = chrono::Utc::now().timestamp();
+    let mut shares = SHARES.lock().map_err(|e| e.to_string())?;
+    shares.retain(|_, share| share.expires_at > now);
+    Ok(shares.values().cloned().collect())
+}
+
+fn create_share_html(run_id: &str) -> String {
+    r#"<!DOCTYPE html>
+<html lang="ko">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+    <title>Shared Run</title>
+    <style>
+        * { margin: 0; padding: 0; box-sizing: border-box; }
+        body {
+            font-family: "Inter", -apple-system, BlinkMacSystemFont, sans-serif;
+            background: #0f0f0f;
+            color: #e5e5e5;
+            padding: 16px;
+        }
+        header { display: flex; justify-content: space-between; align-items: center; margin-bottom: 16px; }
+        h1 { font-size: 20px; font-weight: 600; }
+        h2 { font-size: 15px; color: #aaa; margin: 18px 0 8px; }
+        #connection { font-size: 12px; color: #888; }
+        #connection.online { color: #4ade80; }
+        #summary { font-size: 13px; color: #aaa; }
+        .node, .log { background: #1a1a1a; border-radius: 8px; padding: 10px 12px; margin-bottom: 6px; font-size: 14px; }
+        .node .meta, .log .meta { font-size: 12px; color: #888; }
+        .node.error, .log.error { border-left: 3px solid #f87171; }
+        .log.warn { border-left: 3px solid #facc15; }
+        .log pre { white-space: pre-wrap; word-break: break-all; margin-top: 4px; }
+        a { color: #60a5fa; }
+        .empty { color: #666; }
+    </style>
+</head>
+<body>
+    <header>
+        <h1 id="title">🔗 공유된 실행</h1>
+        <span id="connection">연결 중...</span>
+    </header>
+    <div id="summary"></div>
+    <h2>노드</h2>
+    <div id="nodes"><div class="empty">불러오는 중...</div></div>
+    <h2>산출물</h2>
+    <div id="artifacts"><div class="empty">없음</div></div>
+    <h2>로그</h2>
+    <div id="logs"><div class="empty">없음</div></div>
+
+    <script>
+        const runId = "__RUN_ID__";
+        const token = new URLSearchParams(window.location.search).get('token') || '';
+        const base = `/share/${encodeURIComponent(runId)}`;
+        const icons = { running: '⏳', success: '✅', error: '❌', skipped: '⏭️', cancelled: '⏹️' };
+        let run = null;
+        let artifacts = [];
+
+        function text(value) {
+            const span = document.createElement('span');
+            span.textContent = value == null ? '' : String(value);
+            return span.innerHTML;
+        }
+
+        function render() {
+            if (!run) return;
+            document.getElementById('title').textContent = `🔗 ${run.workflow}`;
+            document.getElementById('summary').innerHTML =
+                `${icons[run.status] || '•'} ${text(run.status)} · 시작 ${text(run.started_at)}`;
+            document.getElementById('nodes').innerHTML = run.nodes.length === 0
+                ? '<div class="empty">아직 완료된 노드가 없습니다</div>'
+                : run.nodes.map(node => `
+                <div class="node ${text(node.status)}">
+                    ${icons[node.status] || '•'} ${text(node.node_id)}
+                    <div class="meta">${text(node.node_type)} · ${node.finished_at - node.started_at} ms${node.error ? ' · ' + text(node.error) : ''}</div>
+                </div>`).join('');
+            document.getElementById('artifacts').innerHTML = artifacts.length === 0
+                ? '<div class="empty">없음</div>'
+                : artifacts.map(a => `<div class="node"><a href="${base}/artifacts/${a.index}?token=${encodeURIComponent(token)}" target="_blank">${text(a.name)}</a><div class="meta">${text(a.node_id)}</div></div>`).join('');
+            document.getElementById('logs').innerHTML = run.logs.length === 0
+                ? '<div class="empty">없음</div>'
+                : run.logs.map(log => `
+                <div class="log ${text(log.level)}">
+                    <div class="meta">${new Date(log.timestamp).toLocaleTimeString()} · ${text(log.level)}${log.node_id ? ' · ' + text(log.node_id) : ''}</div>
+                    <pre>${text(log.message)}</pre>
+                </div>`).join('');
+        }
+
+        async function load() {
+            const response = await fetch(`${base}/run?token=${encodeURIComponent(token)}`);
+            if (!response.ok) {
+                document.getElementById('summary').textContent = '공유가 만료되었거나 링크가 올바르지 않습니다';
+                return;
+            }
+            const data = await response.json();
+            run = data.run;
+            artifacts = data.artifacts || [];
+            render();
+        }
+
+        function connect() {
+            const wsProtocol = window.location.protocol === 'https:' ? 'wss:' : 'ws:';
+            const websocket = new WebSocket(`${wsProtocol}//${window.location.host}${base}/ws?token=${encodeURIComponent(token)}`);
+            const connection = document.getElementById('connection');
+
+            websocket.onopen = () => {
+                connection.textContent = '● 실시간';
+                connection.className = 'online';
+            };
+            websocket.onmessage = (message) => {
+                try {
+                    const event = JSON.parse(message.data);
+                    if (event.event === 'run_log' && run) {
+                        run.logs.push(event);
+                        render();
+                    } else if (event.event === 'node_finished' || event.event === 'run_finished') {
+                        load();
+                    }
+                } catch (e) {
+                    // 실행 이벤트가 아닌 메시지는 무시
+                }
+            };
+            websocket.onclose = () => {
+                connection.textContent = '연결 끊김 - 재연결 중...';
+                connection.className = '';
+                setTimeout(connect, 3000);
+            };
+        }
+
+        load();
+        connect();
+    </script>
+</body>
+</html>"#
+        .replace("__RUN_ID__", run_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::sample_run;
+    use serde_json::json;
+
+    #[test]
+    fn run_share_view_redacts_inputs_and_hides_artifact_paths() {
+        let view = shared_view(&sample_run());
+        let cli = &view["run"]["nodes"][0];
+        assert_eq!(cli["inputs"]["command"], "echo hi");
+        assert_ne!(cli["inputs"]["api_token"], "abc123");
+        assert_eq!(cli["artifacts"], json!(["out.txt"]));
+        assert_eq!(
+            view["artifacts"],
+            json!([{ "index": 0, "node_id": "cli-1", "name": "out.txt" }])
+        );
+        assert!(!view.to_string().contains("/tmp/"));
+    }
+
+    #[test]
+    fn run_share_forwards_only_events_of_the_shared_run() {
+        let other = json!({ "event": "node_finished", "run_id": "b" }).to_string();
+        assert_eq!(filter_event(&other, "a"), None);
+
+        let log = json!({
+            "event": "run_log",
+            "run_id": "a",
+            "message": "hi",
+            "context": { "password": "hunter2" }
+        })
+        .to_string();
+        let forwarded = filter_event(&log, "a").unwrap();
+        assert!(forwarded.contains("\"message\":\"hi\""));
+        assert!(!forwarded.contains("hunter2"));
+    }
+}
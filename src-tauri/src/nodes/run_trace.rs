@@ -0,0 +1,247 @@
+// src-tauri/src/nodes/run_trace.rs
+use super::{atomic_store, event_chunks, run_history, safe_path};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tauri::AppHandle;
+use tokio::sync::{oneshot, Mutex};
+
+// ⏪ 실행 시간 여행 디버깅 (옵트인)
+// 켜 두면 record_node_run 때 노드마다 입력 / 출력 / 에러 원본을 store/run_traces/<run_id>/<node_id>.json 에 보관
+// (실행 기록의 요약과 달리 자르거나 가리지 않으므로 비밀값이 남을 수 있어 기본은 꺼짐)
+// replay_node 는 기록된 입력으로 노드 하나만 다시 실행:
+// "node-replay-request" 이벤트 → 프론트엔드 엔진이 해당 노드만 실행 → complete_node_replay 로 결과 반환
+// 반복문 등으로 같은 노드가 여러 번 실행되면 마지막 실행만 남음
+
+const REPLAY_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(600);
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct TraceSettings {
+    enabled: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct NodeTrace {
+    pub run_id: String,
+    pub node_id: String,
+    pub node_type: String,
+    pub recorded_at: String,
+    pub inputs: Value,
+    pub output: Option<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct NodeReplayRequest {
+    replay_id: String,
+    run_id: String,
+    node_id: String,
+    node_type: String,
+    inputs: Value,
+}
+
+type PendingReplays = Arc<Mutex<HashMap<String, oneshot::Sender<Result<String, String>>>>>;
+
+static PENDING_REPLAYS: std::sync::OnceLock<PendingReplays> = std::sync::OnceLock::new();
+
+fn get_pending_replays() -> &'static PendingReplays {
+    PENDING_REPLAYS.get_or_init(|| Arc::new(Mutex::new(HashMap::new())))
+}
+
+fn settings_path() -> PathBuf {
+    atomic_store::store_dir().join("run_trace_settings.json")
+}
+
+fn traces_root() -> PathBuf {
+    atomic_store::store_dir().join("run_traces")
+}
+
+pub fn is_enabled() -> bool {
+    atomic_store::read_json_verified::<TraceSettings>(&settings_path()).is_some_and(|s| s.enabled)
+}
+
+pub fn trace_path(root: &Path, run_id: &str, node_id: &str) -> Result<PathBuf, String> {
+    // run_id 형식 검사는 실행 기록과 동일
+    run_history::validate_run_id(run_id)?;
+    if node_id.trim().is_empty() {
+        return Err("INVALID_NODE_ID".to_string());
+    }
+    let file_name = safe_path::sanitize_file_name(node_id, "node");
+    Ok(root.join(run_id).join(format!("{}.json", file_name)))
+}
+
+pub fn save_trace(root: &Path, trace: &NodeTrace) -> Result<(), String> {
+    atomic_store::write_json_atomic(&trace_path(root, &trace.run_id, &trace.node_id)?, trace)
+}
+
+pub fn load_trace(root: &Path, run_id: &str, node_id: &str) -> Result<NodeTrace, String> {
+    atomic_store::read_json_verified(&trace_path(root, run_id, node_id)?)
+        .ok_or_else(|| format!("TRACE_NOT_FOUND: {}", node_id))
+}
+
+// record_node_run 에서 호출 (꺼져 있으면 아무것도 하지 않음, 실패해도 실행 기록은 유지)
+pub fn capture(run_id: &str, node: &run_history::NodeRun) {
+    if !is_enabled() {
+        return;
+    }
+    let trace = NodeTrace {
+        run_id: run_id.to_string(),
+        node_id: node.node_id.clone(),
+        node_type: node.node_type.clone(),
+        recorded_at: chrono::Local::now().to_rfc3339(),
+        inputs: node.inputs.clone(),
+        output: node.output.clone(),
+        error: node.error.clone(),
+    };
+    if let Err(e) = save_trace(&traces_root(), &trace) {
+        println!("⚠️ 노드 입출력 기록 실패: {}", e);
+    }
+}
+
+fn new_replay_id() -> String {
+    static COUNTER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+    format!(
+        "replay-{}-{}",
+        chrono::Utc::now().timestamp_millis(),
+        COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+    )
+}
+
+#[tauri::command]
+pub fn set_run_tracing(enabled: bool) -> Result<String, String> {
+    atomic_store::write_json_atomic(&settings_path(), &TraceSettings { enabled })?;
+    println!("⏪ 노드 입출력 기록: {}", if enabled { "켜짐" } else { "꺼짐" });
+    Ok("SUCCESS".to_string())
+}
+
+#[tauri::command]
+pub fn get_run_tracing() -> Result<bool, String> {
+    Ok(is_enabled())
+}
+
+// 실행에서 입출력이 기록된 노드 목록
+#[tauri::command]
+pub fn list_node_traces(run_id: String) -> Result<Vec<NodeTrace>, String> {
+    run_history::validate_run_id(&run_id)?;
+    let Ok(entries) = std::fs::read_dir(traces_root().join(&run_id)) else {
+        return Ok(Vec::new());
+    };
+    let mut traces: Vec<NodeTrace> = entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|e| atomic_store::read_json_verified(&e.path()))
+        .collect();
+    traces.sort_by(|a, b| a.recorded_at.cmp(&b.recorded_at));
+    Ok(traces)
+}
+
+#[tauri::command]
+pub fn get_node_trace(run_id: String, node_id: String) -> Result<NodeTrace, String> {
+    load_trace(&traces_root(), &run_id, &node_id)
+}
+
+// 기록된 입력으로 노드 하나만 다시 실행 (inputs 를 주면 그 값으로 덮어씀)
+#[tauri::command]
+pub async fn replay_node(
+    app_handle: AppHandle,
+    run_id: String,
+    node_id: String,
+    inputs: Option<Value>,
+) -> Result<String, String> {
+    let trace = load_trace(&traces_root(), &run_id, &node_id)?;
+    let replay_id = new_replay_id();
+    let (tx, rx) = oneshot::channel();
+    get_pending_replays()
+        .lock()
+        .await
+        .insert(replay_id.clone(), tx);
+
+    let request = NodeReplayRequest {
+        replay_id: replay_id.clone(),
+        run_id: run_id.clone(),
+        node_id: node_id.clone(),
+        node_type: trace.node_type.clone(),
+        inputs: inputs.unwrap_or_else(|| trace.inputs.clone()),
+    };
+    if let Err(e) = event_chunks::emit_chunked(&app_handle, "node-replay-request", &request) {
+        get_pending_replays().lock().await.remove(&replay_id);
+        return Err(format!("노드 재실행 요청 실패: {}", e));
+    }
+    println!("⏪ 노드 재실행: {} / {} ({})", run_id, node_id, replay_id);
+
+    let result = match tokio::time::timeout(REPLAY_TIMEOUT, rx).await {
+        Ok(Ok(result)) => result,
+        Ok(Err(_)) => Err("REPLAY_CANCELLED".to_string()),
+        Err(_) => {
+            get_pending_replays().lock().await.remove(&replay_id);
+            Err("REPLAY_TIMEOUT".to_string())
+        }
+    };
+    let (output, error) = match result {
+        Ok(output) => (Some(output), None),
+        Err(error) => (None, Some(error)),
+    };
+    let changed = output != trace.output || error != trace.error;
+    Ok(json!({
+        "run_id": run_id,
+        "node_id": node_id,
+        "node_type": trace.node_type,
+        "inputs": request.inputs,
+        "original": { "output": trace.output, "error": trace.error },
+        "replay": { "output": output, "error": error },
+        "changed": changed
+    })
+    .to_string())
+}
+
+// 프론트엔드 엔진이 재실행을 마치면 호출
+#[tauri::command]
+pub async fn complete_node_replay(
+    replay_id: String,
+    output: Option<String>,
+    error: Option<String>,
+) -> Result<String, String> {
+    let sender = get_pending_replays()
+        .lock()
+        .await
+        .remove(&replay_id)
+        .ok_or_else(|| format!("UNKNOWN_REPLAY: {}", replay_id))?;
+    let result = match error {
+        Some(error) => Err(error),
+        None => Ok(output.unwrap_or_default()),
+    };
+    let _ = sender.send(result);
+    Ok("SUCCESS".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::temp_dir;
+    use serde_json::json;
+
+    #[test]
+    fn run_trace_round_trips_exact_payloads() {
+        let dir = temp_dir();
+        let long_output = "x".repeat(10_000);
+        let trace = NodeTrace {
+            run_id: "20260101-120000-000-0".to_string(),
+            node_id: "http/2".to_string(),
+            node_type: "http_node".to_string(),
+            recorded_at: "2026-01-01T12:00:00+09:00".to_string(),
+            inputs: json!({ "url": "https://example.com", "api_token": "abc123" }),
+            output: Some(long_output.clone()),
+            error: None,
+        };
+        save_trace(dir.path(), &trace).unwrap();
+
+        let loaded = load_trace(dir.path(), &trace.run_id, "http/2").unwrap();
+        // 보고서와 달리 자르거나 가리지 않음
+        assert_eq!(loaded, trace);
+        assert_eq!(loaded.output.unwrap().len(), long_output.len());
+        assert!(load_trace(dir.path(), "../etc", "http/2").is_err());
+        assert!(load_trace(dir.path(), &trace.run_id, "other").is_err());
+    }
+}
@@ -0,0 +1,338 @@
+// src-tauri/src/nodes/run_watch.rs
+use super::{placeholders, run_history, value_store};
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+// 👀 실행 중 감시식 (디버깅용 "변수 패널")
+// 실행마다 JSONPath 감시식을 등록하면 record_node_run 때마다 다시 계산해 "watch_values" 실행 이벤트로 방송
+// 계산 대상: { "nodes": { <node_id>: 출력 }, "vars": 저장된 변수 }
+// - $.nodes.fetch-1.items[0].title   노드 출력 (JSON 이면 파싱, 아니면 문자열)
+// - $.vars.user.name                 변수 저장소
+// - [*] / .* 는 모든 항목 → 결과는 배열
+// 노드 출력은 감시식이 있는 실행만 메모리에 보관, finish_run 때 정리
+
+const MAX_WATCHES: usize = 50;
+const MAX_EXPRESSION_CHARS: usize = 500;
+const MAX_VALUE_CHARS: usize = 2000;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Segment {
+    Key(String),
+    Index(i64),
+    Wildcard,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct WatchValue {
+    pub watch_id: String,
+    pub expression: String,
+    pub value: Value,
+    pub truncated: bool,
+    pub changed: bool,
+}
+
+struct Watch {
+    watch_id: String,
+    expression: String,
+    segments: Vec<Segment>,
+    last: Option<Value>,
+}
+
+#[derive(Default)]
+struct RunWatches {
+    watches: Vec<Watch>,
+    outputs: Map<String, Value>,
+    next_id: u64,
+}
+
+static WATCH_STATE: std::sync::OnceLock<Mutex<HashMap<String, RunWatches>>> =
+    std::sync::OnceLock::new();
+
+fn get_state() -> &'static Mutex<HashMap<String, RunWatches>> {
+    WATCH_STATE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+// "$.a.b[0]['c d'][*]" → 경로 조각 ("$" 는 생략 가능)
+pub fn parse_path(expression: &str) -> Result<Vec<Segment>, String> {
+    let invalid = || format!("INVALID_WATCH_EXPRESSION: {}", expression);
+    let text = expression.trim();
+    if text.is_empty() || text.chars().count() > MAX_EXPRESSION_CHARS {
+        return Err(invalid());
+    }
+    let mut rest = match text.strip_prefix('$') {
+        Some(rest) => rest,
+        None => text,
+    };
+    // "$" 없이 시작하면 첫 키 앞의 점을 생략한 것으로 봄 (nodes.x)
+    let mut segments = Vec::new();
+    if !rest.is_empty() && !rest.starts_with('.') && !rest.starts_with('[') {
+        let end = rest.find(['.', '[']).unwrap_or(rest.len());
+        segments.push(Segment::Key(rest[..end].to_string()));
+        rest = &rest[end..];
+    }
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix('.') {
+            let end = after.find(['.', '[']).unwrap_or(after.len());
+            let key = &after[..end];
+            if key.is_empty() {
+                return Err(invalid());
+            }
+            segments.push(if key == "*" {
+                Segment::Wildcard
+            } else {
+                Segment::Key(key.to_string())
+            });
+            rest = &after[end..];
+        } else if let Some(after) = rest.strip_prefix('[') {
+            let quote = after.chars().next().filter(|c| matches!(c, '\'' | '"'));
+            let (segment, len) = if let Some(quote) = quote {
+                let body = &after[1..];
+                let close = body.find(quote).ok_or_else(invalid)?;
+                if !body[close + 1..].starts_with(']') {
+                    return Err(invalid());
+                }
+                (Segment::Key(body[..close].to_string()), close + 3)
+            } else {
+                let close = after.find(']').ok_or_else(invalid)?;
+                let inner = after[..close].trim();
+                let segment = if inner == "*" {
+                    Segment::Wildcard
+                } else {
+                    Segment::Index(inner.parse::<i64>().map_err(|_| invalid())?)
+                };
+                (segment, close + 1)
+            };
+            segments.push(segment);
+            rest = &after[len..];
+        } else {
+            return Err(invalid());
+        }
+    }
+    Ok(segments)
+}
+
+fn select<'a>(values: Vec<&'a Value>, segment: &Segment) -> Vec<&'a Value> {
+    values
+        .into_iter()
+        .flat_map(|value| -> Vec<&'a Value> {
+            match (segment, value) {
+                (Segment::Key(key), Value::Object(map)) => map.get(key).into_iter().collect(),
+                // 점 경로로 배열 인덱스를 쓴 경우 (items.0)
+                (Segment::Key(key), Value::Array(items)) => key
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|i| items.get(i))
+                    .into_iter()
+                    .collect(),
+                (Segment::Index(index), Value::Array(items)) => {
+                    let i = if *index < 0 {
+                        items.len() as i64 + index
+                    } else {
+                        *index
+                    };
+                    usize::try_from(i)
+                        .ok()
+                        .and_then(|i| items.get(i))
+                        .into_iter()
+                        .collect()
+                }
+                (Segment::Wildcard, Value::Object(map)) => map.values().collect(),
+                (Segment::Wildcard, Value::Array(items)) => items.iter().collect(),
+                _ => Vec::new(),
+            }
+        })
+        .collect()
+}
+
+// 와일드카드가 없으면 값 하나 (없으면 null), 있으면 일치한 값 배열
+pub fn evaluate(context: &Value, segments: &[Segment]) -> Value {
+    let matches = segments
+        .iter()
+        .fold(vec![context], |values, segment| select(values, segment));
+    if segments.contains(&Segment::Wildcard) {
+        Value::Array(matches.into_iter().cloned().collect())
+    } else {
+        matches.first().map(|v| (*v).clone()).unwrap_or(Value::Null)
+    }
+}
+
+// 노드 출력 문자열 → JSON 이면 파싱한 값
+pub fn output_value(output: Option<&str>) -> Value {
+    match output {
+        Some(text) => serde_json::from_str(text.trim()).unwrap_or_else(|_| json!(text)),
+        None => Value::Null,
+    }
+}
+
+fn watch_context(outputs: &Map<String, Value>) -> Value {
+    json!({
+        "nodes": outputs,
+        "vars": placeholders::load_variables()
+    })
+}
+
+// 너무 큰 값은 JSON 앞부분만 문자열로 보냄
+fn clip_value(value: Value) -> (Value, bool) {
+    let text = value.to_string();
+    let (preview, truncated) = value_store::preview(&text, MAX_VALUE_CHARS);
+    if truncated {
+        (json!(format!("{}…", preview)), true)
+    } else {
+        (value, false)
+    }
+}
+
+fn evaluate_watch(watch: &mut Watch, context: &Value) -> WatchValue {
+    let (value, truncated) = clip_value(evaluate(context, &watch.segments));
+    let changed = watch.last.as_ref() != Some(&value);
+    watch.last = Some(value.clone());
+    WatchValue {
+        watch_id: watch.watch_id.clone(),
+        expression: watch.expression.clone(),
+        value,
+        truncated,
+        changed,
+    }
+}
+
+// 감시식을 처음 등록하면 이미 끝난 노드의 출력은 실행 기록(요약)에서 채움
+fn seed_outputs(run_id: &str) -> Map<String, Value> {
+    run_history::load_run(run_id)
+        .map(|record| {
+            record
+                .nodes
+                .iter()
+                .map(|node| (node.node_id.clone(), output_value(node.output.as_deref())))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// record_node_run 에서 호출: 감시식이 있는 실행이면 모두 다시 계산해 방송
+pub fn on_node_recorded(run_id: &str, node: &run_history::NodeRun) {
+    let values = {
+        let Ok(mut state) = get_state().lock() else {
+            return;
+        };
+        let Some(run) = state.get_mut(run_id) else {
+            return;
+        };
+        run.outputs
+            .insert(node.node_id.clone(), output_value(node.output.as_deref()));
+        let context = watch_context(&run.outputs);
+        run.watches
+            .iter_mut()
+            .map(|watch| evaluate_watch(watch, &context))
+            .collect::<Vec<_>>()
+    };
+    let _ = run_history::publish_run_event(json!({
+        "event": "watch_values",
+        "run_id": run_id,
+        "node_id": node.node_id,
+        "values": values
+    }));
+}
+
+// finish_run 에서 호출
+pub fn forget_run(run_id: &str) {
+    if let Ok(mut state) = get_state().lock() {
+        state.remove(run_id);
+    }
+}
+
+// 등록 직후 현재 값을 바로 계산해 반환
+#[tauri::command]
+pub fn add_watch(run_id: String, expression: String) -> Result<WatchValue, String> {
+    run_history::validate_run_id(&run_id)?;
+    let segments = parse_path(&expression)?;
+    let seeded = {
+        let state = get_state().lock().map_err(|e| e.to_string())?;
+        (!state.contains_key(&run_id)).then(|| seed_outputs(&run_id))
+    };
+    let mut state = get_state().lock().map_err(|e| e.to_string())?;
+    let run = state.entry(run_id.clone()).or_insert_with(|| RunWatches {
+        outputs: seeded.unwrap_or_default(),
+        ..Default::default()
+    });
+    if run.watches.len() >= MAX_WATCHES {
+        return Err(format!("TOO_MANY_WATCHES: 최대 {}개", MAX_WATCHES));
+    }
+    run.next_id += 1;
+    let mut watch = Watch {
+        watch_id: format!("watch-{}", run.next_id),
+        expression: expression.trim().to_string(),
+        segments,
+        last: None,
+    };
+    let value = evaluate_watch(&mut watch, &watch_context(&run.outputs));
+    run.watches.push(watch);
+    println!("👀 감시식 등록: {} {}", run_id, value.expression);
+    Ok(value)
+}
+
+#[tauri::command]
+pub fn remove_watch(run_id: String, watch_id: String) -> Result<String, String> {
+    let mut state = get_state().lock().map_err(|e| e.to_string())?;
+    let run = state
+        .get_mut(&run_id)
+        .ok_or_else(|| format!("WATCH_NOT_FOUND: {}", watch_id))?;
+    let before = run.watches.len();
+    run.watches.retain(|w| w.watch_id != watch_id);
+    if run.watches.len() == before {
+        return Err(format!("WATCH_NOT_FOUND: {}", watch_id));
+    }
+    if run.watches.is_empty() {
+        state.remove(&run_id);
+    }
+    Ok("SUCCESS".to_string())
+}
+
+// 현재 값 (패널을 새로 열었을 때)
+#[tauri::command]
+pub fn list_watches(run_id: String) -> Result<Vec<WatchValue>, String> {
+    let mut state = get_state().lock().map_err(|e| e.to_string())?;
+    let Some(run) = state.get_mut(&run_id) else {
+        return Ok(Vec::new());
+    };
+    let context = watch_context(&run.outputs);
+    Ok(run
+        .watches
+        .iter_mut()
+        .map(|watch| {
+            let mut value = evaluate_watch(watch, &context);
+            value.changed = false;
+            value
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn run_watch_evaluates_jsonpath_over_node_outputs() {
+        let context = json!({
+            "nodes": {
+                "fetch-1": output_value(Some(r#"{"items":[{"title":"a"},{"title":"b"}]}"#)),
+                "echo": output_value(Some("plain text"))
+            },
+            "vars": { "user": { "name": "kim" } }
+        });
+        let eval = |expr: &str| evaluate(&context, &parse_path(expr).unwrap());
+
+        assert_eq!(eval("$.nodes.fetch-1.items[0].title"), json!("a"));
+        assert_eq!(eval("$.nodes['fetch-1'].items[-1].title"), json!("b"));
+        assert_eq!(eval("$.nodes.fetch-1.items[*].title"), json!(["a", "b"]));
+        assert_eq!(eval("nodes.echo"), json!("plain text"));
+        assert_eq!(eval("$.vars.user.name"), json!("kim"));
+        assert_eq!(eval("$.nodes.missing.value"), serde_json::Value::Null);
+
+        assert!(parse_path("$.nodes[abc]").is_err());
+        assert!(parse_path("$..nodes").is_err());
+        assert!(parse_path("").is_err());
+    }
+}
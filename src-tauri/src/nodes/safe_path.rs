@@ -0,0 +1,272 @@
+// src-tauri/src/nodes/safe_path.rs
+use std::path::{Component, Path, PathBuf};
+use unicode_normalization::UnicodeNormalization;
+
+// 🧭 파일 경로 공용 처리 (모든 파일 노드)
+// - 유니코드 정규화: 새로 만드는 이름은 NFC 로 통일
+//   (macOS 에서 넘어온 NFD 한글 파일명이 Windows 에서 자모 분리되어 보이는 문제)
+//   이미 있는 파일은 입력 그대로 → NFC → NFD 순으로 찾아 실제 이름을 사용
+// - Windows 예약 장치 이름(CON, NUL, COM1 …)과 금지 문자를 파일 생성 전에 거부
+//   (그대로 쓰면 오류 없이 장치로 써져서 파일이 생기지 않음)
+// - Windows 에서 긴 경로는 \\?\ 확장 접두사를 붙여 MAX_PATH(260자) 제한을 넘김
+
+const FORBIDDEN_CHARS: &str = "<>:\"/\\|?*";
+// MAX_PATH 260 에서 파일 이름 변경(.part 등) 여유를 뺀 길이
+const LONG_PATH_THRESHOLD: usize = 240;
+// 대부분 파일 시스템의 이름 길이 제한 (바이트)
+const MAX_NAME_BYTES: usize = 255;
+
+pub fn nfc(text: &str) -> String {
+    text.nfc().collect()
+}
+
+pub fn nfd(text: &str) -> String {
+    text.nfd().collect()
+}
+
+// 확장자와 뒤쪽 공백/점은 무시하고 비교 ("con.txt", "NUL " 도 예약 이름)
+pub fn is_reserved_name(name: &str) -> bool {
+    let stem = name.split('.').next().unwrap_or("");
+    let stem = stem.trim_end_matches([' ', '.']).to_ascii_uppercase();
+    match stem.as_str() {
+        "CON" | "PRN" | "AUX" | "NUL" | "CONIN$" | "CONOUT$" => true,
+        _ => {
+            let (prefix, digit) = (stem.get(..3), stem.get(3..));
+            matches!(prefix, Some("COM" | "LPT"))
+                && matches!(
+                    digit,
+                    Some("1" | "2" | "3" | "4" | "5" | "6" | "7" | "8" | "9" | "¹" | "²" | "³")
+                )
+        }
+    }
+}
+
+// 새로 만들 파일/폴더 이름 검증
+pub fn validate_file_name(name: &str) -> Result<(), String> {
+    if name.is_empty() || name == "." || name == ".." {
+        return Err(format!("INVALID_FILENAME: {}", name));
+    }
+    if let Some(c) = name
+        .chars()
+        .find(|c| FORBIDDEN_CHARS.contains(*c) || c.is_control())
+    {
+        return Err(format!(
+            "INVALID_FILENAME: '{}' 문자는 쓸 수 없습니다 ({})",
+            c.escape_default(),
+            name
+        ));
+    }
+    if name.ends_with(' ') || name.ends_with('.') {
+        return Err(format!(
+            "INVALID_FILENAME: 이름이 공백이나 점으로 끝납니다 ({})",
+            name
+        ));
+    }
+    if is_reserved_name(name) {
+        return Err(format!("RESERVED_FILENAME: {}", name));
+    }
+    if name.len() > MAX_NAME_BYTES {
+        return Err(format!("FILENAME_TOO_LONG: {}바이트", name.len()));
+    }
+    Ok(())
+}
+
+// 쓸 수 없는 문자는 '_' 로, 예약 이름은 앞에 '_' 를 붙여 항상 쓸 수 있는 이름으로
+pub fn sanitize_file_name(name: &str, fallback: &str) -> String {
+    let replaced: String = nfc(name)
+        .chars()
+        .map(|c| {
+            if FORBIDDEN_CHARS.contains(c) || c.is_control() {
+                '_'
+            } else {
+                c
+            }
+        })
+        .collect();
+    let mut cleaned = replaced.trim().trim_end_matches(['.', ' ']).to_string();
+    if cleaned.is_empty() {
+        return fallback.to_string();
+    }
+    if is_reserved_name(&cleaned) {
+        cleaned.insert(0, '_');
+    }
+    truncate_name(&cleaned, MAX_NAME_BYTES)
+}
+
+// 확장자를 살리면서 UTF-8 경계에 맞춰 바이트 길이 제한
+fn truncate_name(name: &str, max_bytes: usize) -> String {
+    if name.len() <= max_bytes {
+        return name.to_string();
+    }
+    let ext = Path::new(name)
+        .extension()
+        .map(|e| format!(".{}", e.to_string_lossy()))
+        .filter(|e| e.len() < max_bytes / 2)
+        .unwrap_or_default();
+    let mut stem = name[..name.len() - ext.len()].to_string();
+    while stem.len() + ext.len() > max_bytes {
+        stem.pop();
+    }
+    format!("{}{}", stem.trim_end_matches(['.', ' ']), ext)
+}
+
+// Windows 절대 경로 문자열 → \\?\ 확장 경로 (이미 접두사가 있거나 상대 경로면 그대로)
+// 확장 경로는 '.', '..', '/' 를 해석하지 않으므로 여기서 정리
+pub fn to_extended(path: &str) -> String {
+    if path.starts_with(r"\\?\") || path.starts_with(r"\\.\") {
+        return path.to_string();
+    }
+    let unified = path.replace('/', "\\");
+    let (prefix, rest) = if let Some(unc) = unified.strip_prefix(r"\\") {
+        (r"\\?\UNC\".to_string(), unc.to_string())
+    } else {
+        let bytes = unified.as_bytes();
+        let is_drive = bytes.len() >= 3
+            && bytes[0].is_ascii_alphabetic()
+            && bytes[1] == b':'
+            && bytes[2] == b'\\';
+        if !is_drive {
+            return path.to_string();
+        }
+        (format!(r"\\?\{}\", &unified[..2]), unified[3..].to_string())
+    };
+
+    let mut parts: Vec<&str> = Vec::new();
+    for part in rest.split('\\') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                parts.pop();
+            }
+            _ => parts.push(part),
+        }
+    }
+    format!("{}{}", prefix, parts.join("\\"))
+}
+
+// 실제 파일 시스템 호출에 쓸 경로 (Windows 에서 긴 경로만 확장 접두사)
+pub fn extended(path: &Path) -> PathBuf {
+    if !cfg!(windows) || path.as_os_str().len() < LONG_PATH_THRESHOLD {
+        return path.to_path_buf();
+    }
+    let absolute = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        match std::env::current_dir() {
+            Ok(dir) => dir.join(path),
+            Err(_) => return path.to_path_buf(),
+        }
+    };
+    PathBuf::from(to_extended(&absolute.to_string_lossy()))
+}
+
+// 새로 만들 파일 경로: NFC 로 통일하고 마지막 이름을 검증
+// (Windows 에서는 중간 폴더 이름의 예약어도 확인)
+pub fn prepare_new(path: &str) -> Result<PathBuf, String> {
+    let normalized = PathBuf::from(nfc(path.trim()));
+    let name = normalized
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .ok_or_else(|| format!("INVALID_FILENAME: {}", path))?;
+    validate_file_name(&name)?;
+    if cfg!(windows) {
+        for component in normalized.components() {
+            if let Component::Normal(part) = component {
+                let part = part.to_string_lossy();
+                if is_reserved_name(&part) {
+                    return Err(format!("RESERVED_FILENAME: {}", part));
+                }
+            }
+        }
+    }
+    Ok(extended(&normalized))
+}
+
+// 이미 있는 파일 경로: 입력 그대로 → NFC → NFD 중 실제로 있는 것
+pub fn resolve_existing(path: &str) -> PathBuf {
+    let trimmed = path.trim();
+    [trimmed.to_string(), nfc(trimmed), nfd(trimmed)]
+        .into_iter()
+        .map(|candidate| extended(Path::new(&candidate)))
+        .find(|candidate| candidate.exists())
+        .unwrap_or_else(|| extended(Path::new(trimmed)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::file_creator_node;
+    use crate::nodes::test_support::{path_str, temp_dir};
+    use std::fs;
+
+    #[test]
+    fn safe_path_rejects_reserved_and_invalid_names() {
+        for name in ["CON", "con.txt", "NUL ", "COM1.log", "lpt9"] {
+            assert!(is_reserved_name(name), "{}", name);
+        }
+        for name in ["CONSOLE", "COM0", "보고서", "auxiliary.txt"] {
+            assert!(!is_reserved_name(name), "{}", name);
+        }
+        assert!(validate_file_name("report.txt").is_ok());
+        assert!(validate_file_name("a:b.txt")
+            .unwrap_err()
+            .starts_with("INVALID_FILENAME"));
+        assert!(validate_file_name("name.").is_err());
+        assert!(validate_file_name("aux.json")
+            .unwrap_err()
+            .starts_with("RESERVED_FILENAME"));
+    }
+
+    #[test]
+    fn safe_path_sanitizes_and_normalizes_names() {
+        // NFD(자모 분리) 한글 → NFC
+        let decomposed = nfd("한글.txt");
+        assert_ne!(decomposed, "한글.txt");
+        assert_eq!(sanitize_file_name(&decomposed, "x"), "한글.txt");
+        assert_eq!(sanitize_file_name("CON", "x"), "_CON");
+        assert_eq!(sanitize_file_name(" a|b. ", "x"), "a_b");
+        assert_eq!(sanitize_file_name("...", "x"), "x");
+
+        let long = format!("{}.mp4", "가".repeat(100));
+        let truncated = sanitize_file_name(&long, "x");
+        assert!(truncated.len() <= 255);
+        assert!(truncated.ends_with("가.mp4"));
+    }
+
+    #[test]
+    fn safe_path_builds_extended_windows_paths() {
+        assert_eq!(
+            to_extended(r"C:\data\.\영상\..\clips/a.mp4"),
+            r"\\?\C:\data\clips\a.mp4"
+        );
+        assert_eq!(
+            to_extended(r"\\nas\share\a.txt"),
+            r"\\?\UNC\nas\share\a.txt"
+        );
+        assert_eq!(to_extended(r"\\?\C:\x"), r"\\?\C:\x");
+        assert_eq!(to_extended("relative/a.txt"), "relative/a.txt");
+    }
+
+    #[test]
+    fn safe_path_finds_existing_nfd_file_names() {
+        let dir = temp_dir();
+        let decomposed = nfd("사진.txt");
+        fs::write(dir.path().join(&decomposed), "x").unwrap();
+
+        let requested = path_str(&dir.path().join("사진.txt"));
+        let resolved = resolve_existing(&requested);
+        assert!(resolved.exists());
+    }
+
+    #[test]
+    fn file_creator_rejects_reserved_names() {
+        let dir = temp_dir();
+        let result = file_creator_node(
+            path_str(dir.path()),
+            "nul.txt".to_string(),
+            "x".to_string(),
+            None,
+        );
+        assert!(result.unwrap_err().starts_with("RESERVED_FILENAME"));
+    }
+}
@@ -0,0 +1,594 @@
+// src-tauri/src/nodes/schedule_conditions.rs
+use super::{atomic_store, http_client};
+use chrono::{DateTime, Datelike, Local, NaiveDate, TimeZone, Timelike, Utc, Weekday};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+// 📅 예약 실행 조건
+// 예약된 시각이 되어도 주말 / 공휴일이면 실행을 건너뜀 (업무 시간 자동화용)
+// - 공휴일: holiday_country(ISO 국가 코드, Nager.Date 공휴일 API, 연도별 캐시) 와
+//   holiday_ics(사용자 ICS 파일 경로 또는 URL, 회사 휴무일 등) 를 합쳐서 판단
+// - 시간대: time_window("09:00"~"18:00", 끝이 시작보다 이르면 자정을 넘는 구간)
+// - 일출/일몰: 좌표 기준 오늘 일출/일몰 ± offset 이후(after) / 이전(before) 에만 실행 (조명 자동화 등)
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScheduleConditions {
+    pub skip_weekends: bool,
+    pub skip_holidays: bool,
+    // "KR", "US", "JP" ...
+    pub holiday_country: Option<String>,
+    pub holiday_ics: Option<String>,
+    pub time_window: Option<TimeWindow>,
+    pub sun: Option<SunWindow>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeWindow {
+    pub start: String, // "HH:MM"
+    pub end: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SunEvent {
+    pub event: String, // "sunrise" | "sunset"
+    #[serde(default)]
+    pub offset_minutes: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SunWindow {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub after: Option<SunEvent>,
+    pub before: Option<SunEvent>,
+}
+
+const HOLIDAY_API_URL: &str = "https://date.nager.at/api/v3/PublicHolidays";
+
+fn holiday_cache_path(country: &str, year: i32) -> PathBuf {
+    atomic_store::store_dir()
+        .join("holidays")
+        .join(format!("{}-{}.json", country, year))
+}
+
+fn parse_ics_date(value: &str) -> Option<NaiveDate> {
+    let digits = value.get(..8)?;
+    NaiveDate::parse_from_str(digits, "%Y%m%d").ok()
+}
+
+// 접힌 줄(공백으로 시작) 이어붙이기
+fn unfold_lines(content: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in content.lines() {
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ => lines.push(line.trim_end().to_string()),
+        }
+    }
+    lines
+}
+
+// ICS 의 종일 일정 → 해당 연도의 날짜별 이름
+// DTEND 는 다음 날(배타적)이므로 여러 날 일정은 전부 포함, RRULE:FREQ=YEARLY 는 매년 반복
+pub fn ics_holidays(content: &str, year: i32) -> BTreeMap<NaiveDate, String> {
+    let mut holidays = BTreeMap::new();
+    let mut start: Option<NaiveDate> = None;
+    let mut end: Option<NaiveDate> = None;
+    let mut summary = String::new();
+    let mut yearly = false;
+
+    for line in unfold_lines(content) {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let name = key.split(';').next().unwrap_or("").to_uppercase();
+        match name.as_str() {
+            "BEGIN" if value.eq_ignore_ascii_case("VEVENT") => {
+                start = None;
+                end = None;
+                summary.clear();
+                yearly = false;
+            }
+            "DTSTART" => start = parse_ics_date(value),
+            "DTEND" => end = parse_ics_date(value),
+            "SUMMARY" => summary = value.replace("\\,", ",").replace("\\;", ";"),
+            "RRULE" => yearly = value.to_uppercase().contains("FREQ=YEARLY"),
+            "END" if value.eq_ignore_ascii_case("VEVENT") => {
+                let Some(first) = start else {
+                    continue;
+                };
+                let last = end
+                    .and_then(|e| e.pred_opt())
+                    .filter(|e| *e >= first)
+                    .unwrap_or(first);
+                let shift = if yearly { year - first.year() } else { 0 };
+                let mut day = first;
+                while day <= last {
+                    let shifted = if shift == 0 {
+                        Some(day)
+                    } else {
+                        day.with_year(day.year() + shift)
+                    };
+                    if let Some(date) = shifted.filter(|d| d.year() == year) {
+                        holidays.entry(date).or_insert_with(|| summary.clone());
+                    }
+                    day = match day.succ_opt() {
+                        Some(next) => next,
+                        None => break,
+                    };
+                }
+            }
+            _ => {}
+        }
+    }
+    holidays
+}
+
+async fn country_holidays(country: &str, year: i32) -> Result<BTreeMap<NaiveDate, String>, String> {
+    let country = country.trim().to_uppercase();
+    if country.len() != 2 || !country.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(format!("INVALID_COUNTRY: {}", country));
+    }
+
+    let cache_path = holiday_cache_path(&country, year);
+    let list: Vec<Value> = match atomic_store::read_json_verified(&cache_path) {
+        Some(list) => list,
+        None => {
+            let url = format!("{}/{}/{}", HOLIDAY_API_URL, year, country);
+            let response = http_client::client()
+                .get(&url)
+                .timeout(std::time::Duration::from_secs(15))
+                .send()
+                .await
+                .map_err(|e| format!("공휴일 정보 요청 실패: {}", e))?;
+            if !response.status().is_success() {
+                return Err(format!(
+                    "HOLIDAY_API_ERROR ({})",
+                    response.status().as_u16()
+                ));
+            }
+            let list: Vec<Value> = response
+                .json()
+                .await
+                .map_err(|e| format!("공휴일 응답 파싱 실패: {}", e))?;
+            atomic_store::write_json_atomic(&cache_path, &list)?;
+            println!(
+                "📅 공휴일 정보 저장: {} {} ({}일)",
+                country,
+                year,
+                list.len()
+            );
+            list
+        }
+    };
+
+    Ok(list
+        .iter()
+        // 지역 한정 공휴일(counties 가 있는 항목)은 제외
+        .filter(|h| h["global"].as_bool().unwrap_or(true))
+        .filter_map(|h| {
+            let date = NaiveDate::parse_from_str(h["date"].as_str()?, "%Y-%m-%d").ok()?;
+            let name = h["localName"].as_str().or(h["name"].as_str())?;
+            Some((date, name.to_string()))
+        })
+        .collect())
+}
+
+async fn read_ics(source: &str) -> Result<String, String> {
+    let source = source.trim();
+    if source.starts_with("http://") || source.starts_with("https://") {
+        http_client::client()
+            .get(source)
+            .timeout(std::time::Duration::from_secs(15))
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .map_err(|e| format!("ICS 요청 실패: {}", e))?
+            .text()
+            .await
+            .map_err(|e| format!("ICS 읽기 실패: {}", e))
+    } else {
+        tokio::fs::read_to_string(source)
+            .await
+            .map_err(|e| format!("ICS_NOT_FOUND: {} ({})", source, e))
+    }
+}
+
+pub async fn holidays_for(
+    conditions: &ScheduleConditions,
+    year: i32,
+) -> Result<BTreeMap<NaiveDate, String>, String> {
+    let mut holidays = BTreeMap::new();
+    if let Some(country) = conditions
+        .holiday_country
+        .as_deref()
+        .filter(|c| !c.trim().is_empty())
+    {
+        holidays.extend(country_holidays(country, year).await?);
+    }
+    if let Some(source) = conditions
+        .holiday_ics
+        .as_deref()
+        .filter(|s| !s.trim().is_empty())
+    {
+        holidays.extend(ics_holidays(&read_ics(source).await?, year));
+    }
+    Ok(holidays)
+}
+
+// 건너뛸 이유 (실행해야 하면 None)
+pub fn skip_reason(
+    conditions: &ScheduleConditions,
+    date: NaiveDate,
+    holidays: &BTreeMap<NaiveDate, String>,
+) -> Option<String> {
+    if conditions.skip_weekends && matches!(date.weekday(), Weekday::Sat | Weekday::Sun) {
+        return Some(format!("weekend ({})", date.weekday()));
+    }
+    if conditions.skip_holidays {
+        if let Some(name) = holidays.get(&date) {
+            return Some(format!("holiday ({})", name));
+        }
+    }
+    None
+}
+
+// 일출/일몰 (UTC) - 일출 방정식, 오차 1~2분 / 백야·극야면 None
+pub fn sun_times(
+    date: NaiveDate,
+    latitude: f64,
+    longitude: f64,
+) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+    let day = date
+        .signed_duration_since(NaiveDate::from_ymd_opt(1970, 1, 1)?)
+        .num_days();
+    let n = (day as f64 + 2440588.0 - 2451545.0).round();
+    let mean_solar_noon = n - longitude / 360.0;
+    let anomaly = (357.5291 + 0.98560028 * mean_solar_noon)
+        .rem_euclid(360.0)
+        .to_radians();
+    let center =
+        1.9148 * anomaly.sin() + 0.0200 * (2.0 * anomaly).sin() + 0.0003 * (3.0 * anomaly).sin();
+    let ecliptic = (anomaly.to_degrees() + center + 180.0 + 102.9372)
+        .rem_euclid(360.0)
+        .to_radians();
+    let transit =
+        2451545.0 + mean_solar_noon + 0.0053 * anomaly.sin() - 0.0069 * (2.0 * ecliptic).sin();
+    let declination = (ecliptic.sin() * 23.4397f64.to_radians().sin()).asin();
+
+    let lat = latitude.to_radians();
+    let cos_hour = ((-0.833f64).to_radians().sin() - lat.sin() * declination.sin())
+        / (lat.cos() * declination.cos());
+    if !(-1.0..=1.0).contains(&cos_hour) {
+        return None;
+    }
+    let hour = cos_hour.acos().to_degrees() / 360.0;
+    let to_utc = |jd: f64| {
+        Utc.timestamp_opt(((jd - 2440587.5) * 86400.0).round() as i64, 0)
+            .single()
+    };
+    Some((to_utc(transit - hour)?, to_utc(transit + hour)?))
+}
+
+fn minutes_of_day(hour: u32, minute: u32) -> u32 {
+    hour * 60 + minute
+}
+
+fn parse_hhmm(time: &str) -> Result<u32, String> {
+    let (hour, minute) = time
+        .trim()
+        .split_once(':')
+        .and_then(|(h, m)| Some((h.parse::<u32>().ok()?, m.parse::<u32>().ok()?)))
+        .filter(|(h, m)| *h < 24 && *m < 60)
+        .ok_or_else(|| format!("INVALID_TIME: {}", time))?;
+    Ok(minutes_of_day(hour, minute))
+}
+
+// start <= now < end, start > end 이면 자정을 넘는 구간 (예: 22:00~06:00)
+pub fn in_window(now: u32, start: u32, end: u32) -> bool {
+    if start <= end {
+        (start..end).contains(&now)
+    } else {
+        now >= start || now < end
+    }
+}
+
+fn sun_event_minutes<Tz: TimeZone>(
+    sun: &SunWindow,
+    event: &SunEvent,
+    now: &DateTime<Tz>,
+) -> Result<u32, String> {
+    let (sunrise, sunset) = sun_times(now.date_naive(), sun.latitude, sun.longitude)
+        .ok_or("SUN_EVENT_UNAVAILABLE: 오늘은 일출/일몰이 없습니다 (백야/극야)")?;
+    let base = match event.event.trim().to_lowercase().as_str() {
+        "sunrise" => sunrise,
+        "sunset" => sunset,
+        other => return Err(format!("INVALID_SUN_EVENT: {}", other)),
+    };
+    let local =
+        (base + chrono::Duration::minutes(event.offset_minutes)).with_timezone(&now.timezone());
+    Ok(minutes_of_day(local.hour(), local.minute()))
+}
+
+// 시간 조건으로 건너뛸 이유 (None 이면 실행)
+pub fn time_skip_reason<Tz: TimeZone>(
+    conditions: &ScheduleConditions,
+    now: &DateTime<Tz>,
+) -> Result<Option<String>, String> {
+    let current = minutes_of_day(now.hour(), now.minute());
+    let format = |minutes: u32| format!("{:02}:{:02}", minutes / 60, minutes % 60);
+
+    if let Some(window) = &conditions.time_window {
+        let (start, end) = (parse_hhmm(&window.start)?, parse_hhmm(&window.end)?);
+        if !in_window(current, start, end) {
+            return Ok(Some(format!(
+                "outside time window ({}~{})",
+                format(start),
+                format(end)
+            )));
+        }
+    }
+
+    if let Some(sun) = &conditions.sun {
+        if !(-90.0..=90.0).contains(&sun.latitude) || !(-180.0..=180.0).contains(&sun.longitude) {
+            return Err("INVALID_COORDINATES".to_string());
+        }
+        let after = sun
+            .after
+            .as_ref()
+            .map(|e| sun_event_minutes(sun, e, now))
+            .transpose()?;
+        let before = sun
+            .before
+            .as_ref()
+            .map(|e| sun_event_minutes(sun, e, now))
+            .transpose()?;
+        let allowed = match (after, before) {
+            (Some(after), Some(before)) => in_window(current, after, before),
+            (Some(after), None) => current >= after,
+            (None, Some(before)) => current < before,
+            (None, None) => true,
+        };
+        if !allowed {
+            return Ok(Some(format!(
+                "outside sun window ({}~{})",
+                after.map(format).unwrap_or_else(|| "00:00".to_string()),
+                before.map(format).unwrap_or_else(|| "24:00".to_string())
+            )));
+        }
+    }
+    Ok(None)
+}
+
+pub async fn check(
+    conditions: &ScheduleConditions,
+    now: DateTime<Local>,
+) -> Result<Option<String>, String> {
+    if let Some(reason) = time_skip_reason(conditions, &now)? {
+        return Ok(Some(reason));
+    }
+    let date = now.date_naive();
+    let holidays = if conditions.skip_holidays {
+        holidays_for(conditions, date.year()).await?
+    } else {
+        BTreeMap::new()
+    };
+    Ok(skip_reason(conditions, date, &holidays))
+}
+
+// UI 미리보기 / 트리거 조건: 주어진 날짜·시각(기본 지금)에 실행되는지
+#[tauri::command]
+pub async fn check_schedule_conditions(
+    conditions: ScheduleConditions,
+    date: Option<String>,
+    time: Option<String>,
+) -> Result<String, String> {
+    let now = Local::now();
+    let date = match date.as_deref().map(str::trim).filter(|d| !d.is_empty()) {
+        Some(date) => NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .map_err(|_| format!("INVALID_DATE: {}", date))?,
+        None => now.date_naive(),
+    };
+    let minutes = match time.as_deref().map(str::trim).filter(|t| !t.is_empty()) {
+        Some(time) => parse_hhmm(time)?,
+        None => minutes_of_day(now.hour(), now.minute()),
+    };
+    let at = date
+        .and_hms_opt(minutes / 60, minutes % 60, 0)
+        .and_then(|naive| Local.from_local_datetime(&naive).earliest())
+        .ok_or("INVALID_DATE")?;
+
+    let reason = check(&conditions, at).await?;
+    Ok(json!({
+        "date": date.to_string(),
+        "time": at.format("%H:%M").to_string(),
+        "run": reason.is_none(),
+        "skip_reason": reason
+    })
+    .to_string())
+}
+
+#[tauri::command]
+pub fn get_sun_times(
+    latitude: f64,
+    longitude: f64,
+    date: Option<String>,
+) -> Result<String, String> {
+    let date = match date.as_deref().map(str::trim).filter(|d| !d.is_empty()) {
+        Some(date) => NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .map_err(|_| format!("INVALID_DATE: {}", date))?,
+        None => Local::now().date_naive(),
+    };
+    let times = sun_times(date, latitude, longitude);
+    let local = |t: DateTime<Utc>| t.with_timezone(&Local).format("%H:%M").to_string();
+    Ok(json!({
+        "date": date.to_string(),
+        "sunrise": times.map(|(rise, _)| local(rise)),
+        "sunset": times.map(|(_, set)| local(set)),
+    })
+    .to_string())
+}
+
+#[tauri::command]
+pub async fn list_holidays(
+    conditions: ScheduleConditions,
+    year: Option<i32>,
+) -> Result<String, String> {
+    let year = year.unwrap_or_else(|| Local::now().year());
+    let holidays = holidays_for(&conditions, year).await?;
+    let list: Vec<Value> = holidays
+        .iter()
+        .map(|(date, name)| json!({ "date": date.to_string(), "name": name }))
+        .collect();
+    Ok(json!({ "year": year, "holidays": list }).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schedule_conditions_reads_ics_holidays() {
+        use chrono::NaiveDate;
+        let ics = "BEGIN:VCALENDAR\r\n\
+BEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20200101\r\nDTEND;VALUE=DATE:20200102\r\nRRULE:FREQ=YEARLY\r\nSUMMARY:신정\r\nEND:VEVENT\r\n\
+BEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20261224\r\nDTEND;VALUE=DATE:20261227\r\nSUMMARY:회사 휴무\\, 연말\r\nEND:VEVENT\r\n\
+BEGIN:VEVENT\r\nDTSTART:20250505T000000Z\r\nSUMMARY:작년 일정\r\nEND:VEVENT\r\n\
+END:VCALENDAR\r\n";
+        let date = |m, d| NaiveDate::from_ymd_opt(2026, m, d).unwrap();
+
+        let holidays = ics_holidays(ics, 2026);
+        assert_eq!(holidays.get(&date(1, 1)).map(String::as_str), Some("신정"));
+        assert_eq!(
+            holidays.get(&date(12, 24)).map(String::as_str),
+            Some("회사 휴무, 연말")
+        );
+        assert!(holidays.contains_key(&date(12, 26)));
+        assert!(!holidays.contains_key(&date(12, 27)));
+        assert_eq!(holidays.len(), 4);
+    }
+
+    #[test]
+    fn schedule_conditions_skips_weekends_and_holidays() {
+        use chrono::NaiveDate;
+        let conditions = ScheduleConditions {
+            skip_weekends: true,
+            skip_holidays: true,
+            ..Default::default()
+        };
+        let mut holidays = std::collections::BTreeMap::new();
+        holidays.insert(
+            NaiveDate::from_ymd_opt(2026, 10, 9).unwrap(),
+            "한글날".to_string(),
+        );
+
+        let friday = NaiveDate::from_ymd_opt(2026, 10, 9).unwrap();
+        let saturday = NaiveDate::from_ymd_opt(2026, 10, 10).unwrap();
+        let monday = NaiveDate::from_ymd_opt(2026, 10, 12).unwrap();
+        assert_eq!(
+            skip_reason(&conditions, friday, &holidays).as_deref(),
+            Some("holiday (한글날)")
+        );
+        assert!(
+            skip_reason(&conditions, saturday, &holidays)
+                .unwrap()
+                .starts_with("weekend")
+        );
+        assert_eq!(
+            skip_reason(&conditions, monday, &holidays),
+            None
+        );
+
+        let weekends_only = ScheduleConditions {
+            skip_weekends: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            skip_reason(&weekends_only, friday, &holidays),
+            None
+        );
+    }
+
+    #[test]
+    fn schedule_conditions_computes_sun_times() {
+        use chrono::{FixedOffset, NaiveDate, Timelike};
+        let kst = FixedOffset::east_opt(9 * 3600).unwrap();
+        let date = NaiveDate::from_ymd_opt(2026, 6, 21).unwrap();
+
+        let (sunrise, sunset) = sun_times(date, 37.5665, 126.9780).unwrap();
+        let minutes = |t: chrono::DateTime<chrono::Utc>| {
+            let local = t.with_timezone(&kst);
+            local.hour() * 60 + local.minute()
+        };
+        // 서울 하지: 일출 05:11, 일몰 19:57 무렵
+        assert!((minutes(sunrise) as i32 - (5 * 60 + 11)).abs() <= 3);
+        assert!((minutes(sunset) as i32 - (19 * 60 + 57)).abs() <= 3);
+
+        // 트롬쇠 하지는 백야
+        assert!(sun_times(date, 69.65, 18.96).is_none());
+    }
+
+    #[test]
+    fn schedule_conditions_checks_time_and_sun_windows() {
+        use chrono::{FixedOffset, TimeZone};
+        let kst = FixedOffset::east_opt(9 * 3600).unwrap();
+        let at = |h, m| kst.with_ymd_and_hms(2026, 6, 21, h, m, 0).unwrap();
+
+        assert!(in_window(600, 540, 1080));
+        assert!(!in_window(1080, 540, 1080));
+        assert!(in_window(60, 1320, 360));
+
+        let business_hours = ScheduleConditions {
+            time_window: Some(TimeWindow {
+                start: "09:00".to_string(),
+                end: "18:00".to_string(),
+            }),
+            ..Default::default()
+        };
+        assert_eq!(
+            time_skip_reason(&business_hours, &at(9, 0)).unwrap(),
+            None
+        );
+        assert_eq!(
+            time_skip_reason(&business_hours, &at(8, 59))
+                .unwrap()
+                .as_deref(),
+            Some("outside time window (09:00~18:00)")
+        );
+
+        // 일몰 30분 후 ~ 일출 전 (자정을 넘는 구간)
+        let evening = ScheduleConditions {
+            sun: Some(SunWindow {
+                latitude: 37.5665,
+                longitude: 126.9780,
+                after: Some(SunEvent {
+                    event: "sunset".to_string(),
+                    offset_minutes: 30,
+                }),
+                before: Some(SunEvent {
+                    event: "sunrise".to_string(),
+                    offset_minutes: 0,
+                }),
+            }),
+            ..Default::default()
+        };
+        assert_eq!(
+            time_skip_reason(&evening, &at(21, 0)).unwrap(),
+            None
+        );
+        assert_eq!(
+            time_skip_reason(&evening, &at(2, 0)).unwrap(),
+            None
+        );
+        assert!(time_skip_reason(&evening, &at(20, 0))
+            .unwrap()
+            .unwrap()
+            .starts_with("outside sun window"));
+        assert!(time_skip_reason(&evening, &at(12, 0))
+            .unwrap()
+            .is_some());
+    }
+}
@@ -0,0 +1,138 @@
+// src-tauri/src/nodes/secrets.rs
+use super::{atomic_store, profiles};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+// 🔐 비밀값 관리 (OS 키체인: Windows 자격 증명 관리자 / macOS 키체인 / Linux Secret Service)
+// 값은 키체인에만 저장하고, 이름 목록만 store/secrets_index.json 에 기록
+// 값은 프론트엔드로 돌려주지 않고 백엔드 노드(totp_node 등)에서만 읽음
+
+const KEYRING_SERVICE: &str = "automation-gui";
+// 앱 내부 키 (워크플로우 서명 키 등): 목록에도 없고 ${secret:이름} 으로도 꺼낼 수 없는 별도 서비스
+const INTERNAL_KEYRING_SERVICE: &str = "automation-gui-internal";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecretInfo {
+    pub name: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+fn index_path() -> PathBuf {
+    atomic_store::store_dir().join("secrets_index.json")
+}
+
+fn load_index() -> Vec<SecretInfo> {
+    atomic_store::read_json_verified(&index_path()).unwrap_or_default()
+}
+
+fn validate_name(name: &str) -> Result<&str, String> {
+    let name = name.trim();
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.'))
+    {
+        return Err(format!("INVALID_SECRET_NAME: {}", name));
+    }
+    Ok(name)
+}
+
+fn entry(name: &str) -> Result<keyring::Entry, String> {
+    keyring::Entry::new(&profiles::keyring_service(KEYRING_SERVICE), name)
+        .map_err(|e| format!("키체인 접근 실패: {}", e))
+}
+
+fn internal_entry(name: &str) -> Result<keyring::Entry, String> {
+    keyring::Entry::new(&profiles::keyring_service(INTERNAL_KEYRING_SERVICE), name)
+        .map_err(|e| format!("키체인 접근 실패: {}", e))
+}
+
+pub fn set(name: &str, value: &str) -> Result<(), String> {
+    let name = validate_name(name)?;
+    entry(name)?
+        .set_password(value)
+        .map_err(|e| format!("비밀값 저장 실패: {}", e))?;
+
+    let now = chrono::Utc::now().timestamp();
+    let mut index = load_index();
+    match index.iter_mut().find(|s| s.name == name) {
+        Some(info) => info.updated_at = now,
+        None => index.push(SecretInfo {
+            name: name.to_string(),
+            created_at: now,
+            updated_at: now,
+        }),
+    }
+    atomic_store::write_json_atomic(&index_path(), &index)
+}
+
+pub fn get(name: &str) -> Result<String, String> {
+    let name = validate_name(name)?;
+    entry(name)?.get_password().map_err(|e| match e {
+        keyring::Error::NoEntry => format!("SECRET_NOT_FOUND: {}", name),
+        other => format!("비밀값 읽기 실패: {}", other),
+    })
+}
+
+pub fn delete(name: &str) -> Result<(), String> {
+    let name = validate_name(name)?;
+    match entry(name)?.delete_password() {
+        Ok(()) | Err(keyring::Error::NoEntry) => {}
+        Err(e) => return Err(format!("비밀값 삭제 실패: {}", e)),
+    }
+
+    let mut index = load_index();
+    index.retain(|s| s.name != name);
+    atomic_store::write_json_atomic(&index_path(), &index)
+}
+
+pub(crate) fn get_internal(name: &str) -> Result<String, String> {
+    internal_entry(name)?.get_password().map_err(|e| match e {
+        keyring::Error::NoEntry => format!("SECRET_NOT_FOUND: {}", name),
+        other => format!("비밀값 읽기 실패: {}", other),
+    })
+}
+
+pub(crate) fn set_internal(name: &str, value: &str) -> Result<(), String> {
+    internal_entry(name)?
+        .set_password(value)
+        .map_err(|e| format!("비밀값 저장 실패: {}", e))
+}
+
+// 키체인 동작 확인용 (목록에 남기지 않음)
+pub fn probe(name: &str) -> Result<(), String> {
+    let entry = entry(validate_name(name)?)?;
+    let value = chrono::Utc::now().timestamp_millis().to_string();
+    entry
+        .set_password(&value)
+        .map_err(|e| format!("키체인 쓰기 실패: {}", e))?;
+    let read = entry
+        .get_password()
+        .map_err(|e| format!("키체인 읽기 실패: {}", e));
+    let _ = entry.delete_password();
+    if read? != value {
+        return Err("키체인 값 불일치".to_string());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn set_secret(name: String, value: String) -> Result<String, String> {
+    set(&name, &value)?;
+    println!("🔐 비밀값 저장: {}", name.trim());
+    Ok("SUCCESS".to_string())
+}
+
+#[tauri::command]
+pub fn delete_secret(name: String) -> Result<String, String> {
+    delete(&name)?;
+    println!("🗑️ 비밀값 삭제: {}", name.trim());
+    Ok("SUCCESS".to_string())
+}
+
+// 이름/시각만 반환 (값은 반환하지 않음)
+#[tauri::command]
+pub fn list_secrets() -> Result<Vec<SecretInfo>, String> {
+    Ok(load_index())
+}
@@ -0,0 +1,220 @@
+// src-tauri/src/nodes/service_node.rs
+use super::os_command;
+use serde_json::{json, Value};
+
+// ⚙️ OS 서비스 관리 노드
+// Windows: sc.exe / Get-Service, Linux: systemctl, macOS: launchctl
+// action: query | start | stop | restart
+// 권한이 부족하면 ELEVATION_REQUIRED 에러 (관리자 권한으로 실행하거나 elevated 옵션 사용)
+
+fn is_permission_error(message: &str) -> bool {
+    let lower = message.to_lowercase();
+    [
+        "failed 5:",
+        "access is denied",
+        "액세스가 거부",
+        "access denied",
+        "interactive authentication required",
+        "permission denied",
+        "operation not permitted",
+        "not privileged",
+    ]
+    .iter()
+    .any(|pattern| lower.contains(pattern))
+}
+
+fn map_error(message: String) -> String {
+    if is_permission_error(&message) {
+        format!("ELEVATION_REQUIRED: {}", message)
+    } else {
+        message
+    }
+}
+
+// 플랫폼별 상태 문자열을 running / stopped / starting / stopping / unknown 으로 정리
+pub fn normalize_state(raw: &str) -> &'static str {
+    match raw.trim().to_lowercase().as_str() {
+        "running" | "active" | "4" => "running",
+        "stopped" | "inactive" | "failed" | "dead" | "1" => "stopped",
+        "startpending" | "start_pending" | "activating" | "reloading" | "2" => "starting",
+        "stoppending" | "stop_pending" | "deactivating" | "3" => "stopping",
+        _ => "unknown",
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn query_service(name: &str) -> Result<Value, String> {
+    let script = format!(
+        "$s = Get-Service -Name {} -ErrorAction Stop; \
+         $c = Get-CimInstance Win32_Service -Filter \"Name='$($s.Name)'\"; \
+         [PSCustomObject]@{{ name = $s.Name; display_name = $s.DisplayName; state = \"$($s.Status)\"; \
+         start_type = \"$($s.StartType)\"; pid = $c.ProcessId }} | ConvertTo-Json -Compress",
+        os_command::ps_quote(name)
+    );
+    let output = os_command::powershell(&script).map_err(|e| {
+        if e.contains("Cannot find any service") || e.contains("찾을 수 없") {
+            format!("SERVICE_NOT_FOUND: {}", name)
+        } else {
+            e
+        }
+    })?;
+    let info: Value =
+        serde_json::from_str(&output).map_err(|e| format!("서비스 정보 파싱 실패: {}", e))?;
+
+    Ok(json!({
+        "name": info["name"],
+        "display_name": info["display_name"],
+        "state": normalize_state(info["state"].as_str().unwrap_or("")),
+        "raw_state": info["state"],
+        "start_type": info["start_type"],
+        "pid": info["pid"].as_u64().filter(|pid| *pid > 0)
+    }))
+}
+
+#[cfg(target_os = "windows")]
+fn control_service(action: &str, name: &str) -> Result<(), String> {
+    os_command::run("sc.exe", &[action, name]).map(|_| ())
+}
+
+#[cfg(target_os = "macos")]
+fn query_service(name: &str) -> Result<Value, String> {
+    let output = os_command::run("launchctl", &["list"])?;
+    // "PID\tStatus\tLabel" 형식, 실행 중이 아니면 PID 가 "-"
+    let line = output
+        .lines()
+        .skip(1)
+        .find(|line| line.split('\t').nth(2) == Some(name))
+        .ok_or_else(|| format!("SERVICE_NOT_FOUND: {}", name))?;
+    let mut columns = line.split('\t');
+    let pid = columns.next().and_then(|v| v.parse::<u64>().ok());
+    let last_exit = columns.next().and_then(|v| v.parse::<i64>().ok());
+
+    Ok(json!({
+        "name": name,
+        "display_name": name,
+        "state": if pid.is_some() { "running" } else { "stopped" },
+        "raw_state": if pid.is_some() { "running" } else { "not running" },
+        "last_exit_status": last_exit,
+        "pid": pid
+    }))
+}
+
+#[cfg(target_os = "macos")]
+fn control_service(action: &str, name: &str) -> Result<(), String> {
+    match action {
+        "start" => os_command::run("launchctl", &["start", name]).map(|_| ()),
+        _ => os_command::run("launchctl", &["stop", name]).map(|_| ()),
+    }
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn query_service(name: &str) -> Result<Value, String> {
+    let output = os_command::run(
+        "systemctl",
+        &[
+            "show",
+            name,
+            "--no-pager",
+            "--property=Id,Description,LoadState,ActiveState,SubState,UnitFileState,MainPID",
+        ],
+    )?;
+    let property = |key: &str| {
+        output
+            .lines()
+            .find_map(|line| line.strip_prefix(&format!("{}=", key)))
+            .unwrap_or("")
+            .to_string()
+    };
+
+    if property("LoadState") == "not-found" {
+        return Err(format!("SERVICE_NOT_FOUND: {}", name));
+    }
+    let active = property("ActiveState");
+
+    Ok(json!({
+        "name": property("Id"),
+        "display_name": property("Description"),
+        "state": normalize_state(&active),
+        "raw_state": format!("{} ({})", active, property("SubState")),
+        "start_type": property("UnitFileState"),
+        "pid": property("MainPID").parse::<u64>().ok().filter(|pid| *pid > 0)
+    }))
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn control_service(action: &str, name: &str) -> Result<(), String> {
+    os_command::run("systemctl", &[action, name, "--no-ask-password"]).map(|_| ())
+}
+
+// 원하는 상태가 될 때까지 최대 timeout 동안 대기
+fn wait_for_state(name: &str, expected: &str, timeout_secs: u64) -> Result<Value, String> {
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(timeout_secs);
+    loop {
+        let status = query_service(name)?;
+        if status["state"] == expected || std::time::Instant::now() >= deadline {
+            return Ok(status);
+        }
+        std::thread::sleep(std::time::Duration::from_millis(500));
+    }
+}
+
+fn run_action(action: &str, name: &str, timeout_secs: u64) -> Result<Value, String> {
+    match action {
+        "query" | "status" => query_service(name),
+        "start" => {
+            control_service("start", name).map_err(map_error)?;
+            wait_for_state(name, "running", timeout_secs)
+        }
+        "stop" => {
+            control_service("stop", name).map_err(map_error)?;
+            wait_for_state(name, "stopped", timeout_secs)
+        }
+        "restart" => {
+            if query_service(name)?["state"] == "running" {
+                control_service("stop", name).map_err(map_error)?;
+                wait_for_state(name, "stopped", timeout_secs)?;
+            }
+            control_service("start", name).map_err(map_error)?;
+            wait_for_state(name, "running", timeout_secs)
+        }
+        other => Err(format!("UNSUPPORTED_ACTION: {}", other)),
+    }
+}
+
+#[tauri::command]
+pub async fn service_node(
+    action: String,
+    service_name: String,
+    timeout_secs: Option<u64>,
+) -> Result<String, String> {
+    let action = action.trim().to_lowercase();
+    let name = service_name.trim().to_string();
+    if name.is_empty() {
+        return Err("서비스 이름이 비어있습니다".to_string());
+    }
+    println!("⚙️ Service Node: {} {}", action, name);
+
+    let timeout = timeout_secs.unwrap_or(30);
+    let action_for_task = action.clone();
+    let status = tokio::task::spawn_blocking(move || run_action(&action_for_task, &name, timeout))
+        .await
+        .map_err(|e| format!("서비스 작업 실패: {}", e))??;
+
+    println!("✅ 서비스 상태: {} → {}", status["name"], status["state"]);
+    Ok(json!({ "action": action, "service": status }).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn service_states_are_normalized() {
+        assert_eq!(normalize_state("Running"), "running");
+        assert_eq!(normalize_state("active"), "running");
+        assert_eq!(normalize_state("inactive"), "stopped");
+        assert_eq!(normalize_state("StartPending"), "starting");
+        assert_eq!(normalize_state("deactivating"), "stopping");
+        assert_eq!(normalize_state("weird"), "unknown");
+    }
+}
@@ -0,0 +1,282 @@
+// src-tauri/src/nodes/shell_session_node.rs
+use super::resource_guard;
+use serde::Serialize;
+use serde_json::json;
+use std::collections::HashMap;
+use std::process::{Command, Stdio};
+use std::sync::Mutex;
+
+// 🐚 셸 세션 노드
+// session_id 별로 작업 폴더와 환경변수를 기억해서 "cd 폴더" → "npm install" 처럼
+// 여러 노드에 나눠 실행해도 이전 명령의 상태가 이어지게 함
+// 명령 실행 후 pwd / 환경변수를 출력하게 해서 다음 실행에 반영
+
+const STATE_MARKER: &str = "__AUTOMATION_GUI_SESSION_STATE__";
+// 세션 상태로 저장하지 않는 셸 내부 변수
+const IGNORED_VARS: [&str; 6] = ["PWD", "OLDPWD", "SHLVL", "_", "__status", "__rc"];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ShellSession {
+    pub session_id: String,
+    pub cwd: String,
+    #[serde(skip)]
+    pub env: HashMap<String, String>,
+    pub commands_run: u64,
+    pub last_used: i64,
+}
+
+static SHELL_SESSIONS: std::sync::OnceLock<Mutex<HashMap<String, ShellSession>>> =
+    std::sync::OnceLock::new();
+
+fn get_shell_sessions() -> &'static Mutex<HashMap<String, ShellSession>> {
+    SHELL_SESSIONS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn new_session(session_id: &str) -> ShellSession {
+    ShellSession {
+        session_id: session_id.to_string(),
+        cwd: dirs::home_dir()
+            .or_else(|| std::env::current_dir().ok())
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_else(|| ".".to_string()),
+        env: std::env::vars().collect(),
+        commands_run: 0,
+        last_used: 0,
+    }
+}
+
+// 명령 뒤에 상태 출력(마커, 작업 폴더, 환경변수)을 붙인 스크립트
+// Windows 는 %^변수% + call 로 명령 실행 후 시점의 값을 읽음 (종료 코드 유지)
+fn wrap_command(command: &str) -> (String, Vec<String>) {
+    if cfg!(target_os = "windows") {
+        let script = format!(
+            "{} & (call set __rc=%^errorlevel%) & echo {} & cd & set & call exit %^__rc%",
+            command, STATE_MARKER
+        );
+        (
+            "cmd".to_string(),
+            vec!["/V:OFF".to_string(), "/C".to_string(), script],
+        )
+    } else {
+        let script = format!(
+            "{}\n__status=$?\nprintf '\\n{}\\n'\npwd\nenv\nexit $__status",
+            command, STATE_MARKER
+        );
+        ("sh".to_string(), vec!["-c".to_string(), script])
+    }
+}
+
+// stdout 을 (명령 출력, 작업 폴더, 환경변수) 로 분리
+pub fn parse_session_output(
+    stdout: &str,
+) -> (String, Option<String>, Option<HashMap<String, String>>) {
+    let Some(index) = stdout.rfind(STATE_MARKER) else {
+        return (stdout.to_string(), None, None);
+    };
+    let output = stdout[..index]
+        .trim_end_matches(['\r', '\n', ' '])
+        .to_string();
+    let mut lines = stdout[index + STATE_MARKER.len()..]
+        .lines()
+        .map(|l| l.trim_end_matches('\r'))
+        .skip_while(|l| l.trim().is_empty());
+
+    let cwd = lines.next().map(|l| l.trim().to_string());
+    let mut env = HashMap::new();
+    let mut last_key: Option<String> = None;
+    for line in lines {
+        match line.split_once('=') {
+            Some((key, value))
+                if !key.is_empty()
+                    && key
+                        .chars()
+                        .all(|c| c.is_alphanumeric() || matches!(c, '_' | '(' | ')')) =>
+            {
+                env.insert(key.to_string(), value.to_string());
+                last_key = Some(key.to_string());
+            }
+            // 여러 줄 값은 이전 변수에 이어붙임
+            _ => {
+                if let Some(value) = last_key.as_ref().and_then(|k| env.get_mut(k)) {
+                    value.push('\n');
+                    value.push_str(line);
+                }
+            }
+        }
+    }
+    for key in IGNORED_VARS {
+        env.remove(key);
+    }
+    (output, cwd, Some(env))
+}
+
+#[tauri::command]
+pub async fn shell_session_node(
+    session_id: String,
+    command: String,
+    cwd: Option<String>,
+    env: Option<HashMap<String, String>>,
+) -> Result<String, String> {
+    let session_id = session_id.trim().to_string();
+    if session_id.is_empty() {
+        return Err("EMPTY_SESSION_ID".to_string());
+    }
+    if command.trim().is_empty() {
+        return Err("EMPTY_COMMAND".to_string());
+    }
+
+    let mut session = {
+        let sessions = get_shell_sessions().lock().map_err(|e| e.to_string())?;
+        sessions
+            .get(&session_id)
+            .cloned()
+            .unwrap_or_else(|| new_session(&session_id))
+    };
+    if let Some(dir) = cwd.filter(|d| !d.trim().is_empty()) {
+        session.cwd = dir.trim().to_string();
+    }
+    if let Some(vars) = env {
+        session.env.extend(vars);
+    }
+    if !std::path::Path::new(&session.cwd).is_dir() {
+        return Err(format!("CWD_NOT_FOUND: {}", session.cwd));
+    }
+
+    println!(
+        "🐚 Shell Session [{}] ({}): {}",
+        session_id, session.cwd, command
+    );
+
+    let (program, args) = wrap_command(&command);
+    let run_env = session.env.clone();
+    let run_cwd = session.cwd.clone();
+    let output = tokio::task::spawn_blocking(move || {
+        let mut cmd = Command::new(program);
+        cmd.args(&args)
+            .current_dir(&run_cwd)
+            .env_clear()
+            .envs(&run_env)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        #[cfg(target_os = "windows")]
+        {
+            use std::os::windows::process::CommandExt;
+            cmd.creation_flags(0x08000000); // CREATE_NO_WINDOW
+        }
+
+        let child = cmd.spawn().map_err(|e| format!("EXECUTION_ERROR: {}", e))?;
+        let guard = resource_guard::watch(Some(child.id()), "shell_session_node");
+        let output = child
+            .wait_with_output()
+            .map_err(|e| format!("EXECUTION_ERROR: {}", e))?;
+        guard.check()?;
+        Ok::<_, String>(output)
+    })
+    .await
+    .map_err(|e| format!("셸 실행 실패: {}", e))??;
+
+    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+    let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+    let exit_code = output.status.code().unwrap_or(-1);
+    let (command_output, new_cwd, new_env) = parse_session_output(&stdout);
+
+    // exit 등으로 상태 출력 전에 끝났으면 이전 상태 유지
+    if let Some(dir) = new_cwd.filter(|d| std::path::Path::new(d).is_dir()) {
+        session.cwd = dir;
+    }
+    if let Some(vars) = new_env {
+        session.env = vars;
+    }
+    session.commands_run += 1;
+    session.last_used = chrono::Utc::now().timestamp();
+    let cwd_after = session.cwd.clone();
+    get_shell_sessions()
+        .lock()
+        .map_err(|e| e.to_string())?
+        .insert(session_id.clone(), session);
+
+    println!("📤 [{}] exit {} → {}", session_id, exit_code, cwd_after);
+    Ok(json!({
+        "session_id": session_id,
+        "output": command_output,
+        "stderr": stderr,
+        "exit_code": exit_code,
+        "cwd": cwd_after
+    })
+    .to_string())
+}
+
+#[tauri::command]
+pub fn reset_shell_session(session_id: String) -> Result<bool, String> {
+    let removed = get_shell_sessions()
+        .lock()
+        .map_err(|e| e.to_string())?
+        .remove(session_id.trim())
+        .is_some();
+    if removed {
+        println!("🐚 셸 세션 초기화: {}", session_id.trim());
+    }
+    Ok(removed)
+}
+
+#[tauri::command]
+pub fn list_shell_sessions() -> Result<Vec<ShellSession>, String> {
+    let sessions = get_shell_sessions().lock().map_err(|e| e.to_string())?;
+    Ok(sessions.values().cloned().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::{parse_output, path_str, temp_dir};
+    use std::fs;
+
+    #[test]
+    fn shell_session_splits_output_from_state() {
+        let stdout = "hello\nworld\n\n__AUTOMATION_GUI_SESSION_STATE__\n/tmp/project\nFOO=bar\nMULTI=a\nb\nPWD=/tmp/project\nSHLVL=1\n";
+        let (output, cwd, env) = parse_session_output(stdout);
+        let env = env.unwrap();
+        assert_eq!(output, "hello\nworld");
+        assert_eq!(cwd.as_deref(), Some("/tmp/project"));
+        assert_eq!(env["FOO"], "bar");
+        assert_eq!(env["MULTI"], "a\nb");
+        assert!(!env.contains_key("PWD"));
+        assert!(!env.contains_key("SHLVL"));
+
+        let (output, cwd, env) = parse_session_output("only output");
+        assert_eq!(output, "only output");
+        assert!(cwd.is_none() && env.is_none());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn shell_session_keeps_cwd_and_env_between_commands() {
+        let dir = temp_dir();
+        let sub = dir.path().join("sub");
+        fs::create_dir_all(&sub).unwrap();
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+
+        runtime
+            .block_on(shell_session_node(
+                "test-session".to_string(),
+                "cd sub && export GREETING=hi".to_string(),
+                Some(path_str(dir.path())),
+                None,
+            ))
+            .unwrap();
+        let result = runtime
+            .block_on(shell_session_node(
+                "test-session".to_string(),
+                "echo \"$GREETING $(basename \"$PWD\")\"".to_string(),
+                None,
+                None,
+            ))
+            .unwrap();
+        let output = parse_output(&result);
+        assert_eq!(output["output"], "hi sub");
+        assert_eq!(output["exit_code"], 0);
+
+        assert!(reset_shell_session("test-session".to_string()).unwrap());
+    }
+}
@@ -0,0 +1,290 @@
+// src-tauri/src/nodes/snapshot_node.rs
+use super::atomic_store;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::path::{Path, PathBuf};
+
+// 📸 스냅샷 비교 노드
+// 처음 본 값을 store/snapshots/<이름>.json 에 저장하고, 다음 실행부터는 비교해 달라진 부분을 보고
+// 설정 파일, API 응답, 보고서가 예기치 않게 바뀌었는지 감시하는 워크플로우용
+// - JSON 은 경로별(추가/삭제/변경), 여러 줄 텍스트는 줄 단위(+/-)로 비교
+// - ignore_paths 로 타임스탬프처럼 매번 바뀌는 값은 제외
+// - update=true 면 현재 값을 새 기준으로 저장, fail_on_change=true 면 달라졌을 때 실패
+
+const MAX_DIFF_LINES: usize = 2000;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Snapshot {
+    value: Value,
+    created_at: String,
+    updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct Change {
+    pub path: String,
+    pub kind: String, // "added" | "removed" | "changed"
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+}
+
+fn child_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+fn is_ignored(path: &str, ignore_paths: &[String]) -> bool {
+    ignore_paths
+        .iter()
+        .any(|ignored| path == ignored || path.starts_with(&format!("{}.", ignored)))
+}
+
+// 경로별 차이 ("rows.0.name" 형식, 루트는 "$")
+pub fn diff_values(before: &Value, after: &Value, ignore_paths: &[String]) -> Vec<Change> {
+    let mut changes = Vec::new();
+    diff_at("", before, after, ignore_paths, &mut changes);
+    changes
+}
+
+fn diff_at(path: &str, before: &Value, after: &Value, ignore: &[String], out: &mut Vec<Change>) {
+    if is_ignored(path, ignore) || before == after {
+        return;
+    }
+    let change = |kind: &str, before: Option<&Value>, after: Option<&Value>, path: String| Change {
+        path: if path.is_empty() {
+            "$".to_string()
+        } else {
+            path
+        },
+        kind: kind.to_string(),
+        before: before.cloned(),
+        after: after.cloned(),
+    };
+    match (before, after) {
+        (Value::Object(old), Value::Object(new)) => {
+            for (key, old_value) in old {
+                let child = child_path(path, key);
+                match new.get(key) {
+                    Some(new_value) => diff_at(&child, old_value, new_value, ignore, out),
+                    None if !is_ignored(&child, ignore) => {
+                        out.push(change("removed", Some(old_value), None, child))
+                    }
+                    None => {}
+                }
+            }
+            for (key, new_value) in new {
+                let child = child_path(path, key);
+                if !old.contains_key(key) && !is_ignored(&child, ignore) {
+                    out.push(change("added", None, Some(new_value), child));
+                }
+            }
+        }
+        (Value::Array(old), Value::Array(new)) => {
+            for i in 0..old.len().max(new.len()) {
+                let child = child_path(path, &i.to_string());
+                match (old.get(i), new.get(i)) {
+                    (Some(o), Some(n)) => diff_at(&child, o, n, ignore, out),
+                    (Some(o), None) => out.push(change("removed", Some(o), None, child)),
+                    (None, Some(n)) => out.push(change("added", None, Some(n), child)),
+                    (None, None) => {}
+                }
+            }
+        }
+        _ => out.push(change(
+            "changed",
+            Some(before),
+            Some(after),
+            path.to_string(),
+        )),
+    }
+}
+
+// 줄 단위 diff (LCS), 바뀐 줄만 "- 이전" / "+ 이후" 로
+pub fn diff_lines(before: &str, after: &str) -> Vec<String> {
+    let old: Vec<&str> = before.lines().collect();
+    let new: Vec<&str> = after.lines().collect();
+    if old.len() > MAX_DIFF_LINES || new.len() > MAX_DIFF_LINES {
+        return vec![format!(
+            "~ {}줄 → {}줄 (너무 길어 줄 비교 생략)",
+            old.len(),
+            new.len()
+        )];
+    }
+
+    // lcs[i][j] = old[i..], new[j..] 의 최장 공통 부분 길이
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut out = Vec::new();
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            i += 1;
+            j += 1;
+        } else if j < new.len() && (i == old.len() || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            out.push(format!("+ {}", new[j]));
+            j += 1;
+        } else {
+            out.push(format!("- {}", old[i]));
+            i += 1;
+        }
+    }
+    out
+}
+
+fn snapshot_path(name: &str) -> Result<PathBuf, String> {
+    let name = name.trim();
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.'))
+        || name.starts_with('.')
+    {
+        return Err(format!("INVALID_SNAPSHOT_NAME: {}", name));
+    }
+    Ok(atomic_store::store_dir()
+        .join("snapshots")
+        .join(format!("{}.json", name)))
+}
+
+fn save_snapshot(path: &Path, value: &Value, created_at: Option<String>) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|_| "DIRECTORY_CREATE_ERROR".to_string())?;
+    }
+    let now = chrono::Local::now().to_rfc3339();
+    atomic_store::write_json_atomic(
+        path,
+        &Snapshot {
+            value: value.clone(),
+            created_at: created_at.unwrap_or_else(|| now.clone()),
+            updated_at: now,
+        },
+    )
+}
+
+#[tauri::command]
+pub fn snapshot_node(
+    value: Value,
+    snapshot_name: String,
+    update: Option<bool>,
+    fail_on_change: Option<bool>,
+    ignore_paths: Option<Vec<String>>,
+) -> Result<String, String> {
+    let path = snapshot_path(&snapshot_name)?;
+    // JSON 문자열은 풀어서 구조 비교
+    let value = match value {
+        Value::String(text) => match serde_json::from_str::<Value>(text.trim()) {
+            Ok(parsed) if parsed.is_object() || parsed.is_array() => parsed,
+            _ => Value::String(text),
+        },
+        other => other,
+    };
+    let ignore_paths = ignore_paths.unwrap_or_default();
+
+    let Some(existing) = atomic_store::read_json_verified::<Snapshot>(&path) else {
+        save_snapshot(&path, &value, None)?;
+        println!("📸 스냅샷 생성: {}", snapshot_name);
+        return Ok(json!({ "status": "created", "snapshot_name": snapshot_name }).to_string());
+    };
+
+    let changes = diff_values(&existing.value, &value, &ignore_paths);
+    let lines = match (&existing.value, &value) {
+        (Value::String(before), Value::String(after)) if before != after => {
+            diff_lines(before, after)
+        }
+        _ => Vec::new(),
+    };
+
+    if changes.is_empty() {
+        println!("📸 스냅샷 동일: {}", snapshot_name);
+        return Ok(json!({
+            "status": "unchanged",
+            "snapshot_name": snapshot_name,
+            "since": existing.updated_at
+        })
+        .to_string());
+    }
+
+    if update.unwrap_or(false) {
+        save_snapshot(&path, &value, Some(existing.created_at))?;
+        println!(
+            "📸 스냅샷 갱신: {} ({}곳 변경)",
+            snapshot_name,
+            changes.len()
+        );
+        return Ok(json!({
+            "status": "updated",
+            "snapshot_name": snapshot_name,
+            "changes": changes,
+            "line_diff": lines
+        })
+        .to_string());
+    }
+
+    println!(
+        "📸 스냅샷 변경 감지: {} ({}곳)",
+        snapshot_name,
+        changes.len()
+    );
+    if fail_on_change.unwrap_or(false) {
+        let paths: Vec<&str> = changes.iter().map(|c| c.path.as_str()).take(10).collect();
+        return Err(format!(
+            "SNAPSHOT_MISMATCH: {} ({})",
+            snapshot_name,
+            paths.join(", ")
+        ));
+    }
+    Ok(json!({
+        "status": "changed",
+        "snapshot_name": snapshot_name,
+        "since": existing.updated_at,
+        "changes": changes,
+        "line_diff": lines
+    })
+    .to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn snapshot_node_diffs_json_by_path() {
+        let before = json!({ "version": 1, "items": ["a", "b"], "fetched_at": "t1", "old": true });
+        let after = json!({ "version": 2, "items": ["a"], "fetched_at": "t2", "new": 1 });
+        let changes = diff_values(&before, &after, &["fetched_at".to_string()]);
+        let summary: Vec<(String, String)> = changes
+            .iter()
+            .map(|c| (c.path.clone(), c.kind.clone()))
+            .collect();
+        assert!(summary.contains(&("version".into(), "changed".into())));
+        assert!(summary.contains(&("items.1".into(), "removed".into())));
+        assert!(summary.contains(&("old".into(), "removed".into())));
+        assert!(summary.contains(&("new".into(), "added".into())));
+        assert!(!summary.iter().any(|(path, _)| path == "fetched_at"));
+
+        let root = diff_values(&json!("a"), &json!("b"), &[]);
+        assert_eq!(root[0].path, "$");
+    }
+
+    #[test]
+    fn snapshot_node_diffs_text_by_line() {
+        assert_eq!(
+            diff_lines("a\nb\nc", "a\nc\nd"),
+            vec!["- b".to_string(), "+ d".to_string()]
+        );
+        assert!(diff_lines("same", "same").is_empty());
+    }
+}
@@ -0,0 +1,318 @@
+// src-tauri/src/nodes/soap_node.rs
+use super::{http_client, mock_replay, template};
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+
+// 🧼 SOAP / XML API 노드 (공공기관·기업 SOAP 서비스 호출용)
+// body 템플릿의 {{path}} 를 context 값으로 채워(XML 이스케이프) Envelope 를 만들고
+// 응답 XML 에서 extract 의 XPath 식으로 값을 뽑아냄 ({"name": "//m:Result"})
+// - body 가 이미 Envelope 이면 그대로 사용, 아니면 <soap:Body> 안에 넣음
+// - 응답 XPath 에는 soap/soap12/xsi/xsd 접두어가 기본 등록, 나머지는 namespaces 로 지정
+//   (접두어 없이 쓰려면 //*[local-name()='Result'])
+
+pub const SOAP11_NS: &str = "http://schemas.xmlsoap.org/soap/envelope/";
+pub const SOAP12_NS: &str = "http://www.w3.org/2003/05/soap-envelope";
+
+pub fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+// context 안의 문자열을 모두 이스케이프 (템플릿에 넣는 값이 XML 구조를 깨지 않도록)
+fn escape_context(value: &Value) -> Value {
+    match value {
+        Value::String(text) => Value::String(escape_xml(text)),
+        Value::Array(items) => Value::Array(items.iter().map(escape_context).collect()),
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, value)| (key.clone(), escape_context(value)))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+pub fn render_xml(template_text: &str, context: &Value) -> String {
+    template::render(template_text, &escape_context(context))
+}
+
+pub fn build_envelope(body: &str, header: Option<&str>, soap12: bool) -> String {
+    let trimmed = body.trim();
+    if trimmed.starts_with("<?xml")
+        || trimmed.contains(":Envelope")
+        || trimmed.starts_with("<Envelope")
+    {
+        return trimmed.to_string();
+    }
+    let ns = if soap12 { SOAP12_NS } else { SOAP11_NS };
+    let header = header
+        .map(str::trim)
+        .filter(|h| !h.is_empty())
+        .map(|h| format!("<soap:Header>{}</soap:Header>", h))
+        .unwrap_or_default();
+    format!(
+        "<?xml version=\"1.0\" encoding=\"utf-8\"?><soap:Envelope xmlns:soap=\"{}\" xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" xmlns:xsd=\"http://www.w3.org/2001/XMLSchema\">{}<soap:Body>{}</soap:Body></soap:Envelope>",
+        ns, header, trimmed
+    )
+}
+
+fn xpath_value(
+    document: &sxd_document::dom::Document,
+    expression: &str,
+    namespaces: &HashMap<String, String>,
+) -> Result<Value, String> {
+    let factory = sxd_xpath::Factory::new();
+    let xpath = factory
+        .build(expression)
+        .map_err(|e| format!("INVALID_XPATH: {} ({})", expression, e))?
+        .ok_or_else(|| format!("INVALID_XPATH: {}", expression))?;
+
+    let mut context = sxd_xpath::Context::new();
+    context.set_namespace("soap", SOAP11_NS);
+    context.set_namespace("soap12", SOAP12_NS);
+    context.set_namespace("xsi", "http://www.w3.org/2001/XMLSchema-instance");
+    context.set_namespace("xsd", "http://www.w3.org/2001/XMLSchema");
+    for (prefix, uri) in namespaces {
+        context.set_namespace(prefix, uri);
+    }
+
+    let value = xpath
+        .evaluate(&context, document.root())
+        .map_err(|e| format!("XPATH_ERROR: {} ({})", expression, e))?;
+    Ok(match value {
+        sxd_xpath::Value::Nodeset(nodes) => {
+            let mut texts: Vec<Value> = nodes
+                .document_order()
+                .iter()
+                .map(|node| json!(node.string_value().trim()))
+                .collect();
+            match texts.len() {
+                0 => Value::Null,
+                1 => texts.remove(0),
+                _ => Value::Array(texts),
+            }
+        }
+        sxd_xpath::Value::Boolean(b) => json!(b),
+        sxd_xpath::Value::Number(n) => json!(n),
+        sxd_xpath::Value::String(s) => json!(s),
+    })
+}
+
+fn parse_xml(xml: &str) -> Result<sxd_document::Package, String> {
+    sxd_document::parser::parse(xml).map_err(|e| format!("INVALID_XML_RESPONSE: {:?}", e))
+}
+
+// 응답 XML 에서 이름별 XPath 값 추출 (없으면 null, 여러 노드면 배열)
+pub fn extract_values(
+    xml: &str,
+    extract: &HashMap<String, String>,
+    namespaces: &HashMap<String, String>,
+) -> Result<Map<String, Value>, String> {
+    let package = parse_xml(xml)?;
+    let document = package.as_document();
+    extract
+        .iter()
+        .map(|(name, expression)| {
+            Ok((
+                name.clone(),
+                xpath_value(&document, expression, namespaces)?,
+            ))
+        })
+        .collect()
+}
+
+// SOAP Fault 메시지 (1.1 faultstring / 1.2 Reason/Text)
+pub fn soap_fault(xml: &str) -> Option<String> {
+    let package = parse_xml(xml).ok()?;
+    let document = package.as_document();
+    let none = HashMap::new();
+    let fault = xpath_value(&document, "//*[local-name()='Fault']", &none).ok()?;
+    if fault.is_null() {
+        return None;
+    }
+    let message = [
+        "//*[local-name()='Fault']/*[local-name()='faultstring']",
+        "//*[local-name()='Fault']//*[local-name()='Reason']/*[local-name()='Text']",
+    ]
+    .iter()
+    .filter_map(|expression| xpath_value(&document, expression, &none).ok())
+    .find_map(|value| value.as_str().map(str::to_string));
+    Some(message.unwrap_or_else(|| template::value_to_text(&fault)))
+}
+
+// 선택 옵션 (프론트엔드에서 객체 하나로 전달)
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SoapOptions {
+    pub soap_action: Option<String>,
+    pub version: Option<String>,
+    pub header: Option<String>,
+    pub extract: Option<HashMap<String, String>>,
+    pub namespaces: Option<HashMap<String, String>>,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub timeout_secs: Option<u64>,
+}
+
+#[tauri::command]
+pub async fn soap_node(
+    endpoint: String,
+    body: String,
+    context: Option<Value>,
+    options: Option<SoapOptions>,
+) -> Result<String, String> {
+    let SoapOptions {
+        soap_action,
+        version,
+        header,
+        extract,
+        namespaces,
+        username,
+        password,
+        timeout_secs,
+    } = options.unwrap_or_default();
+    let endpoint = endpoint.trim().to_string();
+    url::Url::parse(&endpoint).map_err(|_| format!("INVALID_ENDPOINT: {}", endpoint))?;
+    let soap12 = version.as_deref().map(str::trim) == Some("1.2");
+    let context = context.unwrap_or(Value::Null);
+    let envelope = build_envelope(
+        &render_xml(&body, &context),
+        header.map(|h| render_xml(&h, &context)).as_deref(),
+        soap12,
+    );
+    // 요청부터 올바른 XML 인지 확인 (템플릿 오타를 서버 에러보다 먼저 알려줌)
+    parse_xml(&envelope).map_err(|e| e.replace("INVALID_XML_RESPONSE", "INVALID_XML_BODY"))?;
+    let soap_action = soap_action.unwrap_or_default();
+
+    println!("🧼 SOAP Node: {} ({})", endpoint, soap_action);
+
+    let mock_request = json!({ "endpoint": endpoint, "action": soap_action, "envelope": envelope });
+    let (status, text) = match mock_replay::replay("soap", &mock_request)? {
+        Some(recorded) => (
+            recorded["status"].as_u64().unwrap_or(200) as u16,
+            recorded["body"].as_str().unwrap_or("").to_string(),
+        ),
+        None => {
+            let mut request = http_client::client()
+                .post(&endpoint)
+                .timeout(std::time::Duration::from_secs(timeout_secs.unwrap_or(30)))
+                .body(envelope.clone());
+            request = if soap12 {
+                let mut content_type = "application/soap+xml; charset=utf-8".to_string();
+                if !soap_action.is_empty() {
+                    content_type.push_str(&format!("; action=\"{}\"", soap_action));
+                }
+                request.header("Content-Type", content_type)
+            } else {
+                request
+                    .header("Content-Type", "text/xml; charset=utf-8")
+                    .header("SOAPAction", format!("\"{}\"", soap_action))
+            };
+            if let Some(username) = username.filter(|u| !u.is_empty()) {
+                request = request.basic_auth(username, password);
+            }
+
+            let response = request
+                .send()
+                .await
+                .map_err(|e| format!("SOAP 요청 실패: {}", e))?;
+            let status = response.status().as_u16();
+            let text = response
+                .text()
+                .await
+                .map_err(|e| format!("SOAP 응답 읽기 실패: {}", e))?;
+            mock_replay::record(
+                "soap",
+                &mock_request,
+                &json!({ "status": status, "body": text }),
+            );
+            (status, text)
+        }
+    };
+
+    // Fault 는 보통 HTTP 500 으로 오므로 상태 코드보다 먼저 확인
+    if let Some(fault) = soap_fault(&text) {
+        return Err(format!("SOAP_FAULT: {}", fault));
+    }
+    if !(200..300).contains(&status) {
+        return Err(format!("SOAP_HTTP_ERROR ({}): {}", status, text));
+    }
+
+    let values = extract_values(
+        &text,
+        &extract.unwrap_or_default(),
+        &namespaces.unwrap_or_default(),
+    )?;
+    println!("✅ SOAP 응답 {}바이트, 추출 {}개", text.len(), values.len());
+
+    Ok(json!({
+        "status": status,
+        "values": values,
+        "response": text
+    })
+    .to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn soap_builds_escaped_envelope() {
+        let body = render_xml(
+            "<m:GetWeather xmlns:m=\"urn:weather\"><m:City>{{city}}</m:City></m:GetWeather>",
+            &json!({ "city": "Seoul & <Busan>" }),
+        );
+        assert!(body.contains("Seoul &amp; &lt;Busan&gt;"));
+
+        let envelope = build_envelope(&body, None, false);
+        assert!(envelope.contains(SOAP11_NS));
+        assert!(envelope.contains("<soap:Body><m:GetWeather"));
+
+        // 이미 Envelope 면 그대로
+        let full = "<soap:Envelope xmlns:soap=\"x\"><soap:Body/></soap:Envelope>";
+        assert_eq!(build_envelope(full, None, true), full);
+    }
+
+    #[test]
+    fn soap_extracts_values_and_detects_faults() {
+        let response = r#"<?xml version="1.0"?>
+<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+  <soap:Body>
+    <m:GetWeatherResponse xmlns:m="urn:weather">
+      <m:Temp>21.5</m:Temp>
+      <m:Alert>rain</m:Alert>
+      <m:Alert>wind</m:Alert>
+    </m:GetWeatherResponse>
+  </soap:Body>
+</soap:Envelope>"#;
+        let extract = [
+            ("temp".to_string(), "//m:Temp".to_string()),
+            (
+                "alerts".to_string(),
+                "//*[local-name()='Alert']".to_string(),
+            ),
+            ("count".to_string(), "count(//m:Alert)".to_string()),
+            ("missing".to_string(), "//m:Nope".to_string()),
+        ]
+        .into_iter()
+        .collect();
+        let namespaces = [("m".to_string(), "urn:weather".to_string())]
+            .into_iter()
+            .collect();
+        let values = extract_values(response, &extract, &namespaces).unwrap();
+        assert_eq!(values["temp"], "21.5");
+        assert_eq!(values["alerts"], json!(["rain", "wind"]));
+        assert_eq!(values["count"], 2.0);
+        assert!(values["missing"].is_null());
+        assert!(soap_fault(response).is_none());
+
+        let fault = r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/"><soap:Body><soap:Fault><faultcode>soap:Client</faultcode><faultstring>인증키 오류</faultstring></soap:Fault></soap:Body></soap:Envelope>"#;
+        assert_eq!(soap_fault(fault).as_deref(), Some("인증키 오류"));
+    }
+}
@@ -0,0 +1,290 @@
+// src-tauri/src/nodes/static_cache.rs
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use warp::http::{Response, StatusCode};
+use warp::Filter;
+
+// 🗜️ 채팅 서버 정적 응답 캐시
+// Cloudflare 터널 너머 느린 모바일 연결에서 같은 페이지를 매번 통째로 받지 않도록:
+// - ETag + If-None-Match → 바뀌지 않았으면 304 (본문 없음)
+// - Accept-Encoding 에 맞춰 brotli / gzip 압축본 전송 (압축본은 한 번만 만들어 보관)
+// 채팅 페이지는 서버 시작 시 한 번 압축, /files 정적 파일은 (경로, 크기, 수정 시각) 기준으로 메모리에 보관
+
+const MIN_COMPRESS_BYTES: usize = 1024;
+const MAX_CACHED_FILE_BYTES: u64 = 1024 * 1024;
+const MAX_CACHED_FILES: usize = 64;
+
+pub const PAGE_CACHE_CONTROL: &str = "no-cache";
+pub const FILE_CACHE_CONTROL: &str = "private, max-age=300";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    Brotli,
+    Gzip,
+    Identity,
+}
+
+impl Encoding {
+    fn header(self) -> Option<&'static str> {
+        match self {
+            Encoding::Brotli => Some("br"),
+            Encoding::Gzip => Some("gzip"),
+            Encoding::Identity => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CachedAsset {
+    pub content_type: &'static str,
+    pub cache_control: &'static str,
+    pub etag: String,
+    pub body: Vec<u8>,
+    pub gzip: Option<Vec<u8>>,
+    pub brotli: Option<Vec<u8>>,
+}
+
+pub fn etag_for(body: &[u8]) -> String {
+    let digest = Sha256::digest(body);
+    let hex: String = digest[..8].iter().map(|b| format!("{:02x}", b)).collect();
+    format!("\"{}\"", hex)
+}
+
+pub fn is_compressible(content_type: &str) -> bool {
+    content_type.starts_with("text/")
+        || content_type.starts_with("application/json")
+        || content_type.starts_with("image/svg")
+        || content_type.contains("javascript")
+}
+
+fn gzip_bytes(body: &[u8]) -> Option<Vec<u8>> {
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::best());
+    encoder.write_all(body).ok()?;
+    encoder.finish().ok()
+}
+
+fn brotli_bytes(body: &[u8]) -> Option<Vec<u8>> {
+    let mut out = Vec::new();
+    {
+        let mut writer = brotli::CompressorWriter::new(&mut out, 4096, 9, 22);
+        writer.write_all(body).ok()?;
+    }
+    Some(out)
+}
+
+impl CachedAsset {
+    // 압축해서 더 작아질 때만 압축본 보관
+    pub fn new(content_type: &'static str, cache_control: &'static str, body: Vec<u8>) -> Self {
+        Self::build(content_type, cache_control, body, true)
+    }
+
+    fn build(
+        content_type: &'static str,
+        cache_control: &'static str,
+        body: Vec<u8>,
+        compress: bool,
+    ) -> Self {
+        let compress =
+            compress && is_compressible(content_type) && body.len() >= MIN_COMPRESS_BYTES;
+        let (gzip, brotli) = if compress {
+            let smaller = |data: Option<Vec<u8>>| data.filter(|d| d.len() < body.len());
+            (smaller(gzip_bytes(&body)), smaller(brotli_bytes(&body)))
+        } else {
+            (None, None)
+        };
+        Self {
+            content_type,
+            cache_control,
+            etag: etag_for(&body),
+            body,
+            gzip,
+            brotli,
+        }
+    }
+
+    fn variant(&self, encoding: Encoding) -> (Encoding, &[u8]) {
+        match encoding {
+            Encoding::Brotli => match &self.brotli {
+                Some(data) => (Encoding::Brotli, data),
+                None => self.variant(Encoding::Gzip),
+            },
+            Encoding::Gzip => match &self.gzip {
+                Some(data) => (Encoding::Gzip, data),
+                None => (Encoding::Identity, &self.body),
+            },
+            Encoding::Identity => (Encoding::Identity, &self.body),
+        }
+    }
+}
+
+// Accept-Encoding 해석 (q=0 은 거부, 같은 조건이면 br > gzip)
+pub fn pick_encoding(accept_encoding: Option<&str>) -> Encoding {
+    let Some(header) = accept_encoding else {
+        return Encoding::Identity;
+    };
+    let mut brotli = false;
+    let mut gzip = false;
+    for item in header.split(',') {
+        let mut parts = item.split(';');
+        let name = parts.next().unwrap_or("").trim().to_lowercase();
+        let refused = parts.any(|p| {
+            p.trim()
+                .strip_prefix("q=")
+                .and_then(|q| q.trim().parse::<f32>().ok())
+                .is_some_and(|q| q <= 0.0)
+        });
+        if refused {
+            continue;
+        }
+        match name.as_str() {
+            "br" => brotli = true,
+            "gzip" | "*" => gzip = true,
+            _ => {}
+        }
+    }
+    if brotli {
+        Encoding::Brotli
+    } else if gzip {
+        Encoding::Gzip
+    } else {
+        Encoding::Identity
+    }
+}
+
+// If-None-Match 목록 중 하나라도 같으면 (약한 비교, "*" 포함) 304
+pub fn is_not_modified(if_none_match: Option<&str>, etag: &str) -> bool {
+    let Some(header) = if_none_match else {
+        return false;
+    };
+    let strip = |tag: &str| tag.trim().trim_start_matches("W/").to_string();
+    header
+        .split(',')
+        .any(|tag| tag.trim() == "*" || strip(tag) == strip(etag))
+}
+
+pub fn respond(
+    asset: &CachedAsset,
+    accept_encoding: Option<&str>,
+    if_none_match: Option<&str>,
+) -> Response<Vec<u8>> {
+    let builder = Response::builder()
+        .header("etag", &asset.etag)
+        .header("cache-control", asset.cache_control)
+        .header("vary", "accept-encoding");
+    if is_not_modified(if_none_match, &asset.etag) {
+        return builder
+            .status(StatusCode::NOT_MODIFIED)
+            .body(Vec::new())
+            .unwrap_or_default();
+    }
+
+    let (encoding, body) = asset.variant(pick_encoding(accept_encoding));
+    let mut builder = builder
+        .header("content-type", asset.content_type)
+        .header("x-content-type-options", "nosniff");
+    if let Some(name) = encoding.header() {
+        builder = builder.header("content-encoding", name);
+    }
+    builder.body(body.to_vec()).unwrap_or_default()
+}
+
+// (Accept-Encoding, If-None-Match) 헤더 추출
+pub fn conditional_headers(
+) -> impl Filter<Extract = (Option<String>, Option<String>), Error = warp::Rejection> + Clone {
+    warp::header::optional::<String>("accept-encoding")
+        .and(warp::header::optional::<String>("if-none-match"))
+}
+
+// 📁 정적 파일 캐시 (파일이 바뀌면 크기/수정 시각이 달라져 다시 읽음)
+type FileKey = (PathBuf, u64, Option<std::time::SystemTime>);
+
+static FILE_CACHE: std::sync::OnceLock<Mutex<HashMap<FileKey, Arc<CachedAsset>>>> =
+    std::sync::OnceLock::new();
+
+pub async fn load_file(
+    path: &Path,
+    content_type: &'static str,
+) -> Result<Arc<CachedAsset>, String> {
+    let metadata = tokio::fs::metadata(path).await.map_err(|e| e.to_string())?;
+    let key: FileKey = (path.to_path_buf(), metadata.len(), metadata.modified().ok());
+    let cache = FILE_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+    if let Some(asset) = cache.lock().map_err(|e| e.to_string())?.get(&key) {
+        return Ok(asset.clone());
+    }
+
+    // 큰 파일은 매번 압축하지 않고 ETag 만
+    let body = tokio::fs::read(path).await.map_err(|e| e.to_string())?;
+    let small = metadata.len() <= MAX_CACHED_FILE_BYTES;
+    let build = move || CachedAsset::build(content_type, FILE_CACHE_CONTROL, body, small);
+    let asset = Arc::new(
+        tokio::task::spawn_blocking(build)
+            .await
+            .map_err(|e| e.to_string())?,
+    );
+    if small {
+        let mut cache = cache.lock().map_err(|e| e.to_string())?;
+        // 이전 버전 항목 정리, 가득 차면 비우고 다시 채움
+        cache.retain(|(cached, ..), _| cached != path);
+        if cache.len() >= MAX_CACHED_FILES {
+            cache.clear();
+        }
+        cache.insert(key, asset.clone());
+    }
+    Ok(asset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn static_cache_negotiates_encoding_and_etag() {
+        use Encoding;
+        assert_eq!(pick_encoding(Some("gzip, deflate, br")), Encoding::Brotli);
+        assert_eq!(pick_encoding(Some("br;q=0, gzip")), Encoding::Gzip);
+        assert_eq!(pick_encoding(Some("identity")), Encoding::Identity);
+        assert_eq!(pick_encoding(None), Encoding::Identity);
+
+        let etag = etag_for(b"hello");
+        assert!(is_not_modified(Some(&etag), &etag));
+        assert!(is_not_modified(Some(&format!("\"x\", W/{}", etag)), &etag));
+        assert!(is_not_modified(Some("*"), &etag));
+        assert!(!is_not_modified(Some("\"other\""), &etag));
+        assert!(!is_not_modified(None, &etag));
+    }
+
+    #[test]
+    fn static_cache_serves_compressed_page_and_304() {
+        use std::io::Read;
+        let html = "<p>채팅</p>\n".repeat(500);
+        let asset = CachedAsset::new(
+            "text/html; charset=utf-8",
+            PAGE_CACHE_CONTROL,
+            html.clone().into_bytes(),
+        );
+        assert!(asset.brotli.is_some());
+
+        let response = respond(&asset, Some("gzip"), None);
+        assert_eq!(response.status(), 200);
+        assert_eq!(response.headers()["content-encoding"], "gzip");
+        assert_eq!(response.headers()["cache-control"], "no-cache");
+        let mut decoded = String::new();
+        flate2::read::GzDecoder::new(response.body().as_slice())
+            .read_to_string(&mut decoded)
+            .unwrap();
+        assert_eq!(decoded, html);
+
+        let response = respond(&asset, Some("br"), Some(&asset.etag));
+        assert_eq!(response.status(), 304);
+        assert!(response.body().is_empty());
+
+        // 작은 본문이나 이미지는 압축하지 않음
+        let small = CachedAsset::new("text/plain", "no-cache", b"hi".to_vec());
+        let response = respond(&small, Some("br, gzip"), None);
+        assert!(response.headers().get("content-encoding").is_none());
+        assert_eq!(response.body(), b"hi");
+    }
+}
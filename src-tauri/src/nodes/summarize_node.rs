@@ -0,0 +1,209 @@
+// src-tauri/src/nodes/summarize_node.rs
+use serde_json::json;
+
+use super::cli_ai_node::request_claude_completion;
+use super::http_client;
+use super::safe_path;
+use super::node_cache::make_cache_key;
+
+// 한 번에 모델에 보낼 최대 글자 수 (청크 크기)
+const CHUNK_CHARS: usize = 12_000;
+// 리듀스 단계가 무한히 반복되지 않도록 제한
+const MAX_REDUCE_ROUNDS: usize = 4;
+
+// 문단 경계 우선으로 긴 텍스트를 청크로 분할 (문자 단위라 한글도 안전)
+fn split_into_chunks(text: &str, chunk_chars: usize) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+    let mut current_len = 0;
+
+    for paragraph in text.split("\n\n") {
+        let paragraph_len = paragraph.chars().count();
+
+        if current_len > 0 && current_len + paragraph_len + 2 > chunk_chars {
+            chunks.push(std::mem::take(&mut current));
+            current_len = 0;
+        }
+
+        if paragraph_len > chunk_chars {
+            // 문단 하나가 청크보다 길면 글자 수 기준으로 강제 분할
+            let chars: Vec<char> = paragraph.chars().collect();
+            for piece in chars.chunks(chunk_chars) {
+                chunks.push(piece.iter().collect());
+            }
+            continue;
+        }
+
+        if current_len > 0 {
+            current.push_str("\n\n");
+            current_len += 2;
+        }
+        current.push_str(paragraph);
+        current_len += paragraph_len;
+    }
+
+    if !current.trim().is_empty() {
+        chunks.push(current);
+    }
+
+    chunks
+}
+
+fn style_instruction(style: &str) -> &'static str {
+    match style {
+        "bullet" | "bullets" => "Write the summary as concise bullet points.",
+        "tldr" => "Write a one or two sentence TL;DR.",
+        "detailed" => "Write a detailed summary that keeps important facts, numbers and names.",
+        _ => "Write a clear, well-structured paragraph summary.",
+    }
+}
+
+async fn summarize_once(
+    client: &reqwest::Client,
+    api_key: &str,
+    model: &str,
+    text: &str,
+    instruction: &str,
+    stage: &str,
+) -> Result<String, String> {
+    let system_prompt = format!(
+        "You summarize documents for an automation pipeline. {} \
+         ALWAYS respond in the same language as the source text. \
+         Output only the summary, without preamble.",
+        instruction
+    );
+
+    let mock_request = json!({
+        "node": "summarize_node",
+        "stage": stage,
+        "instruction": instruction,
+        "text": make_cache_key("text", &json!(text))
+    });
+
+    let response_json = request_claude_completion(
+        client,
+        api_key,
+        model,
+        &system_prompt,
+        &json!(text),
+        &mock_request,
+    )
+    .await?;
+
+    response_json["content"][0]["text"]
+        .as_str()
+        .map(|summary| summary.trim().to_string())
+        .ok_or_else(|| "No content in API response".to_string())
+}
+
+#[tauri::command]
+pub async fn summarize_node(
+    text_or_file: String,
+    style: Option<String>,
+    max_length: Option<usize>,
+    api_key: String,
+    model: String,
+) -> Result<String, String> {
+    println!("📚 Summarize Node 실행 시작");
+
+    if text_or_file.trim().is_empty() {
+        return Err("NO_INPUT_TEXT".to_string());
+    }
+    if api_key.trim().is_empty() {
+        return Err("NO_API_KEY".to_string());
+    }
+
+    // 파일 경로면 파일 내용을, 아니면 입력 자체를 요약
+    let trimmed_input = text_or_file.trim();
+    let file = (!trimmed_input.contains('\n'))
+        .then(|| safe_path::resolve_existing(trimmed_input))
+        .filter(|path| path.is_file());
+    let (text, source) = match file {
+        Some(path) => {
+            let content =
+                std::fs::read_to_string(&path).map_err(|e| format!("파일 읽기 실패: {}", e))?;
+            (content, trimmed_input.to_string())
+        }
+        None => (text_or_file.clone(), "text".to_string()),
+    };
+
+    let style = style.unwrap_or_else(|| "paragraph".to_string());
+    let max_length = max_length.unwrap_or(1000);
+    let client = http_client::client();
+
+    // 1️⃣ Map: 청크별 부분 요약
+    let chunks = split_into_chunks(&text, CHUNK_CHARS);
+    println!("✂️ {}개 청크로 분할", chunks.len());
+
+    let mut partials = Vec::new();
+    if chunks.len() > 1 {
+        let map_instruction = "Summarize this part of a longer document. Keep key facts; it will be merged with other parts later.";
+        for (index, chunk) in chunks.iter().enumerate() {
+            let partial = summarize_once(
+                &client,
+                &api_key,
+                &model,
+                chunk,
+                map_instruction,
+                &format!("map_{}", index),
+            )
+            .await?;
+            partials.push(partial);
+        }
+    } else {
+        partials = chunks.clone();
+    }
+
+    // 2️⃣ Reduce: 부분 요약이 너무 길면 다시 묶어서 요약
+    let mut combined = partials.join("\n\n");
+    let mut rounds = 0;
+    while combined.chars().count() > CHUNK_CHARS && rounds < MAX_REDUCE_ROUNDS {
+        let mut reduced = Vec::new();
+        for (index, group) in split_into_chunks(&combined, CHUNK_CHARS).iter().enumerate() {
+            reduced.push(
+                summarize_once(
+                    &client,
+                    &api_key,
+                    &model,
+                    group,
+                    "Merge these partial summaries into one shorter summary without losing key facts.",
+                    &format!("reduce_{}_{}", rounds, index),
+                )
+                .await?,
+            );
+        }
+        combined = reduced.join("\n\n");
+        rounds += 1;
+    }
+
+    // 3️⃣ 최종 요약 (스타일 + 길이 제한)
+    let final_instruction = format!(
+        "{} Keep it under {} characters.",
+        style_instruction(&style),
+        max_length
+    );
+    let summary = summarize_once(
+        &client,
+        &api_key,
+        &model,
+        &combined,
+        &final_instruction,
+        "final",
+    )
+    .await?;
+
+    println!("✅ 요약 완료 ({}자)", summary.chars().count());
+
+    let result = json!({
+        "summary": summary,
+        "style": style,
+        "max_length": max_length,
+        "source": source,
+        "source_length": text.chars().count(),
+        "chunks": chunks.len(),
+        "reduce_rounds": rounds,
+        "model_used": model
+    });
+
+    Ok(result.to_string())
+}
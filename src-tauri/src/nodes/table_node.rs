@@ -0,0 +1,277 @@
+// src-tauri/src/nodes/table_node.rs
+use super::template::value_to_text;
+use serde_json::{json, Value};
+
+// 📊 표 변환 노드
+// JSON 행(객체 배열 또는 배열의 배열) → 정렬된 텍스트 / Markdown / HTML 표
+// 명령 실행 결과나 DB 조회 결과를 이메일, 채팅, 보고서에 바로 붙여넣기 위함
+
+// 한글/한자/전각 문자는 고정폭 글꼴에서 두 칸을 차지
+fn char_width(c: char) -> usize {
+    match c as u32 {
+        0x1100..=0x115F
+        | 0x2E80..=0x303E
+        | 0x3041..=0x33FF
+        | 0x3400..=0x4DBF
+        | 0x4E00..=0x9FFF
+        | 0xA960..=0xA97F
+        | 0xAC00..=0xD7A3
+        | 0xF900..=0xFAFF
+        | 0xFE30..=0xFE4F
+        | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6
+        | 0x1F300..=0x1F64F
+        | 0x1F900..=0x1F9FF => 2,
+        _ => 1,
+    }
+}
+
+pub fn display_width(text: &str) -> usize {
+    text.chars().map(char_width).sum()
+}
+
+fn pad(text: &str, width: usize, right_align: bool) -> String {
+    let padding = " ".repeat(width.saturating_sub(display_width(text)));
+    if right_align {
+        format!("{}{}", padding, text)
+    } else {
+        format!("{}{}", text, padding)
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn cell_text(value: &Value) -> String {
+    // 셀 안 줄바꿈은 표를 깨뜨리므로 공백으로
+    value_to_text(value).replace(['\r', '\n'], " ")
+}
+
+struct Grid {
+    headers: Vec<String>,
+    cells: Vec<Vec<String>>,
+    numeric: Vec<bool>, // 열마다 숫자 열인지 (오른쪽 정렬)
+}
+
+// 행 데이터 → 헤더, 셀 문자열, 숫자 열 여부
+fn build_grid(rows: &[Value], columns: Option<Vec<String>>) -> Result<Grid, String> {
+    let headers: Vec<String> = match columns.filter(|c| !c.is_empty()) {
+        Some(columns) => columns,
+        None => match rows.first() {
+            // 객체 배열: 등장 순서대로 모든 키 수집
+            Some(Value::Object(_)) => {
+                let mut keys: Vec<String> = Vec::new();
+                for row in rows {
+                    for key in row.as_object().into_iter().flat_map(|o| o.keys()) {
+                        if !keys.contains(key) {
+                            keys.push(key.clone());
+                        }
+                    }
+                }
+                keys
+            }
+            // 배열의 배열: 첫 행을 헤더로 사용
+            Some(Value::Array(first)) => first.iter().map(cell_text).collect(),
+            _ => return Err("INVALID_ROWS: 객체 배열 또는 배열의 배열이 필요합니다".to_string()),
+        },
+    };
+
+    let first_is_header = matches!(rows.first(), Some(Value::Array(_)))
+        && headers
+            == rows[0]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(cell_text)
+                .collect::<Vec<_>>();
+
+    let mut cells = Vec::new();
+    let mut numeric = vec![true; headers.len()];
+
+    for row in rows.iter().skip(usize::from(first_is_header)) {
+        let values: Vec<Value> = match row {
+            Value::Object(map) => headers
+                .iter()
+                .map(|h| map.get(h).cloned().unwrap_or(Value::Null))
+                .collect(),
+            Value::Array(items) => (0..headers.len())
+                .map(|i| items.get(i).cloned().unwrap_or(Value::Null))
+                .collect(),
+            _ => return Err("INVALID_ROWS: 행은 객체 또는 배열이어야 합니다".to_string()),
+        };
+
+        for (i, value) in values.iter().enumerate() {
+            let is_number = value.is_number()
+                || value
+                    .as_str()
+                    .is_some_and(|s| s.trim().parse::<f64>().is_ok());
+            if !value.is_null() && !is_number {
+                numeric[i] = false;
+            }
+        }
+        cells.push(values.iter().map(cell_text).collect());
+    }
+
+    Ok(Grid {
+        headers,
+        cells,
+        numeric,
+    })
+}
+
+pub fn render_table(
+    rows: &[Value],
+    format: &str,
+    columns: Option<Vec<String>>,
+) -> Result<String, String> {
+    let Grid {
+        headers,
+        cells,
+        numeric,
+    } = build_grid(rows, columns)?;
+
+    match format {
+        "text" => {
+            let widths: Vec<usize> = (0..headers.len())
+                .map(|i| {
+                    cells
+                        .iter()
+                        .map(|row: &Vec<String>| display_width(&row[i]))
+                        .chain(std::iter::once(display_width(&headers[i])))
+                        .max()
+                        .unwrap_or(0)
+                })
+                .collect();
+
+            let line = |row: &[String]| {
+                row.iter()
+                    .enumerate()
+                    .map(|(i, cell)| pad(cell, widths[i], numeric[i]))
+                    .collect::<Vec<_>>()
+                    .join(" | ")
+                    .trim_end()
+                    .to_string()
+            };
+            let separator = widths
+                .iter()
+                .map(|w| "-".repeat(*w))
+                .collect::<Vec<_>>()
+                .join("-+-");
+
+            let mut lines = vec![line(&headers), separator];
+            lines.extend(cells.iter().map(|row| line(row)));
+            Ok(lines.join("\n"))
+        }
+        "markdown" => {
+            let escape = |s: &str| s.replace('|', "\\|");
+            let row_line = |row: &[String]| {
+                format!(
+                    "| {} |",
+                    row.iter()
+                        .map(|c| escape(c))
+                        .collect::<Vec<_>>()
+                        .join(" | ")
+                )
+            };
+            let align = numeric
+                .iter()
+                .map(|n| if *n { "---:" } else { "---" })
+                .collect::<Vec<_>>()
+                .join(" | ");
+
+            let mut lines = vec![row_line(&headers), format!("| {} |", align)];
+            lines.extend(cells.iter().map(|row| row_line(row)));
+            Ok(lines.join("\n"))
+        }
+        "html" => {
+            let mut html = String::from("<table>\n  <thead>\n    <tr>");
+            for header in &headers {
+                html.push_str(&format!("<th>{}</th>", escape_html(header)));
+            }
+            html.push_str("</tr>\n  </thead>\n  <tbody>\n");
+            for row in &cells {
+                html.push_str("    <tr>");
+                for (i, cell) in row.iter().enumerate() {
+                    let style = if numeric[i] {
+                        " style=\"text-align:right\""
+                    } else {
+                        ""
+                    };
+                    html.push_str(&format!("<td{}>{}</td>", style, escape_html(cell)));
+                }
+                html.push_str("</tr>\n");
+            }
+            html.push_str("  </tbody>\n</table>");
+            Ok(html)
+        }
+        other => Err(format!("UNSUPPORTED_FORMAT: {}", other)),
+    }
+}
+
+#[tauri::command]
+pub fn table_node(
+    rows: Value,
+    format: Option<String>,
+    columns: Option<Vec<String>>,
+) -> Result<String, String> {
+    // 이전 노드 출력이 JSON 문자열로 들어오는 경우도 허용
+    let rows = match rows {
+        Value::String(s) => serde_json::from_str(&s)
+            .map_err(|_| "INVALID_ROWS: JSON 배열이 필요합니다".to_string())?,
+        other => other,
+    };
+    let rows = rows
+        .as_array()
+        .ok_or("INVALID_ROWS: JSON 배열이 필요합니다")?;
+
+    let format = format
+        .map(|f| f.trim().to_lowercase())
+        .filter(|f| !f.is_empty())
+        .unwrap_or_else(|| "text".to_string());
+    println!("📊 Table Node: {}행 → {}", rows.len(), format);
+
+    let table = render_table(rows, &format, columns)?;
+    Ok(json!({ "table": table, "format": format, "row_count": rows.len() }).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn table_node_aligns_korean_text_and_numbers() {
+        let rows = json!([
+            { "이름": "홍길동", "score": 95 },
+            { "이름": "Kim", "score": 7 }
+        ]);
+
+        let text = render_table(
+            rows.as_array().unwrap(),
+            "text",
+            Some(vec!["이름".to_string(), "score".to_string()]),
+        )
+        .unwrap();
+
+        assert_eq!(
+            text,
+            "이름   | score\n-------+------\n홍길동 |    95\nKim    |     7"
+        );
+    }
+
+    #[test]
+    fn table_node_renders_markdown_from_array_rows() {
+        let rows = json!([["host", "status"], ["web|1", "up"]]);
+
+        let markdown = render_table(rows.as_array().unwrap(), "markdown", None).unwrap();
+
+        assert_eq!(
+            markdown,
+            "| host | status |\n| --- | --- |\n| web\\|1 | up |"
+        );
+    }
+}
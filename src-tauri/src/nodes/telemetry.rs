@@ -0,0 +1,191 @@
+// src-tauri/src/nodes/telemetry.rs
+use super::atomic_store;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+// 📊 사용 통계 (옵트인, 로컬 집계 전용)
+// 노드 종류별 실행 횟수 / 실행 시간 / 에러 코드만 기록하고 입력·출력 내용은 절대 저장하지 않음
+// 외부로 전송하지 않으며 get_usage_stats 로 사용자가 직접 확인
+
+const FLUSH_EVERY: u32 = 10;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NodeUsage {
+    pub runs: u64,
+    pub failures: u64,
+    pub total_duration_ms: u64,
+    pub max_duration_ms: u64,
+    pub error_codes: BTreeMap<String, u64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UsageStats {
+    pub enabled: bool,
+    pub since: Option<String>,
+    pub workflow_runs: u64,
+    pub workflow_failures: u64,
+    pub nodes: BTreeMap<String, NodeUsage>,
+}
+
+struct TelemetryState {
+    stats: UsageStats,
+    pending: u32,
+}
+
+static TELEMETRY: std::sync::OnceLock<Mutex<TelemetryState>> = std::sync::OnceLock::new();
+
+fn stats_path() -> PathBuf {
+    atomic_store::store_dir().join("usage_stats.json")
+}
+
+fn get_telemetry() -> &'static Mutex<TelemetryState> {
+    TELEMETRY.get_or_init(|| {
+        Mutex::new(TelemetryState {
+            stats: atomic_store::read_json_verified(&stats_path()).unwrap_or_default(),
+            pending: 0,
+        })
+    })
+}
+
+fn flush(state: &mut TelemetryState) -> Result<(), String> {
+    state.pending = 0;
+    atomic_store::write_json_atomic(&stats_path(), &state.stats)
+}
+
+// 노드 종류는 식별자 문자만 허용 (사용자 입력이 섞이지 않도록)
+pub fn sanitize_node_type(node_type: &str) -> Option<String> {
+    let node_type = node_type.trim();
+    (!node_type.is_empty()
+        && node_type.len() <= 64
+        && node_type
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-'))
+    .then(|| node_type.to_string())
+}
+
+// "WAIT_TIMEOUT: ..." 같은 에러 메시지에서 코드만 추출, 코드가 없으면 OTHER
+pub fn error_code_of(message: &str) -> String {
+    let head = message.split(':').next().unwrap_or("").trim();
+    let is_code = head.len() >= 3
+        && head.len() <= 48
+        && head
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+        && head.chars().next().is_some_and(|c| c.is_ascii_uppercase());
+    if is_code {
+        head.to_string()
+    } else {
+        "OTHER".to_string()
+    }
+}
+
+pub fn record(stats: &mut UsageStats, node_type: &str, duration_ms: u64, error: Option<&str>) {
+    let Some(node_type) = sanitize_node_type(node_type) else {
+        return;
+    };
+    let usage = stats.nodes.entry(node_type).or_default();
+    usage.runs += 1;
+    usage.total_duration_ms += duration_ms;
+    usage.max_duration_ms = usage.max_duration_ms.max(duration_ms);
+    if let Some(error) = error {
+        usage.failures += 1;
+        *usage.error_codes.entry(error_code_of(error)).or_insert(0) += 1;
+    }
+}
+
+#[tauri::command]
+pub fn set_telemetry_enabled(enabled: bool) -> Result<String, String> {
+    let mut state = get_telemetry().lock().map_err(|e| e.to_string())?;
+    state.stats.enabled = enabled;
+    if enabled && state.stats.since.is_none() {
+        state.stats.since = Some(chrono::Local::now().to_rfc3339());
+    }
+    flush(&mut state)?;
+    println!("📊 사용 통계: {}", if enabled { "켜짐" } else { "꺼짐" });
+    Ok("SUCCESS".to_string())
+}
+
+// 프론트엔드 실행 엔진이 노드 실행이 끝날 때마다 호출 (꺼져 있으면 무시)
+#[tauri::command]
+pub fn record_node_usage(
+    node_type: String,
+    duration_ms: u64,
+    error: Option<String>,
+) -> Result<(), String> {
+    let mut state = get_telemetry().lock().map_err(|e| e.to_string())?;
+    if !state.stats.enabled {
+        return Ok(());
+    }
+    record(&mut state.stats, &node_type, duration_ms, error.as_deref());
+    state.pending += 1;
+    if state.pending >= FLUSH_EVERY {
+        flush(&mut state)?;
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn record_workflow_usage(success: bool) -> Result<(), String> {
+    let mut state = get_telemetry().lock().map_err(|e| e.to_string())?;
+    if !state.stats.enabled {
+        return Ok(());
+    }
+    state.stats.workflow_runs += 1;
+    if !success {
+        state.stats.workflow_failures += 1;
+    }
+    flush(&mut state)
+}
+
+#[tauri::command]
+pub fn get_usage_stats() -> Result<UsageStats, String> {
+    let mut state = get_telemetry().lock().map_err(|e| e.to_string())?;
+    if state.pending > 0 {
+        flush(&mut state)?;
+    }
+    Ok(state.stats.clone())
+}
+
+#[tauri::command]
+pub fn reset_usage_stats() -> Result<String, String> {
+    let mut state = get_telemetry().lock().map_err(|e| e.to_string())?;
+    let enabled = state.stats.enabled;
+    state.stats = UsageStats {
+        enabled,
+        since: enabled.then(|| chrono::Local::now().to_rfc3339()),
+        ..Default::default()
+    };
+    flush(&mut state)?;
+    println!("📊 사용 통계 초기화");
+    Ok("SUCCESS".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn telemetry_aggregates_without_content() {
+        let mut stats = UsageStats::default();
+        record(&mut stats, "cli_node", 120, None);
+        record(
+            &mut stats,
+            "cli_node",
+            80,
+            Some("COMMAND_FAILED: secret output here"),
+        );
+        record(&mut stats, "cli_node", 10, Some("파일을 찾을 수 없습니다"));
+        record(&mut stats, "../etc/passwd", 10, None);
+
+        let usage = &stats.nodes["cli_node"];
+        assert_eq!(usage.runs, 3);
+        assert_eq!(usage.failures, 2);
+        assert_eq!(usage.total_duration_ms, 210);
+        assert_eq!(usage.max_duration_ms, 120);
+        assert_eq!(usage.error_codes["COMMAND_FAILED"], 1);
+        assert_eq!(usage.error_codes["OTHER"], 1);
+        assert_eq!(stats.nodes.len(), 1);
+    }
+}
@@ -0,0 +1,71 @@
+// src-tauri/src/nodes/template.rs
+use serde_json::Value;
+
+// 📝 간단한 텍스트 템플릿 ("{{path}}" → context 값)
+// path 는 점(.)으로 구분: {{alert.title}}, {{rows.0.name}}
+// 값이 없으면 빈 문자열, 문자열이 아닌 값은 JSON 으로 삽입
+
+pub fn lookup<'a>(context: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .filter(|segment| !segment.is_empty())
+        .try_fold(context, |value, segment| match value {
+            Value::Object(map) => map.get(segment),
+            Value::Array(items) => segment.parse::<usize>().ok().and_then(|i| items.get(i)),
+            _ => None,
+        })
+}
+
+pub fn value_to_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+pub fn render(template: &str, context: &Value) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+
+        match after.find("}}") {
+            Some(end) => {
+                let path = after[..end].trim();
+                if let Some(value) = lookup(context, path) {
+                    result.push_str(&value_to_text(value));
+                }
+                rest = &after[end + 2..];
+            }
+            None => {
+                // 닫는 괄호가 없으면 그대로 둠
+                result.push_str(&rest[start..]);
+                rest = "";
+            }
+        }
+    }
+
+    result.push_str(rest);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn template_renders_nested_paths_and_blanks_missing_values() {
+        let context =
+            json!({ "alert": { "title": "CPU high", "hosts": ["web-1", "web-2"] }, "count": 3 });
+
+        let rendered = render(
+            "[{{ alert.title }}] {{alert.hosts.1}} x{{count}}{{missing}} {{unclosed",
+            &context,
+        );
+
+        assert_eq!(rendered, "[CPU high] web-2 x3 {{unclosed");
+    }
+}
@@ -0,0 +1,97 @@
+// src-tauri/src/nodes/test_support.rs
+// 🧪 노드 단위 테스트 공용 헬퍼 (각 모듈의 #[cfg(test)] mod tests 에서 사용)
+use super::run_history::{NodeRun, RunLogEntry, RunRecord};
+use serde_json::{json, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// 테스트마다 독립된 임시 폴더 (drop 시 자동 삭제)
+pub fn temp_dir() -> tempfile::TempDir {
+    tempfile::tempdir().expect("failed to create temp dir")
+}
+
+pub fn path_str(path: &Path) -> String {
+    path.to_string_lossy().to_string()
+}
+
+fn golden_path(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+        .join(format!("{}.json", name))
+}
+
+// 노드 출력(JSON)을 골든 파일과 비교
+// UPDATE_GOLDEN=1 cargo test 로 실행하면 골든 파일을 현재 출력으로 갱신
+pub fn assert_golden(name: &str, actual: &Value) {
+    let path = golden_path(name);
+
+    if std::env::var("UPDATE_GOLDEN").is_ok() {
+        let content = serde_json::to_string_pretty(actual).expect("failed to serialize output");
+        fs::write(&path, content + "\n").expect("failed to write golden file");
+        return;
+    }
+
+    let expected_text = fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "golden file missing: {} (run with UPDATE_GOLDEN=1 to create it)",
+            path.display()
+        )
+    });
+    let expected: Value =
+        serde_json::from_str(&expected_text).expect("golden file is not valid JSON");
+
+    assert_eq!(
+        &expected,
+        actual,
+        "output differs from golden file {}",
+        path.display()
+    );
+}
+
+// 노드가 돌려주는 JSON 문자열 파싱
+pub fn parse_output(output: &str) -> Value {
+    serde_json::from_str(output).unwrap_or_else(|_| panic!("node output is not JSON: {}", output))
+}
+
+// 실행 기록 샘플 (노드 2개 + 경고 로그 1개)
+pub fn sample_run() -> RunRecord {
+    RunRecord {
+        run_id: "20260101-120000-000-0".to_string(),
+        workflow: "백업 <daily>".to_string(),
+        status: "error".to_string(),
+        started_at: "2026-01-01T12:00:00+09:00".to_string(),
+        finished_at: Some("2026-01-01T12:00:03+09:00".to_string()),
+        nodes: vec![
+            NodeRun {
+                node_id: "cli-1".to_string(),
+                node_type: "cli_node".to_string(),
+                status: "success".to_string(),
+                started_at: 1_000,
+                finished_at: 1_250,
+                inputs: json!({ "command": "echo hi", "api_token": "abc123" }),
+                output: Some("hi".to_string()),
+                error: None,
+                artifacts: vec!["/tmp/out.txt".to_string()],
+            },
+            NodeRun {
+                node_id: "http-2".to_string(),
+                node_type: "http_node".to_string(),
+                status: "error".to_string(),
+                started_at: 1_250,
+                finished_at: 4_000,
+                inputs: json!(null),
+                output: None,
+                error: Some("TIMEOUT".to_string()),
+                artifacts: vec![],
+            },
+        ],
+        logs: vec![RunLogEntry {
+            timestamp: 1_300,
+            level: "warn".to_string(),
+            node_id: Some("http-2".to_string()),
+            message: "재시도 <1/3>".to_string(),
+            context: json!({ "attempt": 1 }),
+        }],
+    }
+}
@@ -1,5 +1,5 @@
+use super::safe_path;
 use std::fs;
-use std::path::Path;
 
 #[tauri::command]
 pub fn text_file_editor_node(
@@ -19,7 +19,13 @@ pub fn text_file_editor_node(
         return Err("EMPTY_NEW_FILE_NAME".to_string());
     }
 
-    let source_path = Path::new(trimmed_file_path);
+    // 새 이름 검증 (NFC 정규화, 예약 이름 / 금지 문자)
+    let trimmed_new_file_name = safe_path::nfc(trimmed_new_file_name);
+    safe_path::validate_file_name(&trimmed_new_file_name)?;
+
+    // NFD 로 저장된 한글 파일명도 찾고, 긴 경로는 확장 접두사로
+    let source_path = safe_path::resolve_existing(trimmed_file_path);
+    let source_path = source_path.as_path();
 
     // 원본 파일이 존재하는지 확인
     if !source_path.exists() {
@@ -37,17 +43,15 @@ pub fn text_file_editor_node(
         None => return Err("INVALID_SOURCE_PATH".to_string()),
     };
 
-    let new_file_path = parent_dir.join(trimmed_new_file_name);
+    let new_file_path = parent_dir.join(&trimmed_new_file_name);
 
     // 새 내용으로 파일 쓰기
     match fs::write(&new_file_path, new_file_content) {
         Ok(_) => {
             // 원본 파일과 새 파일이 다른 경우, 원본 파일 삭제
-            if source_path != new_file_path {
-                if let Err(_) = fs::remove_file(source_path) {
-                    // 원본 파일 삭제 실패는 경고만 하고 성공으로 처리
-                    println!("Warning: Could not delete original file: {:?}", source_path);
-                }
+            if source_path != new_file_path && fs::remove_file(source_path).is_err() {
+                // 원본 파일 삭제 실패는 경고만 하고 성공으로 처리
+                println!("Warning: Could not delete original file: {:?}", source_path);
             }
 
             Ok("SUCCESS".to_string())
@@ -55,3 +59,43 @@ pub fn text_file_editor_node(
         Err(_) => Err("FILE_WRITE_ERROR".to_string()),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::{path_str, temp_dir};
+    use std::fs;
+
+    #[test]
+    fn text_file_editor_renames_and_rewrites() {
+        let dir = temp_dir();
+        let source = dir.path().join("old.txt");
+        fs::write(&source, "before").unwrap();
+
+        let result = text_file_editor_node(
+            path_str(&source),
+            "new.txt".to_string(),
+            "after".to_string(),
+        );
+
+        assert_eq!(result, Ok("SUCCESS".to_string()));
+        assert!(!source.exists());
+        assert_eq!(
+            fs::read_to_string(dir.path().join("new.txt")).unwrap(),
+            "after"
+        );
+    }
+
+    #[test]
+    fn text_file_editor_reports_missing_source() {
+        let dir = temp_dir();
+
+        let result = text_file_editor_node(
+            path_str(&dir.path().join("missing.txt")),
+            "new.txt".to_string(),
+            "x".to_string(),
+        );
+
+        assert_eq!(result, Err("SOURCE_FILE_NOT_FOUND".to_string()));
+    }
+}
@@ -1,4 +1,50 @@
+use super::{safe_path, value_store};
 use serde_json::json;
+use std::io::Write;
+use std::path::PathBuf;
+
+// 📝 텍스트 병합 노드
+// 입력은 일반 텍스트 또는 값 보관소 핸들(val_…) - 핸들은 파일에서 바로 이어 붙여 메모리에 올리지 않음
+// 결과가 INLINE_LIMIT 를 넘거나 핸들 입력이 있으면 보관소 파일에 직접 쓰고 미리보기 + outputHandle 반환
+// (이때 입력 원문은 결과 JSON 에 다시 싣지 않음 - 수백 MB 로그 병합 시 복사본이 여러 벌 생기지 않도록)
+
+enum Part<'a> {
+    Text(&'a str),
+    Stored(PathBuf, u64),
+}
+
+impl<'a> Part<'a> {
+    fn new(text: &'a str) -> Self {
+        match value_store::handle_path(text) {
+            Some(path) => {
+                let path = safe_path::extended(&path);
+                let size = std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+                Part::Stored(path, size)
+            }
+            None => Part::Text(text),
+        }
+    }
+
+    fn len(&self) -> u64 {
+        match self {
+            Part::Text(text) => text.len() as u64,
+            Part::Stored(_, size) => *size,
+        }
+    }
+
+    fn is_stored(&self) -> bool {
+        matches!(self, Part::Stored(..))
+    }
+
+    fn write_to(&self, out: &mut dyn Write) -> std::io::Result<()> {
+        match self {
+            Part::Text(text) => out.write_all(text.as_bytes()),
+            Part::Stored(path, _) => {
+                std::io::copy(&mut std::fs::File::open(path)?, out).map(|_| ())
+            }
+        }
+    }
+}
 
 #[tauri::command]
 pub fn text_merger_node(
@@ -6,27 +52,54 @@ pub fn text_merger_node(
     text2: String,
     separator: String,
 ) -> Result<String, String> {
+    let first = Part::new(&text1);
+    let second = Part::new(&text2);
     println!("📝 Text Merger Node executing:");
-    println!("  Text1: '{}'", text1);
-    println!("  Text2: '{}'", text2);
+    println!("  Text1: {} bytes{}", first.len(), if first.is_stored() { " (보관소)" } else { "" });
+    println!("  Text2: {} bytes{}", second.len(), if second.is_stored() { " (보관소)" } else { "" });
     println!("  Separator: '{}'", separator);
 
     // 입력값 검증 (빈 문자열도 허용하지만 로그로 표시)
-    if text1.is_empty() && text2.is_empty() {
+    if first.len() == 0 && second.len() == 0 {
         println!("⚠️ Both texts are empty, will return empty result");
     }
 
-    // 텍스트 병합
-    let merged_text = if text1.is_empty() && text2.is_empty() {
-        String::new()
-    } else if text1.is_empty() {
-        text2.clone()
-    } else if text2.is_empty() {
-        text1.clone()
-    } else {
-        format!("{}{}{}", text1, separator, text2)
-    };
-    
+    // 한쪽이 비어 있으면 구분자 없이 나머지만
+    let use_separator = first.len() > 0 && second.len() > 0;
+    let length = first.len()
+        + second.len()
+        + if use_separator { separator.len() as u64 } else { 0 };
+
+    // 🗃️ 큰 결과는 보관소 파일에 바로 씀
+    if first.is_stored() || second.is_stored() || length > value_store::INLINE_LIMIT as u64 {
+        let stored = value_store::store_with(|out| {
+            first.write_to(out)?;
+            if use_separator {
+                out.write_all(separator.as_bytes())?;
+            }
+            second.write_to(out)
+        })?;
+        println!("✅ Text merged into value store: {} ({} bytes)", stored.handle, length);
+        return Ok(json!({
+            "merged_text": stored.preview,
+            "outputHandle": stored.handle,
+            "truncated": true,
+            "separator": separator,
+            "length": length,
+            "text1Length": first.len(),
+            "text2Length": second.len()
+        })
+        .to_string());
+    }
+
+    // 텍스트 병합 (한 번만 할당)
+    let mut merged_text = String::with_capacity(length as usize);
+    merged_text.push_str(&text1);
+    if use_separator {
+        merged_text.push_str(&separator);
+    }
+    merged_text.push_str(&text2);
+
     println!("✅ Text merged successfully: '{}'", merged_text);
 
     // JSON 형태로 결과 반환 (FileCreator 패턴과 동일)
@@ -39,4 +112,44 @@ pub fn text_merger_node(
     });
 
     Ok(result.to_string())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::{value_store, release_value, store_large_value};
+    use crate::nodes::test_support::{assert_golden, parse_output};
+
+    #[test]
+    fn text_merger_matches_golden() {
+        let output = text_merger_node("foo".to_string(), "bar".to_string(), ", ".to_string()).unwrap();
+
+        assert_golden("text_merger_basic", &parse_output(&output));
+    }
+
+    #[test]
+    fn text_merger_skips_separator_when_one_side_empty() {
+        let output = text_merger_node("".to_string(), "bar".to_string(), ", ".to_string()).unwrap();
+
+        assert_eq!(parse_output(&output)["merged_text"], "bar");
+    }
+
+    #[test]
+    fn text_merger_streams_large_and_stored_inputs_to_value_store() {
+        let big = "x".repeat(value_store::INLINE_LIMIT);
+        let stored = store_large_value("line1\nline2\n".to_string()).unwrap();
+
+        let output = text_merger_node(stored.handle.clone(), big, "\n".to_string()).unwrap();
+        let value = parse_output(&output);
+        let handle = value["outputHandle"].as_str().unwrap().to_string();
+        assert_eq!(value["truncated"], true);
+        assert_eq!(value["length"], 12 + 1 + value_store::INLINE_LIMIT as u64);
+        assert!(value.get("text1").is_none() && value.get("text2").is_none());
+        assert!(value["merged_text"].as_str().unwrap().starts_with("line1\nline2\n\nxxx"));
+
+        let (head, _, _) = value_store::read_chunk(&handle, 0, 16).unwrap();
+        assert_eq!(head, "line1\nline2\n\nxxx");
+        release_value(handle).unwrap();
+        release_value(stored.handle).unwrap();
+    }
+}
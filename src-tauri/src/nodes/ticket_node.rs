@@ -0,0 +1,335 @@
+// src-tauri/src/nodes/ticket_node.rs
+use super::{http_client, template};
+use serde_json::{json, Value};
+
+// 🎫 Jira / Linear 티켓 노드 (장애 대응 자동화용)
+// action: create(summary, description) | transition(issue_key, transition)
+// summary / description 은 "{{path}}" 템플릿으로 이전 노드 데이터(context)를 삽입
+
+const LINEAR_API_URL: &str = "https://api.linear.app/graphql";
+
+fn required<'a>(value: Option<&'a str>, error: &str) -> Result<&'a str, String> {
+    value
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .ok_or_else(|| error.to_string())
+}
+
+async fn read_response(response: reqwest::Response) -> Result<Value, String> {
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+
+    if !status.is_success() {
+        return Err(format!("TICKET_API_ERROR ({}): {}", status.as_u16(), body));
+    }
+    if body.trim().is_empty() {
+        return Ok(Value::Null);
+    }
+    serde_json::from_str(&body).map_err(|e| format!("응답 파싱 실패: {}", e))
+}
+
+// ===== Jira (REST API v2, 이메일 + API 토큰 인증) =====
+
+#[allow(clippy::too_many_arguments)]
+async fn jira_create(
+    client: &reqwest::Client,
+    base_url: &str,
+    email: &str,
+    api_token: &str,
+    project: &str,
+    issue_type: &str,
+    summary: &str,
+    description: &str,
+) -> Result<Value, String> {
+    let response = client
+        .post(format!("{}/rest/api/2/issue", base_url))
+        .basic_auth(email, Some(api_token))
+        .json(&json!({
+            "fields": {
+                "project": { "key": project },
+                "issuetype": { "name": issue_type },
+                "summary": summary,
+                "description": description
+            }
+        }))
+        .send()
+        .await
+        .map_err(|e| format!("Jira 요청 실패: {}", e))?;
+
+    let created = read_response(response).await?;
+    let key = created["key"].as_str().unwrap_or_default();
+
+    Ok(json!({
+        "provider": "jira",
+        "issue_key": key,
+        "id": created["id"],
+        "url": format!("{}/browse/{}", base_url, key)
+    }))
+}
+
+async fn jira_transition(
+    client: &reqwest::Client,
+    base_url: &str,
+    email: &str,
+    api_token: &str,
+    issue_key: &str,
+    transition: &str,
+) -> Result<Value, String> {
+    let url = format!("{}/rest/api/2/issue/{}/transitions", base_url, issue_key);
+
+    // 전환 이름 → id
+    let response = client
+        .get(&url)
+        .basic_auth(email, Some(api_token))
+        .send()
+        .await
+        .map_err(|e| format!("Jira 요청 실패: {}", e))?;
+    let available = read_response(response).await?;
+
+    let transitions = available["transitions"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default();
+    let target = transitions
+        .iter()
+        .find(|t| {
+            t["name"]
+                .as_str()
+                .is_some_and(|n| n.eq_ignore_ascii_case(transition))
+                || t["to"]["name"]
+                    .as_str()
+                    .is_some_and(|n| n.eq_ignore_ascii_case(transition))
+        })
+        .ok_or_else(|| {
+            let names: Vec<&str> = transitions
+                .iter()
+                .filter_map(|t| t["name"].as_str())
+                .collect();
+            format!(
+                "TRANSITION_NOT_FOUND: {} (가능: {})",
+                transition,
+                names.join(", ")
+            )
+        })?;
+
+    let response = client
+        .post(&url)
+        .basic_auth(email, Some(api_token))
+        .json(&json!({ "transition": { "id": target["id"] } }))
+        .send()
+        .await
+        .map_err(|e| format!("Jira 요청 실패: {}", e))?;
+    read_response(response).await?;
+
+    Ok(json!({
+        "provider": "jira",
+        "issue_key": issue_key,
+        "transition": target["name"],
+        "url": format!("{}/browse/{}", base_url, issue_key)
+    }))
+}
+
+// ===== Linear (GraphQL, API 키 인증) =====
+
+async fn linear_query(
+    client: &reqwest::Client,
+    api_token: &str,
+    query: &str,
+    variables: Value,
+) -> Result<Value, String> {
+    let response = client
+        .post(LINEAR_API_URL)
+        .header("Authorization", api_token)
+        .json(&json!({ "query": query, "variables": variables }))
+        .send()
+        .await
+        .map_err(|e| format!("Linear 요청 실패: {}", e))?;
+
+    let body = read_response(response).await?;
+    if let Some(errors) = body["errors"].as_array() {
+        let messages: Vec<&str> = errors
+            .iter()
+            .filter_map(|e| e["message"].as_str())
+            .collect();
+        return Err(format!("TICKET_API_ERROR: {}", messages.join("; ")));
+    }
+    Ok(body["data"].clone())
+}
+
+async fn linear_create(
+    client: &reqwest::Client,
+    api_token: &str,
+    team: &str,
+    summary: &str,
+    description: &str,
+) -> Result<Value, String> {
+    // 팀 키(ENG) 또는 id 로 팀 찾기
+    let teams = linear_query(
+        client,
+        api_token,
+        "query { teams { nodes { id key name } } }",
+        json!({}),
+    )
+    .await?;
+    let team_id = teams["teams"]["nodes"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .find(|t| {
+            [&t["id"], &t["key"], &t["name"]]
+                .iter()
+                .any(|v| v.as_str().is_some_and(|s| s.eq_ignore_ascii_case(team)))
+        })
+        .and_then(|t| t["id"].as_str())
+        .ok_or_else(|| format!("TEAM_NOT_FOUND: {}", team))?
+        .to_string();
+
+    let data = linear_query(
+        client,
+        api_token,
+        "mutation($input: IssueCreateInput!) { issueCreate(input: $input) { success issue { id identifier url } } }",
+        json!({ "input": { "teamId": team_id, "title": summary, "description": description } }),
+    )
+    .await?;
+
+    let issue = &data["issueCreate"]["issue"];
+    Ok(json!({
+        "provider": "linear",
+        "issue_key": issue["identifier"],
+        "id": issue["id"],
+        "url": issue["url"]
+    }))
+}
+
+async fn linear_transition(
+    client: &reqwest::Client,
+    api_token: &str,
+    issue_key: &str,
+    transition: &str,
+) -> Result<Value, String> {
+    let data = linear_query(
+        client,
+        api_token,
+        "query($id: String!) { issue(id: $id) { id url team { states { nodes { id name } } } } }",
+        json!({ "id": issue_key }),
+    )
+    .await?;
+
+    let issue = &data["issue"];
+    let state = issue["team"]["states"]["nodes"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .find(|s| {
+            s["name"]
+                .as_str()
+                .is_some_and(|n| n.eq_ignore_ascii_case(transition))
+        })
+        .ok_or_else(|| format!("TRANSITION_NOT_FOUND: {}", transition))?;
+
+    linear_query(
+        client,
+        api_token,
+        "mutation($id: String!, $input: IssueUpdateInput!) { issueUpdate(id: $id, input: $input) { success } }",
+        json!({ "id": issue["id"], "input": { "stateId": state["id"] } }),
+    )
+    .await?;
+
+    Ok(json!({
+        "provider": "linear",
+        "issue_key": issue_key,
+        "transition": state["name"],
+        "url": issue["url"]
+    }))
+}
+
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn ticket_node(
+    provider: String,
+    action: String,
+    api_token: String,
+    base_url: Option<String>,
+    email: Option<String>,
+    project: Option<String>,
+    issue_type: Option<String>,
+    summary: Option<String>,
+    description: Option<String>,
+    issue_key: Option<String>,
+    transition: Option<String>,
+    context: Option<Value>,
+) -> Result<String, String> {
+    let provider = provider.trim().to_lowercase();
+    let action = action.trim().to_lowercase();
+    println!("🎫 Ticket Node: {} {}", provider, action);
+
+    if api_token.trim().is_empty() {
+        return Err("EMPTY_API_TOKEN".to_string());
+    }
+
+    let context = context.unwrap_or(Value::Null);
+    let summary = template::render(summary.as_deref().unwrap_or(""), &context);
+    let description = template::render(description.as_deref().unwrap_or(""), &context);
+    let client = http_client::client();
+
+    let result = match (provider.as_str(), action.as_str()) {
+        ("jira", _) => {
+            let base_url = required(base_url.as_deref(), "EMPTY_BASE_URL")?.trim_end_matches('/');
+            let email = required(email.as_deref(), "EMPTY_EMAIL")?;
+
+            if action == "create" {
+                jira_create(
+                    &client,
+                    base_url,
+                    email,
+                    api_token.trim(),
+                    required(project.as_deref(), "EMPTY_PROJECT")?,
+                    issue_type
+                        .as_deref()
+                        .map(str::trim)
+                        .filter(|t| !t.is_empty())
+                        .unwrap_or("Task"),
+                    required(Some(summary.as_str()), "EMPTY_SUMMARY")?,
+                    &description,
+                )
+                .await?
+            } else if action == "transition" {
+                jira_transition(
+                    &client,
+                    base_url,
+                    email,
+                    api_token.trim(),
+                    required(issue_key.as_deref(), "EMPTY_ISSUE_KEY")?,
+                    required(transition.as_deref(), "EMPTY_TRANSITION")?,
+                )
+                .await?
+            } else {
+                return Err(format!("UNSUPPORTED_ACTION: {}", action));
+            }
+        }
+        ("linear", "create") => {
+            linear_create(
+                &client,
+                api_token.trim(),
+                required(project.as_deref(), "EMPTY_TEAM")?,
+                required(Some(summary.as_str()), "EMPTY_SUMMARY")?,
+                &description,
+            )
+            .await?
+        }
+        ("linear", "transition") => {
+            linear_transition(
+                &client,
+                api_token.trim(),
+                required(issue_key.as_deref(), "EMPTY_ISSUE_KEY")?,
+                required(transition.as_deref(), "EMPTY_TRANSITION")?,
+            )
+            .await?
+        }
+        ("linear", _) => return Err(format!("UNSUPPORTED_ACTION: {}", action)),
+        _ => return Err(format!("UNSUPPORTED_PROVIDER: {}", provider)),
+    };
+
+    println!("✅ 티켓 처리 완료: {}", result["issue_key"]);
+    Ok(result.to_string())
+}
@@ -0,0 +1,308 @@
+// src-tauri/src/nodes/timer_node.rs
+use serde::Serialize;
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+use tokio::sync::RwLock;
+
+// ⏱️ 타이머 노드 (카운트다운 / 스톱워치)
+// tick_ms 마다 "timer-tick", 끝나면 "timer-complete", 취소되면 "timer-cancelled" 이벤트
+// - countdown: duration_secs 동안 진행, 기본적으로 끝날 때까지 기다린 뒤 다음 노드로 (뽀모도로, 쿨다운)
+// - stopwatch: cancel_timer 로 멈출 때까지 (duration_secs 가 있으면 그 시간에 자동 종료)
+// timer_id 를 주지 않으면 node_id 로 관리, 같은 ID 로 다시 시작하면 기존 타이머는 교체
+
+const DEFAULT_TICK_MS: u64 = 1000;
+const MIN_TICK_MS: u64 = 100;
+
+#[derive(Debug, Serialize, Clone)]
+pub struct TimerEvent {
+    pub timer_id: String,
+    pub node_id: String,
+    pub mode: String,
+    pub label: Option<String>,
+    pub elapsed_ms: u64,
+    pub remaining_ms: Option<u64>,
+    pub progress: Option<f64>,
+    pub display: String,
+}
+
+struct TimerHandle {
+    node_id: String,
+    mode: String,
+    label: Option<String>,
+    duration_ms: Option<u64>,
+    started_at: Instant,
+    abort_handle: tokio::task::AbortHandle,
+}
+
+type TimerRegistry = Arc<RwLock<HashMap<String, TimerHandle>>>;
+
+static TIMER_REGISTRY: std::sync::OnceLock<TimerRegistry> = std::sync::OnceLock::new();
+
+fn get_timer_registry() -> &'static TimerRegistry {
+    TIMER_REGISTRY.get_or_init(|| Arc::new(RwLock::new(HashMap::new())))
+}
+
+// 남은 시간 / 진행률 (0.0 ~ 1.0)
+pub fn progress(elapsed_ms: u64, duration_ms: Option<u64>) -> (Option<u64>, Option<f64>) {
+    match duration_ms {
+        Some(0) => (Some(0), Some(1.0)),
+        Some(duration) => (
+            Some(duration.saturating_sub(elapsed_ms)),
+            Some((elapsed_ms as f64 / duration as f64).min(1.0)),
+        ),
+        None => (None, None),
+    }
+}
+
+// 화면 표시용 "MM:SS" (1시간 이상이면 "H:MM:SS"), 카운트다운은 남은 시간을 올림으로
+pub fn format_clock(ms: u64, round_up: bool) -> String {
+    let secs = if round_up {
+        ms.div_ceil(1000)
+    } else {
+        ms / 1000
+    };
+    let (hours, minutes, seconds) = (secs / 3600, (secs % 3600) / 60, secs % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{:02}:{:02}", minutes, seconds)
+    }
+}
+
+fn build_event(
+    timer_id: &str,
+    node_id: &str,
+    mode: &str,
+    label: &Option<String>,
+    elapsed_ms: u64,
+    duration_ms: Option<u64>,
+) -> TimerEvent {
+    let (remaining_ms, progress) = progress(elapsed_ms, duration_ms);
+    let display = match (mode, remaining_ms) {
+        ("countdown", Some(remaining)) => format_clock(remaining, true),
+        _ => format_clock(elapsed_ms, false),
+    };
+    TimerEvent {
+        timer_id: timer_id.to_string(),
+        node_id: node_id.to_string(),
+        mode: mode.to_string(),
+        label: label.clone(),
+        elapsed_ms,
+        remaining_ms,
+        progress,
+        display,
+    }
+}
+
+async fn run_timer(
+    app_handle: AppHandle,
+    timer_id: String,
+    node_id: String,
+    mode: String,
+    label: Option<String>,
+    duration_ms: Option<u64>,
+    tick_ms: u64,
+) -> TimerEvent {
+    let started = Instant::now();
+    let mut interval = tokio::time::interval(Duration::from_millis(tick_ms));
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+    loop {
+        interval.tick().await;
+        let elapsed_ms = started.elapsed().as_millis() as u64;
+        if duration_ms.is_some_and(|duration| elapsed_ms >= duration) {
+            break;
+        }
+        let event = build_event(&timer_id, &node_id, &mode, &label, elapsed_ms, duration_ms);
+        let _ = app_handle.emit("timer-tick", &event);
+
+        // 다음 틱보다 종료가 먼저면 종료 시점까지만 대기
+        if let Some(remaining) = event.remaining_ms.filter(|r| *r < tick_ms) {
+            tokio::time::sleep(Duration::from_millis(remaining)).await;
+            break;
+        }
+    }
+
+    let elapsed_ms = started.elapsed().as_millis() as u64;
+    let event = build_event(&timer_id, &node_id, &mode, &label, elapsed_ms, duration_ms);
+    get_timer_registry().write().await.remove(&timer_id);
+    println!("⏰ 타이머 완료: {} ({})", timer_id, event.display);
+    if let Err(e) = app_handle.emit("timer-complete", &event) {
+        eprintln!("❌ Failed to emit timer event: {}", e);
+    }
+    event
+}
+
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn timer_node(
+    app_handle: AppHandle,
+    node_id: String,
+    mode: Option<String>,
+    duration_secs: Option<f64>,
+    tick_ms: Option<u64>,
+    label: Option<String>,
+    timer_id: Option<String>,
+    wait: Option<bool>,
+) -> Result<String, String> {
+    let mode = mode
+        .map(|m| m.trim().to_lowercase())
+        .filter(|m| !m.is_empty())
+        .unwrap_or_else(|| "countdown".to_string());
+    let duration_ms = match duration_secs {
+        Some(secs) if !secs.is_finite() || secs < 0.0 => {
+            return Err(format!("INVALID_DURATION: {}", secs))
+        }
+        Some(secs) => Some((secs * 1000.0).round() as u64),
+        None => None,
+    };
+    match mode.as_str() {
+        "countdown" if duration_ms.is_none() => return Err("DURATION_REQUIRED".to_string()),
+        "countdown" | "stopwatch" => {}
+        other => return Err(format!("UNKNOWN_TIMER_MODE: {}", other)),
+    }
+    let tick_ms = tick_ms.unwrap_or(DEFAULT_TICK_MS).max(MIN_TICK_MS);
+    let label = label.filter(|l| !l.trim().is_empty());
+    let timer_id = timer_id
+        .map(|id| id.trim().to_string())
+        .filter(|id| !id.is_empty())
+        .unwrap_or_else(|| node_id.clone());
+    // 끝이 없는 스톱워치는 기다리지 않음
+    let wait = wait.unwrap_or(mode == "countdown") && duration_ms.is_some();
+
+    // 같은 ID 의 기존 타이머는 교체
+    let _ = cancel_timer(app_handle.clone(), timer_id.clone()).await;
+
+    println!(
+        "⏱️ 타이머 시작: {} ({}, {:?}ms, tick {}ms)",
+        timer_id, mode, duration_ms, tick_ms
+    );
+    let task = tokio::spawn(run_timer(
+        app_handle,
+        timer_id.clone(),
+        node_id.clone(),
+        mode.clone(),
+        label.clone(),
+        duration_ms,
+        tick_ms,
+    ));
+    get_timer_registry().write().await.insert(
+        timer_id.clone(),
+        TimerHandle {
+            node_id,
+            mode: mode.clone(),
+            label,
+            duration_ms,
+            started_at: Instant::now(),
+            abort_handle: task.abort_handle(),
+        },
+    );
+
+    // 등록 전에 이미 끝난 짧은 타이머는 목록에서 제거
+    if task.is_finished() {
+        get_timer_registry().write().await.remove(&timer_id);
+    }
+
+    if !wait {
+        return Ok(json!({ "timer_id": timer_id, "mode": mode, "started": true }).to_string());
+    }
+    match task.await {
+        Ok(event) => Ok(json!({
+            "timer_id": timer_id,
+            "mode": mode,
+            "completed": true,
+            "elapsed_ms": event.elapsed_ms
+        })
+        .to_string()),
+        Err(e) if e.is_cancelled() => Err(format!("TIMER_CANCELLED: {}", timer_id)),
+        Err(e) => Err(format!("타이머 실행 실패: {}", e)),
+    }
+}
+
+#[tauri::command]
+pub async fn cancel_timer(app_handle: AppHandle, timer_id: String) -> Result<String, String> {
+    match get_timer_registry().write().await.remove(&timer_id) {
+        Some(handle) => {
+            handle.abort_handle.abort();
+            let elapsed_ms = handle.started_at.elapsed().as_millis() as u64;
+            let event = build_event(
+                &timer_id,
+                &handle.node_id,
+                &handle.mode,
+                &handle.label,
+                elapsed_ms,
+                handle.duration_ms,
+            );
+            println!("🛑 타이머 취소: {} ({})", timer_id, event.display);
+            let _ = app_handle.emit("timer-cancelled", &event);
+            Ok(json!({ "cancelled": true, "elapsed_ms": elapsed_ms }).to_string())
+        }
+        None => Ok(json!({ "cancelled": false }).to_string()),
+    }
+}
+
+#[tauri::command]
+pub async fn get_timer_status(timer_id: String) -> Result<serde_json::Value, String> {
+    let timers = get_timer_registry().read().await;
+    Ok(match timers.get(&timer_id) {
+        Some(handle) => {
+            let event = build_event(
+                &timer_id,
+                &handle.node_id,
+                &handle.mode,
+                &handle.label,
+                handle.started_at.elapsed().as_millis() as u64,
+                handle.duration_ms,
+            );
+            let mut status = serde_json::to_value(event).map_err(|e| e.to_string())?;
+            status["running"] = json!(!handle.abort_handle.is_finished());
+            status
+        }
+        None => json!({ "running": false }),
+    })
+}
+
+#[tauri::command]
+pub async fn list_timers() -> Result<Vec<TimerEvent>, String> {
+    let timers = get_timer_registry().read().await;
+    Ok(timers
+        .iter()
+        .map(|(timer_id, handle)| {
+            build_event(
+                timer_id,
+                &handle.node_id,
+                &handle.mode,
+                &handle.label,
+                handle.started_at.elapsed().as_millis() as u64,
+                handle.duration_ms,
+            )
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timer_progress_and_clock_display() {
+        assert_eq!(
+            progress(2_500, Some(10_000)),
+            (Some(7_500), Some(0.25))
+        );
+        assert_eq!(
+            progress(12_000, Some(10_000)),
+            (Some(0), Some(1.0))
+        );
+        assert_eq!(progress(0, Some(0)), (Some(0), Some(1.0)));
+        assert_eq!(progress(5_000, None), (None, None));
+
+        // 카운트다운은 남은 시간을 올림 (0.2초 남음 → 00:01)
+        assert_eq!(format_clock(200, true), "00:01");
+        assert_eq!(format_clock(1_500_000, false), "25:00");
+        assert_eq!(format_clock(3_723_000, false), "1:02:03");
+    }
+}
@@ -0,0 +1,471 @@
+// src-tauri/src/nodes/tools.rs
+use super::atomic_store;
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Emitter};
+use tokio::io::AsyncWriteExt;
+
+// 🧰 외부 도구 다운로드 관리자
+// "binaries 폴더에 exe 를 직접 복사" 하는 대신 플랫폼별 도구를 내려받아 SHA-256 검증 후 store/tools 에 배치
+// - 버전 고정: 기본 카탈로그(yt-dlp, cloudflared) + 사용자 고정값(store/tools/pins.json, 기본값보다 우선)
+// - 검증: 고정값의 sha256 또는 checksum_url(SHA256SUMS 형식 목록)에서 파일 이름으로 찾은 값, 둘 다 없으면 설치 거부
+// - zip 으로 배포되는 도구(ffmpeg, whisper)는 archive_entry 로 압축 안의 실행 파일 경로를 지정
+// 설치 정보는 store/tools/installed.json, 프로필과 무관하게 공용
+// os_command::bundled_binary 등은 binaries 폴더 → 관리 폴더 → PATH 순서로 찾음
+
+const MAX_DOWNLOAD_BYTES: u64 = 1024 * 1024 * 1024;
+const PROGRESS_STEP_BYTES: u64 = 1024 * 1024;
+const YT_DLP_VERSION: &str = "2024.08.06";
+const CLOUDFLARED_VERSION: &str = "2024.8.2";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ToolPin {
+    pub version: String,
+    pub url: String,
+    #[serde(default)]
+    pub sha256: Option<String>,
+    #[serde(default)]
+    pub checksum_url: Option<String>,
+    #[serde(default)]
+    pub archive_entry: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InstalledTool {
+    pub version: String,
+    pub sha256: String,
+    pub source_url: String,
+    pub installed_at: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ToolStatus {
+    pub name: String,
+    pub platform: String,
+    pub pinned_version: Option<String>,
+    pub installed_version: Option<String>,
+    pub source: String, // "binaries" | "managed" | "missing"
+    pub path: Option<String>,
+    pub up_to_date: bool,
+}
+
+// 관리 대상 도구 → 실행 파일 이름 (확장자 제외)
+pub const TOOLS: [(&str, &str); 4] = [
+    ("yt-dlp", "yt-dlp"),
+    ("ffmpeg", "ffmpeg"),
+    ("cloudflared", "cloudflared"),
+    ("whisper", "whisper-stream"),
+];
+
+pub fn tools_dir() -> PathBuf {
+    atomic_store::base_store_dir().join("tools")
+}
+
+fn pins_path() -> PathBuf {
+    tools_dir().join("pins.json")
+}
+
+fn installed_path() -> PathBuf {
+    tools_dir().join("installed.json")
+}
+
+// 사용자 고정값: 도구 이름 → 플랫폼 → 고정값
+fn load_pins() -> BTreeMap<String, BTreeMap<String, ToolPin>> {
+    atomic_store::read_json_verified(&pins_path()).unwrap_or_default()
+}
+
+fn load_installed() -> BTreeMap<String, InstalledTool> {
+    atomic_store::read_json_verified(&installed_path()).unwrap_or_default()
+}
+
+pub fn platform_key() -> String {
+    format!("{}-{}", std::env::consts::OS, std::env::consts::ARCH)
+}
+
+pub fn executable_name(binary: &str) -> String {
+    if cfg!(target_os = "windows") {
+        format!("{}.exe", binary)
+    } else {
+        binary.to_string()
+    }
+}
+
+fn binary_for(name: &str) -> Result<&'static str, String> {
+    TOOLS
+        .iter()
+        .find(|(tool, _)| *tool == name)
+        .map(|(_, binary)| *binary)
+        .ok_or_else(|| format!("UNKNOWN_TOOL: {}", name))
+}
+
+// 관리 폴더에 설치된 실행 파일 ("ffmpeg" / "ffmpeg.exe" 둘 다 허용)
+pub fn managed_path(file_name: &str) -> Option<PathBuf> {
+    let file_name = if Path::new(file_name).extension().is_some() {
+        file_name.to_string()
+    } else {
+        executable_name(file_name)
+    };
+    Some(tools_dir().join(file_name)).filter(|path| path.is_file())
+}
+
+// 기본 카탈로그 (cloudflared 는 공식 체크섬 목록 파일이 없어 sha256 고정값이 있어야 설치됨)
+pub fn builtin_pin(name: &str, platform: &str) -> Option<ToolPin> {
+    match name {
+        "yt-dlp" => {
+            let asset = match platform {
+                "windows-x86_64" => "yt-dlp.exe",
+                "macos-x86_64" | "macos-aarch64" => "yt-dlp_macos",
+                "linux-x86_64" => "yt-dlp_linux",
+                "linux-aarch64" => "yt-dlp_linux_aarch64",
+                _ => return None,
+            };
+            let base = format!(
+                "https://github.com/yt-dlp/yt-dlp/releases/download/{}",
+                YT_DLP_VERSION
+            );
+            Some(ToolPin {
+                version: YT_DLP_VERSION.to_string(),
+                url: format!("{}/{}", base, asset),
+                checksum_url: Some(format!("{}/SHA2-256SUMS", base)),
+                ..Default::default()
+            })
+        }
+        "cloudflared" => {
+            let asset = match platform {
+                "windows-x86_64" => "cloudflared-windows-amd64.exe",
+                "linux-x86_64" => "cloudflared-linux-amd64",
+                "linux-aarch64" => "cloudflared-linux-arm64",
+                _ => return None,
+            };
+            Some(ToolPin {
+                version: CLOUDFLARED_VERSION.to_string(),
+                url: format!(
+                    "https://github.com/cloudflare/cloudflared/releases/download/{}/{}",
+                    CLOUDFLARED_VERSION, asset
+                ),
+                ..Default::default()
+            })
+        }
+        _ => None,
+    }
+}
+
+pub fn effective_pin(name: &str, platform: &str) -> Option<ToolPin> {
+    load_pins()
+        .get(name)
+        .and_then(|pins| pins.get(platform))
+        .cloned()
+        .or_else(|| builtin_pin(name, platform))
+}
+
+// "<sha256>  <파일 이름>" 형식 목록에서 찾기 ("*파일 이름" 바이너리 표시도 허용)
+pub fn parse_checksum_list(text: &str, file_name: &str) -> Option<String> {
+    text.lines().find_map(|line| {
+        let (hash, name) = line.trim().split_once(char::is_whitespace)?;
+        let name = name.trim().trim_start_matches('*');
+        (name == file_name && hash.len() == 64 && hash.chars().all(|c| c.is_ascii_hexdigit()))
+            .then(|| hash.to_ascii_lowercase())
+    })
+}
+
+fn url_file_name(url: &str) -> Result<String, String> {
+    url::Url::parse(url)
+        .ok()
+        .and_then(|u| u.path_segments()?.next_back().map(str::to_string))
+        .filter(|name| !name.is_empty())
+        .ok_or_else(|| format!("INVALID_TOOL_URL: {}", url))
+}
+
+async fn expected_sha256(client: &reqwest::Client, pin: &ToolPin) -> Result<String, String> {
+    if let Some(sha256) = pin.sha256.as_deref().map(str::trim).filter(|s| !s.is_empty()) {
+        return Ok(sha256.to_ascii_lowercase());
+    }
+    let Some(checksum_url) = pin.checksum_url.as_deref() else {
+        return Err("CHECKSUM_REQUIRED: set_tool_pin 으로 sha256 을 지정하세요".to_string());
+    };
+    let list = client
+        .get(checksum_url)
+        .send()
+        .await
+        .and_then(|r| r.error_for_status())
+        .map_err(|e| format!("체크섬 목록 다운로드 실패: {}", e))?
+        .text()
+        .await
+        .map_err(|e| format!("체크섬 목록 다운로드 실패: {}", e))?;
+    let file_name = url_file_name(&pin.url)?;
+    parse_checksum_list(&list, &file_name)
+        .ok_or_else(|| format!("CHECKSUM_NOT_FOUND: {}", file_name))
+}
+
+// .part 로 받으면서 해시 계산 → 실제 sha256
+async fn download(
+    app_handle: &AppHandle,
+    client: &reqwest::Client,
+    name: &str,
+    url: &str,
+    part: &Path,
+) -> Result<String, String> {
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .and_then(|r| r.error_for_status())
+        .map_err(|e| format!("도구 다운로드 실패: {}", e))?;
+    let total = response.content_length();
+    if total.is_some_and(|t| t > MAX_DOWNLOAD_BYTES) {
+        return Err("TOOL_TOO_LARGE".to_string());
+    }
+
+    let mut file = tokio::fs::File::create(part)
+        .await
+        .map_err(|e| format!("파일 생성 실패: {}", e))?;
+    let mut hasher = Sha256::new();
+    let mut downloaded = 0u64;
+    let mut reported = 0u64;
+    let mut stream = response.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|e| format!("도구 다운로드 실패: {}", e))?;
+        downloaded += chunk.len() as u64;
+        if downloaded > MAX_DOWNLOAD_BYTES {
+            return Err("TOOL_TOO_LARGE".to_string());
+        }
+        hasher.update(&chunk);
+        file.write_all(&chunk)
+            .await
+            .map_err(|e| format!("파일 쓰기 실패: {}", e))?;
+        if downloaded - reported >= PROGRESS_STEP_BYTES {
+            reported = downloaded;
+            let _ = app_handle.emit(
+                "tool-download-progress",
+                serde_json::json!({ "name": name, "downloaded": downloaded, "total": total }),
+            );
+        }
+    }
+    file.sync_all()
+        .await
+        .map_err(|e| format!("파일 쓰기 실패: {}", e))?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+// zip 안의 실행 파일 하나만 꺼냄
+pub fn extract_entry(archive: &Path, entry: &str, target: &Path) -> Result<(), String> {
+    let file = std::fs::File::open(archive).map_err(|e| format!("압축 파일 열기 실패: {}", e))?;
+    let mut zip = zip::ZipArchive::new(file).map_err(|e| format!("압축 파일 읽기 실패: {}", e))?;
+    let mut source = zip
+        .by_name(entry.trim_start_matches('/'))
+        .map_err(|_| format!("ARCHIVE_ENTRY_NOT_FOUND: {}", entry))?;
+    let mut output =
+        std::fs::File::create(target).map_err(|e| format!("파일 생성 실패: {}", e))?;
+    std::io::copy(&mut source, &mut output).map_err(|e| format!("압축 해제 실패: {}", e))?;
+    Ok(())
+}
+
+fn make_executable(path: &Path) -> Result<(), String> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755))
+            .map_err(|e| format!("실행 권한 설정 실패: {}", e))?;
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+    Ok(())
+}
+
+fn tool_status(name: &str, binary: &str, platform: &str) -> ToolStatus {
+    let pinned_version = effective_pin(name, platform).map(|pin| pin.version);
+    let installed_version = load_installed().get(name).map(|tool| tool.version.clone());
+    let bundled = std::env::current_exe()
+        .ok()
+        .and_then(|exe| Some(exe.parent()?.join("binaries").join(executable_name(binary))))
+        .filter(|path| path.is_file());
+    let (source, path) = match (bundled, managed_path(binary)) {
+        (Some(path), _) => ("binaries", Some(path)),
+        (None, Some(path)) => ("managed", Some(path)),
+        (None, None) => ("missing", None),
+    };
+    let up_to_date = match source {
+        "binaries" => true,
+        "managed" => pinned_version.is_none() || pinned_version == installed_version,
+        _ => false,
+    };
+    ToolStatus {
+        name: name.to_string(),
+        platform: platform.to_string(),
+        pinned_version,
+        installed_version: if source == "managed" { installed_version } else { None },
+        source: source.to_string(),
+        path: path.map(|p| p.to_string_lossy().to_string()),
+        up_to_date,
+    }
+}
+
+#[tauri::command]
+pub fn list_tools() -> Result<Vec<ToolStatus>, String> {
+    let platform = platform_key();
+    Ok(TOOLS
+        .iter()
+        .map(|(name, binary)| tool_status(name, binary, &platform))
+        .collect())
+}
+
+// 현재 플랫폼의 고정값 지정 (None 이면 사용자 고정값 삭제 → 기본 카탈로그)
+#[tauri::command]
+pub fn set_tool_pin(name: String, pin: Option<ToolPin>) -> Result<String, String> {
+    binary_for(&name)?;
+    let platform = platform_key();
+    let mut pins = load_pins();
+    match pin {
+        Some(pin) => {
+            if pin.version.trim().is_empty() {
+                return Err("INVALID_TOOL_VERSION".to_string());
+            }
+            if !pin.url.starts_with("https://") {
+                return Err(format!("INSECURE_TOOL_URL: {}", pin.url));
+            }
+            url_file_name(&pin.url)?;
+            if let Some(sha256) = pin.sha256.as_deref().filter(|s| !s.trim().is_empty()) {
+                if sha256.trim().len() != 64 || !sha256.trim().chars().all(|c| c.is_ascii_hexdigit()) {
+                    return Err("INVALID_SHA256".to_string());
+                }
+            }
+            pins.entry(name.clone()).or_default().insert(platform, pin);
+        }
+        None => {
+            if let Some(tool_pins) = pins.get_mut(&name) {
+                tool_pins.remove(&platform);
+            }
+            pins.retain(|_, tool_pins| !tool_pins.is_empty());
+        }
+    }
+    atomic_store::write_json_atomic(&pins_path(), &pins)?;
+    Ok("SUCCESS".to_string())
+}
+
+// 고정 버전을 내려받아 검증 후 배치 (이미 같은 버전이면 force 없이는 건너뜀)
+#[tauri::command]
+pub async fn install_tool(
+    app_handle: AppHandle,
+    name: String,
+    force: Option<bool>,
+) -> Result<ToolStatus, String> {
+    let binary = binary_for(&name)?;
+    let platform = platform_key();
+    let pin = effective_pin(&name, &platform)
+        .ok_or_else(|| format!("NO_TOOL_PIN: {} ({})", name, platform))?;
+    let current = tool_status(&name, binary, &platform);
+    if current.source == "managed" && current.up_to_date && !force.unwrap_or(false) {
+        return Ok(current);
+    }
+
+    let dir = tools_dir();
+    let downloads = dir.join(".downloads");
+    std::fs::create_dir_all(&downloads).map_err(|_| "DIRECTORY_CREATE_ERROR".to_string())?;
+    let client = reqwest::Client::builder()
+        .user_agent("automation-gui-tools/1.0")
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    println!("🧰 도구 설치: {} {} ({})", name, pin.version, platform);
+    let expected = expected_sha256(&client, &pin).await?;
+    let part = downloads.join(format!("{}.part", url_file_name(&pin.url)?));
+    let result = download(&app_handle, &client, &name, &pin.url, &part).await;
+    let actual = match result {
+        Ok(actual) => actual,
+        Err(e) => {
+            let _ = std::fs::remove_file(&part);
+            return Err(e);
+        }
+    };
+    if actual != expected {
+        let _ = std::fs::remove_file(&part);
+        return Err(format!("CHECKSUM_MISMATCH: expected {}, got {}", expected, actual));
+    }
+
+    // 실행 중인 도구를 덮어쓰지 않도록 임시 이름으로 만든 뒤 교체
+    let target = dir.join(executable_name(binary));
+    let staged = downloads.join(executable_name(binary));
+    let placed = match pin.archive_entry.as_deref().filter(|e| !e.trim().is_empty()) {
+        Some(entry) => extract_entry(&part, entry, &staged),
+        None => std::fs::rename(&part, &staged).map_err(|e| format!("파일 이동 실패: {}", e)),
+    };
+    let _ = std::fs::remove_file(&part);
+    placed?;
+    make_executable(&staged)?;
+    std::fs::rename(&staged, &target).map_err(|e| format!("도구 배치 실패: {}", e))?;
+
+    let mut installed = load_installed();
+    installed.insert(
+        name.clone(),
+        InstalledTool {
+            version: pin.version.clone(),
+            sha256: actual,
+            source_url: pin.url.clone(),
+            installed_at: chrono::Local::now().to_rfc3339(),
+        },
+    );
+    atomic_store::write_json_atomic(&installed_path(), &installed)?;
+    println!("✅ 도구 설치 완료: {}", target.display());
+    Ok(tool_status(&name, binary, &platform))
+}
+
+#[tauri::command]
+pub fn remove_tool(name: String) -> Result<String, String> {
+    let binary = binary_for(&name)?;
+    if let Some(path) = managed_path(binary) {
+        std::fs::remove_file(&path).map_err(|e| format!("도구 삭제 실패: {}", e))?;
+    }
+    let mut installed = load_installed();
+    if installed.remove(&name).is_some() {
+        atomic_store::write_json_atomic(&installed_path(), &installed)?;
+    }
+    Ok("SUCCESS".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::temp_dir;
+    use std::fs;
+
+    #[test]
+    fn tools_finds_checksums_and_pins_builtin_versions() {
+        let sums = "\
+0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef  yt-dlp.exe
+FEDCBA9876543210FEDCBA9876543210FEDCBA9876543210FEDCBA9876543210 *yt-dlp_linux
+";
+        assert_eq!(
+            parse_checksum_list(sums, "yt-dlp_linux").as_deref(),
+            Some("fedcba9876543210fedcba9876543210fedcba9876543210fedcba9876543210")
+        );
+        assert!(parse_checksum_list(sums, "yt-dlp").is_none());
+
+        let pin = builtin_pin("yt-dlp", "windows-x86_64").unwrap();
+        assert!(pin.url.ends_with("/yt-dlp.exe"));
+        assert!(pin.checksum_url.unwrap().ends_with("/SHA2-256SUMS"));
+        // cloudflared 는 체크섬 목록이 없어 sha256 고정값이 필요
+        let pin = builtin_pin("cloudflared", "linux-x86_64").unwrap();
+        assert!(pin.sha256.is_none() && pin.checksum_url.is_none());
+        assert!(builtin_pin("ffmpeg", "linux-x86_64").is_none());
+    }
+
+    #[test]
+    fn tools_extracts_single_entry_from_zip() {
+        use std::io::Write;
+        let dir = temp_dir();
+        let archive = dir.path().join("ffmpeg.zip");
+        let mut zip = zip::ZipWriter::new(fs::File::create(&archive).unwrap());
+        zip.start_file("ffmpeg-7/bin/ffmpeg.exe", zip::write::FileOptions::default())
+            .unwrap();
+        zip.write_all(b"binary").unwrap();
+        zip.finish().unwrap();
+
+        let target = dir.path().join("ffmpeg.exe");
+        extract_entry(&archive, "ffmpeg-7/bin/ffmpeg.exe", &target).unwrap();
+        assert_eq!(fs::read(&target).unwrap(), b"binary");
+        assert!(extract_entry(&archive, "missing.exe", &target).is_err());
+    }
+}
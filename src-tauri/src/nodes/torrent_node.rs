@@ -0,0 +1,444 @@
+// src-tauri/src/nodes/torrent_node.rs
+use super::{disk_preflight, os_command, safe_path};
+use base64::Engine;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter};
+use tokio::sync::{OnceCell, RwLock};
+
+// 🧲 토렌트 / 마그넷 다운로드 노드 (aria2c 를 RPC 모드로 띄워서 사용)
+// yt-dlp 가 지원하지 않는 배포본(리눅스 ISO, 공개 데이터셋 등)을 워크플로우에서 받기 위함
+// - source: magnet 링크 / .torrent 파일 경로 / .torrent URL
+// - aria2c 는 binaries 폴더 → 도구 관리 폴더 → PATH 순서로 찾음 (os_command::bundled_binary)
+// - 진행 상황은 1초마다 "torrent-progress" 이벤트
+// - 완료 후 seed_ratio(업로드/전체) 또는 seed_minutes 중 먼저 닿는 조건까지 시드, 둘 다 없으면 바로 종료
+
+const PROGRESS_INTERVAL_MS: u64 = 1000;
+const RPC_READY_TIMEOUT_MS: u64 = 5000;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TorrentSource {
+    Magnet(String),
+    Url(String),
+    File(PathBuf),
+}
+
+#[derive(Debug, Serialize, Clone)]
+struct TorrentProgress {
+    node_id: String,
+    // "downloading" | "seeding"
+    state: String,
+    name: Option<String>,
+    downloaded_bytes: u64,
+    total_bytes: u64,
+    uploaded_bytes: u64,
+    percent: f64,
+    download_speed: u64,
+    upload_speed: u64,
+}
+
+struct TorrentJob {
+    gid: String,
+    cancelled: Arc<AtomicBool>,
+}
+
+type TorrentRegistry = Arc<RwLock<HashMap<String, TorrentJob>>>;
+
+// 앱 전체에서 aria2c 하나를 공유 (포트/DHT 를 노드마다 따로 열지 않도록)
+struct Aria2Session {
+    _process: tokio::process::Child,
+    rpc_url: String,
+    secret: String,
+    client: reqwest::Client,
+}
+
+static TORRENT_REGISTRY: std::sync::OnceLock<TorrentRegistry> = std::sync::OnceLock::new();
+static TORRENT_SESSION: OnceCell<Aria2Session> = OnceCell::const_new();
+
+fn get_torrent_registry() -> &'static TorrentRegistry {
+    TORRENT_REGISTRY.get_or_init(|| Arc::new(RwLock::new(HashMap::new())))
+}
+
+impl Aria2Session {
+    async fn start() -> Result<Self, String> {
+        let port = std::net::TcpListener::bind(("127.0.0.1", 0))
+            .and_then(|listener| listener.local_addr())
+            .map_err(|e| format!("토렌트 세션 시작 실패: {}", e))?
+            .port();
+        let secret = format!("{:032x}", rand::random::<u128>());
+
+        // RPC 는 127.0.0.1 에서만, 앱이 죽으면 aria2c 도 같이 종료
+        let process = tokio::process::Command::new(os_command::bundled_binary("aria2c"))
+            .args([
+                "--enable-rpc".to_string(),
+                "--rpc-listen-all=false".to_string(),
+                format!("--rpc-listen-port={}", port),
+                format!("--rpc-secret={}", secret),
+                format!("--stop-with-process={}", std::process::id()),
+                "--quiet=true".to_string(),
+            ])
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| format!("ARIA2_NOT_FOUND: {}", e))?;
+
+        let session = Self {
+            _process: process,
+            rpc_url: format!("http://127.0.0.1:{}/jsonrpc", port),
+            secret,
+            client: reqwest::Client::new(),
+        };
+
+        // RPC 가 열릴 때까지 대기
+        let started = std::time::Instant::now();
+        loop {
+            match session.call("aria2.getVersion", vec![]).await {
+                Ok(_) => return Ok(session),
+                Err(e) if started.elapsed().as_millis() as u64 > RPC_READY_TIMEOUT_MS => {
+                    return Err(format!("토렌트 세션 시작 실패: {}", e));
+                }
+                Err(_) => tokio::time::sleep(std::time::Duration::from_millis(100)).await,
+            }
+        }
+    }
+
+    async fn call(&self, method: &str, params: Vec<Value>) -> Result<Value, String> {
+        let mut all_params = vec![json!(format!("token:{}", self.secret))];
+        all_params.extend(params);
+        let response: Value = self
+            .client
+            .post(&self.rpc_url)
+            .json(&json!({
+                "jsonrpc": "2.0",
+                "id": "trigger",
+                "method": method,
+                "params": all_params
+            }))
+            .send()
+            .await
+            .map_err(|e| format!("TORRENT_RPC_FAILED: {}", e))?
+            .json()
+            .await
+            .map_err(|e| format!("TORRENT_RPC_FAILED: {}", e))?;
+        if let Some(error) = response.get("error") {
+            return Err(format!(
+                "TORRENT_ERROR: {}",
+                error["message"].as_str().unwrap_or("unknown")
+            ));
+        }
+        Ok(response["result"].clone())
+    }
+}
+
+async fn get_session() -> Result<&'static Aria2Session, String> {
+    TORRENT_SESSION.get_or_try_init(Aria2Session::start).await
+}
+
+// aria2 는 숫자를 문자열로 돌려줌
+fn number(status: &Value, key: &str) -> u64 {
+    status[key]
+        .as_str()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(0)
+}
+
+pub fn parse_source(source: &str) -> Result<TorrentSource, String> {
+    let source = source.trim();
+    let lower = source.to_lowercase();
+    if lower.starts_with("magnet:") {
+        if !lower.contains("xt=urn:btih:") && !lower.contains("xt=urn:btmh:") {
+            return Err("INVALID_MAGNET_LINK".to_string());
+        }
+        return Ok(TorrentSource::Magnet(source.to_string()));
+    }
+    if lower.starts_with("http://") || lower.starts_with("https://") {
+        return Ok(TorrentSource::Url(source.to_string()));
+    }
+    let path = safe_path::resolve_existing(source);
+    if source.is_empty() || !path.is_file() {
+        return Err("TORRENT_SOURCE_NOT_FOUND".to_string());
+    }
+    Ok(TorrentSource::File(path))
+}
+
+// 시드 종료 조건 (둘 다 없으면 시드하지 않음)
+pub fn seeding_done(
+    uploaded: u64,
+    total: u64,
+    seeded_secs: u64,
+    seed_ratio: Option<f64>,
+    seed_minutes: Option<u64>,
+) -> bool {
+    if seed_ratio.is_none() && seed_minutes.is_none() {
+        return true;
+    }
+    let ratio_reached =
+        seed_ratio.is_some_and(|ratio| total > 0 && uploaded as f64 / total as f64 >= ratio);
+    let time_reached = seed_minutes.is_some_and(|minutes| seeded_secs >= minutes * 60);
+    ratio_reached || time_reached
+}
+
+fn emit_progress(app_handle: &AppHandle, progress: &TorrentProgress) {
+    if let Err(e) = app_handle.emit("torrent-progress", progress) {
+        eprintln!("❌ Failed to emit torrent progress: {}", e);
+    }
+}
+
+#[tauri::command]
+pub async fn torrent_node(
+    app_handle: AppHandle,
+    node_id: String,
+    source: String,
+    download_path: String,
+    seed_ratio: Option<f64>,
+    seed_minutes: Option<u64>,
+) -> Result<String, String> {
+    let source = parse_source(&source)?;
+    let output_dir = safe_path::resolve_existing(&download_path);
+    if !output_dir.is_dir() {
+        return Err(format!(
+            "다운로드 경로가 존재하지 않습니다: {}",
+            output_dir.display()
+        ));
+    }
+    if get_torrent_registry().read().await.contains_key(&node_id) {
+        return Err("TORRENT_ALREADY_RUNNING".to_string());
+    }
+    println!("🧲 Torrent Node: {:?} → {}", source, output_dir.display());
+
+    let session = get_session().await?;
+    // 시드 종료는 seeding_done 으로 직접 판단 → aria2 쪽은 무기한 시드(seed-ratio 0)
+    let options = json!({
+        "dir": output_dir.to_string_lossy(),
+        "allow-overwrite": "true",
+        "seed-ratio": "0.0"
+    });
+    let first_gid = match &source {
+        TorrentSource::Magnet(url) | TorrentSource::Url(url) => {
+            session
+                .call("aria2.addUri", vec![json!([url]), options])
+                .await
+        }
+        TorrentSource::File(path) => {
+            let bytes =
+                std::fs::read(path).map_err(|e| format!("토렌트 파일 읽기 실패: {}", e))?;
+            let encoded = base64::engine::general_purpose::STANDARD.encode(bytes);
+            session
+                .call("aria2.addTorrent", vec![json!(encoded), json!([]), options])
+                .await
+        }
+    }
+    .map_err(|e| format!("토렌트 추가 실패: {}", e))?
+    .as_str()
+    .ok_or("TORRENT_ADD_FAILED")?
+    .to_string();
+
+    let cancelled = Arc::new(AtomicBool::new(false));
+    get_torrent_registry().write().await.insert(
+        node_id.clone(),
+        TorrentJob {
+            gid: first_gid.clone(),
+            cancelled: cancelled.clone(),
+        },
+    );
+
+    let mut gid = first_gid.clone();
+    let result = async {
+        let mut checked_space = false;
+        let mut seeding_since: Option<std::time::Instant> = None;
+
+        loop {
+            tokio::time::sleep(std::time::Duration::from_millis(PROGRESS_INTERVAL_MS)).await;
+            if cancelled.load(Ordering::Relaxed) {
+                return Err("TORRENT_CANCELLED".to_string());
+            }
+
+            let status = session.call("aria2.tellStatus", vec![json!(gid)]).await?;
+
+            // 마그넷 / .torrent URL 은 메타데이터를 받은 뒤 실제 다운로드가 새 gid 로 이어짐
+            if let Some(next) = status["followedBy"][0].as_str() {
+                gid = next.to_string();
+                checked_space = false;
+                if let Some(job) = get_torrent_registry().write().await.get_mut(&node_id) {
+                    job.gid = gid.clone();
+                }
+                continue;
+            }
+            if status["status"] == "error" {
+                return Err(format!(
+                    "TORRENT_ERROR: {}",
+                    status["errorMessage"].as_str().unwrap_or("unknown")
+                ));
+            }
+            if status["status"] == "removed" {
+                return Err("TORRENT_CANCELLED".to_string());
+            }
+
+            let total_bytes = number(&status, "totalLength");
+            let downloaded_bytes = number(&status, "completedLength");
+            let uploaded_bytes = number(&status, "uploadLength");
+            let finished = status["status"] == "complete"
+                || (total_bytes > 0 && downloaded_bytes >= total_bytes);
+
+            // 메타데이터를 받아 전체 크기를 알게 되면 디스크 공간 확인
+            if !checked_space && total_bytes > 0 {
+                checked_space = true;
+                disk_preflight::ensure_free_space(
+                    &output_dir,
+                    total_bytes.saturating_sub(downloaded_bytes),
+                )?;
+            }
+
+            let progress = TorrentProgress {
+                node_id: node_id.clone(),
+                state: if finished { "seeding" } else { "downloading" }.to_string(),
+                name: status["bittorrent"]["info"]["name"]
+                    .as_str()
+                    .map(|name| name.to_string()),
+                downloaded_bytes,
+                total_bytes,
+                uploaded_bytes,
+                percent: if total_bytes > 0 {
+                    (downloaded_bytes as f64 / total_bytes as f64 * 1000.0).round() / 10.0
+                } else {
+                    0.0
+                },
+                download_speed: number(&status, "downloadSpeed"),
+                upload_speed: number(&status, "uploadSpeed"),
+            };
+            emit_progress(&app_handle, &progress);
+
+            if finished {
+                let since = *seeding_since.get_or_insert_with(|| {
+                    println!("✅ 토렌트 다운로드 완료: {:?}", progress.name);
+                    std::time::Instant::now()
+                });
+                if seeding_done(
+                    uploaded_bytes,
+                    total_bytes,
+                    since.elapsed().as_secs(),
+                    seed_ratio,
+                    seed_minutes,
+                ) {
+                    return Ok(progress);
+                }
+            }
+        }
+    }
+    .await;
+
+    // 끝나면(완료/취소/실패) aria2 에서 내림 - 받은 파일은 유지
+    get_torrent_registry().write().await.remove(&node_id);
+    for finished_gid in [&gid, &first_gid] {
+        // 이미 끝난 항목은 forceRemove 가 실패하므로 결과 정리만 시도
+        let _ = session.call("aria2.forceRemove", vec![json!(finished_gid)]).await;
+        let _ = session
+            .call("aria2.removeDownloadResult", vec![json!(finished_gid)])
+            .await;
+    }
+
+    let progress = result?;
+    let path = progress
+        .name
+        .as_deref()
+        .map(|name| output_dir.join(name))
+        .filter(|path| path.exists())
+        .unwrap_or_else(|| output_dir.clone());
+
+    Ok(serde_json::json!({
+        "path": path.to_string_lossy(),
+        "name": progress.name,
+        "total_bytes": progress.total_bytes,
+        "uploaded_bytes": progress.uploaded_bytes,
+        "ratio": if progress.total_bytes > 0 {
+            progress.uploaded_bytes as f64 / progress.total_bytes as f64
+        } else {
+            0.0
+        }
+    })
+    .to_string())
+}
+
+#[tauri::command]
+pub async fn cancel_torrent(node_id: String) -> Result<String, String> {
+    match get_torrent_registry().read().await.get(&node_id) {
+        Some(job) => {
+            job.cancelled.store(true, Ordering::Relaxed);
+            println!(
+                "🛑 토렌트 취소 요청 (node {}, gid {})",
+                node_id, job.gid
+            );
+            Ok("Torrent cancelled".to_string())
+        }
+        None => Ok("No torrent running for this node".to_string()),
+    }
+}
+
+#[tauri::command]
+pub async fn list_torrents() -> Result<serde_json::Value, String> {
+    let jobs = get_torrent_registry().read().await;
+    Ok(serde_json::json!({
+        "running": jobs.keys().collect::<Vec<_>>()
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::{path_str, temp_dir};
+    use std::fs;
+
+    #[test]
+    fn torrent_parses_sources() {
+        let magnet = "magnet:?xt=urn:btih:c9e15763f722f23e98a29decdfae341b98d53056&dn=test";
+        assert_eq!(
+            parse_source(magnet).unwrap(),
+            TorrentSource::Magnet(magnet.to_string())
+        );
+        assert_eq!(
+            parse_source("magnet:?dn=nohash").unwrap_err(),
+            "INVALID_MAGNET_LINK"
+        );
+        assert!(matches!(
+            parse_source("https://example.com/a.torrent").unwrap(),
+            TorrentSource::Url(_)
+        ));
+
+        let dir = temp_dir();
+        let file = dir.path().join("a.torrent");
+        fs::write(&file, b"d4:infod4:name1:aee").unwrap();
+        assert_eq!(
+            parse_source(&path_str(&file)).unwrap(),
+            TorrentSource::File(file.clone())
+        );
+        assert_eq!(
+            parse_source(&path_str(&dir.path().join("missing.torrent"))).unwrap_err(),
+            "TORRENT_SOURCE_NOT_FOUND"
+        );
+    }
+
+    #[test]
+    fn torrent_stops_seeding_at_first_limit() {
+        // 제한이 없으면 시드하지 않음
+        assert!(seeding_done(0, 100, 0, None, None));
+        assert!(!seeding_done(50, 100, 10, Some(1.0), None));
+        assert!(seeding_done(100, 100, 10, Some(1.0), None));
+        assert!(seeding_done(
+            50,
+            100,
+            600,
+            Some(1.0),
+            Some(10)
+        ));
+        assert!(!seeding_done(
+            50,
+            100,
+            599,
+            Some(1.0),
+            Some(10)
+        ));
+    }
+}
@@ -0,0 +1,254 @@
+// src-tauri/src/nodes/usb_watcher.rs
+use super::os_command;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter};
+use tokio::sync::RwLock;
+
+// 🔌 USB 장치 감시 트리거
+// 이동식 드라이브(마운트 경로, 볼륨 이름)와 USB 장치의 연결/분리를 주기적으로 확인해
+// "usb-device" 이벤트로 전달 (예: 카메라 SD 카드 삽입 → 사진 가져오기)
+
+#[derive(Debug, Serialize, Clone, PartialEq, Eq, Hash)]
+struct UsbItem {
+    kind: String, // "drive" | "device"
+    id: String,
+    name: String,
+    mount_path: Option<String>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+struct UsbEvent {
+    node_id: String,
+    action: String, // "added" | "removed"
+    #[serde(flatten)]
+    item: UsbItem,
+    timestamp: u64,
+}
+
+struct UsbWatcherHandle {
+    device_filter: Option<String>,
+    abort_handle: tokio::task::AbortHandle,
+}
+
+type UsbWatcherRegistry = Arc<RwLock<HashMap<String, UsbWatcherHandle>>>;
+
+static USB_WATCHER_REGISTRY: std::sync::OnceLock<UsbWatcherRegistry> = std::sync::OnceLock::new();
+
+fn get_usb_watcher_registry() -> &'static UsbWatcherRegistry {
+    USB_WATCHER_REGISTRY.get_or_init(|| Arc::new(RwLock::new(HashMap::new())))
+}
+
+fn drive(id: String, name: String, mount_path: String) -> UsbItem {
+    UsbItem {
+        kind: "drive".to_string(),
+        id,
+        name,
+        mount_path: Some(mount_path),
+    }
+}
+
+fn device(id: String, name: String) -> UsbItem {
+    UsbItem {
+        kind: "device".to_string(),
+        id,
+        name,
+        mount_path: None,
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn scan_usb() -> Vec<UsbItem> {
+    // DriveType=2: 이동식 디스크 / PNPDeviceID USB\VID_xxxx&PID_xxxx
+    const SCRIPT: &str = r#"
+Get-CimInstance Win32_LogicalDisk -Filter 'DriveType=2' | ForEach-Object { "drive`t$($_.VolumeSerialNumber)`t$($_.VolumeName)`t$($_.DeviceID)\" }
+Get-CimInstance Win32_PnPEntity | Where-Object { $_.PNPDeviceID -like 'USB\VID_*' } | ForEach-Object { "device`t$($_.PNPDeviceID)`t$($_.Name)`t" }
+"#;
+
+    os_command::powershell(SCRIPT)
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| {
+            let parts: Vec<&str> = line.trim_end_matches('\r').split('\t').collect();
+            match parts.as_slice() {
+                ["drive", serial, label, path] => Some(drive(
+                    format!("{}{}", path, serial),
+                    label.to_string(),
+                    path.to_string(),
+                )),
+                ["device", id, name, _] => Some(device(id.to_string(), name.to_string())),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+#[cfg(not(target_os = "windows"))]
+fn scan_usb() -> Vec<UsbItem> {
+    let mut items = Vec::new();
+
+    // 이동식 드라이브 마운트 위치
+    let mut roots: Vec<std::path::PathBuf> = Vec::new();
+    if cfg!(target_os = "macos") {
+        roots.push("/Volumes".into());
+    } else {
+        let user = std::env::var("USER").unwrap_or_default();
+        roots.push(format!("/media/{}", user).into());
+        roots.push(format!("/run/media/{}", user).into());
+    }
+
+    for root in roots {
+        let Ok(entries) = std::fs::read_dir(&root) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            // macOS 시스템 볼륨(/Volumes/Macintosh HD → /)은 제외
+            if std::fs::canonicalize(&path).is_ok_and(|p| p == std::path::Path::new("/")) {
+                continue;
+            }
+            let label = entry.file_name().to_string_lossy().to_string();
+            let path = path.to_string_lossy().to_string();
+            items.push(drive(path.clone(), label, path));
+        }
+    }
+
+    // USB 장치 (lsusb: "Bus 001 Device 004: ID 04a9:3218 Canon, Inc. ...")
+    if let Some(output) = os_command::run_optional("lsusb", &[]) {
+        for line in output.lines() {
+            if let Some((_, rest)) = line.split_once(" ID ") {
+                let (id, name) = rest.split_once(' ').unwrap_or((rest, ""));
+                items.push(device(id.to_string(), name.trim().to_string()));
+            }
+        }
+    }
+
+    items
+}
+
+fn matches_filter(item: &UsbItem, filter: Option<&str>) -> bool {
+    match filter {
+        Some(filter) => {
+            let filter = filter.to_lowercase();
+            item.name.to_lowercase().contains(&filter) || item.id.to_lowercase().contains(&filter)
+        }
+        None => true,
+    }
+}
+
+async fn run_watch_loop(
+    app_handle: AppHandle,
+    node_id: String,
+    device_filter: Option<String>,
+    interval_ms: u64,
+) {
+    let scan = || async {
+        tokio::task::spawn_blocking(scan_usb)
+            .await
+            .unwrap_or_default()
+    };
+
+    // 시작 시점에 이미 연결된 장치는 이벤트 없이 기준으로 사용
+    let mut known: HashSet<UsbItem> = scan().await.into_iter().collect();
+
+    loop {
+        tokio::time::sleep(std::time::Duration::from_millis(interval_ms)).await;
+
+        let current: HashSet<UsbItem> = scan().await.into_iter().collect();
+        let added = current.difference(&known).map(|i| ("added", i));
+        let removed = known.difference(&current).map(|i| ("removed", i));
+
+        for (action, item) in added.chain(removed) {
+            if !matches_filter(item, device_filter.as_deref()) {
+                continue;
+            }
+            println!(
+                "🔌 USB {} (node {}): {} {}",
+                action, node_id, item.kind, item.name
+            );
+
+            let event = UsbEvent {
+                node_id: node_id.clone(),
+                action: action.to_string(),
+                item: item.clone(),
+                timestamp: std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_millis() as u64,
+            };
+            if let Err(e) = app_handle.emit("usb-device", &event) {
+                eprintln!("❌ Failed to emit USB event: {}", e);
+            }
+        }
+
+        known = current;
+    }
+}
+
+#[tauri::command]
+pub async fn start_usb_watcher(
+    app_handle: AppHandle,
+    node_id: String,
+    device_filter: Option<String>,
+    interval_ms: Option<u64>,
+) -> Result<String, String> {
+    let device_filter = device_filter
+        .map(|f| f.trim().to_string())
+        .filter(|f| !f.is_empty());
+
+    // 같은 노드의 기존 감시는 교체
+    let _ = stop_usb_watcher(node_id.clone()).await;
+
+    let task = tokio::spawn(run_watch_loop(
+        app_handle,
+        node_id.clone(),
+        device_filter.clone(),
+        interval_ms.unwrap_or(2000).max(500),
+    ));
+
+    get_usb_watcher_registry().write().await.insert(
+        node_id.clone(),
+        UsbWatcherHandle {
+            device_filter,
+            abort_handle: task.abort_handle(),
+        },
+    );
+
+    println!("🔌 USB 감시 시작 (node {})", node_id);
+    Ok("USB watcher started".to_string())
+}
+
+#[tauri::command]
+pub async fn stop_usb_watcher(node_id: String) -> Result<String, String> {
+    match get_usb_watcher_registry().write().await.remove(&node_id) {
+        Some(handle) => {
+            handle.abort_handle.abort();
+            println!("🛑 USB 감시 중지 (node {})", node_id);
+            Ok("USB watcher stopped".to_string())
+        }
+        None => Ok("No USB watcher running for this node".to_string()),
+    }
+}
+
+// 현재 연결된 이동식 드라이브 / USB 장치 목록
+#[tauri::command]
+pub async fn list_usb_devices() -> Result<String, String> {
+    let items = tokio::task::spawn_blocking(scan_usb)
+        .await
+        .map_err(|e| format!("USB 목록 조회 실패: {}", e))?;
+    Ok(serde_json::json!({ "devices": items }).to_string())
+}
+
+#[tauri::command]
+pub async fn get_usb_watcher_status(node_id: String) -> Result<serde_json::Value, String> {
+    let watchers = get_usb_watcher_registry().read().await;
+
+    Ok(match watchers.get(&node_id) {
+        Some(handle) => serde_json::json!({
+            "running": !handle.abort_handle.is_finished(),
+            "device_filter": handle.device_filter
+        }),
+        None => serde_json::json!({ "running": false }),
+    })
+}
@@ -0,0 +1,299 @@
+// src-tauri/src/nodes/value_store.rs
+use serde::Serialize;
+use serde_json::json;
+use std::fs;
+use std::io::{BufRead, BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+// 🗃️ 큰 노드 출력 임시 보관소
+// 수 MB짜리 명령 출력/파일 내용을 매 노드 완료마다 IPC 로 넘기지 않도록
+// 임시 폴더에 저장하고 프론트엔드에는 핸들 + 미리보기만 전달
+// 전체 내용이 필요하면 read_value_chunk 로 나눠서 읽음
+
+// 이 크기(바이트)를 넘는 출력만 보관소로 옮김
+pub const INLINE_LIMIT: usize = 256 * 1024;
+pub const PREVIEW_CHARS: usize = 2000;
+const MAX_CHUNK_BYTES: u64 = 1024 * 1024;
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ValueHandle {
+    pub handle: String,
+    pub size: u64,
+    pub lines: usize,
+    pub preview: String,
+    pub truncated: bool,
+}
+
+fn values_dir() -> PathBuf {
+    std::env::temp_dir().join("automation-gui-values")
+}
+
+// 핸들은 우리가 만든 형식(영숫자/_)만 허용 - 경로 조작 방지
+fn value_path(handle: &str) -> Result<PathBuf, String> {
+    let valid = handle.starts_with("val_")
+        && handle
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !valid {
+        return Err("INVALID_VALUE_HANDLE".to_string());
+    }
+    let path = values_dir().join(format!("{}.txt", handle));
+    if !path.is_file() {
+        return Err("VALUE_NOT_FOUND".to_string());
+    }
+    Ok(path)
+}
+
+// 앞부분 max_chars 글자만 자름 (UTF-8 경계 안전)
+pub fn preview(text: &str, max_chars: usize) -> (String, bool) {
+    match text.char_indices().nth(max_chars) {
+        Some((end, _)) => (text[..end].to_string(), true),
+        None => (text.to_string(), false),
+    }
+}
+
+fn new_handle() -> String {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or(0);
+    format!(
+        "val_{:x}_{}_{}",
+        nanos,
+        std::process::id(),
+        NEXT_ID.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+// 보관소 핸들이면 파일 경로 (일반 텍스트면 None)
+pub fn handle_path(text: &str) -> Option<PathBuf> {
+    value_path(text.trim()).ok()
+}
+
+// 파일 전체를 메모리에 올리지 않고 크기 / 줄 수 / 미리보기 계산
+fn describe(handle: String, path: &Path, max_chars: usize) -> Result<ValueHandle, String> {
+    let file = fs::File::open(path).map_err(|e| format!("값 읽기 실패: {}", e))?;
+    let size = file.metadata().map(|m| m.len()).unwrap_or(0);
+    let mut reader = BufReader::with_capacity(64 * 1024, file);
+    // 한 글자는 최대 4바이트, 한 글자 더 읽어야 잘렸는지 알 수 있음
+    let preview_limit = (max_chars + 1) * 4;
+    let mut head = Vec::new();
+    let mut newlines = 0;
+    let mut last_byte = None;
+    loop {
+        let buffer = reader
+            .fill_buf()
+            .map_err(|e| format!("값 읽기 실패: {}", e))?;
+        if buffer.is_empty() {
+            break;
+        }
+        newlines += buffer.iter().filter(|b| **b == b'\n').count();
+        if head.len() < preview_limit {
+            let take = (preview_limit - head.len()).min(buffer.len());
+            head.extend_from_slice(&buffer[..take]);
+        }
+        last_byte = buffer.last().copied();
+        let consumed = buffer.len();
+        reader.consume(consumed);
+    }
+    // 미리보기 끝에서 잘린 멀티바이트 문자는 버림
+    let valid = match std::str::from_utf8(&head) {
+        Ok(text) => text.len(),
+        Err(e) if e.error_len().is_none() => e.valid_up_to(),
+        Err(_) => head.len(),
+    };
+    let head = String::from_utf8_lossy(&head[..valid]);
+    let (preview, truncated) = preview(&head, max_chars);
+    let lines = newlines + usize::from(last_byte.is_some_and(|b| b != b'\n'));
+    Ok(ValueHandle {
+        handle,
+        size,
+        lines,
+        preview,
+        truncated: truncated || (valid as u64) < size,
+    })
+}
+
+// 큰 결과를 String 으로 만들지 않고 보관소 파일에 바로 씀 (text_merger 등)
+pub fn store_with(
+    write: impl FnOnce(&mut dyn Write) -> std::io::Result<()>,
+) -> Result<ValueHandle, String> {
+    let dir = values_dir();
+    fs::create_dir_all(&dir).map_err(|_| "DIRECTORY_CREATE_ERROR".to_string())?;
+    let handle = new_handle();
+    let path = dir.join(format!("{}.txt", handle));
+    let file = fs::File::create(&path).map_err(|e| format!("값 저장 실패: {}", e))?;
+    let mut writer = BufWriter::new(file);
+    let written = write(&mut writer).and_then(|_| writer.flush());
+    drop(writer);
+    if let Err(e) = written {
+        let _ = fs::remove_file(&path);
+        return Err(format!("값 저장 실패: {}", e));
+    }
+    let stored = describe(handle, &path, PREVIEW_CHARS)?;
+    println!("🗃️ 큰 출력 보관: {} ({} bytes)", stored.handle, stored.size);
+    Ok(stored)
+}
+
+pub fn store(text: &str) -> Result<ValueHandle, String> {
+    let dir = values_dir();
+    fs::create_dir_all(&dir).map_err(|_| "DIRECTORY_CREATE_ERROR".to_string())?;
+
+    let handle = new_handle();
+    fs::write(dir.join(format!("{}.txt", handle)), text)
+        .map_err(|e| format!("값 저장 실패: {}", e))?;
+
+    let (preview, truncated) = preview(text, PREVIEW_CHARS);
+    println!("🗃️ 큰 출력 보관: {} ({} bytes)", handle, text.len());
+    Ok(ValueHandle {
+        handle,
+        size: text.len() as u64,
+        lines: text.lines().count(),
+        preview,
+        truncated,
+    })
+}
+
+// 작으면 그대로, INLINE_LIMIT 를 넘으면 보관소에 저장하고 핸들 반환
+pub fn offload(text: &str) -> Option<ValueHandle> {
+    if text.len() <= INLINE_LIMIT {
+        return None;
+    }
+    match store(text) {
+        Ok(handle) => Some(handle),
+        Err(e) => {
+            println!("⚠️ 큰 출력 보관 실패, 그대로 전달: {}", e);
+            None
+        }
+    }
+}
+
+// offset 바이트부터 length 바이트 읽기 (잘린 멀티바이트 문자는 대체 문자로)
+pub fn read_chunk(handle: &str, offset: u64, length: u64) -> Result<(String, u64, bool), String> {
+    let path = value_path(handle)?;
+    let mut file = fs::File::open(&path).map_err(|e| format!("값 읽기 실패: {}", e))?;
+    let size = file.metadata().map(|m| m.len()).unwrap_or(0);
+
+    let length = length.min(MAX_CHUNK_BYTES);
+    file.seek(SeekFrom::Start(offset.min(size)))
+        .map_err(|e| format!("값 읽기 실패: {}", e))?;
+    let mut buffer = Vec::new();
+    file.take(length)
+        .read_to_end(&mut buffer)
+        .map_err(|e| format!("값 읽기 실패: {}", e))?;
+
+    let next_offset = offset.min(size) + buffer.len() as u64;
+    Ok((
+        String::from_utf8_lossy(&buffer).to_string(),
+        next_offset,
+        next_offset >= size,
+    ))
+}
+
+pub fn release(handle: &str) -> Result<(), String> {
+    let path = value_path(handle)?;
+    fs::remove_file(path).map_err(|e| format!("값 삭제 실패: {}", e))
+}
+
+#[tauri::command]
+pub fn store_large_value(value: String) -> Result<ValueHandle, String> {
+    store(&value)
+}
+
+#[tauri::command]
+pub fn get_value_preview(handle: String, max_chars: Option<usize>) -> Result<String, String> {
+    let path = value_path(&handle)?;
+    let described = describe(handle, &path, max_chars.unwrap_or(PREVIEW_CHARS))?;
+
+    Ok(json!({
+        "handle": described.handle,
+        "size": described.size,
+        "lines": described.lines,
+        "preview": described.preview,
+        "truncated": described.truncated
+    })
+    .to_string())
+}
+
+#[tauri::command]
+pub fn read_value_chunk(
+    handle: String,
+    offset: Option<u64>,
+    length: Option<u64>,
+) -> Result<String, String> {
+    let (content, next_offset, done) =
+        read_chunk(&handle, offset.unwrap_or(0), length.unwrap_or(64 * 1024))?;
+    Ok(json!({
+        "content": content,
+        "next_offset": next_offset,
+        "done": done
+    })
+    .to_string())
+}
+
+#[tauri::command]
+pub fn release_value(handle: String) -> Result<(), String> {
+    release(&handle)
+}
+
+#[tauri::command]
+pub fn clear_value_store() -> Result<usize, String> {
+    let Ok(entries) = fs::read_dir(values_dir()) else {
+        return Ok(0);
+    };
+    let removed = entries
+        .filter_map(|e| e.ok())
+        .filter(|e| fs::remove_file(e.path()).is_ok())
+        .count();
+    println!("🧹 값 보관소 정리: {}개 삭제", removed);
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn value_store_keeps_small_values_inline() {
+        assert!(offload("짧은 출력").is_none());
+        assert_eq!(
+            preview("가나다라", 2),
+            ("가나".to_string(), true)
+        );
+        assert_eq!(preview("abc", 10), ("abc".to_string(), false));
+    }
+
+    #[test]
+    fn value_store_offloads_and_reads_back_in_chunks() {
+        let text = "line\n".repeat(INLINE_LIMIT / 5 + 10);
+        let stored = offload(&text).expect("should offload");
+        assert_eq!(stored.size, text.len() as u64);
+        assert_eq!(stored.preview.chars().count(), PREVIEW_CHARS);
+        assert!(stored.truncated);
+
+        let mut collected = String::new();
+        let mut offset = 0;
+        loop {
+            let (chunk, next, done) = read_chunk(&stored.handle, offset, 100_000).unwrap();
+            collected.push_str(&chunk);
+            offset = next;
+            if done {
+                break;
+            }
+        }
+        assert_eq!(collected, text);
+
+        release(&stored.handle).unwrap();
+        assert_eq!(
+            read_chunk(&stored.handle, 0, 10).unwrap_err(),
+            "VALUE_NOT_FOUND"
+        );
+        assert_eq!(
+            read_chunk("../etc/passwd", 0, 10).unwrap_err(),
+            "INVALID_VALUE_HANDLE"
+        );
+    }
+}
@@ -0,0 +1,473 @@
+// src-tauri/src/nodes/vcard_node.rs
+use super::safe_path;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+// 📇 연락처(vCard) 노드
+// action: parse(.vcf 파일/텍스트 → JSON 연락처) | generate(JSON 연락처 → vCard 3.0)
+// 휴대폰 내보내기 파일에 흔한 vCard 2.1 QUOTED-PRINTABLE(CHARSET=UTF-8) 도 읽음
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct TypedValue {
+    #[serde(default, rename = "type", skip_serializing_if = "String::is_empty")]
+    pub kind: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Address {
+    #[serde(default, rename = "type", skip_serializing_if = "String::is_empty")]
+    pub kind: String,
+    #[serde(default)]
+    pub street: String,
+    #[serde(default)]
+    pub city: String,
+    #[serde(default)]
+    pub region: String,
+    #[serde(default)]
+    pub postal_code: String,
+    #[serde(default)]
+    pub country: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct Contact {
+    pub full_name: String,
+    pub last_name: String,
+    pub first_name: String,
+    pub organization: String,
+    pub title: String,
+    pub phones: Vec<TypedValue>,
+    pub emails: Vec<TypedValue>,
+    pub addresses: Vec<Address>,
+    pub url: String,
+    pub birthday: String,
+    pub note: String,
+}
+
+fn unescape(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') | Some('N') => result.push('\n'),
+            Some(other) => result.push(other),
+            None => result.push('\\'),
+        }
+    }
+    result
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('\n', "\\n")
+        .replace(',', "\\,")
+        .replace(';', "\\;")
+}
+
+// 이스케이프되지 않은 ; 로 구조 값 분리 (N, ADR, ORG)
+fn split_components(value: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut escaped = false;
+    for c in value.chars() {
+        if escaped {
+            current.push('\\');
+            current.push(c);
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == ';' {
+            parts.push(unescape(&current));
+            current.clear();
+        } else {
+            current.push(c);
+        }
+    }
+    parts.push(unescape(&current));
+    parts
+}
+
+fn decode_quoted_printable(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] == b'=' && index + 2 < bytes.len() {
+            let hex = std::str::from_utf8(&bytes[index + 1..index + 3]).unwrap_or("");
+            if let Ok(byte) = u8::from_str_radix(hex, 16) {
+                decoded.push(byte);
+                index += 3;
+                continue;
+            }
+        }
+        decoded.push(bytes[index]);
+        index += 1;
+    }
+    String::from_utf8_lossy(&decoded).to_string()
+}
+
+// 접힌 줄(공백/탭으로 시작) 펼치기 + QUOTED-PRINTABLE 소프트 줄바꿈(끝의 =) 이어붙이기
+fn unfold(content: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut qp_continues = false;
+    for raw in content.lines() {
+        let raw = raw.trim_end_matches('\r');
+        if qp_continues {
+            if let Some(last) = lines.last_mut() {
+                last.pop();
+                last.push_str(raw.trim_start());
+            }
+        } else if (raw.starts_with(' ') || raw.starts_with('\t')) && !lines.is_empty() {
+            if let Some(last) = lines.last_mut() {
+                last.push_str(&raw[1..]);
+            }
+        } else {
+            lines.push(raw.to_string());
+        }
+        qp_continues = lines.last().is_some_and(|line| {
+            line.to_uppercase().contains("QUOTED-PRINTABLE") && line.ends_with('=')
+        });
+    }
+    lines
+}
+
+struct Property {
+    name: String,
+    types: Vec<String>,
+    value: String,
+}
+
+fn parse_property(line: &str) -> Option<Property> {
+    let (head, value) = line.split_once(':')?;
+    let mut params = head.split(';');
+    // "item1.TEL" 처럼 그룹이 붙은 이름
+    let name = params.next()?.rsplit('.').next()?.to_uppercase();
+    let mut types = Vec::new();
+    let mut quoted_printable = false;
+    for param in params {
+        let (key, values) = param.split_once('=').unwrap_or(("TYPE", param));
+        match key.to_uppercase().as_str() {
+            "TYPE" => types.extend(
+                values
+                    .trim_matches('"')
+                    .split(',')
+                    .map(|t| t.trim().to_lowercase())
+                    .filter(|t| !t.is_empty() && t != "pref" && t != "voice" && t != "internet"),
+            ),
+            "ENCODING" if values.eq_ignore_ascii_case("QUOTED-PRINTABLE") => {
+                quoted_printable = true
+            }
+            _ => {}
+        }
+    }
+    let value = if quoted_printable {
+        decode_quoted_printable(value)
+    } else {
+        value.to_string()
+    };
+    Some(Property { name, types, value })
+}
+
+// FN 이 없을 때 이름 조합: 한글 등은 "성이름", 영문은 "First Last"
+fn display_name(contact: &Contact) -> String {
+    let (last, first) = (contact.last_name.trim(), contact.first_name.trim());
+    if last.is_ascii() && first.is_ascii() {
+        [first, last]
+            .iter()
+            .filter(|s| !s.is_empty())
+            .cloned()
+            .collect::<Vec<_>>()
+            .join(" ")
+    } else {
+        format!("{}{}", last, first)
+    }
+}
+
+pub fn parse_vcards(content: &str) -> Vec<Contact> {
+    let mut contacts = Vec::new();
+    let mut current: Option<Contact> = None;
+
+    for line in unfold(content) {
+        let Some(property) = parse_property(&line) else {
+            continue;
+        };
+        match (
+            property.name.as_str(),
+            property.value.trim().to_uppercase().as_str(),
+        ) {
+            ("BEGIN", "VCARD") => {
+                current = Some(Contact::default());
+                continue;
+            }
+            ("END", "VCARD") => {
+                if let Some(mut contact) = current.take() {
+                    if contact.full_name.is_empty() {
+                        contact.full_name = display_name(&contact);
+                    }
+                    contacts.push(contact);
+                }
+                continue;
+            }
+            _ => {}
+        }
+        let Some(contact) = current.as_mut() else {
+            continue;
+        };
+        let kind = property.types.join(",");
+        match property.name.as_str() {
+            "FN" => contact.full_name = unescape(&property.value),
+            "N" => {
+                let parts = split_components(&property.value);
+                contact.last_name = parts.first().cloned().unwrap_or_default();
+                contact.first_name = parts.get(1).cloned().unwrap_or_default();
+            }
+            "ORG" => {
+                contact.organization = split_components(&property.value)
+                    .into_iter()
+                    .filter(|s| !s.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            }
+            "TITLE" => contact.title = unescape(&property.value),
+            "TEL" => contact.phones.push(TypedValue {
+                kind,
+                value: property.value.trim().trim_start_matches("tel:").to_string(),
+            }),
+            "EMAIL" => contact.emails.push(TypedValue {
+                kind,
+                value: property.value.trim().to_string(),
+            }),
+            "ADR" => {
+                let parts = split_components(&property.value);
+                let part = |i: usize| parts.get(i).cloned().unwrap_or_default();
+                contact.addresses.push(Address {
+                    kind,
+                    street: part(2),
+                    city: part(3),
+                    region: part(4),
+                    postal_code: part(5),
+                    country: part(6),
+                });
+            }
+            "URL" => contact.url = unescape(&property.value),
+            "BDAY" => contact.birthday = property.value.trim().to_string(),
+            "NOTE" => contact.note = unescape(&property.value),
+            _ => {}
+        }
+    }
+    contacts
+}
+
+// 75바이트를 넘는 줄은 접음 (UTF-8 문자 중간에서 자르지 않음)
+fn fold_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + 8);
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            width = 1;
+        }
+        folded.push(c);
+        width += c.len_utf8();
+    }
+    folded
+}
+
+fn type_param(kind: &str) -> String {
+    if kind.trim().is_empty() {
+        String::new()
+    } else {
+        format!(";TYPE={}", kind.trim().to_uppercase())
+    }
+}
+
+pub fn generate_vcard(contact: &Contact) -> Result<String, String> {
+    let full_name = if contact.full_name.trim().is_empty() {
+        display_name(contact)
+    } else {
+        contact.full_name.clone()
+    };
+    if full_name.trim().is_empty() {
+        return Err("CONTACT_NAME_REQUIRED".to_string());
+    }
+
+    let mut lines = vec![
+        "BEGIN:VCARD".to_string(),
+        "VERSION:3.0".to_string(),
+        format!("FN:{}", escape(&full_name)),
+        format!(
+            "N:{};{};;;",
+            escape(&contact.last_name),
+            escape(&contact.first_name)
+        ),
+    ];
+    let mut optional = |name: &str, value: &str| {
+        if !value.trim().is_empty() {
+            lines.push(format!("{}:{}", name, escape(value)));
+        }
+    };
+    optional("ORG", &contact.organization);
+    optional("TITLE", &contact.title);
+    for phone in &contact.phones {
+        lines.push(format!(
+            "TEL{}:{}",
+            type_param(&phone.kind),
+            phone.value.trim()
+        ));
+    }
+    for email in &contact.emails {
+        lines.push(format!(
+            "EMAIL{}:{}",
+            type_param(&email.kind),
+            email.value.trim()
+        ));
+    }
+    for address in &contact.addresses {
+        lines.push(format!(
+            "ADR{}:;;{};{};{};{};{}",
+            type_param(&address.kind),
+            escape(&address.street),
+            escape(&address.city),
+            escape(&address.region),
+            escape(&address.postal_code),
+            escape(&address.country)
+        ));
+    }
+    if !contact.url.trim().is_empty() {
+        lines.push(format!("URL:{}", contact.url.trim()));
+    }
+    if !contact.birthday.trim().is_empty() {
+        lines.push(format!("BDAY:{}", contact.birthday.trim()));
+    }
+    if !contact.note.trim().is_empty() {
+        lines.push(format!("NOTE:{}", escape(&contact.note)));
+    }
+    lines.push("END:VCARD".to_string());
+
+    Ok(lines
+        .iter()
+        .map(|line| fold_line(line))
+        .collect::<Vec<_>>()
+        .join("\r\n")
+        + "\r\n")
+}
+
+#[tauri::command]
+pub async fn vcard_node(
+    action: String,
+    path: Option<String>,
+    content: Option<String>,
+    contacts: Option<Vec<Contact>>,
+    output_path: Option<String>,
+) -> Result<String, String> {
+    let action = action.trim().to_lowercase();
+    println!("📇 vCard Node: {}", action);
+
+    match action.as_str() {
+        "parse" => {
+            let content = match (content.filter(|c| !c.trim().is_empty()), path) {
+                (Some(content), _) => content,
+                (None, Some(path)) => {
+                    let bytes =
+                        std::fs::read(safe_path::resolve_existing(&path)).map_err(|_| "FILE_NOT_FOUND".to_string())?;
+                    String::from_utf8_lossy(&bytes).to_string()
+                }
+                (None, None) => return Err("EMPTY_INPUT".to_string()),
+            };
+            let contacts = parse_vcards(&content);
+            if contacts.is_empty() {
+                return Err("NO_VCARD_FOUND".to_string());
+            }
+            Ok(json!({ "contacts": contacts, "count": contacts.len() }).to_string())
+        }
+        "generate" => {
+            let contacts = contacts.filter(|c| !c.is_empty()).ok_or("EMPTY_INPUT")?;
+            let vcard = contacts
+                .iter()
+                .map(generate_vcard)
+                .collect::<Result<Vec<_>, _>>()?
+                .concat();
+            let output_path = output_path
+                .filter(|p| !p.trim().is_empty())
+                .map(|p| safe_path::prepare_new(&p).map(|p| p.to_string_lossy().to_string()))
+                .transpose()?;
+            if let Some(output_path) = &output_path {
+                std::fs::write(output_path, &vcard)
+                    .map_err(|e| format!("vCard 파일 저장 실패: {}", e))?;
+                println!("✅ vCard 저장: {}", output_path);
+            }
+            Ok(json!({
+                "vcard": vcard,
+                "count": contacts.len(),
+                "output_path": output_path
+            })
+            .to_string())
+        }
+        _ => Err(format!("UNSUPPORTED_ACTION: {}", action)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::{parse_output, path_str, temp_dir};
+    use serde_json::json;
+
+    #[test]
+    fn vcard_parses_phone_exports_and_folded_lines() {
+        let content = "BEGIN:VCARD\r\nVERSION:2.1\r\nN;CHARSET=UTF-8;ENCODING=QUOTED-PRINTABLE:=ED=99=8D;=EA=B8=B8=\r\n=EB=8F=99;;;\r\nTEL;CELL;PREF:010-1234-5678\r\nEND:VCARD\r\nBEGIN:VCARD\r\nVERSION:3.0\r\nFN:Kim\\, Chul\r\nitem1.EMAIL;TYPE=INTERNET,WORK:kim@example.com\r\nNOTE:first\\nsecond line is\r\n  folded\r\nEND:VCARD\r\n";
+        let contacts = parse_vcards(content);
+        assert_eq!(contacts.len(), 2);
+        assert_eq!(contacts[0].full_name, "홍길동");
+        assert_eq!(contacts[0].phones[0].kind, "cell");
+        assert_eq!(contacts[0].phones[0].value, "010-1234-5678");
+        assert_eq!(contacts[1].full_name, "Kim, Chul");
+        assert_eq!(contacts[1].emails[0].kind, "work");
+        assert_eq!(contacts[1].note, "first\nsecond line is folded");
+    }
+
+    #[tokio::test]
+    async fn vcard_generates_and_round_trips_contacts() {
+        let dir = temp_dir();
+        let output = dir.path().join("contacts.vcf");
+        let contacts = serde_json::from_value(json!([{
+            "last_name": "김",
+            "first_name": "철수",
+            "organization": "Trigger; Inc",
+            "phones": [{ "type": "cell", "value": "010-0000-0000" }],
+            "addresses": [{ "type": "work", "street": "테헤란로 1", "city": "서울", "postal_code": "06234", "country": "KR" }]
+        }]))
+        .unwrap();
+        let result = parse_output(
+            &vcard_node(
+                "generate".into(),
+                None,
+                None,
+                Some(contacts),
+                Some(path_str(&output)),
+            )
+            .await
+            .unwrap(),
+        );
+        let vcard = result["vcard"].as_str().unwrap();
+        assert!(vcard.contains("FN:김철수\r\n"));
+        assert!(vcard.contains("ORG:Trigger\\; Inc\r\n"));
+        assert!(vcard.contains("TEL;TYPE=CELL:010-0000-0000\r\n"));
+
+        let parsed = parse_output(
+            &vcard_node("parse".into(), Some(path_str(&output)), None, None, None)
+                .await
+                .unwrap(),
+        );
+        assert_eq!(parsed["count"], 1);
+        assert_eq!(parsed["contacts"][0]["organization"], "Trigger; Inc");
+        assert_eq!(parsed["contacts"][0]["addresses"][0]["city"], "서울");
+    }
+}
@@ -1,30 +1,68 @@
 // src-tauri/src/nodes/video_download_node.rs
+use super::disk_preflight;
+use super::download_registry;
+use super::os_command::ProcessPriority;
+use super::resource_guard;
+use super::safe_path;
 use regex::Regex;
 use std::path::PathBuf;
 use tauri::command;
 
+// 영상 1개당 예상 크기 (고화질 + MOV 변환 시 임시로 두 배), 디스크 사전 확인용
+const ESTIMATED_VIDEO_BYTES: u64 = 500 * 1024 * 1024;
+
 #[command]
 pub async fn video_download_node(
     urls: String,
     folder_name: String,
     download_path: String,
+    priority: Option<String>,
+    stamp_metadata: Option<bool>,
+    skip_downloaded: Option<bool>,
 ) -> Result<String, String> {
     println!("🎬 VideoDownloadNode 업그레이드 버전 실행 시작");
     println!("📝 URLs: {}", urls);
     println!("📁 Folder Name: '{}'", folder_name);
     println!("📂 Download Path: {}", download_path);
 
+    // 백그라운드 작업이면 yt-dlp / ffmpeg 를 낮은 우선순위로 실행
+    let priority = ProcessPriority::parse(priority.as_deref())?;
+
     // 1️⃣ URL 검증 및 파싱
     let valid_urls = validate_and_parse_urls(urls)?;
     println!("✅ 검증된 URL 개수: {}", valid_urls.len());
 
+    // 이전 실행에서 이미 받은 URL 은 건너뜀 (clear_download_history 로 초기화)
+    let valid_urls = if skip_downloaded.unwrap_or(true) {
+        let (skipped, remaining): (Vec<String>, Vec<String>) = valid_urls
+            .into_iter()
+            .partition(|url| download_registry::already_downloaded(url).is_some());
+        for url in &skipped {
+            println!("⏭️ 이미 다운로드한 URL 건너뜀: {}", url);
+        }
+        remaining
+    } else {
+        valid_urls
+    };
+
     // 2️⃣ 똑똑한 폴더 생성
     let final_download_path = create_smart_download_folder(download_path, folder_name).await?;
     println!("🎯 최종 다운로드 경로: {}", final_download_path);
 
+    // 중간에 디스크가 가득 차지 않도록 미리 확인
+    disk_preflight::ensure_free_space(
+        std::path::Path::new(&final_download_path),
+        valid_urls.len() as u64 * ESTIMATED_VIDEO_BYTES,
+    )?;
+
     // 3️⃣ 병렬 다운로드 엔진 실행
-    let _download_result =
-        download_videos_parallel(valid_urls, final_download_path.clone()).await?;
+    let _download_result = download_videos_parallel(
+        valid_urls,
+        final_download_path.clone(),
+        priority,
+        stamp_metadata.unwrap_or(false),
+    )
+    .await?;
     println!("✅ 다운로드 완료");
 
     // 최종 결과 반환 - 경로만!
@@ -148,29 +186,12 @@ async fn create_unique_folder(base_path: String, folder_name: String) -> Result<
 }
 
 fn sanitize_folder_name(name: &str) -> String {
-    // Windows/Mac/Linux 금지 문자들 처리
-    let forbidden_chars = ['<', '>', ':', '"', '/', '\\', '|', '?', '*'];
-
-    let sanitized = name
-        .chars()
-        .map(|c| {
-            if forbidden_chars.contains(&c) || c.is_control() {
-                '_'
-            } else {
-                c
-            }
-        })
-        .collect::<String>()
-        .trim()
-        .to_string();
-
-    // 빈 문자열 방지
-    if sanitized.is_empty() {
-        "New_Folder".to_string()
-    } else {
-        // 길이 제한 (50자)
-        sanitized.chars().take(50).collect()
-    }
+    // Windows/Mac/Linux 금지 문자, 예약 이름(CON 등) 처리
+    let sanitized = safe_path::sanitize_file_name(name, "New_Folder");
+
+    // 길이 제한 (50자)
+    let truncated: String = sanitized.chars().take(50).collect();
+    truncated.trim_end_matches(['.', ' ']).to_string()
 }
 
 // ===================================================================
@@ -180,6 +201,8 @@ fn sanitize_folder_name(name: &str) -> String {
 async fn download_videos_parallel(
     urls: Vec<String>,
     download_path: String,
+    priority: ProcessPriority,
+    stamp_metadata: bool,
 ) -> Result<String, String> {
     let urls_count = urls.len();
 
@@ -209,8 +232,9 @@ async fn download_videos_parallel(
         for url in chunk.iter() {
             let url = url.clone();
             let path = download_path.clone();
-            let handle =
-                tokio::spawn(async move { download_single_video_optimized(url, &path).await });
+            let handle = tokio::spawn(async move {
+                download_single_video_optimized(url, &path, priority, stamp_metadata).await
+            });
             handles.push(handle);
         }
 
@@ -239,6 +263,8 @@ async fn download_videos_parallel(
 async fn download_single_video_optimized(
     url: String,
     download_path: &str,
+    priority: ProcessPriority,
+    stamp_metadata: bool,
 ) -> Result<String, String> {
     // 플랫폼 구분
     let platform = get_platform_from_url(&url);
@@ -258,7 +284,25 @@ async fn download_single_video_optimized(
     let (yt_dlp_cmd, ffmpeg_cmd) = get_binary_tool_paths().await?;
 
     // 플랫폼별 최적화된 다운로드 옵션
-    let args = get_platform_optimized_args(&platform, &output_path_str, &url);
+    let mut args = get_platform_optimized_args(&platform, &output_path_str, &url);
+
+    // 메타데이터 기록용 업로더 이름은 별도 파일로 받음 (stdout 파일 경로 파싱에 영향 없도록)
+    let uploader_file = std::env::temp_dir().join(format!(
+        "ytdlp_uploader_{}_{:08x}.txt",
+        timestamp,
+        rand::random::<u32>()
+    ));
+    if stamp_metadata {
+        let url_index = args.len() - 1;
+        args.splice(
+            url_index..url_index,
+            [
+                "--print-to-file".to_string(),
+                UPLOADER_TEMPLATE.to_string(),
+                uploader_file.to_string_lossy().to_string(),
+            ],
+        );
+    }
     let args_str: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
 
     println!("🎯 {} 플랫폼별 최적화 다운로드 시작...", platform);
@@ -274,10 +318,18 @@ async fn download_single_video_optimized(
         cmd.creation_flags(0x08000000); // CREATE_NO_WINDOW
     }
 
-    let output = cmd
-        .output()
+    cmd.stdout(std::process::Stdio::piped());
+    cmd.stderr(std::process::Stdio::piped());
+    let child = cmd
+        .spawn()
+        .map_err(|e| format!("yt-dlp 실행 실패: {}", e))?;
+    priority.apply(child.id());
+    let guard = resource_guard::watch(child.id(), "yt-dlp");
+    let output = child
+        .wait_with_output()
         .await
         .map_err(|e| format!("yt-dlp 실행 실패: {}", e))?;
+    guard.check()?;
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
@@ -288,7 +340,7 @@ async fn download_single_video_optimized(
     let downloaded_file = find_downloaded_file(&stdout, &platform);
 
     // 틱톡/인스타그램 후처리 (MOV 변환)
-    if is_tiktok || is_instagram {
+    let (final_file, message) = if is_tiktok || is_instagram {
         if let Some(ref input_file) = downloaded_file {
             println!(
                 "🔄 {} MP4 → MOV 변환 중 (프리미어 프로 최적화)...",
@@ -299,7 +351,7 @@ async fn download_single_video_optimized(
             let mov_file = mov_file_path.to_string_lossy().to_string();
 
             let conversion_result =
-                convert_to_mov_optimized(input_file, &mov_file, &ffmpeg_cmd).await;
+                convert_to_mov_optimized(input_file, &mov_file, &ffmpeg_cmd, priority).await;
 
             match conversion_result {
                 Ok(_) => {
@@ -307,23 +359,75 @@ async fn download_single_video_optimized(
                     if let Err(e) = std::fs::remove_file(input_file) {
                         println!("⚠️ 원본 파일 삭제 실패: {}", e);
                     }
-                    Ok(format!(
-                        "🔥 {} MOV 변환 완료! (VFR→CFR + 모노오디오)",
-                        platform
-                    ))
+                    (
+                        Some(mov_file_path),
+                        format!("🔥 {} MOV 변환 완료! (VFR→CFR + 모노오디오)", platform),
+                    )
                 }
                 Err(e) => {
                     println!("❌ MOV 변환 실패: {}", e);
-                    Ok(format!("🔥 {} 다운로드 완료! (변환 실패: {})", platform, e))
+                    (
+                        downloaded_file.clone(),
+                        format!("🔥 {} 다운로드 완료! (변환 실패: {})", platform, e),
+                    )
                 }
             }
         } else {
-            Ok(format!("🔥 {} 다운로드 완료!", platform))
+            (None, format!("🔥 {} 다운로드 완료!", platform))
         }
     } else {
         // 유튜브는 그대로
-        Ok(format!("🔥 {} H.264 고화질 다운로드 완료! (MP4)", platform))
+        (
+            downloaded_file,
+            format!("🔥 {} H.264 고화질 다운로드 완료! (MP4)", platform),
+        )
+    };
+
+    if stamp_metadata {
+        let uploader = std::fs::read_to_string(&uploader_file)
+            .ok()
+            .and_then(|text| text.lines().next().map(|line| line.trim().to_string()))
+            .filter(|line| !line.is_empty() && line != "NA");
+        let _ = std::fs::remove_file(&uploader_file);
+
+        match &final_file {
+            Some(file) => {
+                let downloaded_at = chrono::Local::now().format("%Y-%m-%d %H:%M").to_string();
+                // 기록 실패는 다운로드 자체를 실패로 만들지 않음
+                if let Err(e) = stamp_source_metadata(
+                    file,
+                    &ffmpeg_cmd,
+                    &url,
+                    uploader.as_deref(),
+                    &downloaded_at,
+                    priority,
+                )
+                .await
+                {
+                    println!("⚠️ 메타데이터 기록 실패: {}", e);
+                }
+            }
+            None => println!("⚠️ 다운로드 파일을 찾지 못해 메타데이터 기록 생략"),
+        }
     }
+
+    // 다운로드 기록 (다른 URL 로 같은 영상을 받았으면 새 파일은 정리됨)
+    let record_url = url.clone();
+    let duplicate = tokio::task::spawn_blocking(move || {
+        download_registry::record_download(&record_url, final_file.as_deref(), "video")
+    })
+    .await
+    .ok()
+    .flatten();
+    if let Some(existing) = duplicate {
+        return Ok(format!(
+            "♻️ {} 이미 받은 영상과 동일: {}",
+            platform,
+            existing.file.unwrap_or_default()
+        ));
+    }
+
+    Ok(message)
 }
 
 // ===================================================================
@@ -389,8 +493,17 @@ async fn get_binary_tool_paths() -> Result<(String, String), String> {
         .to_path_buf();
 
     let binaries_dir = exe_dir.join("binaries");
-    let yt_dlp_path = binaries_dir.join("yt-dlp.exe");
-    let ffmpeg_path = binaries_dir.join("ffmpeg.exe");
+    // 🧰 binaries 에 없으면 도구 관리자가 설치한 폴더
+    let find = |file_name: &str| {
+        let path = binaries_dir.join(file_name);
+        if path.exists() {
+            path
+        } else {
+            super::tools::managed_path(file_name).unwrap_or(path)
+        }
+    };
+    let yt_dlp_path = find("yt-dlp.exe");
+    let ffmpeg_path = find("ffmpeg.exe");
 
     // 파일 존재 확인
     if !yt_dlp_path.exists() {
@@ -418,13 +531,13 @@ fn create_output_path(folder_path: &str, platform: &str, timestamp: u64) -> Path
 
     if platform == "틱톡" || platform == "인스타그램" {
         let platform_code = if platform == "틱톡" { "tik" } else { "ins" };
-        output_path.push(&format!(
+        output_path.push(format!(
             "{}_{}_%(title,id)s.%(ext)s",
             platform_code, timestamp
         ));
     } else {
         // 유튜브: 타임스탬프 + 제목 + ID
-        output_path.push(&format!("ytb_{}_%(title,id)s.%(ext)s", timestamp));
+        output_path.push(format!("ytb_{}_%(title,id)s.%(ext)s", timestamp));
     }
 
     output_path
@@ -467,9 +580,10 @@ fn find_downloaded_file(stdout: &str, _platform: &str) -> Option<PathBuf> {
 // ===================================================================
 
 async fn convert_to_mov_optimized(
-    input_file: &PathBuf,
+    input_file: &std::path::Path,
     output_file: &str,
     ffmpeg_cmd: &str,
+    priority: ProcessPriority,
 ) -> Result<(), String> {
     if !input_file.exists() {
         return Err(format!(
@@ -517,10 +631,18 @@ async fn convert_to_mov_optimized(
 
     println!("🎬 FFmpeg 프리미어 프로 최적화 변환 시작...");
 
-    let ffmpeg_output = cmd
-        .output()
+    cmd.stdout(std::process::Stdio::piped());
+    cmd.stderr(std::process::Stdio::piped());
+    let child = cmd
+        .spawn()
+        .map_err(|e| format!("FFmpeg 실행 실패: {}", e))?;
+    priority.apply(child.id());
+    let guard = resource_guard::watch(child.id(), "ffmpeg");
+    let ffmpeg_output = child
+        .wait_with_output()
         .await
         .map_err(|e| format!("FFmpeg 실행 실패: {}", e))?;
+    guard.check()?;
 
     if ffmpeg_output.status.success() {
         let output_path = std::path::Path::new(output_file);
@@ -537,7 +659,100 @@ async fn convert_to_mov_optimized(
 }
 
 // ===================================================================
-// 7️⃣ 결과 요약
+// 7️⃣ 출처 메타데이터 기록 (몇 달 뒤에도 어디서 받은 영상인지 알 수 있도록)
+// ===================================================================
+
+// 업로더가 없는 사이트는 채널/제작자 이름으로
+const UPLOADER_TEMPLATE: &str = "%(uploader,channel,creator)s";
+
+// ffmpeg 인자: 재인코딩 없이(-c copy) 기존 태그는 유지하고 artist / comment / description 만 덮어씀
+pub fn metadata_stamp_args(
+    input: &str,
+    output: &str,
+    source_url: &str,
+    uploader: Option<&str>,
+    downloaded_at: &str,
+) -> Vec<String> {
+    let mut comment = format!("Source: {} | Downloaded: {}", source_url, downloaded_at);
+    if let Some(uploader) = uploader {
+        comment.push_str(&format!(" | Uploader: {}", uploader));
+    }
+
+    let mut args: Vec<String> = ["-i", input, "-map", "0", "-c", "copy", "-map_metadata", "0"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    if let Some(uploader) = uploader {
+        args.extend(["-metadata".to_string(), format!("artist={}", uploader)]);
+    }
+    args.extend([
+        "-metadata".to_string(),
+        format!("comment={}", comment),
+        "-metadata".to_string(),
+        format!("description={}", source_url),
+        "-y".to_string(),
+        output.to_string(),
+    ]);
+    args
+}
+
+async fn stamp_source_metadata(
+    file: &std::path::Path,
+    ffmpeg_cmd: &str,
+    source_url: &str,
+    uploader: Option<&str>,
+    downloaded_at: &str,
+    priority: ProcessPriority,
+) -> Result<(), String> {
+    // 같은 폴더의 임시 파일에 쓴 뒤 원본과 교체 (확장자로 컨테이너가 정해지므로 유지)
+    let extension = file
+        .extension()
+        .map(|e| e.to_string_lossy().to_string())
+        .unwrap_or_else(|| "mp4".to_string());
+    let stamped = file.with_extension(format!("stamped.{}", extension));
+    let args = metadata_stamp_args(
+        &file.to_string_lossy(),
+        &stamped.to_string_lossy(),
+        source_url,
+        uploader,
+        downloaded_at,
+    );
+
+    let mut cmd = tokio::process::Command::new(ffmpeg_cmd);
+    cmd.args(&args);
+
+    #[cfg(target_os = "windows")]
+    {
+        #[allow(unused_imports)]
+        use std::os::windows::process::CommandExt;
+        cmd.creation_flags(0x08000000); // CREATE_NO_WINDOW
+    }
+
+    cmd.stdout(std::process::Stdio::piped());
+    cmd.stderr(std::process::Stdio::piped());
+    let child = cmd
+        .spawn()
+        .map_err(|e| format!("FFmpeg 실행 실패: {}", e))?;
+    priority.apply(child.id());
+    let output = child
+        .wait_with_output()
+        .await
+        .map_err(|e| format!("FFmpeg 실행 실패: {}", e))?;
+
+    if !output.status.success() || !stamped.exists() {
+        let _ = std::fs::remove_file(&stamped);
+        return Err(format!(
+            "FFmpeg 메타데이터 기록 실패: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    std::fs::rename(&stamped, file).map_err(|e| format!("파일 교체 실패: {}", e))?;
+    println!("🏷️ 출처 메타데이터 기록: {}", file.display());
+    Ok(())
+}
+
+// ===================================================================
+// 8️⃣ 결과 요약
 // ===================================================================
 
 fn create_download_summary(results: &[Result<String, String>]) -> Result<String, String> {
@@ -566,3 +781,41 @@ fn create_download_summary(results: &[Result<String, String>]) -> Result<String,
         Ok(summary)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn video_download_stamps_source_metadata_without_reencoding() {
+        let args = metadata_stamp_args(
+            "in.mp4",
+            "in.stamped.mp4",
+            "https://youtu.be/abc",
+            Some("채널명"),
+            "2026-10-16 09:00",
+        );
+        assert_eq!(
+            &args[..8],
+            [
+                "-i",
+                "in.mp4",
+                "-map",
+                "0",
+                "-c",
+                "copy",
+                "-map_metadata",
+                "0"
+            ]
+        );
+        assert!(args.contains(&"artist=채널명".to_string()));
+        assert!(args.contains(
+            &"comment=Source: https://youtu.be/abc | Downloaded: 2026-10-16 09:00 | Uploader: 채널명"
+                .to_string()
+        ));
+        assert_eq!(args.last().unwrap(), "in.stamped.mp4");
+
+        let args = metadata_stamp_args("a.mov", "b.mov", "u", None, "d");
+        assert!(!args.iter().any(|a| a.starts_with("artist=")));
+    }
+}
@@ -0,0 +1,266 @@
+// src-tauri/src/nodes/voice_trigger.rs
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter};
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::sync::RwLock;
+
+// 🎙️ 음성 명령 트리거
+// 로컬 음성 인식기(whisper.cpp의 whisper-stream)를 선택한 마이크로 실행하고,
+// 인식된 문장에 호출어(wake phrase)가 나오면 뒤따르는 명령을 "voice-trigger" 이벤트로 전달
+
+#[derive(Debug, Serialize, Clone)]
+struct VoiceTriggerEvent {
+    node_id: String,
+    wake_phrase: String,
+    command: String,
+    transcript: String,
+    timestamp: u64,
+}
+
+struct VoiceTriggerHandle {
+    wake_phrase: String,
+    microphone: Option<String>,
+    abort_handle: tokio::task::AbortHandle,
+}
+
+type VoiceTriggerRegistry = Arc<RwLock<HashMap<String, VoiceTriggerHandle>>>;
+
+static VOICE_TRIGGER_REGISTRY: std::sync::OnceLock<VoiceTriggerRegistry> =
+    std::sync::OnceLock::new();
+
+fn get_voice_trigger_registry() -> &'static VoiceTriggerRegistry {
+    VOICE_TRIGGER_REGISTRY.get_or_init(|| Arc::new(RwLock::new(HashMap::new())))
+}
+
+// 실행 파일 옆 binaries 폴더에서 인식기/모델 찾기
+fn find_binary(file_name: &str) -> Result<PathBuf, String> {
+    let exe_dir = std::env::current_exe()
+        .map_err(|e| format!("실행 파일 경로 찾기 실패: {}", e))?
+        .parent()
+        .ok_or("상위 폴더 없음")?
+        .to_path_buf();
+
+    let path = exe_dir.join("binaries").join(file_name);
+    if path.exists() {
+        return Ok(path);
+    }
+    // 🧰 도구 관리자로 설치한 경우
+    let managed = super::tools::tools_dir().join(file_name);
+    if managed.exists() {
+        Ok(managed)
+    } else {
+        Err(format!(
+            "{}를 찾을 수 없습니다. binaries 폴더에 있는지 확인하거나 install_tool 로 설치하세요.",
+            file_name
+        ))
+    }
+}
+
+// 비교용 정규화 (소문자, 문장부호 제거, 공백 정리)
+fn normalize_phrase(text: &str) -> String {
+    text.chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c.is_whitespace() {
+                c.to_lowercase().next().unwrap_or(c)
+            } else {
+                ' '
+            }
+        })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// 인식 결과에 호출어가 있으면 호출어 뒤의 명령 반환 (명령이 비어있으면 Some(""))
+fn match_wake_phrase(transcript: &str, wake_phrase: &str) -> Option<String> {
+    let normalized = normalize_phrase(transcript);
+    let phrase = normalize_phrase(wake_phrase);
+    let index = normalized.find(&phrase)?;
+    Some(normalized[index + phrase.len()..].trim().to_string())
+}
+
+// whisper-stream 출력 라인에서 타임스탬프/특수 토큰 제거
+fn clean_transcript_line(line: &str) -> String {
+    let mut text = line.trim().to_string();
+    if text.starts_with('[') {
+        if let Some(end) = text.find(']') {
+            text = text[end + 1..].to_string();
+        }
+    }
+    text.replace("[BLANK_AUDIO]", "")
+        .replace("\u{1b}[2K", "")
+        .trim()
+        .to_string()
+}
+
+async fn run_recognizer_loop(
+    app_handle: AppHandle,
+    node_id: String,
+    wake_phrase: String,
+    mut command: tokio::process::Command,
+) {
+    let mut child = match command.spawn() {
+        Ok(child) => child,
+        Err(e) => {
+            println!("❌ 음성 인식기 실행 실패: {}", e);
+            let _ = app_handle.emit(
+                "voice-trigger-error",
+                &serde_json::json!({ "node_id": node_id, "error": e.to_string() }),
+            );
+            return;
+        }
+    };
+
+    let stdout = match child.stdout.take() {
+        Some(stdout) => stdout,
+        None => return,
+    };
+
+    let mut lines = BufReader::new(stdout).lines();
+    // 호출어만 말하고 잠시 쉰 경우 다음 문장을 명령으로 사용
+    let mut awaiting_command = false;
+
+    while let Ok(Some(line)) = lines.next_line().await {
+        let transcript = clean_transcript_line(&line);
+        if transcript.is_empty() {
+            continue;
+        }
+
+        let command_text = if awaiting_command {
+            awaiting_command = false;
+            Some(normalize_phrase(&transcript))
+        } else {
+            match match_wake_phrase(&transcript, &wake_phrase) {
+                Some(command) if command.is_empty() => {
+                    awaiting_command = true;
+                    None
+                }
+                other => other,
+            }
+        };
+
+        if let Some(command_text) = command_text {
+            println!("🎙️ 음성 명령 인식 (node {}): {}", node_id, command_text);
+
+            let event = VoiceTriggerEvent {
+                node_id: node_id.clone(),
+                wake_phrase: wake_phrase.clone(),
+                command: command_text,
+                transcript,
+                timestamp: std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_millis() as u64,
+            };
+
+            if let Err(e) = app_handle.emit("voice-trigger", &event) {
+                eprintln!("❌ Failed to emit voice trigger event: {}", e);
+            }
+        }
+    }
+
+    println!("🛑 음성 인식기 종료됨 (node {})", node_id);
+}
+
+#[tauri::command]
+pub async fn start_voice_trigger(
+    app_handle: AppHandle,
+    node_id: String,
+    wake_phrase: String,
+    microphone: Option<String>,
+    language: Option<String>,
+) -> Result<String, String> {
+    if wake_phrase.trim().is_empty() {
+        return Err("EMPTY_WAKE_PHRASE".to_string());
+    }
+
+    // 같은 노드의 기존 리스너는 교체
+    let _ = stop_voice_trigger(node_id.clone()).await;
+
+    let recognizer_name = if cfg!(target_os = "windows") {
+        "whisper-stream.exe"
+    } else {
+        "whisper-stream"
+    };
+    let recognizer = find_binary(recognizer_name)?;
+    let model = find_binary("ggml-base.bin")?;
+
+    let mut command = tokio::process::Command::new(&recognizer);
+    command
+        .arg("-m")
+        .arg(&model)
+        .args(["--step", "500", "--length", "4000", "-t", "4"])
+        .args(["-l", language.as_deref().unwrap_or("auto")])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .kill_on_drop(true);
+
+    // 마이크 선택 (인식기의 캡처 장치 번호)
+    if let Some(device) = microphone.as_deref().filter(|d| !d.trim().is_empty()) {
+        command.args(["-c", device.trim()]);
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        #[allow(unused_imports)]
+        use std::os::windows::process::CommandExt;
+        command.creation_flags(0x08000000); // CREATE_NO_WINDOW
+    }
+
+    let task = tokio::spawn(run_recognizer_loop(
+        app_handle,
+        node_id.clone(),
+        wake_phrase.clone(),
+        command,
+    ));
+
+    let registry = get_voice_trigger_registry();
+    registry.write().await.insert(
+        node_id.clone(),
+        VoiceTriggerHandle {
+            wake_phrase: wake_phrase.clone(),
+            microphone,
+            abort_handle: task.abort_handle(),
+        },
+    );
+
+    println!(
+        "🎙️ 음성 트리거 시작 (node {}, 호출어: '{}')",
+        node_id, wake_phrase
+    );
+    Ok(format!("Listening for '{}'", wake_phrase))
+}
+
+// 태스크 중단 시 kill_on_drop 으로 인식기 프로세스도 함께 종료
+#[tauri::command]
+pub async fn stop_voice_trigger(node_id: String) -> Result<String, String> {
+    let registry = get_voice_trigger_registry();
+    match registry.write().await.remove(&node_id) {
+        Some(handle) => {
+            handle.abort_handle.abort();
+            println!("🛑 음성 트리거 중지 (node {})", node_id);
+            Ok("Voice trigger stopped".to_string())
+        }
+        None => Ok("No voice trigger running for this node".to_string()),
+    }
+}
+
+#[tauri::command]
+pub async fn get_voice_trigger_status(node_id: String) -> Result<serde_json::Value, String> {
+    let registry = get_voice_trigger_registry();
+    let triggers = registry.read().await;
+
+    Ok(match triggers.get(&node_id) {
+        Some(handle) => serde_json::json!({
+            "running": !handle.abort_handle.is_finished(),
+            "wake_phrase": handle.wake_phrase,
+            "microphone": handle.microphone
+        }),
+        None => serde_json::json!({ "running": false }),
+    })
+}
@@ -0,0 +1,315 @@
+// src-tauri/src/nodes/watermark_node.rs
+use super::{os_command, safe_path};
+use super::pdf_builder_node::SYSTEM_FONT_CANDIDATES;
+use image::{imageops, DynamicImage, GenericImageView};
+use serde_json::json;
+use std::path::Path;
+
+// 💧 워터마크 노드
+// 이미지/동영상에 로고 또는 텍스트를 덧씌움 (위치, 투명도, 크기)
+// - 이미지 + 로고: image 크레이트로 직접 합성
+// - 텍스트, 동영상: 번들된 ffmpeg 필터(overlay / drawtext) 사용
+
+const IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "bmp", "webp", "gif", "tif", "tiff"];
+
+fn is_image(path: &str) -> bool {
+    Path::new(path)
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .is_some_and(|e| IMAGE_EXTENSIONS.contains(&e.as_str()))
+}
+
+// 위치 이름 → 왼쪽 위 좌표 (margin 포함)
+pub fn overlay_position(
+    position: &str,
+    base: (u32, u32),
+    overlay: (u32, u32),
+    margin: u32,
+) -> Result<(i64, i64), String> {
+    let (bw, bh) = (base.0 as i64, base.1 as i64);
+    let (ow, oh) = (overlay.0 as i64, overlay.1 as i64);
+    let m = margin as i64;
+
+    Ok(match position {
+        "top_left" => (m, m),
+        "top_right" => (bw - ow - m, m),
+        "bottom_left" => (m, bh - oh - m),
+        "bottom_right" => (bw - ow - m, bh - oh - m),
+        "center" => ((bw - ow) / 2, (bh - oh) / 2),
+        other => return Err(format!("UNSUPPORTED_POSITION: {}", other)),
+    })
+}
+
+// ffmpeg 식 (W/H: 원본, w/h: 덧씌울 대상)
+fn ffmpeg_position(
+    position: &str,
+    margin: u32,
+    width_var: &str,
+    height_var: &str,
+) -> Result<String, String> {
+    let (w, h) = (width_var, height_var);
+    Ok(match position {
+        "top_left" => format!("x={m}:y={m}", m = margin),
+        "top_right" => format!("x=W-{w}-{m}:y={m}", w = w, m = margin),
+        "bottom_left" => format!("x={m}:y=H-{h}-{m}", h = h, m = margin),
+        "bottom_right" => format!("x=W-{w}-{m}:y=H-{h}-{m}", w = w, h = h, m = margin),
+        "center" => format!("x=(W-{w})/2:y=(H-{h})/2", w = w, h = h),
+        other => return Err(format!("UNSUPPORTED_POSITION: {}", other)),
+    })
+}
+
+fn overlay_logo_on_image(
+    input_path: &str,
+    output_path: &str,
+    logo_path: &str,
+    position: &str,
+    opacity: f32,
+    scale: f32,
+    margin: u32,
+) -> Result<(), String> {
+    let base = image::open(input_path).map_err(|e| format!("IMAGE_LOAD_ERROR: {}", e))?;
+    let logo = image::open(logo_path).map_err(|e| format!("LOGO_LOAD_ERROR: {}", e))?;
+
+    // 로고 너비 = 원본 너비 × scale (비율 유지)
+    let target_width = ((base.width() as f32 * scale).round() as u32).max(1);
+    let target_height =
+        ((logo.height() as f32 * target_width as f32 / logo.width() as f32).round() as u32).max(1);
+    let mut logo = logo
+        .resize_exact(target_width, target_height, imageops::FilterType::Lanczos3)
+        .to_rgba8();
+
+    for pixel in logo.pixels_mut() {
+        pixel[3] = (pixel[3] as f32 * opacity).round() as u8;
+    }
+
+    let (x, y) = overlay_position(position, base.dimensions(), logo.dimensions(), margin)?;
+    let mut base = base.to_rgba8();
+    imageops::overlay(&mut base, &logo, x, y);
+
+    // JPEG 등 알파 채널이 없는 형식은 RGB 로 저장
+    let output = DynamicImage::ImageRgba8(base);
+    let lower = output_path.to_lowercase();
+    let result = if lower.ends_with(".jpg") || lower.ends_with(".jpeg") || lower.ends_with(".bmp") {
+        DynamicImage::ImageRgb8(output.to_rgb8()).save(output_path)
+    } else {
+        output.save(output_path)
+    };
+    result.map_err(|e| format!("IMAGE_SAVE_ERROR: {}", e))
+}
+
+// drawtext 옵션 값 이스케이프 (':' 와 '\' , ''' )
+fn escape_filter_value(value: &str) -> String {
+    value
+        .replace('\\', "/")
+        .replace(':', "\\:")
+        .replace('\'', "\\'")
+}
+
+#[allow(clippy::too_many_arguments)]
+fn run_ffmpeg_overlay(
+    input_path: &str,
+    output_path: &str,
+    logo_path: Option<&str>,
+    text: Option<&str>,
+    position: &str,
+    opacity: f32,
+    scale: f32,
+    margin: u32,
+    font_size: u32,
+    font_path: Option<&str>,
+) -> Result<(), String> {
+    let mut args: Vec<String> = vec![
+        "-y".into(),
+        "-v".into(),
+        "error".into(),
+        "-i".into(),
+        input_path.into(),
+    ];
+    let mut text_file = None;
+
+    let filter = match (logo_path, text) {
+        (Some(logo), _) => {
+            args.extend(["-i".into(), logo.into()]);
+            format!(
+                "[1:v][0:v]scale2ref=w=main_w*{scale}:h=ow/a[logo][base];\
+                 [logo]format=rgba,colorchannelmixer=aa={opacity}[wm];\
+                 [base][wm]overlay={pos}",
+                scale = scale,
+                opacity = opacity,
+                pos = ffmpeg_position(position, margin, "w", "h")?
+            )
+        }
+        (None, Some(text)) => {
+            // 텍스트는 파일로 넘겨 특수문자 이스케이프 문제를 피함
+            let path = std::env::temp_dir().join(format!(
+                "watermark_{}.txt",
+                std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_nanos()
+            ));
+            std::fs::write(&path, text).map_err(|e| format!("임시 파일 생성 실패: {}", e))?;
+
+            let font = font_path.map(str::to_string).or_else(|| {
+                SYSTEM_FONT_CANDIDATES
+                    .iter()
+                    .find(|p| Path::new(p).exists())
+                    .map(|p| p.to_string())
+            });
+            let font_option = font
+                .map(|f| format!(":fontfile='{}'", escape_filter_value(&f)))
+                .unwrap_or_default();
+
+            let filter = format!(
+                "drawtext=textfile='{}'{}:fontsize={}:fontcolor=white@{}:borderw=2:bordercolor=black@{}:{}",
+                escape_filter_value(&path.to_string_lossy()),
+                font_option,
+                font_size,
+                opacity,
+                opacity * 0.6,
+                ffmpeg_position(position, margin, "tw", "th")?
+                    .replace('W', "w")
+                    .replace('H', "h")
+            );
+            text_file = Some(path);
+            filter
+        }
+        (None, None) => return Err("LOGO_OR_TEXT_REQUIRED".to_string()),
+    };
+
+    args.extend(["-filter_complex".into(), filter]);
+    if is_image(input_path) {
+        args.extend(["-frames:v".into(), "1".into()]);
+    } else {
+        // 동영상: 오디오는 재인코딩 없이 복사
+        args.extend(["-c:a".into(), "copy".into()]);
+    }
+    args.push(output_path.into());
+
+    let arg_refs: Vec<&str> = args.iter().map(String::as_str).collect();
+    let result = os_command::run(&os_command::bundled_binary("ffmpeg"), &arg_refs);
+
+    if let Some(path) = text_file {
+        let _ = std::fs::remove_file(path);
+    }
+    result.map(|_| ())
+}
+
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn watermark_node(
+    input_path: String,
+    output_path: String,
+    logo_path: Option<String>,
+    text: Option<String>,
+    position: Option<String>,
+    opacity: Option<f32>,
+    scale: Option<f32>,
+    margin: Option<u32>,
+    font_size: Option<u32>,
+    font_path: Option<String>,
+) -> Result<String, String> {
+    let input_path = safe_path::resolve_existing(&input_path)
+        .to_string_lossy()
+        .to_string();
+    if !Path::new(&input_path).is_file() {
+        return Err("FILE_NOT_FOUND".to_string());
+    }
+    if output_path.trim().is_empty() {
+        return Err("EMPTY_OUTPUT_PATH".to_string());
+    }
+    let output_path = safe_path::prepare_new(&output_path)?
+        .to_string_lossy()
+        .to_string();
+
+    let logo_path = logo_path
+        .filter(|p| !p.trim().is_empty())
+        .map(|p| safe_path::resolve_existing(&p).to_string_lossy().to_string());
+    if let Some(logo) = &logo_path {
+        if !Path::new(logo).is_file() {
+            return Err("LOGO_NOT_FOUND".to_string());
+        }
+    }
+    let text = text.filter(|t| !t.trim().is_empty());
+    let position = position
+        .unwrap_or_else(|| "bottom_right".to_string())
+        .to_lowercase();
+    let opacity = opacity.unwrap_or(0.7).clamp(0.0, 1.0);
+    let scale = scale.unwrap_or(0.15).clamp(0.01, 1.0);
+    let margin = margin.unwrap_or(20);
+
+    println!(
+        "💧 Watermark Node: {} → {} ({})",
+        input_path, output_path, position
+    );
+    super::file_creator_node::ensure_parent_dir(&output_path)?;
+
+    let output = output_path.clone();
+    tokio::task::spawn_blocking(move || match (&logo_path, is_image(&input_path)) {
+        (Some(logo), true) => overlay_logo_on_image(
+            &input_path,
+            &output,
+            logo,
+            &position,
+            opacity,
+            scale,
+            margin,
+        ),
+        _ => run_ffmpeg_overlay(
+            &input_path,
+            &output,
+            logo_path.as_deref(),
+            text.as_deref(),
+            &position,
+            opacity,
+            scale,
+            margin,
+            font_size.unwrap_or(36),
+            font_path.as_deref(),
+        ),
+    })
+    .await
+    .map_err(|e| format!("워터마크 처리 실패: {}", e))??;
+
+    println!("✅ 워터마크 적용 완료: {}", output_path);
+    Ok(json!({ "file_path": output_path }).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::{path_str, temp_dir};
+
+    #[tokio::test]
+    async fn watermark_node_overlays_logo_at_bottom_right() {
+        let dir = temp_dir();
+        let base_path = dir.path().join("base.png");
+        let logo_path = dir.path().join("logo.png");
+        let output_path = dir.path().join("out.png");
+        image::RgbaImage::from_pixel(100, 100, image::Rgba([0, 0, 0, 255]))
+            .save(&base_path)
+            .unwrap();
+        image::RgbaImage::from_pixel(10, 10, image::Rgba([255, 255, 255, 255]))
+            .save(&logo_path)
+            .unwrap();
+
+        watermark_node(
+            path_str(&base_path),
+            path_str(&output_path),
+            Some(path_str(&logo_path)),
+            None,
+            None,
+            Some(1.0),
+            Some(0.2),
+            Some(5),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+        let output = image::open(&output_path).unwrap().to_rgba8();
+        assert_eq!(output.get_pixel(90, 90)[0], 255); // 로고 (75..95)
+        assert_eq!(output.get_pixel(10, 10)[0], 0);
+    }
+}
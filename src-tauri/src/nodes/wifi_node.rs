@@ -0,0 +1,206 @@
+// src-tauri/src/nodes/wifi_node.rs
+use super::os_command;
+use serde_json::json;
+
+// 📶 Wi-Fi 노드
+// action: status(현재 SSID/신호 세기) | saved(저장된 네트워크 목록) | connect(저장된 네트워크 연결)
+
+#[derive(Debug, Default)]
+struct WifiStatus {
+    connected: bool,
+    ssid: Option<String>,
+    signal: Option<u32>, // 0-100 %
+}
+
+// "키 : 값" 형식 출력에서 값 찾기 (netsh 출력용)
+#[allow(dead_code)]
+fn find_field<'a>(output: &'a str, key: &str) -> Option<&'a str> {
+    output.lines().find_map(|line| {
+        let (name, value) = line.split_once(':')?;
+        (name.trim() == key).then(|| value.trim())
+    })
+}
+
+// RSSI(dBm) → 신호 품질(%) 근사 변환
+#[allow(dead_code)]
+fn rssi_to_percent(rssi: i32) -> u32 {
+    (2 * (rssi + 100)).clamp(0, 100) as u32
+}
+
+#[cfg(target_os = "windows")]
+fn query_status() -> Result<WifiStatus, String> {
+    let output = os_command::run("netsh", &["wlan", "show", "interfaces"])?;
+    let connected = find_field(&output, "State").is_some_and(|s| s == "connected");
+
+    Ok(WifiStatus {
+        connected,
+        ssid: find_field(&output, "SSID")
+            .filter(|_| connected)
+            .map(str::to_string),
+        signal: find_field(&output, "Signal").and_then(|s| s.trim_end_matches('%').parse().ok()),
+    })
+}
+
+#[cfg(target_os = "windows")]
+fn list_saved() -> Result<Vec<String>, String> {
+    let output = os_command::run("netsh", &["wlan", "show", "profiles"])?;
+    Ok(output
+        .lines()
+        .filter(|line| line.contains("All User Profile"))
+        .filter_map(|line| {
+            line.split_once(':')
+                .map(|(_, name)| name.trim().to_string())
+        })
+        .collect())
+}
+
+#[cfg(target_os = "windows")]
+fn connect(ssid: &str) -> Result<(), String> {
+    os_command::run("netsh", &["wlan", "connect", &format!("name={}", ssid)]).map(|_| ())
+}
+
+#[cfg(target_os = "macos")]
+const AIRPORT: &str =
+    "/System/Library/PrivateFrameworks/Apple80211.framework/Versions/Current/Resources/airport";
+
+#[cfg(target_os = "macos")]
+fn wifi_device() -> String {
+    // networksetup -listallhardwareports 에서 Wi-Fi 장치 이름(en0 등) 찾기
+    os_command::run_optional("networksetup", &["-listallhardwareports"])
+        .and_then(|output| {
+            let mut lines = output.lines();
+            while let Some(line) = lines.next() {
+                if line.contains("Wi-Fi") || line.contains("AirPort") {
+                    return lines
+                        .next()
+                        .and_then(|l| l.strip_prefix("Device:"))
+                        .map(|d| d.trim().to_string());
+                }
+            }
+            None
+        })
+        .unwrap_or_else(|| "en0".to_string())
+}
+
+#[cfg(target_os = "macos")]
+fn query_status() -> Result<WifiStatus, String> {
+    let output = os_command::run("networksetup", &["-getairportnetwork", &wifi_device()])?;
+    let ssid = output
+        .split_once("Current Wi-Fi Network:")
+        .map(|(_, ssid)| ssid.trim().to_string());
+    let signal = os_command::run_optional(AIRPORT, &["-I"])
+        .and_then(|info| find_field(&info, "agrCtlRSSI").and_then(|v| v.parse::<i32>().ok()))
+        .map(rssi_to_percent);
+
+    Ok(WifiStatus {
+        connected: ssid.is_some(),
+        ssid,
+        signal,
+    })
+}
+
+#[cfg(target_os = "macos")]
+fn list_saved() -> Result<Vec<String>, String> {
+    let output = os_command::run(
+        "networksetup",
+        &["-listpreferredwirelessnetworks", &wifi_device()],
+    )?;
+    Ok(output
+        .lines()
+        .skip(1) // "Preferred networks on en0:"
+        .map(|line| line.trim().to_string())
+        .filter(|line| !line.is_empty())
+        .collect())
+}
+
+#[cfg(target_os = "macos")]
+fn connect(ssid: &str) -> Result<(), String> {
+    // 저장된 네트워크는 키체인의 비밀번호 사용
+    let output = os_command::run(
+        "networksetup",
+        &["-setairportnetwork", &wifi_device(), ssid],
+    )?;
+    if output.contains("Could not find network") || output.contains("Failed") {
+        return Err(output);
+    }
+    Ok(())
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn query_status() -> Result<WifiStatus, String> {
+    let output = os_command::run("nmcli", &["-t", "-f", "ACTIVE,SSID,SIGNAL", "dev", "wifi"])?;
+    let active = output.lines().find_map(|line| {
+        let mut parts = line.splitn(3, ':');
+        if parts.next()? != "yes" {
+            return None;
+        }
+        let ssid = parts.next()?.to_string();
+        let signal = parts.next().and_then(|s| s.parse().ok());
+        Some((ssid, signal))
+    });
+
+    Ok(match active {
+        Some((ssid, signal)) => WifiStatus {
+            connected: true,
+            ssid: Some(ssid),
+            signal,
+        },
+        None => WifiStatus::default(),
+    })
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn list_saved() -> Result<Vec<String>, String> {
+    let output = os_command::run("nmcli", &["-t", "-f", "NAME,TYPE", "connection", "show"])?;
+    Ok(output
+        .lines()
+        .filter_map(|line| line.rsplit_once(':'))
+        .filter(|(_, kind)| kind.contains("wireless"))
+        .map(|(name, _)| name.to_string())
+        .collect())
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn connect(ssid: &str) -> Result<(), String> {
+    os_command::run("nmcli", &["connection", "up", "id", ssid]).map(|_| ())
+}
+
+#[tauri::command]
+pub async fn wifi_node(action: Option<String>, ssid: Option<String>) -> Result<String, String> {
+    let action = action
+        .map(|a| a.trim().to_lowercase())
+        .filter(|a| !a.is_empty())
+        .unwrap_or_else(|| "status".to_string());
+    println!("📶 Wi-Fi Node: {} {:?}", action, ssid);
+
+    tokio::task::spawn_blocking(move || match action.as_str() {
+        "status" => {
+            let status = query_status()?;
+            Ok(json!({
+                "connected": status.connected,
+                "ssid": status.ssid,
+                "signal": status.signal
+            })
+            .to_string())
+        }
+        "saved" => Ok(json!({ "networks": list_saved()? }).to_string()),
+        "connect" => {
+            let ssid = ssid
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .ok_or("EMPTY_SSID")?;
+
+            // 저장된 네트워크만 연결 (비밀번호는 OS가 관리)
+            if !list_saved()?.contains(&ssid) {
+                return Err(format!("NETWORK_NOT_SAVED: {}", ssid));
+            }
+            connect(&ssid)?;
+            println!("✅ Wi-Fi 연결 요청: {}", ssid);
+
+            Ok(json!({ "connected": true, "ssid": ssid }).to_string())
+        }
+        other => Err(format!("UNSUPPORTED_ACTION: {}", other)),
+    })
+    .await
+    .map_err(|e| format!("Wi-Fi 작업 실패: {}", e))?
+}
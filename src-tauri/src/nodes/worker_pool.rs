@@ -0,0 +1,193 @@
+// src-tauri/src/nodes/worker_pool.rs
+use super::{atomic_store, download_registry};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use tokio::sync::Semaphore;
+
+// ⚙️ CPU 작업 공용 워커 풀
+// 해시 / 압축 / 동기화 / QR 일괄 생성처럼 CPU 를 많이 쓰는 작업이 각자 스레드를 띄우지 않고 같은 풀을 사용
+// - par_map:      rayon 풀에서 항목별 병렬 처리 (파일 여러 개 해시 등)
+// - run_blocking: 동시에 도는 블로킹 작업 수를 허가(semaphore)로 제한한 spawn_blocking
+// 크기는 CPU 수 기준 (GLOBAL_CAP 상한), store/worker_pool.json 의 max_threads 로 노트북에서 더 낮출 수 있음
+// 크기를 바꾸면 새 풀로 교체 (이미 실행 중인 작업은 이전 풀에서 끝까지 실행)
+
+const GLOBAL_CAP: usize = 16;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct WorkerPoolSettings {
+    #[serde(default)]
+    pub max_threads: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkerPoolInfo {
+    pub cpu_count: usize,
+    pub cap: usize,
+    pub max_threads: Option<usize>,
+    pub threads: usize,
+}
+
+struct Pool {
+    threads: usize,
+    rayon: rayon::ThreadPool,
+    permits: Arc<Semaphore>,
+}
+
+static POOL: std::sync::OnceLock<Mutex<Option<Arc<Pool>>>> = std::sync::OnceLock::new();
+
+fn settings_path() -> PathBuf {
+    atomic_store::store_dir().join("worker_pool.json")
+}
+
+fn load_settings() -> WorkerPoolSettings {
+    atomic_store::read_json_verified(&settings_path()).unwrap_or_default()
+}
+
+fn cpu_count() -> usize {
+    std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+}
+
+// CPU 수 → 상한 → 사용자 제한 순서로 줄임 (최소 1)
+pub fn pool_size(cpu_count: usize, max_threads: Option<usize>) -> usize {
+    let size = cpu_count.clamp(1, GLOBAL_CAP);
+    match max_threads.filter(|n| *n > 0) {
+        Some(max) => size.min(max),
+        None => size,
+    }
+}
+
+fn build(threads: usize) -> Result<Pool, String> {
+    let rayon = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .thread_name(|i| format!("worker-pool-{}", i))
+        .build()
+        .map_err(|e| format!("워커 풀 생성 실패: {}", e))?;
+    Ok(Pool {
+        threads,
+        rayon,
+        permits: Arc::new(Semaphore::new(threads)),
+    })
+}
+
+fn current() -> Result<Arc<Pool>, String> {
+    let mut slot = POOL
+        .get_or_init(|| Mutex::new(None))
+        .lock()
+        .map_err(|e| e.to_string())?;
+    if let Some(pool) = slot.as_ref() {
+        return Ok(pool.clone());
+    }
+    let pool = Arc::new(build(pool_size(cpu_count(), load_settings().max_threads))?);
+    println!("⚙️ 워커 풀 준비: {}개 스레드", pool.threads);
+    *slot = Some(pool.clone());
+    Ok(pool)
+}
+
+// 블로킹 스레드에서 호출 (async 에서는 run_blocking 으로 감싸서)
+pub fn par_map<T, R, F>(items: Vec<T>, f: F) -> Result<Vec<R>, String>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Sync + Send,
+{
+    let pool = current()?;
+    Ok(pool.rayon.install(|| items.into_par_iter().map(f).collect()))
+}
+
+// 허가를 받은 뒤 spawn_blocking (동시에 threads 개까지만)
+pub async fn run_blocking<R, F>(f: F) -> Result<R, String>
+where
+    R: Send + 'static,
+    F: FnOnce() -> R + Send + 'static,
+{
+    let permits = current()?.permits.clone();
+    let _permit = permits.acquire_owned().await.map_err(|e| e.to_string())?;
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(|e| format!("작업 실행 실패: {}", e))
+}
+
+// 파일 하나의 (SHA-256, 크기) 또는 에러
+pub type FileHash = Result<(String, u64), String>;
+
+// 파일 여러 개의 SHA-256 (입력 순서 유지, 파일마다 결과)
+pub fn hash_files(paths: Vec<PathBuf>) -> Result<Vec<FileHash>, String> {
+    par_map(paths, |path| download_registry::file_sha256(&path))
+}
+
+fn info(settings: &WorkerPoolSettings, threads: usize) -> WorkerPoolInfo {
+    WorkerPoolInfo {
+        cpu_count: cpu_count(),
+        cap: GLOBAL_CAP,
+        max_threads: settings.max_threads,
+        threads,
+    }
+}
+
+#[tauri::command]
+pub fn get_worker_pool() -> Result<WorkerPoolInfo, String> {
+    Ok(info(&load_settings(), current()?.threads))
+}
+
+// max_threads 가 None 또는 0 이면 CPU 수 기준 기본값
+#[tauri::command]
+pub fn set_worker_pool(max_threads: Option<usize>) -> Result<WorkerPoolInfo, String> {
+    let settings = WorkerPoolSettings {
+        max_threads: max_threads.filter(|n| *n > 0),
+    };
+    atomic_store::write_json_atomic(&settings_path(), &settings)?;
+    let pool = Arc::new(build(pool_size(cpu_count(), settings.max_threads))?);
+    let threads = pool.threads;
+    *POOL
+        .get_or_init(|| Mutex::new(None))
+        .lock()
+        .map_err(|e| e.to_string())? = Some(pool);
+    println!("⚙️ 워커 풀 크기 변경: {}개 스레드", threads);
+    Ok(info(&settings, threads))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::download_registry;
+    use crate::nodes::test_support::temp_dir;
+    use std::fs;
+
+    #[test]
+    fn worker_pool_size_follows_cpu_count_cap_and_user_limit() {
+        assert_eq!(pool_size(8, None), 8);
+        assert_eq!(pool_size(64, None), 16);
+        assert_eq!(pool_size(8, Some(2)), 2);
+        assert_eq!(pool_size(8, Some(0)), 8);
+        assert_eq!(pool_size(0, None), 1);
+    }
+
+    #[tokio::test]
+    async fn worker_pool_hashes_files_in_order() {
+        let dir = temp_dir();
+        let paths: Vec<std::path::PathBuf> = (0..5)
+            .map(|i| {
+                let path = dir.path().join(format!("{}.txt", i));
+                fs::write(&path, format!("file {}", i)).unwrap();
+                path
+            })
+            .collect();
+        let missing = dir.path().join("missing.txt");
+        let mut inputs = paths.clone();
+        inputs.push(missing);
+
+        let hashes = run_blocking(move || hash_files(inputs))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(hashes.len(), 6);
+        for (path, hashed) in paths.iter().zip(&hashes) {
+            assert_eq!(hashed, &download_registry::file_sha256(path));
+        }
+        assert!(hashes[5].is_err());
+    }
+}
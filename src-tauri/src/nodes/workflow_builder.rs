@@ -0,0 +1,452 @@
+// src-tauri/src/nodes/workflow_builder.rs
+use super::ai_extract_node::extract_json_text;
+use super::cli_ai_node::request_claude_completion;
+use super::node_cache::make_cache_key;
+use super::{http_client, json_schema};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::collections::{HashMap, HashSet};
+
+// 🪄 대화로 워크플로우 초안 만들기
+// "하고 싶은 자동화를 설명하면 시작 그래프를 받는다"
+// 1. 프론트엔드가 사이드바 노드 config(type, settings, outputs) 목록을 catalog 로 넘김
+// 2. AI 에게 catalog + 요청을 주고 간단한 형태({ nodes: [{id,type,data}], edges: [...] })로 받음
+// 3. 구조(JSON Schema) + catalog 기준으로 검사 (없는 노드 타입, 모르는 설정 키, 끊긴 연결 등)
+//    실패하면 오류 목록을 붙여 다시 요청 (ai_extract_node 와 같은 수리 방식)
+// 4. 통과하면 기본값 / 위치 / edge id 를 채운 워크플로우 JSON 반환 (저장은 하지 않음 - 초안)
+
+pub const TRIGGER_OUTPUT: &str = "trigger-output";
+pub const TRIGGER_INPUT: &str = "trigger-input";
+const COLUMN_WIDTH: f64 = 320.0;
+const ROW_HEIGHT: f64 = 220.0;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NodeSetting {
+    pub key: String,
+    #[serde(default, rename = "type")]
+    pub kind: Option<String>,
+    #[serde(default)]
+    pub label: Option<String>,
+    #[serde(default)]
+    pub default: Option<Value>,
+}
+
+// 사이드바 자동 발견용 config 와 같은 모양 (outputs 는 선택)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NodeSchema {
+    #[serde(rename = "type")]
+    pub node_type: String,
+    #[serde(default)]
+    pub label: Option<String>,
+    #[serde(default)]
+    pub category: Option<String>,
+    #[serde(default)]
+    pub settings: Vec<NodeSetting>,
+    #[serde(default)]
+    pub outputs: Vec<String>,
+}
+
+// AI 가 돌려줄 간단한 형태의 구조 검사
+fn draft_schema() -> Value {
+    json!({
+        "type": "object",
+        "required": ["nodes", "edges"],
+        "properties": {
+            "nodes": {
+                "type": "array",
+                "minItems": 1,
+                "items": {
+                    "type": "object",
+                    "required": ["id", "type"],
+                    "properties": {
+                        "id": { "type": "string", "minLength": 1 },
+                        "type": { "type": "string", "minLength": 1 },
+                        "data": { "type": "object" }
+                    }
+                }
+            },
+            "edges": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": ["source", "target", "sourceHandle", "targetHandle"],
+                    "properties": {
+                        "source": { "type": "string" },
+                        "target": { "type": "string" },
+                        "sourceHandle": { "type": "string" },
+                        "targetHandle": { "type": "string" }
+                    }
+                }
+            }
+        }
+    })
+}
+
+// 구조 + catalog 검사 (비어 있으면 통과)
+pub fn validate_draft(draft: &Value, catalog: &[NodeSchema]) -> Vec<String> {
+    let mut errors = json_schema::validate(draft, &draft_schema());
+    if !errors.is_empty() {
+        return errors;
+    }
+
+    let schemas: HashMap<&str, &NodeSchema> =
+        catalog.iter().map(|s| (s.node_type.as_str(), s)).collect();
+    let mut node_types: HashMap<&str, &NodeSchema> = HashMap::new();
+    let nodes = draft["nodes"].as_array().map(Vec::as_slice).unwrap_or_default();
+    for (i, node) in nodes.iter().enumerate() {
+        let id = node["id"].as_str().unwrap_or_default();
+        let node_type = node["type"].as_str().unwrap_or_default();
+        let Some(schema) = schemas.get(node_type) else {
+            errors.push(format!("$.nodes[{}].type: unknown node type '{}'", i, node_type));
+            continue;
+        };
+        if node_types.insert(id, schema).is_some() {
+            errors.push(format!("$.nodes[{}].id: duplicate id '{}'", i, id));
+        }
+        if let Some(data) = node["data"].as_object() {
+            for key in data.keys() {
+                if !schema.settings.iter().any(|s| &s.key == key) {
+                    errors.push(format!(
+                        "$.nodes[{}].data.{}: '{}' has no setting '{}'",
+                        i, key, node_type, key
+                    ));
+                }
+            }
+        }
+    }
+
+    let edges = draft["edges"].as_array().map(Vec::as_slice).unwrap_or_default();
+    for (i, edge) in edges.iter().enumerate() {
+        let source = edge["source"].as_str().unwrap_or_default();
+        let target = edge["target"].as_str().unwrap_or_default();
+        let source_handle = edge["sourceHandle"].as_str().unwrap_or_default();
+        let target_handle = edge["targetHandle"].as_str().unwrap_or_default();
+        let (Some(from), Some(to)) = (node_types.get(source), node_types.get(target)) else {
+            errors.push(format!(
+                "$.edges[{}]: connects unknown nodes '{}' -> '{}'",
+                i, source, target
+            ));
+            continue;
+        };
+        if source == target {
+            errors.push(format!("$.edges[{}]: node '{}' is connected to itself", i, source));
+        }
+
+        // 트리거 연결은 trigger-output → trigger-input 끼리만
+        let trigger_out = source_handle == TRIGGER_OUTPUT;
+        let trigger_in = target_handle == TRIGGER_INPUT;
+        if trigger_out != trigger_in {
+            errors.push(format!(
+                "$.edges[{}]: '{}' must connect to '{}'",
+                i, TRIGGER_OUTPUT, TRIGGER_INPUT
+            ));
+            continue;
+        }
+        if trigger_out {
+            continue;
+        }
+        // outputs 를 선언하지 않은 노드는 설정 키를 출력으로 간주 (사이드바 config 에 outputs 가 없음)
+        let known_output = if from.outputs.is_empty() {
+            from.settings.iter().any(|s| s.key == source_handle)
+        } else {
+            from.outputs.iter().any(|o| o == source_handle)
+        };
+        if !known_output {
+            errors.push(format!(
+                "$.edges[{}].sourceHandle: '{}' has no output '{}'",
+                i, from.node_type, source_handle
+            ));
+        }
+        if !to.settings.iter().any(|s| s.key == target_handle) {
+            errors.push(format!(
+                "$.edges[{}].targetHandle: '{}' has no input '{}'",
+                i, to.node_type, target_handle
+            ));
+        }
+    }
+    errors
+}
+
+// 트리거 연결 기준 깊이 → 열, 같은 열 안의 순서 → 행
+fn layout(nodes: &[Value], edges: &[Value]) -> HashMap<String, (f64, f64)> {
+    let ids: Vec<&str> = nodes.iter().filter_map(|n| n["id"].as_str()).collect();
+    let mut depth: HashMap<&str, usize> = ids.iter().map(|id| (*id, 0)).collect();
+    // 순환이 있어도 끝나도록 노드 수만큼만 완화
+    for _ in 0..ids.len() {
+        let mut changed = false;
+        for edge in edges {
+            let (Some(source), Some(target)) = (edge["source"].as_str(), edge["target"].as_str())
+            else {
+                continue;
+            };
+            let next = depth.get(source).copied().unwrap_or(0) + 1;
+            if let Some(current) = depth.get_mut(target) {
+                if *current < next && next < ids.len() {
+                    *current = next;
+                    changed = true;
+                }
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    let mut rows: HashMap<usize, usize> = HashMap::new();
+    ids.iter()
+        .map(|id| {
+            let column = depth[id];
+            let row = rows.entry(column).or_insert(0);
+            let position = (column as f64 * COLUMN_WIDTH, *row as f64 * ROW_HEIGHT);
+            *row += 1;
+            (id.to_string(), position)
+        })
+        .collect()
+}
+
+// 검사를 통과한 초안 → 편집기에서 바로 열 수 있는 워크플로우
+pub fn finalize_draft(draft: &Value, catalog: &[NodeSchema]) -> Value {
+    let empty = Vec::new();
+    let nodes = draft["nodes"].as_array().unwrap_or(&empty);
+    let edges = draft["edges"].as_array().unwrap_or(&empty);
+    let positions = layout(nodes, edges);
+
+    let nodes: Vec<Value> = nodes
+        .iter()
+        .map(|node| {
+            let id = node["id"].as_str().unwrap_or_default();
+            let node_type = node["type"].as_str().unwrap_or_default();
+            // 기본값 위에 AI 가 정한 값
+            let mut data = Map::new();
+            if let Some(schema) = catalog.iter().find(|s| s.node_type == node_type) {
+                for setting in &schema.settings {
+                    if let Some(default) = &setting.default {
+                        data.insert(setting.key.clone(), default.clone());
+                    }
+                }
+            }
+            if let Some(values) = node["data"].as_object() {
+                data.extend(values.clone());
+            }
+            let (x, y) = positions.get(id).copied().unwrap_or_default();
+            json!({
+                "id": id,
+                "type": node_type,
+                "position": { "x": x, "y": y },
+                "data": data
+            })
+        })
+        .collect();
+
+    let mut seen = HashSet::new();
+    let edges: Vec<Value> = edges
+        .iter()
+        .filter_map(|edge| {
+            let key = (
+                edge["source"].as_str()?.to_string(),
+                edge["sourceHandle"].as_str()?.to_string(),
+                edge["target"].as_str()?.to_string(),
+                edge["targetHandle"].as_str()?.to_string(),
+            );
+            if !seen.insert(key.clone()) {
+                return None;
+            }
+            Some(json!({
+                "id": format!("e{}-{}-{}-{}", key.0, key.1, key.2, key.3),
+                "source": key.0,
+                "sourceHandle": key.1,
+                "target": key.2,
+                "targetHandle": key.3
+            }))
+        })
+        .collect();
+
+    json!({ "nodes": nodes, "edges": edges })
+}
+
+fn system_prompt(catalog: &[NodeSchema]) -> String {
+    let catalog_text = serde_json::to_string(catalog).unwrap_or_default();
+    format!(
+        "You design automation workflows for a visual node editor. Respond with ONLY a single \
+         JSON object, no markdown, no explanations:\n\
+         {{\"nodes\": [{{\"id\": \"1\", \"type\": <node type>, \"data\": {{<setting key>: <value>}}}}], \
+         \"edges\": [{{\"source\": <node id>, \"sourceHandle\": <output>, \"target\": <node id>, \
+         \"targetHandle\": <input>}}]}}\n\n\
+         Rules:\n\
+         - Use only node types and setting keys from the catalog below.\n\
+         - Execution order: connect \"{out}\" of a node to \"{inp}\" of the next node.\n\
+         - Data flow: connect an output of one node to a setting key of another node.\n\
+         - Only set data values the user asked for; leave other settings out.\n\
+         - Keep the graph minimal.\n\n\
+         Node catalog:\n{catalog}",
+        out = TRIGGER_OUTPUT,
+        inp = TRIGGER_INPUT,
+        catalog = catalog_text
+    )
+}
+
+#[tauri::command]
+pub async fn build_workflow_from_prompt(
+    request: String,
+    catalog: Vec<NodeSchema>,
+    api_key: String,
+    model: String,
+    max_retries: Option<u32>,
+) -> Result<String, String> {
+    println!("🪄 워크플로우 초안 생성: {}", request);
+
+    if request.trim().is_empty() {
+        return Err("NO_USER_INPUT".to_string());
+    }
+    if api_key.trim().is_empty() {
+        return Err("NO_API_KEY".to_string());
+    }
+    if catalog.is_empty() {
+        return Err("EMPTY_NODE_CATALOG".to_string());
+    }
+
+    let system_prompt = system_prompt(&catalog);
+    let catalog_key = make_cache_key("catalog", &json!(catalog));
+    let client = http_client::client();
+    let max_retries = max_retries.unwrap_or(2);
+    let mut prompt = request.clone();
+    let mut attempts = Vec::new();
+
+    for attempt in 0..=max_retries {
+        let mock_request = json!({
+            "node": "workflow_builder",
+            "attempt": attempt,
+            "catalog": catalog_key,
+            "prompt": make_cache_key("prompt", &json!(prompt))
+        });
+        let response_json = request_claude_completion(
+            &client,
+            &api_key,
+            &model,
+            &system_prompt,
+            &json!(prompt),
+            &mock_request,
+        )
+        .await?;
+        let raw_output = response_json["content"][0]["text"]
+            .as_str()
+            .ok_or("No content in API response")?
+            .to_string();
+
+        let errors = match serde_json::from_str::<Value>(extract_json_text(&raw_output)) {
+            Ok(draft) => {
+                let errors = validate_draft(&draft, &catalog);
+                if errors.is_empty() {
+                    let workflow = finalize_draft(&draft, &catalog);
+                    println!(
+                        "✅ 워크플로우 초안 완성: 노드 {}개 (시도 {}회)",
+                        workflow["nodes"].as_array().map(Vec::len).unwrap_or(0),
+                        attempt + 1
+                    );
+                    return Ok(json!({
+                        "workflow": workflow,
+                        "attempts": attempt + 1,
+                        "repair_history": attempts,
+                        "model_used": model
+                    })
+                    .to_string());
+                }
+                errors
+            }
+            Err(e) => vec![format!("$: output is not valid JSON ({})", e)],
+        };
+
+        println!("⚠️ 워크플로우 초안 검사 실패 (시도 {}): {:?}", attempt + 1, errors);
+        attempts.push(json!({ "attempt": attempt + 1, "errors": errors }));
+        prompt = format!(
+            "Request:\n{}\n\nYour previous output:\n{}\n\nIt failed validation with these errors:\n- {}\n\n\
+             Return a corrected workflow JSON.",
+            request,
+            raw_output,
+            errors.join("\n- ")
+        );
+    }
+
+    Err(format!("WORKFLOW_VALIDATION_FAILED: {}", Value::Array(attempts)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn builder_catalog() -> Vec<NodeSchema> {
+        serde_json::from_value(json!([
+            {
+                "type": "textInputNode",
+                "settings": [{ "key": "text", "type": "text", "default": "" }],
+                "outputs": ["text"]
+            },
+            {
+                "type": "fileCreatorNode",
+                "label": "File Creator",
+                "settings": [
+                    { "key": "filePath", "type": "text", "default": "" },
+                    { "key": "fileName", "type": "text", "default": "" },
+                    { "key": "fileContent", "type": "text", "default": "" }
+                ]
+            }
+        ]))
+        .unwrap()
+    }
+
+    #[test]
+    fn workflow_builder_validates_draft_against_catalog() {
+        let catalog = builder_catalog();
+        let draft = json!({
+            "nodes": [
+                { "id": "1", "type": "textInputNode", "data": { "text": "hello" } },
+                { "id": "2", "type": "fileCreatorNode", "data": { "fileName": "a.txt" } }
+            ],
+            "edges": [
+                { "source": "1", "sourceHandle": "trigger-output", "target": "2", "targetHandle": "trigger-input" },
+                { "source": "1", "sourceHandle": "text", "target": "2", "targetHandle": "fileContent" }
+            ]
+        });
+        assert!(validate_draft(&draft, &catalog).is_empty());
+
+        let bad = json!({
+            "nodes": [
+                { "id": "1", "type": "textInputNode", "data": { "color": "red" } },
+                { "id": "2", "type": "emailNode" }
+            ],
+            "edges": [
+                { "source": "1", "sourceHandle": "trigger-output", "target": "9", "targetHandle": "trigger-input" },
+                { "source": "1", "sourceHandle": "missing", "target": "1", "targetHandle": "text" }
+            ]
+        });
+        let errors = validate_draft(&bad, &catalog);
+        assert_eq!(errors.len(), 5, "{:?}", errors);
+        assert!(errors.iter().any(|e| e.contains("unknown node type 'emailNode'")));
+        assert!(errors.iter().any(|e| e.contains("has no setting 'color'")));
+        assert!(errors.iter().any(|e| e.contains("unknown nodes '1' -> '9'")));
+        assert!(!validate_draft(&json!({ "nodes": [] }), &catalog).is_empty());
+    }
+
+    #[test]
+    fn workflow_builder_finalizes_defaults_layout_and_edges() {
+        let catalog = builder_catalog();
+        let edge = json!({ "source": "1", "sourceHandle": "trigger-output", "target": "2", "targetHandle": "trigger-input" });
+        let draft = json!({
+            "nodes": [
+                { "id": "1", "type": "textInputNode" },
+                { "id": "2", "type": "fileCreatorNode", "data": { "fileName": "a.txt" } }
+            ],
+            "edges": [edge.clone(), edge]
+        });
+        let workflow = finalize_draft(&draft, &catalog);
+        let nodes = workflow["nodes"].as_array().unwrap();
+        assert_eq!(nodes[1]["data"]["fileName"], "a.txt");
+        assert_eq!(nodes[1]["data"]["filePath"], "");
+        assert_eq!(nodes[0]["position"]["x"], 0.0);
+        assert!(nodes[1]["position"]["x"].as_f64().unwrap() > 0.0);
+        // 중복 연결은 하나로
+        assert_eq!(workflow["edges"].as_array().unwrap().len(), 1);
+        assert!(workflow["edges"][0]["id"].as_str().unwrap().starts_with("e1-"));
+    }
+}
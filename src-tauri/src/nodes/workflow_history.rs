@@ -0,0 +1,255 @@
+// src-tauri/src/nodes/workflow_history.rs
+use super::atomic_store;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+
+// 🕘 워크플로우 버전 기록 + 변경 메모
+// 저장할 때마다 내용 스냅샷을 store/workflow_history/<경로 해시>/<버전>.json 에 보관 (내용이 같으면 건너뜀)
+// 버전마다 변경 요약(summary) 한 줄과 자유 메모(annotations)를 붙여 팀원이 "왜 바뀌었는지" 볼 수 있게 함
+// 메모는 같은 폴더의 index.json 에 스냅샷과 함께 보관, 오래된 버전은 MAX_VERSIONS 개를 넘으면 메모와 함께 삭제
+
+const MAX_VERSIONS: usize = 50;
+const MAX_SUMMARY_CHARS: usize = 200;
+const MAX_NOTE_CHARS: usize = 4000;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Annotation {
+    pub created_at: String,
+    #[serde(default)]
+    pub author: Option<String>,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct WorkflowVersion {
+    pub version_id: String,
+    pub saved_at: String,
+    pub sha256: String,
+    pub size: usize,
+    #[serde(default)]
+    pub summary: Option<String>,
+    #[serde(default)]
+    pub annotations: Vec<Annotation>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WorkflowHistory {
+    pub path: String,
+    pub versions: Vec<WorkflowVersion>,
+}
+
+fn history_root() -> PathBuf {
+    atomic_store::store_dir().join("workflow_history")
+}
+
+// 같은 파일은 항상 같은 폴더 (경로 문자열 해시)
+pub fn history_dir(root: &Path, workflow_path: &Path) -> PathBuf {
+    let path = workflow_path.to_string_lossy().replace('\\', "/");
+    let hash = format!("{:x}", Sha256::digest(path.as_bytes()));
+    root.join(&hash[..16])
+}
+
+fn load_history(dir: &Path) -> Option<WorkflowHistory> {
+    atomic_store::read_json_verified(&dir.join("index.json"))
+}
+
+fn save_history(dir: &Path, history: &WorkflowHistory) -> Result<(), String> {
+    atomic_store::write_json_atomic(&dir.join("index.json"), history)
+}
+
+fn validate_version_id(version_id: &str) -> Result<&str, String> {
+    if version_id.is_empty() || !version_id.chars().all(|c| c.is_ascii_digit() || c == '-') {
+        return Err(format!("INVALID_VERSION_ID: {}", version_id));
+    }
+    Ok(version_id)
+}
+
+fn clip(text: &str, max_chars: usize) -> String {
+    text.trim().chars().take(max_chars).collect()
+}
+
+// 저장 직후 호출, 새 버전 ID 반환 (직전 버전과 내용이 같으면 None)
+pub fn record_version(
+    root: &Path,
+    workflow_path: &Path,
+    content: &str,
+) -> Result<Option<String>, String> {
+    let dir = history_dir(root, workflow_path);
+    let mut history = load_history(&dir).unwrap_or_else(|| WorkflowHistory {
+        path: workflow_path.to_string_lossy().to_string(),
+        versions: Vec::new(),
+    });
+    let sha256 = format!("{:x}", Sha256::digest(content.as_bytes()));
+    if history.versions.last().is_some_and(|v| v.sha256 == sha256) {
+        return Ok(None);
+    }
+
+    let now = chrono::Local::now();
+    let mut version_id = now.format("%Y%m%d-%H%M%S-%3f").to_string();
+    // 같은 밀리초에 두 번 저장된 경우
+    if history.versions.iter().any(|v| v.version_id == version_id) {
+        version_id = format!("{}-{}", version_id, history.versions.len());
+    }
+    atomic_store::write_atomic(&dir.join(format!("{}.json", version_id)), content.as_bytes())?;
+    history.versions.push(WorkflowVersion {
+        version_id: version_id.clone(),
+        saved_at: now.to_rfc3339(),
+        sha256,
+        size: content.len(),
+        ..Default::default()
+    });
+
+    while history.versions.len() > MAX_VERSIONS {
+        let oldest = history.versions.remove(0);
+        let _ = atomic_store::remove_with_sidecars(&dir.join(format!("{}.json", oldest.version_id)));
+    }
+    save_history(&dir, &history)?;
+    Ok(Some(version_id))
+}
+
+// version_id 가 없으면 가장 최근 버전에 붙임
+pub fn annotate(
+    root: &Path,
+    workflow_path: &Path,
+    version_id: Option<&str>,
+    summary: Option<&str>,
+    note: Option<&str>,
+    author: Option<&str>,
+) -> Result<WorkflowVersion, String> {
+    let dir = history_dir(root, workflow_path);
+    let mut history = load_history(&dir).ok_or("WORKFLOW_HISTORY_NOT_FOUND")?;
+    let version = match version_id {
+        Some(id) => {
+            let id = validate_version_id(id)?;
+            history.versions.iter_mut().find(|v| v.version_id == id)
+        }
+        None => history.versions.last_mut(),
+    }
+    .ok_or_else(|| format!("VERSION_NOT_FOUND: {}", version_id.unwrap_or("latest")))?;
+
+    if let Some(summary) = summary {
+        let summary = clip(summary, MAX_SUMMARY_CHARS);
+        version.summary = (!summary.is_empty()).then_some(summary);
+    }
+    if let Some(note) = note.map(|n| clip(n, MAX_NOTE_CHARS)).filter(|n| !n.is_empty()) {
+        version.annotations.push(Annotation {
+            created_at: chrono::Local::now().to_rfc3339(),
+            author: author
+                .map(str::trim)
+                .filter(|a| !a.is_empty())
+                .map(str::to_string),
+            text: note,
+        });
+    }
+    let updated = version.clone();
+    save_history(&dir, &history)?;
+    Ok(updated)
+}
+
+pub fn list_versions(root: &Path, workflow_path: &Path) -> Vec<WorkflowVersion> {
+    load_history(&history_dir(root, workflow_path))
+        .map(|history| history.versions)
+        .unwrap_or_default()
+}
+
+pub fn read_version(root: &Path, workflow_path: &Path, version_id: &str) -> Result<String, String> {
+    let version_id = validate_version_id(version_id)?;
+    let path = history_dir(root, workflow_path).join(format!("{}.json", version_id));
+    atomic_store::read_verified(&path)
+        .map(|bytes| String::from_utf8_lossy(&bytes).to_string())
+        .ok_or_else(|| format!("VERSION_NOT_FOUND: {}", version_id))
+}
+
+// 워크플로우 저장 후 호출 (기록 실패는 저장을 막지 않음)
+pub fn on_workflow_saved(workflow_path: &Path, content: &str) {
+    match record_version(&history_root(), workflow_path, content) {
+        Ok(Some(version_id)) => println!("🕘 워크플로우 버전 기록: {}", version_id),
+        Ok(None) => {}
+        Err(e) => println!("⚠️ 워크플로우 버전 기록 실패: {}", e),
+    }
+}
+
+// 최신순
+#[tauri::command]
+pub fn list_workflow_versions(file_path: String) -> Result<Vec<WorkflowVersion>, String> {
+    let mut versions = list_versions(&history_root(), Path::new(&file_path));
+    versions.reverse();
+    Ok(versions)
+}
+
+#[tauri::command]
+pub fn get_workflow_version(file_path: String, version_id: String) -> Result<String, String> {
+    read_version(&history_root(), Path::new(&file_path), &version_id)
+}
+
+#[tauri::command]
+pub fn annotate_workflow_version(
+    file_path: String,
+    version_id: Option<String>,
+    summary: Option<String>,
+    note: Option<String>,
+    author: Option<String>,
+) -> Result<WorkflowVersion, String> {
+    if summary.is_none() && note.is_none() {
+        return Err("EMPTY_ANNOTATION".to_string());
+    }
+    annotate(
+        &history_root(),
+        Path::new(&file_path),
+        version_id.as_deref(),
+        summary.as_deref(),
+        note.as_deref(),
+        author.as_deref(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::temp_dir;
+
+    #[test]
+    fn workflow_history_records_versions_and_annotations() {
+        let dir = temp_dir();
+        let root = dir.path().join("history");
+        let workflow = dir.path().join("backup.flow.json");
+
+        let v1 = record_version(&root, &workflow, "{\"v\":1}")
+            .unwrap()
+            .unwrap();
+        // 내용이 같으면 새 버전을 만들지 않음
+        assert_eq!(
+            record_version(&root, &workflow, "{\"v\":1}").unwrap(),
+            None
+        );
+        let v2 = record_version(&root, &workflow, "{\"v\":2}")
+            .unwrap()
+            .unwrap();
+        assert_ne!(v1, v2);
+
+        let latest = annotate(
+            &root,
+            &workflow,
+            None,
+            Some("타임아웃 30초로 늘림"),
+            Some("야간 백업이 느린 NAS 에서 실패해서"),
+            Some("kim"),
+        )
+        .unwrap();
+        assert_eq!(latest.version_id, v2);
+        assert_eq!(latest.summary.as_deref(), Some("타임아웃 30초로 늘림"));
+        assert_eq!(latest.annotations[0].author.as_deref(), Some("kim"));
+
+        annotate(&root, &workflow, Some(&v1), None, Some("초기 버전"), None)
+            .unwrap();
+        let versions = list_versions(&root, &workflow);
+        assert_eq!(versions.len(), 2);
+        assert_eq!(versions[0].annotations[0].text, "초기 버전");
+        assert_eq!(
+            read_version(&root, &workflow, &v1).unwrap(),
+            "{\"v\":1}"
+        );
+        assert!(read_version(&root, &workflow, "../index").is_err());
+    }
+}
@@ -0,0 +1,237 @@
+// src-tauri/src/nodes/workflow_lock.rs
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+// 🔒 워크플로우 실행 잠금
+// 같은 워크플로우 파일을 GUI / 예약 실행(--run-workflow) / 스케줄이 동시에 실행해
+// 같은 출력 파일을 함께 쓰지 않도록 "<워크플로우>.lock" 파일로 잠금
+// 잠금을 만든 프로세스가 이미 종료됐으면(비정상 종료 등) 오래된 잠금으로 보고 가져옴
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LockInfo {
+    pub lock_id: String,
+    pub pid: u32,
+    pub owner: String,
+    pub started_at: i64,
+}
+
+pub fn lock_path(workflow: &Path) -> PathBuf {
+    let mut name = workflow
+        .file_name()
+        .map(|n| n.to_os_string())
+        .unwrap_or_default();
+    name.push(".lock");
+    workflow.with_file_name(name)
+}
+
+pub fn read_lock(workflow: &Path) -> Option<LockInfo> {
+    let content = std::fs::read_to_string(lock_path(workflow)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn process_alive(pid: u32) -> bool {
+    let mut system = sysinfo::System::new();
+    system.refresh_process(sysinfo::Pid::from_u32(pid))
+}
+
+fn new_lock_id() -> String {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or(0);
+    format!("lock_{}_{}", std::process::id(), nanos)
+}
+
+// 잠금 파일 옆의 작업용 파일 ("<워크플로우>.lock.<id>.tmp" 등)
+fn sibling(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.file_name().map(|n| n.to_os_string()).unwrap_or_default();
+    name.push(suffix);
+    path.with_file_name(name)
+}
+
+// 내용을 다 쓴 임시 파일을 hard_link 로 잠금 경로에 연결
+// → 잠금 파일은 생기는 순간부터 완전한 내용 (빈 파일을 다른 실행이 읽고 오래된 잠금으로 지우지 않음)
+// hard link 를 지원하지 않는 파일 시스템(FAT 등)에서만 create_new 로 직접 씀
+fn create_lock(path: &Path, lock_id: &str, data: &[u8]) -> std::io::Result<()> {
+    let temp = sibling(path, &format!(".{}.tmp", lock_id));
+    let linked = std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&temp)
+        .and_then(|mut file| file.write_all(data).and_then(|_| file.sync_all()))
+        .and_then(|_| std::fs::hard_link(&temp, path));
+    let _ = std::fs::remove_file(&temp);
+    match linked {
+        Err(e) if e.kind() == std::io::ErrorKind::Unsupported => {
+            let mut file = std::fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(path)?;
+            file.write_all(data).and_then(|_| file.sync_all())
+        }
+        other => other,
+    }
+}
+
+// 읽었던 내용 그대로일 때만 삭제 (그 사이 다른 실행이 새로 잡은 잠금은 건드리지 않음)
+// 잠금 파일을 먼저 고유한 이름으로 옮긴 뒤 확인하므로 확인과 삭제 사이에 바뀔 수 없음
+fn remove_if_unchanged(path: &Path, seen: &str) -> Result<bool, String> {
+    let claimed = sibling(path, &format!(".{}.stale", new_lock_id()));
+    match std::fs::rename(path, &claimed) {
+        Ok(()) => {}
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+        Err(e) => return Err(format!("잠금 정리 실패: {}", e)),
+    }
+    if std::fs::read_to_string(&claimed).ok().as_deref() == Some(seen) {
+        std::fs::remove_file(&claimed).map_err(|e| format!("잠금 정리 실패: {}", e))?;
+        return Ok(true);
+    }
+    // 다른 실행의 새 잠금이었으면 되돌림 (그 사이 또 새 잠금이 생겼으면 그쪽을 유지)
+    if std::fs::hard_link(&claimed, path).is_err() && !path.exists() {
+        let _ = std::fs::rename(&claimed, path);
+    }
+    let _ = std::fs::remove_file(&claimed);
+    Ok(false)
+}
+
+// 잠금 파일을 원자적으로 생성, 이미 있으면 살아있는 잠금인지 확인
+pub fn try_acquire(
+    workflow: &Path,
+    owner: &str,
+    is_alive: impl Fn(u32) -> bool,
+) -> Result<LockInfo, String> {
+    let path = lock_path(workflow);
+    let info = LockInfo {
+        lock_id: new_lock_id(),
+        pid: std::process::id(),
+        owner: owner.to_string(),
+        started_at: chrono::Utc::now().timestamp(),
+    };
+    let data = serde_json::to_vec(&info).map_err(|e| e.to_string())?;
+
+    // 오래된 잠금을 지운 뒤 몇 번만 다시 시도
+    for _ in 0..3 {
+        match create_lock(&path, &info.lock_id, &data) {
+            Ok(()) => return Ok(info),
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                let Ok(content) = std::fs::read_to_string(&path) else {
+                    continue; // 그 사이 해제됨
+                };
+                match serde_json::from_str::<LockInfo>(&content) {
+                    Ok(existing) if is_alive(existing.pid) => {
+                        return Err(format!(
+                            "WORKFLOW_LOCKED: {}",
+                            json!({
+                                "owner": existing.owner,
+                                "pid": existing.pid,
+                                "started_at": existing.started_at
+                            })
+                        ));
+                    }
+                    stale => {
+                        println!("🔓 오래된 잠금 정리: {:?}", stale.ok().map(|s| s.owner));
+                        remove_if_unchanged(&path, &content)?;
+                    }
+                }
+            }
+            Err(e) => return Err(format!("잠금 파일 생성 실패: {}", e)),
+        }
+    }
+    Err("WORKFLOW_LOCKED: 다른 실행이 동시에 잠금을 가져갔습니다".to_string())
+}
+
+// 자신의 lock_id 와 같을 때만 해제
+pub fn release(workflow: &Path, lock_id: &str) -> Result<bool, String> {
+    let path = lock_path(workflow);
+    let Ok(content) = std::fs::read_to_string(&path) else {
+        return Ok(false);
+    };
+    match serde_json::from_str::<LockInfo>(&content) {
+        Ok(existing) if existing.lock_id == lock_id => remove_if_unchanged(&path, &content)
+            .map_err(|e| format!("잠금 해제 실패: {}", e)),
+        _ => Ok(false),
+    }
+}
+
+#[tauri::command]
+pub fn acquire_workflow_lock(
+    workflow_path: String,
+    owner: Option<String>,
+    allow_concurrent: Option<bool>,
+) -> Result<String, String> {
+    let workflow = PathBuf::from(workflow_path.trim());
+    if !workflow.is_file() {
+        return Err(format!("WORKFLOW_NOT_FOUND: {}", workflow_path));
+    }
+    // 동시 실행을 명시적으로 허용하면 잠그지 않음
+    if allow_concurrent.unwrap_or(false) {
+        return Ok(json!({ "lock_id": null }).to_string());
+    }
+
+    let owner = owner.unwrap_or_else(|| "gui".to_string());
+    let info = try_acquire(&workflow, &owner, process_alive)?;
+    println!("🔒 워크플로우 잠금: {} ({})", workflow.display(), owner);
+    Ok(json!({
+        "lock_id": info.lock_id,
+        "lock_path": lock_path(&workflow).to_string_lossy()
+    })
+    .to_string())
+}
+
+#[tauri::command]
+pub fn release_workflow_lock(workflow_path: String, lock_id: String) -> Result<bool, String> {
+    let released = release(Path::new(workflow_path.trim()), &lock_id)?;
+    if released {
+        println!("🔓 워크플로우 잠금 해제: {}", workflow_path.trim());
+    }
+    Ok(released)
+}
+
+#[tauri::command]
+pub fn get_workflow_lock(workflow_path: String) -> Result<Option<LockInfo>, String> {
+    Ok(read_lock(Path::new(workflow_path.trim())).filter(|lock| process_alive(lock.pid)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::temp_dir;
+    use std::fs;
+
+    #[test]
+    fn workflow_lock_blocks_concurrent_runs_and_recovers_stale_locks() {
+        let dir = temp_dir();
+        let workflow = dir.path().join("daily.json");
+        fs::write(&workflow, "{}").unwrap();
+
+        let first = try_acquire(&workflow, "gui", |_| true).unwrap();
+        assert!(lock_path(&workflow).ends_with("daily.json.lock"));
+
+        let blocked = try_acquire(&workflow, "schedule", |_| true).unwrap_err();
+        assert!(blocked.starts_with("WORKFLOW_LOCKED"));
+        assert!(blocked.contains("\"owner\":\"gui\""));
+
+        // 잠금을 만든 프로세스가 죽었으면 가져옴
+        let second = try_acquire(&workflow, "schedule", |_| false).unwrap();
+        assert_eq!(
+            read_lock(&workflow).unwrap().owner,
+            "schedule"
+        );
+
+        // 다른 실행의 lock_id 로는 해제되지 않음
+        assert!(!release(&workflow, &first.lock_id).unwrap());
+        assert!(release(&workflow, &second.lock_id).unwrap());
+        assert!(!lock_path(&workflow).exists());
+
+        // 작업용 임시 파일(.tmp / .stale)이 남지 않음
+        let leftovers: Vec<_> = fs::read_dir(dir.path())
+            .unwrap()
+            .flatten()
+            .map(|entry| entry.file_name().to_string_lossy().to_string())
+            .filter(|name| name != "daily.json")
+            .collect();
+        assert!(leftovers.is_empty(), "{:?}", leftovers);
+    }
+}
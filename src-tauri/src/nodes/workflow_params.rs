@@ -0,0 +1,314 @@
+// src-tauri/src/nodes/workflow_params.rs
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::path::Path;
+
+// 워크플로우 입력 파라미터 정의 (workflow JSON의 "parameters" 배열에 저장)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkflowParameter {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub param_type: String, // "string" | "number" | "file_path" | "enum"
+    #[serde(default)]
+    pub required: bool,
+    #[serde(default)]
+    pub default: Option<Value>,
+    #[serde(default)]
+    pub options: Vec<String>, // enum 타입일 때 허용값
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+fn parse_workflow(workflow_data: &str) -> Result<Value, String> {
+    serde_json::from_str::<Value>(workflow_data)
+        .map_err(|_| "잘못된 워크플로우 파일 형식입니다".to_string())
+}
+
+fn read_parameters(workflow: &Value) -> Result<Vec<WorkflowParameter>, String> {
+    match workflow.get("parameters") {
+        None | Some(Value::Null) => Ok(Vec::new()),
+        Some(params) => serde_json::from_value::<Vec<WorkflowParameter>>(params.clone())
+            .map_err(|e| format!("INVALID_PARAMETER_DEFINITION: {}", e)),
+    }
+}
+
+// 파라미터 정의 자체 검증 (이름 중복, 알 수 없는 타입 등)
+fn validate_definitions(params: &[WorkflowParameter]) -> Result<(), String> {
+    let mut seen = std::collections::HashSet::new();
+
+    for param in params {
+        let name = param.name.trim();
+        if name.is_empty() {
+            return Err("EMPTY_PARAMETER_NAME".to_string());
+        }
+        if !name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+        {
+            return Err(format!("INVALID_PARAMETER_NAME: {}", name));
+        }
+        if !seen.insert(name.to_string()) {
+            return Err(format!("DUPLICATE_PARAMETER: {}", name));
+        }
+        match param.param_type.as_str() {
+            "string" | "number" | "file_path" => {}
+            "enum" => {
+                if param.options.is_empty() {
+                    return Err(format!("ENUM_WITHOUT_OPTIONS: {}", name));
+                }
+            }
+            other => return Err(format!("UNKNOWN_PARAMETER_TYPE: {} ({})", name, other)),
+        }
+    }
+
+    Ok(())
+}
+
+// 입력값 하나를 파라미터 타입에 맞게 검증/변환
+fn coerce_value(param: &WorkflowParameter, value: &Value) -> Result<Value, String> {
+    let name = param.name.trim();
+
+    match param.param_type.as_str() {
+        "string" => match value {
+            Value::String(s) => Ok(Value::String(s.clone())),
+            Value::Number(n) => Ok(Value::String(n.to_string())),
+            Value::Bool(b) => Ok(Value::String(b.to_string())),
+            _ => Err(format!("INVALID_INPUT_TYPE: {} (string 필요)", name)),
+        },
+        "number" => match value {
+            Value::Number(n) => Ok(Value::Number(n.clone())),
+            Value::String(s) => s
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .and_then(serde_json::Number::from_f64)
+                .map(Value::Number)
+                .ok_or_else(|| format!("INVALID_INPUT_TYPE: {} (number 필요)", name)),
+            _ => Err(format!("INVALID_INPUT_TYPE: {} (number 필요)", name)),
+        },
+        "file_path" => {
+            let path_str = value
+                .as_str()
+                .ok_or_else(|| format!("INVALID_INPUT_TYPE: {} (file path 필요)", name))?
+                .trim();
+            if path_str.is_empty() {
+                return Err(format!("EMPTY_FILE_PATH: {}", name));
+            }
+            if !Path::new(path_str).exists() {
+                return Err(format!("FILE_NOT_FOUND: {} ({})", name, path_str));
+            }
+            Ok(Value::String(path_str.to_string()))
+        }
+        "enum" => {
+            let choice = value
+                .as_str()
+                .ok_or_else(|| format!("INVALID_INPUT_TYPE: {} (enum 값 필요)", name))?;
+            if param.options.iter().any(|option| option == choice) {
+                Ok(Value::String(choice.to_string()))
+            } else {
+                Err(format!(
+                    "INVALID_ENUM_VALUE: {} ('{}' 허용값: {})",
+                    name,
+                    choice,
+                    param.options.join(", ")
+                ))
+            }
+        }
+        other => Err(format!("UNKNOWN_PARAMETER_TYPE: {} ({})", name, other)),
+    }
+}
+
+// 정의 + 입력값 → 최종 실행 입력값 (기본값 적용 포함)
+// 이름은 validate_definitions 와 같이 앞뒤 공백을 뺀 값으로 비교 / 저장 (${input:NAME} 과 맞도록)
+pub fn resolve_inputs(
+    params: &[WorkflowParameter],
+    inputs: &Map<String, Value>,
+) -> Result<Map<String, Value>, String> {
+    validate_definitions(params)?;
+
+    let mut resolved = Map::new();
+    let mut errors = Vec::new();
+
+    for param in params {
+        let name = param.name.trim();
+        let provided = inputs
+            .iter()
+            .find(|(key, _)| key.trim() == name)
+            .map(|(_, v)| v)
+            .filter(|v| !v.is_null());
+
+        let value = match (provided, &param.default) {
+            (Some(v), _) => v,
+            (None, Some(default)) if !default.is_null() => default,
+            (None, _) => {
+                if param.required {
+                    errors.push(format!("MISSING_REQUIRED_INPUT: {}", name));
+                }
+                continue;
+            }
+        };
+
+        match coerce_value(param, value) {
+            Ok(v) => {
+                resolved.insert(name.to_string(), v);
+            }
+            Err(e) => errors.push(e),
+        }
+    }
+
+    // 정의되지 않은 입력은 오타일 가능성이 높으므로 거부
+    for key in inputs.keys() {
+        if !params.iter().any(|p| p.name.trim() == key.trim()) {
+            errors.push(format!("UNKNOWN_INPUT: {}", key));
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(resolved)
+    } else {
+        Err(errors.join("\n"))
+    }
+}
+
+fn value_to_placeholder_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+// 노드 data 안의 ${input:NAME} 플레이스홀더를 실제 값으로 치환
+pub fn inject_inputs(value: &mut Value, inputs: &Map<String, Value>) {
+    match value {
+        Value::String(s) => {
+            if !s.contains("${input:") {
+                return;
+            }
+            // 문자열 전체가 플레이스홀더 하나면 원래 타입 그대로 주입
+            let exact = inputs
+                .iter()
+                .find(|(name, _)| *s == format!("${{input:{}}}", name))
+                .map(|(_, input)| input.clone());
+            if let Some(input) = exact {
+                *value = input;
+                return;
+            }
+            for (name, input) in inputs {
+                let placeholder = format!("${{input:{}}}", name);
+                if s.contains(&placeholder) {
+                    *s = s.replace(&placeholder, &value_to_placeholder_text(input));
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                inject_inputs(item, inputs);
+            }
+        }
+        Value::Object(map) => {
+            for (_, item) in map.iter_mut() {
+                inject_inputs(item, inputs);
+            }
+        }
+        _ => {}
+    }
+}
+
+// 워크플로우에 선언된 입력 파라미터 목록 조회 (CLI/핫키/웹훅 실행 전 폼 구성용)
+#[tauri::command]
+pub fn get_workflow_parameters(workflow_data: String) -> Result<Value, String> {
+    let workflow = parse_workflow(&workflow_data)?;
+    let params = read_parameters(&workflow)?;
+    validate_definitions(&params)?;
+
+    Ok(json!(params))
+}
+
+// 실행 시작 시 입력값 검증 + 노드 데이터에 주입한 워크플로우 반환
+#[tauri::command]
+pub fn prepare_workflow_run(
+    workflow_data: String,
+    inputs: Option<Value>,
+) -> Result<String, String> {
+    println!("🧩 워크플로우 실행 입력값 준비 중");
+
+    let mut workflow = parse_workflow(&workflow_data)?;
+    let params = read_parameters(&workflow)?;
+
+    let inputs = match inputs {
+        None | Some(Value::Null) => Map::new(),
+        Some(Value::Object(map)) => map,
+        Some(_) => return Err("INVALID_INPUTS: JSON 객체가 필요합니다".to_string()),
+    };
+
+    let resolved = resolve_inputs(&params, &inputs)?;
+
+    if let Some(nodes) = workflow.get_mut("nodes").and_then(|n| n.as_array_mut()) {
+        for node in nodes.iter_mut() {
+            if let Some(data) = node.get_mut("data") {
+                inject_inputs(data, &resolved);
+            }
+        }
+    }
+
+    println!("✅ 입력값 {}개 주입 완료", resolved.len());
+
+    workflow["run_inputs"] = Value::Object(resolved);
+    serde_json::to_string(&workflow).map_err(|e| format!("Failed to serialize workflow: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::{assert_golden, parse_output};
+    use serde_json::json;
+
+    #[test]
+    fn prepare_workflow_run_injects_inputs() {
+        let workflow = json!({
+            "parameters": [
+                { "name": "target", "type": "string", "required": true },
+                { "name": "count", "type": "number", "default": 3 },
+                { "name": "mode", "type": "enum", "options": ["fast", "slow"], "default": "fast" }
+            ],
+            "nodes": [
+                { "id": "1", "data": { "command": "echo ${input:target}", "repeat": "${input:count}" } }
+            ],
+            "edges": []
+        });
+
+        let output =
+            prepare_workflow_run(workflow.to_string(), Some(json!({ "target": "world" }))).unwrap();
+
+        assert_golden("workflow_params_injected", &parse_output(&output));
+    }
+
+    #[test]
+    fn prepare_workflow_run_rejects_missing_and_unknown_inputs() {
+        let workflow = json!({
+            "parameters": [{ "name": "target", "type": "string", "required": true }],
+            "nodes": []
+        });
+
+        let error =
+            prepare_workflow_run(workflow.to_string(), Some(json!({ "tagret": "x" }))).unwrap_err();
+
+        assert!(error.contains("MISSING_REQUIRED_INPUT: target"));
+        assert!(error.contains("UNKNOWN_INPUT: tagret"));
+    }
+
+    #[test]
+    fn prepare_workflow_run_trims_parameter_names() {
+        let workflow = json!({
+            "parameters": [{ "name": " target ", "type": "string", "required": true }],
+            "nodes": [{ "id": "1", "data": { "command": "echo ${input:target}" } }]
+        });
+
+        let output =
+            prepare_workflow_run(workflow.to_string(), Some(json!({ "target": "world" }))).unwrap();
+        let prepared = parse_output(&output);
+
+        assert_eq!(prepared["nodes"][0]["data"]["command"], "echo world");
+        assert_eq!(prepared["run_inputs"]["target"], "world");
+    }
+}
@@ -0,0 +1,453 @@
+// src-tauri/src/nodes/workflow_signing.rs
+use super::{atomic_store, secrets};
+use base64::{engine::general_purpose, Engine as _};
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::path::{Path, PathBuf};
+
+// ✍️ 워크플로우 서명 / 검증 (ed25519)
+// 팀에서 신뢰할 수 있는 워크플로우 파일을 배포하고, 실행 전에 서명 없음 / 변조를 경고하기 위해
+// - 개인 키: 키체인의 내부 항목 "workflow-signing-key" (비밀값 목록 / ${secret:} 치환과 분리), 처음 서명할 때 생성
+// - 신뢰하는 공개 키 목록: store/trusted_signers.json (내 키는 항상 신뢰)
+// - 서명은 워크플로우 파일 최상위 "signature" 에 저장, 서명 대상은 signature 를 뺀 나머지를 키 정렬한 JSON
+// 파일에 저장된 그대로(${workspace} 경로 포함)를 서명하므로 다른 PC 에서 열어도 검증됨
+// 서명을 안에 넣을 수 없는 파일(마켓플레이스 플러그인 등)은 내용 바이트 전체에 대한 분리 서명 사용
+
+const SIGNING_KEY_NAME: &str = "workflow-signing-key";
+const ALGORITHM: &str = "ed25519";
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WorkflowSignature {
+    pub algorithm: String,
+    pub public_key: String,
+    pub signer: String,
+    pub signed_at: String,
+    pub value: String,
+}
+
+// 분리 서명 (서명 대상은 파일 내용 바이트 그대로)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DetachedSignature {
+    #[serde(default = "default_algorithm")]
+    pub algorithm: String,
+    pub public_key: String,
+    #[serde(default)]
+    pub signer: String,
+    pub value: String,
+}
+
+fn default_algorithm() -> String {
+    ALGORITHM.to_string()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrustedSigner {
+    pub name: String,
+    pub public_key: String,
+    pub added_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct Verification {
+    pub status: String, // "valid" | "untrusted" | "unsigned" | "tampered"
+    pub signer: Option<String>,
+    pub public_key: Option<String>,
+    pub signed_at: Option<String>,
+    pub trusted: bool,
+}
+
+// 키를 정렬해 직렬화 (serde_json 설정과 관계없이 같은 바이트가 나오도록)
+pub fn canonical_json(value: &Value) -> String {
+    match value {
+        Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+            let fields: Vec<String> = keys
+                .into_iter()
+                .map(|key| {
+                    format!(
+                        "{}:{}",
+                        Value::String(key.clone()),
+                        canonical_json(&map[key])
+                    )
+                })
+                .collect();
+            format!("{{{}}}", fields.join(","))
+        }
+        Value::Array(items) => format!(
+            "[{}]",
+            items
+                .iter()
+                .map(canonical_json)
+                .collect::<Vec<_>>()
+                .join(",")
+        ),
+        other => other.to_string(),
+    }
+}
+
+fn signing_payload(workflow: &Value) -> Vec<u8> {
+    let mut unsigned = workflow.clone();
+    if let Some(map) = unsigned.as_object_mut() {
+        map.remove("signature");
+    }
+    canonical_json(&unsigned).into_bytes()
+}
+
+pub fn encode_public_key(key: &VerifyingKey) -> String {
+    general_purpose::STANDARD.encode(key.to_bytes())
+}
+
+pub fn decode_public_key(text: &str) -> Result<VerifyingKey, String> {
+    let bytes: [u8; 32] = general_purpose::STANDARD
+        .decode(text.trim())
+        .ok()
+        .and_then(|b| b.try_into().ok())
+        .ok_or_else(|| "INVALID_PUBLIC_KEY".to_string())?;
+    VerifyingKey::from_bytes(&bytes).map_err(|_| "INVALID_PUBLIC_KEY".to_string())
+}
+
+pub fn sign(
+    workflow: &mut Value,
+    key: &SigningKey,
+    signer: &str,
+) -> Result<WorkflowSignature, String> {
+    if !workflow.is_object() {
+        return Err("잘못된 워크플로우 파일 형식입니다".to_string());
+    }
+    let signature = WorkflowSignature {
+        algorithm: ALGORITHM.to_string(),
+        public_key: encode_public_key(&key.verifying_key()),
+        signer: signer.to_string(),
+        signed_at: chrono::Local::now().to_rfc3339(),
+        value: general_purpose::STANDARD.encode(key.sign(&signing_payload(workflow)).to_bytes()),
+    };
+    workflow["signature"] = serde_json::to_value(&signature).map_err(|e| e.to_string())?;
+    Ok(signature)
+}
+
+fn signature_matches(algorithm: &str, public_key: &str, value: &str, payload: &[u8]) -> bool {
+    algorithm == ALGORITHM
+        && decode_public_key(public_key)
+            .ok()
+            .zip(
+                general_purpose::STANDARD
+                    .decode(value)
+                    .ok()
+                    .and_then(|b| <[u8; 64]>::try_from(b).ok())
+                    .map(|b| Signature::from_bytes(&b)),
+            )
+            .is_some_and(|(key, sig)| key.verify(payload, &sig).is_ok())
+}
+
+// trusted: 신뢰하는 공개 키 목록
+pub fn verify(workflow: &Value, trusted: &[String]) -> Verification {
+    let mut result = Verification {
+        status: "unsigned".to_string(),
+        signer: None,
+        public_key: None,
+        signed_at: None,
+        trusted: false,
+    };
+    let Some(raw) = workflow.get("signature").filter(|s| !s.is_null()) else {
+        return result;
+    };
+    let Ok(signature) = serde_json::from_value::<WorkflowSignature>(raw.clone()) else {
+        result.status = "tampered".to_string();
+        return result;
+    };
+    result.signer = Some(signature.signer.clone());
+    result.public_key = Some(signature.public_key.clone());
+    result.signed_at = Some(signature.signed_at.clone());
+
+    let valid = signature_matches(
+        &signature.algorithm,
+        &signature.public_key,
+        &signature.value,
+        &signing_payload(workflow),
+    );
+    if !valid {
+        result.status = "tampered".to_string();
+        return result;
+    }
+    result.trusted = trusted.iter().any(|k| k.trim() == signature.public_key);
+    result.status = if result.trusted { "valid" } else { "untrusted" }.to_string();
+    result
+}
+
+// 분리 서명 검증 (상태 값은 verify 와 같음)
+pub fn verify_detached(
+    content: &[u8],
+    signature: Option<&DetachedSignature>,
+    trusted: &[String],
+) -> Verification {
+    let mut result = Verification {
+        status: "unsigned".to_string(),
+        signer: None,
+        public_key: None,
+        signed_at: None,
+        trusted: false,
+    };
+    let Some(signature) = signature else {
+        return result;
+    };
+    result.signer = Some(signature.signer.clone()).filter(|s| !s.is_empty());
+    result.public_key = Some(signature.public_key.clone());
+    if !signature_matches(
+        &signature.algorithm,
+        &signature.public_key,
+        &signature.value,
+        content,
+    ) {
+        result.status = "tampered".to_string();
+        return result;
+    }
+    result.trusted = trusted.iter().any(|k| k.trim() == signature.public_key);
+    result.status = if result.trusted { "valid" } else { "untrusted" }.to_string();
+    result
+}
+
+fn trusted_path() -> PathBuf {
+    atomic_store::store_dir().join("trusted_signers.json")
+}
+
+fn load_trusted() -> Vec<TrustedSigner> {
+    atomic_store::read_json_verified(&trusted_path()).unwrap_or_default()
+}
+
+fn decode_signing_key(encoded: &str) -> Result<SigningKey, String> {
+    let bytes: [u8; 32] = general_purpose::STANDARD
+        .decode(encoded.trim())
+        .ok()
+        .and_then(|b| b.try_into().ok())
+        .ok_or_else(|| "INVALID_SIGNING_KEY".to_string())?;
+    Ok(SigningKey::from_bytes(&bytes))
+}
+
+// 키체인의 개인 키 (일반 비밀값과 분리된 내부 항목)
+// 예전 버전은 일반 비밀값으로 저장했으므로 발견하면 내부 항목으로 옮기고 비밀값 목록에서 제거
+fn stored_signing_key() -> Result<Option<String>, String> {
+    match secrets::get_internal(SIGNING_KEY_NAME) {
+        Ok(encoded) => return Ok(Some(encoded)),
+        Err(e) if !e.starts_with("SECRET_NOT_FOUND") => return Err(e),
+        Err(_) => {}
+    }
+    match secrets::get(SIGNING_KEY_NAME) {
+        Ok(encoded) => {
+            secrets::set_internal(SIGNING_KEY_NAME, &encoded)?;
+            if let Err(e) = secrets::delete(SIGNING_KEY_NAME) {
+                println!("⚠️ 예전 서명 키 정리 실패: {}", e);
+            }
+            println!("🔑 워크플로우 서명 키를 내부 키체인 항목으로 이동");
+            Ok(Some(encoded))
+        }
+        Err(e) if e.starts_with("SECRET_NOT_FOUND") => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+// 없으면 새로 만들어 저장
+fn signing_key() -> Result<SigningKey, String> {
+    if let Some(encoded) = stored_signing_key()? {
+        return decode_signing_key(&encoded);
+    }
+    let key = SigningKey::generate(&mut rand::rngs::OsRng);
+    secrets::set_internal(
+        SIGNING_KEY_NAME,
+        &general_purpose::STANDARD.encode(key.to_bytes()),
+    )?;
+    println!("🔑 워크플로우 서명 키 생성");
+    Ok(key)
+}
+
+// 검증만 할 때는 키를 새로 만들지 않음
+fn own_public_key() -> Option<String> {
+    let key = decode_signing_key(&stored_signing_key().ok()??).ok()?;
+    Some(encode_public_key(&key.verifying_key()))
+}
+
+pub(crate) fn trusted_keys() -> Vec<String> {
+    let mut keys: Vec<String> = load_trusted().into_iter().map(|s| s.public_key).collect();
+    keys.extend(own_public_key());
+    keys
+}
+
+fn read_workflow(path: &str) -> Result<Value, String> {
+    let content =
+        std::fs::read_to_string(path).map_err(|_| format!("파일을 찾을 수 없습니다: {}", path))?;
+    serde_json::from_str(&content).map_err(|_| "잘못된 워크플로우 파일 형식입니다".to_string())
+}
+
+// 불러오기 전에 호출: 파일 원문 그대로 검증 (경고만, 로드를 막지는 않음)
+pub fn check_file_content(content: &str) -> Option<Verification> {
+    let workflow: Value = serde_json::from_str(content).ok()?;
+    let verification = verify(&workflow, &trusted_keys());
+    match verification.status.as_str() {
+        "tampered" => println!("🚨 워크플로우 서명이 맞지 않습니다 (변조 가능성)"),
+        "untrusted" => println!(
+            "⚠️ 신뢰하지 않는 서명자의 워크플로우: {}",
+            verification.signer.as_deref().unwrap_or("-")
+        ),
+        _ => {}
+    }
+    Some(verification)
+}
+
+#[tauri::command]
+pub fn sign_workflow(workflow_path: String, signer: Option<String>) -> Result<String, String> {
+    let mut workflow = read_workflow(&workflow_path)?;
+    let key = signing_key()?;
+    let signer = signer
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .or_else(|| {
+            std::env::var("USERNAME")
+                .or_else(|_| std::env::var("USER"))
+                .ok()
+        })
+        .unwrap_or_else(|| "unknown".to_string());
+    let signature = sign(&mut workflow, &key, &signer)?;
+    let content = serde_json::to_string_pretty(&workflow).map_err(|e| e.to_string())?;
+    atomic_store::write_atomic(Path::new(&workflow_path), content.as_bytes())
+        .map_err(|e| format!("Save failed: {}", e))?;
+    println!("✍️ 워크플로우 서명: {} ({})", workflow_path, signer);
+    Ok(json!({ "signature": signature }).to_string())
+}
+
+#[tauri::command]
+pub fn verify_workflow(workflow_path: String) -> Result<Verification, String> {
+    let workflow = read_workflow(&workflow_path)?;
+    Ok(verify(&workflow, &trusted_keys()))
+}
+
+// 다른 팀원에게 전달할 내 공개 키
+#[tauri::command]
+pub fn get_signing_public_key() -> Result<String, String> {
+    Ok(encode_public_key(&signing_key()?.verifying_key()))
+}
+
+#[tauri::command]
+pub fn trust_signer(name: String, public_key: String) -> Result<String, String> {
+    let public_key = encode_public_key(&decode_public_key(&public_key)?);
+    let mut trusted = load_trusted();
+    trusted.retain(|s| s.public_key != public_key);
+    trusted.push(TrustedSigner {
+        name: name.trim().to_string(),
+        public_key,
+        added_at: chrono::Local::now().to_rfc3339(),
+    });
+    atomic_store::write_json_atomic(&trusted_path(), &trusted)?;
+    println!("🤝 서명자 신뢰 추가: {}", name.trim());
+    Ok("SUCCESS".to_string())
+}
+
+#[tauri::command]
+pub fn untrust_signer(public_key: String) -> Result<String, String> {
+    let mut trusted = load_trusted();
+    let before = trusted.len();
+    trusted.retain(|s| s.public_key != public_key.trim());
+    atomic_store::write_json_atomic(&trusted_path(), &trusted)?;
+    Ok(json!({ "removed": before != trusted.len() }).to_string())
+}
+
+#[tauri::command]
+pub fn list_trusted_signers() -> Result<Vec<TrustedSigner>, String> {
+    Ok(load_trusted())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn test_signing_key(seed: u8) -> ed25519_dalek::SigningKey {
+        ed25519_dalek::SigningKey::from_bytes(&[seed; 32])
+    }
+
+    // 배포자 쪽 분리 서명 (앱은 검증만 함)
+    fn sign_detached(content: &[u8], key: &SigningKey, signer: &str) -> DetachedSignature {
+        DetachedSignature {
+            algorithm: ALGORITHM.to_string(),
+            public_key: encode_public_key(&key.verifying_key()),
+            signer: signer.to_string(),
+            value: general_purpose::STANDARD.encode(key.sign(content).to_bytes()),
+        }
+    }
+
+    #[test]
+    fn workflow_signing_canonical_json_sorts_keys() {
+        assert_eq!(
+            canonical_json(&json!({ "b": [1, { "d": 1, "c": "x" }], "a": null })),
+            r#"{"a":null,"b":[1,{"c":"x","d":1}]}"#
+        );
+    }
+
+    #[test]
+    fn workflow_signing_verifies_trusted_and_detects_tampering() {
+        let key = test_signing_key(7);
+        let public_key = encode_public_key(&key.verifying_key());
+        let mut workflow =
+            json!({ "nodes": [{ "id": "n1", "data": { "path": "${workspace}/a.txt" } }] });
+
+        assert_eq!(verify(&workflow, &[]).status, "unsigned");
+
+        sign(&mut workflow, &key, "팀장").unwrap();
+        let trusted = verify(&workflow, std::slice::from_ref(&public_key));
+        assert_eq!(trusted.status, "valid");
+        assert_eq!(trusted.signer.as_deref(), Some("팀장"));
+        assert_eq!(verify(&workflow, &[]).status, "untrusted");
+
+        // 키 순서가 바뀌어도 같은 워크플로우
+        let reordered: serde_json::Value =
+            serde_json::from_str(&serde_json::to_string_pretty(&workflow).unwrap()).unwrap();
+        assert_eq!(
+            verify(&reordered, std::slice::from_ref(&public_key)).status,
+            "valid"
+        );
+
+        workflow["nodes"][0]["data"]["path"] = json!("/etc/passwd");
+        assert_eq!(
+            verify(&workflow, &[public_key]).status,
+            "tampered"
+        );
+    }
+
+    #[test]
+    fn workflow_signing_rejects_swapped_public_key() {
+        let mut workflow = json!({ "nodes": [] });
+        sign(&mut workflow, &test_signing_key(1), "a").unwrap();
+        let other = encode_public_key(&test_signing_key(2).verifying_key());
+        workflow["signature"]["public_key"] = json!(other.clone());
+        assert_eq!(
+            verify(&workflow, &[other]).status,
+            "tampered"
+        );
+        assert!(decode_public_key("not-base64").is_err());
+    }
+
+    #[test]
+    fn workflow_signing_verifies_detached_signatures() {
+        let key = test_signing_key(3);
+        let public_key = encode_public_key(&key.verifying_key());
+        let content = b"plugin binary";
+        let signature = sign_detached(content, &key, "배포자");
+
+        let trusted = std::slice::from_ref(&public_key);
+        let verified = verify_detached(content, Some(&signature), trusted);
+        assert_eq!(verified.status, "valid");
+        assert_eq!(verified.signer.as_deref(), Some("배포자"));
+        assert_eq!(
+            verify_detached(content, Some(&signature), &[]).status,
+            "untrusted"
+        );
+        assert_eq!(
+            verify_detached(b"plugin binarY", Some(&signature), trusted).status,
+            "tampered"
+        );
+        assert_eq!(
+            verify_detached(content, None, trusted).status,
+            "unsigned"
+        );
+    }
+}
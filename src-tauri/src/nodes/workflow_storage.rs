@@ -1,9 +1,14 @@
+use super::{git_sync, secret_scan, workflow_history, workflow_signing, workspace};
 use std::fs;
+use tauri::Emitter;
 use tauri_plugin_dialog::DialogExt;
 
 // 🆕 특정 파일 경로로 워크플로우 로드하는 새 함수
 #[tauri::command]
-pub fn load_specific_workflow(file_path: String) -> Result<String, String> {
+pub fn load_specific_workflow(
+    app_handle: tauri::AppHandle,
+    file_path: String,
+) -> Result<String, String> {
     println!("🔄 특정 파일에서 워크플로우 로드 시도: {}", file_path);
     
     // 파일 존재 여부 확인
@@ -22,7 +27,14 @@ pub fn load_specific_workflow(file_path: String) -> Result<String, String> {
             match serde_json::from_str::<serde_json::Value>(&content) {
                 Ok(_) => {
                     println!("✅ 워크플로우 파일 로드 성공: {}", file_path);
-                    Ok(content)
+                    // ✍️ 서명 확인 (서명 없음/변조/신뢰하지 않는 서명자는 화면에 경고만)
+                    if let Some(verification) = workflow_signing::check_file_content(&content) {
+                        if verification.status != "valid" {
+                            let _ = app_handle.emit("workflow-signature", &verification);
+                        }
+                    }
+                    // ${workspace} / ${home} 경로를 이 PC 기준으로
+                    workspace::expand_workflow_text(&content, std::path::Path::new(&file_path))
                 },
                 Err(_) => {
                     Err("잘못된 워크플로우 파일 형식입니다".to_string())
@@ -56,12 +68,40 @@ pub fn save_workflow_to_desktop(
             // FilePath를 PathBuf로 변환
             let path_buf = path.as_path().unwrap();
 
+            // base_dir 이 있으면 절대 경로를 ${workspace} / ${home} 으로 바꿔 저장
+            let workflow_data = match serde_json::from_str::<serde_json::Value>(&workflow_data) {
+                Ok(mut workflow) => {
+                    if workspace::collapse_workflow(&mut workflow, path_buf) {
+                        serde_json::to_string_pretty(&workflow).unwrap_or(workflow_data)
+                    } else {
+                        workflow_data
+                    }
+                }
+                Err(_) => workflow_data,
+            };
+
+            // 🕵️ API 키처럼 보이는 값이 있으면 경고 (비밀값 관리로 옮길지는 프론트엔드에서 선택)
+            let secret_findings = serde_json::from_str::<serde_json::Value>(&workflow_data)
+                .map(|workflow| secret_scan::scan_workflow(&workflow))
+                .unwrap_or_default();
+
             // 사용자가 경로를 선택했을 때 파일 저장
-            match fs::write(&path_buf, workflow_data) {
+            match fs::write(path_buf, &workflow_data) {
                 Ok(_) => {
                     // 🎯 수정: 파일 경로를 문자열로 반환 (Store에 저장용)
                     let path_string = path_buf.to_string_lossy().to_string();
                     println!("✅ Workflow saved successfully: {}", path_string);
+                    // 🕘 버전 스냅샷 (변경 메모는 annotate_workflow_version 으로)
+                    workflow_history::on_workflow_saved(path_buf, &workflow_data);
+                    // 🔀 Git 동기화 폴더 안이면 자동 커밋
+                    git_sync::on_workflow_saved(&app_handle, path_buf);
+                    if !secret_findings.is_empty() {
+                        println!("⚠️ 워크플로우에 비밀값으로 보이는 값 {}개", secret_findings.len());
+                        let _ = app_handle.emit(
+                            "secrets-detected",
+                            serde_json::json!({ "path": path_string, "findings": secret_findings }),
+                        );
+                    }
                     Ok(path_string) // 성공 메시지 대신 파일 경로 반환
                 }
                 Err(e) => Err(format!("Save failed: {}", e)),
@@ -91,10 +131,15 @@ pub fn load_workflow_from_desktop(app_handle: tauri::AppHandle) -> Result<String
             let path_buf = path.as_path().unwrap();
 
             // 사용자가 파일을 선택했을 때 파일 읽기
-            match fs::read_to_string(&path_buf) {
+            match fs::read_to_string(path_buf) {
                 Ok(content) => {
                     println!("Workflow loaded successfully: {:?}", path_buf);
-                    Ok(content)
+                    if let Some(verification) = workflow_signing::check_file_content(&content) {
+                        if verification.status != "valid" {
+                            let _ = app_handle.emit("workflow-signature", &verification);
+                        }
+                    }
+                    workspace::expand_workflow_text(&content, path_buf)
                 }
                 Err(e) => Err(format!("Load failed: {}", e)),
             }
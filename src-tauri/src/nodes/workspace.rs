@@ -0,0 +1,264 @@
+// src-tauri/src/nodes/workspace.rs
+use serde_json::{json, Value};
+use std::path::{Path, PathBuf};
+
+// 📂 워크스페이스 기준 경로 (워크플로우 공유용)
+// 워크플로우 JSON 최상위 "base_dir" 로 기준 폴더를 지정하면
+// - 저장할 때: 노드 data 안의 절대 경로를 ${workspace}/… (기준 폴더 아래) 또는 ${home}/… 로 바꿔 저장
+// - 불러올 때: ${workspace} / ${home} 을 이 PC 의 실제 경로로 바꾸고,
+//   경로 필드(…path, …folder, …dir)의 "./a.txt", "sub/a.txt" 같은 상대 경로는 기준 폴더 기준으로 풀어줌
+// base_dir 값: "" 또는 "." → 워크플로우 파일이 있는 폴더, 상대 경로 → 그 폴더 기준, 절대 경로 → 그대로
+// 사용자 이름이나 드라이브 구성이 다른 PC 에서도 같은 워크플로우가 동작하도록
+
+pub const WORKSPACE_PLACEHOLDER: &str = "${workspace}";
+pub const HOME_PLACEHOLDER: &str = "${home}";
+
+// 워크플로우의 기준 폴더 (base_dir 이 없으면 기능 꺼짐)
+pub fn base_dir(workflow: &Value, workflow_file: Option<&Path>) -> Option<PathBuf> {
+    let configured = workflow.get("base_dir")?.as_str()?.trim();
+    let configured = expand_text(configured, None, dirs::home_dir().as_deref());
+    let workflow_dir = workflow_file.and_then(Path::parent);
+    match configured.as_str() {
+        "" | "." => workflow_dir.map(Path::to_path_buf),
+        other if Path::new(other).is_absolute() => Some(PathBuf::from(other)),
+        other => workflow_dir.map(|dir| dir.join(other)),
+    }
+}
+
+fn unify(path: &Path) -> String {
+    path.to_string_lossy()
+        .replace('\\', "/")
+        .trim_end_matches('/')
+        .to_string()
+}
+
+fn expand_text(text: &str, base: Option<&Path>, home: Option<&Path>) -> String {
+    let mut expanded = text.to_string();
+    if let Some(base) = base.filter(|_| expanded.contains(WORKSPACE_PLACEHOLDER)) {
+        expanded = expanded.replace(WORKSPACE_PLACEHOLDER, &unify(base));
+    }
+    if let Some(home) = home.filter(|_| expanded.contains(HOME_PLACEHOLDER)) {
+        expanded = expanded.replace(HOME_PLACEHOLDER, &unify(home));
+    }
+    expanded
+}
+
+// prefix 폴더 아래 경로면 나머지 부분 ("/a/b.txt" 또는 "")
+fn strip_dir_prefix<'a>(text: &'a str, prefix: &str) -> Option<&'a str> {
+    if prefix.is_empty() {
+        return None;
+    }
+    let head = text.get(..prefix.len())?;
+    // Windows 경로는 대소문자를 구분하지 않음
+    let same = if cfg!(windows) {
+        head.eq_ignore_ascii_case(prefix)
+    } else {
+        head == prefix
+    };
+    let rest = &text[prefix.len()..];
+    (same && (rest.is_empty() || rest.starts_with('/'))).then_some(rest)
+}
+
+// 절대 경로 문자열 → 플레이스홀더 (기준 폴더가 홈 폴더 안에 있어도 workspace 우선)
+pub fn collapse_text(text: &str, base: Option<&Path>, home: Option<&Path>) -> Option<String> {
+    let unified = text.replace('\\', "/");
+    for (dir, placeholder) in [(base, WORKSPACE_PLACEHOLDER), (home, HOME_PLACEHOLDER)] {
+        if let Some(rest) = dir.and_then(|dir| strip_dir_prefix(&unified, &unify(dir))) {
+            return Some(format!("{}{}", placeholder, rest));
+        }
+    }
+    None
+}
+
+fn is_path_key(key: &str) -> bool {
+    let key = key.to_lowercase();
+    key.ends_with("path") || key.ends_with("folder") || key.ends_with("dir")
+}
+
+// "./a.txt", "../x", "sub/a.txt" 같은 상대 경로 (파일명만 있는 값이나 URL 은 제외)
+fn is_relative_path_value(text: &str) -> bool {
+    let text = text.trim();
+    !text.is_empty()
+        && !text.contains("://")
+        && !text.contains("${")
+        && !Path::new(text).is_absolute()
+        && !text.starts_with('/')
+        && !text.starts_with('\\')
+        && text.contains(['/', '\\'])
+}
+
+fn expand_value(value: &mut Value, key: Option<&str>, base: Option<&Path>, home: Option<&Path>) {
+    match value {
+        Value::String(text) => {
+            let mut expanded = expand_text(text, base, home);
+            if let Some(base) = base {
+                if key.is_some_and(is_path_key) && is_relative_path_value(&expanded) {
+                    let relative = expanded.trim().trim_start_matches("./").to_string();
+                    expanded = format!("{}/{}", unify(base), relative.replace('\\', "/"));
+                }
+            }
+            *text = expanded;
+        }
+        Value::Array(items) => items
+            .iter_mut()
+            .for_each(|item| expand_value(item, key, base, home)),
+        Value::Object(map) => {
+            for (child_key, item) in map.iter_mut() {
+                expand_value(item, Some(child_key), base, home);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn collapse_value(value: &mut Value, base: Option<&Path>, home: Option<&Path>) {
+    match value {
+        Value::String(text) => {
+            if let Some(collapsed) = collapse_text(text, base, home) {
+                *text = collapsed;
+            }
+        }
+        Value::Array(items) => items
+            .iter_mut()
+            .for_each(|item| collapse_value(item, base, home)),
+        Value::Object(map) => map
+            .values_mut()
+            .for_each(|item| collapse_value(item, base, home)),
+        _ => {}
+    }
+}
+
+fn node_data_mut(workflow: &mut Value) -> Vec<&mut Value> {
+    workflow
+        .get_mut("nodes")
+        .and_then(Value::as_array_mut)
+        .map(|nodes| {
+            nodes
+                .iter_mut()
+                .filter_map(|node| node.get_mut("data"))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// 불러온 워크플로우의 플레이스홀더 / 상대 경로를 이 PC 경로로
+pub fn expand_workflow(workflow: &mut Value, workflow_file: Option<&Path>) {
+    let base = base_dir(workflow, workflow_file);
+    let home = dirs::home_dir();
+    for data in node_data_mut(workflow) {
+        expand_value(data, None, base.as_deref(), home.as_deref());
+    }
+}
+
+// 저장할 워크플로우의 절대 경로를 플레이스홀더로 (base_dir 이 있을 때만)
+pub fn collapse_workflow(workflow: &mut Value, workflow_file: &Path) -> bool {
+    let Some(base) = base_dir(workflow, Some(workflow_file)) else {
+        return false;
+    };
+    let home = dirs::home_dir();
+    for data in node_data_mut(workflow) {
+        collapse_value(data, Some(&base), home.as_deref());
+    }
+    true
+}
+
+// 파일에서 읽은 워크플로우 텍스트 → 경로를 풀어준 텍스트 (base_dir / 플레이스홀더가 없으면 원문 그대로)
+pub fn expand_workflow_text(content: &str, workflow_file: &Path) -> Result<String, String> {
+    if !content.contains("${") && !content.contains("\"base_dir\"") {
+        return Ok(content.to_string());
+    }
+    let mut workflow: Value = serde_json::from_str(content)
+        .map_err(|_| "잘못된 워크플로우 파일 형식입니다".to_string())?;
+    expand_workflow(&mut workflow, Some(workflow_file));
+    serde_json::to_string_pretty(&workflow).map_err(|e| e.to_string())
+}
+
+// UI 표시용: 이 워크플로우의 기준 폴더
+#[tauri::command]
+pub fn resolve_workspace(
+    workflow_data: String,
+    workflow_path: Option<String>,
+) -> Result<String, String> {
+    let workflow: Value = serde_json::from_str(&workflow_data)
+        .map_err(|_| "잘못된 워크플로우 파일 형식입니다".to_string())?;
+    let base = base_dir(&workflow, workflow_path.as_deref().map(Path::new));
+    Ok(json!({
+        "enabled": workflow.get("base_dir").is_some_and(|b| !b.is_null()),
+        "base_dir": base.as_ref().map(|b| b.to_string_lossy().to_string()),
+        "exists": base.as_ref().is_some_and(|b| b.is_dir()),
+        "home": dirs::home_dir().map(|h| h.to_string_lossy().to_string())
+    })
+    .to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::test_support::{path_str, temp_dir};
+    use serde_json::json;
+
+    fn unified(path: &std::path::Path) -> String {
+        path_str(path).replace('\\', "/")
+    }
+
+    #[test]
+    fn workspace_collapses_paths_under_base_dir() {
+        let dir = temp_dir();
+        let project = dir.path().join("project");
+        let workflow_file = project.join("flow.flow.json");
+        let mut workflow = json!({
+            "base_dir": ".",
+            "nodes": [{ "data": {
+                "file_path": path_str(&project.join("in").join("a.txt")),
+                "sibling": format!("{}2/x.txt", unified(&project)),
+                "label": "project"
+            }}]
+        });
+
+        assert!(collapse_workflow(&mut workflow, &workflow_file));
+        let data = &workflow["nodes"][0]["data"];
+        assert_eq!(data["file_path"], "${workspace}/in/a.txt");
+        // 이름만 같은 옆 폴더는 건드리지 않음
+        assert_eq!(data["sibling"], format!("{}2/x.txt", unified(&project)));
+        assert_eq!(data["label"], "project");
+
+        // base_dir 이 없으면 저장 내용 그대로
+        let mut plain = json!({ "nodes": [{ "data": { "file_path": path_str(&project) } }] });
+        assert!(!collapse_workflow(&mut plain, &workflow_file));
+    }
+
+    #[test]
+    fn workspace_expands_placeholders_and_relative_paths() {
+        let dir = temp_dir();
+        let workflow_file = dir.path().join("shared").join("flow.flow.json");
+        let base = unified(&dir.path().join("shared").join("assets"));
+        let mut workflow = json!({
+            "base_dir": "assets",
+            "nodes": [{ "data": {
+                "file_path": "${workspace}/in/a.txt",
+                "output_folder": "./out",
+                "download_path": "clips/today",
+                "file_name": "a.txt",
+                "url": "https://example.com/a/b",
+                "text": "see ${workspace}"
+            }}]
+        });
+
+        expand_workflow(&mut workflow, Some(&workflow_file));
+        let data = &workflow["nodes"][0]["data"];
+        assert_eq!(data["file_path"], format!("{}/in/a.txt", base));
+        assert_eq!(data["output_folder"], format!("{}/out", base));
+        assert_eq!(data["download_path"], format!("{}/clips/today", base));
+        assert_eq!(data["file_name"], "a.txt");
+        assert_eq!(data["url"], "https://example.com/a/b");
+        assert_eq!(data["text"], format!("see {}", base));
+    }
+
+    #[test]
+    fn workspace_leaves_plain_workflows_untouched() {
+        let content = r#"{"nodes":[{"data":{"file_path":"docs/a.txt"}}]}"#;
+        let expanded =
+            expand_workflow_text(content, std::path::Path::new("/tmp/flow.json")).unwrap();
+        assert_eq!(expanded, content);
+    }
+}
@@ -0,0 +1,273 @@
+// src-tauri/src/nodes/ws_limits.rs
+use super::atomic_store;
+use futures_util::{SinkExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tokio::sync::{broadcast, Notify};
+
+// 📶 채팅 서버 WebSocket 한도
+// 느린 폰 하나 때문에 broadcast 버퍼가 밀리거나 메모리가 계속 늘지 않도록:
+// - max_message_bytes:  주고받는 메시지 한 개 최대 크기 (넘는 송신 메시지는 거부)
+// - send_queue:         클라이언트별 송신 대기열 길이, 가득 차면 가장 오래된 메시지부터 버림
+// - broadcast_capacity: 서버 하나의 broadcast 채널 크기 (대기열로 옮기지 못한 메시지가 밀리면 건너뜀)
+// - heartbeat_secs / idle_timeout_secs: 주기적으로 ping, idle_timeout 동안 아무것도(pong 포함) 안 오면 연결 정리
+//   → 화면 꺼진 폰 / 끊긴 터널 연결이 남아 send_to_mobile 의 수신자 수를 부풀리지 않음
+// 설정은 store/ws_limits.json, 새로 시작하는 채팅 서버부터 적용
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct WsLimits {
+    pub max_message_bytes: usize,
+    pub send_queue: usize,
+    pub broadcast_capacity: usize,
+    pub heartbeat_secs: u64,
+    pub idle_timeout_secs: u64,
+}
+
+impl Default for WsLimits {
+    fn default() -> Self {
+        Self {
+            max_message_bytes: 1024 * 1024,
+            send_queue: 256,
+            broadcast_capacity: 1000,
+            heartbeat_secs: 20,
+            idle_timeout_secs: 60,
+        }
+    }
+}
+
+fn settings_path() -> PathBuf {
+    atomic_store::store_dir().join("ws_limits.json")
+}
+
+pub fn load_limits() -> WsLimits {
+    atomic_store::read_json_verified(&settings_path()).unwrap_or_default()
+}
+
+pub fn validate_limits(limits: &WsLimits) -> Result<(), String> {
+    if !(1024..=64 * 1024 * 1024).contains(&limits.max_message_bytes) {
+        return Err("INVALID_WS_LIMIT: max_message_bytes 는 1KB ~ 64MB".to_string());
+    }
+    if !(1..=10_000).contains(&limits.send_queue) {
+        return Err("INVALID_WS_LIMIT: send_queue 는 1 ~ 10000".to_string());
+    }
+    if !(16..=100_000).contains(&limits.broadcast_capacity) {
+        return Err("INVALID_WS_LIMIT: broadcast_capacity 는 16 ~ 100000".to_string());
+    }
+    if !(5..=300).contains(&limits.heartbeat_secs) {
+        return Err("INVALID_WS_LIMIT: heartbeat_secs 는 5 ~ 300".to_string());
+    }
+    if limits.idle_timeout_secs <= limits.heartbeat_secs {
+        return Err("INVALID_WS_LIMIT: idle_timeout_secs 는 heartbeat_secs 보다 커야 함".to_string());
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Heartbeat {
+    Ping,
+    Reap,
+}
+
+// 마지막 수신 후 지난 시간으로 판단 (ping 을 보냈는데 idle_timeout 동안 pong 도 없으면 정리)
+pub fn heartbeat_action(idle: Duration, limits: &WsLimits) -> Heartbeat {
+    if idle >= Duration::from_secs(limits.idle_timeout_secs) {
+        Heartbeat::Reap
+    } else {
+        Heartbeat::Ping
+    }
+}
+
+// 클라이언트별 송신 대기열 (가득 차면 가장 오래된 것부터 버림)
+pub struct SendQueue {
+    capacity: usize,
+    items: Mutex<VecDeque<String>>,
+    notify: Notify,
+}
+
+impl SendQueue {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            items: Mutex::new(VecDeque::new()),
+            notify: Notify::new(),
+        }
+    }
+
+    // 버린 메시지 수 반환
+    pub fn push(&self, message: String) -> usize {
+        let mut dropped = 0;
+        if let Ok(mut items) = self.items.lock() {
+            while items.len() >= self.capacity {
+                items.pop_front();
+                dropped += 1;
+            }
+            items.push_back(message);
+        }
+        self.notify.notify_one();
+        dropped
+    }
+
+    pub fn try_pop(&self) -> Option<String> {
+        self.items.lock().ok()?.pop_front()
+    }
+
+    pub async fn pop(&self) -> String {
+        loop {
+            if let Some(message) = self.try_pop() {
+                return message;
+            }
+            self.notify.notified().await;
+        }
+    }
+}
+
+fn idle_for(last_seen: &Mutex<Instant>) -> Duration {
+    last_seen
+        .lock()
+        .map(|seen| seen.elapsed())
+        .unwrap_or_default()
+}
+
+pub fn configure(ws: warp::ws::Ws, limits: &WsLimits) -> warp::ws::Ws {
+    ws.max_message_size(limits.max_message_bytes)
+        .max_frame_size(limits.max_message_bytes)
+}
+
+// broadcast → 클라이언트 대기열 → 소켓 (소켓이 느려도 broadcast 수신은 멈추지 않음)
+// 수신 쪽은 pong / close 확인용으로 계속 읽고, 셋 중 하나라도 끝나면 연결 종료 (rx 도 함께 해제)
+pub async fn pump(
+    websocket: warp::ws::WebSocket,
+    mut rx: broadcast::Receiver<String>,
+    limits: WsLimits,
+) {
+    let (mut ws_sender, mut ws_receiver) = websocket.split();
+    let queue = SendQueue::new(limits.send_queue);
+    let mut dropped = 0usize;
+    // 세 future 가 함께 poll 되지만 pump 전체가 Send 여야 하므로 Cell 대신 Mutex
+    let last_seen = Mutex::new(Instant::now());
+
+    let fill = async {
+        loop {
+            match rx.recv().await {
+                Ok(message) if message.len() > limits.max_message_bytes => {
+                    println!("⚠️ WebSocket 메시지가 너무 커서 건너뜀: {} bytes", message.len());
+                }
+                Ok(message) => dropped += queue.push(message),
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    dropped += skipped as usize;
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    };
+    let drain = async {
+        let period = Duration::from_secs(limits.heartbeat_secs);
+        let mut heartbeat = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+        loop {
+            let message = tokio::select! {
+                message = queue.pop() => {
+                    println!("📱 WebSocket으로 메시지 전송: {}", message);
+                    warp::ws::Message::text(message)
+                }
+                _ = heartbeat.tick() => match heartbeat_action(idle_for(&last_seen), &limits) {
+                    Heartbeat::Ping => warp::ws::Message::ping(Vec::new()),
+                    Heartbeat::Reap => {
+                        println!("💤 응답 없는 WebSocket 클라이언트 정리");
+                        break;
+                    }
+                },
+            };
+            if let Err(e) = ws_sender.send(message).await {
+                println!("❌ WebSocket 클라이언트 연결 해제됨: {}", e);
+                break;
+            }
+        }
+        let _ = ws_sender.close().await;
+    };
+    let receive = async {
+        while let Some(Ok(message)) = ws_receiver.next().await {
+            if let Ok(mut seen) = last_seen.lock() {
+                *seen = Instant::now();
+            }
+            if message.is_close() {
+                break;
+            }
+        }
+    };
+    tokio::select! {
+        _ = fill => {}
+        _ = drain => {}
+        _ = receive => {}
+    }
+    if dropped > 0 {
+        println!("📶 느린 WebSocket 클라이언트: 메시지 {}개 버림", dropped);
+    }
+}
+
+#[tauri::command]
+pub fn get_ws_limits() -> Result<WsLimits, String> {
+    Ok(load_limits())
+}
+
+#[tauri::command]
+pub fn set_ws_limits(limits: WsLimits) -> Result<String, String> {
+    validate_limits(&limits)?;
+    atomic_store::write_json_atomic(&settings_path(), &limits)?;
+    Ok("SUCCESS".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn ws_send_queue_drops_oldest_when_full() {
+        let queue = SendQueue::new(2);
+        assert_eq!(queue.push("a".to_string()), 0);
+        assert_eq!(queue.push("b".to_string()), 0);
+        assert_eq!(queue.push("c".to_string()), 1);
+        assert_eq!(queue.try_pop().as_deref(), Some("b"));
+        assert_eq!(queue.try_pop().as_deref(), Some("c"));
+        assert_eq!(queue.try_pop(), None);
+    }
+
+    #[test]
+    fn ws_limits_validate_ranges() {
+        let limits: WsLimits =
+            serde_json::from_value(json!({ "send_queue": 32 })).unwrap();
+        assert_eq!(limits.send_queue, 32);
+        assert_eq!(limits.broadcast_capacity, 1000);
+        assert!(validate_limits(&limits).is_ok());
+
+        let mut bad = limits.clone();
+        bad.send_queue = 0;
+        assert!(validate_limits(&bad).is_err());
+        let mut bad = limits;
+        bad.max_message_bytes = 10;
+        assert!(validate_limits(&bad).unwrap_err().starts_with("INVALID_WS_LIMIT"));
+    }
+
+    #[test]
+    fn ws_heartbeat_reaps_after_idle_timeout() {
+        use std::time::Duration;
+        let limits = WsLimits::default();
+        assert!(validate_limits(&limits).is_ok());
+        assert_eq!(
+            heartbeat_action(Duration::from_secs(25), &limits),
+            Heartbeat::Ping
+        );
+        assert_eq!(
+            heartbeat_action(Duration::from_secs(61), &limits),
+            Heartbeat::Reap
+        );
+
+        let mut bad = limits;
+        bad.idle_timeout_secs = bad.heartbeat_secs;
+        assert!(validate_limits(&bad).is_err());
+    }
+}
@@ -1,9 +1,19 @@
-import React, { useState, useCallback } from 'react';
+import React, { useState, useCallback, useRef, useEffect } from 'react';
 import { ReactFlowProvider } from '@xyflow/react';
+import { invoke } from '@tauri-apps/api/core';
+import { Store } from '@tauri-apps/plugin-store';
+import { ask } from '@tauri-apps/plugin-dialog';
 import Workspace from './Workspace';
 import ViewerPage from './ViewerPage';
 import { WorkflowProvider } from './WorkflowContext';
 import { ViewerProvider } from './ViewerPage';
+import ApprovalPrompt from './ApprovalPrompt';
+import SignatureNotice from './SignatureNotice';
+import SyncNotice from './SyncNotice';
+import DoctorNotice from './DoctorNotice';
+import { listenChunked } from './EventChunks';
+import RunDebugBar, { PausedRun } from './RunDebugBar';
+import { getNodeManager } from './NodeManager';
 import { Node, Edge } from '@xyflow/react';
 import './App.css';
 
@@ -29,6 +39,131 @@ const defaultNodes = [
   }
 ];
 
+// 🧾 실행 기록 (run_history) - 트리거 실행이 이어지는 동안을 실행 하나로 기록
+interface ActiveRun {
+  runId: Promise<string>;
+  workflow: Promise<string>;
+  pending: Map<string, number>; // 트리거됐지만 아직 끝나지 않은 노드 → 트리거 시각
+  failed: boolean;
+  cancelled: boolean; // 브레이크포인트에서 중단(abort_run)
+  debugQueue: Promise<void>; // debug_before_node 는 실행마다 하나씩 (동시에 두 곳에서 멈추지 않도록)
+  lastOutput?: any; // 마지막으로 끝난 노드의 결과 (실행 결과로 돌려줌)
+  lastError?: string;
+  onFinish?: (status: RunStatus, output?: any, error?: string) => void;
+}
+
+type RunStatus = 'success' | 'error' | 'cancelled';
+
+// ⏪ replay_node 요청 (기록된 입력으로 노드 하나만 다시 실행)
+interface NodeReplayRequest {
+  replay_id: string;
+  run_id: string;
+  node_id: string;
+  node_type: string;
+  inputs: any;
+}
+
+// 🔀 메시지 라우팅 결과 (message_router 규칙에 맞은 채팅 / 웹훅 / 이메일)
+interface RoutedMessage {
+  rule_id: string;
+  target: { kind: 'workflow'; workflow: string } | { kind: 'node'; node_id: string };
+  captures: Record<string, string>;
+  message: { source: string; sender?: string; subject?: string; text: string; node_id?: string };
+  workflow_path?: string; // 워크플로우 대상의 파일 (워크플로우 폴더에서 찾은 경로)
+}
+
+// ▶️ 워크플로우 단위 실행 (입력 파라미터 검증 / ${input:NAME} 주입 후 시작 노드 트리거)
+interface WorkflowRunOptions {
+  workflowPath?: string; // 없으면 지금 열린 워크플로우
+  inputs?: Record<string, any>; // 워크플로우 입력 파라미터 값 (prepare_workflow_run)
+  values?: Record<string, any>; // 시작 노드 data 에 함께 넣을 값 (메시지 내용 등)
+  prepared?: string; // 백엔드에서 이미 입력값을 주입한 워크플로우 JSON (MCP 도구 호출)
+  onFinish?: (status: RunStatus, output?: any, error?: string) => void;
+}
+
+// 🪟 다른 인스턴스 / trigger:// 링크 / 원격 제어에서 넘어온 실행 요청 (instance)
+interface InstanceRequest {
+  kind: 'run_workflow' | 'deep_link' | 'focus';
+  workflow_path?: string;
+  url?: string; // trigger:// 링크로 열렸을 때만
+  schedule_task?: string;
+}
+
+// 🧩 MCP 도구 호출 (mcp_server) - 실행이 끝나면 complete_mcp_tool_call 로 결과 반환
+interface McpRunWorkflow {
+  call_id: string;
+  tool: string;
+  workflow_path: string;
+  workflow: string;
+}
+
+// 지금 열린 워크플로우 파일 (마지막으로 저장/불러온 경로)
+const currentWorkflowPath = async (): Promise<string | undefined> => {
+  try {
+    const store = await Store.load('app-settings.json');
+    return (await store.get<string>('lastSavedWorkflow')) || undefined;
+  } catch (error) {
+    return undefined;
+  }
+};
+
+// 실행 기록에 쓰는 워크플로우 이름 (파일 이름에서 .flow.json / .json 제외, 없으면 workspace)
+const workflowNameOf = (path?: string): string => {
+  const fileName = path?.split(/[\\/]/).pop() || '';
+  return fileName.replace(/(\.flow)?\.json$/i, '') || 'workspace';
+};
+
+const currentWorkflowName = async (): Promise<string> => workflowNameOf(await currentWorkflowPath());
+
+// 시작하지 못한 실행 요청도 실패한 실행으로 기록 (원격 제어 페이지가 run_finished 로 상태를 갱신하도록)
+const recordFailedStart = async (workflowPath: string | undefined, error: unknown) => {
+  try {
+    const runId = await invoke<string>('start_run', { workflow: workflowNameOf(workflowPath) });
+    await invoke('record_node_run', {
+      runId,
+      node: {
+        node_id: 'start',
+        node_type: 'workflow',
+        status: 'error',
+        started_at: Date.now(),
+        finished_at: Date.now(),
+        inputs: {},
+        output: null,
+        error: String((error as any)?.message ?? error),
+        artifacts: []
+      }
+    });
+    await invoke('finish_run', { runId, status: 'error' });
+  } catch (err) {
+    console.warn('⚠️ 실행 실패 기록 실패:', err);
+  }
+};
+
+const samePath = (a?: string, b?: string) =>
+  !!a && !!b && a.replace(/\\/g, '/') === b.replace(/\\/g, '/');
+
+// 트리거 입력이 연결되지 않은 노드 = 시작 노드
+const startNodeIds = (nodes: any[], edges: any[]): string[] =>
+  nodes
+    .filter(n => !edges.some(e => e.target === n.id && e.targetHandle === 'trigger-input'))
+    .map(n => n.id);
+
+// 🎛️ 백엔드 트리거 노드 이벤트 → 해당 노드의 결과로 다음 노드 실행 (payload 에서 node_id 를 뺀 값이 결과)
+// fires 가 false 면 무시
+const TRIGGER_EVENTS: { event: string; fires?: (payload: any) => boolean }[] = [
+  // 매핑된 버튼은 백엔드가 이미 워크플로우 실행을 요청했고, 뗄 때 이벤트는 학습용
+  { event: 'midi-trigger', fires: payload => !payload.workflow && payload.pressed },
+  { event: 'voice-trigger' },
+  { event: 'geofence-event' },
+  { event: 'usb-device' }
+];
+
+// 노드 입력값 (실행 결과 / 트리거 표시 제외)
+const nodeInputs = (node: any) => {
+  const { outputData, triggerExecution, ...inputs } = node?.data || {};
+  return inputs;
+};
+
 function App() {
   // 현재 페이지 상태
   const [currentPage, setCurrentPage] = useState('workspace');
@@ -37,6 +172,7 @@ function App() {
   const [nodes, setNodes] = useState(defaultNodes);
   const [edges, setEdges] = useState([]);
   const [viewerItems, setViewerItems] = useState([]);
+  const [workflowParameters, setWorkflowParameters] = useState<any[]>([]);
 
   // 페이지 전환 함수들
   const goToViewer = useCallback(() => {
@@ -90,12 +226,8 @@ function App() {
     );
   }, [edges]);
 
-  // 🚀 트리거 전달 함수 (트리거만 전달)
-  const triggerNextNodes = useCallback((completedNodeId: string) => {
-    const nextNodeIds: string[] = edges
-      .filter(edge => edge.source === completedNodeId && edge.sourceHandle === 'trigger-output')
-      .map(edge => edge.target);
-    
+  // 🚀 트리거 전달 함수 (트리거만 전달, 브레이크포인트 확인을 통과한 노드들)
+  const triggerNextNodes = useCallback((nextNodeIds: string[]) => {
     if (nextNodeIds.length === 0) return;
     
     const triggerTime = Date.now();
@@ -106,20 +238,419 @@ function App() {
           : node
       )
     );
-  }, [edges]);
+  }, []);
+
+  // 🧾 실행 기록용 최신 노드 목록과 진행 중인 실행
+  const nodesRef = useRef(nodes);
+  nodesRef.current = nodes;
+  const edgesRef = useRef(edges);
+  edgesRef.current = edges;
+  const parametersRef = useRef(workflowParameters);
+  parametersRef.current = workflowParameters;
+  const runRef = useRef<ActiveRun | null>(null);
+
+  const ensureRun = useCallback((): ActiveRun => {
+    if (!runRef.current) {
+      const workflow = currentWorkflowName();
+      runRef.current = {
+        runId: workflow.then(name => invoke<string>('start_run', { workflow: name })),
+        workflow,
+        pending: new Map(),
+        failed: false,
+        cancelled: false,
+        debugQueue: Promise.resolve()
+      };
+    }
+    return runRef.current;
+  }, []);
+
+  // 실행 중이 아니면 (수동 실행) undefined
+  const getCurrentRunId = useCallback(
+    (): Promise<string | undefined> => runRef.current?.runId ?? Promise.resolve(undefined),
+    []
+  );
+
+  const recordNodeRun = useCallback((run: ActiveRun, nodeId: string, output?: any, error?: string) => {
+    const node = nodesRef.current.find(n => n.id === nodeId);
+    const startedAt = run.pending.get(nodeId) ?? Date.now();
+    run.pending.delete(nodeId);
+    if (error === undefined) {
+      run.lastOutput = output;
+    } else {
+      run.lastError = error;
+    }
+    const record = {
+      node_id: nodeId,
+      node_type: node?.type || 'unknown',
+      status: error === undefined ? 'success' : 'error',
+      started_at: startedAt,
+      finished_at: Date.now(),
+      inputs: nodeInputs(node),
+      output: output === undefined ? null : JSON.stringify(output),
+      error: error ?? null,
+      artifacts: []
+    };
+    run.runId
+      .then(runId => invoke('record_node_run', { runId, node: record }))
+      .catch(err => console.warn('⚠️ 실행 기록 실패:', err));
+  }, []);
+
+  // 트리거된 노드가 모두 끝나면 실행 종료
+  const finishRunIfIdle = useCallback((run: ActiveRun) => {
+    if (run.pending.size > 0 || runRef.current !== run) return;
+    runRef.current = null;
+    const status: RunStatus = run.cancelled ? 'cancelled' : run.failed ? 'error' : 'success';
+    run.onFinish?.(status, run.lastOutput, run.lastError);
+    run.runId
+      .then(runId => invoke('finish_run', { runId, status }))
+      .catch(err => console.warn('⚠️ 실행 종료 기록 실패:', err));
+  }, []);
+
+  // 🐞 브레이크포인트 / 단계 실행
+  const [pausedRun, setPausedRun] = useState<PausedRun | null>(null);
+  const [breakpoints, setBreakpoints] = useState<string[]>([]);
+
+  useEffect(() => {
+    currentWorkflowName()
+      .then(workflow => invoke<string[]>('get_breakpoints', { workflow }))
+      .then(setBreakpoints)
+      .catch(err => console.warn('⚠️ 브레이크포인트 불러오기 실패:', err));
+
+    const unlisten = listenChunked<PausedRun>('run-paused', setPausedRun);
+    return () => {
+      unlisten.then(fn => fn());
+    };
+  }, []);
+
+  const toggleBreakpoint = useCallback(async (nodeId: string) => {
+    const next = breakpoints.includes(nodeId)
+      ? breakpoints.filter(id => id !== nodeId)
+      : [...breakpoints, nodeId];
+    setBreakpoints(next);
+    try {
+      await invoke('set_breakpoints', { workflow: await currentWorkflowName(), nodeIds: next });
+    } catch (err) {
+      console.warn('⚠️ 브레이크포인트 저장 실패:', err);
+    }
+  }, [breakpoints]);
+
+  // 노드를 트리거하기 직전에 debug_before_node 대기 → 통과한 노드만 반환 (중단되면 실행 취소)
+  const pauseBeforeNodes = useCallback((run: ActiveRun, nodeIds: string[]): Promise<string[]> => {
+    const allowed: string[] = [];
+    const checks = run.debugQueue.then(async () => {
+      const runId = await run.runId;
+      const workflow = await run.workflow;
+      for (const nodeId of nodeIds) {
+        if (run.cancelled) {
+          run.pending.delete(nodeId);
+          continue;
+        }
+        const node = nodesRef.current.find(n => n.id === nodeId);
+        try {
+          await invoke('debug_before_node', {
+            runId,
+            workflow,
+            nodeId,
+            nodeType: node?.type,
+            values: nodeInputs(node)
+          });
+          allowed.push(nodeId);
+        } catch (err) {
+          if (String(err).includes('RUN_ABORTED')) {
+            console.log(`🛑 실행 중단: ${runId} @ ${nodeId}`);
+            run.cancelled = true;
+            run.pending.delete(nodeId);
+          } else {
+            // 디버거를 못 쓰면 실행은 그대로 진행
+            console.warn('⚠️ 브레이크포인트 확인 실패:', err);
+            allowed.push(nodeId);
+          }
+        }
+        setPausedRun(current => current?.run_id === runId && current.node_id === nodeId ? null : current);
+      }
+    });
+    run.debugQueue = checks.catch(() => undefined);
+    return checks.then(() => allowed, () => nodeIds);
+  }, []);
+
+  // ⏪ 재실행 중인 노드 → replay_id (결과는 complete_node_replay 로만 돌려주고 다음 노드로 넘기지 않음)
+  const replaysRef = useRef(new Map<string, string>());
+
+  const completeReplay = useCallback((nodeId: string, output?: any, error?: string): boolean => {
+    const replayId = replaysRef.current.get(nodeId);
+    if (!replayId) return false;
+    replaysRef.current.delete(nodeId);
+    invoke('complete_node_replay', {
+      replayId,
+      output: output === undefined ? null : JSON.stringify(output),
+      error: error ?? null
+    }).catch(err => console.warn('⚠️ 재실행 결과 전달 실패:', err));
+    return true;
+  }, []);
+
+  useEffect(() => {
+    const unlisten = listenChunked<NodeReplayRequest>('node-replay-request', (request) => {
+      const node = nodesRef.current.find(n => n.id === request.node_id);
+      if (!node) {
+        invoke('complete_node_replay', {
+          replayId: request.replay_id,
+          output: null,
+          error: `REPLAY_NODE_NOT_FOUND: ${request.node_id}`
+        }).catch(err => console.warn('⚠️ 재실행 결과 전달 실패:', err));
+        return;
+      }
+
+      console.log(`⏪ 노드 재실행: ${request.node_id} (${request.replay_id})`);
+      replaysRef.current.set(request.node_id, request.replay_id);
+      const inputs = request.inputs && typeof request.inputs === 'object' ? request.inputs : {};
+      updateNodeData(request.node_id, { ...inputs, triggerExecution: Date.now() });
+    });
+
+    return () => {
+      unlisten.then(fn => fn());
+    };
+  }, [updateNodeData]);
+
+  // ▶️ 워크플로우 실행 시작: 실행 시작 시점에 prepare_workflow_run 으로 입력값 검증 + ${input:NAME} 주입
+  // - 지금 열린 워크플로우: 캔버스의 노드/엣지 + parameters 정의 (저장하지 않은 변경도 그대로 실행)
+  // - 다른 워크플로우: 파일을 불러와 캔버스를 교체 (불러오기와 같음)
+  // 주입된 입력값은 캔버스의 노드 data 에 그대로 남음 (파일의 ${input:NAME} 은 저장하기 전까지 유지)
+  const startWorkflowRun = useCallback(async (options: WorkflowRunOptions = {}) => {
+    if (runRef.current) throw new Error('RUN_IN_PROGRESS');
+
+    const openPath = await currentWorkflowPath();
+    const path = options.workflowPath || openPath;
+    const isOpen = !options.prepared && (!options.workflowPath || samePath(options.workflowPath, openPath));
+    const flow: any = options.prepared
+      ? JSON.parse(options.prepared)
+      : isOpen
+        ? { parameters: parametersRef.current, nodes: nodesRef.current, edges: edgesRef.current }
+        : JSON.parse(await invoke<string>('load_specific_workflow', { filePath: path }));
+
+    const prepared = options.prepared ? flow : JSON.parse(await invoke<string>('prepare_workflow_run', {
+      workflowData: JSON.stringify(flow),
+      inputs: options.inputs ?? null
+    }));
+    const preparedNodes: any[] = Array.isArray(prepared.nodes) ? prepared.nodes : [];
+    const preparedEdges: any[] = Array.isArray(prepared.edges) ? prepared.edges : [];
+    const targetIds = startNodeIds(preparedNodes, preparedEdges);
+    if (targetIds.length === 0) throw new Error(`NO_START_NODE: ${path || 'workspace'}`);
+
+    if (!isOpen) {
+      const store = await Store.load('app-settings.json');
+      await store.set('lastSavedWorkflow', path);
+      getNodeManager().syncWithNodes(preparedNodes);
+      setWorkflowParameters(Array.isArray(flow.parameters) ? flow.parameters : []);
+      const loadedIds = new Set(preparedNodes.map(n => n.id));
+      setViewerItems(Array.isArray(flow.viewerItems)
+        ? flow.viewerItems.filter((item: any) => loadedIds.has(item.nodeId))
+        : []);
+      console.log(`📂 실행할 워크플로우 불러옴: ${path}`);
+    }
+
+    const run = ensureRun();
+    run.onFinish = options.onFinish;
+    targetIds.forEach(nodeId => run.pending.set(nodeId, Date.now()));
+    const values = options.values || {};
+    nodesRef.current = preparedNodes.map(node =>
+      targetIds.includes(node.id) ? { ...node, data: { ...node.data, ...values } } : node
+    );
+    edgesRef.current = preparedEdges;
+    setEdges(preparedEdges);
+    setNodes(nodesRef.current);
+    console.log(`▶️ 워크플로우 실행 시작: ${path || 'workspace'} → ${targetIds.join(', ')}`);
+
+    setTimeout(async () => {
+      triggerNextNodes(await pauseBeforeNodes(run, targetIds));
+      finishRunIfIdle(run);
+    }, 20);
+  }, [ensureRun, pauseBeforeNodes, triggerNextNodes, finishRunIfIdle]);
+
+  // 🪟 실행 요청 처리 (앱을 시작시킨 요청은 take_launch_request 로 한 번 꺼냄)
+  // trigger:// 링크는 아무 웹 페이지에서나 열 수 있으므로 실행 전에 확인
+  const handleInstanceRequest = useCallback(async (request: InstanceRequest | null) => {
+    if (!request || request.kind !== 'run_workflow' || !request.workflow_path) {
+      if (request?.kind === 'deep_link') console.log(`🔗 처리하지 않는 링크: ${request.url}`);
+      return;
+    }
+    if (request.url) {
+      const confirmed = await ask(
+        `링크로 워크플로우 실행이 요청되었습니다.\n\n${request.workflow_path}\n\n실행할까요?`,
+        { title: '워크플로우 실행 확인', kind: 'warning' }
+      );
+      if (!confirmed) {
+        console.log(`🚫 링크 실행 취소: ${request.workflow_path}`);
+        return;
+      }
+    }
+    const source = request.schedule_task ? `예약 작업 ${request.schedule_task}` : request.url ? '링크' : '실행 요청';
+    console.log(`🪟 ${source} → 워크플로우 실행: ${request.workflow_path}`);
+    try {
+      await startWorkflowRun({ workflowPath: request.workflow_path });
+    } catch (err) {
+      console.warn('⚠️ 워크플로우 실행 요청 실패:', err);
+      await recordFailedStart(request.workflow_path, err);
+    }
+  }, [startWorkflowRun]);
+
+  // 시작 요청은 마지막 워크플로우 자동 로드가 끝난 뒤에 처리 (자동 로드가 실행 중인 캔버스를 덮지 않도록)
+  const [autoLoaded] = useState(() => {
+    let resolve = () => {};
+    const promise = new Promise<void>(done => { resolve = done; });
+    return { promise, resolve };
+  });
+
+  useEffect(() => {
+    const unlisten = listenChunked<InstanceRequest>('instance-request', handleInstanceRequest);
+    autoLoaded.promise
+      .then(() => invoke<InstanceRequest | null>('take_launch_request'))
+      .then(handleInstanceRequest)
+      .catch(err => console.warn('⚠️ 시작 요청 확인 실패:', err));
+
+    return () => {
+      unlisten.then(fn => fn());
+    };
+  }, [handleInstanceRequest, autoLoaded]);
+
+  // 🧩 MCP 도구 호출 → 워크플로우 실행 → 마지막 노드 결과(또는 오류)를 돌려줌
+  useEffect(() => {
+    const unlisten = listenChunked<McpRunWorkflow>('mcp-run-workflow', (request) => {
+      const complete = (output: any, error?: string) =>
+        invoke('complete_mcp_tool_call', {
+          callId: request.call_id,
+          output: error === undefined ? JSON.stringify(output ?? {}) : null,
+          error: error ?? null
+        }).catch(err => console.warn('⚠️ MCP 결과 전달 실패:', err));
+
+      console.log(`🧩 MCP 도구 실행: ${request.tool} (${request.call_id})`);
+      startWorkflowRun({
+        workflowPath: request.workflow_path,
+        prepared: request.workflow,
+        onFinish: (status, output, error) => {
+          if (status === 'success') {
+            complete(output);
+          } else {
+            complete(undefined, status === 'cancelled' ? 'WORKFLOW_CANCELLED' : error || 'WORKFLOW_FAILED');
+          }
+        }
+      }).catch(err => complete(undefined, String(err?.message ?? err)));
+    });
+
+    return () => {
+      unlisten.then(fn => fn());
+    };
+  }, [startWorkflowRun]);
+
+  // 🔀 라우팅된 메시지 → 대상 노드에 값을 넣고 트리거, 워크플로우 대상은 (열려 있지 않으면 불러와서) 시작 노드부터 실행
+  // 노드에 들어가는 값: message, sender, subject, source + 정규식의 이름 있는 캡처 그룹
+  useEffect(() => {
+    const unlisten = listenChunked<RoutedMessage>('routed-message', async (routed) => {
+      const { message, target, captures } = routed;
+      const namedCaptures = Object.fromEntries(
+        Object.entries(captures || {}).filter(([key]) => isNaN(Number(key)))
+      );
+      const values = {
+        message: message.text,
+        sender: message.sender || '',
+        subject: message.subject || '',
+        source: message.source,
+        ...namedCaptures
+      };
+
+      if (target.kind === 'workflow') {
+        const isOpen = target.workflow === await currentWorkflowName()
+          || samePath(routed.workflow_path, await currentWorkflowPath());
+        if (!isOpen && !routed.workflow_path) {
+          console.warn(`⚠️ 라우팅 대상 워크플로우를 찾을 수 없음: ${target.workflow} (규칙 ${routed.rule_id})`);
+          return;
+        }
+        console.log(`🔀 ${message.source} 메시지 → 규칙 ${routed.rule_id}: 워크플로우 ${target.workflow}`);
+        startWorkflowRun({ workflowPath: isOpen ? undefined : routed.workflow_path, values })
+          .catch(async err => {
+            console.warn('⚠️ 라우팅 실행 실패:', err);
+            await recordFailedStart(routed.workflow_path || target.workflow, err);
+          });
+        return;
+      }
+
+      const targetIds = nodesRef.current.some(n => n.id === target.node_id) ? [target.node_id] : [];
+      if (targetIds.length === 0) {
+        console.warn(`⚠️ 라우팅 대상이 현재 열린 워크플로우에 없음: 노드 ${target.node_id} (규칙 ${routed.rule_id})`);
+        return;
+      }
+
+      console.log(`🔀 ${message.source} 메시지 → 규칙 ${routed.rule_id}: ${targetIds.join(', ')}`);
+      const triggerTime = Date.now();
+      setNodes(currentNodes =>
+        currentNodes.map(node =>
+          targetIds.includes(node.id)
+            ? { ...node, data: { ...node.data, ...values, triggerExecution: triggerTime } }
+            : node
+        )
+      );
+    });
+
+    return () => {
+      unlisten.then(fn => fn());
+    };
+  }, [startWorkflowRun]);
 
   // 🎯 통합 실행 함수 (기존 호환성 유지)
   const executeNextNodes = useCallback((completedNodeId: string, outputData?: any) => {
+    if (completeReplay(completedNodeId, outputData ?? {})) return;
+
+    const run = ensureRun();
+    const nextNodeIds = edges
+      .filter(edge => edge.source === completedNodeId && edge.sourceHandle === 'trigger-output')
+      .map(edge => edge.target);
+    recordNodeRun(run, completedNodeId, outputData);
+    nextNodeIds.forEach(nodeId => run.pending.set(nodeId, Date.now()));
+    finishRunIfIdle(run);
+
     // 1단계: 데이터 먼저 전달
     if (outputData) {
       sendDataToNextNodes(completedNodeId, outputData);
     }
     
-    // 2단계: 잠시 후 트리거 전달 (데이터 전달이 완료된 후)
-    setTimeout(() => {
-      triggerNextNodes(completedNodeId);
+    // 2단계: 잠시 후 트리거 전달 (데이터 전달이 완료된 후, 브레이크포인트에서는 재개될 때까지 대기)
+    setTimeout(async () => {
+      triggerNextNodes(await pauseBeforeNodes(run, nextNodeIds));
+      finishRunIfIdle(run);
     }, 20); // 20ms 지연으로 데이터 전달 완료 보장
-  }, [sendDataToNextNodes, triggerNextNodes]);
+  }, [edges, completeReplay, ensureRun, recordNodeRun, finishRunIfIdle, sendDataToNextNodes, pauseBeforeNodes, triggerNextNodes]);
+
+  const executeNextNodesRef = useRef(executeNextNodes);
+  executeNextNodesRef.current = executeNextNodes;
+
+  useEffect(() => {
+    const unlisteners = TRIGGER_EVENTS.map(({ event, fires }) =>
+      listenChunked<any>(event, (payload) => {
+        if (!payload?.node_id || (fires && !fires(payload))) return;
+        const { node_id: nodeId, ...outputData } = payload;
+        if (!nodesRef.current.some(n => n.id === nodeId)) {
+          console.warn(`⚠️ ${event}: 현재 열린 워크플로우에 노드 ${nodeId} 없음`);
+          return;
+        }
+        console.log(`🎛️ ${event} → 노드 ${nodeId}`);
+        updateNodeData(nodeId, { outputData });
+        executeNextNodesRef.current(nodeId, outputData);
+      })
+    );
+
+    return () => {
+      unlisteners.forEach(unlisten => unlisten.then(fn => fn()));
+    };
+  }, [updateNodeData]);
+
+  // ❌ 트리거 실행 중 실패한 노드 보고 (다음 노드로는 전파하지 않음)
+  const reportNodeError = useCallback((nodeId: string, error: string) => {
+    if (completeReplay(nodeId, undefined, error)) return;
+
+    const run = ensureRun();
+    run.failed = true;
+    recordNodeRun(run, nodeId, undefined, error);
+    finishRunIfIdle(run);
+  }, [completeReplay, ensureRun, recordNodeRun, finishRunIfIdle]);
 
   // 뷰어 아이템 변경 핸들러
   const handleViewerItemsChange = useCallback((newItems) => {
@@ -153,6 +684,10 @@ function App() {
               edges={edges}
               updateNodeData={updateNodeData}
               onExecuteNextNodes={executeNextNodes}
+              onReportNodeError={reportNodeError}
+              getCurrentRunId={getCurrentRunId}
+              breakpoints={breakpoints}
+              onToggleBreakpoint={toggleBreakpoint}
               viewerItems={viewerItems}
               onViewerItemsChange={handleViewerItemsChange}
             >
@@ -163,6 +698,9 @@ function App() {
                 onEdgesChange={handleEdgesChange}
                 viewerItems={viewerItems}
                 onViewerItemsChange={handleViewerItemsChange}
+                workflowParameters={workflowParameters}
+                onWorkflowParametersChange={setWorkflowParameters}
+                onAutoLoaded={autoLoaded.resolve}
                 onGoToViewer={goToViewer}
                 updateNodeData={updateNodeData}
                 executeNextNodes={executeNextNodes}
@@ -185,6 +723,10 @@ function App() {
               edges={edges}
               updateNodeData={updateNodeData}
               onExecuteNextNodes={executeNextNodes}
+              onReportNodeError={reportNodeError}
+              getCurrentRunId={getCurrentRunId}
+              breakpoints={breakpoints}
+              onToggleBreakpoint={toggleBreakpoint}
               viewerItems={viewerItems}
               onViewerItemsChange={handleViewerItemsChange}
             >
@@ -201,6 +743,19 @@ function App() {
           </ViewerProvider>
         </ReactFlowProvider>
       </div>
+
+      {/* 🐞 브레이크포인트에서 멈춘 실행 (두 페이지 공통) */}
+      <RunDebugBar pausedRun={pausedRun} onResumed={() => setPausedRun(null)} />
+
+      {/* ✋ 승인 노드 대기 목록 (두 페이지 공통) */}
+      <ApprovalPrompt />
+
+      {/* ✍️ 불러온 워크플로우의 서명 경고, 🔀 동기화 충돌 / 오류, 🩺 시작 점검 (두 페이지 공통) */}
+      <div className="notice-stack">
+        <SignatureNotice />
+        <SyncNotice />
+        <DoctorNotice />
+      </div>
     </div>
   );
 }
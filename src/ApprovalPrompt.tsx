@@ -0,0 +1,86 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import { Hand } from 'lucide-react';
+
+/**
+ * ApprovalPrompt - 승인 노드 대기 목록
+ * 역할: approval_node 가 보낸 "approval-requested" 를 앱 화면에 띄우고 고른 선택지를 respond_approval 로 전달
+ * - 채널과 상관없이 앱에서도 답할 수 있음 (채팅 / 토스트에서 먼저 답하면 "approval-finished" 로 사라짐)
+ * - 앱을 새로 열었을 때도 list_pending_approvals 로 남아 있는 요청 복원
+ */
+
+interface PendingApproval {
+  approval_id: string;
+  node_id: string;
+  message: string;
+  options: string[];
+  channel: string;
+  chat_node_id?: string;
+  requested_at: string;
+  expires_at: string;
+}
+
+function ApprovalPrompt() {
+  const [approvals, setApprovals] = useState<PendingApproval[]>([]);
+
+  useEffect(() => {
+    const unlisteners: Promise<() => void>[] = [];
+
+    invoke<PendingApproval[]>('list_pending_approvals')
+      .then(setApprovals)
+      .catch(error => console.warn('⚠️ 승인 대기 목록 불러오기 실패:', error));
+
+    unlisteners.push(listen<PendingApproval>('approval-requested', (event) => {
+      const request = event.payload;
+      setApprovals(current => [
+        ...current.filter(item => item.approval_id !== request.approval_id),
+        request
+      ]);
+    }));
+
+    unlisteners.push(listen<{ approval_id: string }>('approval-finished', (event) => {
+      setApprovals(current => current.filter(item => item.approval_id !== event.payload.approval_id));
+    }));
+
+    return () => {
+      unlisteners.forEach(unlisten => unlisten.then(fn => fn()));
+    };
+  }, []);
+
+  const respond = useCallback(async (approvalId: string, option: string) => {
+    try {
+      await invoke('respond_approval', { approvalId, option });
+      setApprovals(current => current.filter(item => item.approval_id !== approvalId));
+    } catch (error) {
+      // 다른 채널에서 이미 답했으면 APPROVAL_NOT_FOUND
+      console.warn('⚠️ 승인 응답 실패:', error);
+      setApprovals(current => current.filter(item => item.approval_id !== approvalId));
+    }
+  }, []);
+
+  if (approvals.length === 0) return null;
+
+  return (
+    <div className="approval-prompt-list">
+      {approvals.map(approval => (
+        <div key={approval.approval_id} className="approval-prompt">
+          <div className="approval-prompt-title">
+            <Hand size={14} />
+            승인 요청 (노드 {approval.node_id})
+          </div>
+          <div className="approval-prompt-message">{approval.message}</div>
+          <div className="approval-prompt-buttons">
+            {approval.options.map(option => (
+              <button key={option} onClick={() => respond(approval.approval_id, option)}>
+                {option}
+              </button>
+            ))}
+          </div>
+        </div>
+      ))}
+    </div>
+  );
+}
+
+export default ApprovalPrompt;
@@ -0,0 +1,67 @@
+import React, { useState, useEffect } from 'react';
+import { Stethoscope, X } from 'lucide-react';
+import { listenChunked } from './EventChunks';
+
+/**
+ * DoctorNotice - 시작 점검 결과 알림
+ * 역할: 앱 시작 점검에서 문제가 있을 때 백엔드가 보낸 "doctor-report" 표시
+ * - 실패 / 경고 항목과 해결 힌트만 보여줌 (전체 결과는 doctor 명령으로 확인)
+ */
+
+interface DoctorCheck {
+  category: string;
+  name: string;
+  status: 'ok' | 'warn' | 'fail';
+  message: string;
+  hint?: string;
+}
+
+interface DoctorReport {
+  generated_at: string;
+  ok: boolean;
+  failed: number;
+  warnings: number;
+  checks: DoctorCheck[];
+}
+
+const MAX_CHECKS = 3;
+
+function DoctorNotice() {
+  const [report, setReport] = useState<DoctorReport | null>(null);
+
+  useEffect(() => {
+    const unlisten = listenChunked<DoctorReport>('doctor-report', (payload) => {
+      setReport(payload.ok ? null : payload);
+    });
+    return () => {
+      unlisten.then(fn => fn());
+    };
+  }, []);
+
+  if (!report) return null;
+
+  // 실패 항목 먼저
+  const problems = report.checks
+    .filter(check => check.status !== 'ok')
+    .sort((a, b) => (a.status === b.status ? 0 : a.status === 'fail' ? -1 : 1));
+
+  return (
+    <div className={`app-notice ${report.failed > 0 ? 'error' : 'warn'}`}>
+      <Stethoscope size={16} />
+      <div className="app-notice-body">
+        환경 점검: 실패 {report.failed} / 경고 {report.warnings}
+        {problems.slice(0, MAX_CHECKS).map(check => (
+          <div key={`${check.category}-${check.name}`}>
+            {check.name}: {check.message}{check.hint ? ` (${check.hint})` : ''}
+          </div>
+        ))}
+        {problems.length > MAX_CHECKS && <div>외 {problems.length - MAX_CHECKS}개</div>}
+      </div>
+      <button onClick={() => setReport(null)} title="닫기">
+        <X size={14} />
+      </button>
+    </div>
+  );
+}
+
+export default DoctorNotice;
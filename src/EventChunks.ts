@@ -0,0 +1,69 @@
+/**
+ * EventChunks.ts - 분할 전송된 백엔드 이벤트 수신
+ * 역할: 백엔드 emit_chunked 가 보낸 이벤트를 원래 이벤트처럼 받기
+ * - 작은 이벤트: 원래 이름으로 그대로 도착
+ * - 큰 이벤트: "event-chunk" 조각들 + "event-chunk-done" 완료 표시 → index 순서로 이어 붙여 JSON.parse
+ */
+
+import { listen, UnlistenFn } from '@tauri-apps/api/event';
+
+interface EventChunk {
+  stream_id: string;
+  event: string;
+  index: number;
+  data: string;
+}
+
+interface EventChunkDone {
+  stream_id: string;
+  event: string;
+  chunks: number;
+  size: number;
+}
+
+/**
+ * 원래 이벤트와 분할 이벤트를 모두 듣고, 완성된 payload 만 handler 로 전달
+ * 반환된 함수를 호출하면 모든 리스너 해제
+ */
+export async function listenChunked<T>(
+  eventName: string,
+  handler: (payload: T) => void
+): Promise<UnlistenFn> {
+  const streams = new Map<string, string[]>();
+
+  const unlistenDirect = await listen<T>(eventName, (event) => handler(event.payload));
+
+  const unlistenChunk = await listen<EventChunk>('event-chunk', (event) => {
+    const chunk = event.payload;
+    if (chunk.event !== eventName) return;
+    const parts = streams.get(chunk.stream_id) || [];
+    parts[chunk.index] = chunk.data;
+    streams.set(chunk.stream_id, parts);
+  });
+
+  const unlistenDone = await listen<EventChunkDone>('event-chunk-done', (event) => {
+    const done = event.payload;
+    if (done.event !== eventName) return;
+    const parts = streams.get(done.stream_id) || [];
+    streams.delete(done.stream_id);
+
+    // 조각이 빠졌으면 버림 (잘린 JSON 으로 실행하지 않도록)
+    const received = parts.filter(part => part !== undefined).length;
+    if (received !== done.chunks) {
+      console.error(`❌ ${eventName}: 조각 누락 (${received}/${done.chunks})`);
+      return;
+    }
+
+    try {
+      handler(JSON.parse(parts.join('')) as T);
+    } catch (error) {
+      console.error(`❌ ${eventName}: 분할 이벤트 파싱 실패`, error);
+    }
+  });
+
+  return () => {
+    unlistenDirect();
+    unlistenChunk();
+    unlistenDone();
+  };
+}
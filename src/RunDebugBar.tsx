@@ -0,0 +1,54 @@
+import React, { useCallback } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { StepForward, Play, Square } from 'lucide-react';
+
+/**
+ * RunDebugBar - 브레이크포인트에서 멈춘 실행 표시
+ * 역할: "run-paused" 로 받은 실행을 보여주고 step_run / continue_run / abort_run 으로 재개
+ * (멈춘 노드의 입력값은 values 에 있음)
+ */
+
+export interface PausedRun {
+  run_id: string;
+  workflow: string;
+  node_id: string;
+  node_type: string;
+  reason: string; // "breakpoint" | "step"
+  values: any;
+  paused_at: string;
+}
+
+function RunDebugBar({ pausedRun, onResumed }: { pausedRun: PausedRun | null; onResumed: () => void }) {
+  const resume = useCallback(async (command: 'step_run' | 'continue_run' | 'abort_run') => {
+    if (!pausedRun) return;
+    try {
+      await invoke(command, { runId: pausedRun.run_id });
+    } catch (error) {
+      // 이미 재개된 실행이면 RUN_NOT_PAUSED
+      console.warn(`⚠️ ${command} 실패:`, error);
+    }
+    onResumed();
+  }, [pausedRun, onResumed]);
+
+  if (!pausedRun) return null;
+
+  return (
+    <div className="run-debug-bar">
+      <span className="run-debug-info">
+        {pausedRun.reason === 'breakpoint' ? '🔴 브레이크포인트' : '👣 단계 실행'}: 노드 {pausedRun.node_id}
+        {pausedRun.node_type && ` (${pausedRun.node_type})`}
+      </span>
+      <button onClick={() => resume('step_run')} title="Step">
+        <StepForward size={14} />
+      </button>
+      <button onClick={() => resume('continue_run')} title="Continue">
+        <Play size={14} />
+      </button>
+      <button onClick={() => resume('abort_run')} title="Abort">
+        <Square size={14} />
+      </button>
+    </div>
+  );
+}
+
+export default RunDebugBar;
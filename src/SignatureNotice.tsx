@@ -0,0 +1,55 @@
+import React, { useState, useEffect } from 'react';
+import { listen } from '@tauri-apps/api/event';
+import { ShieldAlert, X } from 'lucide-react';
+
+/**
+ * SignatureNotice - 워크플로우 서명 경고
+ * 역할: 워크플로우를 불러올 때 백엔드가 보낸 "workflow-signature" (서명 없음 / 변조 / 신뢰하지 않는 서명자) 표시
+ * - 경고만 하고 불러오기는 막지 않음 (닫기 전까지 남아 있음)
+ */
+
+interface Verification {
+  status: 'valid' | 'untrusted' | 'unsigned' | 'tampered';
+  signer?: string;
+  public_key?: string;
+  signed_at?: string;
+  trusted: boolean;
+}
+
+const describe = (verification: Verification): string => {
+  switch (verification.status) {
+    case 'tampered':
+      return '서명이 맞지 않습니다. 서명 이후 파일이 바뀌었을 수 있습니다.';
+    case 'untrusted':
+      return `신뢰하지 않는 서명자의 워크플로우입니다: ${verification.signer || '-'}`;
+    default:
+      return '서명되지 않은 워크플로우입니다. 출처를 확인한 뒤 실행하세요.';
+  }
+};
+
+function SignatureNotice() {
+  const [verification, setVerification] = useState<Verification | null>(null);
+
+  useEffect(() => {
+    const unlisten = listen<Verification>('workflow-signature', (event) => {
+      setVerification(event.payload.status === 'valid' ? null : event.payload);
+    });
+    return () => {
+      unlisten.then(fn => fn());
+    };
+  }, []);
+
+  if (!verification) return null;
+
+  return (
+    <div className={`app-notice ${verification.status}`}>
+      <ShieldAlert size={16} />
+      <span>{describe(verification)}</span>
+      <button onClick={() => setVerification(null)} title="닫기">
+        <X size={14} />
+      </button>
+    </div>
+  );
+}
+
+export default SignatureNotice;
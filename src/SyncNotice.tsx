@@ -0,0 +1,76 @@
+import React, { useState, useEffect } from 'react';
+import { Cloud, GitMerge, X } from 'lucide-react';
+import { listenChunked } from './EventChunks';
+
+/**
+ * SyncNotice - 워크플로우 폴더 동기화 알림
+ * 역할: 백엔드가 보낸 "git-sync-conflict" / "git-sync-error", "cloud-sync-conflict" / "cloud-sync-error" 표시
+ * - Git 충돌은 저장소에서, 클라우드 충돌은 sync_now(resolve) 로 풀어야 하므로 파일 목록만 보여줌 (닫기 전까지 남아 있음)
+ */
+
+interface GitSyncReport {
+  committed?: string;
+  pulled: boolean;
+  pushed: boolean;
+  conflicts: string[];
+}
+
+interface CloudSyncReport {
+  uploaded: string[];
+  downloaded: string[];
+  deleted_remote: string[];
+  deleted_local: string[];
+  conflicts: string[];
+  revision: number;
+}
+
+interface Notice {
+  source: 'git' | 'cloud';
+  kind: 'conflict' | 'error';
+  text: string;
+}
+
+const MAX_FILES = 5;
+
+const listFiles = (files: string[]): string =>
+  files.length > MAX_FILES
+    ? `${files.slice(0, MAX_FILES).join(', ')} 외 ${files.length - MAX_FILES}개`
+    : files.join(', ');
+
+function SyncNotice() {
+  const [notice, setNotice] = useState<Notice | null>(null);
+
+  useEffect(() => {
+    const unlisteners = [
+      listenChunked<GitSyncReport>('git-sync-conflict', (report) => {
+        setNotice({ source: 'git', kind: 'conflict', text: `Git 동기화 충돌: ${listFiles(report.conflicts)}` });
+      }),
+      listenChunked<string>('git-sync-error', (error) => {
+        setNotice({ source: 'git', kind: 'error', text: `Git 동기화 실패: ${error}` });
+      }),
+      listenChunked<CloudSyncReport>('cloud-sync-conflict', (report) => {
+        setNotice({ source: 'cloud', kind: 'conflict', text: `클라우드 동기화 충돌: ${listFiles(report.conflicts)}` });
+      }),
+      listenChunked<string>('cloud-sync-error', (error) => {
+        setNotice({ source: 'cloud', kind: 'error', text: `클라우드 동기화 실패: ${error}` });
+      })
+    ];
+    return () => {
+      unlisteners.forEach(unlisten => unlisten.then(fn => fn()));
+    };
+  }, []);
+
+  if (!notice) return null;
+
+  return (
+    <div className={`app-notice ${notice.kind}`}>
+      {notice.source === 'git' ? <GitMerge size={16} /> : <Cloud size={16} />}
+      <span>{notice.text}</span>
+      <button onClick={() => setNotice(null)} title="닫기">
+        <X size={14} />
+      </button>
+    </div>
+  );
+}
+
+export default SyncNotice;
@@ -16,6 +16,12 @@ export function WorkflowProvider({
   edges, 
   updateNodeData,
   onExecuteNextNodes,
+  onReportNodeError,
+  // 진행 중인 실행의 run_id (로그 노드가 실행 기록에 남길 때 사용)
+  getCurrentRunId,
+  // 브레이크포인트 (노드 ID 목록)
+  breakpoints,
+  onToggleBreakpoint,
   // 뷰어 관련 props
   viewerItems,
   onViewerItemsChange
@@ -103,9 +109,14 @@ export function WorkflowProvider({
     allEdges: edges,
     updateNodeData,
     executeNextNodes: onExecuteNextNodes,
+    // 트리거 실행 실패 보고 (실행 기록용)
+    reportNodeError: onReportNodeError,
+    getCurrentRunId,
+    breakpoints,
+    toggleBreakpoint: onToggleBreakpoint,
     // 🆕 뷰어 관련 함수들
     viewerActions
-  }), [nodes, edges, updateNodeData, onExecuteNextNodes, viewerActions]);
+  }), [nodes, edges, updateNodeData, onExecuteNextNodes, onReportNodeError, getCurrentRunId, breakpoints, onToggleBreakpoint, viewerActions]);
 
   return (
     <WorkflowContext.Provider value={contextValue}>
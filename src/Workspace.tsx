@@ -57,6 +57,9 @@ interface WorkspaceProps {
   onEdgesChange: (edges: Edge[]) => void;
   viewerItems: any[];
   onViewerItemsChange: (items: any[]) => void;
+  workflowParameters: any[]; // 워크플로우 입력 파라미터 정의 (캔버스에는 없고 파일의 "parameters" 에 저장)
+  onWorkflowParametersChange: (parameters: any[]) => void;
+  onAutoLoaded?: () => void; // 마지막 워크플로우 자동 로드가 끝났을 때 (실패해도 호출)
   onGoToViewer: () => void;
   updateNodeData: (nodeId: string, newData: Partial<BaseNodeData>) => void;
   executeNextNodes: (nodeId: string) => void;
@@ -69,6 +72,9 @@ function Workspace({
   onEdgesChange,
   viewerItems,
   onViewerItemsChange,
+  workflowParameters,
+  onWorkflowParametersChange,
+  onAutoLoaded,
   onGoToViewer,
   updateNodeData: updateNodeDataProp,
   executeNextNodes: executeNextNodesProp
@@ -157,6 +163,7 @@ function Workspace({
             }
             if (Array.isArray(flow.edges)) onEdgesChange(flow.edges);
             if (flow.viewport) reactFlowInstance.setViewport(flow.viewport);
+            onWorkflowParametersChange(Array.isArray(flow.parameters) ? flow.parameters : []);
             
             if (Array.isArray(flow.viewerItems)) {
               const currentNodeIds = new Set(flow.nodes?.map((node: Node) => node.id as string) || []);
@@ -174,6 +181,8 @@ function Workspace({
           const store = await getAppStore();
           await store.delete('lastSavedWorkflow');
         }
+      } finally {
+        onAutoLoaded?.();
       }
     };
 
@@ -474,7 +483,8 @@ function Workspace({
       
       const workflowData = {
         ...flow,
-        viewerItems
+        viewerItems,
+        ...(workflowParameters.length > 0 ? { parameters: workflowParameters } : {})
       };
       
       const result = await invoke('save_workflow_to_desktop', { 
@@ -500,7 +510,7 @@ function Workspace({
         alert('❌ Save failed: ' + (error?.message || error));
       }
     }
-  }, [reactFlowInstance, viewerItems]);
+  }, [reactFlowInstance, viewerItems, workflowParameters]);
 
   // 워크플로우 불러오기
   const loadWorkflow = useCallback(async () => {
@@ -520,6 +530,7 @@ function Workspace({
       }
       if (Array.isArray(flow.edges)) onEdgesChange(flow.edges);
       if (flow.viewport) reactFlowInstance.setViewport(flow.viewport);
+      onWorkflowParametersChange(Array.isArray(flow.parameters) ? flow.parameters : []);
       
       if (Array.isArray(flow.viewerItems)) {
         const currentNodeIds = new Set(flow.nodes?.map((node: Node) => node.id as string) || []);
@@ -541,7 +552,7 @@ function Workspace({
         alert('❌ Load failed: ' + (error?.message || error));
       }
     }
-  }, [reactFlowInstance, onNodesChange, onEdgesChange, nodeManager, cleanupViewerItems, onViewerItemsChange]);
+  }, [reactFlowInstance, onNodesChange, onEdgesChange, nodeManager, cleanupViewerItems, onViewerItemsChange, onWorkflowParametersChange]);
 
   // 워크플로우 추가
   const appendWorkflow = useCallback(async () => {
@@ -0,0 +1,260 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { BarChart3, Settings, Tag, Hash, Calendar, Plus, ArrowDown, ArrowUp, List } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toNumber, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function AggregateNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localAction, setLocalAction] = useState('');
+  const [localName, setLocalName] = useState('');
+  const [localValue, setLocalValue] = useState('');
+  const [localPeriod, setLocalPeriod] = useState('');
+
+  const isActionConnected = useHandleConnection(id, 'action');
+  const isNameConnected = useHandleConnection(id, 'name');
+  const isValueConnected = useHandleConnection(id, 'value');
+  const isPeriodConnected = useHandleConnection(id, 'period');
+
+  useEffect(() => {
+    setLocalAction(data?.action || '');
+    setLocalName(data?.name || '');
+    setLocalValue(data?.value || '');
+    setLocalPeriod(data?.period || '');
+  }, [data?.action, data?.name, data?.value, data?.period]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'action' && !isActionConnected && data.action !== value) updateNodeData(id, { action: value });
+    if (key === 'name' && !isNameConnected && data.name !== value) updateNodeData(id, { name: value });
+    if (key === 'value' && !isValueConnected && data.value !== value) updateNodeData(id, { value: value });
+    if (key === 'period' && !isPeriodConnected && data.period !== value) updateNodeData(id, { period: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentAction = data?.action?.trim() || '';
+    const currentName = data?.name?.trim() || '';
+    const currentValue = data?.value?.trim() || '';
+    const currentPeriod = data?.period?.trim() || '';
+
+    if (!currentAction) {
+      console.warn('⚠️ AggregateNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Action is required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Action is required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        action: currentAction,
+        name: currentName || undefined,
+        value: toNumber(currentValue),
+        period: currentPeriod || undefined
+      };
+
+      console.log(`📊 AggregateNode ${id}: Aggregate... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('aggregate_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        count: toText(parsed.count),
+        sum: toText(parsed.sum),
+        avg: toText(parsed.avg),
+        min: toText(parsed.min),
+        max: toText(parsed.max),
+        aggregates: toText(parsed.aggregates)
+      };
+
+      setStatus('completed');
+      setResult(parsed.aggregates ? `${parsed.aggregates.length} counters` : `${parsed.name}: ${parsed.count}`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 AggregateNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 AggregateNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Aggregate failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.action, data?.name, data?.value, data?.period, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`📊 Aggregate node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Aggregate"
+      icon={<BarChart3 size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Named counter kept between runs (count, sum, min, max) with daily / weekly / monthly reset"
+    >
+      <div onBlur={() => handleBlur('action', localAction)}>
+        <InputField
+          nodeId={id}
+          label="Action"
+          icon={<Settings size={12} />}
+          value={localAction}
+          placeholder="increment | reset | read | list"
+          onChange={setLocalAction}
+          handleId="action"
+          disabled={isActionConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('name', localName)}>
+        <InputField
+          nodeId={id}
+          label="Counter Name"
+          icon={<Tag size={12} />}
+          value={localName}
+          placeholder="files-processed"
+          onChange={setLocalName}
+          handleId="name"
+          disabled={isNameConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('value', localValue)}>
+        <InputField
+          nodeId={id}
+          label="Value"
+          icon={<Hash size={12} />}
+          value={localValue}
+          placeholder="1"
+          onChange={setLocalValue}
+          handleId="value"
+          disabled={isValueConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('period', localPeriod)}>
+        <InputField
+          nodeId={id}
+          label="Period"
+          icon={<Calendar size={12} />}
+          value={localPeriod}
+          placeholder="total | day | week | month"
+          onChange={setLocalPeriod}
+          handleId="period"
+          disabled={isPeriodConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Count"
+        icon={<Hash size={12} />}
+        value={data.outputData?.count || ''}
+        handleId="count"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Sum"
+        icon={<Plus size={12} />}
+        value={data.outputData?.sum || ''}
+        handleId="sum"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Average"
+        icon={<BarChart3 size={12} />}
+        value={data.outputData?.avg || ''}
+        handleId="avg"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Min"
+        icon={<ArrowDown size={12} />}
+        value={data.outputData?.min || ''}
+        handleId="min"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Max"
+        icon={<ArrowUp size={12} />}
+        value={data.outputData?.max || ''}
+        handleId="max"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="All Counters"
+        icon={<List size={12} />}
+        value={data.outputData?.aggregates || ''}
+        handleId="aggregates"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'aggregateNode',
+  label: 'Aggregate',
+  color: '#8B5CF6',
+  category: 'Data',
+  settings: [
+    { key: 'action', type: 'text', label: 'Action', default: 'increment' },
+    { key: 'name', type: 'text', label: 'Counter Name', default: '' },
+    { key: 'value', type: 'text', label: 'Value', default: '' },
+    { key: 'period', type: 'text', label: 'Period', default: '' }
+  ]
+};
+
+export default AggregateNode;
\ No newline at end of file
@@ -0,0 +1,253 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { Braces, FileText, KeyRound, Cpu, RotateCcw, History } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toNumber, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function AiExtractNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localText, setLocalText] = useState('');
+  const [localJsonSchema, setLocalJsonSchema] = useState('');
+  const [localApiKey, setLocalApiKey] = useState('');
+  const [localModel, setLocalModel] = useState('');
+  const [localMaxRetries, setLocalMaxRetries] = useState('');
+
+  const isTextConnected = useHandleConnection(id, 'text');
+  const isJsonSchemaConnected = useHandleConnection(id, 'jsonSchema');
+  const isApiKeyConnected = useHandleConnection(id, 'apiKey');
+  const isModelConnected = useHandleConnection(id, 'model');
+  const isMaxRetriesConnected = useHandleConnection(id, 'maxRetries');
+
+  useEffect(() => {
+    setLocalText(data?.text || '');
+    setLocalJsonSchema(data?.jsonSchema || '');
+    setLocalApiKey(data?.apiKey || '');
+    setLocalModel(data?.model || '');
+    setLocalMaxRetries(data?.maxRetries || '');
+  }, [data?.text, data?.jsonSchema, data?.apiKey, data?.model, data?.maxRetries]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'text' && !isTextConnected && data.text !== value) updateNodeData(id, { text: value });
+    if (key === 'jsonSchema' && !isJsonSchemaConnected && data.jsonSchema !== value) updateNodeData(id, { jsonSchema: value });
+    if (key === 'apiKey' && !isApiKeyConnected && data.apiKey !== value) updateNodeData(id, { apiKey: value });
+    if (key === 'model' && !isModelConnected && data.model !== value) updateNodeData(id, { model: value });
+    if (key === 'maxRetries' && !isMaxRetriesConnected && data.maxRetries !== value) updateNodeData(id, { maxRetries: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentText = data?.text || '';
+    const currentJsonSchema = data?.jsonSchema || '';
+    const currentApiKey = data?.apiKey?.trim() || '';
+    const currentModel = data?.model?.trim() || '';
+    const currentMaxRetries = data?.maxRetries?.trim() || '';
+
+    if (!currentText || !currentJsonSchema || !currentApiKey || !currentModel) {
+      console.warn('⚠️ AiExtractNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Text, JSON Schema, API Key and Model are required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Text, JSON Schema, API Key and Model are required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        text: currentText,
+        jsonSchema: currentJsonSchema,
+        apiKey: currentApiKey,
+        model: currentModel,
+        maxRetries: toNumber(currentMaxRetries)
+      };
+
+      console.log(`🧠 AiExtractNode ${id}: Extracting structured data... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('ai_extract_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        data: toText(parsed.data),
+        attempts: toText(parsed.attempts),
+        repairHistory: toText(parsed.repair_history)
+      };
+
+      setStatus('completed');
+      setResult(`Valid JSON after ${parsed.attempts} attempt(s)`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 AiExtractNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 AiExtractNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ AI extraction failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.text, data?.jsonSchema, data?.apiKey, data?.model, data?.maxRetries, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`🧠 AI Extract node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="AI Extract"
+      icon={<Braces size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Extracts JSON matching a schema from text with Claude, retrying with repair prompts"
+    >
+      <div onBlur={() => handleBlur('text', localText)}>
+        <InputField
+          nodeId={id}
+          label="Text"
+          icon={<FileText size={12} />}
+          value={localText}
+          placeholder="Source text"
+          onChange={setLocalText}
+          handleId="text"
+          disabled={isTextConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('jsonSchema', localJsonSchema)}>
+        <InputField
+          nodeId={id}
+          label="JSON Schema"
+          icon={<Braces size={12} />}
+          value={localJsonSchema}
+          placeholder={'{"type":"object","properties":{...}}'}
+          onChange={setLocalJsonSchema}
+          handleId="jsonSchema"
+          disabled={isJsonSchemaConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('apiKey', localApiKey)}>
+        <InputField
+          nodeId={id}
+          label="API Key"
+          icon={<KeyRound size={12} />}
+          value={localApiKey}
+          placeholder="${secret:anthropic}"
+          onChange={setLocalApiKey}
+          handleId="apiKey"
+          disabled={isApiKeyConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('model', localModel)}>
+        <InputField
+          nodeId={id}
+          label="Model"
+          icon={<Cpu size={12} />}
+          value={localModel}
+          placeholder="Claude model id"
+          onChange={setLocalModel}
+          handleId="model"
+          disabled={isModelConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('maxRetries', localMaxRetries)}>
+        <InputField
+          nodeId={id}
+          label="Max Retries"
+          icon={<RotateCcw size={12} />}
+          value={localMaxRetries}
+          placeholder="2"
+          onChange={setLocalMaxRetries}
+          handleId="maxRetries"
+          disabled={isMaxRetriesConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Data"
+        icon={<Braces size={12} />}
+        value={data.outputData?.data || ''}
+        handleId="data"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Attempts"
+        icon={<RotateCcw size={12} />}
+        value={data.outputData?.attempts || ''}
+        handleId="attempts"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Repair History"
+        icon={<History size={12} />}
+        value={data.outputData?.repairHistory || ''}
+        handleId="repairHistory"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'aiExtractNode',
+  label: 'AI Extract',
+  color: '#7C4DFF',
+  category: 'AI',
+  settings: [
+    { key: 'text', type: 'text', label: 'Text', default: '' },
+    { key: 'jsonSchema', type: 'text', label: 'JSON Schema', default: '' },
+    { key: 'apiKey', type: 'text', label: 'API Key', default: '' },
+    { key: 'model', type: 'text', label: 'Model', default: '' },
+    { key: 'maxRetries', type: 'text', label: 'Max Retries', default: '' }
+  ]
+};
+
+export default AiExtractNode;
\ No newline at end of file
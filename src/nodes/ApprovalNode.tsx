@@ -0,0 +1,274 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { Hand, MessageSquare, ListChecks, Radio, Timer, CheckCircle, MessagesSquare, Hash } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toNumber, toList, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function ApprovalNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localMessage, setLocalMessage] = useState('');
+  const [localOptions, setLocalOptions] = useState('');
+  const [localChannel, setLocalChannel] = useState('');
+  const [localTimeoutSecs, setLocalTimeoutSecs] = useState('');
+  const [localDefaultOption, setLocalDefaultOption] = useState('');
+  const [localChatNodeId, setLocalChatNodeId] = useState('');
+
+  const isMessageConnected = useHandleConnection(id, 'message');
+  const isOptionsConnected = useHandleConnection(id, 'options');
+  const isChannelConnected = useHandleConnection(id, 'channel');
+  const isTimeoutSecsConnected = useHandleConnection(id, 'timeoutSecs');
+  const isDefaultOptionConnected = useHandleConnection(id, 'defaultOption');
+  const isChatNodeIdConnected = useHandleConnection(id, 'chatNodeId');
+
+  useEffect(() => {
+    setLocalMessage(data?.message || '');
+    setLocalOptions(data?.options || '');
+    setLocalChannel(data?.channel || '');
+    setLocalTimeoutSecs(data?.timeoutSecs || '');
+    setLocalDefaultOption(data?.defaultOption || '');
+    setLocalChatNodeId(data?.chatNodeId || '');
+  }, [data?.message, data?.options, data?.channel, data?.timeoutSecs, data?.defaultOption, data?.chatNodeId]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'message' && !isMessageConnected && data.message !== value) updateNodeData(id, { message: value });
+    if (key === 'options' && !isOptionsConnected && data.options !== value) updateNodeData(id, { options: value });
+    if (key === 'channel' && !isChannelConnected && data.channel !== value) updateNodeData(id, { channel: value });
+    if (key === 'timeoutSecs' && !isTimeoutSecsConnected && data.timeoutSecs !== value) updateNodeData(id, { timeoutSecs: value });
+    if (key === 'defaultOption' && !isDefaultOptionConnected && data.defaultOption !== value) updateNodeData(id, { defaultOption: value });
+    if (key === 'chatNodeId' && !isChatNodeIdConnected && data.chatNodeId !== value) updateNodeData(id, { chatNodeId: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentMessage = data?.message || '';
+    const currentOptions = data?.options?.trim() || '';
+    const currentChannel = data?.channel?.trim() || '';
+    const currentTimeoutSecs = data?.timeoutSecs?.trim() || '';
+    const currentDefaultOption = data?.defaultOption?.trim() || '';
+    const currentChatNodeId = data?.chatNodeId?.trim() || '';
+
+    if (!currentMessage) {
+      console.warn('⚠️ ApprovalNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Message is required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Message is required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        nodeId: id,
+        message: currentMessage,
+        options: toList(currentOptions),
+        timeoutSecs: toNumber(currentTimeoutSecs),
+        channel: currentChannel || undefined,
+        defaultOption: currentDefaultOption || undefined,
+        chatNodeId: currentChatNodeId || undefined
+      };
+
+      console.log(`✋ ApprovalNode ${id}: Waiting for approval... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('approval_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        selected: toText(parsed.selected),
+        index: toText(parsed.index),
+        timedOut: toText(parsed.timed_out)
+      };
+
+      setStatus('completed');
+      setResult(`Selected: ${parsed.selected}`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 ApprovalNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 ApprovalNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Approval failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.message, data?.options, data?.channel, data?.timeoutSecs, data?.defaultOption, data?.chatNodeId, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`✋ Approval node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Approval"
+      icon={<Hand size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Pauses the run until someone picks an option (dialog, chat, notification or app)"
+    >
+      <div onBlur={() => handleBlur('message', localMessage)}>
+        <InputField
+          nodeId={id}
+          label="Message"
+          icon={<MessageSquare size={12} />}
+          value={localMessage}
+          placeholder="Deploy to production?"
+          onChange={setLocalMessage}
+          handleId="message"
+          disabled={isMessageConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('options', localOptions)}>
+        <InputField
+          nodeId={id}
+          label="Options"
+          icon={<ListChecks size={12} />}
+          value={localOptions}
+          placeholder="승인, 거절"
+          onChange={setLocalOptions}
+          handleId="options"
+          disabled={isOptionsConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('channel', localChannel)}>
+        <InputField
+          nodeId={id}
+          label="Channel"
+          icon={<Radio size={12} />}
+          value={localChannel}
+          placeholder="dialog | chat | notification | app"
+          onChange={setLocalChannel}
+          handleId="channel"
+          disabled={isChannelConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('timeoutSecs', localTimeoutSecs)}>
+        <InputField
+          nodeId={id}
+          label="Timeout (sec)"
+          icon={<Timer size={12} />}
+          value={localTimeoutSecs}
+          placeholder="600"
+          onChange={setLocalTimeoutSecs}
+          handleId="timeoutSecs"
+          disabled={isTimeoutSecsConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('defaultOption', localDefaultOption)}>
+        <InputField
+          nodeId={id}
+          label="Default Option"
+          icon={<CheckCircle size={12} />}
+          value={localDefaultOption}
+          placeholder="Used when the timeout passes"
+          onChange={setLocalDefaultOption}
+          handleId="defaultOption"
+          disabled={isDefaultOptionConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('chatNodeId', localChatNodeId)}>
+        <InputField
+          nodeId={id}
+          label="Chat Server Node"
+          icon={<MessagesSquare size={12} />}
+          value={localChatNodeId}
+          placeholder="Chat server node ID (chat)"
+          onChange={setLocalChatNodeId}
+          handleId="chatNodeId"
+          disabled={isChatNodeIdConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Selected"
+        icon={<CheckCircle size={12} />}
+        value={data.outputData?.selected || ''}
+        handleId="selected"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Option Index"
+        icon={<Hash size={12} />}
+        value={data.outputData?.index || ''}
+        handleId="index"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Timed Out"
+        icon={<Timer size={12} />}
+        value={data.outputData?.timedOut || ''}
+        handleId="timedOut"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'approvalNode',
+  label: 'Approval',
+  color: '#F59E0B',
+  category: 'Control',
+  settings: [
+    { key: 'message', type: 'text', label: 'Message', default: '' },
+    { key: 'options', type: 'text', label: 'Options', default: '' },
+    { key: 'channel', type: 'text', label: 'Channel', default: 'dialog' },
+    { key: 'timeoutSecs', type: 'text', label: 'Timeout (sec)', default: '' },
+    { key: 'defaultOption', type: 'text', label: 'Default Option', default: '' },
+    { key: 'chatNodeId', type: 'text', label: 'Chat Server Node', default: '' }
+  ]
+};
+
+export default ApprovalNode;
\ No newline at end of file
@@ -0,0 +1,233 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { ShieldCheck, ListChecks, FileText, MessageSquare, AlertTriangle, CheckCircle, List } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toJson, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function AssertNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localCondition, setLocalCondition] = useState('');
+  const [localValue, setLocalValue] = useState('');
+  const [localMessage, setLocalMessage] = useState('');
+  const [localSeverity, setLocalSeverity] = useState('');
+
+  const isConditionConnected = useHandleConnection(id, 'condition');
+  const isValueConnected = useHandleConnection(id, 'value');
+  const isMessageConnected = useHandleConnection(id, 'message');
+  const isSeverityConnected = useHandleConnection(id, 'severity');
+
+  useEffect(() => {
+    setLocalCondition(data?.condition || '');
+    setLocalValue(data?.value || '');
+    setLocalMessage(data?.message || '');
+    setLocalSeverity(data?.severity || '');
+  }, [data?.condition, data?.value, data?.message, data?.severity]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'condition' && !isConditionConnected && data.condition !== value) updateNodeData(id, { condition: value });
+    if (key === 'value' && !isValueConnected && data.value !== value) updateNodeData(id, { value: value });
+    if (key === 'message' && !isMessageConnected && data.message !== value) updateNodeData(id, { message: value });
+    if (key === 'severity' && !isSeverityConnected && data.severity !== value) updateNodeData(id, { severity: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentCondition = data?.condition?.trim() || '';
+    const currentValue = data?.value || '';
+    const currentMessage = data?.message || '';
+    const currentSeverity = data?.severity?.trim() || '';
+
+    if (!currentCondition) {
+      console.warn('⚠️ AssertNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Condition is required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Condition is required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        condition: toJson(currentCondition),
+        value: currentValue || undefined,
+        message: currentMessage || undefined,
+        severity: currentSeverity || undefined
+      };
+
+      console.log(`✅ AssertNode ${id}: Checking... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('assert_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        value: currentValue,
+        passed: toText(parsed.passed),
+        failures: toText(parsed.failures)
+      };
+
+      setStatus('completed');
+      setResult(parsed.passed ? 'All checks passed' : `Warning: ${parsed.message}`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 AssertNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 AssertNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Assertion failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.condition, data?.value, data?.message, data?.severity, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`✅ Assert node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Assert"
+      icon={<ShieldCheck size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Fail (or warn) when the data does not match the expected checks"
+    >
+      <div onBlur={() => handleBlur('condition', localCondition)}>
+        <InputField
+          nodeId={id}
+          label="Condition"
+          icon={<ListChecks size={12} />}
+          value={localCondition}
+          placeholder={'{"op": "gte", "path": "rows", "value": 1}'}
+          onChange={setLocalCondition}
+          handleId="condition"
+          disabled={isConditionConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('value', localValue)}>
+        <InputField
+          nodeId={id}
+          label="Value"
+          icon={<FileText size={12} />}
+          value={localValue}
+          placeholder="Data to check (JSON or text)"
+          onChange={setLocalValue}
+          handleId="value"
+          disabled={isValueConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('message', localMessage)}>
+        <InputField
+          nodeId={id}
+          label="Message"
+          icon={<MessageSquare size={12} />}
+          value={localMessage}
+          placeholder="Expected rows: {{value.rows}}"
+          onChange={setLocalMessage}
+          handleId="message"
+          disabled={isMessageConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('severity', localSeverity)}>
+        <InputField
+          nodeId={id}
+          label="Severity"
+          icon={<AlertTriangle size={12} />}
+          value={localSeverity}
+          placeholder="error | warn"
+          onChange={setLocalSeverity}
+          handleId="severity"
+          disabled={isSeverityConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Value"
+        icon={<FileText size={12} />}
+        value={data.outputData?.value || ''}
+        handleId="value"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Passed"
+        icon={<CheckCircle size={12} />}
+        value={data.outputData?.passed || ''}
+        handleId="passed"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Failures"
+        icon={<List size={12} />}
+        value={data.outputData?.failures || ''}
+        handleId="failures"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'assertNode',
+  label: 'Assert',
+  color: '#16A34A',
+  category: 'Control',
+  settings: [
+    { key: 'condition', type: 'text', label: 'Condition', default: '' },
+    { key: 'value', type: 'text', label: 'Value', default: '' },
+    { key: 'message', type: 'text', label: 'Message', default: '' },
+    { key: 'severity', type: 'text', label: 'Severity', default: 'error' }
+  ]
+};
+
+export default AssertNode;
\ No newline at end of file
@@ -0,0 +1,293 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { Barcode, Type, Image, MoveHorizontal, MoveVertical, CaseSensitive, FolderOpen, File } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toNumber, toBool, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function BarcodeNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localValue, setLocalValue] = useState('');
+  const [localSymbology, setLocalSymbology] = useState('');
+  const [localFormat, setLocalFormat] = useState('');
+  const [localModuleWidth, setLocalModuleWidth] = useState('');
+  const [localHeight, setLocalHeight] = useState('');
+  const [localShowText, setLocalShowText] = useState('');
+  const [localOutputPath, setLocalOutputPath] = useState('');
+
+  const isValueConnected = useHandleConnection(id, 'value');
+  const isSymbologyConnected = useHandleConnection(id, 'symbology');
+  const isFormatConnected = useHandleConnection(id, 'format');
+  const isModuleWidthConnected = useHandleConnection(id, 'moduleWidth');
+  const isHeightConnected = useHandleConnection(id, 'height');
+  const isShowTextConnected = useHandleConnection(id, 'showText');
+  const isOutputPathConnected = useHandleConnection(id, 'outputPath');
+
+  useEffect(() => {
+    setLocalValue(data?.value || '');
+    setLocalSymbology(data?.symbology || '');
+    setLocalFormat(data?.format || '');
+    setLocalModuleWidth(data?.moduleWidth || '');
+    setLocalHeight(data?.height || '');
+    setLocalShowText(data?.showText || '');
+    setLocalOutputPath(data?.outputPath || '');
+  }, [data?.value, data?.symbology, data?.format, data?.moduleWidth, data?.height, data?.showText, data?.outputPath]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'value' && !isValueConnected && data.value !== value) updateNodeData(id, { value: value });
+    if (key === 'symbology' && !isSymbologyConnected && data.symbology !== value) updateNodeData(id, { symbology: value });
+    if (key === 'format' && !isFormatConnected && data.format !== value) updateNodeData(id, { format: value });
+    if (key === 'moduleWidth' && !isModuleWidthConnected && data.moduleWidth !== value) updateNodeData(id, { moduleWidth: value });
+    if (key === 'height' && !isHeightConnected && data.height !== value) updateNodeData(id, { height: value });
+    if (key === 'showText' && !isShowTextConnected && data.showText !== value) updateNodeData(id, { showText: value });
+    if (key === 'outputPath' && !isOutputPathConnected && data.outputPath !== value) updateNodeData(id, { outputPath: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentValue = data?.value?.trim() || '';
+    const currentSymbology = data?.symbology?.trim() || '';
+    const currentFormat = data?.format?.trim() || '';
+    const currentModuleWidth = data?.moduleWidth?.trim() || '';
+    const currentHeight = data?.height?.trim() || '';
+    const currentShowText = data?.showText?.trim() || '';
+    const currentOutputPath = data?.outputPath?.trim() || '';
+
+    if (!currentValue) {
+      console.warn('⚠️ BarcodeNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Data is required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Data is required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        data: currentValue,
+        symbology: currentSymbology || undefined,
+        format: currentFormat || undefined,
+        moduleWidth: toNumber(currentModuleWidth),
+        height: toNumber(currentHeight),
+        showText: toBool(currentShowText),
+        outputPath: currentOutputPath || undefined
+      };
+
+      console.log(`📊 BarcodeNode ${id}: Generating barcode... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('barcode_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        filePath: toText(parsed.file_path),
+        image: toText(parsed.image_base64 ?? parsed.svg),
+        text: toText(parsed.text)
+      };
+
+      setStatus('completed');
+      setResult(`${parsed.symbology} ${parsed.text}`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 BarcodeNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 BarcodeNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Barcode generation failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.value, data?.symbology, data?.format, data?.moduleWidth, data?.height, data?.showText, data?.outputPath, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`📊 Barcode node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Barcode"
+      icon={<Barcode size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Generates a Code 128 or EAN-13 barcode as PNG or SVG"
+    >
+      <div onBlur={() => handleBlur('value', localValue)}>
+        <InputField
+          nodeId={id}
+          label="Data"
+          icon={<Type size={12} />}
+          value={localValue}
+          placeholder="ABC-12345"
+          onChange={setLocalValue}
+          handleId="value"
+          disabled={isValueConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('symbology', localSymbology)}>
+        <InputField
+          nodeId={id}
+          label="Symbology"
+          icon={<Barcode size={12} />}
+          value={localSymbology}
+          placeholder="code128 | ean13"
+          onChange={setLocalSymbology}
+          handleId="symbology"
+          disabled={isSymbologyConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('format', localFormat)}>
+        <InputField
+          nodeId={id}
+          label="Format"
+          icon={<Image size={12} />}
+          value={localFormat}
+          placeholder="png | svg"
+          onChange={setLocalFormat}
+          handleId="format"
+          disabled={isFormatConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('moduleWidth', localModuleWidth)}>
+        <InputField
+          nodeId={id}
+          label="Bar Width"
+          icon={<MoveHorizontal size={12} />}
+          value={localModuleWidth}
+          placeholder="2"
+          onChange={setLocalModuleWidth}
+          handleId="moduleWidth"
+          disabled={isModuleWidthConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('height', localHeight)}>
+        <InputField
+          nodeId={id}
+          label="Height"
+          icon={<MoveVertical size={12} />}
+          value={localHeight}
+          placeholder="80"
+          onChange={setLocalHeight}
+          handleId="height"
+          disabled={isHeightConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('showText', localShowText)}>
+        <InputField
+          nodeId={id}
+          label="Show Text"
+          icon={<CaseSensitive size={12} />}
+          value={localShowText}
+          placeholder="true | false"
+          onChange={setLocalShowText}
+          handleId="showText"
+          disabled={isShowTextConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('outputPath', localOutputPath)}>
+        <InputField
+          nodeId={id}
+          label="Output Path"
+          icon={<FolderOpen size={12} />}
+          value={localOutputPath}
+          placeholder="/path/to/barcode.png (inline when empty)"
+          onChange={setLocalOutputPath}
+          handleId="outputPath"
+          disabled={isOutputPathConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="File Path"
+        icon={<File size={12} />}
+        value={data.outputData?.filePath || ''}
+        handleId="filePath"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Image (base64 / SVG)"
+        icon={<Image size={12} />}
+        value={data.outputData?.image || ''}
+        handleId="image"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Encoded Text"
+        icon={<Type size={12} />}
+        value={data.outputData?.text || ''}
+        handleId="text"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'barcodeNode',
+  label: 'Barcode',
+  color: '#14B8A6',
+  category: 'Data',
+  settings: [
+    { key: 'value', type: 'text', label: 'Data', default: '' },
+    { key: 'symbology', type: 'text', label: 'Symbology', default: 'code128' },
+    { key: 'format', type: 'text', label: 'Format', default: 'png' },
+    { key: 'moduleWidth', type: 'text', label: 'Bar Width', default: '' },
+    { key: 'height', type: 'text', label: 'Height', default: '' },
+    { key: 'showText', type: 'text', label: 'Show Text', default: '' },
+    { key: 'outputPath', type: 'text', label: 'Output Path', default: '' }
+  ]
+};
+
+export default BarcodeNode;
\ No newline at end of file
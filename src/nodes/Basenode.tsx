@@ -1,6 +1,6 @@
 import React, { useCallback } from 'react';
 import { Handle, Position } from '@xyflow/react';
-import { Play, Eye, EyeOff, Zap } from 'lucide-react';
+import { Play, Eye, EyeOff, Zap, CircleDot } from 'lucide-react';
 import './basenode.css';
 
 
@@ -151,8 +151,9 @@ function BaseNode<T extends BaseNodeData = BaseNodeData>({
   isInViewer = false
 }: Omit<BaseNodeProps<T>, 'infoRows' | 'result' | 'dataOutputs' | 'customExecuteIcon' | 'customButtons'>) {
 
-  const { viewerActions } = useWorkflow();
+  const { viewerActions, breakpoints, toggleBreakpoint } = useWorkflow();
   const isViewer = useViewer();
+  const hasBreakpoint = breakpoints?.includes(id) ?? false;
 
   const handleManualExecute = React.useCallback(() => {
     onExecute('manual');
@@ -205,6 +206,17 @@ function BaseNode<T extends BaseNodeData = BaseNodeData>({
               </button>
             )}
             
+            {/* 브레이크포인트 버튼 (트리거 실행이 이 노드 직전에 멈춤) */}
+            {!isViewer && toggleBreakpoint && (
+              <button
+                onClick={() => toggleBreakpoint(id)}
+                className={`node-breakpoint-button ${hasBreakpoint ? 'active' : ''}`}
+                title={hasBreakpoint ? 'Remove breakpoint' : 'Add breakpoint'}
+              >
+                <CircleDot size={12} />
+              </button>
+            )}
+
             {/* 개별 실행 버튼 (얇게) */}
             <button onClick={handleManualExecute} className="node-manual-button" title="Manual Execute">
               <Play size={12} />
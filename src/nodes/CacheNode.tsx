@@ -0,0 +1,253 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { Database, Settings, Key, FileText, Timer, Folder, CheckCircle, Clock } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toNumber, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function CacheNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localAction, setLocalAction] = useState('');
+  const [localKey, setLocalKey] = useState('');
+  const [localValue, setLocalValue] = useState('');
+  const [localTtlSecs, setLocalTtlSecs] = useState('');
+  const [localNamespace, setLocalNamespace] = useState('');
+
+  const isActionConnected = useHandleConnection(id, 'action');
+  const isKeyConnected = useHandleConnection(id, 'key');
+  const isValueConnected = useHandleConnection(id, 'value');
+  const isTtlSecsConnected = useHandleConnection(id, 'ttlSecs');
+  const isNamespaceConnected = useHandleConnection(id, 'namespace');
+
+  useEffect(() => {
+    setLocalAction(data?.action || '');
+    setLocalKey(data?.key || '');
+    setLocalValue(data?.value || '');
+    setLocalTtlSecs(data?.ttlSecs || '');
+    setLocalNamespace(data?.namespace || '');
+  }, [data?.action, data?.key, data?.value, data?.ttlSecs, data?.namespace]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'action' && !isActionConnected && data.action !== value) updateNodeData(id, { action: value });
+    if (key === 'key' && !isKeyConnected && data.key !== value) updateNodeData(id, { key: value });
+    if (key === 'value' && !isValueConnected && data.value !== value) updateNodeData(id, { value: value });
+    if (key === 'ttlSecs' && !isTtlSecsConnected && data.ttlSecs !== value) updateNodeData(id, { ttlSecs: value });
+    if (key === 'namespace' && !isNamespaceConnected && data.namespace !== value) updateNodeData(id, { namespace: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentAction = data?.action?.trim() || '';
+    const currentKey = data?.key?.trim() || '';
+    const currentValue = data?.value || '';
+    const currentTtlSecs = data?.ttlSecs?.trim() || '';
+    const currentNamespace = data?.namespace?.trim() || '';
+
+    if (!currentAction) {
+      console.warn('⚠️ CacheNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Action is required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Action is required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        action: currentAction,
+        key: currentKey || undefined,
+        value: currentValue || undefined,
+        ttlSecs: toNumber(currentTtlSecs),
+        namespace: currentNamespace || undefined
+      };
+
+      console.log(`🗄️ CacheNode ${id}: Cache... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('cache_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        value: toText(parsed.value),
+        hit: toText(parsed.hit ?? false),
+        expiresAt: toText(parsed.expires_at)
+      };
+
+      setStatus('completed');
+      setResult(currentAction === 'get' ? (parsed.hit ? 'Cache hit' : 'Cache miss') : `Cache ${currentAction} done`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 CacheNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 CacheNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Cache failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.action, data?.key, data?.value, data?.ttlSecs, data?.namespace, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`🗄️ Cache node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Cache"
+      icon={<Database size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Key-value cache with TTL (get / set / delete / clear) shared between runs"
+    >
+      <div onBlur={() => handleBlur('action', localAction)}>
+        <InputField
+          nodeId={id}
+          label="Action"
+          icon={<Settings size={12} />}
+          value={localAction}
+          placeholder="get | set | delete | clear"
+          onChange={setLocalAction}
+          handleId="action"
+          disabled={isActionConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('key', localKey)}>
+        <InputField
+          nodeId={id}
+          label="Key"
+          icon={<Key size={12} />}
+          value={localKey}
+          placeholder="weather-seoul"
+          onChange={setLocalKey}
+          handleId="key"
+          disabled={isKeyConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('value', localValue)}>
+        <InputField
+          nodeId={id}
+          label="Value"
+          icon={<FileText size={12} />}
+          value={localValue}
+          placeholder="Value to store (set)"
+          onChange={setLocalValue}
+          handleId="value"
+          disabled={isValueConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('ttlSecs', localTtlSecs)}>
+        <InputField
+          nodeId={id}
+          label="TTL (sec)"
+          icon={<Timer size={12} />}
+          value={localTtlSecs}
+          placeholder="Never expires when empty"
+          onChange={setLocalTtlSecs}
+          handleId="ttlSecs"
+          disabled={isTtlSecsConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('namespace', localNamespace)}>
+        <InputField
+          nodeId={id}
+          label="Namespace"
+          icon={<Folder size={12} />}
+          value={localNamespace}
+          placeholder="default"
+          onChange={setLocalNamespace}
+          handleId="namespace"
+          disabled={isNamespaceConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Value"
+        icon={<FileText size={12} />}
+        value={data.outputData?.value || ''}
+        handleId="value"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Hit"
+        icon={<CheckCircle size={12} />}
+        value={data.outputData?.hit || ''}
+        handleId="hit"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Expires At"
+        icon={<Clock size={12} />}
+        value={data.outputData?.expiresAt || ''}
+        handleId="expiresAt"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'cacheNode',
+  label: 'Cache',
+  color: '#0EA5E9',
+  category: 'Data',
+  settings: [
+    { key: 'action', type: 'text', label: 'Action', default: 'get' },
+    { key: 'key', type: 'text', label: 'Key', default: '' },
+    { key: 'value', type: 'text', label: 'Value', default: '' },
+    { key: 'ttlSecs', type: 'text', label: 'TTL (sec)', default: '' },
+    { key: 'namespace', type: 'text', label: 'Namespace', default: '' }
+  ]
+};
+
+export default CacheNode;
\ No newline at end of file
@@ -0,0 +1,176 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { ClipboardPaste, FolderOpen, Image, File, Maximize } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function ClipboardImageNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localOutputPath, setLocalOutputPath] = useState('');
+  const [localFormat, setLocalFormat] = useState('');
+
+  const isOutputPathConnected = useHandleConnection(id, 'outputPath');
+  const isFormatConnected = useHandleConnection(id, 'format');
+
+  useEffect(() => {
+    setLocalOutputPath(data?.outputPath || '');
+    setLocalFormat(data?.format || '');
+  }, [data?.outputPath, data?.format]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'outputPath' && !isOutputPathConnected && data.outputPath !== value) updateNodeData(id, { outputPath: value });
+    if (key === 'format' && !isFormatConnected && data.format !== value) updateNodeData(id, { format: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentOutputPath = data?.outputPath?.trim() || '';
+    const currentFormat = data?.format?.trim() || '';
+
+    setStatus('running');
+    try {
+      const params = {
+        outputPath: currentOutputPath || undefined,
+        format: currentFormat || undefined
+      };
+
+      console.log(`📋 ClipboardImageNode ${id}: Reading clipboard image... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('clipboard_image_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        filePath: toText(parsed.file_path),
+        imageBase64: toText(parsed.image_base64),
+        size: `${parsed.width}x${parsed.height}`
+      };
+
+      setStatus('completed');
+      setResult(`Clipboard image ${parsed.width}x${parsed.height}`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 ClipboardImageNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 ClipboardImageNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Clipboard image read failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.outputPath, data?.format, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`📋 Clipboard Image node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Clipboard Image"
+      icon={<ClipboardPaste size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Saves the image on the clipboard to a file (or returns it as base64)"
+    >
+      <div onBlur={() => handleBlur('outputPath', localOutputPath)}>
+        <InputField
+          nodeId={id}
+          label="Output Path"
+          icon={<FolderOpen size={12} />}
+          value={localOutputPath}
+          placeholder="/path/to/clip.png (base64 when empty)"
+          onChange={setLocalOutputPath}
+          handleId="outputPath"
+          disabled={isOutputPathConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('format', localFormat)}>
+        <InputField
+          nodeId={id}
+          label="Format"
+          icon={<Image size={12} />}
+          value={localFormat}
+          placeholder="png | jpeg"
+          onChange={setLocalFormat}
+          handleId="format"
+          disabled={isFormatConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="File Path"
+        icon={<File size={12} />}
+        value={data.outputData?.filePath || ''}
+        handleId="filePath"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Image (base64)"
+        icon={<Image size={12} />}
+        value={data.outputData?.imageBase64 || ''}
+        handleId="imageBase64"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Size"
+        icon={<Maximize size={12} />}
+        value={data.outputData?.size || ''}
+        handleId="size"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'clipboardImageNode',
+  label: 'Clipboard Image',
+  color: '#FF9800',
+  category: 'File',
+  settings: [
+    { key: 'outputPath', type: 'text', label: 'Output Path', default: '' },
+    { key: 'format', type: 'text', label: 'Format', default: '' }
+  ]
+};
+
+export default ClipboardImageNode;
\ No newline at end of file
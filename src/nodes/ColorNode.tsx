@@ -0,0 +1,253 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { Pipette, Settings, MoveHorizontal, MoveVertical, Image, Hash, Palette } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toNumber, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function ColorNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localAction, setLocalAction] = useState('');
+  const [localX, setLocalX] = useState('');
+  const [localY, setLocalY] = useState('');
+  const [localImagePath, setLocalImagePath] = useState('');
+  const [localCount, setLocalCount] = useState('');
+
+  const isActionConnected = useHandleConnection(id, 'action');
+  const isXConnected = useHandleConnection(id, 'x');
+  const isYConnected = useHandleConnection(id, 'y');
+  const isImagePathConnected = useHandleConnection(id, 'imagePath');
+  const isCountConnected = useHandleConnection(id, 'count');
+
+  useEffect(() => {
+    setLocalAction(data?.action || '');
+    setLocalX(data?.x || '');
+    setLocalY(data?.y || '');
+    setLocalImagePath(data?.imagePath || '');
+    setLocalCount(data?.count || '');
+  }, [data?.action, data?.x, data?.y, data?.imagePath, data?.count]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'action' && !isActionConnected && data.action !== value) updateNodeData(id, { action: value });
+    if (key === 'x' && !isXConnected && data.x !== value) updateNodeData(id, { x: value });
+    if (key === 'y' && !isYConnected && data.y !== value) updateNodeData(id, { y: value });
+    if (key === 'imagePath' && !isImagePathConnected && data.imagePath !== value) updateNodeData(id, { imagePath: value });
+    if (key === 'count' && !isCountConnected && data.count !== value) updateNodeData(id, { count: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentAction = data?.action?.trim() || '';
+    const currentX = data?.x?.trim() || '';
+    const currentY = data?.y?.trim() || '';
+    const currentImagePath = data?.imagePath?.trim() || '';
+    const currentCount = data?.count?.trim() || '';
+
+    if (!currentAction) {
+      console.warn('⚠️ ColorNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Action is required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Action is required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        action: currentAction,
+        x: toNumber(currentX),
+        y: toNumber(currentY),
+        imagePath: currentImagePath || undefined,
+        count: toNumber(currentCount)
+      };
+
+      console.log(`🎨 ColorNode ${id}: Reading color... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('color_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        hex: toText(parsed.hex ?? parsed.dominant),
+        rgb: parsed.hex ? `${parsed.r},${parsed.g},${parsed.b}` : '',
+        palette: toText(parsed.palette)
+      };
+
+      setStatus('completed');
+      setResult(toText(parsed.hex ?? parsed.dominant));
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 ColorNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 ColorNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Color read failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.action, data?.x, data?.y, data?.imagePath, data?.count, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`🎨 Color Picker node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Color Picker"
+      icon={<Pipette size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Reads the screen color at a point or extracts a palette from an image"
+    >
+      <div onBlur={() => handleBlur('action', localAction)}>
+        <InputField
+          nodeId={id}
+          label="Action"
+          icon={<Settings size={12} />}
+          value={localAction}
+          placeholder="pixel | palette"
+          onChange={setLocalAction}
+          handleId="action"
+          disabled={isActionConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('x', localX)}>
+        <InputField
+          nodeId={id}
+          label="X"
+          icon={<MoveHorizontal size={12} />}
+          value={localX}
+          placeholder="Screen X (pixel)"
+          onChange={setLocalX}
+          handleId="x"
+          disabled={isXConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('y', localY)}>
+        <InputField
+          nodeId={id}
+          label="Y"
+          icon={<MoveVertical size={12} />}
+          value={localY}
+          placeholder="Screen Y (pixel)"
+          onChange={setLocalY}
+          handleId="y"
+          disabled={isYConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('imagePath', localImagePath)}>
+        <InputField
+          nodeId={id}
+          label="Image Path"
+          icon={<Image size={12} />}
+          value={localImagePath}
+          placeholder="/path/to/image.png (palette)"
+          onChange={setLocalImagePath}
+          handleId="imagePath"
+          disabled={isImagePathConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('count', localCount)}>
+        <InputField
+          nodeId={id}
+          label="Palette Size"
+          icon={<Hash size={12} />}
+          value={localCount}
+          placeholder="5"
+          onChange={setLocalCount}
+          handleId="count"
+          disabled={isCountConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Color (hex)"
+        icon={<Pipette size={12} />}
+        value={data.outputData?.hex || ''}
+        handleId="hex"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="RGB"
+        icon={<Palette size={12} />}
+        value={data.outputData?.rgb || ''}
+        handleId="rgb"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Palette"
+        icon={<Palette size={12} />}
+        value={data.outputData?.palette || ''}
+        handleId="palette"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'colorNode',
+  label: 'Color Picker',
+  color: '#EC4899',
+  category: 'Media',
+  settings: [
+    { key: 'action', type: 'text', label: 'Action', default: 'pixel' },
+    { key: 'x', type: 'text', label: 'X', default: '' },
+    { key: 'y', type: 'text', label: 'Y', default: '' },
+    { key: 'imagePath', type: 'text', label: 'Image Path', default: '' },
+    { key: 'count', type: 'text', label: 'Palette Size', default: '' }
+  ]
+};
+
+export default ColorNode;
\ No newline at end of file
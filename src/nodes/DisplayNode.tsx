@@ -0,0 +1,227 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { Monitor, Settings, MoveHorizontal, MoveVertical, Image, Hash } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toNumber, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function DisplayNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localAction, setLocalAction] = useState('');
+  const [localMonitor, setLocalMonitor] = useState('');
+  const [localWidth, setLocalWidth] = useState('');
+  const [localHeight, setLocalHeight] = useState('');
+  const [localImagePath, setLocalImagePath] = useState('');
+
+  const isActionConnected = useHandleConnection(id, 'action');
+  const isMonitorConnected = useHandleConnection(id, 'monitor');
+  const isWidthConnected = useHandleConnection(id, 'width');
+  const isHeightConnected = useHandleConnection(id, 'height');
+  const isImagePathConnected = useHandleConnection(id, 'imagePath');
+
+  useEffect(() => {
+    setLocalAction(data?.action || '');
+    setLocalMonitor(data?.monitor || '');
+    setLocalWidth(data?.width || '');
+    setLocalHeight(data?.height || '');
+    setLocalImagePath(data?.imagePath || '');
+  }, [data?.action, data?.monitor, data?.width, data?.height, data?.imagePath]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'action' && !isActionConnected && data.action !== value) updateNodeData(id, { action: value });
+    if (key === 'monitor' && !isMonitorConnected && data.monitor !== value) updateNodeData(id, { monitor: value });
+    if (key === 'width' && !isWidthConnected && data.width !== value) updateNodeData(id, { width: value });
+    if (key === 'height' && !isHeightConnected && data.height !== value) updateNodeData(id, { height: value });
+    if (key === 'imagePath' && !isImagePathConnected && data.imagePath !== value) updateNodeData(id, { imagePath: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentAction = data?.action?.trim() || '';
+    const currentMonitor = data?.monitor?.trim() || '';
+    const currentWidth = data?.width?.trim() || '';
+    const currentHeight = data?.height?.trim() || '';
+    const currentImagePath = data?.imagePath?.trim() || '';
+
+    setStatus('running');
+    try {
+      const params = {
+        action: currentAction || undefined,
+        monitor: currentMonitor || undefined,
+        width: toNumber(currentWidth),
+        height: toNumber(currentHeight),
+        imagePath: currentImagePath || undefined
+      };
+
+      console.log(`🖥️ DisplayNode ${id}: Running display action... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('display_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        monitors: toText(parsed.monitors),
+        monitorCount: String(parsed.monitors?.length ?? 0)
+      };
+
+      setStatus('completed');
+      setResult(parsed.action ? `Display ${parsed.action} done` : `${parsed.monitors.length} monitors`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 DisplayNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 DisplayNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Display action failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.action, data?.monitor, data?.width, data?.height, data?.imagePath, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`🖥️ Display node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Display"
+      icon={<Monitor size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Lists monitors, changes resolution or primary monitor, or sets the wallpaper"
+    >
+      <div onBlur={() => handleBlur('action', localAction)}>
+        <InputField
+          nodeId={id}
+          label="Action"
+          icon={<Settings size={12} />}
+          value={localAction}
+          placeholder="list | set_resolution | set_primary | set_wallpaper"
+          onChange={setLocalAction}
+          handleId="action"
+          disabled={isActionConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('monitor', localMonitor)}>
+        <InputField
+          nodeId={id}
+          label="Monitor"
+          icon={<Monitor size={12} />}
+          value={localMonitor}
+          placeholder="Monitor index or name"
+          onChange={setLocalMonitor}
+          handleId="monitor"
+          disabled={isMonitorConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('width', localWidth)}>
+        <InputField
+          nodeId={id}
+          label="Width"
+          icon={<MoveHorizontal size={12} />}
+          value={localWidth}
+          placeholder="1920"
+          onChange={setLocalWidth}
+          handleId="width"
+          disabled={isWidthConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('height', localHeight)}>
+        <InputField
+          nodeId={id}
+          label="Height"
+          icon={<MoveVertical size={12} />}
+          value={localHeight}
+          placeholder="1080"
+          onChange={setLocalHeight}
+          handleId="height"
+          disabled={isHeightConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('imagePath', localImagePath)}>
+        <InputField
+          nodeId={id}
+          label="Wallpaper Image"
+          icon={<Image size={12} />}
+          value={localImagePath}
+          placeholder="/path/to/wallpaper.jpg"
+          onChange={setLocalImagePath}
+          handleId="imagePath"
+          disabled={isImagePathConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Monitors"
+        icon={<Monitor size={12} />}
+        value={data.outputData?.monitors || ''}
+        handleId="monitors"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Monitor Count"
+        icon={<Hash size={12} />}
+        value={data.outputData?.monitorCount || ''}
+        handleId="monitorCount"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'displayNode',
+  label: 'Display',
+  color: '#6366F1',
+  category: 'System',
+  settings: [
+    { key: 'action', type: 'text', label: 'Action', default: 'list' },
+    { key: 'monitor', type: 'text', label: 'Monitor', default: '' },
+    { key: 'width', type: 'text', label: 'Width', default: '' },
+    { key: 'height', type: 'text', label: 'Height', default: '' },
+    { key: 'imagePath', type: 'text', label: 'Wallpaper Image', default: '' }
+  ]
+};
+
+export default DisplayNode;
\ No newline at end of file
@@ -0,0 +1,222 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { Globe, Settings, Network, List, FileText, CheckCircle } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function DnsNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localAction, setLocalAction] = useState('');
+  const [localHostname, setLocalHostname] = useState('');
+  const [localIp, setLocalIp] = useState('');
+
+  const isActionConnected = useHandleConnection(id, 'action');
+  const isHostnameConnected = useHandleConnection(id, 'hostname');
+  const isIpConnected = useHandleConnection(id, 'ip');
+
+  useEffect(() => {
+    setLocalAction(data?.action || '');
+    setLocalHostname(data?.hostname || '');
+    setLocalIp(data?.ip || '');
+  }, [data?.action, data?.hostname, data?.ip]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'action' && !isActionConnected && data.action !== value) updateNodeData(id, { action: value });
+    if (key === 'hostname' && !isHostnameConnected && data.hostname !== value) updateNodeData(id, { hostname: value });
+    if (key === 'ip' && !isIpConnected && data.ip !== value) updateNodeData(id, { ip: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentAction = data?.action?.trim() || '';
+    const currentHostname = data?.hostname?.trim() || '';
+    const currentIp = data?.ip?.trim() || '';
+
+    if (!currentAction) {
+      console.warn('⚠️ DnsNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Action is required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Action is required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        action: currentAction,
+        hostname: currentHostname || undefined,
+        ip: currentIp || undefined
+      };
+
+      console.log(`🌐 DnsNode ${id}: Running DNS action... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('dns_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        address: toText(parsed.addresses?.[0]),
+        addresses: toText(parsed.addresses),
+        entries: toText(parsed.entries),
+        changed: toText(parsed.changed)
+      };
+
+      setStatus('completed');
+      setResult(`DNS ${currentAction} done`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 DnsNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 DnsNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ DNS action failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.action, data?.hostname, data?.ip, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`🌐 DNS node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="DNS"
+      icon={<Globe size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Resolves hostnames, flushes the DNS cache and edits the hosts file"
+    >
+      <div onBlur={() => handleBlur('action', localAction)}>
+        <InputField
+          nodeId={id}
+          label="Action"
+          icon={<Settings size={12} />}
+          value={localAction}
+          placeholder="resolve | flush | hosts_list | hosts_add | hosts_remove"
+          onChange={setLocalAction}
+          handleId="action"
+          disabled={isActionConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('hostname', localHostname)}>
+        <InputField
+          nodeId={id}
+          label="Hostname"
+          icon={<Globe size={12} />}
+          value={localHostname}
+          placeholder="example.com"
+          onChange={setLocalHostname}
+          handleId="hostname"
+          disabled={isHostnameConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('ip', localIp)}>
+        <InputField
+          nodeId={id}
+          label="IP"
+          icon={<Network size={12} />}
+          value={localIp}
+          placeholder="127.0.0.1 (hosts_add)"
+          onChange={setLocalIp}
+          handleId="ip"
+          disabled={isIpConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="First Address"
+        icon={<Network size={12} />}
+        value={data.outputData?.address || ''}
+        handleId="address"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Addresses"
+        icon={<List size={12} />}
+        value={data.outputData?.addresses || ''}
+        handleId="addresses"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Hosts Entries"
+        icon={<FileText size={12} />}
+        value={data.outputData?.entries || ''}
+        handleId="entries"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Changed"
+        icon={<CheckCircle size={12} />}
+        value={data.outputData?.changed || ''}
+        handleId="changed"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'dnsNode',
+  label: 'DNS',
+  color: '#0EA5E9',
+  category: 'Network',
+  settings: [
+    { key: 'action', type: 'text', label: 'Action', default: 'resolve' },
+    { key: 'hostname', type: 'text', label: 'Hostname', default: '' },
+    { key: 'ip', type: 'text', label: 'IP', default: '' }
+  ]
+};
+
+export default DnsNode;
\ No newline at end of file
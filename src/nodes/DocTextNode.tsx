@@ -0,0 +1,182 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { FileSearch, File, FileText, Database, FileType, Hash } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function DocTextNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localPath, setLocalPath] = useState('');
+
+  const isPathConnected = useHandleConnection(id, 'path');
+
+  useEffect(() => {
+    setLocalPath(data?.path || '');
+  }, [data?.path]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'path' && !isPathConnected && data.path !== value) updateNodeData(id, { path: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentPath = data?.path?.trim() || '';
+
+    if (!currentPath) {
+      console.warn('⚠️ DocTextNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Document Path is required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Document Path is required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        path: currentPath
+      };
+
+      console.log(`📄 DocTextNode ${id}: Extracting document text... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('doc_text_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        text: toText(parsed.text),
+        textHandle: toText(parsed.outputHandle),
+        format: toText(parsed.format),
+        chars: toText(parsed.chars)
+      };
+
+      setStatus('completed');
+      setResult(`${parsed.format}: ${parsed.chars} characters${parsed.truncated ? ' (stored)' : ''}`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 DocTextNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 DocTextNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Document text extraction failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.path, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`📄 Document Text node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Document Text"
+      icon={<FileSearch size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Extracts plain text from DOCX, HWPX or HWP documents"
+    >
+      <div onBlur={() => handleBlur('path', localPath)}>
+        <InputField
+          nodeId={id}
+          label="Document Path"
+          icon={<File size={12} />}
+          value={localPath}
+          placeholder="/path/to/report.hwp"
+          onChange={setLocalPath}
+          handleId="path"
+          disabled={isPathConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Text"
+        icon={<FileText size={12} />}
+        value={data.outputData?.text || ''}
+        handleId="text"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Full Text Handle"
+        icon={<Database size={12} />}
+        value={data.outputData?.textHandle || ''}
+        handleId="textHandle"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Format"
+        icon={<FileType size={12} />}
+        value={data.outputData?.format || ''}
+        handleId="format"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Characters"
+        icon={<Hash size={12} />}
+        value={data.outputData?.chars || ''}
+        handleId="chars"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'docTextNode',
+  label: 'Document Text',
+  color: '#FF9800',
+  category: 'File',
+  settings: [
+    { key: 'path', type: 'text', label: 'Document Path', default: '' }
+  ]
+};
+
+export default DocTextNode;
\ No newline at end of file
@@ -0,0 +1,231 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { MailOpen, File, FolderOpen, Paperclip, Type, User, Calendar, FileText } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toBool, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function EmailParseNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localPath, setLocalPath] = useState('');
+  const [localOutputDir, setLocalOutputDir] = useState('');
+  const [localExtractAttachments, setLocalExtractAttachments] = useState('');
+
+  const isPathConnected = useHandleConnection(id, 'path');
+  const isOutputDirConnected = useHandleConnection(id, 'outputDir');
+  const isExtractAttachmentsConnected = useHandleConnection(id, 'extractAttachments');
+
+  useEffect(() => {
+    setLocalPath(data?.path || '');
+    setLocalOutputDir(data?.outputDir || '');
+    setLocalExtractAttachments(data?.extractAttachments || '');
+  }, [data?.path, data?.outputDir, data?.extractAttachments]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'path' && !isPathConnected && data.path !== value) updateNodeData(id, { path: value });
+    if (key === 'outputDir' && !isOutputDirConnected && data.outputDir !== value) updateNodeData(id, { outputDir: value });
+    if (key === 'extractAttachments' && !isExtractAttachmentsConnected && data.extractAttachments !== value) updateNodeData(id, { extractAttachments: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentPath = data?.path?.trim() || '';
+    const currentOutputDir = data?.outputDir?.trim() || '';
+    const currentExtractAttachments = data?.extractAttachments?.trim() || '';
+
+    if (!currentPath) {
+      console.warn('⚠️ EmailParseNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Email File is required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Email File is required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        path: currentPath,
+        outputDir: currentOutputDir || undefined,
+        extractAttachments: toBool(currentExtractAttachments)
+      };
+
+      console.log(`✉️ EmailParseNode ${id}: Parsing email file... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('email_parse_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        subject: toText(parsed.subject),
+        from: parsed.from.map(a => a.address).filter(Boolean).join(','),
+        date: toText(parsed.date),
+        text: toText(parsed.text),
+        attachments: parsed.attachments.map(a => a.path || a.name).join('\n')
+      };
+
+      setStatus('completed');
+      setResult(`${parsed.subject || '(no subject)'} - ${parsed.attachments.length} attachment(s)`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 EmailParseNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 EmailParseNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Email parse failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.path, data?.outputDir, data?.extractAttachments, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`✉️ Email Parse node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Email Parse"
+      icon={<MailOpen size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Parses a .eml / .msg file into headers, body and attachments"
+    >
+      <div onBlur={() => handleBlur('path', localPath)}>
+        <InputField
+          nodeId={id}
+          label="Email File"
+          icon={<File size={12} />}
+          value={localPath}
+          placeholder="/path/to/message.eml"
+          onChange={setLocalPath}
+          handleId="path"
+          disabled={isPathConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('outputDir', localOutputDir)}>
+        <InputField
+          nodeId={id}
+          label="Attachment Folder"
+          icon={<FolderOpen size={12} />}
+          value={localOutputDir}
+          placeholder="/path/to/attachments"
+          onChange={setLocalOutputDir}
+          handleId="outputDir"
+          disabled={isOutputDirConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('extractAttachments', localExtractAttachments)}>
+        <InputField
+          nodeId={id}
+          label="Extract Attachments"
+          icon={<Paperclip size={12} />}
+          value={localExtractAttachments}
+          placeholder="true | false"
+          onChange={setLocalExtractAttachments}
+          handleId="extractAttachments"
+          disabled={isExtractAttachmentsConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Subject"
+        icon={<Type size={12} />}
+        value={data.outputData?.subject || ''}
+        handleId="subject"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="From"
+        icon={<User size={12} />}
+        value={data.outputData?.from || ''}
+        handleId="from"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Date"
+        icon={<Calendar size={12} />}
+        value={data.outputData?.date || ''}
+        handleId="date"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Body"
+        icon={<FileText size={12} />}
+        value={data.outputData?.text || ''}
+        handleId="text"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Attachments"
+        icon={<Paperclip size={12} />}
+        value={data.outputData?.attachments || ''}
+        handleId="attachments"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'emailParseNode',
+  label: 'Email Parse',
+  color: '#14B8A6',
+  category: 'Data',
+  settings: [
+    { key: 'path', type: 'text', label: 'Email File', default: '' },
+    { key: 'outputDir', type: 'text', label: 'Attachment Folder', default: '' },
+    { key: 'extractAttachments', type: 'text', label: 'Extract Attachments', default: '' }
+  ]
+};
+
+export default EmailParseNode;
\ No newline at end of file
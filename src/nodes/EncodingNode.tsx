@@ -0,0 +1,273 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { Languages, File, Settings, Search, FolderOpen, AlertTriangle, FileText } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toBool, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function EncodingNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localFilePath, setLocalFilePath] = useState('');
+  const [localAction, setLocalAction] = useState('');
+  const [localTargetEncoding, setLocalTargetEncoding] = useState('');
+  const [localSourceEncoding, setLocalSourceEncoding] = useState('');
+  const [localOutputPath, setLocalOutputPath] = useState('');
+  const [localLossy, setLocalLossy] = useState('');
+
+  const isFilePathConnected = useHandleConnection(id, 'filePath');
+  const isActionConnected = useHandleConnection(id, 'action');
+  const isTargetEncodingConnected = useHandleConnection(id, 'targetEncoding');
+  const isSourceEncodingConnected = useHandleConnection(id, 'sourceEncoding');
+  const isOutputPathConnected = useHandleConnection(id, 'outputPath');
+  const isLossyConnected = useHandleConnection(id, 'lossy');
+
+  useEffect(() => {
+    setLocalFilePath(data?.filePath || '');
+    setLocalAction(data?.action || '');
+    setLocalTargetEncoding(data?.targetEncoding || '');
+    setLocalSourceEncoding(data?.sourceEncoding || '');
+    setLocalOutputPath(data?.outputPath || '');
+    setLocalLossy(data?.lossy || '');
+  }, [data?.filePath, data?.action, data?.targetEncoding, data?.sourceEncoding, data?.outputPath, data?.lossy]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'filePath' && !isFilePathConnected && data.filePath !== value) updateNodeData(id, { filePath: value });
+    if (key === 'action' && !isActionConnected && data.action !== value) updateNodeData(id, { action: value });
+    if (key === 'targetEncoding' && !isTargetEncodingConnected && data.targetEncoding !== value) updateNodeData(id, { targetEncoding: value });
+    if (key === 'sourceEncoding' && !isSourceEncodingConnected && data.sourceEncoding !== value) updateNodeData(id, { sourceEncoding: value });
+    if (key === 'outputPath' && !isOutputPathConnected && data.outputPath !== value) updateNodeData(id, { outputPath: value });
+    if (key === 'lossy' && !isLossyConnected && data.lossy !== value) updateNodeData(id, { lossy: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentFilePath = data?.filePath?.trim() || '';
+    const currentAction = data?.action?.trim() || '';
+    const currentTargetEncoding = data?.targetEncoding?.trim() || '';
+    const currentSourceEncoding = data?.sourceEncoding?.trim() || '';
+    const currentOutputPath = data?.outputPath?.trim() || '';
+    const currentLossy = data?.lossy?.trim() || '';
+
+    if (!currentFilePath) {
+      console.warn('⚠️ EncodingNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('File Path is required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'File Path is required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        filePath: currentFilePath,
+        action: currentAction || undefined,
+        targetEncoding: currentTargetEncoding || undefined,
+        sourceEncoding: currentSourceEncoding || undefined,
+        outputPath: currentOutputPath || undefined,
+        lossy: toBool(currentLossy)
+      };
+
+      console.log(`🔤 EncodingNode ${id}: Processing file encoding... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('encoding_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        filePath: toText(parsed.file_path),
+        encoding: toText(parsed.encoding ?? parsed.target_encoding),
+        text: toText(parsed.text)
+      };
+
+      setStatus('completed');
+      setResult(parsed.encoding ? `Detected ${parsed.encoding}` : `Converted ${parsed.source_encoding} → ${parsed.target_encoding}`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 EncodingNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 EncodingNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Encoding action failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.filePath, data?.action, data?.targetEncoding, data?.sourceEncoding, data?.outputPath, data?.lossy, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`🔤 Encoding node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Encoding"
+      icon={<Languages size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Detects a text file encoding or converts it (e.g. EUC-KR → UTF-8)"
+    >
+      <div onBlur={() => handleBlur('filePath', localFilePath)}>
+        <InputField
+          nodeId={id}
+          label="File Path"
+          icon={<File size={12} />}
+          value={localFilePath}
+          placeholder="/path/to/file.txt"
+          onChange={setLocalFilePath}
+          handleId="filePath"
+          disabled={isFilePathConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('action', localAction)}>
+        <InputField
+          nodeId={id}
+          label="Action"
+          icon={<Settings size={12} />}
+          value={localAction}
+          placeholder="detect | convert"
+          onChange={setLocalAction}
+          handleId="action"
+          disabled={isActionConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('targetEncoding', localTargetEncoding)}>
+        <InputField
+          nodeId={id}
+          label="Target Encoding"
+          icon={<Languages size={12} />}
+          value={localTargetEncoding}
+          placeholder="utf-8 (convert)"
+          onChange={setLocalTargetEncoding}
+          handleId="targetEncoding"
+          disabled={isTargetEncodingConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('sourceEncoding', localSourceEncoding)}>
+        <InputField
+          nodeId={id}
+          label="Source Encoding"
+          icon={<Search size={12} />}
+          value={localSourceEncoding}
+          placeholder="Auto-detect"
+          onChange={setLocalSourceEncoding}
+          handleId="sourceEncoding"
+          disabled={isSourceEncodingConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('outputPath', localOutputPath)}>
+        <InputField
+          nodeId={id}
+          label="Output Path"
+          icon={<FolderOpen size={12} />}
+          value={localOutputPath}
+          placeholder="Overwrites the source when empty"
+          onChange={setLocalOutputPath}
+          handleId="outputPath"
+          disabled={isOutputPathConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('lossy', localLossy)}>
+        <InputField
+          nodeId={id}
+          label="Lossy"
+          icon={<AlertTriangle size={12} />}
+          value={localLossy}
+          placeholder="true | false"
+          onChange={setLocalLossy}
+          handleId="lossy"
+          disabled={isLossyConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="File Path"
+        icon={<File size={12} />}
+        value={data.outputData?.filePath || ''}
+        handleId="filePath"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Encoding"
+        icon={<Languages size={12} />}
+        value={data.outputData?.encoding || ''}
+        handleId="encoding"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Text"
+        icon={<FileText size={12} />}
+        value={data.outputData?.text || ''}
+        handleId="text"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'encodingNode',
+  label: 'Encoding',
+  color: '#FF9800',
+  category: 'File',
+  settings: [
+    { key: 'filePath', type: 'text', label: 'File Path', default: '' },
+    { key: 'action', type: 'text', label: 'Action', default: 'detect' },
+    { key: 'targetEncoding', type: 'text', label: 'Target Encoding', default: '' },
+    { key: 'sourceEncoding', type: 'text', label: 'Source Encoding', default: '' },
+    { key: 'outputPath', type: 'text', label: 'Output Path', default: '' },
+    { key: 'lossy', type: 'text', label: 'Lossy', default: '' }
+  ]
+};
+
+export default EncodingNode;
\ No newline at end of file
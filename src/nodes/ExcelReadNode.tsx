@@ -0,0 +1,262 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { Sheet, File, Grid3x3, Heading, Eraser, Braces, Hash } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toBool, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function ExcelReadNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localPath, setLocalPath] = useState('');
+  const [localSheet, setLocalSheet] = useState('');
+  const [localRange, setLocalRange] = useState('');
+  const [localHeader, setLocalHeader] = useState('');
+  const [localSkipEmptyRows, setLocalSkipEmptyRows] = useState('');
+
+  const isPathConnected = useHandleConnection(id, 'path');
+  const isSheetConnected = useHandleConnection(id, 'sheet');
+  const isRangeConnected = useHandleConnection(id, 'range');
+  const isHeaderConnected = useHandleConnection(id, 'header');
+  const isSkipEmptyRowsConnected = useHandleConnection(id, 'skipEmptyRows');
+
+  useEffect(() => {
+    setLocalPath(data?.path || '');
+    setLocalSheet(data?.sheet || '');
+    setLocalRange(data?.range || '');
+    setLocalHeader(data?.header || '');
+    setLocalSkipEmptyRows(data?.skipEmptyRows || '');
+  }, [data?.path, data?.sheet, data?.range, data?.header, data?.skipEmptyRows]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'path' && !isPathConnected && data.path !== value) updateNodeData(id, { path: value });
+    if (key === 'sheet' && !isSheetConnected && data.sheet !== value) updateNodeData(id, { sheet: value });
+    if (key === 'range' && !isRangeConnected && data.range !== value) updateNodeData(id, { range: value });
+    if (key === 'header' && !isHeaderConnected && data.header !== value) updateNodeData(id, { header: value });
+    if (key === 'skipEmptyRows' && !isSkipEmptyRowsConnected && data.skipEmptyRows !== value) updateNodeData(id, { skipEmptyRows: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentPath = data?.path?.trim() || '';
+    const currentSheet = data?.sheet?.trim() || '';
+    const currentRange = data?.range?.trim() || '';
+    const currentHeader = data?.header?.trim() || '';
+    const currentSkipEmptyRows = data?.skipEmptyRows?.trim() || '';
+
+    if (!currentPath) {
+      console.warn('⚠️ ExcelReadNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('File Path is required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'File Path is required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        path: currentPath,
+        sheet: currentSheet || undefined,
+        range: currentRange || undefined,
+        header: toBool(currentHeader),
+        skipEmptyRows: toBool(currentSkipEmptyRows)
+      };
+
+      console.log(`📗 ExcelReadNode ${id}: Reading spreadsheet... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('excel_read_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        rows: toText(parsed.rows),
+        headers: toText(parsed.headers),
+        rowCount: toText(parsed.row_count),
+        sheet: toText(parsed.sheet)
+      };
+
+      setStatus('completed');
+      setResult(`${parsed.row_count} rows from ${parsed.sheet}`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 ExcelReadNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 ExcelReadNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Excel read failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.path, data?.sheet, data?.range, data?.header, data?.skipEmptyRows, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`📗 Excel Read node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Excel Read"
+      icon={<Sheet size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Reads rows from an .xlsx / .xls / .ods sheet as JSON"
+    >
+      <div onBlur={() => handleBlur('path', localPath)}>
+        <InputField
+          nodeId={id}
+          label="File Path"
+          icon={<File size={12} />}
+          value={localPath}
+          placeholder="/path/to/book.xlsx"
+          onChange={setLocalPath}
+          handleId="path"
+          disabled={isPathConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('sheet', localSheet)}>
+        <InputField
+          nodeId={id}
+          label="Sheet"
+          icon={<Sheet size={12} />}
+          value={localSheet}
+          placeholder="First sheet when empty"
+          onChange={setLocalSheet}
+          handleId="sheet"
+          disabled={isSheetConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('range', localRange)}>
+        <InputField
+          nodeId={id}
+          label="Range"
+          icon={<Grid3x3 size={12} />}
+          value={localRange}
+          placeholder="A1:D20 | B3 | A:C"
+          onChange={setLocalRange}
+          handleId="range"
+          disabled={isRangeConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('header', localHeader)}>
+        <InputField
+          nodeId={id}
+          label="First Row Is Header"
+          icon={<Heading size={12} />}
+          value={localHeader}
+          placeholder="true | false"
+          onChange={setLocalHeader}
+          handleId="header"
+          disabled={isHeaderConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('skipEmptyRows', localSkipEmptyRows)}>
+        <InputField
+          nodeId={id}
+          label="Skip Empty Rows"
+          icon={<Eraser size={12} />}
+          value={localSkipEmptyRows}
+          placeholder="true | false"
+          onChange={setLocalSkipEmptyRows}
+          handleId="skipEmptyRows"
+          disabled={isSkipEmptyRowsConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Rows"
+        icon={<Braces size={12} />}
+        value={data.outputData?.rows || ''}
+        handleId="rows"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Headers"
+        icon={<Heading size={12} />}
+        value={data.outputData?.headers || ''}
+        handleId="headers"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Row Count"
+        icon={<Hash size={12} />}
+        value={data.outputData?.rowCount || ''}
+        handleId="rowCount"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Sheet"
+        icon={<Sheet size={12} />}
+        value={data.outputData?.sheet || ''}
+        handleId="sheet"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'excelReadNode',
+  label: 'Excel Read',
+  color: '#FF9800',
+  category: 'File',
+  settings: [
+    { key: 'path', type: 'text', label: 'File Path', default: '' },
+    { key: 'sheet', type: 'text', label: 'Sheet', default: '' },
+    { key: 'range', type: 'text', label: 'Range', default: '' },
+    { key: 'header', type: 'text', label: 'First Row Is Header', default: '' },
+    { key: 'skipEmptyRows', type: 'text', label: 'Skip Empty Rows', default: '' }
+  ]
+};
+
+export default ExcelReadNode;
\ No newline at end of file
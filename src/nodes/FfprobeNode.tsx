@@ -0,0 +1,240 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { Film, File, Image, Timer, Maximize, Music, Braces } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toNumber, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function FfprobeNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localPath, setLocalPath] = useState('');
+  const [localThumbnailPath, setLocalThumbnailPath] = useState('');
+  const [localThumbnailAtSecs, setLocalThumbnailAtSecs] = useState('');
+
+  const isPathConnected = useHandleConnection(id, 'path');
+  const isThumbnailPathConnected = useHandleConnection(id, 'thumbnailPath');
+  const isThumbnailAtSecsConnected = useHandleConnection(id, 'thumbnailAtSecs');
+
+  useEffect(() => {
+    setLocalPath(data?.path || '');
+    setLocalThumbnailPath(data?.thumbnailPath || '');
+    setLocalThumbnailAtSecs(data?.thumbnailAtSecs || '');
+  }, [data?.path, data?.thumbnailPath, data?.thumbnailAtSecs]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'path' && !isPathConnected && data.path !== value) updateNodeData(id, { path: value });
+    if (key === 'thumbnailPath' && !isThumbnailPathConnected && data.thumbnailPath !== value) updateNodeData(id, { thumbnailPath: value });
+    if (key === 'thumbnailAtSecs' && !isThumbnailAtSecsConnected && data.thumbnailAtSecs !== value) updateNodeData(id, { thumbnailAtSecs: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentPath = data?.path?.trim() || '';
+    const currentThumbnailPath = data?.thumbnailPath?.trim() || '';
+    const currentThumbnailAtSecs = data?.thumbnailAtSecs?.trim() || '';
+
+    if (!currentPath) {
+      console.warn('⚠️ FfprobeNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Media File is required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Media File is required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        path: currentPath,
+        thumbnailPath: currentThumbnailPath || undefined,
+        thumbnailAtSecs: toNumber(currentThumbnailAtSecs)
+      };
+
+      console.log(`🎞️ FfprobeNode ${id}: Probing media file... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('ffprobe_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        durationSecs: toText(parsed.duration_secs),
+        resolution: parsed.width ? `${parsed.width}x${parsed.height}` : '',
+        videoCodec: toText(parsed.video_codec),
+        audioCodec: toText(parsed.audio_codec),
+        thumbnailPath: toText(parsed.thumbnail_path),
+        info: resultData
+      };
+
+      setStatus('completed');
+      setResult(`${parsed.container} ${parsed.duration_secs ?? '?'}s`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 FfprobeNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 FfprobeNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Media probe failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.path, data?.thumbnailPath, data?.thumbnailAtSecs, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`🎞️ Media Info node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Media Info"
+      icon={<Film size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Reads duration, resolution and codecs of a media file, optionally saving a thumbnail"
+    >
+      <div onBlur={() => handleBlur('path', localPath)}>
+        <InputField
+          nodeId={id}
+          label="Media File"
+          icon={<File size={12} />}
+          value={localPath}
+          placeholder="/path/to/video.mp4"
+          onChange={setLocalPath}
+          handleId="path"
+          disabled={isPathConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('thumbnailPath', localThumbnailPath)}>
+        <InputField
+          nodeId={id}
+          label="Thumbnail Path"
+          icon={<Image size={12} />}
+          value={localThumbnailPath}
+          placeholder="/path/to/thumb.jpg (optional)"
+          onChange={setLocalThumbnailPath}
+          handleId="thumbnailPath"
+          disabled={isThumbnailPathConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('thumbnailAtSecs', localThumbnailAtSecs)}>
+        <InputField
+          nodeId={id}
+          label="Thumbnail At (sec)"
+          icon={<Timer size={12} />}
+          value={localThumbnailAtSecs}
+          placeholder="1.0"
+          onChange={setLocalThumbnailAtSecs}
+          handleId="thumbnailAtSecs"
+          disabled={isThumbnailAtSecsConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Duration (sec)"
+        icon={<Timer size={12} />}
+        value={data.outputData?.durationSecs || ''}
+        handleId="durationSecs"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Resolution"
+        icon={<Maximize size={12} />}
+        value={data.outputData?.resolution || ''}
+        handleId="resolution"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Video Codec"
+        icon={<Film size={12} />}
+        value={data.outputData?.videoCodec || ''}
+        handleId="videoCodec"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Audio Codec"
+        icon={<Music size={12} />}
+        value={data.outputData?.audioCodec || ''}
+        handleId="audioCodec"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Thumbnail Path"
+        icon={<Image size={12} />}
+        value={data.outputData?.thumbnailPath || ''}
+        handleId="thumbnailPath"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="All Info"
+        icon={<Braces size={12} />}
+        value={data.outputData?.info || ''}
+        handleId="info"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'ffprobeNode',
+  label: 'Media Info (ffprobe)',
+  color: '#EC4899',
+  category: 'Media',
+  settings: [
+    { key: 'path', type: 'text', label: 'Media File', default: '' },
+    { key: 'thumbnailPath', type: 'text', label: 'Thumbnail Path', default: '' },
+    { key: 'thumbnailAtSecs', type: 'text', label: 'Thumbnail At (sec)', default: '' }
+  ]
+};
+
+export default FfprobeNode;
\ No newline at end of file
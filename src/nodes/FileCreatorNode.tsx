@@ -8,7 +8,7 @@ import { useWorkflow, useHandleConnection } from '../WorkflowContext';
 
 
 function FileCreatorNode({ id, data, selected }) {
-  const { executeNextNodes, updateNodeData } = useWorkflow();
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
 
   const [status, setStatus] = useState('waiting');
   const [result, setResult] = useState<string>('');
@@ -45,6 +45,9 @@ function FileCreatorNode({ id, data, selected }) {
       setResult('File path and name are required');
       
       updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'File path and name are required');
+      }
       
       setTimeout(() => { 
         setStatus('waiting'); 
@@ -101,6 +104,11 @@ function FileCreatorNode({ id, data, selected }) {
       setStatus('failed');
       setResult(errorMessage);
 
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
       updateNodeData(id, {
         triggerExecution: undefined,
         outputData: {
@@ -112,7 +120,7 @@ function FileCreatorNode({ id, data, selected }) {
 
       setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
     }
-  }, [id, data?.filePath, data?.fileName, data?.fileContent, executeNextNodes, updateNodeData]);
+  }, [id, data?.filePath, data?.fileName, data?.fileContent, executeNextNodes, reportNodeError, updateNodeData]);
 
   // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
   useEffect(() => {
@@ -0,0 +1,193 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { LayoutGrid, FolderOpen, FileCode, Image, Film } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function GalleryNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localFolder, setLocalFolder] = useState('');
+  const [localOutput, setLocalOutput] = useState('');
+
+  const isFolderConnected = useHandleConnection(id, 'folder');
+  const isOutputConnected = useHandleConnection(id, 'output');
+
+  useEffect(() => {
+    setLocalFolder(data?.folder || '');
+    setLocalOutput(data?.output || '');
+  }, [data?.folder, data?.output]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'folder' && !isFolderConnected && data.folder !== value) updateNodeData(id, { folder: value });
+    if (key === 'output' && !isOutputConnected && data.output !== value) updateNodeData(id, { output: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentFolder = data?.folder?.trim() || '';
+    const currentOutput = data?.output?.trim() || '';
+
+    if (!currentFolder) {
+      console.warn('⚠️ GalleryNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Folder is required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Folder is required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        folder: currentFolder,
+        output: currentOutput || undefined
+      };
+
+      console.log(`🖼️ GalleryNode ${id}: Building gallery... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('gallery_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        output: toText(parsed.output),
+        images: toText(parsed.images),
+        videos: toText(parsed.videos)
+      };
+
+      setStatus('completed');
+      setResult(`${parsed.images} images, ${parsed.videos} videos`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 GalleryNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 GalleryNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Gallery build failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.folder, data?.output, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`🖼️ Gallery node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Gallery"
+      icon={<LayoutGrid size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Builds a static HTML gallery (with thumbnails) for the photos and videos in a folder"
+    >
+      <div onBlur={() => handleBlur('folder', localFolder)}>
+        <InputField
+          nodeId={id}
+          label="Folder"
+          icon={<FolderOpen size={12} />}
+          value={localFolder}
+          placeholder="/Users/username/Pictures/Trip"
+          onChange={setLocalFolder}
+          handleId="folder"
+          disabled={isFolderConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('output', localOutput)}>
+        <InputField
+          nodeId={id}
+          label="Output HTML"
+          icon={<FileCode size={12} />}
+          value={localOutput}
+          placeholder="<folder>/index.html when empty"
+          onChange={setLocalOutput}
+          handleId="output"
+          disabled={isOutputConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Gallery File"
+        icon={<FileCode size={12} />}
+        value={data.outputData?.output || ''}
+        handleId="output"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Images"
+        icon={<Image size={12} />}
+        value={data.outputData?.images || ''}
+        handleId="images"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Videos"
+        icon={<Film size={12} />}
+        value={data.outputData?.videos || ''}
+        handleId="videos"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'galleryNode',
+  label: 'Gallery',
+  color: '#EC4899',
+  category: 'Media',
+  settings: [
+    { key: 'folder', type: 'text', label: 'Folder', default: '' },
+    { key: 'output', type: 'text', label: 'Output HTML', default: '' }
+  ]
+};
+
+export default GalleryNode;
\ No newline at end of file
@@ -0,0 +1,193 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { ListFilter, List, Braces, Hash } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toJson, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function ListOpsNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localInput, setLocalInput] = useState('');
+  const [localOperations, setLocalOperations] = useState('');
+
+  const isInputConnected = useHandleConnection(id, 'input');
+  const isOperationsConnected = useHandleConnection(id, 'operations');
+
+  useEffect(() => {
+    setLocalInput(data?.input || '');
+    setLocalOperations(data?.operations || '');
+  }, [data?.input, data?.operations]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'input' && !isInputConnected && data.input !== value) updateNodeData(id, { input: value });
+    if (key === 'operations' && !isOperationsConnected && data.operations !== value) updateNodeData(id, { operations: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentInput = data?.input || '';
+    const currentOperations = data?.operations || '';
+
+    if (!currentInput || !currentOperations) {
+      console.warn('⚠️ ListOpsNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Input and Operations are required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Input and Operations are required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        input: currentInput,
+        operations: toJson(currentOperations) ?? []
+      };
+
+      console.log(`📋 ListOpsNode ${id}: Applying list operations... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('list_ops_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        output: toText(parsed.output),
+        items: toText(parsed.items),
+        count: toText(parsed.count)
+      };
+
+      setStatus('completed');
+      setResult(`${parsed.count} items`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 ListOpsNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 ListOpsNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ List operations failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.input, data?.operations, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`📋 List Operations node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="List Operations"
+      icon={<ListFilter size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Sorts, filters, dedupes, slices or shuffles lines or a JSON array"
+    >
+      <div onBlur={() => handleBlur('input', localInput)}>
+        <InputField
+          nodeId={id}
+          label="Input"
+          icon={<List size={12} />}
+          value={localInput}
+          placeholder="One item per line or a JSON array"
+          onChange={setLocalInput}
+          handleId="input"
+          disabled={isInputConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('operations', localOperations)}>
+        <InputField
+          nodeId={id}
+          label="Operations"
+          icon={<ListFilter size={12} />}
+          value={localOperations}
+          placeholder={'[{"op":"unique"},{"op":"sort","mode":"natural"}]'}
+          onChange={setLocalOperations}
+          handleId="operations"
+          disabled={isOperationsConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Output"
+        icon={<List size={12} />}
+        value={data.outputData?.output || ''}
+        handleId="output"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Items"
+        icon={<Braces size={12} />}
+        value={data.outputData?.items || ''}
+        handleId="items"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Count"
+        icon={<Hash size={12} />}
+        value={data.outputData?.count || ''}
+        handleId="count"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'listOpsNode',
+  label: 'List Operations',
+  color: '#14B8A6',
+  category: 'Data',
+  settings: [
+    { key: 'input', type: 'text', label: 'Input', default: '' },
+    { key: 'operations', type: 'text', label: 'Operations', default: '[{"op":"unique"}]' }
+  ]
+};
+
+export default ListOpsNode;
\ No newline at end of file
@@ -0,0 +1,297 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { Lock, Settings, Tag, Key, Hash, Clock, Timer, User, Unlock } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toNumber, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function LockNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localAction, setLocalAction] = useState('');
+  const [localName, setLocalName] = useState('');
+  const [localLeaseId, setLocalLeaseId] = useState('');
+  const [localPermits, setLocalPermits] = useState('');
+  const [localTimeoutSecs, setLocalTimeoutSecs] = useState('');
+  const [localTtlSecs, setLocalTtlSecs] = useState('');
+  const [localHolder, setLocalHolder] = useState('');
+
+  const isActionConnected = useHandleConnection(id, 'action');
+  const isNameConnected = useHandleConnection(id, 'name');
+  const isLeaseIdConnected = useHandleConnection(id, 'leaseId');
+  const isPermitsConnected = useHandleConnection(id, 'permits');
+  const isTimeoutSecsConnected = useHandleConnection(id, 'timeoutSecs');
+  const isTtlSecsConnected = useHandleConnection(id, 'ttlSecs');
+  const isHolderConnected = useHandleConnection(id, 'holder');
+
+  useEffect(() => {
+    setLocalAction(data?.action || '');
+    setLocalName(data?.name || '');
+    setLocalLeaseId(data?.leaseId || '');
+    setLocalPermits(data?.permits || '');
+    setLocalTimeoutSecs(data?.timeoutSecs || '');
+    setLocalTtlSecs(data?.ttlSecs || '');
+    setLocalHolder(data?.holder || '');
+  }, [data?.action, data?.name, data?.leaseId, data?.permits, data?.timeoutSecs, data?.ttlSecs, data?.holder]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'action' && !isActionConnected && data.action !== value) updateNodeData(id, { action: value });
+    if (key === 'name' && !isNameConnected && data.name !== value) updateNodeData(id, { name: value });
+    if (key === 'leaseId' && !isLeaseIdConnected && data.leaseId !== value) updateNodeData(id, { leaseId: value });
+    if (key === 'permits' && !isPermitsConnected && data.permits !== value) updateNodeData(id, { permits: value });
+    if (key === 'timeoutSecs' && !isTimeoutSecsConnected && data.timeoutSecs !== value) updateNodeData(id, { timeoutSecs: value });
+    if (key === 'ttlSecs' && !isTtlSecsConnected && data.ttlSecs !== value) updateNodeData(id, { ttlSecs: value });
+    if (key === 'holder' && !isHolderConnected && data.holder !== value) updateNodeData(id, { holder: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentAction = data?.action?.trim() || '';
+    const currentName = data?.name?.trim() || '';
+    const currentLeaseId = data?.leaseId?.trim() || '';
+    const currentPermits = data?.permits?.trim() || '';
+    const currentTimeoutSecs = data?.timeoutSecs?.trim() || '';
+    const currentTtlSecs = data?.ttlSecs?.trim() || '';
+    const currentHolder = data?.holder?.trim() || '';
+
+    const missingField = currentAction === 'release'
+      ? (!currentLeaseId ? 'Lease ID is required' : '')
+      : (!currentName ? 'Lock Name is required' : '');
+    if (missingField) {
+      console.warn('⚠️ LockNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult(missingField);
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, missingField);
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        name: currentName,
+        permits: toNumber(currentPermits),
+        timeoutSecs: toNumber(currentTimeoutSecs),
+        ttlSecs: toNumber(currentTtlSecs),
+        holder: currentHolder || undefined
+      };
+
+      console.log(`🔒 LockNode ${id}: Lock... (mode: ${mode})`);
+
+      // release 는 lease_id 만 받음
+      const resultData = currentAction === 'release'
+        ? await invoke<string>('release_lock', { leaseId: currentLeaseId })
+        : await invoke<string>('acquire_lock', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        leaseId: toText(parsed.lease_id),
+        waitedMs: toText(parsed.waited_ms),
+        released: toText(parsed.released ?? false)
+      };
+
+      setStatus('completed');
+      setResult(currentAction === 'release' ? (parsed.released ? 'Lock released' : 'Lease already expired') : `Lock acquired (${parsed.waited_ms} ms)`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 LockNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 LockNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Lock failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.action, data?.name, data?.leaseId, data?.permits, data?.timeoutSecs, data?.ttlSecs, data?.holder, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`🔒 Lock node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Lock"
+      icon={<Lock size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Acquire a named lock (waits until free) or release it with the lease ID"
+    >
+      <div onBlur={() => handleBlur('action', localAction)}>
+        <InputField
+          nodeId={id}
+          label="Action"
+          icon={<Settings size={12} />}
+          value={localAction}
+          placeholder="acquire | release"
+          onChange={setLocalAction}
+          handleId="action"
+          disabled={isActionConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('name', localName)}>
+        <InputField
+          nodeId={id}
+          label="Lock Name"
+          icon={<Tag size={12} />}
+          value={localName}
+          placeholder="shared-report"
+          onChange={setLocalName}
+          handleId="name"
+          disabled={isNameConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('leaseId', localLeaseId)}>
+        <InputField
+          nodeId={id}
+          label="Lease ID"
+          icon={<Key size={12} />}
+          value={localLeaseId}
+          placeholder="Connect the lease from the acquiring Lock node"
+          onChange={setLocalLeaseId}
+          handleId="leaseId"
+          disabled={isLeaseIdConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('permits', localPermits)}>
+        <InputField
+          nodeId={id}
+          label="Permits"
+          icon={<Hash size={12} />}
+          value={localPermits}
+          placeholder="1"
+          onChange={setLocalPermits}
+          handleId="permits"
+          disabled={isPermitsConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('timeoutSecs', localTimeoutSecs)}>
+        <InputField
+          nodeId={id}
+          label="Timeout (sec)"
+          icon={<Clock size={12} />}
+          value={localTimeoutSecs}
+          placeholder="Wait forever when empty"
+          onChange={setLocalTimeoutSecs}
+          handleId="timeoutSecs"
+          disabled={isTimeoutSecsConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('ttlSecs', localTtlSecs)}>
+        <InputField
+          nodeId={id}
+          label="TTL (sec)"
+          icon={<Timer size={12} />}
+          value={localTtlSecs}
+          placeholder="Auto release after"
+          onChange={setLocalTtlSecs}
+          handleId="ttlSecs"
+          disabled={isTtlSecsConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('holder', localHolder)}>
+        <InputField
+          nodeId={id}
+          label="Holder"
+          icon={<User size={12} />}
+          value={localHolder}
+          placeholder="Workflow name"
+          onChange={setLocalHolder}
+          handleId="holder"
+          disabled={isHolderConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Lease ID"
+        icon={<Key size={12} />}
+        value={data.outputData?.leaseId || ''}
+        handleId="leaseId"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Waited (ms)"
+        icon={<Clock size={12} />}
+        value={data.outputData?.waitedMs || ''}
+        handleId="waitedMs"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Released"
+        icon={<Unlock size={12} />}
+        value={data.outputData?.released || ''}
+        handleId="released"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'lockNode',
+  label: 'Lock',
+  color: '#64748B',
+  category: 'Control',
+  settings: [
+    { key: 'action', type: 'text', label: 'Action', default: 'acquire' },
+    { key: 'name', type: 'text', label: 'Lock Name', default: '' },
+    { key: 'leaseId', type: 'text', label: 'Lease ID', default: '' },
+    { key: 'permits', type: 'text', label: 'Permits', default: '' },
+    { key: 'timeoutSecs', type: 'text', label: 'Timeout (sec)', default: '' },
+    { key: 'ttlSecs', type: 'text', label: 'TTL (sec)', default: '' },
+    { key: 'holder', type: 'text', label: 'Holder', default: '' }
+  ]
+};
+
+export default LockNode;
\ No newline at end of file
@@ -0,0 +1,255 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { ScrollText, Settings, MessageSquare, Braces, MessageCircle, Filter, Hash } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toJson, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function LogNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData, getCurrentRunId } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localLevel, setLocalLevel] = useState('');
+  const [localMessage, setLocalMessage] = useState('');
+  const [localContext, setLocalContext] = useState('');
+  const [localChatNodeId, setLocalChatNodeId] = useState('');
+  const [localMinChatLevel, setLocalMinChatLevel] = useState('');
+
+  const isLevelConnected = useHandleConnection(id, 'level');
+  const isMessageConnected = useHandleConnection(id, 'message');
+  const isContextConnected = useHandleConnection(id, 'context');
+  const isChatNodeIdConnected = useHandleConnection(id, 'chatNodeId');
+  const isMinChatLevelConnected = useHandleConnection(id, 'minChatLevel');
+
+  useEffect(() => {
+    setLocalLevel(data?.level || '');
+    setLocalMessage(data?.message || '');
+    setLocalContext(data?.context || '');
+    setLocalChatNodeId(data?.chatNodeId || '');
+    setLocalMinChatLevel(data?.minChatLevel || '');
+  }, [data?.level, data?.message, data?.context, data?.chatNodeId, data?.minChatLevel]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'level' && !isLevelConnected && data.level !== value) updateNodeData(id, { level: value });
+    if (key === 'message' && !isMessageConnected && data.message !== value) updateNodeData(id, { message: value });
+    if (key === 'context' && !isContextConnected && data.context !== value) updateNodeData(id, { context: value });
+    if (key === 'chatNodeId' && !isChatNodeIdConnected && data.chatNodeId !== value) updateNodeData(id, { chatNodeId: value });
+    if (key === 'minChatLevel' && !isMinChatLevelConnected && data.minChatLevel !== value) updateNodeData(id, { minChatLevel: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentLevel = data?.level?.trim() || '';
+    const currentMessage = data?.message || '';
+    const currentContext = data?.context?.trim() || '';
+    const currentChatNodeId = data?.chatNodeId?.trim() || '';
+    const currentMinChatLevel = data?.minChatLevel?.trim() || '';
+
+    if (!currentMessage) {
+      console.warn('⚠️ LogNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Message is required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Message is required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        level: currentLevel || undefined,
+        message: currentMessage,
+        context: toJson(currentContext),
+        runId: await getCurrentRunId(),
+        nodeId: id,
+        chatNodeId: currentChatNodeId || undefined,
+        minChatLevel: currentMinChatLevel || undefined
+      };
+
+      console.log(`📝 LogNode ${id}: Writing log... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('log_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        message: toText(parsed.message),
+        level: toText(parsed.level),
+        runId: toText(parsed.run_id)
+      };
+
+      setStatus('completed');
+      setResult(parsed.run_id ? 'Logged to run history' : 'Logged');
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 LogNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 LogNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Log failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.level, data?.message, data?.context, data?.chatNodeId, data?.minChatLevel, getCurrentRunId, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`📝 Log node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Log"
+      icon={<ScrollText size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Write a log line into the run history (and optionally the chat page)"
+    >
+      <div onBlur={() => handleBlur('level', localLevel)}>
+        <InputField
+          nodeId={id}
+          label="Level"
+          icon={<Settings size={12} />}
+          value={localLevel}
+          placeholder="debug | info | warn | error"
+          onChange={setLocalLevel}
+          handleId="level"
+          disabled={isLevelConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('message', localMessage)}>
+        <InputField
+          nodeId={id}
+          label="Message"
+          icon={<MessageSquare size={12} />}
+          value={localMessage}
+          placeholder="Processed {count} files"
+          onChange={setLocalMessage}
+          handleId="message"
+          disabled={isMessageConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('context', localContext)}>
+        <InputField
+          nodeId={id}
+          label="Context (JSON)"
+          icon={<Braces size={12} />}
+          value={localContext}
+          placeholder={'{"file": "report.pdf"}'}
+          onChange={setLocalContext}
+          handleId="context"
+          disabled={isContextConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('chatNodeId', localChatNodeId)}>
+        <InputField
+          nodeId={id}
+          label="Chat Node ID"
+          icon={<MessageCircle size={12} />}
+          value={localChatNodeId}
+          placeholder="Mirror to this chat page"
+          onChange={setLocalChatNodeId}
+          handleId="chatNodeId"
+          disabled={isChatNodeIdConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('minChatLevel', localMinChatLevel)}>
+        <InputField
+          nodeId={id}
+          label="Min Chat Level"
+          icon={<Filter size={12} />}
+          value={localMinChatLevel}
+          placeholder="info"
+          onChange={setLocalMinChatLevel}
+          handleId="minChatLevel"
+          disabled={isMinChatLevelConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Message"
+        icon={<MessageSquare size={12} />}
+        value={data.outputData?.message || ''}
+        handleId="message"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Level"
+        icon={<Settings size={12} />}
+        value={data.outputData?.level || ''}
+        handleId="level"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Run ID"
+        icon={<Hash size={12} />}
+        value={data.outputData?.runId || ''}
+        handleId="runId"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'logNode',
+  label: 'Log',
+  color: '#6B7280',
+  category: 'Utility',
+  settings: [
+    { key: 'level', type: 'text', label: 'Level', default: 'info' },
+    { key: 'message', type: 'text', label: 'Message', default: '' },
+    { key: 'context', type: 'text', label: 'Context (JSON)', default: '' },
+    { key: 'chatNodeId', type: 'text', label: 'Chat Node ID', default: '' },
+    { key: 'minChatLevel', type: 'text', label: 'Min Chat Level', default: '' }
+  ]
+};
+
+export default LogNode;
\ No newline at end of file
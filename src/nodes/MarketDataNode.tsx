@@ -0,0 +1,242 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { TrendingUp, Tags, Building2, DollarSign, Timer, List, XCircle } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toNumber, toList, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function MarketDataNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localSymbols, setLocalSymbols] = useState('');
+  const [localProvider, setLocalProvider] = useState('');
+  const [localVsCurrency, setLocalVsCurrency] = useState('');
+  const [localCacheSecs, setLocalCacheSecs] = useState('');
+
+  const isSymbolsConnected = useHandleConnection(id, 'symbols');
+  const isProviderConnected = useHandleConnection(id, 'provider');
+  const isVsCurrencyConnected = useHandleConnection(id, 'vsCurrency');
+  const isCacheSecsConnected = useHandleConnection(id, 'cacheSecs');
+
+  useEffect(() => {
+    setLocalSymbols(data?.symbols || '');
+    setLocalProvider(data?.provider || '');
+    setLocalVsCurrency(data?.vsCurrency || '');
+    setLocalCacheSecs(data?.cacheSecs || '');
+  }, [data?.symbols, data?.provider, data?.vsCurrency, data?.cacheSecs]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'symbols' && !isSymbolsConnected && data.symbols !== value) updateNodeData(id, { symbols: value });
+    if (key === 'provider' && !isProviderConnected && data.provider !== value) updateNodeData(id, { provider: value });
+    if (key === 'vsCurrency' && !isVsCurrencyConnected && data.vsCurrency !== value) updateNodeData(id, { vsCurrency: value });
+    if (key === 'cacheSecs' && !isCacheSecsConnected && data.cacheSecs !== value) updateNodeData(id, { cacheSecs: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentSymbols = data?.symbols?.trim() || '';
+    const currentProvider = data?.provider?.trim() || '';
+    const currentVsCurrency = data?.vsCurrency?.trim() || '';
+    const currentCacheSecs = data?.cacheSecs?.trim() || '';
+
+    if (!currentSymbols) {
+      console.warn('⚠️ MarketDataNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Symbols is required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Symbols is required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        symbols: toList(currentSymbols) ?? [],
+        provider: currentProvider || undefined,
+        vsCurrency: currentVsCurrency || undefined,
+        cacheSecs: toNumber(currentCacheSecs)
+      };
+
+      console.log(`📈 MarketDataNode ${id}: Fetching quotes... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('market_data_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        price: toText(parsed.quotes[0]?.price),
+        changePercent: toText(parsed.quotes[0]?.change_percent),
+        quotes: toText(parsed.quotes),
+        notFound: parsed.not_found.join(',')
+      };
+
+      setStatus('completed');
+      setResult(`${parsed.quotes.length} quote(s)`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 MarketDataNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 MarketDataNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Market data fetch failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.symbols, data?.provider, data?.vsCurrency, data?.cacheSecs, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`📈 Market Data node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Market Data"
+      icon={<TrendingUp size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Fetches stock or crypto quotes from Yahoo, Upbit or CoinGecko (cached)"
+    >
+      <div onBlur={() => handleBlur('symbols', localSymbols)}>
+        <InputField
+          nodeId={id}
+          label="Symbols"
+          icon={<Tags size={12} />}
+          value={localSymbols}
+          placeholder="AAPL, 005930.KS"
+          onChange={setLocalSymbols}
+          handleId="symbols"
+          disabled={isSymbolsConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('provider', localProvider)}>
+        <InputField
+          nodeId={id}
+          label="Provider"
+          icon={<Building2 size={12} />}
+          value={localProvider}
+          placeholder="yahoo | upbit | coingecko"
+          onChange={setLocalProvider}
+          handleId="provider"
+          disabled={isProviderConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('vsCurrency', localVsCurrency)}>
+        <InputField
+          nodeId={id}
+          label="Currency"
+          icon={<DollarSign size={12} />}
+          value={localVsCurrency}
+          placeholder="usd (coingecko)"
+          onChange={setLocalVsCurrency}
+          handleId="vsCurrency"
+          disabled={isVsCurrencyConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('cacheSecs', localCacheSecs)}>
+        <InputField
+          nodeId={id}
+          label="Cache (sec)"
+          icon={<Timer size={12} />}
+          value={localCacheSecs}
+          placeholder="60"
+          onChange={setLocalCacheSecs}
+          handleId="cacheSecs"
+          disabled={isCacheSecsConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="First Price"
+        icon={<DollarSign size={12} />}
+        value={data.outputData?.price || ''}
+        handleId="price"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="First Change %"
+        icon={<TrendingUp size={12} />}
+        value={data.outputData?.changePercent || ''}
+        handleId="changePercent"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Quotes"
+        icon={<List size={12} />}
+        value={data.outputData?.quotes || ''}
+        handleId="quotes"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Not Found"
+        icon={<XCircle size={12} />}
+        value={data.outputData?.notFound || ''}
+        handleId="notFound"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'marketDataNode',
+  label: 'Market Data',
+  color: '#0EA5E9',
+  category: 'Network',
+  settings: [
+    { key: 'symbols', type: 'text', label: 'Symbols', default: '' },
+    { key: 'provider', type: 'text', label: 'Provider', default: 'yahoo' },
+    { key: 'vsCurrency', type: 'text', label: 'Currency', default: '' },
+    { key: 'cacheSecs', type: 'text', label: 'Cache (sec)', default: '' }
+  ]
+};
+
+export default MarketDataNode;
\ No newline at end of file
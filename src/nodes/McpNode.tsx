@@ -0,0 +1,313 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { Blocks, Cable, Settings, Terminal, List, Braces, Link, Wrench, FileText } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toList, toJson, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function McpNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localTransport, setLocalTransport] = useState('');
+  const [localAction, setLocalAction] = useState('');
+  const [localCommand, setLocalCommand] = useState('');
+  const [localArgs, setLocalArgs] = useState('');
+  const [localEnv, setLocalEnv] = useState('');
+  const [localUrl, setLocalUrl] = useState('');
+  const [localToolName, setLocalToolName] = useState('');
+  const [localArguments, setLocalArguments] = useState('');
+
+  const isTransportConnected = useHandleConnection(id, 'transport');
+  const isActionConnected = useHandleConnection(id, 'action');
+  const isCommandConnected = useHandleConnection(id, 'command');
+  const isArgsConnected = useHandleConnection(id, 'args');
+  const isEnvConnected = useHandleConnection(id, 'env');
+  const isUrlConnected = useHandleConnection(id, 'url');
+  const isToolNameConnected = useHandleConnection(id, 'toolName');
+  const isArgumentsConnected = useHandleConnection(id, 'arguments');
+
+  useEffect(() => {
+    setLocalTransport(data?.transport || '');
+    setLocalAction(data?.action || '');
+    setLocalCommand(data?.command || '');
+    setLocalArgs(data?.args || '');
+    setLocalEnv(data?.env || '');
+    setLocalUrl(data?.url || '');
+    setLocalToolName(data?.toolName || '');
+    setLocalArguments(data?.arguments || '');
+  }, [data?.transport, data?.action, data?.command, data?.args, data?.env, data?.url, data?.toolName, data?.arguments]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'transport' && !isTransportConnected && data.transport !== value) updateNodeData(id, { transport: value });
+    if (key === 'action' && !isActionConnected && data.action !== value) updateNodeData(id, { action: value });
+    if (key === 'command' && !isCommandConnected && data.command !== value) updateNodeData(id, { command: value });
+    if (key === 'args' && !isArgsConnected && data.args !== value) updateNodeData(id, { args: value });
+    if (key === 'env' && !isEnvConnected && data.env !== value) updateNodeData(id, { env: value });
+    if (key === 'url' && !isUrlConnected && data.url !== value) updateNodeData(id, { url: value });
+    if (key === 'toolName' && !isToolNameConnected && data.toolName !== value) updateNodeData(id, { toolName: value });
+    if (key === 'arguments' && !isArgumentsConnected && data.arguments !== value) updateNodeData(id, { arguments: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentTransport = data?.transport?.trim() || '';
+    const currentAction = data?.action?.trim() || '';
+    const currentCommand = data?.command?.trim() || '';
+    const currentArgs = data?.args?.trim() || '';
+    const currentEnv = data?.env?.trim() || '';
+    const currentUrl = data?.url?.trim() || '';
+    const currentToolName = data?.toolName?.trim() || '';
+    const currentArguments = data?.arguments?.trim() || '';
+
+    if (!currentTransport || !currentAction) {
+      console.warn('⚠️ McpNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Transport and Action are required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Transport and Action are required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        transport: currentTransport,
+        action: currentAction,
+        command: currentCommand || undefined,
+        args: toList(currentArgs),
+        env: toJson(currentEnv),
+        url: currentUrl || undefined,
+        toolName: currentToolName || undefined,
+        arguments: toJson(currentArguments)
+      };
+
+      console.log(`🧩 McpNode ${id}: Calling MCP server... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('mcp_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        text: toText(parsed.text),
+        content: toText(parsed.content),
+        tools: toText(parsed.tools)
+      };
+
+      setStatus('completed');
+      setResult(parsed.tools ? `${parsed.tools.length} tools` : `Called ${parsed.tool}`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 McpNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 McpNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ MCP call failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.transport, data?.action, data?.command, data?.args, data?.env, data?.url, data?.toolName, data?.arguments, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`🧩 MCP Client node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="MCP Client"
+      icon={<Blocks size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Lists or calls tools on an MCP server over stdio or SSE"
+    >
+      <div onBlur={() => handleBlur('transport', localTransport)}>
+        <InputField
+          nodeId={id}
+          label="Transport"
+          icon={<Cable size={12} />}
+          value={localTransport}
+          placeholder="stdio | sse"
+          onChange={setLocalTransport}
+          handleId="transport"
+          disabled={isTransportConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('action', localAction)}>
+        <InputField
+          nodeId={id}
+          label="Action"
+          icon={<Settings size={12} />}
+          value={localAction}
+          placeholder="list_tools | call_tool"
+          onChange={setLocalAction}
+          handleId="action"
+          disabled={isActionConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('command', localCommand)}>
+        <InputField
+          nodeId={id}
+          label="Command"
+          icon={<Terminal size={12} />}
+          value={localCommand}
+          placeholder="npx (stdio)"
+          onChange={setLocalCommand}
+          handleId="command"
+          disabled={isCommandConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('args', localArgs)}>
+        <InputField
+          nodeId={id}
+          label="Arguments"
+          icon={<List size={12} />}
+          value={localArgs}
+          placeholder="-y, @modelcontextprotocol/server-filesystem, /tmp"
+          onChange={setLocalArgs}
+          handleId="args"
+          disabled={isArgsConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('env', localEnv)}>
+        <InputField
+          nodeId={id}
+          label="Environment"
+          icon={<Braces size={12} />}
+          value={localEnv}
+          placeholder={'{"API_KEY":"..."}'}
+          onChange={setLocalEnv}
+          handleId="env"
+          disabled={isEnvConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('url', localUrl)}>
+        <InputField
+          nodeId={id}
+          label="SSE URL"
+          icon={<Link size={12} />}
+          value={localUrl}
+          placeholder="http://localhost:3001/sse (sse)"
+          onChange={setLocalUrl}
+          handleId="url"
+          disabled={isUrlConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('toolName', localToolName)}>
+        <InputField
+          nodeId={id}
+          label="Tool Name"
+          icon={<Wrench size={12} />}
+          value={localToolName}
+          placeholder="read_file (call_tool)"
+          onChange={setLocalToolName}
+          handleId="toolName"
+          disabled={isToolNameConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('arguments', localArguments)}>
+        <InputField
+          nodeId={id}
+          label="Tool Arguments"
+          icon={<Braces size={12} />}
+          value={localArguments}
+          placeholder={'{"path":"/tmp/a.txt"}'}
+          onChange={setLocalArguments}
+          handleId="arguments"
+          disabled={isArgumentsConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Text"
+        icon={<FileText size={12} />}
+        value={data.outputData?.text || ''}
+        handleId="text"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Content"
+        icon={<Braces size={12} />}
+        value={data.outputData?.content || ''}
+        handleId="content"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Tools"
+        icon={<Wrench size={12} />}
+        value={data.outputData?.tools || ''}
+        handleId="tools"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'mcpNode',
+  label: 'MCP Client',
+  color: '#7C4DFF',
+  category: 'AI',
+  settings: [
+    { key: 'transport', type: 'text', label: 'Transport', default: 'stdio' },
+    { key: 'action', type: 'text', label: 'Action', default: 'list_tools' },
+    { key: 'command', type: 'text', label: 'Command', default: '' },
+    { key: 'args', type: 'text', label: 'Arguments', default: '' },
+    { key: 'env', type: 'text', label: 'Environment', default: '' },
+    { key: 'url', type: 'text', label: 'SSE URL', default: '' },
+    { key: 'toolName', type: 'text', label: 'Tool Name', default: '' },
+    { key: 'arguments', type: 'text', label: 'Tool Arguments', default: '' }
+  ]
+};
+
+export default McpNode;
\ No newline at end of file
@@ -0,0 +1,184 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { Volume2, Play } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toNumber, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function MediaControlNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localAction, setLocalAction] = useState('');
+  const [localLevel, setLocalLevel] = useState('');
+
+  const isActionConnected = useHandleConnection(id, 'action');
+  const isLevelConnected = useHandleConnection(id, 'level');
+
+  useEffect(() => {
+    setLocalAction(data?.action || '');
+    setLocalLevel(data?.level || '');
+  }, [data?.action, data?.level]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'action' && !isActionConnected && data.action !== value) updateNodeData(id, { action: value });
+    if (key === 'level' && !isLevelConnected && data.level !== value) updateNodeData(id, { level: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentAction = data?.action?.trim() || '';
+    const currentLevel = data?.level?.trim() || '';
+
+    if (!currentAction) {
+      console.warn('⚠️ MediaControlNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Action is required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Action is required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        action: currentAction,
+        level: toNumber(currentLevel)
+      };
+
+      console.log(`🔊 MediaControlNode ${id}: Sending media command... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('media_control_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        action: toText(parsed.action),
+        level: toText(parsed.level)
+      };
+
+      setStatus('completed');
+      setResult(`Media ${parsed.action} done`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 MediaControlNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 MediaControlNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Media control failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.action, data?.level, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`🔊 Media Control node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Media Control"
+      icon={<Volume2 size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Controls system volume and media playback"
+    >
+      <div onBlur={() => handleBlur('action', localAction)}>
+        <InputField
+          nodeId={id}
+          label="Action"
+          icon={<Play size={12} />}
+          value={localAction}
+          placeholder="set_volume | mute | unmute | toggle_mute | play_pause | next | previous | stop"
+          onChange={setLocalAction}
+          handleId="action"
+          disabled={isActionConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('level', localLevel)}>
+        <InputField
+          nodeId={id}
+          label="Volume Level"
+          icon={<Volume2 size={12} />}
+          value={localLevel}
+          placeholder="0-100 (set_volume)"
+          onChange={setLocalLevel}
+          handleId="level"
+          disabled={isLevelConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Action"
+        icon={<Play size={12} />}
+        value={data.outputData?.action || ''}
+        handleId="action"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Volume Level"
+        icon={<Volume2 size={12} />}
+        value={data.outputData?.level || ''}
+        handleId="level"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'mediaControlNode',
+  label: 'Media Control',
+  color: '#EC4899',
+  category: 'System',
+  settings: [
+    { key: 'action', type: 'text', label: 'Action', default: 'play_pause' },
+    { key: 'level', type: 'text', label: 'Volume Level', default: '' }
+  ]
+};
+
+export default MediaControlNode;
\ No newline at end of file
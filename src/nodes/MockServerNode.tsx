@@ -0,0 +1,205 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { ServerCrash, Route, Plug, Network, Link } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toNumber, toBool, toJson, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function MockServerNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localRoutes, setLocalRoutes] = useState('');
+  const [localPort, setLocalPort] = useState('');
+  const [localBindAll, setLocalBindAll] = useState('');
+
+  const isRoutesConnected = useHandleConnection(id, 'routes');
+  const isPortConnected = useHandleConnection(id, 'port');
+  const isBindAllConnected = useHandleConnection(id, 'bindAll');
+
+  useEffect(() => {
+    setLocalRoutes(data?.routes || '');
+    setLocalPort(data?.port || '');
+    setLocalBindAll(data?.bindAll || '');
+  }, [data?.routes, data?.port, data?.bindAll]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'routes' && !isRoutesConnected && data.routes !== value) updateNodeData(id, { routes: value });
+    if (key === 'port' && !isPortConnected && data.port !== value) updateNodeData(id, { port: value });
+    if (key === 'bindAll' && !isBindAllConnected && data.bindAll !== value) updateNodeData(id, { bindAll: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentRoutes = data?.routes || '';
+    const currentPort = data?.port?.trim() || '';
+    const currentBindAll = data?.bindAll?.trim() || '';
+
+    if (!currentRoutes) {
+      console.warn('⚠️ MockServerNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Routes is required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Routes is required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        nodeId: id,
+        routes: toJson(currentRoutes) ?? [],
+        port: toNumber(currentPort),
+        bindAll: toBool(currentBindAll)
+      };
+
+      console.log(`🧪 MockServerNode ${id}: Starting mock server... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('mock_server_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        url: toText(parsed.url),
+        port: toText(parsed.port)
+      };
+
+      setStatus('completed');
+      setResult(`Listening on ${parsed.url} (${parsed.routes} routes)`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 MockServerNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 MockServerNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Mock server start failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.routes, data?.port, data?.bindAll, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`🧪 Mock Server node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Mock Server"
+      icon={<ServerCrash size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Starts a local HTTP stub that answers routes with canned JSON (restarting replaces it)"
+    >
+      <div onBlur={() => handleBlur('routes', localRoutes)}>
+        <InputField
+          nodeId={id}
+          label="Routes"
+          icon={<Route size={12} />}
+          value={localRoutes}
+          placeholder={'[{"method":"GET","path":"/api/users/:id","body":{"id":"{{params.id}}"}}]'}
+          onChange={setLocalRoutes}
+          handleId="routes"
+          disabled={isRoutesConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('port', localPort)}>
+        <InputField
+          nodeId={id}
+          label="Port"
+          icon={<Plug size={12} />}
+          value={localPort}
+          placeholder="Random when empty"
+          onChange={setLocalPort}
+          handleId="port"
+          disabled={isPortConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('bindAll', localBindAll)}>
+        <InputField
+          nodeId={id}
+          label="Bind All Interfaces"
+          icon={<Network size={12} />}
+          value={localBindAll}
+          placeholder="true | false (LAN access)"
+          onChange={setLocalBindAll}
+          handleId="bindAll"
+          disabled={isBindAllConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Server URL"
+        icon={<Link size={12} />}
+        value={data.outputData?.url || ''}
+        handleId="url"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Port"
+        icon={<Plug size={12} />}
+        value={data.outputData?.port || ''}
+        handleId="port"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'mockServerNode',
+  label: 'Mock Server',
+  color: '#0EA5E9',
+  category: 'Network',
+  settings: [
+    { key: 'routes', type: 'text', label: 'Routes', default: '' },
+    { key: 'port', type: 'text', label: 'Port', default: '' },
+    { key: 'bindAll', type: 'text', label: 'Bind All Interfaces', default: '' }
+  ]
+};
+
+export default MockServerNode;
\ No newline at end of file
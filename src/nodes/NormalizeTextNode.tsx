@@ -0,0 +1,264 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { WrapText, Files, CornerDownLeft, Eraser, Scissors, IndentIncrease, Ruler, CheckCircle } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toNumber, toBool, toList, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function NormalizeTextNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localFilePaths, setLocalFilePaths] = useState('');
+  const [localLineEnding, setLocalLineEnding] = useState('');
+  const [localStripBom, setLocalStripBom] = useState('');
+  const [localTrimTrailing, setLocalTrimTrailing] = useState('');
+  const [localIndent, setLocalIndent] = useState('');
+  const [localTabWidth, setLocalTabWidth] = useState('');
+
+  const isFilePathsConnected = useHandleConnection(id, 'filePaths');
+  const isLineEndingConnected = useHandleConnection(id, 'lineEnding');
+  const isStripBomConnected = useHandleConnection(id, 'stripBom');
+  const isTrimTrailingConnected = useHandleConnection(id, 'trimTrailing');
+  const isIndentConnected = useHandleConnection(id, 'indent');
+  const isTabWidthConnected = useHandleConnection(id, 'tabWidth');
+
+  useEffect(() => {
+    setLocalFilePaths(data?.filePaths || '');
+    setLocalLineEnding(data?.lineEnding || '');
+    setLocalStripBom(data?.stripBom || '');
+    setLocalTrimTrailing(data?.trimTrailing || '');
+    setLocalIndent(data?.indent || '');
+    setLocalTabWidth(data?.tabWidth || '');
+  }, [data?.filePaths, data?.lineEnding, data?.stripBom, data?.trimTrailing, data?.indent, data?.tabWidth]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'filePaths' && !isFilePathsConnected && data.filePaths !== value) updateNodeData(id, { filePaths: value });
+    if (key === 'lineEnding' && !isLineEndingConnected && data.lineEnding !== value) updateNodeData(id, { lineEnding: value });
+    if (key === 'stripBom' && !isStripBomConnected && data.stripBom !== value) updateNodeData(id, { stripBom: value });
+    if (key === 'trimTrailing' && !isTrimTrailingConnected && data.trimTrailing !== value) updateNodeData(id, { trimTrailing: value });
+    if (key === 'indent' && !isIndentConnected && data.indent !== value) updateNodeData(id, { indent: value });
+    if (key === 'tabWidth' && !isTabWidthConnected && data.tabWidth !== value) updateNodeData(id, { tabWidth: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentFilePaths = data?.filePaths?.trim() || '';
+    const currentLineEnding = data?.lineEnding?.trim() || '';
+    const currentStripBom = data?.stripBom?.trim() || '';
+    const currentTrimTrailing = data?.trimTrailing?.trim() || '';
+    const currentIndent = data?.indent?.trim() || '';
+    const currentTabWidth = data?.tabWidth?.trim() || '';
+
+    if (!currentFilePaths) {
+      console.warn('⚠️ NormalizeTextNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('File Paths is required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'File Paths is required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        filePaths: toList(currentFilePaths) ?? [],
+        lineEnding: currentLineEnding || undefined,
+        stripBom: toBool(currentStripBom),
+        trimTrailing: toBool(currentTrimTrailing),
+        indent: currentIndent || undefined,
+        tabWidth: toNumber(currentTabWidth)
+      };
+
+      console.log(`🧹 NormalizeTextNode ${id}: Normalizing text files... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('normalize_text_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        changedCount: toText(parsed.changed_count),
+        files: toText(parsed.files)
+      };
+
+      setStatus('completed');
+      setResult(`${parsed.changed_count} file(s) changed`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 NormalizeTextNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 NormalizeTextNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Text normalization failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.filePaths, data?.lineEnding, data?.stripBom, data?.trimTrailing, data?.indent, data?.tabWidth, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`🧹 Normalize Text node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Normalize Text"
+      icon={<WrapText size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Normalizes line endings, BOM, trailing spaces and indentation of text files"
+    >
+      <div onBlur={() => handleBlur('filePaths', localFilePaths)}>
+        <InputField
+          nodeId={id}
+          label="File Paths"
+          icon={<Files size={12} />}
+          value={localFilePaths}
+          placeholder="/path/a.txt, /path/b.md"
+          onChange={setLocalFilePaths}
+          handleId="filePaths"
+          disabled={isFilePathsConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('lineEnding', localLineEnding)}>
+        <InputField
+          nodeId={id}
+          label="Line Ending"
+          icon={<CornerDownLeft size={12} />}
+          value={localLineEnding}
+          placeholder="lf | crlf | keep"
+          onChange={setLocalLineEnding}
+          handleId="lineEnding"
+          disabled={isLineEndingConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('stripBom', localStripBom)}>
+        <InputField
+          nodeId={id}
+          label="Strip BOM"
+          icon={<Eraser size={12} />}
+          value={localStripBom}
+          placeholder="true | false"
+          onChange={setLocalStripBom}
+          handleId="stripBom"
+          disabled={isStripBomConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('trimTrailing', localTrimTrailing)}>
+        <InputField
+          nodeId={id}
+          label="Trim Trailing Spaces"
+          icon={<Scissors size={12} />}
+          value={localTrimTrailing}
+          placeholder="true | false"
+          onChange={setLocalTrimTrailing}
+          handleId="trimTrailing"
+          disabled={isTrimTrailingConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('indent', localIndent)}>
+        <InputField
+          nodeId={id}
+          label="Indent"
+          icon={<IndentIncrease size={12} />}
+          value={localIndent}
+          placeholder="spaces | tabs | keep"
+          onChange={setLocalIndent}
+          handleId="indent"
+          disabled={isIndentConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('tabWidth', localTabWidth)}>
+        <InputField
+          nodeId={id}
+          label="Tab Width"
+          icon={<Ruler size={12} />}
+          value={localTabWidth}
+          placeholder="4"
+          onChange={setLocalTabWidth}
+          handleId="tabWidth"
+          disabled={isTabWidthConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Changed Files"
+        icon={<CheckCircle size={12} />}
+        value={data.outputData?.changedCount || ''}
+        handleId="changedCount"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="File Results"
+        icon={<Files size={12} />}
+        value={data.outputData?.files || ''}
+        handleId="files"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'normalizeTextNode',
+  label: 'Normalize Text',
+  color: '#FF9800',
+  category: 'File',
+  settings: [
+    { key: 'filePaths', type: 'text', label: 'File Paths', default: '' },
+    { key: 'lineEnding', type: 'text', label: 'Line Ending', default: 'lf' },
+    { key: 'stripBom', type: 'text', label: 'Strip BOM', default: '' },
+    { key: 'trimTrailing', type: 'text', label: 'Trim Trailing Spaces', default: '' },
+    { key: 'indent', type: 'text', label: 'Indent', default: 'keep' },
+    { key: 'tabWidth', type: 'text', label: 'Tab Width', default: '' }
+  ]
+};
+
+export default NormalizeTextNode;
\ No newline at end of file
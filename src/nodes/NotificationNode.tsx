@@ -0,0 +1,282 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { Bell, Type, MessageSquare, MousePointerClick, Reply, Timer } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toNumber, toList, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+// 토스트 버튼 / 답장 결과 ("notification-action" 이벤트, Windows 전용)
+interface NotificationAction {
+  node_id: string;
+  result: 'activated' | 'dismissed' | 'timeout' | 'error';
+  action?: string;
+  reply?: string;
+  error?: string;
+}
+
+
+function NotificationNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localTitle, setLocalTitle] = useState('');
+  const [localMessage, setLocalMessage] = useState('');
+  const [localActions, setLocalActions] = useState('');
+  const [localReplyPlaceholder, setLocalReplyPlaceholder] = useState('');
+  const [localTimeoutSecs, setLocalTimeoutSecs] = useState('');
+
+  const isTitleConnected = useHandleConnection(id, 'title');
+  const isMessageConnected = useHandleConnection(id, 'message');
+  const isActionsConnected = useHandleConnection(id, 'actions');
+  const isReplyPlaceholderConnected = useHandleConnection(id, 'replyPlaceholder');
+  const isTimeoutSecsConnected = useHandleConnection(id, 'timeoutSecs');
+
+  useEffect(() => {
+    setLocalTitle(data?.title || '');
+    setLocalMessage(data?.message || '');
+    setLocalActions(data?.actions || '');
+    setLocalReplyPlaceholder(data?.replyPlaceholder || '');
+    setLocalTimeoutSecs(data?.timeoutSecs || '');
+  }, [data?.title, data?.message, data?.actions, data?.replyPlaceholder, data?.timeoutSecs]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'title' && !isTitleConnected && data.title !== value) updateNodeData(id, { title: value });
+    if (key === 'message' && !isMessageConnected && data.message !== value) updateNodeData(id, { message: value });
+    if (key === 'actions' && !isActionsConnected && data.actions !== value) updateNodeData(id, { actions: value });
+    if (key === 'replyPlaceholder' && !isReplyPlaceholderConnected && data.replyPlaceholder !== value) updateNodeData(id, { replyPlaceholder: value });
+    if (key === 'timeoutSecs' && !isTimeoutSecsConnected && data.timeoutSecs !== value) updateNodeData(id, { timeoutSecs: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentTitle = data?.title || '';
+    const currentMessage = data?.message || '';
+    const currentActions = data?.actions?.trim() || '';
+    const currentReplyPlaceholder = data?.replyPlaceholder?.trim() || '';
+    const currentTimeoutSecs = data?.timeoutSecs?.trim() || '';
+
+    if (!currentTitle.trim() && !currentMessage.trim()) {
+      console.warn('⚠️ NotificationNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Title or Message is required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Title or Message is required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        nodeId: id,
+        title: currentTitle,
+        message: currentMessage,
+        actions: toList(currentActions),
+        replyPlaceholder: currentReplyPlaceholder || undefined,
+        timeoutSecs: toNumber(currentTimeoutSecs)
+      };
+
+      console.log(`🔔 NotificationNode ${id}: Showing notification... (mode: ${mode})`);
+
+      // 토스트 결과가 invoke 응답보다 먼저 올 수 있으니 리스너를 먼저 등록
+      let resolveAction: (action: NotificationAction) => void = () => {};
+      const actionReceived = new Promise<NotificationAction>((resolve) => { resolveAction = resolve; });
+      const unlisten = await listen<NotificationAction>('notification-action', (event) => {
+        if (event.payload.node_id === id) resolveAction(event.payload);
+      });
+
+      let action: NotificationAction | undefined;
+      try {
+        const resultData = await invoke<string>('notification_node', params);
+        const parsed = JSON.parse(resultData);
+        // 버튼 / 답장 토스트면 사용자의 선택 (또는 dismissed / timeout) 까지 대기
+        action = parsed.interactive ? await actionReceived : undefined;
+      } finally {
+        unlisten();
+      }
+
+      if (action?.result === 'error') {
+        throw new Error(`TOAST_ERROR: ${action.error || 'unknown'}`);
+      }
+
+      const outputData = {
+        result: action?.result || 'shown',
+        action: action?.action || '',
+        reply: action?.reply || ''
+      };
+
+      setStatus('completed');
+      setResult(action ? `Notification ${action.result}` : 'Notification shown');
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 NotificationNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 NotificationNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Notification failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.title, data?.message, data?.actions, data?.replyPlaceholder, data?.timeoutSecs, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`🔔 Notification node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Notification"
+      icon={<Bell size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Shows a desktop notification; on Windows buttons and a reply box wait for the user"
+    >
+      <div onBlur={() => handleBlur('title', localTitle)}>
+        <InputField
+          nodeId={id}
+          label="Title"
+          icon={<Type size={12} />}
+          value={localTitle}
+          placeholder="Notification title"
+          onChange={setLocalTitle}
+          handleId="title"
+          disabled={isTitleConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('message', localMessage)}>
+        <InputField
+          nodeId={id}
+          label="Message"
+          icon={<MessageSquare size={12} />}
+          value={localMessage}
+          placeholder="Notification message"
+          onChange={setLocalMessage}
+          handleId="message"
+          disabled={isMessageConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('actions', localActions)}>
+        <InputField
+          nodeId={id}
+          label="Buttons"
+          icon={<MousePointerClick size={12} />}
+          value={localActions}
+          placeholder="Approve, Reject (Windows, max 5)"
+          onChange={setLocalActions}
+          handleId="actions"
+          disabled={isActionsConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('replyPlaceholder', localReplyPlaceholder)}>
+        <InputField
+          nodeId={id}
+          label="Reply Placeholder"
+          icon={<Reply size={12} />}
+          value={localReplyPlaceholder}
+          placeholder="Type a reply... (Windows)"
+          onChange={setLocalReplyPlaceholder}
+          handleId="replyPlaceholder"
+          disabled={isReplyPlaceholderConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('timeoutSecs', localTimeoutSecs)}>
+        <InputField
+          nodeId={id}
+          label="Timeout (sec)"
+          icon={<Timer size={12} />}
+          value={localTimeoutSecs}
+          placeholder="300"
+          onChange={setLocalTimeoutSecs}
+          handleId="timeoutSecs"
+          disabled={isTimeoutSecsConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Result"
+        icon={<Bell size={12} />}
+        value={data.outputData?.result || ''}
+        handleId="result"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Clicked Button"
+        icon={<MousePointerClick size={12} />}
+        value={data.outputData?.action || ''}
+        handleId="action"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Reply"
+        icon={<Reply size={12} />}
+        value={data.outputData?.reply || ''}
+        handleId="reply"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'notificationNode',
+  label: 'Notification',
+  color: '#F59E0B',
+  category: 'System',
+  settings: [
+    { key: 'title', type: 'text', label: 'Title', default: '' },
+    { key: 'message', type: 'text', label: 'Message', default: '' },
+    { key: 'actions', type: 'text', label: 'Buttons', default: '' },
+    { key: 'replyPlaceholder', type: 'text', label: 'Reply Placeholder', default: '' },
+    { key: 'timeoutSecs', type: 'text', label: 'Timeout (sec)', default: '' }
+  ]
+};
+
+export default NotificationNode;
\ No newline at end of file
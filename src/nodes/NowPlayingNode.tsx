@@ -0,0 +1,185 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { Music, Play, Radio, Type, User } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function NowPlayingNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localAction, setLocalAction] = useState('');
+  const [localPlayer, setLocalPlayer] = useState('');
+
+  const isActionConnected = useHandleConnection(id, 'action');
+  const isPlayerConnected = useHandleConnection(id, 'player');
+
+  useEffect(() => {
+    setLocalAction(data?.action || '');
+    setLocalPlayer(data?.player || '');
+  }, [data?.action, data?.player]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'action' && !isActionConnected && data.action !== value) updateNodeData(id, { action: value });
+    if (key === 'player' && !isPlayerConnected && data.player !== value) updateNodeData(id, { player: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentAction = data?.action?.trim() || '';
+    const currentPlayer = data?.player?.trim() || '';
+
+    setStatus('running');
+    try {
+      const params = {
+        action: currentAction || undefined,
+        player: currentPlayer || undefined
+      };
+
+      console.log(`🎵 NowPlayingNode ${id}: Reading current track... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('now_playing_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        text: toText(parsed.text),
+        title: toText(parsed.track?.title),
+        artist: toText(parsed.track?.artist),
+        playing: toText(parsed.playing)
+      };
+
+      setStatus('completed');
+      setResult(parsed.text || 'Nothing playing');
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 NowPlayingNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 NowPlayingNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Now playing failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.action, data?.player, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`🎵 Now Playing node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Now Playing"
+      icon={<Music size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Reads the currently playing track or controls the media player"
+    >
+      <div onBlur={() => handleBlur('action', localAction)}>
+        <InputField
+          nodeId={id}
+          label="Action"
+          icon={<Play size={12} />}
+          value={localAction}
+          placeholder="get | play_pause | play | pause | next | previous"
+          onChange={setLocalAction}
+          handleId="action"
+          disabled={isActionConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('player', localPlayer)}>
+        <InputField
+          nodeId={id}
+          label="Player"
+          icon={<Radio size={12} />}
+          value={localPlayer}
+          placeholder="Spotify (optional)"
+          onChange={setLocalPlayer}
+          handleId="player"
+          disabled={isPlayerConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Now Playing"
+        icon={<Music size={12} />}
+        value={data.outputData?.text || ''}
+        handleId="text"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Title"
+        icon={<Type size={12} />}
+        value={data.outputData?.title || ''}
+        handleId="title"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Artist"
+        icon={<User size={12} />}
+        value={data.outputData?.artist || ''}
+        handleId="artist"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Playing"
+        icon={<Play size={12} />}
+        value={data.outputData?.playing || ''}
+        handleId="playing"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'nowPlayingNode',
+  label: 'Now Playing',
+  color: '#EC4899',
+  category: 'Media',
+  settings: [
+    { key: 'action', type: 'text', label: 'Action', default: 'get' },
+    { key: 'player', type: 'text', label: 'Player', default: '' }
+  ]
+};
+
+export default NowPlayingNode;
\ No newline at end of file
@@ -0,0 +1,376 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { KeySquare, Settings, Ruler, CaseLower, CaseUpper, Hash, AtSign, EyeOff, Ban, WholeWord, Minus, BookOpen, Lock, Gauge } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toNumber, toBool, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function PasswordNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localMode, setLocalMode] = useState('');
+  const [localLength, setLocalLength] = useState('');
+  const [localLowercase, setLocalLowercase] = useState('');
+  const [localUppercase, setLocalUppercase] = useState('');
+  const [localDigits, setLocalDigits] = useState('');
+  const [localSymbols, setLocalSymbols] = useState('');
+  const [localExcludeAmbiguous, setLocalExcludeAmbiguous] = useState('');
+  const [localExcludeChars, setLocalExcludeChars] = useState('');
+  const [localWordCount, setLocalWordCount] = useState('');
+  const [localSeparator, setLocalSeparator] = useState('');
+  const [localWordlistPath, setLocalWordlistPath] = useState('');
+  const [localStoreAs, setLocalStoreAs] = useState('');
+
+  const isModeConnected = useHandleConnection(id, 'mode');
+  const isLengthConnected = useHandleConnection(id, 'length');
+  const isLowercaseConnected = useHandleConnection(id, 'lowercase');
+  const isUppercaseConnected = useHandleConnection(id, 'uppercase');
+  const isDigitsConnected = useHandleConnection(id, 'digits');
+  const isSymbolsConnected = useHandleConnection(id, 'symbols');
+  const isExcludeAmbiguousConnected = useHandleConnection(id, 'excludeAmbiguous');
+  const isExcludeCharsConnected = useHandleConnection(id, 'excludeChars');
+  const isWordCountConnected = useHandleConnection(id, 'wordCount');
+  const isSeparatorConnected = useHandleConnection(id, 'separator');
+  const isWordlistPathConnected = useHandleConnection(id, 'wordlistPath');
+  const isStoreAsConnected = useHandleConnection(id, 'storeAs');
+
+  useEffect(() => {
+    setLocalMode(data?.mode || '');
+    setLocalLength(data?.length || '');
+    setLocalLowercase(data?.lowercase || '');
+    setLocalUppercase(data?.uppercase || '');
+    setLocalDigits(data?.digits || '');
+    setLocalSymbols(data?.symbols || '');
+    setLocalExcludeAmbiguous(data?.excludeAmbiguous || '');
+    setLocalExcludeChars(data?.excludeChars || '');
+    setLocalWordCount(data?.wordCount || '');
+    setLocalSeparator(data?.separator || '');
+    setLocalWordlistPath(data?.wordlistPath || '');
+    setLocalStoreAs(data?.storeAs || '');
+  }, [data?.mode, data?.length, data?.lowercase, data?.uppercase, data?.digits, data?.symbols, data?.excludeAmbiguous, data?.excludeChars, data?.wordCount, data?.separator, data?.wordlistPath, data?.storeAs]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'mode' && !isModeConnected && data.mode !== value) updateNodeData(id, { mode: value });
+    if (key === 'length' && !isLengthConnected && data.length !== value) updateNodeData(id, { length: value });
+    if (key === 'lowercase' && !isLowercaseConnected && data.lowercase !== value) updateNodeData(id, { lowercase: value });
+    if (key === 'uppercase' && !isUppercaseConnected && data.uppercase !== value) updateNodeData(id, { uppercase: value });
+    if (key === 'digits' && !isDigitsConnected && data.digits !== value) updateNodeData(id, { digits: value });
+    if (key === 'symbols' && !isSymbolsConnected && data.symbols !== value) updateNodeData(id, { symbols: value });
+    if (key === 'excludeAmbiguous' && !isExcludeAmbiguousConnected && data.excludeAmbiguous !== value) updateNodeData(id, { excludeAmbiguous: value });
+    if (key === 'excludeChars' && !isExcludeCharsConnected && data.excludeChars !== value) updateNodeData(id, { excludeChars: value });
+    if (key === 'wordCount' && !isWordCountConnected && data.wordCount !== value) updateNodeData(id, { wordCount: value });
+    if (key === 'separator' && !isSeparatorConnected && data.separator !== value) updateNodeData(id, { separator: value });
+    if (key === 'wordlistPath' && !isWordlistPathConnected && data.wordlistPath !== value) updateNodeData(id, { wordlistPath: value });
+    if (key === 'storeAs' && !isStoreAsConnected && data.storeAs !== value) updateNodeData(id, { storeAs: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentMode = data?.mode?.trim() || '';
+    const currentLength = data?.length?.trim() || '';
+    const currentLowercase = data?.lowercase?.trim() || '';
+    const currentUppercase = data?.uppercase?.trim() || '';
+    const currentDigits = data?.digits?.trim() || '';
+    const currentSymbols = data?.symbols?.trim() || '';
+    const currentExcludeAmbiguous = data?.excludeAmbiguous?.trim() || '';
+    const currentExcludeChars = data?.excludeChars || '';
+    const currentWordCount = data?.wordCount?.trim() || '';
+    const currentSeparator = data?.separator || '';
+    const currentWordlistPath = data?.wordlistPath?.trim() || '';
+    const currentStoreAs = data?.storeAs?.trim() || '';
+
+    setStatus('running');
+    try {
+      const params = {
+        mode: currentMode || undefined,
+        length: toNumber(currentLength),
+        lowercase: toBool(currentLowercase),
+        uppercase: toBool(currentUppercase),
+        digits: toBool(currentDigits),
+        symbols: toBool(currentSymbols),
+        excludeAmbiguous: toBool(currentExcludeAmbiguous),
+        excludeChars: currentExcludeChars || undefined,
+        wordCount: toNumber(currentWordCount),
+        separator: currentSeparator || undefined,
+        wordlistPath: currentWordlistPath || undefined,
+        storeAs: currentStoreAs || undefined
+      };
+
+      console.log(`🔑 PasswordNode ${id}: Generating password... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('password_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        value: toText(parsed.value),
+        storedAs: toText(parsed.stored_as),
+        entropyBits: toText(parsed.entropy_bits)
+      };
+
+      setStatus('completed');
+      setResult(parsed.stored_as ? `Stored as ${parsed.stored_as}` : `${parsed.length} characters generated`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 PasswordNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 PasswordNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Password generation failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.mode, data?.length, data?.lowercase, data?.uppercase, data?.digits, data?.symbols, data?.excludeAmbiguous, data?.excludeChars, data?.wordCount, data?.separator, data?.wordlistPath, data?.storeAs, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`🔑 Password Generator node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Password Generator"
+      icon={<KeySquare size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Generates a password or diceware passphrase, optionally storing it as a secret"
+    >
+      <div onBlur={() => handleBlur('mode', localMode)}>
+        <InputField
+          nodeId={id}
+          label="Mode"
+          icon={<Settings size={12} />}
+          value={localMode}
+          placeholder="password | passphrase"
+          onChange={setLocalMode}
+          handleId="mode"
+          disabled={isModeConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('length', localLength)}>
+        <InputField
+          nodeId={id}
+          label="Length"
+          icon={<Ruler size={12} />}
+          value={localLength}
+          placeholder="20"
+          onChange={setLocalLength}
+          handleId="length"
+          disabled={isLengthConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('lowercase', localLowercase)}>
+        <InputField
+          nodeId={id}
+          label="Lowercase"
+          icon={<CaseLower size={12} />}
+          value={localLowercase}
+          placeholder="true | false"
+          onChange={setLocalLowercase}
+          handleId="lowercase"
+          disabled={isLowercaseConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('uppercase', localUppercase)}>
+        <InputField
+          nodeId={id}
+          label="Uppercase"
+          icon={<CaseUpper size={12} />}
+          value={localUppercase}
+          placeholder="true | false"
+          onChange={setLocalUppercase}
+          handleId="uppercase"
+          disabled={isUppercaseConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('digits', localDigits)}>
+        <InputField
+          nodeId={id}
+          label="Digits"
+          icon={<Hash size={12} />}
+          value={localDigits}
+          placeholder="true | false"
+          onChange={setLocalDigits}
+          handleId="digits"
+          disabled={isDigitsConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('symbols', localSymbols)}>
+        <InputField
+          nodeId={id}
+          label="Symbols"
+          icon={<AtSign size={12} />}
+          value={localSymbols}
+          placeholder="true | false"
+          onChange={setLocalSymbols}
+          handleId="symbols"
+          disabled={isSymbolsConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('excludeAmbiguous', localExcludeAmbiguous)}>
+        <InputField
+          nodeId={id}
+          label="Exclude Ambiguous"
+          icon={<EyeOff size={12} />}
+          value={localExcludeAmbiguous}
+          placeholder="true | false (0/O, 1/l/I)"
+          onChange={setLocalExcludeAmbiguous}
+          handleId="excludeAmbiguous"
+          disabled={isExcludeAmbiguousConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('excludeChars', localExcludeChars)}>
+        <InputField
+          nodeId={id}
+          label="Exclude Chars"
+          icon={<Ban size={12} />}
+          value={localExcludeChars}
+          placeholder="Characters to leave out"
+          onChange={setLocalExcludeChars}
+          handleId="excludeChars"
+          disabled={isExcludeCharsConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('wordCount', localWordCount)}>
+        <InputField
+          nodeId={id}
+          label="Word Count"
+          icon={<WholeWord size={12} />}
+          value={localWordCount}
+          placeholder="6 (passphrase)"
+          onChange={setLocalWordCount}
+          handleId="wordCount"
+          disabled={isWordCountConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('separator', localSeparator)}>
+        <InputField
+          nodeId={id}
+          label="Separator"
+          icon={<Minus size={12} />}
+          value={localSeparator}
+          placeholder="- (passphrase)"
+          onChange={setLocalSeparator}
+          handleId="separator"
+          disabled={isSeparatorConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('wordlistPath', localWordlistPath)}>
+        <InputField
+          nodeId={id}
+          label="Wordlist"
+          icon={<BookOpen size={12} />}
+          value={localWordlistPath}
+          placeholder="/path/to/eff_large_wordlist.txt"
+          onChange={setLocalWordlistPath}
+          handleId="wordlistPath"
+          disabled={isWordlistPathConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('storeAs', localStoreAs)}>
+        <InputField
+          nodeId={id}
+          label="Store As Secret"
+          icon={<Lock size={12} />}
+          value={localStoreAs}
+          placeholder="Secret name (value is not output)"
+          onChange={setLocalStoreAs}
+          handleId="storeAs"
+          disabled={isStoreAsConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Password"
+        icon={<KeySquare size={12} />}
+        value={data.outputData?.value || ''}
+        handleId="value"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Stored As"
+        icon={<Lock size={12} />}
+        value={data.outputData?.storedAs || ''}
+        handleId="storedAs"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Entropy (bits)"
+        icon={<Gauge size={12} />}
+        value={data.outputData?.entropyBits || ''}
+        handleId="entropyBits"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'passwordNode',
+  label: 'Password Generator',
+  color: '#EF4444',
+  category: 'Security',
+  settings: [
+    { key: 'mode', type: 'text', label: 'Mode', default: 'password' },
+    { key: 'length', type: 'text', label: 'Length', default: '' },
+    { key: 'lowercase', type: 'text', label: 'Lowercase', default: '' },
+    { key: 'uppercase', type: 'text', label: 'Uppercase', default: '' },
+    { key: 'digits', type: 'text', label: 'Digits', default: '' },
+    { key: 'symbols', type: 'text', label: 'Symbols', default: '' },
+    { key: 'excludeAmbiguous', type: 'text', label: 'Exclude Ambiguous', default: '' },
+    { key: 'excludeChars', type: 'text', label: 'Exclude Chars', default: '' },
+    { key: 'wordCount', type: 'text', label: 'Word Count', default: '' },
+    { key: 'separator', type: 'text', label: 'Separator', default: '' },
+    { key: 'wordlistPath', type: 'text', label: 'Wordlist', default: '' },
+    { key: 'storeAs', type: 'text', label: 'Store As Secret', default: '' }
+  ]
+};
+
+export default PasswordNode;
\ No newline at end of file
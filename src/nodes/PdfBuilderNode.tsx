@@ -0,0 +1,204 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { FileType2, Braces, Database, FolderOpen, Hash } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function PdfBuilderNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localTemplate, setLocalTemplate] = useState('');
+  const [localContext, setLocalContext] = useState('');
+  const [localOutputPath, setLocalOutputPath] = useState('');
+
+  const isTemplateConnected = useHandleConnection(id, 'template');
+  const isContextConnected = useHandleConnection(id, 'context');
+  const isOutputPathConnected = useHandleConnection(id, 'outputPath');
+
+  useEffect(() => {
+    setLocalTemplate(data?.template || '');
+    setLocalContext(data?.context || '');
+    setLocalOutputPath(data?.outputPath || '');
+  }, [data?.template, data?.context, data?.outputPath]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'template' && !isTemplateConnected && data.template !== value) updateNodeData(id, { template: value });
+    if (key === 'context' && !isContextConnected && data.context !== value) updateNodeData(id, { context: value });
+    if (key === 'outputPath' && !isOutputPathConnected && data.outputPath !== value) updateNodeData(id, { outputPath: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentTemplate = data?.template || '';
+    const currentContext = data?.context || '';
+    const currentOutputPath = data?.outputPath?.trim() || '';
+
+    if (!currentTemplate || !currentOutputPath) {
+      console.warn('⚠️ PdfBuilderNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Template and Output Path are required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Template and Output Path are required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        template: currentTemplate,
+        context: currentContext || undefined,
+        outputPath: currentOutputPath
+      };
+
+      console.log(`🧾 PdfBuilderNode ${id}: Building PDF... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('pdf_builder_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        filePath: toText(parsed.file_path),
+        pages: toText(parsed.pages)
+      };
+
+      setStatus('completed');
+      setResult(`PDF created (${parsed.pages} pages)`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 PdfBuilderNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 PdfBuilderNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ PDF build failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.template, data?.context, data?.outputPath, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`🧾 PDF Builder node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="PDF Builder"
+      icon={<FileType2 size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Builds a PDF (quotes, reports) from a JSON block template filled with {{context}} values"
+    >
+      <div onBlur={() => handleBlur('template', localTemplate)}>
+        <InputField
+          nodeId={id}
+          label="Template"
+          icon={<Braces size={12} />}
+          value={localTemplate}
+          placeholder={'{"header":"{{company}}","blocks":[{"type":"text","text":"..."}]}'}
+          onChange={setLocalTemplate}
+          handleId="template"
+          disabled={isTemplateConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('context', localContext)}>
+        <InputField
+          nodeId={id}
+          label="Context"
+          icon={<Database size={12} />}
+          value={localContext}
+          placeholder={'{"company":"ACME"}'}
+          onChange={setLocalContext}
+          handleId="context"
+          disabled={isContextConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('outputPath', localOutputPath)}>
+        <InputField
+          nodeId={id}
+          label="Output Path"
+          icon={<FolderOpen size={12} />}
+          value={localOutputPath}
+          placeholder="/Users/username/Desktop/quote.pdf"
+          onChange={setLocalOutputPath}
+          handleId="outputPath"
+          disabled={isOutputPathConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="PDF Path"
+        icon={<FileType2 size={12} />}
+        value={data.outputData?.filePath || ''}
+        handleId="filePath"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Pages"
+        icon={<Hash size={12} />}
+        value={data.outputData?.pages || ''}
+        handleId="pages"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'pdfBuilderNode',
+  label: 'PDF Builder',
+  color: '#FF9800',
+  category: 'File',
+  settings: [
+    { key: 'template', type: 'text', label: 'Template', default: '' },
+    { key: 'context', type: 'text', label: 'Context', default: '' },
+    { key: 'outputPath', type: 'text', label: 'Output Path', default: '' }
+  ]
+};
+
+export default PdfBuilderNode;
\ No newline at end of file
@@ -0,0 +1,282 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { Images, FolderInput, FolderOutput, FolderTree, Settings, Eye, Layers, CheckCircle, Copy, XCircle, List } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toBool, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function PhotoSortNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localSource, setLocalSource] = useState('');
+  const [localDestination, setLocalDestination] = useState('');
+  const [localPattern, setLocalPattern] = useState('');
+  const [localMode, setLocalMode] = useState('');
+  const [localDryRun, setLocalDryRun] = useState('');
+  const [localRecursive, setLocalRecursive] = useState('');
+
+  const isSourceConnected = useHandleConnection(id, 'source');
+  const isDestinationConnected = useHandleConnection(id, 'destination');
+  const isPatternConnected = useHandleConnection(id, 'pattern');
+  const isModeConnected = useHandleConnection(id, 'mode');
+  const isDryRunConnected = useHandleConnection(id, 'dryRun');
+  const isRecursiveConnected = useHandleConnection(id, 'recursive');
+
+  useEffect(() => {
+    setLocalSource(data?.source || '');
+    setLocalDestination(data?.destination || '');
+    setLocalPattern(data?.pattern || '');
+    setLocalMode(data?.mode || '');
+    setLocalDryRun(data?.dryRun || '');
+    setLocalRecursive(data?.recursive || '');
+  }, [data?.source, data?.destination, data?.pattern, data?.mode, data?.dryRun, data?.recursive]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'source' && !isSourceConnected && data.source !== value) updateNodeData(id, { source: value });
+    if (key === 'destination' && !isDestinationConnected && data.destination !== value) updateNodeData(id, { destination: value });
+    if (key === 'pattern' && !isPatternConnected && data.pattern !== value) updateNodeData(id, { pattern: value });
+    if (key === 'mode' && !isModeConnected && data.mode !== value) updateNodeData(id, { mode: value });
+    if (key === 'dryRun' && !isDryRunConnected && data.dryRun !== value) updateNodeData(id, { dryRun: value });
+    if (key === 'recursive' && !isRecursiveConnected && data.recursive !== value) updateNodeData(id, { recursive: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentSource = data?.source?.trim() || '';
+    const currentDestination = data?.destination?.trim() || '';
+    const currentPattern = data?.pattern?.trim() || '';
+    const currentMode = data?.mode?.trim() || '';
+    const currentDryRun = data?.dryRun?.trim() || '';
+    const currentRecursive = data?.recursive?.trim() || '';
+
+    if (!currentSource || !currentDestination) {
+      console.warn('⚠️ PhotoSortNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Source Folder and Destination Folder are required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Source Folder and Destination Folder are required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        source: currentSource,
+        destination: currentDestination,
+        pattern: currentPattern || undefined,
+        mode: currentMode || undefined,
+        dryRun: toBool(currentDryRun),
+        recursive: toBool(currentRecursive)
+      };
+
+      console.log(`🗂️ PhotoSortNode ${id}: Sorting photos... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('photo_sort_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        processed: toText(parsed.processed),
+        duplicates: toText(parsed.duplicates),
+        failed: toText(parsed.failed),
+        files: toText(parsed.files)
+      };
+
+      setStatus('completed');
+      setResult(`${parsed.dry_run ? 'Planned' : 'Sorted'} ${parsed.processed} file(s)`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 PhotoSortNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 PhotoSortNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Photo sort failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.source, data?.destination, data?.pattern, data?.mode, data?.dryRun, data?.recursive, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`🗂️ Photo Sort node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Photo Sort"
+      icon={<Images size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Moves or copies photos and videos into folders by capture date (EXIF)"
+    >
+      <div onBlur={() => handleBlur('source', localSource)}>
+        <InputField
+          nodeId={id}
+          label="Source Folder"
+          icon={<FolderInput size={12} />}
+          value={localSource}
+          placeholder="/Users/username/Pictures/Import"
+          onChange={setLocalSource}
+          handleId="source"
+          disabled={isSourceConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('destination', localDestination)}>
+        <InputField
+          nodeId={id}
+          label="Destination Folder"
+          icon={<FolderOutput size={12} />}
+          value={localDestination}
+          placeholder="/Users/username/Pictures/Sorted"
+          onChange={setLocalDestination}
+          handleId="destination"
+          disabled={isDestinationConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('pattern', localPattern)}>
+        <InputField
+          nodeId={id}
+          label="Folder Pattern"
+          icon={<FolderTree size={12} />}
+          value={localPattern}
+          placeholder="{year}/{year}-{month}"
+          onChange={setLocalPattern}
+          handleId="pattern"
+          disabled={isPatternConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('mode', localMode)}>
+        <InputField
+          nodeId={id}
+          label="Mode"
+          icon={<Settings size={12} />}
+          value={localMode}
+          placeholder="move | copy"
+          onChange={setLocalMode}
+          handleId="mode"
+          disabled={isModeConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('dryRun', localDryRun)}>
+        <InputField
+          nodeId={id}
+          label="Dry Run"
+          icon={<Eye size={12} />}
+          value={localDryRun}
+          placeholder="true | false"
+          onChange={setLocalDryRun}
+          handleId="dryRun"
+          disabled={isDryRunConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('recursive', localRecursive)}>
+        <InputField
+          nodeId={id}
+          label="Recursive"
+          icon={<Layers size={12} />}
+          value={localRecursive}
+          placeholder="true | false"
+          onChange={setLocalRecursive}
+          handleId="recursive"
+          disabled={isRecursiveConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Processed"
+        icon={<CheckCircle size={12} />}
+        value={data.outputData?.processed || ''}
+        handleId="processed"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Duplicates"
+        icon={<Copy size={12} />}
+        value={data.outputData?.duplicates || ''}
+        handleId="duplicates"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Failed"
+        icon={<XCircle size={12} />}
+        value={data.outputData?.failed || ''}
+        handleId="failed"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="File Report"
+        icon={<List size={12} />}
+        value={data.outputData?.files || ''}
+        handleId="files"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'photoSortNode',
+  label: 'Photo Sort',
+  color: '#FF9800',
+  category: 'File',
+  settings: [
+    { key: 'source', type: 'text', label: 'Source Folder', default: '' },
+    { key: 'destination', type: 'text', label: 'Destination Folder', default: '' },
+    { key: 'pattern', type: 'text', label: 'Folder Pattern', default: '' },
+    { key: 'mode', type: 'text', label: 'Mode', default: 'move' },
+    { key: 'dryRun', type: 'text', label: 'Dry Run', default: '' },
+    { key: 'recursive', type: 'text', label: 'Recursive', default: '' }
+  ]
+};
+
+export default PhotoSortNode;
\ No newline at end of file
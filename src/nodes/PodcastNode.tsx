@@ -0,0 +1,233 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { Podcast, Rss, FolderOpen, Archive, Download, Hash, List } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toNumber, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function PodcastNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localFeedUrl, setLocalFeedUrl] = useState('');
+  const [localDestination, setLocalDestination] = useState('');
+  const [localKeepLastN, setLocalKeepLastN] = useState('');
+  const [localMaxNew, setLocalMaxNew] = useState('');
+
+  const isFeedUrlConnected = useHandleConnection(id, 'feedUrl');
+  const isDestinationConnected = useHandleConnection(id, 'destination');
+  const isKeepLastNConnected = useHandleConnection(id, 'keepLastN');
+  const isMaxNewConnected = useHandleConnection(id, 'maxNew');
+
+  useEffect(() => {
+    setLocalFeedUrl(data?.feedUrl || '');
+    setLocalDestination(data?.destination || '');
+    setLocalKeepLastN(data?.keepLastN || '');
+    setLocalMaxNew(data?.maxNew || '');
+  }, [data?.feedUrl, data?.destination, data?.keepLastN, data?.maxNew]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'feedUrl' && !isFeedUrlConnected && data.feedUrl !== value) updateNodeData(id, { feedUrl: value });
+    if (key === 'destination' && !isDestinationConnected && data.destination !== value) updateNodeData(id, { destination: value });
+    if (key === 'keepLastN' && !isKeepLastNConnected && data.keepLastN !== value) updateNodeData(id, { keepLastN: value });
+    if (key === 'maxNew' && !isMaxNewConnected && data.maxNew !== value) updateNodeData(id, { maxNew: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentFeedUrl = data?.feedUrl?.trim() || '';
+    const currentDestination = data?.destination?.trim() || '';
+    const currentKeepLastN = data?.keepLastN?.trim() || '';
+    const currentMaxNew = data?.maxNew?.trim() || '';
+
+    if (!currentFeedUrl || !currentDestination) {
+      console.warn('⚠️ PodcastNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Feed URL and Destination Folder are required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Feed URL and Destination Folder are required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        feedUrl: currentFeedUrl,
+        destination: currentDestination,
+        keepLastN: toNumber(currentKeepLastN),
+        maxNew: toNumber(currentMaxNew)
+      };
+
+      console.log(`🎙️ PodcastNode ${id}: Checking podcast feed... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('podcast_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        downloaded: parsed.downloaded.map(e => e.file).join('\n'),
+        downloadedCount: String(parsed.downloaded.length),
+        files: parsed.files.join('\n')
+      };
+
+      setStatus('completed');
+      setResult(`${parsed.downloaded.length} new, ${parsed.removed.length} removed`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 PodcastNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 PodcastNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Podcast download failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.feedUrl, data?.destination, data?.keepLastN, data?.maxNew, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`🎙️ Podcast node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Podcast"
+      icon={<Podcast size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Downloads new episodes from a podcast RSS feed and keeps only the latest N"
+    >
+      <div onBlur={() => handleBlur('feedUrl', localFeedUrl)}>
+        <InputField
+          nodeId={id}
+          label="Feed URL"
+          icon={<Rss size={12} />}
+          value={localFeedUrl}
+          placeholder="https://example.com/feed.xml"
+          onChange={setLocalFeedUrl}
+          handleId="feedUrl"
+          disabled={isFeedUrlConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('destination', localDestination)}>
+        <InputField
+          nodeId={id}
+          label="Destination Folder"
+          icon={<FolderOpen size={12} />}
+          value={localDestination}
+          placeholder="/Users/username/Podcasts/Show"
+          onChange={setLocalDestination}
+          handleId="destination"
+          disabled={isDestinationConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('keepLastN', localKeepLastN)}>
+        <InputField
+          nodeId={id}
+          label="Keep Last N"
+          icon={<Archive size={12} />}
+          value={localKeepLastN}
+          placeholder="Keep all when empty"
+          onChange={setLocalKeepLastN}
+          handleId="keepLastN"
+          disabled={isKeepLastNConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('maxNew', localMaxNew)}>
+        <InputField
+          nodeId={id}
+          label="Max New Episodes"
+          icon={<Download size={12} />}
+          value={localMaxNew}
+          placeholder="Per run (optional)"
+          onChange={setLocalMaxNew}
+          handleId="maxNew"
+          disabled={isMaxNewConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="New Episodes"
+        icon={<Download size={12} />}
+        value={data.outputData?.downloaded || ''}
+        handleId="downloaded"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="New Count"
+        icon={<Hash size={12} />}
+        value={data.outputData?.downloadedCount || ''}
+        handleId="downloadedCount"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="All Files"
+        icon={<List size={12} />}
+        value={data.outputData?.files || ''}
+        handleId="files"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'podcastNode',
+  label: 'Podcast',
+  color: '#EC4899',
+  category: 'Media',
+  settings: [
+    { key: 'feedUrl', type: 'text', label: 'Feed URL', default: '' },
+    { key: 'destination', type: 'text', label: 'Destination Folder', default: '' },
+    { key: 'keepLastN', type: 'text', label: 'Keep Last N', default: '' },
+    { key: 'maxNew', type: 'text', label: 'Max New Episodes', default: '' }
+  ]
+};
+
+export default PodcastNode;
\ No newline at end of file
@@ -0,0 +1,262 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { Plug, Server, Timer, Hourglass, Clock, CheckCircle, List } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toNumber, toBool, toList, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function PortCheckNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localHost, setLocalHost] = useState('');
+  const [localPorts, setLocalPorts] = useState('');
+  const [localTimeoutMs, setLocalTimeoutMs] = useState('');
+  const [localWaitUntilOpen, setLocalWaitUntilOpen] = useState('');
+  const [localMaxWaitSecs, setLocalMaxWaitSecs] = useState('');
+
+  const isHostConnected = useHandleConnection(id, 'host');
+  const isPortsConnected = useHandleConnection(id, 'ports');
+  const isTimeoutMsConnected = useHandleConnection(id, 'timeoutMs');
+  const isWaitUntilOpenConnected = useHandleConnection(id, 'waitUntilOpen');
+  const isMaxWaitSecsConnected = useHandleConnection(id, 'maxWaitSecs');
+
+  useEffect(() => {
+    setLocalHost(data?.host || '');
+    setLocalPorts(data?.ports || '');
+    setLocalTimeoutMs(data?.timeoutMs || '');
+    setLocalWaitUntilOpen(data?.waitUntilOpen || '');
+    setLocalMaxWaitSecs(data?.maxWaitSecs || '');
+  }, [data?.host, data?.ports, data?.timeoutMs, data?.waitUntilOpen, data?.maxWaitSecs]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'host' && !isHostConnected && data.host !== value) updateNodeData(id, { host: value });
+    if (key === 'ports' && !isPortsConnected && data.ports !== value) updateNodeData(id, { ports: value });
+    if (key === 'timeoutMs' && !isTimeoutMsConnected && data.timeoutMs !== value) updateNodeData(id, { timeoutMs: value });
+    if (key === 'waitUntilOpen' && !isWaitUntilOpenConnected && data.waitUntilOpen !== value) updateNodeData(id, { waitUntilOpen: value });
+    if (key === 'maxWaitSecs' && !isMaxWaitSecsConnected && data.maxWaitSecs !== value) updateNodeData(id, { maxWaitSecs: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentHost = data?.host?.trim() || '';
+    const currentPorts = data?.ports?.trim() || '';
+    const currentTimeoutMs = data?.timeoutMs?.trim() || '';
+    const currentWaitUntilOpen = data?.waitUntilOpen?.trim() || '';
+    const currentMaxWaitSecs = data?.maxWaitSecs?.trim() || '';
+
+    if (!currentHost || !currentPorts) {
+      console.warn('⚠️ PortCheckNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Host and Ports are required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Host and Ports are required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        host: currentHost,
+        ports: (toList(currentPorts) ?? []).map(port => toNumber(port)),
+        timeoutMs: toNumber(currentTimeoutMs),
+        waitUntilOpen: toBool(currentWaitUntilOpen),
+        maxWaitSecs: toNumber(currentMaxWaitSecs)
+      };
+
+      console.log(`🔌 PortCheckNode ${id}: Checking ports... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('port_check_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        allOpen: toText(parsed.all_open),
+        openPorts: parsed.ports.filter(p => p.open).map(p => p.port).join(','),
+        ports: toText(parsed.ports),
+        elapsedMs: toText(parsed.elapsed_ms)
+      };
+
+      setStatus('completed');
+      setResult(parsed.all_open ? 'All ports open' : 'Some ports closed');
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 PortCheckNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 PortCheckNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Port check failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.host, data?.ports, data?.timeoutMs, data?.waitUntilOpen, data?.maxWaitSecs, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`🔌 Port Check node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Port Check"
+      icon={<Plug size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Checks whether TCP ports are open, optionally waiting until they all open"
+    >
+      <div onBlur={() => handleBlur('host', localHost)}>
+        <InputField
+          nodeId={id}
+          label="Host"
+          icon={<Server size={12} />}
+          value={localHost}
+          placeholder="127.0.0.1"
+          onChange={setLocalHost}
+          handleId="host"
+          disabled={isHostConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('ports', localPorts)}>
+        <InputField
+          nodeId={id}
+          label="Ports"
+          icon={<Plug size={12} />}
+          value={localPorts}
+          placeholder="80, 443, 5432"
+          onChange={setLocalPorts}
+          handleId="ports"
+          disabled={isPortsConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('timeoutMs', localTimeoutMs)}>
+        <InputField
+          nodeId={id}
+          label="Timeout (ms)"
+          icon={<Timer size={12} />}
+          value={localTimeoutMs}
+          placeholder="1000"
+          onChange={setLocalTimeoutMs}
+          handleId="timeoutMs"
+          disabled={isTimeoutMsConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('waitUntilOpen', localWaitUntilOpen)}>
+        <InputField
+          nodeId={id}
+          label="Wait Until Open"
+          icon={<Hourglass size={12} />}
+          value={localWaitUntilOpen}
+          placeholder="true | false"
+          onChange={setLocalWaitUntilOpen}
+          handleId="waitUntilOpen"
+          disabled={isWaitUntilOpenConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('maxWaitSecs', localMaxWaitSecs)}>
+        <InputField
+          nodeId={id}
+          label="Max Wait (sec)"
+          icon={<Clock size={12} />}
+          value={localMaxWaitSecs}
+          placeholder="60"
+          onChange={setLocalMaxWaitSecs}
+          handleId="maxWaitSecs"
+          disabled={isMaxWaitSecsConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="All Open"
+        icon={<CheckCircle size={12} />}
+        value={data.outputData?.allOpen || ''}
+        handleId="allOpen"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Open Ports"
+        icon={<Plug size={12} />}
+        value={data.outputData?.openPorts || ''}
+        handleId="openPorts"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Port Results"
+        icon={<List size={12} />}
+        value={data.outputData?.ports || ''}
+        handleId="ports"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Elapsed (ms)"
+        icon={<Timer size={12} />}
+        value={data.outputData?.elapsedMs || ''}
+        handleId="elapsedMs"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'portCheckNode',
+  label: 'Port Check',
+  color: '#0EA5E9',
+  category: 'Network',
+  settings: [
+    { key: 'host', type: 'text', label: 'Host', default: '127.0.0.1' },
+    { key: 'ports', type: 'text', label: 'Ports', default: '' },
+    { key: 'timeoutMs', type: 'text', label: 'Timeout (ms)', default: '' },
+    { key: 'waitUntilOpen', type: 'text', label: 'Wait Until Open', default: '' },
+    { key: 'maxWaitSecs', type: 'text', label: 'Max Wait (sec)', default: '' }
+  ]
+};
+
+export default PortCheckNode;
\ No newline at end of file
@@ -0,0 +1,165 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { UserCheck, Timer, CheckCircle, Maximize } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toNumber, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function PresenceNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localActiveThresholdSecs, setLocalActiveThresholdSecs] = useState('');
+
+  const isActiveThresholdSecsConnected = useHandleConnection(id, 'activeThresholdSecs');
+
+  useEffect(() => {
+    setLocalActiveThresholdSecs(data?.activeThresholdSecs || '');
+  }, [data?.activeThresholdSecs]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'activeThresholdSecs' && !isActiveThresholdSecsConnected && data.activeThresholdSecs !== value) updateNodeData(id, { activeThresholdSecs: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentActiveThresholdSecs = data?.activeThresholdSecs?.trim() || '';
+
+    setStatus('running');
+    try {
+      const params = {
+        activeThresholdSecs: toNumber(currentActiveThresholdSecs)
+      };
+
+      console.log(`👤 PresenceNode ${id}: Checking user presence... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('presence_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        active: toText(parsed.active),
+        available: toText(parsed.available),
+        idleSeconds: toText(parsed.idle_seconds),
+        fullscreen: toText(parsed.fullscreen)
+      };
+
+      setStatus('completed');
+      setResult(parsed.available ? 'User is available' : 'User is busy or away');
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 PresenceNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 PresenceNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Presence check failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.activeThresholdSecs, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`👤 Presence node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Presence"
+      icon={<UserCheck size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Checks whether the user is active (idle time, fullscreen, focus assist)"
+    >
+      <div onBlur={() => handleBlur('activeThresholdSecs', localActiveThresholdSecs)}>
+        <InputField
+          nodeId={id}
+          label="Active Threshold (sec)"
+          icon={<Timer size={12} />}
+          value={localActiveThresholdSecs}
+          placeholder="60"
+          onChange={setLocalActiveThresholdSecs}
+          handleId="activeThresholdSecs"
+          disabled={isActiveThresholdSecsConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Active"
+        icon={<UserCheck size={12} />}
+        value={data.outputData?.active || ''}
+        handleId="active"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Available"
+        icon={<CheckCircle size={12} />}
+        value={data.outputData?.available || ''}
+        handleId="available"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Idle Seconds"
+        icon={<Timer size={12} />}
+        value={data.outputData?.idleSeconds || ''}
+        handleId="idleSeconds"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Fullscreen"
+        icon={<Maximize size={12} />}
+        value={data.outputData?.fullscreen || ''}
+        handleId="fullscreen"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'presenceNode',
+  label: 'Presence',
+  color: '#10B981',
+  category: 'System',
+  settings: [
+    { key: 'activeThresholdSecs', type: 'text', label: 'Active Threshold (sec)', default: '' }
+  ]
+};
+
+export default PresenceNode;
\ No newline at end of file
@@ -0,0 +1,175 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { TextCursorInput, ListChecks, Type, Braces } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toJson, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function PromptNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localFields, setLocalFields] = useState('');
+  const [localTitle, setLocalTitle] = useState('');
+
+  const isFieldsConnected = useHandleConnection(id, 'fields');
+  const isTitleConnected = useHandleConnection(id, 'title');
+
+  useEffect(() => {
+    setLocalFields(data?.fields || '');
+    setLocalTitle(data?.title || '');
+  }, [data?.fields, data?.title]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'fields' && !isFieldsConnected && data.fields !== value) updateNodeData(id, { fields: value });
+    if (key === 'title' && !isTitleConnected && data.title !== value) updateNodeData(id, { title: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentFields = data?.fields || '';
+    const currentTitle = data?.title?.trim() || '';
+
+    if (!currentFields) {
+      console.warn('⚠️ PromptNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Fields is required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Fields is required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        fields: toJson(currentFields),
+        title: currentTitle || undefined
+      };
+
+      console.log(`📝 PromptNode ${id}: Asking for input... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('prompt_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        answers: resultData
+      };
+
+      setStatus('completed');
+      setResult(`${Object.keys(parsed).length} value(s) entered`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 PromptNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 PromptNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ User input failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.fields, data?.title, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`📝 User Input node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="User Input"
+      icon={<TextCursorInput size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Asks for values in native input dialogs mid-run and outputs them as JSON"
+    >
+      <div onBlur={() => handleBlur('fields', localFields)}>
+        <InputField
+          nodeId={id}
+          label="Fields"
+          icon={<ListChecks size={12} />}
+          value={localFields}
+          placeholder={'[{"name":"count","type":"number","required":true}]'}
+          onChange={setLocalFields}
+          handleId="fields"
+          disabled={isFieldsConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('title', localTitle)}>
+        <InputField
+          nodeId={id}
+          label="Dialog Title"
+          icon={<Type size={12} />}
+          value={localTitle}
+          placeholder="입력 요청"
+          onChange={setLocalTitle}
+          handleId="title"
+          disabled={isTitleConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Answers"
+        icon={<Braces size={12} />}
+        value={data.outputData?.answers || ''}
+        handleId="answers"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'promptNode',
+  label: 'User Input',
+  color: '#F59E0B',
+  category: 'Control',
+  settings: [
+    { key: 'fields', type: 'text', label: 'Fields', default: '' },
+    { key: 'title', type: 'text', label: 'Dialog Title', default: '' }
+  ]
+};
+
+export default PromptNode;
\ No newline at end of file
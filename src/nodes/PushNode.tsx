@@ -0,0 +1,344 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { Smartphone, Building2, MessageSquare, Type, Server, Hash, KeyRound, Flag, Link, Tags, Braces } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toList, toJson, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function PushNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localProvider, setLocalProvider] = useState('');
+  const [localMessage, setLocalMessage] = useState('');
+  const [localTitle, setLocalTitle] = useState('');
+  const [localServerUrl, setLocalServerUrl] = useState('');
+  const [localTopic, setLocalTopic] = useState('');
+  const [localToken, setLocalToken] = useState('');
+  const [localPriority, setLocalPriority] = useState('');
+  const [localClickUrl, setLocalClickUrl] = useState('');
+  const [localTags, setLocalTags] = useState('');
+  const [localContext, setLocalContext] = useState('');
+
+  const isProviderConnected = useHandleConnection(id, 'provider');
+  const isMessageConnected = useHandleConnection(id, 'message');
+  const isTitleConnected = useHandleConnection(id, 'title');
+  const isServerUrlConnected = useHandleConnection(id, 'serverUrl');
+  const isTopicConnected = useHandleConnection(id, 'topic');
+  const isTokenConnected = useHandleConnection(id, 'token');
+  const isPriorityConnected = useHandleConnection(id, 'priority');
+  const isClickUrlConnected = useHandleConnection(id, 'clickUrl');
+  const isTagsConnected = useHandleConnection(id, 'tags');
+  const isContextConnected = useHandleConnection(id, 'context');
+
+  useEffect(() => {
+    setLocalProvider(data?.provider || '');
+    setLocalMessage(data?.message || '');
+    setLocalTitle(data?.title || '');
+    setLocalServerUrl(data?.serverUrl || '');
+    setLocalTopic(data?.topic || '');
+    setLocalToken(data?.token || '');
+    setLocalPriority(data?.priority || '');
+    setLocalClickUrl(data?.clickUrl || '');
+    setLocalTags(data?.tags || '');
+    setLocalContext(data?.context || '');
+  }, [data?.provider, data?.message, data?.title, data?.serverUrl, data?.topic, data?.token, data?.priority, data?.clickUrl, data?.tags, data?.context]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'provider' && !isProviderConnected && data.provider !== value) updateNodeData(id, { provider: value });
+    if (key === 'message' && !isMessageConnected && data.message !== value) updateNodeData(id, { message: value });
+    if (key === 'title' && !isTitleConnected && data.title !== value) updateNodeData(id, { title: value });
+    if (key === 'serverUrl' && !isServerUrlConnected && data.serverUrl !== value) updateNodeData(id, { serverUrl: value });
+    if (key === 'topic' && !isTopicConnected && data.topic !== value) updateNodeData(id, { topic: value });
+    if (key === 'token' && !isTokenConnected && data.token !== value) updateNodeData(id, { token: value });
+    if (key === 'priority' && !isPriorityConnected && data.priority !== value) updateNodeData(id, { priority: value });
+    if (key === 'clickUrl' && !isClickUrlConnected && data.clickUrl !== value) updateNodeData(id, { clickUrl: value });
+    if (key === 'tags' && !isTagsConnected && data.tags !== value) updateNodeData(id, { tags: value });
+    if (key === 'context' && !isContextConnected && data.context !== value) updateNodeData(id, { context: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentProvider = data?.provider?.trim() || '';
+    const currentMessage = data?.message || '';
+    const currentTitle = data?.title || '';
+    const currentServerUrl = data?.serverUrl?.trim() || '';
+    const currentTopic = data?.topic?.trim() || '';
+    const currentToken = data?.token?.trim() || '';
+    const currentPriority = data?.priority?.trim() || '';
+    const currentClickUrl = data?.clickUrl?.trim() || '';
+    const currentTags = data?.tags?.trim() || '';
+    const currentContext = data?.context?.trim() || '';
+
+    if (!currentProvider || !currentMessage) {
+      console.warn('⚠️ PushNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Provider and Message are required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Provider and Message are required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        provider: currentProvider,
+        message: currentMessage,
+        title: currentTitle || undefined,
+        serverUrl: currentServerUrl || undefined,
+        topic: currentTopic || undefined,
+        token: currentToken || undefined,
+        priority: currentPriority || undefined,
+        clickUrl: currentClickUrl || undefined,
+        tags: toList(currentTags),
+        context: toJson(currentContext)
+      };
+
+      console.log(`📲 PushNode ${id}: Sending push notification... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('push_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        id: toText(parsed.id),
+        message: toText(parsed.message)
+      };
+
+      setStatus('completed');
+      setResult(`Sent via ${parsed.provider}`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 PushNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 PushNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Push notification failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.provider, data?.message, data?.title, data?.serverUrl, data?.topic, data?.token, data?.priority, data?.clickUrl, data?.tags, data?.context, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`📲 Push Notification node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Push Notification"
+      icon={<Smartphone size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Sends a phone push via ntfy or Gotify (title and message support {{templates}})"
+    >
+      <div onBlur={() => handleBlur('provider', localProvider)}>
+        <InputField
+          nodeId={id}
+          label="Provider"
+          icon={<Building2 size={12} />}
+          value={localProvider}
+          placeholder="ntfy | gotify"
+          onChange={setLocalProvider}
+          handleId="provider"
+          disabled={isProviderConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('message', localMessage)}>
+        <InputField
+          nodeId={id}
+          label="Message"
+          icon={<MessageSquare size={12} />}
+          value={localMessage}
+          placeholder="Backup finished: {{file}}"
+          onChange={setLocalMessage}
+          handleId="message"
+          disabled={isMessageConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('title', localTitle)}>
+        <InputField
+          nodeId={id}
+          label="Title"
+          icon={<Type size={12} />}
+          value={localTitle}
+          placeholder="Title (optional)"
+          onChange={setLocalTitle}
+          handleId="title"
+          disabled={isTitleConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('serverUrl', localServerUrl)}>
+        <InputField
+          nodeId={id}
+          label="Server URL"
+          icon={<Server size={12} />}
+          value={localServerUrl}
+          placeholder="https://ntfy.sh"
+          onChange={setLocalServerUrl}
+          handleId="serverUrl"
+          disabled={isServerUrlConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('topic', localTopic)}>
+        <InputField
+          nodeId={id}
+          label="Topic"
+          icon={<Hash size={12} />}
+          value={localTopic}
+          placeholder="my-alerts (ntfy)"
+          onChange={setLocalTopic}
+          handleId="topic"
+          disabled={isTopicConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('token', localToken)}>
+        <InputField
+          nodeId={id}
+          label="Token"
+          icon={<KeyRound size={12} />}
+          value={localToken}
+          placeholder="${secret:gotify} (gotify / protected ntfy)"
+          onChange={setLocalToken}
+          handleId="token"
+          disabled={isTokenConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('priority', localPriority)}>
+        <InputField
+          nodeId={id}
+          label="Priority"
+          icon={<Flag size={12} />}
+          value={localPriority}
+          placeholder="min | low | default | high | urgent"
+          onChange={setLocalPriority}
+          handleId="priority"
+          disabled={isPriorityConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('clickUrl', localClickUrl)}>
+        <InputField
+          nodeId={id}
+          label="Click URL"
+          icon={<Link size={12} />}
+          value={localClickUrl}
+          placeholder="https://example.com"
+          onChange={setLocalClickUrl}
+          handleId="clickUrl"
+          disabled={isClickUrlConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('tags', localTags)}>
+        <InputField
+          nodeId={id}
+          label="Tags"
+          icon={<Tags size={12} />}
+          value={localTags}
+          placeholder="warning, backup (ntfy)"
+          onChange={setLocalTags}
+          handleId="tags"
+          disabled={isTagsConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('context', localContext)}>
+        <InputField
+          nodeId={id}
+          label="Template Context"
+          icon={<Braces size={12} />}
+          value={localContext}
+          placeholder={'{"file":"db.sql"}'}
+          onChange={setLocalContext}
+          handleId="context"
+          disabled={isContextConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Message ID"
+        icon={<Hash size={12} />}
+        value={data.outputData?.id || ''}
+        handleId="id"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Sent Message"
+        icon={<MessageSquare size={12} />}
+        value={data.outputData?.message || ''}
+        handleId="message"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'pushNode',
+  label: 'Push Notification',
+  color: '#F59E0B',
+  category: 'Integration',
+  settings: [
+    { key: 'provider', type: 'text', label: 'Provider', default: 'ntfy' },
+    { key: 'message', type: 'text', label: 'Message', default: '' },
+    { key: 'title', type: 'text', label: 'Title', default: '' },
+    { key: 'serverUrl', type: 'text', label: 'Server URL', default: '' },
+    { key: 'topic', type: 'text', label: 'Topic', default: '' },
+    { key: 'token', type: 'text', label: 'Token', default: '' },
+    { key: 'priority', type: 'text', label: 'Priority', default: '' },
+    { key: 'clickUrl', type: 'text', label: 'Click URL', default: '' },
+    { key: 'tags', type: 'text', label: 'Tags', default: '' },
+    { key: 'context', type: 'text', label: 'Template Context', default: '' }
+  ]
+};
+
+export default PushNode;
\ No newline at end of file
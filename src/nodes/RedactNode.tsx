@@ -0,0 +1,213 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { EyeOff, FileText, ListChecks, Settings, Hash, List } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toList, toJson, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function RedactNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localText, setLocalText] = useState('');
+  const [localRules, setLocalRules] = useState('');
+  const [localStyle, setLocalStyle] = useState('');
+
+  const isTextConnected = useHandleConnection(id, 'text');
+  const isRulesConnected = useHandleConnection(id, 'rules');
+  const isStyleConnected = useHandleConnection(id, 'style');
+
+  useEffect(() => {
+    setLocalText(data?.text || '');
+    setLocalRules(data?.rules || '');
+    setLocalStyle(data?.style || '');
+  }, [data?.text, data?.rules, data?.style]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'text' && !isTextConnected && data.text !== value) updateNodeData(id, { text: value });
+    if (key === 'rules' && !isRulesConnected && data.rules !== value) updateNodeData(id, { rules: value });
+    if (key === 'style' && !isStyleConnected && data.style !== value) updateNodeData(id, { style: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentText = data?.text || '';
+    const currentRules = data?.rules?.trim() || '';
+    const currentStyle = data?.style?.trim() || '';
+
+    if (!currentText) {
+      console.warn('⚠️ RedactNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Text is required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Text is required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        text: currentText,
+        rules: currentRules.startsWith('[') ? toJson(currentRules) : toList(currentRules),
+        style: currentStyle || undefined
+      };
+
+      console.log(`🙈 RedactNode ${id}: Redacting... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('redact_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        text: toText(parsed.text),
+        redactedCount: toText(parsed.redacted_count),
+        counts: toText(parsed.counts)
+      };
+
+      setStatus('completed');
+      setResult(`${parsed.redacted_count} items redacted`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 RedactNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 RedactNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Redact failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.text, data?.rules, data?.style, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`🙈 Redact node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Redact"
+      icon={<EyeOff size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Mask emails, phone numbers, RRN, card numbers or custom patterns before sending text out"
+    >
+      <div onBlur={() => handleBlur('text', localText)}>
+        <InputField
+          nodeId={id}
+          label="Text"
+          icon={<FileText size={12} />}
+          value={localText}
+          placeholder="Text to redact"
+          onChange={setLocalText}
+          handleId="text"
+          disabled={isTextConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('rules', localRules)}>
+        <InputField
+          nodeId={id}
+          label="Rules"
+          icon={<ListChecks size={12} />}
+          value={localRules}
+          placeholder={'email, phone or [{"name": "id", "pattern": "EMP-\\d+"}]'}
+          onChange={setLocalRules}
+          handleId="rules"
+          disabled={isRulesConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('style', localStyle)}>
+        <InputField
+          nodeId={id}
+          label="Style"
+          icon={<Settings size={12} />}
+          value={localStyle}
+          placeholder="label | partial"
+          onChange={setLocalStyle}
+          handleId="style"
+          disabled={isStyleConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Redacted Text"
+        icon={<FileText size={12} />}
+        value={data.outputData?.text || ''}
+        handleId="text"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Redacted Count"
+        icon={<Hash size={12} />}
+        value={data.outputData?.redactedCount || ''}
+        handleId="redactedCount"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Counts"
+        icon={<List size={12} />}
+        value={data.outputData?.counts || ''}
+        handleId="counts"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'redactNode',
+  label: 'Redact',
+  color: '#475569',
+  category: 'Text',
+  settings: [
+    { key: 'text', type: 'text', label: 'Text', default: '' },
+    { key: 'rules', type: 'text', label: 'Rules', default: '' },
+    { key: 'style', type: 'text', label: 'Style', default: 'label' }
+  ]
+};
+
+export default RedactNode;
\ No newline at end of file
@@ -0,0 +1,213 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { ServerCog, Settings, Timer, Activity, Hash, Braces } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toNumber, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function ServiceNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localAction, setLocalAction] = useState('');
+  const [localServiceName, setLocalServiceName] = useState('');
+  const [localTimeoutSecs, setLocalTimeoutSecs] = useState('');
+
+  const isActionConnected = useHandleConnection(id, 'action');
+  const isServiceNameConnected = useHandleConnection(id, 'serviceName');
+  const isTimeoutSecsConnected = useHandleConnection(id, 'timeoutSecs');
+
+  useEffect(() => {
+    setLocalAction(data?.action || '');
+    setLocalServiceName(data?.serviceName || '');
+    setLocalTimeoutSecs(data?.timeoutSecs || '');
+  }, [data?.action, data?.serviceName, data?.timeoutSecs]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'action' && !isActionConnected && data.action !== value) updateNodeData(id, { action: value });
+    if (key === 'serviceName' && !isServiceNameConnected && data.serviceName !== value) updateNodeData(id, { serviceName: value });
+    if (key === 'timeoutSecs' && !isTimeoutSecsConnected && data.timeoutSecs !== value) updateNodeData(id, { timeoutSecs: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentAction = data?.action?.trim() || '';
+    const currentServiceName = data?.serviceName?.trim() || '';
+    const currentTimeoutSecs = data?.timeoutSecs?.trim() || '';
+
+    if (!currentAction || !currentServiceName) {
+      console.warn('⚠️ ServiceNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Action and Service Name are required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Action and Service Name are required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        action: currentAction,
+        serviceName: currentServiceName,
+        timeoutSecs: toNumber(currentTimeoutSecs)
+      };
+
+      console.log(`⚙️ ServiceNode ${id}: Running service action... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('service_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        state: toText(parsed.service.state),
+        pid: toText(parsed.service.pid),
+        service: toText(parsed.service)
+      };
+
+      setStatus('completed');
+      setResult(`${parsed.service.name}: ${parsed.service.state}`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 ServiceNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 ServiceNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Service action failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.action, data?.serviceName, data?.timeoutSecs, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`⚙️ Service node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Service"
+      icon={<ServerCog size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Queries, starts, stops or restarts an OS service and waits for the new state"
+    >
+      <div onBlur={() => handleBlur('action', localAction)}>
+        <InputField
+          nodeId={id}
+          label="Action"
+          icon={<Settings size={12} />}
+          value={localAction}
+          placeholder="status | start | stop | restart"
+          onChange={setLocalAction}
+          handleId="action"
+          disabled={isActionConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('serviceName', localServiceName)}>
+        <InputField
+          nodeId={id}
+          label="Service Name"
+          icon={<ServerCog size={12} />}
+          value={localServiceName}
+          placeholder="nginx"
+          onChange={setLocalServiceName}
+          handleId="serviceName"
+          disabled={isServiceNameConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('timeoutSecs', localTimeoutSecs)}>
+        <InputField
+          nodeId={id}
+          label="Timeout (sec)"
+          icon={<Timer size={12} />}
+          value={localTimeoutSecs}
+          placeholder="30"
+          onChange={setLocalTimeoutSecs}
+          handleId="timeoutSecs"
+          disabled={isTimeoutSecsConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="State"
+        icon={<Activity size={12} />}
+        value={data.outputData?.state || ''}
+        handleId="state"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="PID"
+        icon={<Hash size={12} />}
+        value={data.outputData?.pid || ''}
+        handleId="pid"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Service Info"
+        icon={<Braces size={12} />}
+        value={data.outputData?.service || ''}
+        handleId="service"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'serviceNode',
+  label: 'Service',
+  color: '#10B981',
+  category: 'System',
+  settings: [
+    { key: 'action', type: 'text', label: 'Action', default: 'status' },
+    { key: 'serviceName', type: 'text', label: 'Service Name', default: '' },
+    { key: 'timeoutSecs', type: 'text', label: 'Timeout (sec)', default: '' }
+  ]
+};
+
+export default ServiceNode;
\ No newline at end of file
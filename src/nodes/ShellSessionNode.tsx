@@ -0,0 +1,242 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { SquareTerminal, Tag, FolderOpen, Braces, FileText, AlertTriangle, Hash } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toJson, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function ShellSessionNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localSessionId, setLocalSessionId] = useState('');
+  const [localCommand, setLocalCommand] = useState('');
+  const [localCwd, setLocalCwd] = useState('');
+  const [localEnv, setLocalEnv] = useState('');
+
+  const isSessionIdConnected = useHandleConnection(id, 'sessionId');
+  const isCommandConnected = useHandleConnection(id, 'command');
+  const isCwdConnected = useHandleConnection(id, 'cwd');
+  const isEnvConnected = useHandleConnection(id, 'env');
+
+  useEffect(() => {
+    setLocalSessionId(data?.sessionId || '');
+    setLocalCommand(data?.command || '');
+    setLocalCwd(data?.cwd || '');
+    setLocalEnv(data?.env || '');
+  }, [data?.sessionId, data?.command, data?.cwd, data?.env]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'sessionId' && !isSessionIdConnected && data.sessionId !== value) updateNodeData(id, { sessionId: value });
+    if (key === 'command' && !isCommandConnected && data.command !== value) updateNodeData(id, { command: value });
+    if (key === 'cwd' && !isCwdConnected && data.cwd !== value) updateNodeData(id, { cwd: value });
+    if (key === 'env' && !isEnvConnected && data.env !== value) updateNodeData(id, { env: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentSessionId = data?.sessionId?.trim() || '';
+    const currentCommand = data?.command || '';
+    const currentCwd = data?.cwd?.trim() || '';
+    const currentEnv = data?.env?.trim() || '';
+
+    if (!currentSessionId || !currentCommand) {
+      console.warn('⚠️ ShellSessionNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Session ID and Command are required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Session ID and Command are required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        sessionId: currentSessionId,
+        command: currentCommand,
+        cwd: currentCwd || undefined,
+        env: toJson(currentEnv)
+      };
+
+      console.log(`🐚 ShellSessionNode ${id}: Running command in session... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('shell_session_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        output: toText(parsed.output),
+        stderr: toText(parsed.stderr),
+        exitCode: toText(parsed.exit_code),
+        cwd: toText(parsed.cwd)
+      };
+
+      setStatus('completed');
+      setResult(`Exit code ${parsed.exit_code}`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 ShellSessionNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 ShellSessionNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Shell session command failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.sessionId, data?.command, data?.cwd, data?.env, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`🐚 Shell Session node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Shell Session"
+      icon={<SquareTerminal size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Runs commands in a persistent shell session (cwd and env carry over between runs)"
+    >
+      <div onBlur={() => handleBlur('sessionId', localSessionId)}>
+        <InputField
+          nodeId={id}
+          label="Session ID"
+          icon={<Tag size={12} />}
+          value={localSessionId}
+          placeholder="build"
+          onChange={setLocalSessionId}
+          handleId="sessionId"
+          disabled={isSessionIdConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('command', localCommand)}>
+        <InputField
+          nodeId={id}
+          label="Command"
+          icon={<SquareTerminal size={12} />}
+          value={localCommand}
+          placeholder="cd project && npm test"
+          onChange={setLocalCommand}
+          handleId="command"
+          disabled={isCommandConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('cwd', localCwd)}>
+        <InputField
+          nodeId={id}
+          label="Working Directory"
+          icon={<FolderOpen size={12} />}
+          value={localCwd}
+          placeholder="Keeps the session cwd when empty"
+          onChange={setLocalCwd}
+          handleId="cwd"
+          disabled={isCwdConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('env', localEnv)}>
+        <InputField
+          nodeId={id}
+          label="Environment"
+          icon={<Braces size={12} />}
+          value={localEnv}
+          placeholder={'{"NODE_ENV":"test"}'}
+          onChange={setLocalEnv}
+          handleId="env"
+          disabled={isEnvConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Output"
+        icon={<FileText size={12} />}
+        value={data.outputData?.output || ''}
+        handleId="output"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Stderr"
+        icon={<AlertTriangle size={12} />}
+        value={data.outputData?.stderr || ''}
+        handleId="stderr"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Exit Code"
+        icon={<Hash size={12} />}
+        value={data.outputData?.exitCode || ''}
+        handleId="exitCode"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Working Directory"
+        icon={<FolderOpen size={12} />}
+        value={data.outputData?.cwd || ''}
+        handleId="cwd"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'shellSessionNode',
+  label: 'Shell Session',
+  color: '#64748B',
+  category: 'System',
+  settings: [
+    { key: 'sessionId', type: 'text', label: 'Session ID', default: 'default' },
+    { key: 'command', type: 'text', label: 'Command', default: '' },
+    { key: 'cwd', type: 'text', label: 'Working Directory', default: '' },
+    { key: 'env', type: 'text', label: 'Environment', default: '' }
+  ]
+};
+
+export default ShellSessionNode;
\ No newline at end of file
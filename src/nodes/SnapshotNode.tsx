@@ -0,0 +1,262 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { Camera, FileText, Tag, RefreshCw, AlertTriangle, EyeOff, Info, GitCompare, List, FileDiff } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toBool, toList, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function SnapshotNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localValue, setLocalValue] = useState('');
+  const [localSnapshotName, setLocalSnapshotName] = useState('');
+  const [localUpdate, setLocalUpdate] = useState('');
+  const [localFailOnChange, setLocalFailOnChange] = useState('');
+  const [localIgnorePaths, setLocalIgnorePaths] = useState('');
+
+  const isValueConnected = useHandleConnection(id, 'value');
+  const isSnapshotNameConnected = useHandleConnection(id, 'snapshotName');
+  const isUpdateConnected = useHandleConnection(id, 'update');
+  const isFailOnChangeConnected = useHandleConnection(id, 'failOnChange');
+  const isIgnorePathsConnected = useHandleConnection(id, 'ignorePaths');
+
+  useEffect(() => {
+    setLocalValue(data?.value || '');
+    setLocalSnapshotName(data?.snapshotName || '');
+    setLocalUpdate(data?.update || '');
+    setLocalFailOnChange(data?.failOnChange || '');
+    setLocalIgnorePaths(data?.ignorePaths || '');
+  }, [data?.value, data?.snapshotName, data?.update, data?.failOnChange, data?.ignorePaths]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'value' && !isValueConnected && data.value !== value) updateNodeData(id, { value: value });
+    if (key === 'snapshotName' && !isSnapshotNameConnected && data.snapshotName !== value) updateNodeData(id, { snapshotName: value });
+    if (key === 'update' && !isUpdateConnected && data.update !== value) updateNodeData(id, { update: value });
+    if (key === 'failOnChange' && !isFailOnChangeConnected && data.failOnChange !== value) updateNodeData(id, { failOnChange: value });
+    if (key === 'ignorePaths' && !isIgnorePathsConnected && data.ignorePaths !== value) updateNodeData(id, { ignorePaths: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentValue = data?.value || '';
+    const currentSnapshotName = data?.snapshotName?.trim() || '';
+    const currentUpdate = data?.update?.trim() || '';
+    const currentFailOnChange = data?.failOnChange?.trim() || '';
+    const currentIgnorePaths = data?.ignorePaths?.trim() || '';
+
+    if (!currentValue || !currentSnapshotName) {
+      console.warn('⚠️ SnapshotNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Value and Snapshot Name are required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Value and Snapshot Name are required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        value: currentValue,
+        snapshotName: currentSnapshotName,
+        update: toBool(currentUpdate),
+        failOnChange: toBool(currentFailOnChange),
+        ignorePaths: toList(currentIgnorePaths)
+      };
+
+      console.log(`📸 SnapshotNode ${id}: Comparing snapshot... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('snapshot_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        status: toText(parsed.status),
+        changed: toText(parsed.status === 'changed' || parsed.status === 'updated'),
+        changes: toText(parsed.changes),
+        lineDiff: (parsed.line_diff ?? []).join('\n')
+      };
+
+      setStatus('completed');
+      setResult(`Snapshot ${parsed.status}`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 SnapshotNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 SnapshotNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Snapshot failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.value, data?.snapshotName, data?.update, data?.failOnChange, data?.ignorePaths, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`📸 Snapshot node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Snapshot"
+      icon={<Camera size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Save a value the first time, then report what changed on later runs"
+    >
+      <div onBlur={() => handleBlur('value', localValue)}>
+        <InputField
+          nodeId={id}
+          label="Value"
+          icon={<FileText size={12} />}
+          value={localValue}
+          placeholder="JSON or text to watch"
+          onChange={setLocalValue}
+          handleId="value"
+          disabled={isValueConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('snapshotName', localSnapshotName)}>
+        <InputField
+          nodeId={id}
+          label="Snapshot Name"
+          icon={<Tag size={12} />}
+          value={localSnapshotName}
+          placeholder="pricing-page"
+          onChange={setLocalSnapshotName}
+          handleId="snapshotName"
+          disabled={isSnapshotNameConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('update', localUpdate)}>
+        <InputField
+          nodeId={id}
+          label="Update Baseline"
+          icon={<RefreshCw size={12} />}
+          value={localUpdate}
+          placeholder="true | false"
+          onChange={setLocalUpdate}
+          handleId="update"
+          disabled={isUpdateConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('failOnChange', localFailOnChange)}>
+        <InputField
+          nodeId={id}
+          label="Fail On Change"
+          icon={<AlertTriangle size={12} />}
+          value={localFailOnChange}
+          placeholder="true | false"
+          onChange={setLocalFailOnChange}
+          handleId="failOnChange"
+          disabled={isFailOnChangeConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('ignorePaths', localIgnorePaths)}>
+        <InputField
+          nodeId={id}
+          label="Ignore Paths"
+          icon={<EyeOff size={12} />}
+          value={localIgnorePaths}
+          placeholder="updated_at, items.0.id"
+          onChange={setLocalIgnorePaths}
+          handleId="ignorePaths"
+          disabled={isIgnorePathsConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Status"
+        icon={<Info size={12} />}
+        value={data.outputData?.status || ''}
+        handleId="status"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Changed"
+        icon={<GitCompare size={12} />}
+        value={data.outputData?.changed || ''}
+        handleId="changed"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Changes"
+        icon={<List size={12} />}
+        value={data.outputData?.changes || ''}
+        handleId="changes"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Line Diff"
+        icon={<FileDiff size={12} />}
+        value={data.outputData?.lineDiff || ''}
+        handleId="lineDiff"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'snapshotNode',
+  label: 'Snapshot',
+  color: '#DB2777',
+  category: 'Control',
+  settings: [
+    { key: 'value', type: 'text', label: 'Value', default: '' },
+    { key: 'snapshotName', type: 'text', label: 'Snapshot Name', default: '' },
+    { key: 'update', type: 'text', label: 'Update Baseline', default: '' },
+    { key: 'failOnChange', type: 'text', label: 'Fail On Change', default: '' },
+    { key: 'ignorePaths', type: 'text', label: 'Ignore Paths', default: '' }
+  ]
+};
+
+export default SnapshotNode;
\ No newline at end of file
@@ -0,0 +1,355 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { Code, Link, Braces, Zap, Tag, Search, User, KeyRound, Timer, Hash } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toNumber, toJson, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function SoapNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localEndpoint, setLocalEndpoint] = useState('');
+  const [localBody, setLocalBody] = useState('');
+  const [localContext, setLocalContext] = useState('');
+  const [localSoapAction, setLocalSoapAction] = useState('');
+  const [localVersion, setLocalVersion] = useState('');
+  const [localExtract, setLocalExtract] = useState('');
+  const [localNamespaces, setLocalNamespaces] = useState('');
+  const [localUsername, setLocalUsername] = useState('');
+  const [localPassword, setLocalPassword] = useState('');
+  const [localTimeoutSecs, setLocalTimeoutSecs] = useState('');
+
+  const isEndpointConnected = useHandleConnection(id, 'endpoint');
+  const isBodyConnected = useHandleConnection(id, 'body');
+  const isContextConnected = useHandleConnection(id, 'context');
+  const isSoapActionConnected = useHandleConnection(id, 'soapAction');
+  const isVersionConnected = useHandleConnection(id, 'version');
+  const isExtractConnected = useHandleConnection(id, 'extract');
+  const isNamespacesConnected = useHandleConnection(id, 'namespaces');
+  const isUsernameConnected = useHandleConnection(id, 'username');
+  const isPasswordConnected = useHandleConnection(id, 'password');
+  const isTimeoutSecsConnected = useHandleConnection(id, 'timeoutSecs');
+
+  useEffect(() => {
+    setLocalEndpoint(data?.endpoint || '');
+    setLocalBody(data?.body || '');
+    setLocalContext(data?.context || '');
+    setLocalSoapAction(data?.soapAction || '');
+    setLocalVersion(data?.version || '');
+    setLocalExtract(data?.extract || '');
+    setLocalNamespaces(data?.namespaces || '');
+    setLocalUsername(data?.username || '');
+    setLocalPassword(data?.password || '');
+    setLocalTimeoutSecs(data?.timeoutSecs || '');
+  }, [data?.endpoint, data?.body, data?.context, data?.soapAction, data?.version, data?.extract, data?.namespaces, data?.username, data?.password, data?.timeoutSecs]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'endpoint' && !isEndpointConnected && data.endpoint !== value) updateNodeData(id, { endpoint: value });
+    if (key === 'body' && !isBodyConnected && data.body !== value) updateNodeData(id, { body: value });
+    if (key === 'context' && !isContextConnected && data.context !== value) updateNodeData(id, { context: value });
+    if (key === 'soapAction' && !isSoapActionConnected && data.soapAction !== value) updateNodeData(id, { soapAction: value });
+    if (key === 'version' && !isVersionConnected && data.version !== value) updateNodeData(id, { version: value });
+    if (key === 'extract' && !isExtractConnected && data.extract !== value) updateNodeData(id, { extract: value });
+    if (key === 'namespaces' && !isNamespacesConnected && data.namespaces !== value) updateNodeData(id, { namespaces: value });
+    if (key === 'username' && !isUsernameConnected && data.username !== value) updateNodeData(id, { username: value });
+    if (key === 'password' && !isPasswordConnected && data.password !== value) updateNodeData(id, { password: value });
+    if (key === 'timeoutSecs' && !isTimeoutSecsConnected && data.timeoutSecs !== value) updateNodeData(id, { timeoutSecs: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentEndpoint = data?.endpoint?.trim() || '';
+    const currentBody = data?.body || '';
+    const currentContext = data?.context?.trim() || '';
+    const currentSoapAction = data?.soapAction?.trim() || '';
+    const currentVersion = data?.version?.trim() || '';
+    const currentExtract = data?.extract?.trim() || '';
+    const currentNamespaces = data?.namespaces?.trim() || '';
+    const currentUsername = data?.username?.trim() || '';
+    const currentPassword = data?.password?.trim() || '';
+    const currentTimeoutSecs = data?.timeoutSecs?.trim() || '';
+
+    if (!currentEndpoint || !currentBody) {
+      console.warn('⚠️ SoapNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Endpoint and Body XML are required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Endpoint and Body XML are required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        endpoint: currentEndpoint,
+        body: currentBody,
+        context: toJson(currentContext),
+        options: {
+          soapAction: currentSoapAction || undefined,
+          version: currentVersion || undefined,
+          extract: toJson(currentExtract),
+          namespaces: toJson(currentNamespaces),
+          username: currentUsername || undefined,
+          password: currentPassword || undefined,
+          timeoutSecs: toNumber(currentTimeoutSecs)
+        }
+      };
+
+      console.log(`🧼 SoapNode ${id}: Calling SOAP service... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('soap_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        values: toText(parsed.values),
+        response: toText(parsed.response),
+        status: toText(parsed.status)
+      };
+
+      setStatus('completed');
+      setResult(`HTTP ${parsed.status}, ${Object.keys(parsed.values).length} value(s)`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 SoapNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 SoapNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ SOAP request failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.endpoint, data?.body, data?.context, data?.soapAction, data?.version, data?.extract, data?.namespaces, data?.username, data?.password, data?.timeoutSecs, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`🧼 SOAP Request node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="SOAP Request"
+      icon={<Code size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Calls a SOAP service with a {{templated}} body and extracts values by XPath"
+    >
+      <div onBlur={() => handleBlur('endpoint', localEndpoint)}>
+        <InputField
+          nodeId={id}
+          label="Endpoint"
+          icon={<Link size={12} />}
+          value={localEndpoint}
+          placeholder="https://example.com/service.asmx"
+          onChange={setLocalEndpoint}
+          handleId="endpoint"
+          disabled={isEndpointConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('body', localBody)}>
+        <InputField
+          nodeId={id}
+          label="Body XML"
+          icon={<Code size={12} />}
+          value={localBody}
+          placeholder={'<GetWeather xmlns="http://example.com/"><City>{{city}}</City></GetWeather>'}
+          onChange={setLocalBody}
+          handleId="body"
+          disabled={isBodyConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('context', localContext)}>
+        <InputField
+          nodeId={id}
+          label="Template Context"
+          icon={<Braces size={12} />}
+          value={localContext}
+          placeholder={'{"city":"Seoul"}'}
+          onChange={setLocalContext}
+          handleId="context"
+          disabled={isContextConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('soapAction', localSoapAction)}>
+        <InputField
+          nodeId={id}
+          label="SOAP Action"
+          icon={<Zap size={12} />}
+          value={localSoapAction}
+          placeholder="http://example.com/GetWeather"
+          onChange={setLocalSoapAction}
+          handleId="soapAction"
+          disabled={isSoapActionConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('version', localVersion)}>
+        <InputField
+          nodeId={id}
+          label="SOAP Version"
+          icon={<Tag size={12} />}
+          value={localVersion}
+          placeholder="1.1 | 1.2"
+          onChange={setLocalVersion}
+          handleId="version"
+          disabled={isVersionConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('extract', localExtract)}>
+        <InputField
+          nodeId={id}
+          label="Extract (XPath)"
+          icon={<Search size={12} />}
+          value={localExtract}
+          placeholder={'{"temp":"//m:Temperature"}'}
+          onChange={setLocalExtract}
+          handleId="extract"
+          disabled={isExtractConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('namespaces', localNamespaces)}>
+        <InputField
+          nodeId={id}
+          label="Namespaces"
+          icon={<Braces size={12} />}
+          value={localNamespaces}
+          placeholder={'{"m":"http://example.com/"}'}
+          onChange={setLocalNamespaces}
+          handleId="namespaces"
+          disabled={isNamespacesConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('username', localUsername)}>
+        <InputField
+          nodeId={id}
+          label="Username"
+          icon={<User size={12} />}
+          value={localUsername}
+          placeholder="Basic auth user (optional)"
+          onChange={setLocalUsername}
+          handleId="username"
+          disabled={isUsernameConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('password', localPassword)}>
+        <InputField
+          nodeId={id}
+          label="Password"
+          icon={<KeyRound size={12} />}
+          value={localPassword}
+          placeholder="${secret:soap}"
+          onChange={setLocalPassword}
+          handleId="password"
+          disabled={isPasswordConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('timeoutSecs', localTimeoutSecs)}>
+        <InputField
+          nodeId={id}
+          label="Timeout (sec)"
+          icon={<Timer size={12} />}
+          value={localTimeoutSecs}
+          placeholder="30"
+          onChange={setLocalTimeoutSecs}
+          handleId="timeoutSecs"
+          disabled={isTimeoutSecsConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Values"
+        icon={<Braces size={12} />}
+        value={data.outputData?.values || ''}
+        handleId="values"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Response XML"
+        icon={<Code size={12} />}
+        value={data.outputData?.response || ''}
+        handleId="response"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="HTTP Status"
+        icon={<Hash size={12} />}
+        value={data.outputData?.status || ''}
+        handleId="status"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'soapNode',
+  label: 'SOAP Request',
+  color: '#0EA5E9',
+  category: 'Network',
+  settings: [
+    { key: 'endpoint', type: 'text', label: 'Endpoint', default: '' },
+    { key: 'body', type: 'text', label: 'Body XML', default: '' },
+    { key: 'context', type: 'text', label: 'Template Context', default: '' },
+    { key: 'soapAction', type: 'text', label: 'SOAP Action', default: '' },
+    { key: 'version', type: 'text', label: 'SOAP Version', default: '1.1' },
+    { key: 'extract', type: 'text', label: 'Extract (XPath)', default: '' },
+    { key: 'namespaces', type: 'text', label: 'Namespaces', default: '' },
+    { key: 'username', type: 'text', label: 'Username', default: '' },
+    { key: 'password', type: 'text', label: 'Password', default: '' },
+    { key: 'timeoutSecs', type: 'text', label: 'Timeout (sec)', default: '' }
+  ]
+};
+
+export default SoapNode;
\ No newline at end of file
@@ -0,0 +1,253 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { BookOpen, FileText, AlignLeft, Ruler, KeyRound, Cpu, Layers } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toNumber, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function SummarizeNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localTextOrFile, setLocalTextOrFile] = useState('');
+  const [localStyle, setLocalStyle] = useState('');
+  const [localMaxLength, setLocalMaxLength] = useState('');
+  const [localApiKey, setLocalApiKey] = useState('');
+  const [localModel, setLocalModel] = useState('');
+
+  const isTextOrFileConnected = useHandleConnection(id, 'textOrFile');
+  const isStyleConnected = useHandleConnection(id, 'style');
+  const isMaxLengthConnected = useHandleConnection(id, 'maxLength');
+  const isApiKeyConnected = useHandleConnection(id, 'apiKey');
+  const isModelConnected = useHandleConnection(id, 'model');
+
+  useEffect(() => {
+    setLocalTextOrFile(data?.textOrFile || '');
+    setLocalStyle(data?.style || '');
+    setLocalMaxLength(data?.maxLength || '');
+    setLocalApiKey(data?.apiKey || '');
+    setLocalModel(data?.model || '');
+  }, [data?.textOrFile, data?.style, data?.maxLength, data?.apiKey, data?.model]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'textOrFile' && !isTextOrFileConnected && data.textOrFile !== value) updateNodeData(id, { textOrFile: value });
+    if (key === 'style' && !isStyleConnected && data.style !== value) updateNodeData(id, { style: value });
+    if (key === 'maxLength' && !isMaxLengthConnected && data.maxLength !== value) updateNodeData(id, { maxLength: value });
+    if (key === 'apiKey' && !isApiKeyConnected && data.apiKey !== value) updateNodeData(id, { apiKey: value });
+    if (key === 'model' && !isModelConnected && data.model !== value) updateNodeData(id, { model: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentTextOrFile = data?.textOrFile || '';
+    const currentStyle = data?.style?.trim() || '';
+    const currentMaxLength = data?.maxLength?.trim() || '';
+    const currentApiKey = data?.apiKey?.trim() || '';
+    const currentModel = data?.model?.trim() || '';
+
+    if (!currentTextOrFile || !currentApiKey || !currentModel) {
+      console.warn('⚠️ SummarizeNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Text or File, API Key and Model are required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Text or File, API Key and Model are required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        textOrFile: currentTextOrFile,
+        style: currentStyle || undefined,
+        maxLength: toNumber(currentMaxLength),
+        apiKey: currentApiKey,
+        model: currentModel
+      };
+
+      console.log(`📚 SummarizeNode ${id}: Summarizing... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('summarize_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        summary: toText(parsed.summary),
+        source: toText(parsed.source),
+        chunks: toText(parsed.chunks)
+      };
+
+      setStatus('completed');
+      setResult(`Summarized ${parsed.source_length} characters`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 SummarizeNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 SummarizeNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Summarize failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.textOrFile, data?.style, data?.maxLength, data?.apiKey, data?.model, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`📚 Summarize node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Summarize"
+      icon={<BookOpen size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Summarizes text or a text file with Claude (long input is chunked)"
+    >
+      <div onBlur={() => handleBlur('textOrFile', localTextOrFile)}>
+        <InputField
+          nodeId={id}
+          label="Text or File"
+          icon={<FileText size={12} />}
+          value={localTextOrFile}
+          placeholder="Text to summarize or /path/to/file.txt"
+          onChange={setLocalTextOrFile}
+          handleId="textOrFile"
+          disabled={isTextOrFileConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('style', localStyle)}>
+        <InputField
+          nodeId={id}
+          label="Style"
+          icon={<AlignLeft size={12} />}
+          value={localStyle}
+          placeholder="paragraph | bullets | tldr | detailed"
+          onChange={setLocalStyle}
+          handleId="style"
+          disabled={isStyleConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('maxLength', localMaxLength)}>
+        <InputField
+          nodeId={id}
+          label="Max Length"
+          icon={<Ruler size={12} />}
+          value={localMaxLength}
+          placeholder="Max characters (optional)"
+          onChange={setLocalMaxLength}
+          handleId="maxLength"
+          disabled={isMaxLengthConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('apiKey', localApiKey)}>
+        <InputField
+          nodeId={id}
+          label="API Key"
+          icon={<KeyRound size={12} />}
+          value={localApiKey}
+          placeholder="${secret:anthropic}"
+          onChange={setLocalApiKey}
+          handleId="apiKey"
+          disabled={isApiKeyConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('model', localModel)}>
+        <InputField
+          nodeId={id}
+          label="Model"
+          icon={<Cpu size={12} />}
+          value={localModel}
+          placeholder="Claude model id"
+          onChange={setLocalModel}
+          handleId="model"
+          disabled={isModelConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Summary"
+        icon={<BookOpen size={12} />}
+        value={data.outputData?.summary || ''}
+        handleId="summary"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Source"
+        icon={<FileText size={12} />}
+        value={data.outputData?.source || ''}
+        handleId="source"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Chunks"
+        icon={<Layers size={12} />}
+        value={data.outputData?.chunks || ''}
+        handleId="chunks"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'summarizeNode',
+  label: 'Summarize',
+  color: '#7C4DFF',
+  category: 'AI',
+  settings: [
+    { key: 'textOrFile', type: 'text', label: 'Text or File', default: '' },
+    { key: 'style', type: 'text', label: 'Style', default: 'paragraph' },
+    { key: 'maxLength', type: 'text', label: 'Max Length', default: '' },
+    { key: 'apiKey', type: 'text', label: 'API Key', default: '' },
+    { key: 'model', type: 'text', label: 'Model', default: '' }
+  ]
+};
+
+export default SummarizeNode;
\ No newline at end of file
@@ -0,0 +1,204 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { Table, Braces, Settings, Columns, Hash } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toList, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function TableNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localRows, setLocalRows] = useState('');
+  const [localFormat, setLocalFormat] = useState('');
+  const [localColumns, setLocalColumns] = useState('');
+
+  const isRowsConnected = useHandleConnection(id, 'rows');
+  const isFormatConnected = useHandleConnection(id, 'format');
+  const isColumnsConnected = useHandleConnection(id, 'columns');
+
+  useEffect(() => {
+    setLocalRows(data?.rows || '');
+    setLocalFormat(data?.format || '');
+    setLocalColumns(data?.columns || '');
+  }, [data?.rows, data?.format, data?.columns]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'rows' && !isRowsConnected && data.rows !== value) updateNodeData(id, { rows: value });
+    if (key === 'format' && !isFormatConnected && data.format !== value) updateNodeData(id, { format: value });
+    if (key === 'columns' && !isColumnsConnected && data.columns !== value) updateNodeData(id, { columns: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentRows = data?.rows || '';
+    const currentFormat = data?.format?.trim() || '';
+    const currentColumns = data?.columns?.trim() || '';
+
+    if (!currentRows) {
+      console.warn('⚠️ TableNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Rows is required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Rows is required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        rows: currentRows,
+        format: currentFormat || undefined,
+        columns: toList(currentColumns)
+      };
+
+      console.log(`📊 TableNode ${id}: Rendering table... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('table_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        table: toText(parsed.table),
+        rowCount: toText(parsed.row_count)
+      };
+
+      setStatus('completed');
+      setResult(`${parsed.row_count} rows rendered`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 TableNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 TableNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Table rendering failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.rows, data?.format, data?.columns, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`📊 Table node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Table"
+      icon={<Table size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Renders a JSON array of rows as an aligned text, Markdown or HTML table"
+    >
+      <div onBlur={() => handleBlur('rows', localRows)}>
+        <InputField
+          nodeId={id}
+          label="Rows"
+          icon={<Braces size={12} />}
+          value={localRows}
+          placeholder={'[{"name":"web","status":"up"}]'}
+          onChange={setLocalRows}
+          handleId="rows"
+          disabled={isRowsConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('format', localFormat)}>
+        <InputField
+          nodeId={id}
+          label="Format"
+          icon={<Settings size={12} />}
+          value={localFormat}
+          placeholder="text | markdown | html"
+          onChange={setLocalFormat}
+          handleId="format"
+          disabled={isFormatConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('columns', localColumns)}>
+        <InputField
+          nodeId={id}
+          label="Columns"
+          icon={<Columns size={12} />}
+          value={localColumns}
+          placeholder="name, status (optional)"
+          onChange={setLocalColumns}
+          handleId="columns"
+          disabled={isColumnsConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Table"
+        icon={<Table size={12} />}
+        value={data.outputData?.table || ''}
+        handleId="table"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Row Count"
+        icon={<Hash size={12} />}
+        value={data.outputData?.rowCount || ''}
+        handleId="rowCount"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'tableNode',
+  label: 'Table',
+  color: '#14B8A6',
+  category: 'Data',
+  settings: [
+    { key: 'rows', type: 'text', label: 'Rows', default: '' },
+    { key: 'format', type: 'text', label: 'Format', default: 'text' },
+    { key: 'columns', type: 'text', label: 'Columns', default: '' }
+  ]
+};
+
+export default TableNode;
\ No newline at end of file
@@ -0,0 +1,393 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { Ticket, Building2, Settings, KeyRound, Link, Mail, FolderKanban, Tag, Type, FileText, Hash, ArrowRightCircle, Braces } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toJson, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function TicketNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localProvider, setLocalProvider] = useState('');
+  const [localAction, setLocalAction] = useState('');
+  const [localApiToken, setLocalApiToken] = useState('');
+  const [localBaseUrl, setLocalBaseUrl] = useState('');
+  const [localEmail, setLocalEmail] = useState('');
+  const [localProject, setLocalProject] = useState('');
+  const [localIssueType, setLocalIssueType] = useState('');
+  const [localSummary, setLocalSummary] = useState('');
+  const [localDescription, setLocalDescription] = useState('');
+  const [localIssueKey, setLocalIssueKey] = useState('');
+  const [localTransition, setLocalTransition] = useState('');
+  const [localContext, setLocalContext] = useState('');
+
+  const isProviderConnected = useHandleConnection(id, 'provider');
+  const isActionConnected = useHandleConnection(id, 'action');
+  const isApiTokenConnected = useHandleConnection(id, 'apiToken');
+  const isBaseUrlConnected = useHandleConnection(id, 'baseUrl');
+  const isEmailConnected = useHandleConnection(id, 'email');
+  const isProjectConnected = useHandleConnection(id, 'project');
+  const isIssueTypeConnected = useHandleConnection(id, 'issueType');
+  const isSummaryConnected = useHandleConnection(id, 'summary');
+  const isDescriptionConnected = useHandleConnection(id, 'description');
+  const isIssueKeyConnected = useHandleConnection(id, 'issueKey');
+  const isTransitionConnected = useHandleConnection(id, 'transition');
+  const isContextConnected = useHandleConnection(id, 'context');
+
+  useEffect(() => {
+    setLocalProvider(data?.provider || '');
+    setLocalAction(data?.action || '');
+    setLocalApiToken(data?.apiToken || '');
+    setLocalBaseUrl(data?.baseUrl || '');
+    setLocalEmail(data?.email || '');
+    setLocalProject(data?.project || '');
+    setLocalIssueType(data?.issueType || '');
+    setLocalSummary(data?.summary || '');
+    setLocalDescription(data?.description || '');
+    setLocalIssueKey(data?.issueKey || '');
+    setLocalTransition(data?.transition || '');
+    setLocalContext(data?.context || '');
+  }, [data?.provider, data?.action, data?.apiToken, data?.baseUrl, data?.email, data?.project, data?.issueType, data?.summary, data?.description, data?.issueKey, data?.transition, data?.context]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'provider' && !isProviderConnected && data.provider !== value) updateNodeData(id, { provider: value });
+    if (key === 'action' && !isActionConnected && data.action !== value) updateNodeData(id, { action: value });
+    if (key === 'apiToken' && !isApiTokenConnected && data.apiToken !== value) updateNodeData(id, { apiToken: value });
+    if (key === 'baseUrl' && !isBaseUrlConnected && data.baseUrl !== value) updateNodeData(id, { baseUrl: value });
+    if (key === 'email' && !isEmailConnected && data.email !== value) updateNodeData(id, { email: value });
+    if (key === 'project' && !isProjectConnected && data.project !== value) updateNodeData(id, { project: value });
+    if (key === 'issueType' && !isIssueTypeConnected && data.issueType !== value) updateNodeData(id, { issueType: value });
+    if (key === 'summary' && !isSummaryConnected && data.summary !== value) updateNodeData(id, { summary: value });
+    if (key === 'description' && !isDescriptionConnected && data.description !== value) updateNodeData(id, { description: value });
+    if (key === 'issueKey' && !isIssueKeyConnected && data.issueKey !== value) updateNodeData(id, { issueKey: value });
+    if (key === 'transition' && !isTransitionConnected && data.transition !== value) updateNodeData(id, { transition: value });
+    if (key === 'context' && !isContextConnected && data.context !== value) updateNodeData(id, { context: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentProvider = data?.provider?.trim() || '';
+    const currentAction = data?.action?.trim() || '';
+    const currentApiToken = data?.apiToken?.trim() || '';
+    const currentBaseUrl = data?.baseUrl?.trim() || '';
+    const currentEmail = data?.email?.trim() || '';
+    const currentProject = data?.project?.trim() || '';
+    const currentIssueType = data?.issueType?.trim() || '';
+    const currentSummary = data?.summary || '';
+    const currentDescription = data?.description || '';
+    const currentIssueKey = data?.issueKey?.trim() || '';
+    const currentTransition = data?.transition?.trim() || '';
+    const currentContext = data?.context?.trim() || '';
+
+    if (!currentProvider || !currentAction || !currentApiToken) {
+      console.warn('⚠️ TicketNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Provider, Action and API Token are required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Provider, Action and API Token are required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        provider: currentProvider,
+        action: currentAction,
+        apiToken: currentApiToken,
+        baseUrl: currentBaseUrl || undefined,
+        email: currentEmail || undefined,
+        project: currentProject || undefined,
+        issueType: currentIssueType || undefined,
+        summary: currentSummary || undefined,
+        description: currentDescription || undefined,
+        issueKey: currentIssueKey || undefined,
+        transition: currentTransition || undefined,
+        context: toJson(currentContext)
+      };
+
+      console.log(`🎫 TicketNode ${id}: Processing ticket... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('ticket_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        issueKey: toText(parsed.issue_key),
+        url: toText(parsed.url),
+        id: toText(parsed.id)
+      };
+
+      setStatus('completed');
+      setResult(`${parsed.provider} ${parsed.issue_key} ${currentAction === 'create' ? 'created' : 'updated'}`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 TicketNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 TicketNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Ticket action failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.provider, data?.action, data?.apiToken, data?.baseUrl, data?.email, data?.project, data?.issueType, data?.summary, data?.description, data?.issueKey, data?.transition, data?.context, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`🎫 Ticket node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Ticket"
+      icon={<Ticket size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Creates or transitions Jira / Linear issues (summary and description support {{templates}})"
+    >
+      <div onBlur={() => handleBlur('provider', localProvider)}>
+        <InputField
+          nodeId={id}
+          label="Provider"
+          icon={<Building2 size={12} />}
+          value={localProvider}
+          placeholder="jira | linear"
+          onChange={setLocalProvider}
+          handleId="provider"
+          disabled={isProviderConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('action', localAction)}>
+        <InputField
+          nodeId={id}
+          label="Action"
+          icon={<Settings size={12} />}
+          value={localAction}
+          placeholder="create | transition"
+          onChange={setLocalAction}
+          handleId="action"
+          disabled={isActionConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('apiToken', localApiToken)}>
+        <InputField
+          nodeId={id}
+          label="API Token"
+          icon={<KeyRound size={12} />}
+          value={localApiToken}
+          placeholder="${secret:jira}"
+          onChange={setLocalApiToken}
+          handleId="apiToken"
+          disabled={isApiTokenConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('baseUrl', localBaseUrl)}>
+        <InputField
+          nodeId={id}
+          label="Base URL"
+          icon={<Link size={12} />}
+          value={localBaseUrl}
+          placeholder="https://team.atlassian.net (Jira)"
+          onChange={setLocalBaseUrl}
+          handleId="baseUrl"
+          disabled={isBaseUrlConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('email', localEmail)}>
+        <InputField
+          nodeId={id}
+          label="Email"
+          icon={<Mail size={12} />}
+          value={localEmail}
+          placeholder="me@example.com (Jira)"
+          onChange={setLocalEmail}
+          handleId="email"
+          disabled={isEmailConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('project', localProject)}>
+        <InputField
+          nodeId={id}
+          label="Project / Team"
+          icon={<FolderKanban size={12} />}
+          value={localProject}
+          placeholder="PROJ (Jira) or team key (Linear)"
+          onChange={setLocalProject}
+          handleId="project"
+          disabled={isProjectConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('issueType', localIssueType)}>
+        <InputField
+          nodeId={id}
+          label="Issue Type"
+          icon={<Tag size={12} />}
+          value={localIssueType}
+          placeholder="Task"
+          onChange={setLocalIssueType}
+          handleId="issueType"
+          disabled={isIssueTypeConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('summary', localSummary)}>
+        <InputField
+          nodeId={id}
+          label="Summary"
+          icon={<Type size={12} />}
+          value={localSummary}
+          placeholder="Build failed: {{branch}}"
+          onChange={setLocalSummary}
+          handleId="summary"
+          disabled={isSummaryConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('description', localDescription)}>
+        <InputField
+          nodeId={id}
+          label="Description"
+          icon={<FileText size={12} />}
+          value={localDescription}
+          placeholder="Issue description"
+          onChange={setLocalDescription}
+          handleId="description"
+          disabled={isDescriptionConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('issueKey', localIssueKey)}>
+        <InputField
+          nodeId={id}
+          label="Issue Key"
+          icon={<Hash size={12} />}
+          value={localIssueKey}
+          placeholder="PROJ-123 (transition)"
+          onChange={setLocalIssueKey}
+          handleId="issueKey"
+          disabled={isIssueKeyConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('transition', localTransition)}>
+        <InputField
+          nodeId={id}
+          label="Transition"
+          icon={<ArrowRightCircle size={12} />}
+          value={localTransition}
+          placeholder="Done (transition)"
+          onChange={setLocalTransition}
+          handleId="transition"
+          disabled={isTransitionConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('context', localContext)}>
+        <InputField
+          nodeId={id}
+          label="Template Context"
+          icon={<Braces size={12} />}
+          value={localContext}
+          placeholder={'{"branch":"main"}'}
+          onChange={setLocalContext}
+          handleId="context"
+          disabled={isContextConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Issue Key"
+        icon={<Hash size={12} />}
+        value={data.outputData?.issueKey || ''}
+        handleId="issueKey"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Issue URL"
+        icon={<Link size={12} />}
+        value={data.outputData?.url || ''}
+        handleId="url"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Issue ID"
+        icon={<Ticket size={12} />}
+        value={data.outputData?.id || ''}
+        handleId="id"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'ticketNode',
+  label: 'Ticket',
+  color: '#2563EB',
+  category: 'Integration',
+  settings: [
+    { key: 'provider', type: 'text', label: 'Provider', default: 'jira' },
+    { key: 'action', type: 'text', label: 'Action', default: 'create' },
+    { key: 'apiToken', type: 'text', label: 'API Token', default: '' },
+    { key: 'baseUrl', type: 'text', label: 'Base URL', default: '' },
+    { key: 'email', type: 'text', label: 'Email', default: '' },
+    { key: 'project', type: 'text', label: 'Project / Team', default: '' },
+    { key: 'issueType', type: 'text', label: 'Issue Type', default: '' },
+    { key: 'summary', type: 'text', label: 'Summary', default: '' },
+    { key: 'description', type: 'text', label: 'Description', default: '' },
+    { key: 'issueKey', type: 'text', label: 'Issue Key', default: '' },
+    { key: 'transition', type: 'text', label: 'Transition', default: '' },
+    { key: 'context', type: 'text', label: 'Template Context', default: '' }
+  ]
+};
+
+export default TicketNode;
\ No newline at end of file
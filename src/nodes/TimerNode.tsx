@@ -0,0 +1,316 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { Timer, Settings, Hourglass, Tag, Hash, Clock, CheckCircle, Square } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toNumber, toBool, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+// "timer-tick" / "timer-complete" / "timer-cancelled" 이벤트
+interface TimerEvent {
+  timer_id: string;
+  node_id: string;
+  mode: string;
+  label?: string;
+  elapsed_ms: number;
+  remaining_ms?: number;
+  progress?: number;
+  display: string;
+}
+
+
+function TimerNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localMode, setLocalMode] = useState('');
+  const [localDurationSecs, setLocalDurationSecs] = useState('');
+  const [localTickMs, setLocalTickMs] = useState('');
+  const [localLabel, setLocalLabel] = useState('');
+  const [localTimerId, setLocalTimerId] = useState('');
+  const [localWait, setLocalWait] = useState('');
+
+  const isModeConnected = useHandleConnection(id, 'mode');
+  const isDurationSecsConnected = useHandleConnection(id, 'durationSecs');
+  const isTickMsConnected = useHandleConnection(id, 'tickMs');
+  const isLabelConnected = useHandleConnection(id, 'label');
+  const isTimerIdConnected = useHandleConnection(id, 'timerId');
+  const isWaitConnected = useHandleConnection(id, 'wait');
+
+  const [clock, setClock] = useState('');
+  const [ticking, setTicking] = useState(false);
+
+  useEffect(() => {
+    setLocalMode(data?.mode || '');
+    setLocalDurationSecs(data?.durationSecs || '');
+    setLocalTickMs(data?.tickMs || '');
+    setLocalLabel(data?.label || '');
+    setLocalTimerId(data?.timerId || '');
+    setLocalWait(data?.wait || '');
+  }, [data?.mode, data?.durationSecs, data?.tickMs, data?.label, data?.timerId, data?.wait]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'mode' && !isModeConnected && data.mode !== value) updateNodeData(id, { mode: value });
+    if (key === 'durationSecs' && !isDurationSecsConnected && data.durationSecs !== value) updateNodeData(id, { durationSecs: value });
+    if (key === 'tickMs' && !isTickMsConnected && data.tickMs !== value) updateNodeData(id, { tickMs: value });
+    if (key === 'label' && !isLabelConnected && data.label !== value) updateNodeData(id, { label: value });
+    if (key === 'timerId' && !isTimerIdConnected && data.timerId !== value) updateNodeData(id, { timerId: value });
+    if (key === 'wait' && !isWaitConnected && data.wait !== value) updateNodeData(id, { wait: value });
+  };
+
+  // 남은 시간 표시 (이 노드가 시작한 타이머만)
+  useEffect(() => {
+    const show = (event: { payload: TimerEvent }, running: boolean, suffix = '') => {
+      const { node_id, label, display } = event.payload;
+      if (node_id !== id) return;
+      setClock(`${label ? `${label} ` : ''}${display}${suffix}`);
+      setTicking(running);
+    };
+    const unlisteners = [
+      listen<TimerEvent>('timer-tick', (event) => show(event, true)),
+      listen<TimerEvent>('timer-complete', (event) => show(event, false, ' ✓')),
+      listen<TimerEvent>('timer-cancelled', (event) => show(event, false, ' (cancelled)')),
+    ];
+    return () => {
+      unlisteners.forEach(unlisten => unlisten.then(fn => fn()));
+    };
+  }, [id]);
+
+  const cancelTimer = useCallback(async () => {
+    try {
+      await invoke('cancel_timer', { timerId: data?.timerId?.trim() || id });
+    } catch (error) {
+      console.warn('⚠️ TimerNode: cancel failed', error);
+    }
+  }, [id, data?.timerId]);
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentMode = data?.mode?.trim() || '';
+    const currentDurationSecs = data?.durationSecs?.trim() || '';
+    const currentTickMs = data?.tickMs?.trim() || '';
+    const currentLabel = data?.label?.trim() || '';
+    const currentTimerId = data?.timerId?.trim() || '';
+    const currentWait = data?.wait?.trim() || '';
+
+    setStatus('running');
+    try {
+      const params = {
+        nodeId: id,
+        mode: currentMode || undefined,
+        durationSecs: toNumber(currentDurationSecs),
+        tickMs: toNumber(currentTickMs),
+        label: currentLabel || undefined,
+        timerId: currentTimerId || undefined,
+        wait: toBool(currentWait)
+      };
+
+      console.log(`⏱️ TimerNode ${id}: Starting timer... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('timer_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        timerId: toText(parsed.timer_id),
+        elapsedMs: toText(parsed.elapsed_ms),
+        completed: toText(parsed.completed ?? false)
+      };
+
+      setStatus('completed');
+      setResult(parsed.completed ? 'Timer finished' : 'Timer started');
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 TimerNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 TimerNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Timer failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.mode, data?.durationSecs, data?.tickMs, data?.label, data?.timerId, data?.wait, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`⏱️ Timer node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Timer"
+      icon={<Timer size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Countdown or stopwatch; a countdown waits until it ends before running the next nodes"
+    >
+      <div onBlur={() => handleBlur('mode', localMode)}>
+        <InputField
+          nodeId={id}
+          label="Mode"
+          icon={<Settings size={12} />}
+          value={localMode}
+          placeholder="countdown | stopwatch"
+          onChange={setLocalMode}
+          handleId="mode"
+          disabled={isModeConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('durationSecs', localDurationSecs)}>
+        <InputField
+          nodeId={id}
+          label="Duration (sec)"
+          icon={<Hourglass size={12} />}
+          value={localDurationSecs}
+          placeholder="1500"
+          onChange={setLocalDurationSecs}
+          handleId="durationSecs"
+          disabled={isDurationSecsConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('tickMs', localTickMs)}>
+        <InputField
+          nodeId={id}
+          label="Tick (ms)"
+          icon={<Timer size={12} />}
+          value={localTickMs}
+          placeholder="1000"
+          onChange={setLocalTickMs}
+          handleId="tickMs"
+          disabled={isTickMsConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('label', localLabel)}>
+        <InputField
+          nodeId={id}
+          label="Label"
+          icon={<Tag size={12} />}
+          value={localLabel}
+          placeholder="Pomodoro"
+          onChange={setLocalLabel}
+          handleId="label"
+          disabled={isLabelConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('timerId', localTimerId)}>
+        <InputField
+          nodeId={id}
+          label="Timer ID"
+          icon={<Hash size={12} />}
+          value={localTimerId}
+          placeholder="Node ID when empty"
+          onChange={setLocalTimerId}
+          handleId="timerId"
+          disabled={isTimerIdConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('wait', localWait)}>
+        <InputField
+          nodeId={id}
+          label="Wait Until Done"
+          icon={<Clock size={12} />}
+          value={localWait}
+          placeholder="true | false"
+          onChange={setLocalWait}
+          handleId="wait"
+          disabled={isWaitConnected}
+        />
+      </div>
+
+      {clock && (
+        <div className="node-input-field">
+          <div className="node-input-content">
+            <div className="node-input-label">
+              <Hourglass size={12} />
+              Clock
+            </div>
+            <div className="node-input-display-only">{clock}</div>
+            {ticking && (
+              <button className="node-custom-button destructive" onClick={cancelTimer}>
+                <Square size={12} /> Cancel
+              </button>
+            )}
+          </div>
+        </div>
+      )}
+
+      <OutputField
+        nodeId={id}
+        label="Timer ID"
+        icon={<Hash size={12} />}
+        value={data.outputData?.timerId || ''}
+        handleId="timerId"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Elapsed (ms)"
+        icon={<Timer size={12} />}
+        value={data.outputData?.elapsedMs || ''}
+        handleId="elapsedMs"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Completed"
+        icon={<CheckCircle size={12} />}
+        value={data.outputData?.completed || ''}
+        handleId="completed"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'timerNode',
+  label: 'Timer',
+  color: '#F59E0B',
+  category: 'Control',
+  settings: [
+    { key: 'mode', type: 'text', label: 'Mode', default: 'countdown' },
+    { key: 'durationSecs', type: 'text', label: 'Duration (sec)', default: '' },
+    { key: 'tickMs', type: 'text', label: 'Tick (ms)', default: '' },
+    { key: 'label', type: 'text', label: 'Label', default: '' },
+    { key: 'timerId', type: 'text', label: 'Timer ID', default: '' },
+    { key: 'wait', type: 'text', label: 'Wait Until Done', default: '' }
+  ]
+};
+
+export default TimerNode;
\ No newline at end of file
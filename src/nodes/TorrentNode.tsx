@@ -0,0 +1,286 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { Magnet, FolderOpen, Upload, Timer, File, Type, Gauge, Square } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toNumber, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+// "torrent-progress" 이벤트 (1초마다)
+interface TorrentProgress {
+  node_id: string;
+  state: 'downloading' | 'seeding';
+  name?: string;
+  percent: number;
+  download_speed: number;
+  upload_speed: number;
+}
+
+
+function TorrentNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localSource, setLocalSource] = useState('');
+  const [localDownloadPath, setLocalDownloadPath] = useState('');
+  const [localSeedRatio, setLocalSeedRatio] = useState('');
+  const [localSeedMinutes, setLocalSeedMinutes] = useState('');
+
+  const isSourceConnected = useHandleConnection(id, 'source');
+  const isDownloadPathConnected = useHandleConnection(id, 'downloadPath');
+  const isSeedRatioConnected = useHandleConnection(id, 'seedRatio');
+  const isSeedMinutesConnected = useHandleConnection(id, 'seedMinutes');
+
+  const [progress, setProgress] = useState('');
+
+  useEffect(() => {
+    setLocalSource(data?.source || '');
+    setLocalDownloadPath(data?.downloadPath || '');
+    setLocalSeedRatio(data?.seedRatio || '');
+    setLocalSeedMinutes(data?.seedMinutes || '');
+  }, [data?.source, data?.downloadPath, data?.seedRatio, data?.seedMinutes]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'source' && !isSourceConnected && data.source !== value) updateNodeData(id, { source: value });
+    if (key === 'downloadPath' && !isDownloadPathConnected && data.downloadPath !== value) updateNodeData(id, { downloadPath: value });
+    if (key === 'seedRatio' && !isSeedRatioConnected && data.seedRatio !== value) updateNodeData(id, { seedRatio: value });
+    if (key === 'seedMinutes' && !isSeedMinutesConnected && data.seedMinutes !== value) updateNodeData(id, { seedMinutes: value });
+  };
+
+  // 진행률 표시 (이 노드의 다운로드만)
+  useEffect(() => {
+    const unlisten = listen<TorrentProgress>('torrent-progress', (event) => {
+      const { node_id, state, name, percent, download_speed, upload_speed } = event.payload;
+      if (node_id !== id) return;
+      const speed = state === 'seeding' ? `↑ ${Math.round(upload_speed / 1024)} KB/s` : `↓ ${Math.round(download_speed / 1024)} KB/s`;
+      setProgress(`${name || 'metadata'}: ${state} ${percent}% (${speed})`);
+    });
+    return () => {
+      unlisten.then(fn => fn());
+    };
+  }, [id]);
+
+  const cancelDownload = useCallback(async () => {
+    try {
+      await invoke('cancel_torrent', { nodeId: id });
+    } catch (error) {
+      console.warn('⚠️ TorrentNode: cancel failed', error);
+    }
+  }, [id]);
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentSource = data?.source?.trim() || '';
+    const currentDownloadPath = data?.downloadPath?.trim() || '';
+    const currentSeedRatio = data?.seedRatio?.trim() || '';
+    const currentSeedMinutes = data?.seedMinutes?.trim() || '';
+
+    if (!currentSource || !currentDownloadPath) {
+      console.warn('⚠️ TorrentNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Source and Download Folder are required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Source and Download Folder are required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    setProgress('');
+    try {
+      const params = {
+        nodeId: id,
+        source: currentSource,
+        downloadPath: currentDownloadPath,
+        seedRatio: toNumber(currentSeedRatio),
+        seedMinutes: toNumber(currentSeedMinutes)
+      };
+
+      console.log(`🧲 TorrentNode ${id}: Starting torrent download... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('torrent_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        path: toText(parsed.path),
+        name: toText(parsed.name),
+        ratio: toText(parsed.ratio)
+      };
+
+      setStatus('completed');
+      setResult(`Downloaded ${parsed.name || parsed.path}`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 TorrentNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 TorrentNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Torrent download failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.source, data?.downloadPath, data?.seedRatio, data?.seedMinutes, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`🧲 Torrent Download node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Torrent Download"
+      icon={<Magnet size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Downloads a magnet link or .torrent with aria2, then seeds until the ratio or time limit"
+    >
+      <div onBlur={() => handleBlur('source', localSource)}>
+        <InputField
+          nodeId={id}
+          label="Source"
+          icon={<Magnet size={12} />}
+          value={localSource}
+          placeholder="magnet:?xt=... or /path/to/file.torrent"
+          onChange={setLocalSource}
+          handleId="source"
+          disabled={isSourceConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('downloadPath', localDownloadPath)}>
+        <InputField
+          nodeId={id}
+          label="Download Folder"
+          icon={<FolderOpen size={12} />}
+          value={localDownloadPath}
+          placeholder="/Users/username/Downloads"
+          onChange={setLocalDownloadPath}
+          handleId="downloadPath"
+          disabled={isDownloadPathConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('seedRatio', localSeedRatio)}>
+        <InputField
+          nodeId={id}
+          label="Seed Ratio"
+          icon={<Upload size={12} />}
+          value={localSeedRatio}
+          placeholder="1.0 (optional)"
+          onChange={setLocalSeedRatio}
+          handleId="seedRatio"
+          disabled={isSeedRatioConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('seedMinutes', localSeedMinutes)}>
+        <InputField
+          nodeId={id}
+          label="Seed Minutes"
+          icon={<Timer size={12} />}
+          value={localSeedMinutes}
+          placeholder="30 (optional)"
+          onChange={setLocalSeedMinutes}
+          handleId="seedMinutes"
+          disabled={isSeedMinutesConnected}
+        />
+      </div>
+
+      {(status === 'running' || progress) && (
+        <div className="node-input-field">
+          <div className="node-input-content">
+            <div className="node-input-label">
+              <Gauge size={12} />
+              Progress
+            </div>
+            <div className="node-input-display-only">{progress || 'Starting...'}</div>
+            {status === 'running' && (
+              <button className="node-custom-button destructive" onClick={cancelDownload}>
+                <Square size={12} /> Cancel
+              </button>
+            )}
+          </div>
+        </div>
+      )}
+
+      <OutputField
+        nodeId={id}
+        label="Downloaded Path"
+        icon={<File size={12} />}
+        value={data.outputData?.path || ''}
+        handleId="path"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Name"
+        icon={<Type size={12} />}
+        value={data.outputData?.name || ''}
+        handleId="name"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Seed Ratio"
+        icon={<Upload size={12} />}
+        value={data.outputData?.ratio || ''}
+        handleId="ratio"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'torrentNode',
+  label: 'Torrent Download',
+  color: '#EC4899',
+  category: 'Media',
+  settings: [
+    { key: 'source', type: 'text', label: 'Source', default: '' },
+    { key: 'downloadPath', type: 'text', label: 'Download Folder', default: '' },
+    { key: 'seedRatio', type: 'text', label: 'Seed Ratio', default: '' },
+    { key: 'seedMinutes', type: 'text', label: 'Seed Minutes', default: '' }
+  ]
+};
+
+export default TorrentNode;
\ No newline at end of file
@@ -0,0 +1,164 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { ShieldCheck, KeyRound, Timer } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function TotpNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localSecretName, setLocalSecretName] = useState('');
+
+  const isSecretNameConnected = useHandleConnection(id, 'secretName');
+
+  useEffect(() => {
+    setLocalSecretName(data?.secretName || '');
+  }, [data?.secretName]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'secretName' && !isSecretNameConnected && data.secretName !== value) updateNodeData(id, { secretName: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentSecretName = data?.secretName?.trim() || '';
+
+    if (!currentSecretName) {
+      console.warn('⚠️ TotpNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Secret Name is required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Secret Name is required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        secretName: currentSecretName
+      };
+
+      console.log(`🔐 TotpNode ${id}: Generating TOTP code... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('totp_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        code: toText(parsed.code),
+        remainingSeconds: toText(parsed.remaining_seconds)
+      };
+
+      setStatus('completed');
+      setResult(`Code valid for ${parsed.remaining_seconds}s`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 TotpNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 TotpNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ TOTP generation failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.secretName, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`🔐 TOTP Code node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="TOTP Code"
+      icon={<ShieldCheck size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Generates the current 2FA (TOTP) code from a stored secret"
+    >
+      <div onBlur={() => handleBlur('secretName', localSecretName)}>
+        <InputField
+          nodeId={id}
+          label="Secret Name"
+          icon={<KeyRound size={12} />}
+          value={localSecretName}
+          placeholder="github-2fa"
+          onChange={setLocalSecretName}
+          handleId="secretName"
+          disabled={isSecretNameConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Code"
+        icon={<ShieldCheck size={12} />}
+        value={data.outputData?.code || ''}
+        handleId="code"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Remaining (sec)"
+        icon={<Timer size={12} />}
+        value={data.outputData?.remainingSeconds || ''}
+        handleId="remainingSeconds"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'totpNode',
+  label: 'TOTP Code',
+  color: '#EF4444',
+  category: 'Security',
+  settings: [
+    { key: 'secretName', type: 'text', label: 'Secret Name', default: '' }
+  ]
+};
+
+export default TotpNode;
\ No newline at end of file
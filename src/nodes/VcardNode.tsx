@@ -0,0 +1,253 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { Contact, Settings, File, FileText, Users, FolderOpen, Hash } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toJson, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function VcardNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localAction, setLocalAction] = useState('');
+  const [localPath, setLocalPath] = useState('');
+  const [localContent, setLocalContent] = useState('');
+  const [localContacts, setLocalContacts] = useState('');
+  const [localOutputPath, setLocalOutputPath] = useState('');
+
+  const isActionConnected = useHandleConnection(id, 'action');
+  const isPathConnected = useHandleConnection(id, 'path');
+  const isContentConnected = useHandleConnection(id, 'content');
+  const isContactsConnected = useHandleConnection(id, 'contacts');
+  const isOutputPathConnected = useHandleConnection(id, 'outputPath');
+
+  useEffect(() => {
+    setLocalAction(data?.action || '');
+    setLocalPath(data?.path || '');
+    setLocalContent(data?.content || '');
+    setLocalContacts(data?.contacts || '');
+    setLocalOutputPath(data?.outputPath || '');
+  }, [data?.action, data?.path, data?.content, data?.contacts, data?.outputPath]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'action' && !isActionConnected && data.action !== value) updateNodeData(id, { action: value });
+    if (key === 'path' && !isPathConnected && data.path !== value) updateNodeData(id, { path: value });
+    if (key === 'content' && !isContentConnected && data.content !== value) updateNodeData(id, { content: value });
+    if (key === 'contacts' && !isContactsConnected && data.contacts !== value) updateNodeData(id, { contacts: value });
+    if (key === 'outputPath' && !isOutputPathConnected && data.outputPath !== value) updateNodeData(id, { outputPath: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentAction = data?.action?.trim() || '';
+    const currentPath = data?.path?.trim() || '';
+    const currentContent = data?.content || '';
+    const currentContacts = data?.contacts?.trim() || '';
+    const currentOutputPath = data?.outputPath?.trim() || '';
+
+    if (!currentAction) {
+      console.warn('⚠️ VcardNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Action is required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Action is required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        action: currentAction,
+        path: currentPath || undefined,
+        content: currentContent || undefined,
+        contacts: toJson(currentContacts),
+        outputPath: currentOutputPath || undefined
+      };
+
+      console.log(`📇 VcardNode ${id}: Processing vCard... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('vcard_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        contacts: toText(parsed.contacts),
+        vcard: toText(parsed.vcard),
+        count: toText(parsed.count)
+      };
+
+      setStatus('completed');
+      setResult(`${parsed.count} contact(s)`);
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 VcardNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 VcardNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ vCard action failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.action, data?.path, data?.content, data?.contacts, data?.outputPath, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`📇 vCard node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="vCard"
+      icon={<Contact size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Parses .vcf contacts to JSON or generates vCards from JSON contacts"
+    >
+      <div onBlur={() => handleBlur('action', localAction)}>
+        <InputField
+          nodeId={id}
+          label="Action"
+          icon={<Settings size={12} />}
+          value={localAction}
+          placeholder="parse | generate"
+          onChange={setLocalAction}
+          handleId="action"
+          disabled={isActionConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('path', localPath)}>
+        <InputField
+          nodeId={id}
+          label="vCard File"
+          icon={<File size={12} />}
+          value={localPath}
+          placeholder="/path/to/contacts.vcf (parse)"
+          onChange={setLocalPath}
+          handleId="path"
+          disabled={isPathConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('content', localContent)}>
+        <InputField
+          nodeId={id}
+          label="vCard Text"
+          icon={<FileText size={12} />}
+          value={localContent}
+          placeholder="BEGIN:VCARD... (parse)"
+          onChange={setLocalContent}
+          handleId="content"
+          disabled={isContentConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('contacts', localContacts)}>
+        <InputField
+          nodeId={id}
+          label="Contacts"
+          icon={<Users size={12} />}
+          value={localContacts}
+          placeholder={'[{"full_name":"Hong Gildong","phones":[{"type":"cell","value":"010-..."}]}]'}
+          onChange={setLocalContacts}
+          handleId="contacts"
+          disabled={isContactsConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('outputPath', localOutputPath)}>
+        <InputField
+          nodeId={id}
+          label="Output Path"
+          icon={<FolderOpen size={12} />}
+          value={localOutputPath}
+          placeholder="/path/to/out.vcf (generate)"
+          onChange={setLocalOutputPath}
+          handleId="outputPath"
+          disabled={isOutputPathConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Contacts"
+        icon={<Users size={12} />}
+        value={data.outputData?.contacts || ''}
+        handleId="contacts"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="vCard"
+        icon={<Contact size={12} />}
+        value={data.outputData?.vcard || ''}
+        handleId="vcard"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Count"
+        icon={<Hash size={12} />}
+        value={data.outputData?.count || ''}
+        handleId="count"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'vcardNode',
+  label: 'vCard',
+  color: '#14B8A6',
+  category: 'Data',
+  settings: [
+    { key: 'action', type: 'text', label: 'Action', default: 'parse' },
+    { key: 'path', type: 'text', label: 'vCard File', default: '' },
+    { key: 'content', type: 'text', label: 'vCard Text', default: '' },
+    { key: 'contacts', type: 'text', label: 'Contacts', default: '' },
+    { key: 'outputPath', type: 'text', label: 'Output Path', default: '' }
+  ]
+};
+
+export default VcardNode;
\ No newline at end of file
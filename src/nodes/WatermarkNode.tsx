@@ -0,0 +1,335 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { Stamp, File, FolderOpen, Image, Type, Move, Droplet, Maximize, Square, FileType, CheckCircle } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toNumber, toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function WatermarkNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localInputPath, setLocalInputPath] = useState('');
+  const [localOutputPath, setLocalOutputPath] = useState('');
+  const [localLogoPath, setLocalLogoPath] = useState('');
+  const [localText, setLocalText] = useState('');
+  const [localPosition, setLocalPosition] = useState('');
+  const [localOpacity, setLocalOpacity] = useState('');
+  const [localScale, setLocalScale] = useState('');
+  const [localMargin, setLocalMargin] = useState('');
+  const [localFontSize, setLocalFontSize] = useState('');
+  const [localFontPath, setLocalFontPath] = useState('');
+
+  const isInputPathConnected = useHandleConnection(id, 'inputPath');
+  const isOutputPathConnected = useHandleConnection(id, 'outputPath');
+  const isLogoPathConnected = useHandleConnection(id, 'logoPath');
+  const isTextConnected = useHandleConnection(id, 'text');
+  const isPositionConnected = useHandleConnection(id, 'position');
+  const isOpacityConnected = useHandleConnection(id, 'opacity');
+  const isScaleConnected = useHandleConnection(id, 'scale');
+  const isMarginConnected = useHandleConnection(id, 'margin');
+  const isFontSizeConnected = useHandleConnection(id, 'fontSize');
+  const isFontPathConnected = useHandleConnection(id, 'fontPath');
+
+  useEffect(() => {
+    setLocalInputPath(data?.inputPath || '');
+    setLocalOutputPath(data?.outputPath || '');
+    setLocalLogoPath(data?.logoPath || '');
+    setLocalText(data?.text || '');
+    setLocalPosition(data?.position || '');
+    setLocalOpacity(data?.opacity || '');
+    setLocalScale(data?.scale || '');
+    setLocalMargin(data?.margin || '');
+    setLocalFontSize(data?.fontSize || '');
+    setLocalFontPath(data?.fontPath || '');
+  }, [data?.inputPath, data?.outputPath, data?.logoPath, data?.text, data?.position, data?.opacity, data?.scale, data?.margin, data?.fontSize, data?.fontPath]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'inputPath' && !isInputPathConnected && data.inputPath !== value) updateNodeData(id, { inputPath: value });
+    if (key === 'outputPath' && !isOutputPathConnected && data.outputPath !== value) updateNodeData(id, { outputPath: value });
+    if (key === 'logoPath' && !isLogoPathConnected && data.logoPath !== value) updateNodeData(id, { logoPath: value });
+    if (key === 'text' && !isTextConnected && data.text !== value) updateNodeData(id, { text: value });
+    if (key === 'position' && !isPositionConnected && data.position !== value) updateNodeData(id, { position: value });
+    if (key === 'opacity' && !isOpacityConnected && data.opacity !== value) updateNodeData(id, { opacity: value });
+    if (key === 'scale' && !isScaleConnected && data.scale !== value) updateNodeData(id, { scale: value });
+    if (key === 'margin' && !isMarginConnected && data.margin !== value) updateNodeData(id, { margin: value });
+    if (key === 'fontSize' && !isFontSizeConnected && data.fontSize !== value) updateNodeData(id, { fontSize: value });
+    if (key === 'fontPath' && !isFontPathConnected && data.fontPath !== value) updateNodeData(id, { fontPath: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentInputPath = data?.inputPath?.trim() || '';
+    const currentOutputPath = data?.outputPath?.trim() || '';
+    const currentLogoPath = data?.logoPath?.trim() || '';
+    const currentText = data?.text || '';
+    const currentPosition = data?.position?.trim() || '';
+    const currentOpacity = data?.opacity?.trim() || '';
+    const currentScale = data?.scale?.trim() || '';
+    const currentMargin = data?.margin?.trim() || '';
+    const currentFontSize = data?.fontSize?.trim() || '';
+    const currentFontPath = data?.fontPath?.trim() || '';
+
+    if (!currentInputPath || !currentOutputPath) {
+      console.warn('⚠️ WatermarkNode: Missing required fields, skipping execution');
+      setStatus('failed');
+      setResult('Input File and Output File are required');
+
+      updateNodeData(id, { triggerExecution: undefined });
+      if (mode === 'triggered') {
+        reportNodeError(id, 'Input File and Output File are required');
+      }
+
+      setTimeout(() => {
+        setStatus('waiting');
+        setResult('');
+      }, 2000);
+      return;
+    }
+
+    setStatus('running');
+    try {
+      const params = {
+        inputPath: currentInputPath,
+        outputPath: currentOutputPath,
+        logoPath: currentLogoPath || undefined,
+        text: currentText || undefined,
+        position: currentPosition || undefined,
+        opacity: toNumber(currentOpacity),
+        scale: toNumber(currentScale),
+        margin: toNumber(currentMargin),
+        fontSize: toNumber(currentFontSize),
+        fontPath: currentFontPath || undefined
+      };
+
+      console.log(`🖋️ WatermarkNode ${id}: Applying watermark... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('watermark_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        filePath: toText(parsed.file_path)
+      };
+
+      setStatus('completed');
+      setResult('Watermark applied');
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 WatermarkNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 WatermarkNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Watermark failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.inputPath, data?.outputPath, data?.logoPath, data?.text, data?.position, data?.opacity, data?.scale, data?.margin, data?.fontSize, data?.fontPath, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`🖋️ Watermark node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Watermark"
+      icon={<Stamp size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Adds a logo or text watermark to an image or video"
+    >
+      <div onBlur={() => handleBlur('inputPath', localInputPath)}>
+        <InputField
+          nodeId={id}
+          label="Input File"
+          icon={<File size={12} />}
+          value={localInputPath}
+          placeholder="/path/to/photo.jpg"
+          onChange={setLocalInputPath}
+          handleId="inputPath"
+          disabled={isInputPathConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('outputPath', localOutputPath)}>
+        <InputField
+          nodeId={id}
+          label="Output File"
+          icon={<FolderOpen size={12} />}
+          value={localOutputPath}
+          placeholder="/path/to/photo_marked.jpg"
+          onChange={setLocalOutputPath}
+          handleId="outputPath"
+          disabled={isOutputPathConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('logoPath', localLogoPath)}>
+        <InputField
+          nodeId={id}
+          label="Logo Image"
+          icon={<Image size={12} />}
+          value={localLogoPath}
+          placeholder="/path/to/logo.png"
+          onChange={setLocalLogoPath}
+          handleId="logoPath"
+          disabled={isLogoPathConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('text', localText)}>
+        <InputField
+          nodeId={id}
+          label="Text"
+          icon={<Type size={12} />}
+          value={localText}
+          placeholder="© ACME"
+          onChange={setLocalText}
+          handleId="text"
+          disabled={isTextConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('position', localPosition)}>
+        <InputField
+          nodeId={id}
+          label="Position"
+          icon={<Move size={12} />}
+          value={localPosition}
+          placeholder="bottom_right | bottom_left | top_right | top_left | center"
+          onChange={setLocalPosition}
+          handleId="position"
+          disabled={isPositionConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('opacity', localOpacity)}>
+        <InputField
+          nodeId={id}
+          label="Opacity"
+          icon={<Droplet size={12} />}
+          value={localOpacity}
+          placeholder="0.7"
+          onChange={setLocalOpacity}
+          handleId="opacity"
+          disabled={isOpacityConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('scale', localScale)}>
+        <InputField
+          nodeId={id}
+          label="Logo Scale"
+          icon={<Maximize size={12} />}
+          value={localScale}
+          placeholder="0.15"
+          onChange={setLocalScale}
+          handleId="scale"
+          disabled={isScaleConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('margin', localMargin)}>
+        <InputField
+          nodeId={id}
+          label="Margin (px)"
+          icon={<Square size={12} />}
+          value={localMargin}
+          placeholder="20"
+          onChange={setLocalMargin}
+          handleId="margin"
+          disabled={isMarginConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('fontSize', localFontSize)}>
+        <InputField
+          nodeId={id}
+          label="Font Size"
+          icon={<Type size={12} />}
+          value={localFontSize}
+          placeholder="36"
+          onChange={setLocalFontSize}
+          handleId="fontSize"
+          disabled={isFontSizeConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('fontPath', localFontPath)}>
+        <InputField
+          nodeId={id}
+          label="Font File"
+          icon={<FileType size={12} />}
+          value={localFontPath}
+          placeholder="/path/to/font.ttf (optional)"
+          onChange={setLocalFontPath}
+          handleId="fontPath"
+          disabled={isFontPathConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Output File"
+        icon={<CheckCircle size={12} />}
+        value={data.outputData?.filePath || ''}
+        handleId="filePath"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'watermarkNode',
+  label: 'Watermark',
+  color: '#EC4899',
+  category: 'Media',
+  settings: [
+    { key: 'inputPath', type: 'text', label: 'Input File', default: '' },
+    { key: 'outputPath', type: 'text', label: 'Output File', default: '' },
+    { key: 'logoPath', type: 'text', label: 'Logo Image', default: '' },
+    { key: 'text', type: 'text', label: 'Text', default: '' },
+    { key: 'position', type: 'text', label: 'Position', default: 'bottom_right' },
+    { key: 'opacity', type: 'text', label: 'Opacity', default: '' },
+    { key: 'scale', type: 'text', label: 'Logo Scale', default: '' },
+    { key: 'margin', type: 'text', label: 'Margin (px)', default: '' },
+    { key: 'fontSize', type: 'text', label: 'Font Size', default: '' },
+    { key: 'fontPath', type: 'text', label: 'Font File', default: '' }
+  ]
+};
+
+export default WatermarkNode;
\ No newline at end of file
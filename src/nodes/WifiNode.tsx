@@ -0,0 +1,185 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { Wifi, Settings, CheckCircle, Signal, List } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import BaseNode, { InputField, OutputField } from './Basenode';
+import { toText, errorText } from './nodeValues';
+
+
+import { useWorkflow, useHandleConnection } from '../WorkflowContext';
+
+
+function WifiNode({ id, data, selected }) {
+  const { executeNextNodes, reportNodeError, updateNodeData } = useWorkflow();
+
+  const [status, setStatus] = useState('waiting');
+  const [result, setResult] = useState<string>('');
+
+  const [localAction, setLocalAction] = useState('');
+  const [localSsid, setLocalSsid] = useState('');
+
+  const isActionConnected = useHandleConnection(id, 'action');
+  const isSsidConnected = useHandleConnection(id, 'ssid');
+
+  useEffect(() => {
+    setLocalAction(data?.action || '');
+    setLocalSsid(data?.ssid || '');
+  }, [data?.action, data?.ssid]);
+
+  const handleBlur = (key, value) => {
+    if (key === 'action' && !isActionConnected && data.action !== value) updateNodeData(id, { action: value });
+    if (key === 'ssid' && !isSsidConnected && data.ssid !== value) updateNodeData(id, { ssid: value });
+  };
+
+  const executeNode = useCallback(async (mode = 'triggered') => {
+    // 실행 전 필수 필드 검증
+    const currentAction = data?.action?.trim() || '';
+    const currentSsid = data?.ssid?.trim() || '';
+
+    setStatus('running');
+    try {
+      const params = {
+        action: currentAction || undefined,
+        ssid: currentSsid || undefined
+      };
+
+      console.log(`📶 WifiNode ${id}: Running Wi-Fi action... (mode: ${mode})`);
+
+      const resultData = await invoke<string>('wifi_node', params);
+      const parsed = JSON.parse(resultData);
+
+      const outputData = {
+        connected: toText(parsed.connected),
+        ssid: toText(parsed.ssid),
+        signal: toText(parsed.signal),
+        networks: toText(parsed.networks)
+      };
+
+      setStatus('completed');
+      setResult(parsed.networks ? `${parsed.networks.length} saved networks` : (parsed.connected ? `Connected: ${parsed.ssid}` : 'Not connected'));
+
+      updateNodeData(id, {
+        triggerExecution: undefined,
+        outputData
+      });
+
+      // 실행 모드에 따른 연쇄 실행 결정
+      if (mode === 'triggered') {
+        executeNextNodes(id, outputData);
+        console.log(`🔗 WifiNode: Triggering next nodes with data (auto-execution)`);
+      } else {
+        console.log(`🔧 WifiNode: Manual execution completed, no chain reaction`);
+      }
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+
+    } catch (error: unknown) {
+      const errorMessage = errorText(error);
+      console.error('❌ Wi-Fi action failed:', errorMessage, error);
+      setStatus('failed');
+      setResult(errorMessage);
+
+      // 트리거 실행이면 실패 보고 (다음 노드로는 전파하지 않음)
+      if (mode === 'triggered') {
+        reportNodeError(id, errorMessage);
+      }
+
+      updateNodeData(id, { triggerExecution: undefined });
+
+      setTimeout(() => { setStatus('waiting'); setResult(''); }, 2000);
+    }
+  }, [id, data?.action, data?.ssid, executeNextNodes, reportNodeError, updateNodeData]);
+
+  // ✅ 트리거 실행 감지 (executeNode가 useCallback으로 안정화됨)
+  useEffect(() => {
+    if (data.triggerExecution && typeof data.triggerExecution === 'number') {
+      console.log(`📶 Wi-Fi node ${id} auto-execution triggered!`);
+      executeNode('triggered'); // 자동 트리거 모드로 실행
+    }
+  }, [data.triggerExecution, executeNode]);
+
+  return (
+    <BaseNode
+      id={id}
+      title="Wi-Fi"
+      icon={<Wifi size={16} stroke="white" />}
+      status={status}
+      selected={selected}
+      onExecute={executeNode} // 실행 모드 매개변수 지원
+      data={data}
+      result={result}
+      description="Reads Wi-Fi status, lists saved networks or connects to a saved network"
+    >
+      <div onBlur={() => handleBlur('action', localAction)}>
+        <InputField
+          nodeId={id}
+          label="Action"
+          icon={<Settings size={12} />}
+          value={localAction}
+          placeholder="status | saved | connect"
+          onChange={setLocalAction}
+          handleId="action"
+          disabled={isActionConnected}
+        />
+      </div>
+
+      <div onBlur={() => handleBlur('ssid', localSsid)}>
+        <InputField
+          nodeId={id}
+          label="SSID"
+          icon={<Wifi size={12} />}
+          value={localSsid}
+          placeholder="Saved network name (connect)"
+          onChange={setLocalSsid}
+          handleId="ssid"
+          disabled={isSsidConnected}
+        />
+      </div>
+
+      <OutputField
+        nodeId={id}
+        label="Connected"
+        icon={<CheckCircle size={12} />}
+        value={data.outputData?.connected || ''}
+        handleId="connected"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="SSID"
+        icon={<Wifi size={12} />}
+        value={data.outputData?.ssid || ''}
+        handleId="ssid"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Signal"
+        icon={<Signal size={12} />}
+        value={data.outputData?.signal || ''}
+        handleId="signal"
+      />
+
+      <OutputField
+        nodeId={id}
+        label="Saved Networks"
+        icon={<List size={12} />}
+        value={data.outputData?.networks || ''}
+        handleId="networks"
+      />
+    </BaseNode>
+  );
+}
+
+// 사이드바 자동 발견을 위한 설정 정보
+export const config = {
+  type: 'wifiNode',
+  label: 'Wi-Fi',
+  color: '#0EA5E9',
+  category: 'Network',
+  settings: [
+    { key: 'action', type: 'text', label: 'Action', default: 'status' },
+    { key: 'ssid', type: 'text', label: 'SSID', default: '' }
+  ]
+};
+
+export default WifiNode;
\ No newline at end of file
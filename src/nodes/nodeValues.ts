@@ -0,0 +1,46 @@
+// 🔄 노드 값 변환
+// 노드 데이터는 모두 문자열 (README Node.md), 백엔드 명령 인자는 타입에 맞게 바꿔서 넘김
+// - 빈 문자열은 undefined → 백엔드에서 None (기본값 사용)
+// - 결과의 객체/배열은 JSON 문자열로 출력
+
+export const toNumber = (value: string): number | undefined => {
+  const trimmed = value.trim();
+  if (!trimmed) return undefined;
+  const parsed = Number(trimmed);
+  if (Number.isNaN(parsed)) throw new Error(`INVALID_NUMBER: ${trimmed}`);
+  return parsed;
+};
+
+export const toBool = (value: string): boolean | undefined => {
+  const trimmed = value.trim().toLowerCase();
+  if (!trimmed) return undefined;
+  return trimmed === 'true' || trimmed === '1' || trimmed === 'yes';
+};
+
+// 쉼표 또는 줄바꿈으로 구분된 목록
+export const toList = (value: string): string[] | undefined => {
+  const items = value.split(/[,\n]/).map(item => item.trim()).filter(Boolean);
+  return items.length ? items : undefined;
+};
+
+export const toJson = (value: string): unknown => {
+  const trimmed = value.trim();
+  if (!trimmed) return undefined;
+  try {
+    return JSON.parse(trimmed);
+  } catch {
+    throw new Error(`INVALID_JSON: ${trimmed.slice(0, 80)}`);
+  }
+};
+
+export const toText = (value: unknown): string => {
+  if (value === undefined || value === null) return '';
+  if (typeof value === 'string') return value;
+  return typeof value === 'object' ? JSON.stringify(value) : String(value);
+};
+
+// invoke 실패는 보통 문자열 ("UPPER_CASE: detail") 로 옴
+export const errorText = (error: unknown): string => {
+  if (typeof error === 'string') return error;
+  return error instanceof Error ? error.message : 'Unknown error occurred';
+};